@@ -17,6 +17,11 @@ fn main() {
     // Set environment variable for use in code
     println!("cargo:rustc-env=GIT_HASH={git_hash}");
 
+    // Target triple selects the release asset `self-update` downloads
+    let target = std::env::var("TARGET").unwrap_or_else(|_| "unknown".to_string());
+    println!("cargo:rustc-env=XCHECKER_BUILD_TARGET={target}");
+    println!("cargo:rerun-if-env-changed=XCHECKER_RELEASE_PUBLIC_KEY");
+
     // Rerun if git HEAD changes
     println!("cargo:rerun-if-changed=.git/HEAD");
     println!("cargo:rerun-if-changed=.git/refs/heads");
@@ -0,0 +1,62 @@
+//! Criterion benches for packet build, redaction scan, canonicalization, and hashing.
+//!
+//! Set `XCHECKER_BENCH_DIR` to benchmark a real checkout; otherwise a small
+//! synthetic corpus is generated.
+
+use camino::Utf8PathBuf;
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use std::hint::black_box;
+use tempfile::TempDir;
+
+use xchecker_benchmark::throughput::{
+    Corpus, STAGE_CANONICALIZATION, STAGE_HASHING, STAGE_PACKET_BUILD, STAGE_REDACTION_SCAN,
+};
+use xchecker_utils::canonicalization::Canonicalizer;
+use xchecker_utils::redaction::SecretRedactor;
+
+fn synthetic_root(dir: &TempDir) -> Utf8PathBuf {
+    let root = Utf8PathBuf::try_from(dir.path().to_path_buf()).expect("utf-8 temp path");
+    for i in 0..100 {
+        let body = format!(
+            "# Section {i}\n\n{}\n",
+            "Benchmark content line. ".repeat(40)
+        );
+        std::fs::write(root.join(format!("doc-{i}.md")), body).expect("write corpus file");
+        std::fs::write(
+            root.join(format!("data-{i}.yaml")),
+            format!("id: {i}\nname: item\n"),
+        )
+        .expect("write corpus file");
+    }
+    root
+}
+
+fn throughput_benches(c: &mut Criterion) {
+    let synthetic = TempDir::new().expect("temp dir");
+    let root = match std::env::var("XCHECKER_BENCH_DIR") {
+        Ok(dir) => Utf8PathBuf::from(dir),
+        Err(_) => synthetic_root(&synthetic),
+    };
+    let corpus = Corpus::load(&root).expect("load corpus");
+    let redactor = SecretRedactor::new().expect("redactor");
+    let canonicalizer = Canonicalizer::new();
+    let context = TempDir::new().expect("temp dir");
+    let context_dir = Utf8PathBuf::try_from(context.path().to_path_buf()).expect("utf-8 path");
+
+    let mut group = c.benchmark_group("throughput");
+    group.throughput(Throughput::Bytes(corpus.total_bytes() as u64));
+    group.bench_function(STAGE_PACKET_BUILD, |b| {
+        b.iter(|| black_box(corpus.packet_build(&context_dir).expect("packet build")));
+    });
+    group.bench_function(STAGE_REDACTION_SCAN, |b| {
+        b.iter(|| black_box(corpus.redaction_scan(&redactor).expect("redaction scan")));
+    });
+    group.bench_function(STAGE_CANONICALIZATION, |b| {
+        b.iter(|| black_box(corpus.canonicalize(&canonicalizer)));
+    });
+    group.bench_function(STAGE_HASHING, |b| b.iter(|| black_box(corpus.hash())));
+    group.finish();
+}
+
+criterion_group!(benches, throughput_benches);
+criterion_main!(benches);
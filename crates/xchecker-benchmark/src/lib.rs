@@ -2,6 +2,9 @@
 //!
 //! This module provides benchmarking capabilities to validate that xchecker
 //! meets its performance targets: empty run ≤ 5s, packetization ≤ 200ms for 100 files.
+//! The [`throughput`] module measures per-stage throughput on a real directory.
+
+pub mod throughput;
 
 use anyhow::Result;
 use camino::{Utf8Path, Utf8PathBuf};
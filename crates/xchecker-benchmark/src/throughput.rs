@@ -0,0 +1,339 @@
+//! Throughput benchmarks over a user-provided directory
+//!
+//! Measures the hot paths that dominate xchecker runs on large repositories:
+//! packet build, secret redaction scan, canonicalization, and BLAKE3 hashing.
+//! The same workload functions back the criterion benches in `benches/` and the
+//! hidden `xchecker bench` subcommand, so CI numbers and ad-hoc numbers agree.
+
+use anyhow::{Context, Result, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+use xchecker_packet::{
+    ContentSelector, DEFAULT_PACKET_MAX_BYTES, DEFAULT_PACKET_MAX_LINES, PacketBuilder,
+    SelectedFile,
+};
+use xchecker_utils::canonicalization::Canonicalizer;
+use xchecker_utils::redaction::SecretRedactor;
+use xchecker_utils::types::FileType;
+
+/// Stage name for packet assembly
+pub const STAGE_PACKET_BUILD: &str = "packet_build";
+/// Stage name for the secret redaction scan
+pub const STAGE_REDACTION_SCAN: &str = "redaction_scan";
+/// Stage name for content canonicalization
+pub const STAGE_CANONICALIZATION: &str = "canonicalization";
+/// Stage name for BLAKE3 hashing
+pub const STAGE_HASHING: &str = "hashing";
+
+/// Configuration for a throughput run
+#[derive(Debug, Clone)]
+pub struct ThroughputConfig {
+    /// Directory whose files form the workload
+    pub root: Utf8PathBuf,
+    /// Number of measured samples per stage
+    pub samples: usize,
+    /// Number of unmeasured warm-up passes per stage
+    pub warmup: usize,
+}
+
+impl ThroughputConfig {
+    /// Create a config for `root` with default sample counts
+    #[must_use]
+    pub fn new(root: impl Into<Utf8PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            samples: 10,
+            warmup: 1,
+        }
+    }
+}
+
+/// Files loaded from the benchmark root
+///
+/// Loaded once up front so that file system reads are excluded from the
+/// redaction, canonicalization, and hashing measurements.
+#[derive(Debug)]
+pub struct Corpus {
+    root: Utf8PathBuf,
+    files: Vec<SelectedFile>,
+    total_bytes: usize,
+    total_lines: usize,
+}
+
+impl Corpus {
+    /// Load every file the default content selector would pick under `root`
+    pub fn load(root: &Utf8Path) -> Result<Self> {
+        if !root.is_dir() {
+            bail!("Benchmark root is not a directory: {root}");
+        }
+
+        let selector = ContentSelector::new()?;
+        let files = selector
+            .select_files(root)
+            .with_context(|| format!("Failed to select files from {root}"))?;
+        let total_bytes = files.iter().map(|f| f.content.len()).sum();
+        let total_lines = files.iter().map(|f| f.content.lines().count()).sum();
+
+        Ok(Self {
+            root: root.to_owned(),
+            files,
+            total_bytes,
+            total_lines,
+        })
+    }
+
+    /// Number of files in the corpus
+    #[must_use]
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Total content size in bytes
+    #[must_use]
+    pub const fn total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+
+    /// Build a packet over the corpus root with a budget large enough to hold every file
+    pub fn packet_build(&self, context_dir: &Utf8Path) -> Result<usize> {
+        let max_bytes = self
+            .total_bytes
+            .saturating_mul(2)
+            .max(DEFAULT_PACKET_MAX_BYTES);
+        let max_lines = self
+            .total_lines
+            .saturating_mul(2)
+            .max(DEFAULT_PACKET_MAX_LINES);
+        let mut builder = PacketBuilder::with_limits(max_bytes, max_lines)?;
+        let packet = builder.build_packet(&self.root, "bench", context_dir, None)?;
+        Ok(packet.content().len())
+    }
+
+    /// Scan every file for secrets, returning the number of matches
+    pub fn redaction_scan(&self, redactor: &SecretRedactor) -> Result<usize> {
+        let mut matches = 0;
+        for file in &self.files {
+            matches += redactor
+                .scan_for_secrets(&file.content, file.path.as_str())?
+                .len();
+        }
+        Ok(matches)
+    }
+
+    /// Canonicalize every file according to its extension, returning output bytes
+    ///
+    /// YAML that fails to parse falls back to text normalization, matching how
+    /// non-artifact inputs are treated elsewhere.
+    #[must_use]
+    pub fn canonicalize(&self, canonicalizer: &Canonicalizer) -> usize {
+        let mut bytes = 0;
+        for file in &self.files {
+            let ext = file.path.extension().unwrap_or_default();
+            let output = match FileType::from_extension(ext) {
+                FileType::Yaml => canonicalizer.canonicalize_yaml(&file.content).ok(),
+                FileType::Markdown => canonicalizer.normalize_markdown(&file.content).ok(),
+                FileType::Text => None,
+            }
+            .unwrap_or_else(|| canonicalizer.normalize_text(&file.content));
+            bytes += output.len();
+        }
+        bytes
+    }
+
+    /// Hash every file with BLAKE3, folding the digests into one
+    #[must_use]
+    pub fn hash(&self) -> String {
+        let mut combined = blake3::Hasher::new();
+        for file in &self.files {
+            combined.update(blake3::hash(file.content.as_bytes()).as_bytes());
+        }
+        combined.finalize().to_hex().to_string()
+    }
+}
+
+/// Timing summary for a single stage
+#[derive(Debug, Clone, Serialize)]
+pub struct StageResult {
+    /// Median sample time in milliseconds
+    pub median_ms: f64,
+    /// Fastest sample in milliseconds
+    pub min_ms: f64,
+    /// Slowest sample in milliseconds
+    pub max_ms: f64,
+    /// Throughput in MiB/s at the median
+    pub mib_per_sec: f64,
+    /// Throughput in files/s at the median
+    pub files_per_sec: f64,
+}
+
+/// Results of a throughput run, serialized as the `xchecker bench` JSON output
+#[derive(Debug, Clone, Serialize)]
+pub struct ThroughputReport {
+    /// Benchmark root directory
+    pub root: String,
+    /// Number of files in the workload
+    pub file_count: usize,
+    /// Total workload size in bytes
+    pub total_bytes: usize,
+    /// Measured samples per stage
+    pub samples: usize,
+    /// Warm-up passes per stage
+    pub warmup: usize,
+    /// Per-stage results keyed by stage name
+    pub stages: BTreeMap<String, StageResult>,
+}
+
+/// Run all throughput stages against `config.root`
+pub fn run_throughput(config: &ThroughputConfig) -> Result<ThroughputReport> {
+    if config.samples == 0 {
+        bail!("Benchmark samples must be at least 1");
+    }
+
+    let corpus = Corpus::load(&config.root)?;
+    let redactor = SecretRedactor::new()?;
+    let canonicalizer = Canonicalizer::new();
+    let context_temp = TempDir::new()?;
+    let context_dir = Utf8PathBuf::try_from(context_temp.path().to_path_buf())?;
+
+    let mut stages = BTreeMap::new();
+    stages.insert(
+        STAGE_PACKET_BUILD.to_string(),
+        measure(&corpus, config, || {
+            corpus.packet_build(&context_dir).map(drop)
+        })?,
+    );
+    stages.insert(
+        STAGE_REDACTION_SCAN.to_string(),
+        measure(&corpus, config, || {
+            corpus.redaction_scan(&redactor).map(drop)
+        })?,
+    );
+    stages.insert(
+        STAGE_CANONICALIZATION.to_string(),
+        measure(&corpus, config, || {
+            std::hint::black_box(corpus.canonicalize(&canonicalizer));
+            Ok(())
+        })?,
+    );
+    stages.insert(
+        STAGE_HASHING.to_string(),
+        measure(&corpus, config, || {
+            std::hint::black_box(corpus.hash());
+            Ok(())
+        })?,
+    );
+
+    Ok(ThroughputReport {
+        root: config.root.to_string(),
+        file_count: corpus.file_count(),
+        total_bytes: corpus.total_bytes(),
+        samples: config.samples,
+        warmup: config.warmup,
+        stages,
+    })
+}
+
+/// Time `f` over warm-up and measured passes and summarize the samples
+fn measure(
+    corpus: &Corpus,
+    config: &ThroughputConfig,
+    mut f: impl FnMut() -> Result<()>,
+) -> Result<StageResult> {
+    for _ in 0..config.warmup {
+        f()?;
+    }
+
+    let mut samples = Vec::with_capacity(config.samples);
+    for _ in 0..config.samples {
+        let start = Instant::now();
+        f()?;
+        samples.push(start.elapsed());
+    }
+    samples.sort();
+
+    let median = median_of_sorted(&samples);
+    let median_secs = median.as_secs_f64();
+    let per_sec = |amount: f64| {
+        if median_secs > 0.0 {
+            amount / median_secs
+        } else {
+            0.0
+        }
+    };
+
+    Ok(StageResult {
+        median_ms: median_secs * 1000.0,
+        min_ms: samples[0].as_secs_f64() * 1000.0,
+        max_ms: samples[samples.len() - 1].as_secs_f64() * 1000.0,
+        mib_per_sec: per_sec(corpus.total_bytes() as f64 / (1024.0 * 1024.0)),
+        files_per_sec: per_sec(corpus.file_count() as f64),
+    })
+}
+
+/// Median of a non-empty, sorted slice of durations
+fn median_of_sorted(sorted: &[Duration]) -> Duration {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn sample_root() -> Result<(TempDir, Utf8PathBuf)> {
+        let temp_dir = TempDir::new()?;
+        let root = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        fs::write(root.join("README.md"), "# Title\n\nSome text.\n")?;
+        fs::write(root.join("SCHEMA.yaml"), "b: 2\na: 1\n")?;
+        fs::write(root.join("notes.txt"), "plain text\r\n")?;
+        Ok((temp_dir, root))
+    }
+
+    #[test]
+    fn test_run_throughput_reports_all_stages() -> Result<()> {
+        let (_temp, root) = sample_root()?;
+        let config = ThroughputConfig {
+            samples: 2,
+            warmup: 0,
+            ..ThroughputConfig::new(root)
+        };
+
+        let report = run_throughput(&config)?;
+
+        assert!(report.file_count >= 2);
+        assert!(report.total_bytes > 0);
+        for stage in [
+            STAGE_PACKET_BUILD,
+            STAGE_REDACTION_SCAN,
+            STAGE_CANONICALIZATION,
+            STAGE_HASHING,
+        ] {
+            let result = report.stages.get(stage).expect("stage missing");
+            assert!(result.min_ms <= result.median_ms);
+            assert!(result.median_ms <= result.max_ms);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_throughput_rejects_missing_dir() {
+        let config = ThroughputConfig::new("/definitely/not/a/real/xchecker/dir");
+        assert!(run_throughput(&config).is_err());
+    }
+
+    #[test]
+    fn test_median_of_sorted() {
+        let ms = Duration::from_millis;
+        assert_eq!(median_of_sorted(&[ms(1), ms(2), ms(9)]), ms(2));
+        assert_eq!(median_of_sorted(&[ms(2), ms(4)]), ms(3));
+    }
+}
@@ -4,8 +4,10 @@ use std::path::PathBuf;
 use crate::error::XCheckerError;
 
 use super::{
-    Config, ConfigSource, Defaults, HooksConfig, LlmConfig, PhasesConfig, RunnerConfig,
-    SecurityConfig, Selectors,
+    ComplianceConfig, Config, ConfigSource, ContextRetentionConfig, Defaults, DoneConfig,
+    FeaturesConfig, FinalConfig, GitHooksConfig, HooksConfig, LlmConfig, OutputsConfig,
+    PacketConfig, PacketTransformsConfig, PhasesConfig, PricingConfig, RedactionConfig,
+    RunnerConfig, SecurityConfig, Selectors,
 };
 
 impl Config {
@@ -76,6 +78,8 @@ pub struct ConfigBuilder {
     execution_strategy: Option<String>,
     extra_secret_patterns: Vec<String>,
     ignore_secret_patterns: Vec<String>,
+    packet_transforms: Option<PacketTransformsConfig>,
+    packet_max_memory_bytes: Option<usize>,
 }
 
 impl Default for ConfigBuilder {
@@ -103,6 +107,8 @@ impl ConfigBuilder {
             execution_strategy: None,
             extra_secret_patterns: Vec::new(),
             ignore_secret_patterns: Vec::new(),
+            packet_transforms: None,
+            packet_max_memory_bytes: None,
         }
     }
 
@@ -328,6 +334,40 @@ impl ConfigBuilder {
         self
     }
 
+    /// Enable built-in packet transformers.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifests` - Summarize `Cargo.toml` / `package.json` into dependency overviews
+    /// * `lockfiles` - Summarize `Cargo.lock` / `package-lock.json` into package lists
+    #[must_use]
+    pub fn packet_transforms(mut self, manifests: bool, lockfiles: bool) -> Self {
+        let transforms = self.packet_transforms.get_or_insert_with(Default::default);
+        transforms.manifests = manifests;
+        transforms.lockfiles = lockfiles;
+        self
+    }
+
+    /// Summarize OpenAPI / JSON Schema documents in packets into operations,
+    /// schemas, and auth.
+    #[must_use]
+    pub fn packet_transform_openapi(mut self, enabled: bool) -> Self {
+        self.packet_transforms
+            .get_or_insert_with(Default::default)
+            .openapi = enabled;
+        self
+    }
+
+    /// Set the memory budget for file contents held during packet assembly.
+    ///
+    /// Packet building fails with a `packet_overflow` error instead of
+    /// growing past this many bytes.
+    #[must_use]
+    pub fn packet_max_memory_bytes(mut self, bytes: usize) -> Self {
+        self.packet_max_memory_bytes = Some(bytes);
+        self
+    }
+
     /// Build the `Config` from the builder values.
     ///
     /// This creates a `Config` using the values set on the builder, with
@@ -446,12 +486,32 @@ impl ConfigBuilder {
         let security = SecurityConfig {
             extra_secret_patterns: self.extra_secret_patterns,
             ignore_secret_patterns: self.ignore_secret_patterns,
+            allow_raw_context: false,
+            source_roots: Vec::new(),
         };
         if !security.extra_secret_patterns.is_empty() || !security.ignore_secret_patterns.is_empty()
         {
             source_attribution.insert("security".to_string(), ConfigSource::Programmatic);
         }
 
+        if self.packet_transforms.is_some() {
+            source_attribution.insert("packet_transforms".to_string(), ConfigSource::Programmatic);
+        }
+        if self.packet_max_memory_bytes.is_some() {
+            source_attribution.insert(
+                "packet_max_memory_bytes".to_string(),
+                ConfigSource::Programmatic,
+            );
+        }
+        let packet = PacketConfig {
+            transforms: self.packet_transforms.unwrap_or_default(),
+            max_memory_bytes: self.packet_max_memory_bytes,
+            spill_threshold_bytes: None,
+            non_utf8: None,
+            overflow_shrink_factor: None,
+            overflow_retries: None,
+        };
+
         let config = Config {
             defaults,
             selectors,
@@ -460,6 +520,16 @@ impl ConfigBuilder {
             phases,
             hooks,
             security,
+            packet,
+            final_: FinalConfig::default(),
+            outputs: OutputsConfig::default(),
+            compliance: ComplianceConfig::default(),
+            redaction: RedactionConfig::default(),
+            context_retention: ContextRetentionConfig::default(),
+            git_hooks: GitHooksConfig::default(),
+            done: DoneConfig::default(),
+            features: FeaturesConfig::default(),
+            pricing: PricingConfig::default(),
             source_attribution,
         };
 
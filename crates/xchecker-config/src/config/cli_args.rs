@@ -18,11 +18,14 @@ pub struct CliArgs {
     pub dangerously_skip_permissions: bool,
     pub ignore_secret_pattern: Vec<String>,
     pub extra_secret_pattern: Vec<String>,
+    /// Extra directories filesystem sources may read from (`--source-root`, repeatable)
+    pub source_root: Vec<String>,
     pub phase_timeout: Option<u64>,
     pub stdout_cap_bytes: Option<usize>,
     pub stderr_cap_bytes: Option<usize>,
     pub lock_ttl_seconds: Option<u64>,
     pub debug_packet: bool,
+    pub raw_context: bool,
     pub allow_links: bool,
     pub strict_validation: Option<bool>,
     pub llm_provider: Option<String>,
@@ -32,4 +35,14 @@ pub struct CliArgs {
     pub llm_fallback_provider: Option<String>,
     pub prompt_template: Option<String>,
     pub execution_strategy: Option<String>,
+    /// Failure point to inject for resilience tests (hidden `--inject-failure`)
+    pub inject_failure: Option<String>,
+    /// Reuse a completed run recorded with this key (`--idempotency-key`)
+    pub idempotency_key: Option<String>,
+    /// Write the rendered prompt to `context/<phase>-prompt.txt` (`--dump-prompt`)
+    pub dump_prompt: bool,
+    /// Write the rendered prompt and stop before calling the provider (`--prompt-only`)
+    pub prompt_only: bool,
+    /// Experimental features to switch on (`--enable-feature`, repeatable)
+    pub enable_features: Vec<String>,
 }
@@ -0,0 +1,122 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use super::sources::stable_source_label;
+use super::{Config, ConfigBuilder};
+use crate::error::XCheckerError;
+use xchecker_utils::types::ConfigSource;
+
+/// A configuration key whose effective value differs from the built-in default
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigDiffEntry {
+    /// Dotted TOML key, e.g. `defaults.model` or `packet.transforms.lockfiles`
+    pub key: String,
+    /// Built-in default value (`None` when unset by default)
+    pub default: Option<String>,
+    /// Effective value (`None` when an override unset a default)
+    pub value: Option<String>,
+    /// Where the override came from: `cli`, `env`, `config`, or `programmatic`
+    pub source: String,
+}
+
+impl Config {
+    /// Flatten every section into dotted TOML keys and rendered values
+    ///
+    /// Unset optional values are omitted. Strings render without quotes;
+    /// arrays and other values use their TOML representation.
+    #[must_use]
+    pub fn flattened(&self) -> BTreeMap<String, String> {
+        let mut flat = BTreeMap::new();
+        let sections = [
+            ("defaults", toml::Value::try_from(&self.defaults)),
+            ("selectors", toml::Value::try_from(&self.selectors)),
+            ("runner", toml::Value::try_from(&self.runner)),
+            ("llm", toml::Value::try_from(&self.llm)),
+            ("phases", toml::Value::try_from(&self.phases)),
+            ("hooks", toml::Value::try_from(&self.hooks)),
+            ("security", toml::Value::try_from(&self.security)),
+            ("packet", toml::Value::try_from(&self.packet)),
+            ("final", toml::Value::try_from(&self.final_)),
+            ("outputs", toml::Value::try_from(&self.outputs)),
+            ("compliance", toml::Value::try_from(&self.compliance)),
+            ("redaction", toml::Value::try_from(&self.redaction)),
+            (
+                "context_retention",
+                toml::Value::try_from(&self.context_retention),
+            ),
+            ("git_hooks", toml::Value::try_from(&self.git_hooks)),
+            ("done", toml::Value::try_from(&self.done)),
+            ("features", toml::Value::try_from(&self.features)),
+            ("pricing", toml::Value::try_from(&self.pricing)),
+        ];
+        for (section, value) in sections {
+            // Config sections are plain structs; serialization cannot fail
+            if let Ok(value) = value {
+                flatten_into(section, &value, &mut flat);
+            }
+        }
+        flat
+    }
+
+    /// Keys whose effective values differ from the built-in defaults
+    ///
+    /// Entries are sorted by key. Each records the default, the effective
+    /// value, and the source of the override; keys changed by a config file
+    /// section without finer attribution report `config`.
+    pub fn diff_from_defaults(&self) -> Result<Vec<ConfigDiffEntry>, XCheckerError> {
+        let defaults = ConfigBuilder::new().build()?.flattened();
+        let effective = self.flattened();
+
+        let keys: std::collections::BTreeSet<&String> =
+            defaults.keys().chain(effective.keys()).collect();
+        Ok(keys
+            .into_iter()
+            .filter(|key| defaults.get(*key) != effective.get(*key))
+            .map(|key| ConfigDiffEntry {
+                key: key.clone(),
+                default: defaults.get(key).cloned(),
+                value: effective.get(key).cloned(),
+                source: self.override_source(key),
+            })
+            .collect())
+    }
+
+    /// Best-known source for a dotted key, from the most to least specific
+    /// attribution key (`runner.mode` → `runner_mode`, `runner`;
+    /// `defaults.model` → `model`)
+    fn override_source(&self, key: &str) -> String {
+        let segments: Vec<&str> = key.split('.').collect();
+        let mut candidates = vec![segments.join("_")];
+        if segments[0] == "defaults" {
+            candidates.push(segments[1..].join("_"));
+        }
+        if segments.len() > 2 {
+            candidates.push(segments[..2].join("_"));
+        }
+        candidates.push(segments[0].to_string());
+
+        let source = candidates
+            .iter()
+            .find_map(|candidate| self.source_attribution.get(candidate))
+            .filter(|source| !matches!(source, ConfigSource::Default))
+            .unwrap_or(&ConfigSource::Config);
+        stable_source_label(source).to_string()
+    }
+}
+
+fn flatten_into(prefix: &str, value: &toml::Value, flat: &mut BTreeMap<String, String>) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, value) in table {
+                flatten_into(&format!("{prefix}.{key}"), value, flat);
+            }
+        }
+        toml::Value::String(s) => {
+            flat.insert(prefix.to_string(), s.clone());
+        }
+        other => {
+            flat.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
@@ -6,8 +6,10 @@ use std::path::{Path, PathBuf};
 use crate::error::{ConfigError, XCheckerError};
 
 use super::{
-    ClaudeConfig, CliArgs, Config, ConfigSource, Defaults, GeminiConfig, HooksConfig, LlmConfig,
-    PhasesConfig, RunnerConfig, SecurityConfig, Selectors,
+    ClaudeConfig, CliArgs, ComplianceConfig, Config, ConfigSource, ContextRetentionConfig,
+    Defaults, DoneConfig, FeaturesConfig, FinalConfig, GeminiConfig, GitHooksConfig, HooksConfig,
+    LlmConfig, OutputsConfig, PacketConfig, PhasesConfig, PricingConfig, RedactionConfig,
+    RunnerConfig, SecurityConfig, Selectors,
 };
 
 /// TOML configuration file structure
@@ -20,6 +22,17 @@ struct TomlConfig {
     phases: Option<PhasesConfig>,
     hooks: Option<HooksConfig>,
     security: Option<SecurityConfig>,
+    packet: Option<PacketConfig>,
+    #[serde(rename = "final")]
+    final_: Option<FinalConfig>,
+    outputs: Option<OutputsConfig>,
+    compliance: Option<ComplianceConfig>,
+    redaction: Option<RedactionConfig>,
+    context_retention: Option<ContextRetentionConfig>,
+    git_hooks: Option<GitHooksConfig>,
+    done: Option<DoneConfig>,
+    features: Option<FeaturesConfig>,
+    pricing: Option<PricingConfig>,
 }
 
 impl Config {
@@ -61,6 +74,16 @@ impl Config {
         let mut hooks = HooksConfig::default();
         let mut phases = PhasesConfig::default();
         let mut security = SecurityConfig::default();
+        let mut packet = PacketConfig::default();
+        let mut final_ = FinalConfig::default();
+        let mut outputs = OutputsConfig::default();
+        let mut compliance = ComplianceConfig::default();
+        let mut redaction = RedactionConfig::default();
+        let mut context_retention = ContextRetentionConfig::default();
+        let mut git_hooks = GitHooksConfig::default();
+        let mut done = DoneConfig::default();
+        let mut features = FeaturesConfig::default();
+        let mut pricing = PricingConfig::default();
 
         // Track default sources
         source_attribution.insert("max_turns".to_string(), ConfigSource::Default);
@@ -75,6 +98,8 @@ impl Config {
         source_attribution.insert("lock_ttl_seconds".to_string(), ConfigSource::Default);
         source_attribution.insert("debug_packet".to_string(), ConfigSource::Default);
         source_attribution.insert("allow_links".to_string(), ConfigSource::Default);
+        source_attribution.insert("response_min_bytes".to_string(), ConfigSource::Default);
+        source_attribution.insert("response_retries".to_string(), ConfigSource::Default);
 
         // Discover and load config file (if not explicitly provided)
         let config_path = if let Some(explicit_path) = &cli_args.config_path {
@@ -148,6 +173,35 @@ impl Config {
                     source_attribution
                         .insert("strict_validation".to_string(), config_source.clone());
                 }
+                if file_defaults.response_min_bytes.is_some() {
+                    defaults.response_min_bytes = file_defaults.response_min_bytes;
+                    source_attribution
+                        .insert("response_min_bytes".to_string(), config_source.clone());
+                }
+                if file_defaults.response_retries.is_some() {
+                    defaults.response_retries = file_defaults.response_retries;
+                    source_attribution
+                        .insert("response_retries".to_string(), config_source.clone());
+                }
+                if file_defaults.output_language.is_some() {
+                    defaults.output_language = file_defaults.output_language;
+                    source_attribution.insert("output_language".to_string(), config_source.clone());
+                }
+                if file_defaults.fsync.is_some() {
+                    defaults.fsync = file_defaults.fsync;
+                    source_attribution.insert("fsync".to_string(), config_source.clone());
+                }
+                if file_defaults.compress_threshold_bytes.is_some() {
+                    defaults.compress_threshold_bytes = file_defaults.compress_threshold_bytes;
+                    source_attribution.insert(
+                        "compress_threshold_bytes".to_string(),
+                        config_source.clone(),
+                    );
+                }
+                if file_defaults.compress_level.is_some() {
+                    defaults.compress_level = file_defaults.compress_level;
+                    source_attribution.insert("compress_level".to_string(), config_source.clone());
+                }
             }
 
             if let Some(file_selectors) = file_config.selectors {
@@ -188,12 +242,18 @@ impl Config {
                     source_attribution
                         .insert("llm_fallback_provider".to_string(), config_source.clone());
                 }
-                if let Some(file_claude) = file_llm.claude
-                    && file_claude.binary.is_some()
-                {
-                    llm.claude = Some(file_claude);
-                    source_attribution
-                        .insert("llm_claude_binary".to_string(), config_source.clone());
+                if let Some(file_claude) = file_llm.claude {
+                    if file_claude.binary.is_some() {
+                        source_attribution
+                            .insert("llm_claude_binary".to_string(), config_source.clone());
+                    }
+                    if file_claude.max_restarts.is_some() {
+                        source_attribution
+                            .insert("llm_claude_max_restarts".to_string(), config_source.clone());
+                    }
+                    if file_claude.binary.is_some() || file_claude.max_restarts.is_some() {
+                        llm.claude = Some(file_claude);
+                    }
                 }
                 if let Some(file_gemini) = file_llm.gemini {
                     llm.gemini = Some(file_gemini);
@@ -235,8 +295,90 @@ impl Config {
 
             // Load security configuration from file
             if let Some(file_security) = file_config.security {
+                // A discovered config is part of the checkout: it may narrow the
+                // source roots but not reach outside the project
+                if cli_args.config_path.is_none() {
+                    Self::check_project_source_roots(&file_security.source_roots, start_dir)?;
+                }
                 security = file_security;
-                source_attribution.insert("security".to_string(), config_source);
+                source_attribution.insert("security".to_string(), config_source.clone());
+            }
+
+            // Load packet configuration from file
+            if let Some(file_packet) = file_config.packet {
+                if file_packet.max_memory_bytes.is_some() {
+                    source_attribution
+                        .insert("packet_max_memory_bytes".to_string(), config_source.clone());
+                }
+                if file_packet.non_utf8.is_some() {
+                    source_attribution.insert("packet_non_utf8".to_string(), config_source.clone());
+                }
+                if file_packet.overflow_shrink_factor.is_some() {
+                    source_attribution.insert(
+                        "packet_overflow_shrink_factor".to_string(),
+                        config_source.clone(),
+                    );
+                }
+                if file_packet.overflow_retries.is_some() {
+                    source_attribution
+                        .insert("packet_overflow_retries".to_string(), config_source.clone());
+                }
+                packet = file_packet;
+                source_attribution.insert("packet_transforms".to_string(), config_source.clone());
+            }
+
+            // Load final phase configuration from file
+            if let Some(file_final) = file_config.final_ {
+                final_ = file_final;
+                source_attribution.insert("final".to_string(), config_source.clone());
+            }
+
+            // Load output sink configuration from file
+            if let Some(file_outputs) = file_config.outputs {
+                outputs = file_outputs;
+                source_attribution.insert("outputs".to_string(), config_source.clone());
+            }
+
+            // Load compliance scanner configuration from file
+            if let Some(file_compliance) = file_config.compliance {
+                compliance = file_compliance;
+                source_attribution.insert("compliance".to_string(), config_source.clone());
+            }
+
+            // Load path-based redaction rules from file
+            if let Some(file_redaction) = file_config.redaction {
+                redaction = file_redaction;
+                source_attribution.insert("redaction".to_string(), config_source.clone());
+            }
+
+            // Load context directory quota from file
+            if let Some(file_context_retention) = file_config.context_retention {
+                context_retention = file_context_retention;
+                source_attribution.insert("context_retention".to_string(), config_source.clone());
+            }
+
+            // Load git hook checks from file
+            if let Some(file_git_hooks) = file_config.git_hooks {
+                git_hooks = file_git_hooks;
+                source_attribution.insert("git_hooks".to_string(), config_source.clone());
+            }
+
+            // Load definition-of-done criteria from file
+            if let Some(file_done) = file_config.done {
+                done = file_done;
+                source_attribution.insert("done".to_string(), config_source.clone());
+            }
+
+            // Load experimental feature toggles from file
+            if let Some(file_features) = file_config.features {
+                features = file_features;
+                source_attribution.insert("features".to_string(), config_source.clone());
+            }
+
+            // Load the provider price table from file
+            if let Some(file_pricing) = file_config.pricing {
+                pricing = file_pricing;
+                source_attribution.insert("pricing".to_string(), config_source);
             }
         }
 
@@ -320,6 +462,27 @@ impl Config {
             source_attribution.insert("security".to_string(), ConfigSource::Cli);
         }
 
+        // Extra source roots from the CLI widen the configured ones
+        if !cli_args.source_root.is_empty() {
+            if security.source_roots.is_empty() {
+                security.source_roots.push(".".to_string());
+            }
+            security.source_roots.extend(cli_args.source_root.clone());
+            source_attribution.insert("security".to_string(), ConfigSource::Cli);
+        }
+
+        // Unredacted context files must be explicitly permitted by config
+        if cli_args.raw_context {
+            if !security.allow_raw_context {
+                return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                    key: "raw_context".to_string(),
+                    value: "--raw-context requires `allow_raw_context = true` in [security]"
+                        .to_string(),
+                }));
+            }
+            source_attribution.insert("raw_context".to_string(), ConfigSource::Cli);
+        }
+
         // Apply LLM configuration with precedence: CLI > env > config > defaults
         // Check environment variable first
         if let Ok(env_provider) = env::var("XCHECKER_LLM_PROVIDER")
@@ -370,7 +533,10 @@ impl Config {
         // Apply Claude binary configuration
         if let Some(binary) = &cli_args.llm_claude_binary {
             if llm.claude.is_none() {
-                llm.claude = Some(ClaudeConfig { binary: None });
+                llm.claude = Some(ClaudeConfig {
+                    binary: None,
+                    max_restarts: None,
+                });
             }
             if let Some(claude_config) = &mut llm.claude {
                 claude_config.binary = Some(binary.clone());
@@ -385,6 +551,7 @@ impl Config {
                     binary: None,
                     default_model: None,
                     profiles: None,
+                    max_restarts: None,
                 });
             }
             if let Some(gemini_config) = &mut llm.gemini {
@@ -402,6 +569,7 @@ impl Config {
                     binary: None,
                     default_model: None,
                     profiles: None,
+                    max_restarts: None,
                 });
             }
             if let Some(gemini_config) = &mut llm.gemini {
@@ -417,6 +585,7 @@ impl Config {
                     binary: None,
                     default_model: None,
                     profiles: None,
+                    max_restarts: None,
                 });
             }
             if let Some(gemini_config) = &mut llm.gemini {
@@ -447,6 +616,23 @@ impl Config {
             source_attribution.insert("execution_strategy".to_string(), ConfigSource::Default);
         }
 
+        // Experimental features: env and CLI can only switch more features on
+        if let Ok(env_features) = env::var("XCHECKER_FEATURES") {
+            let names: Vec<&str> = env_features
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .collect();
+            if !names.is_empty() {
+                features.enable(names);
+                source_attribution.insert("features".to_string(), ConfigSource::Env);
+            }
+        }
+        if !cli_args.enable_features.is_empty() {
+            features.enable(cli_args.enable_features.iter().cloned());
+            source_attribution.insert("features".to_string(), ConfigSource::Cli);
+        }
+
         let config = Self {
             defaults,
             selectors,
@@ -455,6 +641,16 @@ impl Config {
             phases,
             hooks,
             security,
+            packet,
+            final_,
+            outputs,
+            compliance,
+            redaction,
+            context_retention,
+            git_hooks,
+            done,
+            features,
+            pricing,
             source_attribution,
         };
 
@@ -498,6 +694,40 @@ impl Config {
         Ok(None)
     }
 
+    /// Reject `source_roots` of a discovered project config that leave `project_root`
+    ///
+    /// Roots that exist are compared after canonicalization; roots that do not
+    /// exist yet must be relative paths without `..` segments.
+    fn check_project_source_roots(
+        roots: &[String],
+        project_root: &Path,
+    ) -> Result<(), XCheckerError> {
+        let project = project_root
+            .canonicalize()
+            .unwrap_or_else(|_| project_root.to_path_buf());
+        for root in roots {
+            let path = Path::new(root);
+            let inside = match project_root.join(path).canonicalize() {
+                Ok(resolved) => resolved.starts_with(&project),
+                Err(_) => path.components().all(|component| {
+                    matches!(
+                        component,
+                        std::path::Component::Normal(_) | std::path::Component::CurDir
+                    )
+                }),
+            };
+            if !inside {
+                return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                    key: "security.source_roots".to_string(),
+                    value: format!(
+                        "'{root}' is outside the project; .xchecker/config.toml may only narrow the source roots (pass --source-root or --config to widen them)"
+                    ),
+                }));
+            }
+        }
+        Ok(())
+    }
+
     /// Load configuration from TOML file
     fn load_config_file(path: &Path) -> Result<TomlConfig, XCheckerError> {
         match std::fs::read_to_string(path) {
@@ -517,6 +747,16 @@ impl Config {
                     phases: None,
                     hooks: None,
                     security: None,
+                    packet: None,
+                    final_: None,
+                    outputs: None,
+                    compliance: None,
+                    redaction: None,
+                    context_retention: None,
+                    git_hooks: None,
+                    done: None,
+                    features: None,
+                    pricing: None,
                 })
             }
             Err(e) => Err(XCheckerError::Config(ConfigError::DiscoveryFailed {
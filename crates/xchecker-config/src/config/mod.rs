@@ -6,6 +6,7 @@
 
 mod builder;
 mod cli_args;
+mod diff;
 mod discovery;
 mod model;
 mod selectors;
@@ -14,6 +15,7 @@ mod validation;
 
 pub use builder::ConfigBuilder;
 pub use cli_args::CliArgs;
+pub use diff::ConfigDiffEntry;
 pub use model::*;
 pub use selectors::ALWAYS_EXCLUDE_PATTERNS;
 pub use xchecker_prompt_template::PromptTemplate;
@@ -21,8 +23,14 @@ pub use xchecker_selectors::*;
 pub use xchecker_utils::types::ConfigSource;
 
 use crate::error::{ConfigError, XCheckerError};
+use xchecker_utils::deprecation::{self, DeprecationKind, DeprecationNotice};
 use xchecker_utils::runner::RunnerMode;
 
+/// Settings that can only be set through deprecated names: the
+/// source-attribution key, then the deprecated config key and CLI flag
+const DEPRECATED_SETTINGS: &[(&str, &str, &str)] =
+    &[("claude_path", "runner.claude_path", "--claude-path")];
+
 impl Config {
     /// Convert runner mode string to enum
     pub fn get_runner_mode(&self) -> Result<RunnerMode, XCheckerError> {
@@ -64,18 +72,8 @@ impl Config {
     /// - `model_for_phase(Tasks)` -> "sonnet"
     #[must_use]
     pub fn model_for_phase(&self, phase: crate::types::PhaseId) -> String {
-        use crate::types::PhaseId;
-
         // First, check for phase-specific override
-        let phase_model = match phase {
-            PhaseId::Requirements => self.phases.requirements.as_ref(),
-            PhaseId::Design => self.phases.design.as_ref(),
-            PhaseId::Tasks => self.phases.tasks.as_ref(),
-            PhaseId::Review => self.phases.review.as_ref(),
-            PhaseId::Fixup => self.phases.fixup.as_ref(),
-            PhaseId::Final => self.phases.final_.as_ref(),
-        }
-        .and_then(|pc| pc.model.clone());
+        let phase_model = self.phases.get(phase).and_then(|pc| pc.model.clone());
 
         // Precedence: phase-specific > global default > "haiku"
         phase_model
@@ -98,6 +96,28 @@ impl Config {
     pub fn strict_validation(&self) -> bool {
         self.defaults.strict_validation.unwrap_or(false)
     }
+
+    /// Deprecated config keys and CLI flags this configuration was built from
+    ///
+    /// Looks at where each value came from, so a deprecated key is reported
+    /// when it was read from a config file and a deprecated flag when it was
+    /// passed on the command line. Notices come from the central registry in
+    /// [`xchecker_utils::deprecation`] and are sorted for stable output.
+    #[must_use]
+    pub fn deprecations(&self) -> Vec<DeprecationNotice> {
+        let mut notices: Vec<DeprecationNotice> = DEPRECATED_SETTINGS
+            .iter()
+            .filter_map(|(attribution, key, flag)| {
+                match self.source_attribution.get(*attribution)? {
+                    ConfigSource::Config => deprecation::notice(DeprecationKind::ConfigKey, key),
+                    ConfigSource::Cli => deprecation::notice(DeprecationKind::CliFlag, flag),
+                    _ => None,
+                }
+            })
+            .collect();
+        deprecation::normalize(&mut notices);
+        notices
+    }
 }
 
 impl xchecker_redaction::SecretConfigProvider for Config {
@@ -134,6 +154,16 @@ impl Config {
             phases: PhasesConfig::default(),
             hooks: HooksConfig::default(),
             security: SecurityConfig::default(),
+            packet: PacketConfig::default(),
+            final_: FinalConfig::default(),
+            outputs: OutputsConfig::default(),
+            compliance: ComplianceConfig::default(),
+            redaction: RedactionConfig::default(),
+            context_retention: ContextRetentionConfig::default(),
+            git_hooks: GitHooksConfig::default(),
+            done: DoneConfig::default(),
+            features: FeaturesConfig::default(),
+            pricing: PricingConfig::default(),
             source_attribution: std::collections::HashMap::new(),
         }
     }
@@ -269,11 +299,13 @@ mode = "native"
             dangerously_skip_permissions: false,
             ignore_secret_pattern: vec![],
             extra_secret_pattern: vec![],
+            source_root: vec![],
             phase_timeout: None,
             stdout_cap_bytes: None,
             stderr_cap_bytes: None,
             lock_ttl_seconds: None,
             debug_packet: false,
+            raw_context: false,
             allow_links: false,
             strict_validation: None,
             llm_provider: None,
@@ -283,6 +315,11 @@ mode = "native"
             llm_fallback_provider: None,
             prompt_template: None,
             execution_strategy: None,
+            inject_failure: None,
+            idempotency_key: None,
+            dump_prompt: false,
+            prompt_only: false,
+            enable_features: vec![],
         };
 
         let config = Config::discover_from(temp_dir.path(), &cli_args).unwrap();
@@ -962,6 +999,55 @@ model = "claude-sonnet-4-20250514"
         );
     }
 
+    #[test]
+    fn test_llm_max_restarts_per_provider() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            r#"
+[llm.claude]
+max_restarts = 3
+
+[llm.gemini]
+max_restarts = 0
+"#,
+        );
+
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+
+        let config = Config::discover(&cli_args).unwrap();
+        assert_eq!(config.llm.max_restarts("claude-cli"), 3);
+        assert_eq!(config.llm.max_restarts("gemini-cli"), 0);
+        assert_eq!(config.llm.max_restarts("openrouter"), 0);
+        assert_eq!(
+            config.source_attribution.get("llm_claude_max_restarts"),
+            Some(&ConfigSource::Config)
+        );
+
+        let too_many = create_test_config_file(
+            temp_dir.path(),
+            r#"
+[llm.claude]
+max_restarts = 6
+"#,
+        );
+        let result = Config::discover(&CliArgs {
+            config_path: Some(too_many),
+            ..Default::default()
+        });
+        match result {
+            Err(XCheckerError::Config(ConfigError::InvalidValue { key, .. })) => {
+                assert_eq!(key, "llm.claude.max_restarts");
+            }
+            other => panic!("Expected InvalidValue for llm.claude.max_restarts, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_llm_fallback_provider_rejects_invalid_provider() {
         let _guard = config_env_guard();
@@ -1825,6 +1911,49 @@ phase_timeout = 1200
         assert_eq!(config.model_for_phase(PhaseId::Review), "opus");
     }
 
+    #[test]
+    fn test_phases_config_generation_params() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            r#"
+[phases.design]
+temperature = 0.7
+max_output_tokens = 8192
+stop_sequences = ["</design>"]
+"#,
+        );
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+
+        use crate::types::PhaseId;
+        let design = config.phases.get(PhaseId::Design).unwrap();
+        assert_eq!(design.temperature, Some(0.7));
+        assert_eq!(design.max_output_tokens, Some(8192));
+        assert_eq!(design.stop_sequences, Some(vec!["</design>".to_string()]));
+        assert!(config.phases.get(PhaseId::Tasks).is_none());
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            r#"
+[phases.design]
+temperature = 3.5
+"#,
+        );
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err().to_string();
+        assert!(err.contains("phases.design.temperature"), "{err}");
+    }
+
     #[test]
     fn test_phases_config_empty_section() {
         let _guard = config_env_guard();
@@ -2279,7 +2408,7 @@ packet_max_bytes = 32768
     }
 
     #[test]
-    fn test_security_config_from_toml_file() {
+    fn test_packet_transforms_from_toml_file() {
         let _guard = config_env_guard();
         let _home = crate::paths::with_isolated_home();
         let temp_dir = TempDir::new().unwrap();
@@ -2287,9 +2416,9 @@ packet_max_bytes = 32768
         let config_path = create_test_config_file(
             temp_dir.path(),
             r#"
-[security]
-extra_secret_patterns = ["CUSTOM_[A-Z0-9]{32}", "MY_SECRET_[A-Za-z0-9]{20}"]
-ignore_secret_patterns = ["github_pat", "aws_access_key"]
+[packet.transforms]
+lockfiles = true
+openapi = true
 "#,
         );
 
@@ -2299,41 +2428,762 @@ ignore_secret_patterns = ["github_pat", "aws_access_key"]
         };
         let config = Config::discover(&cli_args).unwrap();
 
-        // Should have extra patterns from file
-        assert_eq!(config.security.extra_secret_patterns.len(), 2);
-        assert!(
-            config
-                .security
-                .extra_secret_patterns
-                .contains(&"CUSTOM_[A-Z0-9]{32}".to_string())
+        assert!(config.packet.transforms.lockfiles);
+        assert!(config.packet.transforms.openapi);
+        assert!(!config.packet.transforms.manifests);
+        assert!(matches!(
+            config.source_attribution.get("packet_transforms"),
+            Some(ConfigSource::Config)
+        ));
+    }
+
+    #[test]
+    fn test_output_language_validation() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            r#"
+[defaults]
+output_language = "de"
+"#,
         );
-        assert!(
-            config
-                .security
-                .extra_secret_patterns
-                .contains(&"MY_SECRET_[A-Za-z0-9]{20}".to_string())
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+        assert_eq!(config.defaults.output_language.as_deref(), Some("de"));
+
+        let bad_dir = TempDir::new().unwrap();
+        let bad_path = create_test_config_file(
+            bad_dir.path(),
+            r#"
+[defaults]
+output_language = "klingon"
+"#,
         );
+        let cli_args = CliArgs {
+            config_path: Some(bad_path),
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err();
+        assert!(err.to_string().contains("output_language"));
+    }
 
-        // Should have ignore patterns from file
-        assert_eq!(config.security.ignore_secret_patterns.len(), 2);
-        assert!(
-            config
-                .security
-                .ignore_secret_patterns
-                .contains(&"github_pat".to_string())
+    #[test]
+    fn test_fsync_policy_validation() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            r#"
+[defaults]
+fsync = "batch"
+"#,
         );
-        assert!(
-            config
-                .security
-                .ignore_secret_patterns
-                .contains(&"aws_access_key".to_string())
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+        assert_eq!(config.defaults.fsync.as_deref(), Some("batch"));
+
+        let bad_dir = TempDir::new().unwrap();
+        let bad_path = create_test_config_file(
+            bad_dir.path(),
+            r#"
+[defaults]
+fsync = "sometimes"
+"#,
         );
+        let cli_args = CliArgs {
+            config_path: Some(bad_path),
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err();
+        assert!(err.to_string().contains("fsync"));
+    }
 
-        // Source attribution should be config file
+    #[test]
+    fn test_compression_settings_validation() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            r#"
+[defaults]
+compress_threshold_bytes = 1048576
+compress_level = 9
+"#,
+        );
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+        assert_eq!(config.defaults.compress_threshold_bytes, Some(1_048_576));
+        assert_eq!(config.defaults.compress_level, Some(9));
+
+        let bad_dir = TempDir::new().unwrap();
+        let bad_path = create_test_config_file(
+            bad_dir.path(),
+            r#"
+[defaults]
+compress_threshold_bytes = 1048576
+compress_level = 30
+"#,
+        );
+        let cli_args = CliArgs {
+            config_path: Some(bad_path),
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err();
+        assert!(err.to_string().contains("compress_level"));
+    }
+
+    #[test]
+    fn test_packet_memory_budget_from_toml_file() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            r#"
+[packet]
+max_memory_bytes = 1048576
+spill_threshold_bytes = 65536
+"#,
+        );
+
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+
+        assert_eq!(config.packet.max_memory_bytes, Some(1_048_576));
+        assert_eq!(config.packet.spill_threshold_bytes, Some(65_536));
         assert!(matches!(
-            config.source_attribution.get("security"),
+            config.source_attribution.get("packet_max_memory_bytes"),
+            Some(ConfigSource::Config)
+        ));
+
+        let zero_dir = TempDir::new().unwrap();
+        let zero_path =
+            create_test_config_file(zero_dir.path(), "[packet]\nmax_memory_bytes = 0\n");
+        let cli_args = CliArgs {
+            config_path: Some(zero_path),
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err();
+        assert!(err.to_string().contains("max_memory_bytes"));
+    }
+
+    #[test]
+    fn test_final_config_from_toml_file() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            r#"
+[final]
+mode = "assemble"
+template = "final-template.md"
+languages = ["de", "fr"]
+"#,
+        );
+
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+
+        assert_eq!(config.final_.mode.as_deref(), Some("assemble"));
+        assert_eq!(config.final_.template.as_deref(), Some("final-template.md"));
+        assert_eq!(config.final_.languages, ["de", "fr"]);
+        assert!(matches!(
+            config.source_attribution.get("final"),
             Some(ConfigSource::Config)
         ));
+
+        let bad_dir = TempDir::new().unwrap();
+        let bad_path = create_test_config_file(bad_dir.path(), "[final]\nmode = \"merge\"\n");
+        let cli_args = CliArgs {
+            config_path: Some(bad_path),
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err();
+        assert!(err.to_string().contains("final.mode"));
+
+        let bad_path = create_test_config_file(bad_dir.path(), "[final]\nlanguages = [\"xx\"]\n");
+        let cli_args = CliArgs {
+            config_path: Some(bad_path),
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err();
+        assert!(err.to_string().contains("final.languages"));
+    }
+
+    #[test]
+    fn test_diff_from_defaults_reports_overrides_with_source() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let cli_args = CliArgs::default();
+        let config = Config::discover_from(temp_dir.path(), &cli_args).unwrap();
+        assert_eq!(config.diff_from_defaults().unwrap(), vec![]);
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            r#"
+[defaults]
+model = "sonnet"
+packet_max_bytes = 65536
+
+[runner]
+mode = "native"
+
+[packet.transforms]
+lockfiles = true
+"#,
+        );
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            max_turns: Some(3),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+        let diff = config.diff_from_defaults().unwrap();
+
+        let keys: Vec<&str> = diff.iter().map(|entry| entry.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "defaults.max_turns",
+                "defaults.model",
+                "packet.transforms.lockfiles",
+                "runner.mode",
+            ]
+        );
+        assert_eq!(
+            diff[0],
+            ConfigDiffEntry {
+                key: "defaults.max_turns".to_string(),
+                default: Some("6".to_string()),
+                value: Some("3".to_string()),
+                source: "cli".to_string(),
+            }
+        );
+        assert_eq!(diff[1].default, None);
+        assert_eq!(diff[1].value.as_deref(), Some("sonnet"));
+        assert_eq!(diff[1].source, "config");
+        assert_eq!(diff[2].source, "config");
+        assert_eq!(diff[3].value.as_deref(), Some("native"));
+    }
+
+    #[test]
+    fn test_compliance_config_from_toml_file() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            r#"
+[compliance]
+mode = "block"
+patterns = ["(?i)acme confidential"]
+ignore_patterns = ["lgpl_license"]
+"#,
+        );
+
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+
+        assert_eq!(config.compliance.mode.as_deref(), Some("block"));
+        assert_eq!(config.compliance.patterns, vec!["(?i)acme confidential"]);
+        assert_eq!(config.compliance.ignore_patterns, vec!["lgpl_license"]);
+        assert!(matches!(
+            config.source_attribution.get("compliance"),
+            Some(ConfigSource::Config)
+        ));
+
+        let bad_dir = TempDir::new().unwrap();
+        let bad_path = create_test_config_file(bad_dir.path(), "[compliance]\nmode = \"deny\"\n");
+        let cli_args = CliArgs {
+            config_path: Some(bad_path),
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err();
+        assert!(err.to_string().contains("compliance.mode"));
+    }
+
+    #[test]
+    fn test_redaction_paths_config_from_toml_file() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            r#"
+[redaction.paths]
+exclude = ["*.tfstate"]
+mask = ["service-account.json"]
+"#,
+        );
+
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+
+        assert_eq!(config.redaction.paths.exclude, vec!["*.tfstate"]);
+        assert_eq!(config.redaction.paths.mask, vec!["service-account.json"]);
+        assert!(matches!(
+            config.source_attribution.get("redaction"),
+            Some(ConfigSource::Config)
+        ));
+
+        let bad_dir = TempDir::new().unwrap();
+        let bad_path =
+            create_test_config_file(bad_dir.path(), "[redaction.paths]\nexclude = [\"[\"]\n");
+        let cli_args = CliArgs {
+            config_path: Some(bad_path),
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err();
+        assert!(err.to_string().contains("redaction.paths"));
+    }
+
+    #[test]
+    fn test_context_retention_config_from_toml_file() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            "[context_retention]\nmax_bytes = 1048576\nmax_files = 20\n",
+        );
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+
+        assert_eq!(config.context_retention.max_bytes, Some(1_048_576));
+        assert_eq!(config.context_retention.max_files, Some(20));
+        assert!(matches!(
+            config.source_attribution.get("context_retention"),
+            Some(ConfigSource::Config)
+        ));
+
+        let bad_dir = TempDir::new().unwrap();
+        let bad_path =
+            create_test_config_file(bad_dir.path(), "[context_retention]\nmax_files = 0\n");
+        let cli_args = CliArgs {
+            config_path: Some(bad_path),
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err();
+        assert!(err.to_string().contains("context_retention.max_files"));
+    }
+
+    #[test]
+    fn test_git_hooks_config_from_toml_file() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            "[git_hooks]\ninstall = [\"pre-commit\", \"pre-push\"]\nverify_receipts = false\n",
+        );
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+
+        assert_eq!(config.git_hooks.install, vec!["pre-commit", "pre-push"]);
+        assert_eq!(config.git_hooks.verify_receipts, Some(false));
+        assert_eq!(config.git_hooks.secret_scan, None);
+        assert!(!config.git_hooks.scan_all_changed);
+        assert!(matches!(
+            config.source_attribution.get("git_hooks"),
+            Some(ConfigSource::Config)
+        ));
+
+        let bad_dir = TempDir::new().unwrap();
+        let bad_path =
+            create_test_config_file(bad_dir.path(), "[git_hooks]\ninstall = [\"post-merge\"]\n");
+        let cli_args = CliArgs {
+            config_path: Some(bad_path),
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err();
+        assert!(err.to_string().contains("git_hooks.install"));
+    }
+
+    #[test]
+    fn test_done_config_from_toml_file() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            "[done]
+required_phases = [\"requirements\", \"review\"]
+max_finding_severity = \"medium\"
+",
+        );
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+
+        assert!(config.done.is_configured());
+        assert_eq!(config.done.required_phases, vec!["requirements", "review"]);
+        assert_eq!(config.done.max_finding_severity.as_deref(), Some("medium"));
+        assert!(!config.done.hooks_green);
+        assert!(!Config::minimal_for_testing().done.is_configured());
+
+        for (bad, key) in [
+            (
+                "[done]\nrequired_phases = [\"deploy\"]\n",
+                "done.required_phases",
+            ),
+            (
+                "[done]\nmax_finding_severity = \"severe\"\n",
+                "done.max_finding_severity",
+            ),
+        ] {
+            let bad_dir = TempDir::new().unwrap();
+            let cli_args = CliArgs {
+                config_path: Some(create_test_config_file(bad_dir.path(), bad)),
+                ..Default::default()
+            };
+            let err = Config::discover(&cli_args).unwrap_err();
+            assert!(err.to_string().contains(key), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_features_layer_file_env_and_cli() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            "[features]
+parallel_phases = true
+daemon = false
+",
+        );
+        let cli_args = CliArgs {
+            config_path: Some(config_path.clone()),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+        assert_eq!(config.features.enabled(), ["parallel_phases"]);
+        assert!(!config.features.is_enabled("daemon"));
+        assert!(Config::minimal_for_testing().features.enabled().is_empty());
+
+        // SAFETY: serialized by config_env_guard
+        unsafe { std::env::set_var("XCHECKER_FEATURES", "daemon, ") };
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            enable_features: vec!["response_cache".to_string()],
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+        assert_eq!(
+            config.features.enabled(),
+            ["daemon", "parallel_phases", "response_cache"]
+        );
+        assert_eq!(
+            config.source_attribution.get("features"),
+            Some(&ConfigSource::Cli)
+        );
+
+        let cli_args = CliArgs {
+            enable_features: vec!["warp_drive".to_string()],
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err();
+        assert!(err.to_string().contains("features.warp_drive"), "{err}");
+        // SAFETY: serialized by config_env_guard
+        unsafe { std::env::remove_var("XCHECKER_FEATURES") };
+    }
+
+    #[test]
+    fn test_pricing_table_from_toml_file() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            "[pricing.claude-sonnet-4-5-20250929]
+input_per_mtok = 3.0
+output_per_mtok = 15.0
+
+[pricing.haiku]
+input_per_mtok = 1.0
+output_per_mtok = 5.0
+",
+        );
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+        let price = config
+            .pricing
+            .price_for(["claude-haiku-4-5-20251001", "haiku"])
+            .unwrap();
+        assert_eq!(price.output_per_mtok, 5.0);
+        assert!((price.cost(1_000_000, 200_000) - 2.0).abs() < 1e-9);
+        assert!(config.pricing.price_for(["opus"]).is_none());
+        assert_eq!(
+            config.source_attribution.get("pricing"),
+            Some(&ConfigSource::Config)
+        );
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            "[pricing.haiku]
+input_per_mtok = -1.0
+output_per_mtok = 5.0
+",
+        );
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err();
+        assert!(
+            err.to_string().contains("pricing.haiku.input_per_mtok"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_security_config_from_toml_file() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            r#"
+[security]
+extra_secret_patterns = ["CUSTOM_[A-Z0-9]{32}", "MY_SECRET_[A-Za-z0-9]{20}"]
+ignore_secret_patterns = ["github_pat", "aws_access_key"]
+"#,
+        );
+
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+
+        // Should have extra patterns from file
+        assert_eq!(config.security.extra_secret_patterns.len(), 2);
+        assert!(
+            config
+                .security
+                .extra_secret_patterns
+                .contains(&"CUSTOM_[A-Z0-9]{32}".to_string())
+        );
+        assert!(
+            config
+                .security
+                .extra_secret_patterns
+                .contains(&"MY_SECRET_[A-Za-z0-9]{20}".to_string())
+        );
+
+        // Should have ignore patterns from file
+        assert_eq!(config.security.ignore_secret_patterns.len(), 2);
+        assert!(
+            config
+                .security
+                .ignore_secret_patterns
+                .contains(&"github_pat".to_string())
+        );
+        assert!(
+            config
+                .security
+                .ignore_secret_patterns
+                .contains(&"aws_access_key".to_string())
+        );
+
+        // Source attribution should be config file
+        assert!(matches!(
+            config.source_attribution.get("security"),
+            Some(ConfigSource::Config)
+        ));
+    }
+
+    #[test]
+    fn test_raw_context_requires_config_permission() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let denied_path = create_test_config_file(temp_dir.path(), "[security]\n");
+        let cli_args = CliArgs {
+            config_path: Some(denied_path),
+            raw_context: true,
+            ..Default::default()
+        };
+        let err = Config::discover(&cli_args).unwrap_err();
+        assert!(err.to_string().contains("raw_context"));
+
+        let allowed_dir = TempDir::new().unwrap();
+        let allowed_path = create_test_config_file(
+            allowed_dir.path(),
+            r#"
+[security]
+allow_raw_context = true
+"#,
+        );
+        let cli_args = CliArgs {
+            config_path: Some(allowed_path),
+            raw_context: true,
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+        assert!(config.security.allow_raw_context);
+        assert!(matches!(
+            config.source_attribution.get("raw_context"),
+            Some(ConfigSource::Cli)
+        ));
+    }
+
+    #[test]
+    fn test_source_roots_build_allowlist() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+        let docs = TempDir::new().unwrap();
+
+        let config_path = create_test_config_file(
+            temp_dir.path(),
+            &format!(
+                "[security]\nsource_roots = [{:?}]\n",
+                docs.path().display().to_string()
+            ),
+        );
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+        assert_eq!(
+            config.security.source_allowlist().roots(),
+            [docs.path().canonicalize().unwrap()]
+        );
+
+        // Without source_roots only the project root is trusted
+        let allowlist = SecurityConfig::default().source_allowlist();
+        assert_eq!(
+            allowlist.roots(),
+            [std::env::current_dir().unwrap().canonicalize().unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_project_config_may_only_narrow_source_roots() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let project = TempDir::new().unwrap();
+        let outside = TempDir::new().unwrap();
+        fs::create_dir(project.path().join("docs")).unwrap();
+
+        create_test_config_file(project.path(), "[security]\nsource_roots = [\"docs\"]\n");
+        let config = Config::discover_from(project.path(), &CliArgs::default()).unwrap();
+        assert_eq!(config.security.source_roots, ["docs"]);
+
+        for root in ["..", "docs/../..", outside.path().to_str().unwrap()] {
+            create_test_config_file(
+                project.path(),
+                &format!("[security]\nsource_roots = [{root:?}]\n"),
+            );
+            let err = Config::discover_from(project.path(), &CliArgs::default()).unwrap_err();
+            assert!(err.to_string().contains("may only narrow"), "{root}: {err}");
+        }
+
+        // --source-root widens, keeping the project root when none was configured
+        fs::remove_file(project.path().join(".xchecker/config.toml")).unwrap();
+        let cli_args = CliArgs {
+            source_root: vec![outside.path().display().to_string()],
+            ..Default::default()
+        };
+        let config = Config::discover_from(project.path(), &cli_args).unwrap();
+        assert_eq!(
+            config.security.source_roots,
+            [".".to_string(), outside.path().display().to_string()]
+        );
+        assert_eq!(
+            config.source_attribution.get("security"),
+            Some(&ConfigSource::Cli)
+        );
+    }
+
+    #[test]
+    fn test_deprecated_claude_path_reported_by_source() {
+        let _guard = config_env_guard();
+        let _home = crate::paths::with_isolated_home();
+        let temp_dir = TempDir::new().unwrap();
+
+        let config_path =
+            create_test_config_file(temp_dir.path(), "[runner]\nclaude_path = \"/opt/claude\"\n");
+        let cli_args = CliArgs {
+            config_path: Some(config_path.clone()),
+            ..Default::default()
+        };
+        let names: Vec<String> = Config::discover(&cli_args)
+            .unwrap()
+            .deprecations()
+            .into_iter()
+            .map(|notice| notice.name)
+            .collect();
+        assert_eq!(names, ["runner.claude_path"]);
+
+        // The same setting from the command line is reported as the flag
+        let cli_args = CliArgs {
+            config_path: Some(config_path),
+            claude_path: Some("/usr/bin/claude".to_string()),
+            ..Default::default()
+        };
+        let deprecations = Config::discover(&cli_args).unwrap().deprecations();
+        assert_eq!(deprecations.len(), 1);
+        assert_eq!(deprecations[0].name, "--claude-path");
+        assert_eq!(deprecations[0].replacement, "--llm-claude-binary");
+
+        assert!(Config::minimal_for_testing().deprecations().is_empty());
     }
 
     #[test]
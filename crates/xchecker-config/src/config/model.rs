@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use xchecker_selectors::Selectors;
+use xchecker_utils::source::SourceAllowlist;
 use xchecker_utils::types::ConfigSource;
 
 /// Default timeout for hook execution in seconds
@@ -177,6 +178,26 @@ pub struct Config {
     pub hooks: HooksConfig,
     /// Security configuration for secret detection and redaction.
     pub security: SecurityConfig,
+    /// Packet construction configuration (content transformers).
+    pub packet: PacketConfig,
+    /// Final phase configuration (LLM synthesis or deterministic assembly).
+    pub final_: FinalConfig,
+    /// Output sinks regenerated when a phase run completes.
+    pub outputs: OutputsConfig,
+    /// License/compliance scanning of packet contents before they are sent.
+    pub compliance: ComplianceConfig,
+    /// Path-based exclusion and masking of secret-bearing files.
+    pub redaction: RedactionConfig,
+    /// Size quota and rotation for per-spec `context/` files.
+    pub context_retention: ContextRetentionConfig,
+    /// Checks run by git hooks written with `xchecker hooks install`.
+    pub git_hooks: GitHooksConfig,
+    /// Criteria a spec must meet to be reported as done.
+    pub done: DoneConfig,
+    /// Experimental features switched on or off.
+    pub features: FeaturesConfig,
+    /// Provider prices used by `--estimate` and `--max-estimated-cost`.
+    pub pricing: PricingConfig,
     /// Source attribution for each setting (for status display).
     pub source_attribution: HashMap<String, ConfigSource>,
 }
@@ -212,6 +233,31 @@ pub struct Defaults {
     /// missing required sections) become hard errors that fail the phase.
     /// When disabled (default), validation issues are logged as warnings only.
     pub strict_validation: Option<bool>,
+    /// Minimum provider response size in bytes before postprocessing.
+    ///
+    /// Responses smaller than this (after trimming whitespace) are rejected
+    /// by the response validation stage and retried.
+    pub response_min_bytes: Option<usize>,
+    /// Number of re-invocations when a response fails validation.
+    ///
+    /// Covers invalid UTF-8, byte order marks, base64 dumps, unterminated
+    /// code fences, and undersized responses. Set to 0 to fail immediately.
+    pub response_retries: Option<u32>,
+    /// Language for generated artifacts as an ISO 639-1 code (e.g. "en", "de").
+    ///
+    /// Adds a language instruction to every phase prompt; responses detected
+    /// as another language fail validation and are retried.
+    pub output_language: Option<String>,
+    /// When atomic writes fsync: "always" (default), "batch", or "never".
+    ///
+    /// "batch" defers fsync to the end of each phase and "never" leaves it to
+    /// the OS; both trade crash durability for speed on network filesystems.
+    pub fsync: Option<String>,
+    /// Store artifacts and receipts larger than this many bytes zstd-compressed
+    /// as `<name>.zst`. Unset (default) disables compression.
+    pub compress_threshold_bytes: Option<u64>,
+    /// zstd level for compressed artifacts and receipts (1-22, default 3)
+    pub compress_level: Option<i32>,
 }
 
 /// LLM provider configuration
@@ -235,10 +281,32 @@ pub struct LlmConfig {
     pub prompt_template: Option<String>,
 }
 
+/// Provider restarts after a crash when `max_restarts` is unset
+pub const DEFAULT_PROVIDER_MAX_RESTARTS: u32 = 1;
+
+impl LlmConfig {
+    /// Rapid restarts allowed within a phase for the CLI `provider`
+    ///
+    /// Reads `max_restarts` from `[llm.claude]` or `[llm.gemini]`; HTTP
+    /// providers report failures as errors and are never restarted.
+    #[must_use]
+    pub fn max_restarts(&self, provider: &str) -> u32 {
+        let configured = match provider {
+            "claude-cli" => self.claude.as_ref().and_then(|c| c.max_restarts),
+            "gemini-cli" => self.gemini.as_ref().and_then(|g| g.max_restarts),
+            _ => return 0,
+        };
+        configured.unwrap_or(DEFAULT_PROVIDER_MAX_RESTARTS)
+    }
+}
+
 /// Claude CLI provider configuration
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ClaudeConfig {
     pub binary: Option<String>,
+    /// Restarts within a phase when the CLI exits abnormally without output
+    /// (default 1, 0 disables)
+    pub max_restarts: Option<u32>,
 }
 
 /// Gemini CLI provider configuration
@@ -247,6 +315,9 @@ pub struct GeminiConfig {
     pub binary: Option<String>,
     pub default_model: Option<String>,
     pub profiles: Option<HashMap<String, GeminiProfileConfig>>,
+    /// Restarts within a phase when the CLI exits abnormally without output
+    /// (default 1, 0 disables)
+    pub max_restarts: Option<u32>,
 }
 
 /// Gemini profile configuration for per-phase model selection
@@ -279,8 +350,10 @@ pub struct AnthropicConfig {
 
 /// Per-phase configuration overrides
 ///
-/// Allows configuring model, timeout, and max_turns on a per-phase basis.
-/// Values set here override global defaults for that specific phase.
+/// Allows configuring model, timeout, max_turns, and generation parameters on
+/// a per-phase basis. Values set here override global defaults for that
+/// specific phase. Generation parameters the provider does not support are
+/// ignored with a receipt warning.
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct PhaseConfig {
     /// Model to use for this phase (overrides defaults.model)
@@ -289,6 +362,15 @@ pub struct PhaseConfig {
     pub max_turns: Option<u32>,
     /// Phase timeout in seconds (overrides defaults.phase_timeout)
     pub phase_timeout: Option<u64>,
+    /// Sampling temperature (0.0-2.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// Maximum tokens the provider may generate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<u32>,
+    /// Sequences that end generation when produced
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_sequences: Option<Vec<String>>,
 }
 
 /// Phase-specific configuration section
@@ -317,6 +399,484 @@ pub struct PhasesConfig {
     pub fixup: Option<PhaseConfig>,
     #[serde(rename = "final")]
     pub final_: Option<PhaseConfig>,
+    pub localize: Option<PhaseConfig>,
+}
+
+impl PhasesConfig {
+    /// Overrides configured for `phase`, if any
+    #[must_use]
+    pub const fn get(&self, phase: xchecker_utils::types::PhaseId) -> Option<&PhaseConfig> {
+        use xchecker_utils::types::PhaseId;
+
+        match phase {
+            PhaseId::Requirements => self.requirements.as_ref(),
+            PhaseId::Design => self.design.as_ref(),
+            PhaseId::Tasks => self.tasks.as_ref(),
+            PhaseId::Review => self.review.as_ref(),
+            PhaseId::Fixup => self.fixup.as_ref(),
+            PhaseId::Final => self.final_.as_ref(),
+            PhaseId::Localize => self.localize.as_ref(),
+        }
+    }
+
+    /// Mutable overrides configured for `phase`, if any
+    pub const fn get_mut(
+        &mut self,
+        phase: xchecker_utils::types::PhaseId,
+    ) -> Option<&mut PhaseConfig> {
+        use xchecker_utils::types::PhaseId;
+
+        match phase {
+            PhaseId::Requirements => self.requirements.as_mut(),
+            PhaseId::Design => self.design.as_mut(),
+            PhaseId::Tasks => self.tasks.as_mut(),
+            PhaseId::Review => self.review.as_mut(),
+            PhaseId::Fixup => self.fixup.as_mut(),
+            PhaseId::Final => self.final_.as_mut(),
+            PhaseId::Localize => self.localize.as_mut(),
+        }
+    }
+
+    /// Configured phases with their names
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &PhaseConfig)> {
+        [
+            ("requirements", &self.requirements),
+            ("design", &self.design),
+            ("tasks", &self.tasks),
+            ("review", &self.review),
+            ("fixup", &self.fixup),
+            ("final", &self.final_),
+            ("localize", &self.localize),
+        ]
+        .into_iter()
+        .filter_map(|(name, phase)| phase.as_ref().map(|phase| (name, phase)))
+    }
+}
+
+/// Packet construction configuration
+///
+/// # Example
+///
+/// ```toml
+/// [packet]
+/// max_memory_bytes = 268435456
+/// spill_threshold_bytes = 1048576
+/// non_utf8 = "lossy"
+/// overflow_shrink_factor = 0.5
+/// overflow_retries = 2
+///
+/// [packet.transforms]
+/// manifests = true
+/// lockfiles = true
+/// openapi = true
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct PacketConfig {
+    /// Built-in content transformers applied to selected files
+    #[serde(default)]
+    pub transforms: PacketTransformsConfig,
+    /// Memory budget for file contents held during packet assembly.
+    ///
+    /// Packet building fails with a `packet_overflow` error once in-memory
+    /// file contents exceed this many bytes. Unset means no budget.
+    #[serde(default)]
+    pub max_memory_bytes: Option<usize>,
+    /// Processed files at least this large are spilled to temp files during
+    /// assembly and do not count against `max_memory_bytes`.
+    ///
+    /// Only used when `max_memory_bytes` is set. Default: 1 MiB.
+    #[serde(default)]
+    pub spill_threshold_bytes: Option<usize>,
+    /// Handling of input files that are not valid UTF-8: `"reject"` fails
+    /// packet assembly, `"lossy"` replaces invalid bytes with U+FFFD and
+    /// records a warning. Default: `"reject"`.
+    ///
+    /// BOMs and CRLF/CR line endings are always normalized before hashing.
+    #[serde(default)]
+    pub non_utf8: Option<String>,
+    /// Fraction of the packet budget kept when the provider rejects a
+    /// packet as too large for its context window. Default: 0.5.
+    #[serde(default)]
+    pub overflow_shrink_factor: Option<f64>,
+    /// Rebuild-and-retry attempts after a context overflow; 0 disables
+    /// shrinking. Default: 2.
+    #[serde(default)]
+    pub overflow_retries: Option<u32>,
+}
+
+/// Built-in packet transformers (all disabled by default)
+///
+/// Transformed files are replaced in the packet by a compact summary, which
+/// saves budget on repository-wide specs. Receipts still record the BLAKE3
+/// hash of the original file.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct PacketTransformsConfig {
+    /// Summarize `Cargo.toml` and `package.json` into dependency overviews
+    #[serde(default)]
+    pub manifests: bool,
+    /// Summarize `Cargo.lock` and `package-lock.json` into resolved package lists
+    #[serde(default)]
+    pub lockfiles: bool,
+    /// Summarize OpenAPI / JSON Schema documents into operations, schemas, and auth
+    #[serde(default)]
+    pub openapi: bool,
+}
+
+/// Final phase configuration
+///
+/// # Example
+///
+/// ```toml
+/// [final]
+/// mode = "assemble"
+/// template = "docs/final-template.md"
+/// languages = ["de", "fr"]
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct FinalConfig {
+    /// How the Final phase produces `50-final.md`.
+    ///
+    /// - `"synthesize"` (default): ask the LLM to synthesize prior artifacts
+    /// - `"assemble"`: stitch prior artifacts together from a template without an LLM call
+    #[serde(default)]
+    pub mode: Option<String>,
+    /// Template file for `assemble` mode, relative to the working directory.
+    ///
+    /// Supports `{{spec_id}}`, `{{requirements}}`, `{{design}}`, `{{tasks}}`,
+    /// and `{{review}}` placeholders. Defaults to a built-in template.
+    #[serde(default)]
+    pub template: Option<String>,
+    /// Languages the `localize` phase translates `50-final.md` into, written
+    /// as `50-final.<code>.md`. Empty (default) disables localization.
+    #[serde(default)]
+    pub languages: Vec<String>,
+}
+
+/// Output sink configuration
+///
+/// Each configured output is regenerated after every phase run, whether the
+/// run passed or failed.
+///
+/// # Example
+///
+/// ```toml
+/// [outputs]
+/// badge = "docs/badges/{spec_id}.svg"
+/// embed_config_snapshot = true
+/// front_matter = true
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct OutputsConfig {
+    /// Path of the SVG status badge, relative to the working directory.
+    ///
+    /// `{spec_id}` is replaced with the spec being run.
+    #[serde(default)]
+    pub badge: Option<String>,
+    /// Embed the redacted effective configuration in every receipt.
+    ///
+    /// Receipts always carry `config_hash`; the snapshot itself is opt-in
+    /// because it repeats the whole configuration in each receipt.
+    #[serde(default)]
+    pub embed_config_snapshot: bool,
+    /// Prepend YAML front matter (spec, phase, run id, model, receipt, body
+    /// hash) to generated Markdown artifacts.
+    #[serde(default)]
+    pub front_matter: bool,
+}
+
+/// License/compliance scanner configuration
+///
+/// The scanner flags packet files carrying restrictive license headers or
+/// proprietary markers before they are sent to an external provider. It is
+/// separate from secret detection: matches are recorded in the receipt's
+/// packet section rather than redacted.
+///
+/// # Example
+///
+/// ```toml
+/// [compliance]
+/// mode = "block"
+/// patterns = ["(?i)acme corp confidential"]
+/// ignore_patterns = ["lgpl_license"]
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct ComplianceConfig {
+    /// What to do when a packet file matches.
+    ///
+    /// - `"off"` (default): do not scan
+    /// - `"warn"`: record findings and add receipt warnings
+    /// - `"block"`: record findings and fail the phase before the LLM call
+    #[serde(default)]
+    pub mode: Option<String>,
+    /// Additional regex patterns, reported as `extra_pattern_<index>`.
+    #[serde(default)]
+    pub patterns: Vec<String>,
+    /// Built-in pattern IDs to suppress.
+    #[serde(default)]
+    pub ignore_patterns: Vec<String>,
+}
+
+/// Redaction rules beyond content patterns (`[redaction]`)
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct RedactionConfig {
+    /// Secret-bearing files recognized by path.
+    #[serde(default)]
+    pub paths: RedactionPathsConfig,
+}
+
+/// Secret-bearing files recognized by path (`[redaction.paths]`)
+///
+/// Matching files are decided before their content is read, so structured
+/// secrets that content patterns miss never reach a packet. Well-known secret
+/// files (`.env*`, `*.pem`, SSH keys, keystores) are always excluded; these
+/// globs add project-specific ones. A pattern without `/` matches the file
+/// name at any depth.
+///
+/// # Example
+///
+/// ```toml
+/// [redaction.paths]
+/// exclude = ["*.tfstate", "config/credentials/*.yml"]
+/// mask = ["service-account.json"]
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct RedactionPathsConfig {
+    /// Files left out of packets.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Files included with `[REDACTED:secret_file]` in place of their
+    /// content; wins over `exclude`.
+    #[serde(default)]
+    pub mask: Vec<String>,
+}
+
+/// Quota for the per-spec `context/` directory
+///
+/// Packet previews and debug packets accumulate in `context/`. When a limit
+/// is set, the oldest files are removed after each phase writes its own
+/// context files, and every removal is recorded as a receipt warning.
+/// Unset limits are not enforced.
+///
+/// # Example
+///
+/// ```toml
+/// [context_retention]
+/// max_bytes = 10485760
+/// max_files = 50
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct ContextRetentionConfig {
+    /// Maximum total size of `context/` in bytes.
+    #[serde(default)]
+    pub max_bytes: Option<u64>,
+    /// Maximum number of files in `context/`.
+    #[serde(default)]
+    pub max_files: Option<usize>,
+}
+
+/// Git hook checks for repositories that commit spec state
+///
+/// `xchecker hooks install` writes hooks that run `xchecker hooks run`,
+/// which reads this section from the repository's `.xchecker/config.toml`.
+/// Phase hooks are configured separately under `[hooks]`.
+///
+/// # Example
+///
+/// ```toml
+/// [git_hooks]
+/// install = ["pre-commit", "pre-push"]
+/// secret_scan = true
+/// verify_receipts = true
+/// scan_all_changed = false
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct GitHooksConfig {
+    /// Hooks written by `xchecker hooks install` when no `--hook` is given.
+    ///
+    /// Values: `"pre-commit"`, `"pre-push"`. Default: `["pre-commit"]`.
+    #[serde(default)]
+    pub install: Vec<String>,
+    /// Scan changed files for secrets. Default: `true`.
+    #[serde(default)]
+    pub secret_scan: Option<bool>,
+    /// Check that artifacts of changed specs still match the hashes in their
+    /// latest receipts. Default: `true`.
+    #[serde(default)]
+    pub verify_receipts: Option<bool>,
+    /// Secret-scan every changed file instead of only files under the spec
+    /// directories.
+    #[serde(default)]
+    pub scan_all_changed: bool,
+}
+
+/// Review finding severities accepted by `done.max_finding_severity`, most severe first
+pub const FINDING_SEVERITIES: [&str; 5] = ["critical", "high", "medium", "low", "minor"];
+
+/// Definition of done for specs
+///
+/// `xchecker status` evaluates these criteria and reports `done` with the
+/// unmet ones listed; `xchecker ci --require-done` fails when any is unmet.
+/// Criteria left unset are not checked, and without a `[done]` section no
+/// evaluation happens at all.
+///
+/// # Example
+///
+/// ```toml
+/// [done]
+/// required_phases = ["requirements", "design", "tasks", "review"]
+/// max_finding_severity = "medium"
+/// hooks_green = true
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct DoneConfig {
+    /// Phases that must have completed (Markdown and core YAML artifacts present).
+    #[serde(default)]
+    pub required_phases: Vec<String>,
+    /// Highest severity an open review finding may have.
+    ///
+    /// Values: `"critical"`, `"high"`, `"medium"`, `"low"`, `"minor"`. Findings
+    /// above it are open until checked off in the `xchecker export-findings`
+    /// checklist (`20-tasks.findings.md`). Findings without a severity never block.
+    #[serde(default)]
+    pub max_finding_severity: Option<String>,
+    /// Require the latest receipt of every phase to record no hook failures.
+    #[serde(default)]
+    pub hooks_green: bool,
+}
+
+impl DoneConfig {
+    /// Whether any criterion is set
+    #[must_use]
+    pub fn is_configured(&self) -> bool {
+        !self.required_phases.is_empty() || self.max_finding_severity.is_some() || self.hooks_green
+    }
+}
+
+/// An experimental feature that can be switched on before it ships
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExperimentalFeature {
+    /// Name used in `[features]`, `XCHECKER_FEATURES`, and `--enable-feature`
+    pub name: &'static str,
+    /// What the feature gates
+    pub description: &'static str,
+}
+
+/// Registry of every experimental feature
+///
+/// Features start dark (off) and are enabled per environment; names not in
+/// this list are rejected by config validation.
+pub const EXPERIMENTAL_FEATURES: [ExperimentalFeature; 3] = [
+    ExperimentalFeature {
+        name: "daemon",
+        description: "Long-running daemon mode that executes queued phase runs",
+    },
+    ExperimentalFeature {
+        name: "response_cache",
+        description: "Reuse provider responses for identical packets and prompts",
+    },
+    ExperimentalFeature {
+        name: "parallel_phases",
+        description: "Run independent phases of a workflow concurrently",
+    },
+];
+
+/// Experimental features switched on or off (`[features]`)
+///
+/// Sources are layered: the config file sets features to `true` or `false`,
+/// `XCHECKER_FEATURES` (comma-separated) enables more, and `--enable-feature`
+/// enables more still. Features not mentioned anywhere are off. Enabled
+/// features are recorded in every receipt's `features` flag.
+///
+/// # Example
+///
+/// ```toml
+/// [features]
+/// parallel_phases = true
+/// response_cache = false
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct FeaturesConfig {
+    /// Feature name to on/off
+    pub toggles: BTreeMap<String, bool>,
+}
+
+impl FeaturesConfig {
+    /// Whether `name` is switched on
+    #[must_use]
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.toggles.get(name).copied().unwrap_or(false)
+    }
+
+    /// Names of the features switched on, sorted
+    #[must_use]
+    pub fn enabled(&self) -> Vec<&str> {
+        self.toggles
+            .iter()
+            .filter(|(_, on)| **on)
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Switch on every feature in `names`
+    pub fn enable<I, S>(&mut self, names: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for name in names {
+            self.toggles.insert(name.into(), true);
+        }
+    }
+}
+
+/// Price of one model, per million tokens
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
+pub struct ModelPrice {
+    /// Price of one million input (prompt and packet) tokens
+    pub input_per_mtok: f64,
+    /// Price of one million output tokens
+    pub output_per_mtok: f64,
+}
+
+impl ModelPrice {
+    /// Cost of `input` and `output` tokens
+    #[must_use]
+    pub fn cost(&self, input: u64, output: u64) -> f64 {
+        (input as f64 * self.input_per_mtok + output as f64 * self.output_per_mtok) / 1_000_000.0
+    }
+}
+
+/// Provider price table (`[pricing]`)
+///
+/// Keyed by model name; a phase is priced by its resolved model name first,
+/// then by the alias it was configured with. The currency is whatever the
+/// table is written in. Models without an entry are estimated in tokens only.
+///
+/// # Example
+///
+/// ```toml
+/// [pricing.claude-sonnet-4-5-20250929]
+/// input_per_mtok = 3.0
+/// output_per_mtok = 15.0
+///
+/// [pricing.haiku]
+/// input_per_mtok = 1.0
+/// output_per_mtok = 5.0
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq)]
+#[serde(transparent)]
+pub struct PricingConfig {
+    /// Model name to price
+    pub models: BTreeMap<String, ModelPrice>,
+}
+
+impl PricingConfig {
+    /// Price of the first of `names` that has an entry
+    #[must_use]
+    pub fn price_for<'a>(&self, names: impl IntoIterator<Item = &'a str>) -> Option<&ModelPrice> {
+        names.into_iter().find_map(|name| self.models.get(name))
+    }
 }
 
 /// Runner configuration for cross-platform execution
@@ -339,6 +899,8 @@ pub struct RunnerConfig {
 /// [security]
 /// extra_secret_patterns = ["SECRET_[A-Z0-9]{32}", "API_KEY_[A-Za-z0-9]{40}"]
 /// ignore_secret_patterns = ["github_pat"]
+/// allow_raw_context = false
+/// source_roots = [".", "../shared-docs"]
 /// ```
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct SecurityConfig {
@@ -358,6 +920,34 @@ pub struct SecurityConfig {
     /// patterns if you're certain they won't match real secrets.
     #[serde(default)]
     pub ignore_secret_patterns: Vec<String>,
+
+    /// Permit `--raw-context` to write unredacted context files.
+    ///
+    /// Context files (packet previews, debug packets) are redacted by default.
+    /// The `--raw-context` flag is rejected unless this is set to `true`.
+    #[serde(default)]
+    pub allow_raw_context: bool,
+
+    /// Directories `--source fs` and `--source openapi` may read from.
+    ///
+    /// Relative entries resolve against the current directory. When empty,
+    /// only the project root (the current directory) is allowed. A discovered
+    /// project config may only narrow the roots to directories inside the
+    /// project; `--source-root` or an explicit `--config` can widen them.
+    #[serde(default)]
+    pub source_roots: Vec<String>,
+}
+
+impl SecurityConfig {
+    /// Allowlist for filesystem sources built from `source_roots`
+    #[must_use]
+    pub fn source_allowlist(&self) -> SourceAllowlist {
+        if self.source_roots.is_empty() {
+            SourceAllowlist::project_root()
+        } else {
+            SourceAllowlist::new(&self.source_roots)
+        }
+    }
 }
 
 impl Default for Defaults {
@@ -376,6 +966,12 @@ impl Default for Defaults {
             debug_packet: Some(false),
             allow_links: Some(false),
             strict_validation: None, // Default: soft validation (warnings only)
+            response_min_bytes: Some(64),
+            response_retries: Some(1),
+            output_language: None,
+            fsync: None,
+            compress_threshold_bytes: None,
+            compress_level: None,
         }
     }
 }
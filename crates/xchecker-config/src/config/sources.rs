@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use super::Config;
 use xchecker_utils::types::ConfigSource;
 
-fn stable_source_label(source: &ConfigSource) -> &'static str {
+pub(super) fn stable_source_label(source: &ConfigSource) -> &'static str {
     match source {
         ConfigSource::Cli => "cli",
         ConfigSource::Env => "env",
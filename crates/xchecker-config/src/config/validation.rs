@@ -1,6 +1,10 @@
 use crate::error::{ConfigError, XCheckerError};
 
-use super::{Config, PromptTemplate};
+use super::{Config, EXPERIMENTAL_FEATURES, FINDING_SEVERITIES, PromptTemplate};
+use xchecker_utils::atomic_write::FsyncPolicy;
+use xchecker_utils::compression;
+use xchecker_utils::input_encoding::NonUtf8Policy;
+use xchecker_validation::OutputLanguage;
 
 impl Config {
     /// Validate configuration values
@@ -37,6 +41,196 @@ impl Config {
             }
         }
 
+        // Validate packet memory budget
+        for (key, value) in [
+            ("max_memory_bytes", self.packet.max_memory_bytes),
+            ("spill_threshold_bytes", self.packet.spill_threshold_bytes),
+        ] {
+            if value == Some(0) {
+                return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                    key: format!("packet.{key}"),
+                    value: "must be greater than 0".to_string(),
+                }));
+            }
+        }
+
+        // Validate context-overflow shrink factor
+        if let Some(factor) = self.packet.overflow_shrink_factor
+            && !(factor > 0.0 && factor < 1.0)
+        {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "packet.overflow_shrink_factor".to_string(),
+                value: format!("{factor} (must be between 0.0 and 1.0, exclusive)"),
+            }));
+        }
+
+        // Validate non-UTF-8 input policy
+        if let Some(policy) = &self.packet.non_utf8
+            && let Err(value) = policy.parse::<NonUtf8Policy>()
+        {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "packet.non_utf8".to_string(),
+                value,
+            }));
+        }
+
+        // Validate final phase mode
+        if let Some(mode) = &self.final_.mode
+            && mode != "assemble"
+            && mode != "synthesize"
+        {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "final.mode".to_string(),
+                value: format!("'{mode}' (expected \"assemble\" or \"synthesize\")"),
+            }));
+        }
+
+        // Validate localization target languages
+        if let Some(language) = self
+            .final_
+            .languages
+            .iter()
+            .find(|code| OutputLanguage::from_code(code).is_none())
+        {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "final.languages".to_string(),
+                value: format!(
+                    "'{language}' is not supported (supported: {})",
+                    OutputLanguage::supported_codes()
+                ),
+            }));
+        }
+
+        // Validate compliance scanner mode
+        if let Some(mode) = &self.compliance.mode
+            && !matches!(mode.as_str(), "off" | "warn" | "block")
+        {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "compliance.mode".to_string(),
+                value: format!("'{mode}' (expected \"off\", \"warn\", or \"block\")"),
+            }));
+        }
+
+        // Validate path-based redaction globs
+        if let Err(e) = xchecker_redaction::SecretPathRules::new(
+            &self.redaction.paths.exclude,
+            &self.redaction.paths.mask,
+        ) {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "redaction.paths".to_string(),
+                value: format!("{e:#}"),
+            }));
+        }
+
+        // Validate context directory quota
+        if self.context_retention.max_bytes == Some(0) {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "context_retention.max_bytes".to_string(),
+                value: "0 (must be greater than 0)".to_string(),
+            }));
+        }
+        if self.context_retention.max_files == Some(0) {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "context_retention.max_files".to_string(),
+                value: "0 (must be greater than 0)".to_string(),
+            }));
+        }
+
+        // Validate git hooks written by `xchecker hooks install`
+        if let Some(hook) = self
+            .git_hooks
+            .install
+            .iter()
+            .find(|hook| !matches!(hook.as_str(), "pre-commit" | "pre-push"))
+        {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "git_hooks.install".to_string(),
+                value: format!("'{hook}' (expected \"pre-commit\" or \"pre-push\")"),
+            }));
+        }
+
+        // Validate definition-of-done criteria
+        if let Some(phase) = self.done.required_phases.iter().find(|phase| {
+            !crate::types::PhaseId::ORDER
+                .iter()
+                .any(|known| known.as_str() == phase.as_str())
+        }) {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "done.required_phases".to_string(),
+                value: format!("'{phase}' (not a known phase)"),
+            }));
+        }
+        if let Some(severity) = self.done.max_finding_severity.as_deref()
+            && !FINDING_SEVERITIES.contains(&severity)
+        {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "done.max_finding_severity".to_string(),
+                value: format!(
+                    "'{severity}' (expected one of {})",
+                    FINDING_SEVERITIES.join(", ")
+                ),
+            }));
+        }
+
+        // Validate experimental feature names against the registry
+        if let Some(name) = self.features.toggles.keys().find(|name| {
+            !EXPERIMENTAL_FEATURES
+                .iter()
+                .any(|feature| feature.name == name.as_str())
+        }) {
+            let known: Vec<&str> = EXPERIMENTAL_FEATURES.iter().map(|f| f.name).collect();
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: format!("features.{name}"),
+                value: format!(
+                    "'{name}' (not a known feature; expected one of {})",
+                    known.join(", ")
+                ),
+            }));
+        }
+
+        // Validate the price table: prices must be finite and non-negative
+        for (model, price) in &self.pricing.models {
+            for (field, value) in [
+                ("input_per_mtok", price.input_per_mtok),
+                ("output_per_mtok", price.output_per_mtok),
+            ] {
+                if !value.is_finite() || value < 0.0 {
+                    return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                        key: format!("pricing.{model}.{field}"),
+                        value: format!("{value} (must be a non-negative number)"),
+                    }));
+                }
+            }
+        }
+
+        // Validate per-phase generation parameters
+        for (phase, phase_config) in self.phases.iter() {
+            if let Some(temperature) = phase_config.temperature
+                && !(0.0..=2.0).contains(&temperature)
+            {
+                return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                    key: format!("phases.{phase}.temperature"),
+                    value: format!("{temperature} (must be between 0.0 and 2.0)"),
+                }));
+            }
+            if phase_config.max_output_tokens == Some(0) {
+                return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                    key: format!("phases.{phase}.max_output_tokens"),
+                    value: "must be greater than 0".to_string(),
+                }));
+            }
+            if phase_config
+                .stop_sequences
+                .as_ref()
+                .is_some_and(|stops| stops.iter().any(String::is_empty))
+            {
+                return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                    key: format!("phases.{phase}.stop_sequences"),
+                    value: "entries must not be empty".to_string(),
+                }));
+            }
+        }
+
         // Validate max_turns
         if let Some(max_turns) = self.defaults.max_turns {
             if max_turns == 0 {
@@ -117,6 +311,66 @@ impl Config {
             }
         }
 
+        // Validate response validation settings
+        if let Some(min_bytes) = self.defaults.response_min_bytes
+            && min_bytes > 1_000_000
+        {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "response_min_bytes".to_string(),
+                value: "exceeds maximum limit of 1MB".to_string(),
+            }));
+        }
+
+        if let Some(retries) = self.defaults.response_retries
+            && retries > 5
+        {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "response_retries".to_string(),
+                value: "exceeds maximum limit of 5".to_string(),
+            }));
+        }
+
+        if let Some(language) = &self.defaults.output_language
+            && OutputLanguage::from_code(language).is_none()
+        {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "output_language".to_string(),
+                value: format!(
+                    "'{language}' is not supported (supported: {})",
+                    OutputLanguage::supported_codes()
+                ),
+            }));
+        }
+
+        if let Some(policy) = &self.defaults.fsync
+            && let Err(value) = policy.parse::<FsyncPolicy>()
+        {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "fsync".to_string(),
+                value,
+            }));
+        }
+
+        if self.defaults.compress_threshold_bytes == Some(0) {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "compress_threshold_bytes".to_string(),
+                value: "must be greater than 0 (omit it to disable compression)".to_string(),
+            }));
+        }
+
+        if let Some(level) = self.defaults.compress_level
+            && !compression::LEVEL_RANGE.contains(&level)
+        {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "compress_level".to_string(),
+                value: format!(
+                    "{level} is out of range ({}-{})",
+                    compression::LEVEL_RANGE.start(),
+                    compression::LEVEL_RANGE.end()
+                ),
+            }));
+        }
+
         // Validate output format
         if let Some(format) = &self.defaults.output_format {
             match format.as_str() {
@@ -191,6 +445,27 @@ impl Config {
             self.validate_http_provider_model(fallback_provider, true)?;
         }
 
+        // Rapid restarts are for crashes at startup, not for retrying real failures
+        for (key, max_restarts) in [
+            (
+                "llm.claude.max_restarts",
+                self.llm.claude.as_ref().and_then(|c| c.max_restarts),
+            ),
+            (
+                "llm.gemini.max_restarts",
+                self.llm.gemini.as_ref().and_then(|g| g.max_restarts),
+            ),
+        ] {
+            if let Some(restarts) = max_restarts
+                && restarts > 5
+            {
+                return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                    key: key.to_string(),
+                    value: "exceeds maximum limit of 5".to_string(),
+                }));
+            }
+        }
+
         // Validate execution strategy - must be "controlled" (V11-V14 requirement)
         if let Some(strategy) = &self.llm.execution_strategy {
             if strategy != "controlled" {
@@ -7,6 +7,7 @@
 pub use xchecker_utils::types::{CheckStatus, DoctorCheck, DoctorOutput};
 
 pub mod docs_doctor;
+pub mod measure;
 pub mod release_doctor;
 pub mod tests_doctor;
 pub mod wsl;
@@ -17,14 +18,26 @@ use std::path::Path;
 
 use xchecker_config::Config;
 use xchecker_utils::cache;
+use xchecker_utils::lock;
 use xchecker_utils::logging;
 use xchecker_utils::paths;
 use xchecker_utils::runner::{CommandSpec, Runner, RunnerMode, WslOptions};
 
+/// Build flavor of the running binary, reported by the `build_flavor` check
+#[derive(Debug, Clone, Default)]
+pub struct BuildFlavor {
+    /// Network-optional subsystems compiled into the binary; empty for
+    /// builds made with the `no-telemetry` feature
+    pub network_subsystems: Vec<&'static str>,
+    /// Fail the check unless the build is telemetry-free
+    pub require_telemetry_free: bool,
+}
+
 /// Doctor command implementation
 pub struct DoctorCommand {
     config: Config,
     cache: Option<cache::InsightCache>,
+    build_flavor: Option<BuildFlavor>,
 }
 
 impl DoctorCommand {
@@ -35,7 +48,18 @@ impl DoctorCommand {
         let cache_dir = paths::cache_dir();
         let cache = cache::InsightCache::new(cache_dir).ok();
 
-        Self { config, cache }
+        Self {
+            config,
+            cache,
+            build_flavor: None,
+        }
+    }
+
+    /// Report the binary's build flavor as the `build_flavor` check
+    #[must_use]
+    pub fn with_build_flavor(mut self, build_flavor: BuildFlavor) -> Self {
+        self.build_flavor = Some(build_flavor);
+        self
     }
 
     /// Create from CLI args (wired from cli module)
@@ -47,6 +71,7 @@ impl DoctorCommand {
         Ok(Self {
             config: cfg.clone(),
             cache,
+            build_flavor: None,
         })
     }
 
@@ -81,6 +106,8 @@ impl DoctorCommand {
                 ok: false,
                 checks,
                 cache_stats: None,
+                measurements: Vec::new(),
+                deprecations: Vec::new(),
             });
         }
 
@@ -127,13 +154,17 @@ impl DoctorCommand {
         // 6. LLM provider validation
         checks.push(self.check_llm_provider());
 
+        // 7. Hung or dead phase runs
+        checks.push(self.check_phase_heartbeats());
+
+        // 8. Network-optional subsystems compiled into the binary
+        checks.extend(self.check_build_flavor());
+
         // Sort checks by name for stable output (required for JCS canonical emission)
         checks.sort_by(|a, b| a.name.cmp(&b.name));
 
         // Determine overall health
-        let has_fail = checks.iter().any(|c| c.status == CheckStatus::Fail);
-        let has_warn = checks.iter().any(|c| c.status == CheckStatus::Warn);
-        let ok = !has_fail && (!strict_exit || !has_warn);
+        let ok = overall_ok(&checks, strict_exit);
 
         // Get cache stats if cache is available (wired from InsightCache)
         let cache_stats = self.cache.as_ref().map(|c| *c.stats());
@@ -149,9 +180,33 @@ impl DoctorCommand {
             ok,
             checks,
             cache_stats,
+            measurements: Vec::new(),
+            deprecations: self.config.deprecations(),
         })
     }
 
+    /// Run all health checks plus timed round-trips to each configured provider
+    ///
+    /// Measurement spends real tokens; see [`measure`].
+    pub async fn run_with_measurement(
+        &mut self,
+        strict_exit: bool,
+        samples: usize,
+    ) -> Result<DoctorOutput> {
+        let mut output = self.run_with_options_strict(strict_exit)?;
+        // Forced stub failures short-circuit before any provider is called
+        if std::env::var_os("XCHECKER_STUB_FORCE_FAIL").is_some() {
+            return Ok(output);
+        }
+
+        let (checks, measurements) = measure::measure_providers(&self.config, samples).await;
+        output.checks.extend(checks);
+        output.checks.sort_by(|a, b| a.name.cmp(&b.name));
+        output.ok = overall_ok(&output.checks, strict_exit);
+        output.measurements = measurements;
+        Ok(output)
+    }
+
     /// Check if claude is in PATH
     fn check_claude_path(&self) -> DoctorCheck {
         if let Ok(path) = which::which("claude") {
@@ -517,60 +572,39 @@ impl DoctorCommand {
         String::from_utf8_lossy(raw).to_string()
     }
 
-    /// Check write permissions to .xchecker directory
+    /// Check write permissions to the xchecker home (`XCHECKER_HOME`, `--workspace`, or `.xchecker`)
     fn check_write_permissions(&self) -> DoctorCheck {
-        let xchecker_dir = Path::new(".xchecker");
-
-        // Try to create the directory if it doesn't exist (ignore benign races)
-        if !xchecker_dir.exists() {
-            match paths::ensure_dir_all(xchecker_dir) {
-                Ok(()) => {
-                    return DoctorCheck {
-                        name: "write_permissions".to_string(),
-                        status: CheckStatus::Pass,
-                        details: "Created .xchecker directory successfully".to_string(),
-                    };
-                }
-                Err(e) => {
-                    return DoctorCheck {
-                        name: "write_permissions".to_string(),
-                        status: CheckStatus::Fail,
-                        details: format!("Cannot create .xchecker directory: {e}"),
-                    };
-                }
-            }
-        }
+        let home = paths::xchecker_home();
 
-        // Try to write a test file
-        let test_file = xchecker_dir.join(".doctor_test");
-        match std::fs::write(&test_file, "test") {
-            Ok(()) => {
-                // Clean up test file
-                let _ = std::fs::remove_file(&test_file);
-                DoctorCheck {
-                    name: "write_permissions".to_string(),
-                    status: CheckStatus::Pass,
-                    details: ".xchecker directory is writable".to_string(),
-                }
-            }
+        match paths::probe_writable(&home) {
+            Ok(()) => DoctorCheck {
+                name: "write_permissions".to_string(),
+                status: CheckStatus::Pass,
+                details: format!("{home} is writable"),
+            },
+            Err(e) if paths::is_read_only_error(&e) => DoctorCheck {
+                name: "write_permissions".to_string(),
+                status: CheckStatus::Fail,
+                details: format!("{} ({e})", paths::read_only_home_hint(&home)),
+            },
             Err(e) => DoctorCheck {
                 name: "write_permissions".to_string(),
                 status: CheckStatus::Fail,
-                details: format!("Cannot write to .xchecker directory: {e}"),
+                details: format!("Cannot write to {home}: {e}"),
             },
         }
     }
 
     /// Check same-volume atomic rename capability
     fn check_atomic_rename(&self) -> DoctorCheck {
-        let xchecker_dir = Path::new(".xchecker");
+        let xchecker_dir = paths::xchecker_home();
 
         // Ensure directory exists (ignore benign races)
-        if let Err(e) = paths::ensure_dir_all(xchecker_dir) {
+        if let Err(e) = paths::ensure_dir_all(&xchecker_dir) {
             return DoctorCheck {
                 name: "atomic_rename".to_string(),
                 status: CheckStatus::Fail,
-                details: format!("Cannot create .xchecker directory: {e}"),
+                details: format!("Cannot create {xchecker_dir} directory: {e}"),
             };
         }
 
@@ -621,6 +655,94 @@ impl DoctorCommand {
         }
     }
 
+    /// Check heartbeats of executing phases for runs that stopped making progress
+    fn check_phase_heartbeats(&self) -> DoctorCheck {
+        let heartbeats = lock::list_heartbeats();
+        let silent: Vec<String> = heartbeats
+            .iter()
+            .filter(|hb| hb.state() == lock::HeartbeatState::Silent)
+            .map(|hb| {
+                format!(
+                    "{} ({} phase, PID {}, silent for {}s)",
+                    hb.spec_id,
+                    hb.phase,
+                    hb.pid,
+                    hb.age_secs()
+                )
+            })
+            .collect();
+        let stalled: Vec<String> = heartbeats
+            .iter()
+            .filter(|hb| hb.state() == lock::HeartbeatState::Stalled)
+            .map(|hb| {
+                format!(
+                    "{} ({} phase, PID {}, no provider output for {}s)",
+                    hb.spec_id,
+                    hb.phase,
+                    hb.pid,
+                    hb.progress_age_secs()
+                )
+            })
+            .collect();
+
+        if !silent.is_empty() {
+            return DoctorCheck {
+                name: "phase_heartbeats".to_string(),
+                status: CheckStatus::Warn,
+                details: format!(
+                    "Phase runs stopped sending heartbeats: {}. Stop the process or rerun with --force to reclaim the lock",
+                    silent.join(", ")
+                ),
+            };
+        }
+        if !stalled.is_empty() {
+            return DoctorCheck {
+                name: "phase_heartbeats".to_string(),
+                status: CheckStatus::Warn,
+                details: format!(
+                    "Phase runs stalled waiting for the provider: {}. Check the provider, or stop the process",
+                    stalled.join(", ")
+                ),
+            };
+        }
+
+        DoctorCheck {
+            name: "phase_heartbeats".to_string(),
+            status: CheckStatus::Pass,
+            details: if heartbeats.is_empty() {
+                "No phases executing".to_string()
+            } else {
+                format!("{} phase run(s) executing normally", heartbeats.len())
+            },
+        }
+    }
+
+    /// Check which network-optional subsystems the binary was built with
+    fn check_build_flavor(&self) -> Option<DoctorCheck> {
+        let flavor = self.build_flavor.as_ref()?;
+        if flavor.network_subsystems.is_empty() {
+            return Some(DoctorCheck {
+                name: "build_flavor".to_string(),
+                status: CheckStatus::Pass,
+                details: "Telemetry-free build: no network-optional subsystems compiled in"
+                    .to_string(),
+            });
+        }
+
+        Some(DoctorCheck {
+            name: "build_flavor".to_string(),
+            status: if flavor.require_telemetry_free {
+                CheckStatus::Fail
+            } else {
+                CheckStatus::Pass
+            },
+            details: format!(
+                "Network-optional subsystems compiled in: {}. Build with --features no-telemetry to remove them",
+                flavor.network_subsystems.join(", ")
+            ),
+        })
+    }
+
     /// Check LLM provider configuration and binary discoverability
     fn check_llm_provider(&self) -> DoctorCheck {
         // 1. Check provider configuration
@@ -868,6 +990,13 @@ impl DoctorCommand {
     }
 }
 
+/// Overall health: no failures, and no warnings in strict mode
+fn overall_ok(checks: &[DoctorCheck], strict_exit: bool) -> bool {
+    let has_fail = checks.iter().any(|c| c.status == CheckStatus::Fail);
+    let has_warn = checks.iter().any(|c| c.status == CheckStatus::Warn);
+    !has_fail && (!strict_exit || !has_warn)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -891,6 +1020,39 @@ mod tests {
         assert_eq!(names, sorted_names, "Checks should be sorted by name");
     }
 
+    #[test]
+    fn test_build_flavor_check_asserts_telemetry_free_build() {
+        let config = Config::discover(&CliArgs::default()).unwrap();
+        let doctor = DoctorCommand::new(config.clone());
+        assert!(doctor.check_build_flavor().is_none());
+
+        let doctor = DoctorCommand::new(config.clone()).with_build_flavor(BuildFlavor {
+            network_subsystems: vec![],
+            require_telemetry_free: true,
+        });
+        let check = doctor.check_build_flavor().unwrap();
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert!(check.details.contains("Telemetry-free"));
+
+        let mut flavor = BuildFlavor {
+            network_subsystems: vec!["self-update"],
+            require_telemetry_free: false,
+        };
+        let doctor = DoctorCommand::new(config.clone()).with_build_flavor(flavor.clone());
+        assert_eq!(
+            doctor.check_build_flavor().unwrap().status,
+            CheckStatus::Pass
+        );
+
+        flavor.require_telemetry_free = true;
+        let check = DoctorCommand::new(config)
+            .with_build_flavor(flavor)
+            .check_build_flavor()
+            .unwrap();
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.details.contains("self-update"));
+    }
+
     #[test]
     fn test_checks_sorted_lexicographically() {
         // Create unsorted checks
@@ -1146,6 +1308,8 @@ mod tests {
             ok: true,
             checks: checks1,
             cache_stats: None,
+            measurements: Vec::new(),
+            deprecations: Vec::new(),
         };
 
         let mut output2 = DoctorOutput {
@@ -1154,6 +1318,8 @@ mod tests {
             ok: true,
             checks: checks2,
             cache_stats: None,
+            measurements: Vec::new(),
+            deprecations: Vec::new(),
         };
 
         // Sort both (as run() does)
@@ -0,0 +1,291 @@
+//! Timed provider round-trips for `doctor --measure`
+//!
+//! Regular doctor checks never call a provider. Measurement mode sends a tiny
+//! prompt to each configured provider (primary and fallback) a few times and
+//! reports latency, output-token throughput, and whether the provider's token
+//! accounting looks sane. Every round-trip spends real tokens, so the mode is
+//! opt-in.
+
+use std::time::{Duration, Instant};
+
+use xchecker_config::Config;
+use xchecker_llm::{LlmBackend, LlmInvocation, Message, redact_error_message_for_logging};
+use xchecker_utils::types::{CheckStatus, DoctorCheck, MeasurementSample, ProviderMeasurement};
+
+/// Prompt sent on every round-trip
+pub const MEASUREMENT_PROMPT: &str = "Reply with the single word: pong";
+
+/// Per-round-trip timeout
+const MEASUREMENT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Width of the longest bar in [`render_latency_histogram`]
+const HISTOGRAM_WIDTH: u64 = 30;
+
+/// Providers to measure: the primary and, if configured and distinct, the fallback
+#[must_use]
+pub fn configured_providers(config: &Config) -> Vec<String> {
+    let primary = config
+        .llm
+        .provider
+        .clone()
+        .unwrap_or_else(|| "claude-cli".to_string());
+    let mut providers = vec![primary];
+    if let Some(fallback) = config.llm.fallback_provider.as_ref()
+        && !providers.contains(fallback)
+    {
+        providers.push(fallback.clone());
+    }
+    providers
+}
+
+/// Measure every configured provider, returning one check per provider and
+/// the measurements of those that could be constructed
+pub async fn measure_providers(
+    config: &Config,
+    samples: usize,
+) -> (Vec<DoctorCheck>, Vec<ProviderMeasurement>) {
+    let mut checks = Vec::new();
+    let mut measurements = Vec::new();
+
+    for (idx, provider) in configured_providers(config).iter().enumerate() {
+        // [defaults].model names a model of the primary provider; the fallback
+        // uses its own default
+        let model = if idx == 0 {
+            config.defaults.model.as_deref().unwrap_or_default()
+        } else {
+            ""
+        };
+
+        match xchecker_llm::from_config_for_provider(provider, config) {
+            Ok(backend) => {
+                let measurement = measure_backend(backend.as_ref(), provider, model, samples).await;
+                checks.push(measurement_check(&measurement));
+                measurements.push(measurement);
+            }
+            Err(e) => checks.push(DoctorCheck {
+                name: check_name(provider),
+                status: CheckStatus::Fail,
+                details: format!(
+                    "Could not construct provider: {}",
+                    redact_error_message_for_logging(&e.to_string())
+                ),
+            }),
+        }
+    }
+
+    (checks, measurements)
+}
+
+/// Time `samples` round-trips against a backend
+pub async fn measure_backend(
+    backend: &dyn LlmBackend,
+    provider: &str,
+    model: &str,
+    samples: usize,
+) -> ProviderMeasurement {
+    let mut results = Vec::with_capacity(samples);
+    let mut model_used = String::new();
+    let mut zero_counts = false;
+
+    for _ in 0..samples {
+        let invocation = LlmInvocation::new(
+            "doctor",
+            "measure",
+            model,
+            MEASUREMENT_TIMEOUT,
+            vec![Message::user(MEASUREMENT_PROMPT)],
+        );
+
+        let started = Instant::now();
+        let outcome = backend.invoke(invocation).await;
+        let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        results.push(match outcome {
+            Ok(result) => {
+                model_used = result.model_used;
+                zero_counts |= result.tokens_input == Some(0)
+                    || (result.tokens_output == Some(0) && !result.raw_response.trim().is_empty());
+                MeasurementSample {
+                    latency_ms,
+                    tokens_input: result.tokens_input,
+                    tokens_output: result.tokens_output,
+                    error: None,
+                }
+            }
+            Err(e) => MeasurementSample {
+                latency_ms,
+                tokens_input: None,
+                tokens_output: None,
+                error: Some(redact_error_message_for_logging(&e.to_string())),
+            },
+        });
+    }
+
+    let ok: Vec<&MeasurementSample> = results.iter().filter(|s| s.error.is_none()).collect();
+    let mut latencies: Vec<u64> = ok.iter().map(|s| s.latency_ms).collect();
+    latencies.sort_unstable();
+
+    let (tokens, millis) = ok
+        .iter()
+        .filter_map(|s| s.tokens_output.map(|tokens| (tokens, s.latency_ms)))
+        .fold((0u64, 0u64), |(t, m), (tokens, ms)| (t + tokens, m + ms));
+    #[allow(clippy::cast_precision_loss)] // token and millisecond counts are small
+    let output_tokens_per_sec = (millis > 0 && tokens > 0)
+        .then(|| ((tokens as f64 * 10_000.0) / millis as f64).round() / 10.0);
+
+    let token_accounting = if ok.is_empty() {
+        CheckStatus::Fail
+    } else if zero_counts
+        || ok
+            .iter()
+            .any(|s| s.tokens_input.is_none() || s.tokens_output.is_none())
+    {
+        CheckStatus::Warn
+    } else {
+        CheckStatus::Pass
+    };
+
+    ProviderMeasurement {
+        provider: provider.to_string(),
+        model: model_used,
+        latency_min_ms: latencies.first().copied(),
+        latency_p50_ms: latencies.get(latencies.len() / 2).copied(),
+        latency_max_ms: latencies.last().copied(),
+        output_tokens_per_sec,
+        token_accounting,
+        samples: results,
+    }
+}
+
+/// Summarize a measurement as a doctor check
+///
+/// Fails when no round-trip succeeded; warns on partial failures or
+/// implausible token accounting.
+#[must_use]
+pub fn measurement_check(measurement: &ProviderMeasurement) -> DoctorCheck {
+    let total = measurement.samples.len();
+    let succeeded = measurement
+        .samples
+        .iter()
+        .filter(|s| s.error.is_none())
+        .count();
+
+    let mut details = format!("{succeeded}/{total} round-trips ok");
+    if let (Some(min), Some(p50), Some(max)) = (
+        measurement.latency_min_ms,
+        measurement.latency_p50_ms,
+        measurement.latency_max_ms,
+    ) {
+        details.push_str(&format!("; latency p50 {p50}ms (min {min}ms, max {max}ms)"));
+    }
+    if let Some(rate) = measurement.output_tokens_per_sec {
+        details.push_str(&format!("; {rate} output tokens/s"));
+    }
+    details.push_str(match measurement.token_accounting {
+        CheckStatus::Pass => "; token counts reported",
+        CheckStatus::Warn => "; token counts missing or zero (budgets fall back to estimates)",
+        CheckStatus::Fail => "",
+    });
+    if succeeded == 0
+        && let Some(error) = measurement.samples.iter().find_map(|s| s.error.as_deref())
+    {
+        details.push_str(&format!("; last error: {error}"));
+    }
+
+    let status = if succeeded == 0 {
+        CheckStatus::Fail
+    } else if succeeded < total || measurement.token_accounting != CheckStatus::Pass {
+        CheckStatus::Warn
+    } else {
+        CheckStatus::Pass
+    };
+
+    DoctorCheck {
+        name: check_name(&measurement.provider),
+        status,
+        details,
+    }
+}
+
+/// Render per-sample latencies as a text histogram (shown with `--verbose`)
+#[must_use]
+pub fn render_latency_histogram(measurement: &ProviderMeasurement) -> String {
+    let model = if measurement.model.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", measurement.model)
+    };
+    let mut out = format!("{}{model}\n", measurement.provider);
+
+    let longest = measurement.latency_max_ms.unwrap_or(0).max(1);
+    for (idx, sample) in measurement.samples.iter().enumerate() {
+        let line = match &sample.error {
+            Some(error) => format!("  #{:<2} {:>7}  failed: {error}\n", idx + 1, ""),
+            None => {
+                let width = (sample.latency_ms * HISTOGRAM_WIDTH)
+                    .div_ceil(longest)
+                    .max(1);
+                let width = usize::try_from(width).unwrap_or(0);
+                format!(
+                    "  #{:<2} {:>5}ms {}\n",
+                    idx + 1,
+                    sample.latency_ms,
+                    "█".repeat(width)
+                )
+            }
+        };
+        out.push_str(&line);
+    }
+    out
+}
+
+fn check_name(provider: &str) -> String {
+    format!("provider_roundtrip_{}", provider.replace('-', "_"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xchecker_llm::{FakeLlmClient, LlmError, LlmResult};
+
+    #[tokio::test]
+    async fn test_measure_backend_summarizes_samples() {
+        let fake = FakeLlmClient::new()
+            .with_result(LlmResult::new("pong", "fake", "fake-model").with_tokens(12, 2))
+            .with_error(LlmError::Transport("connection reset".to_string()))
+            .with_result(LlmResult::new("pong", "fake", "fake-model").with_tokens(12, 2));
+
+        let measurement = measure_backend(&fake, "fake", "", 3).await;
+
+        assert_eq!(measurement.samples.len(), 3);
+        assert!(measurement.samples[1].error.is_some());
+        assert_eq!(measurement.model, "fake-model");
+        assert!(measurement.latency_p50_ms.is_some());
+        assert_eq!(measurement.token_accounting, CheckStatus::Pass);
+
+        let check = measurement_check(&measurement);
+        assert_eq!(check.name, "provider_roundtrip_fake");
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.details.starts_with("2/3 round-trips ok"));
+
+        let histogram = render_latency_histogram(&measurement);
+        assert!(histogram.starts_with("fake (fake-model)\n"));
+        assert!(histogram.contains("failed: "));
+    }
+
+    #[tokio::test]
+    async fn test_missing_token_counts_warn_and_total_failure_fails() {
+        let fake = FakeLlmClient::new().with_response("pong");
+        let measurement = measure_backend(&fake, "fake", "", 1).await;
+        assert_eq!(measurement.token_accounting, CheckStatus::Warn);
+        assert_eq!(measurement.output_tokens_per_sec, None);
+
+        let fake = FakeLlmClient::new().with_error(LlmError::Timeout {
+            duration: Duration::from_secs(60),
+        });
+        let measurement = measure_backend(&fake, "fake", "", 1).await;
+        let check = measurement_check(&measurement);
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.details.contains("last error"));
+    }
+}
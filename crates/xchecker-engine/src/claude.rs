@@ -188,24 +188,8 @@ impl ClaudeWrapper {
     /// needed for reproducibility. These are passed through unchanged to Claude CLI.
     /// Note: Claude 3.x models are legacy and should not be used.
     fn resolve_model_alias(alias: &str, _runner: &Runner) -> Result<String, ClaudeError> {
-        // Resolve common aliases to simple model names
-        // Claude CLI handles the actual model resolution to current versions
-        let resolved = match alias {
-            // Sonnet - recommended for production (best balance of intelligence and speed)
-            "sonnet" | "sonnet-latest" => "sonnet",
-
-            // Haiku - recommended for testing/development (fast and cost-effective)
-            "haiku" | "haiku-latest" => "haiku",
-
-            // Opus - for complex tasks requiring maximum capability
-            "opus" | "opus-latest" => "opus",
-
-            // Pass through any other name - let Claude CLI handle validation
-            // This allows specific versions like "claude-sonnet-4-5-20250929"
-            other => other,
-        };
-
-        Ok(resolved.to_string())
+        // Families map to themselves; unknown names pass through (see llm::models)
+        Ok(crate::llm::models::resolve_model("claude-cli", alias).name)
     }
 
     /// Validate that a model name is available by querying Claude CLI
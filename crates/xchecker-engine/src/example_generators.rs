@@ -10,15 +10,21 @@
 //! - Sorted arrays (by path for outputs/artifacts, by name for checks)
 //! - Pinned tool versions for byte-identical assertions
 
+use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 use serde_json::Value as JsonValue;
 use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
 use crate::doctor::{CheckStatus, DoctorCheck, DoctorOutput};
 use crate::types::{
     ArtifactInfo, ConfigSource, ConfigValue, DriftPair, FileEvidence, FileHash, LlmInfo, LockDrift,
     PacketEvidence, PipelineInfo, Priority, Receipt, StatusOutput,
 };
+use xchecker_utils::canonicalization::emit_jcs;
+use xchecker_utils::deprecation::{self, DeprecationKind};
+use xchecker_utils::runner::ProcessUsage;
 
 /// Fixed timestamp for deterministic examples
 /// Only available in test builds to avoid accidental use in production
@@ -51,6 +57,7 @@ pub fn make_example_receipt_minimal() -> Receipt {
             files: vec![],
             max_bytes: 100000,
             max_lines: 5000,
+            ..Default::default()
         },
         outputs: vec![],
         exit_code: 0,
@@ -63,6 +70,9 @@ pub fn make_example_receipt_minimal() -> Receipt {
         diff_context: None,
         llm: None,
         pipeline: None,
+        suggested_actions: vec![],
+        config_hash: None,
+        config_snapshot: None,
     }
 }
 
@@ -79,11 +89,13 @@ pub fn make_example_receipt_full() -> Receipt {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized:
                 "fedcba9876543210fedcba9876543210fedcba9876543210fedcba9876543210".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized:
                 "abc1234567890abcabc1234567890abcabc1234567890abcabc1234567890abc".to_string(),
+            blake3_front_matter: None,
         },
     ];
     // Sort by path for deterministic output
@@ -126,6 +138,7 @@ pub fn make_example_receipt_full() -> Receipt {
             files: packet_files,
             max_bytes: 100000,
             max_lines: 5000,
+            ..Default::default()
         },
         outputs,
         exit_code: 0,
@@ -146,10 +159,21 @@ pub fn make_example_receipt_full() -> Receipt {
             timed_out: Some(false),
             timeout_seconds: Some(600),
             budget_exhausted: None,
+            requests: None,
+            provider_usage: None,
+            process_usage: Some(ProcessUsage {
+                wall_time_ms: 41_250,
+                user_cpu_ms: Some(3_120),
+                system_cpu_ms: Some(480),
+                peak_rss_bytes: Some(187_695_104),
+            }),
         }),
         pipeline: Some(PipelineInfo {
             execution_strategy: Some("controlled".to_string()),
         }),
+        suggested_actions: vec![],
+        config_hash: None,
+        config_snapshot: None,
     }
 }
 
@@ -169,6 +193,7 @@ pub fn make_example_status_minimal() -> StatusOutput {
         effective_config: BTreeMap::new(),
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     }
 }
 
@@ -238,6 +263,7 @@ pub fn make_example_status_full() -> StatusOutput {
             est_added: 42,
             est_removed: 15,
         }),
+        metadata: None,
     }
 }
 
@@ -265,6 +291,8 @@ pub fn make_example_doctor_minimal() -> DoctorOutput {
         ok: true,
         checks,
         cache_stats: None,
+        measurements: Vec::new(),
+        deprecations: Vec::new(),
     }
 }
 
@@ -322,9 +350,73 @@ pub fn make_example_doctor_full() -> DoctorOutput {
         ok: true,
         checks,
         cache_stats: None,
+        measurements: Vec::new(),
+        deprecations: vec![
+            deprecation::notice(DeprecationKind::ConfigKey, "runner.claude_path")
+                .expect("runner.claude_path is registered as deprecated"),
+        ],
     }
 }
 
+/// One example file published under `docs/schemas/`
+pub struct SchemaExample {
+    /// File name, e.g. `receipt.v1.minimal.json`
+    pub file_name: &'static str,
+    /// Renders the example as JCS canonical JSON
+    pub render: fn() -> Result<String>,
+}
+
+fn render<T: Serialize>(make: fn() -> T) -> Result<String> {
+    emit_jcs(&make())
+}
+
+/// Every published schema example, minimal and full for each schema
+pub static SCHEMA_EXAMPLES: &[SchemaExample] = &[
+    SchemaExample {
+        file_name: "receipt.v1.minimal.json",
+        render: || render(make_example_receipt_minimal),
+    },
+    SchemaExample {
+        file_name: "receipt.v1.full.json",
+        render: || render(make_example_receipt_full),
+    },
+    SchemaExample {
+        file_name: "status.v1.minimal.json",
+        render: || render(make_example_status_minimal),
+    },
+    SchemaExample {
+        file_name: "status.v1.full.json",
+        render: || render(make_example_status_full),
+    },
+    SchemaExample {
+        file_name: "doctor.v1.minimal.json",
+        render: || render(make_example_doctor_minimal),
+    },
+    SchemaExample {
+        file_name: "doctor.v1.full.json",
+        render: || render(make_example_doctor_full),
+    },
+];
+
+/// Write every entry of [`SCHEMA_EXAMPLES`] into `dir`, creating it if needed
+///
+/// Existing files are overwritten. Returns the written paths in table order.
+pub fn emit_examples(dir: &Path) -> Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+    SCHEMA_EXAMPLES
+        .iter()
+        .map(|example| {
+            let path = dir.join(example.file_name);
+            let json = (example.render)()
+                .with_context(|| format!("Failed to render {}", example.file_name))?;
+            std::fs::write(&path, json)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            Ok(path)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -517,6 +609,17 @@ mod tests {
         assert_eq!(doctor.emitted_at, fixed_now());
     }
 
+    #[test]
+    fn test_emit_examples_writes_every_schema_example() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path().join("schemas");
+        let written = emit_examples(&dir).unwrap();
+        assert_eq!(written.len(), SCHEMA_EXAMPLES.len());
+
+        let receipt = std::fs::read_to_string(dir.join("receipt.v1.full.json")).unwrap();
+        assert_eq!(receipt, emit_jcs(&make_example_receipt_full()).unwrap());
+    }
+
     #[test]
     fn test_pinned_tool_versions() {
         let receipt = make_example_receipt_minimal();
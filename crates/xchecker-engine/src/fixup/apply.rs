@@ -7,6 +7,9 @@ use crate::atomic_write::write_file_atomic;
 use crate::error::FixupError;
 use crate::runner::CommandSpec;
 
+use xchecker_utils::console::Stream;
+use xchecker_utils::mddiff::{DEFAULT_CONTEXT_LINES, DiffEngine, MarkdownDiff, render_diff};
+
 use super::model::{AppliedFile, ChangeSummary, FixupMode, FixupPreview, FixupResult, UnifiedDiff};
 use super::parse::FixupParser;
 
@@ -391,6 +394,49 @@ impl FixupParser {
         Ok(lines.join("\n") + "\n")
     }
 
+    /// Render the change a diff would make to its target for human review
+    ///
+    /// The diff is applied in memory (the target is not modified) and the
+    /// original and patched contents are re-diffed with `engine`, aligned
+    /// section by section for Markdown targets, optionally highlighting
+    /// changed words (see [`render_diff`]).
+    ///
+    /// # Security
+    ///
+    /// The target path is validated through `SandboxRoot::join()` before it is read.
+    pub fn render_preview(
+        &self,
+        diff: &UnifiedDiff,
+        engine: &dyn DiffEngine,
+        word_level: bool,
+        stream: Stream,
+    ) -> Result<String, FixupError> {
+        let sandbox_path = self.validate_target_path(&diff.target_file)?;
+        let original = std::fs::read_to_string(sandbox_path.as_path()).map_err(|_| {
+            FixupError::TargetFileNotFound {
+                path: diff.target_file.clone(),
+            }
+        })?;
+
+        let original = normalize_line_endings_for_diff(&original);
+        let patched = self.apply_diff_to_content(&original, diff)?;
+        let markdown = MarkdownDiff::new(engine);
+        let engine: &dyn DiffEngine = if diff.target_file.ends_with(".md") {
+            &markdown
+        } else {
+            engine
+        };
+        Ok(render_diff(
+            engine,
+            &original,
+            &patched,
+            &diff.target_file,
+            DEFAULT_CONTEXT_LINES,
+            word_level,
+            stream,
+        ))
+    }
+
     /// Compute BLAKE3 hash of content
     fn compute_blake3_hash(&self, content: &str) -> String {
         let hash = blake3::hash(content.as_bytes());
@@ -679,4 +725,39 @@ FIXUP PLAN:
         assert_eq!(added, 2); // +let x = 1; and +let z = 4;
         assert_eq!(removed, 1); // -let z = 3;
     }
+
+    #[test]
+    fn test_render_preview_leaves_target_untouched() {
+        use xchecker_utils::mddiff::PatienceDiff;
+
+        let temp_dir = TempDir::new().unwrap();
+        let original = "# Design\n\nThe service SHALL retry three times.\n";
+        std::fs::write(temp_dir.path().join("design.md"), original).unwrap();
+        let parser = FixupParser::new(FixupMode::Preview, temp_dir.path().to_path_buf()).unwrap();
+
+        let content = r#"
+FIXUP PLAN:
+
+```diff
+--- a/design.md
++++ b/design.md
+@@ -1,3 +1,3 @@
+ # Design
+ 
+-The service SHALL retry three times.
++The service SHALL retry five times.
+```
+"#;
+        let diffs = parser.parse_diffs(content).unwrap();
+        let rendered = parser
+            .render_preview(&diffs[0], &PatienceDiff, true, Stream::Stdout)
+            .unwrap();
+
+        assert!(rendered.starts_with("--- a/design.md\n+++ b/design.md\n"));
+        assert!(rendered.contains("~The service SHALL retry [-three-]{+five+} times.\n"));
+        assert_eq!(
+            std::fs::read_to_string(temp_dir.path().join("design.md")).unwrap(),
+            original
+        );
+    }
 }
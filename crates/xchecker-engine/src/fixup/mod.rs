@@ -2,6 +2,7 @@
 //!
 //! This module implements the fixup system that detects "FIXUP PLAN:" markers in review output,
 //! parses unified diff blocks, and provides preview/apply modes for safe fixup application.
+//! Previews can be re-rendered with a selectable diff engine (see [`DiffAlgorithm`]).
 //!
 //! # Security
 //!
@@ -32,6 +33,10 @@ pub use parse::FixupParser;
 pub use paths::validate_fixup_target;
 pub use phase::FixupPhase;
 pub use report::{pending_fixups_for_spec, pending_fixups_result_from_handle};
+pub use xchecker_utils::mddiff::{
+    DEFAULT_CONTEXT_LINES, DiffAlgorithm, DiffEngine, DiffOp, HistogramDiff, MarkdownDiff,
+    MyersDiff, PatienceDiff, WordToken, render_diff, tokenize_words, unified_diff, word_diff,
+};
 
 #[cfg(test)]
 mod tests {
@@ -6,6 +6,7 @@
 
 use crate::phase::{NextStep, Phase, PhaseContext, PhaseMetadata, PhaseResult};
 use crate::status::artifact::{Artifact, ArtifactType};
+use crate::types::PhaseId;
 use anyhow::Result;
 
 use super::FixupMode;
@@ -67,6 +68,7 @@ impl Phase for FixupPhase {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         };
 
         let budget_used = crate::packet::BudgetUsage::new(65536, 1200);
@@ -93,7 +95,7 @@ impl Phase for FixupPhase {
                 );
 
                 let fixup_artifact = Artifact {
-                    name: "40-fixup.md".to_string(),
+                    name: PhaseId::Fixup.artifact_name("md"),
                     content: fixup_content.clone(),
                     artifact_type: ArtifactType::Markdown,
                     blake3_hash: blake3::hash(fixup_content.as_bytes()).to_hex().to_string(),
@@ -2,6 +2,7 @@ use super::model::FixupMode;
 use super::parse::FixupParser;
 use crate::gate::{PendingFixupsResult, PendingFixupsStats};
 use crate::orchestrator::OrchestratorHandle;
+use crate::types::PhaseId;
 use std::path::PathBuf;
 
 /// Get pending fixups result for a spec by ID
@@ -50,7 +51,9 @@ pub fn pending_fixups_for_spec(spec_id: &str) -> PendingFixupsStats {
 
 /// Internal implementation for counting pending fixups with result type
 fn pending_fixups_result_impl(base_path: &std::path::Path) -> PendingFixupsResult {
-    let review_md_path = base_path.join("artifacts").join("30-review.md");
+    let review_md_path = base_path
+        .join("artifacts")
+        .join(PhaseId::Review.artifact_name("md"));
 
     if !review_md_path.exists() {
         return PendingFixupsResult::None; // No review phase completed yet
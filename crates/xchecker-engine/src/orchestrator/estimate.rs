@@ -0,0 +1,221 @@
+//! Cost estimation before a run (`--estimate`, `--max-estimated-cost`)
+//!
+//! Each planned phase's prompt and packet are built exactly as a run would
+//! build them and rendered with the configured prompt template; input tokens
+//! are estimated from that text at [`CHARS_PER_TOKEN`]. Output tokens come
+//! from the phase's last successful run, else its `max_output_tokens`, else
+//! [`DEFAULT_OUTPUT_TOKENS`]. Artifacts a planned phase has not produced yet
+//! are counted as input to the phases after it at their estimated output
+//! size. Costs are priced with `[pricing]`; nothing is sent to a provider.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::llm::models::resolve_model;
+use crate::types::PhaseId;
+
+use super::localize::{localize_config, localize_languages};
+use super::{OrchestratorConfig, PhaseOrchestrator};
+
+/// Characters per token assumed when estimating token counts
+pub const CHARS_PER_TOKEN: u64 = 4;
+
+/// Output tokens assumed for a phase with no previous run or output limit
+pub const DEFAULT_OUTPUT_TOKENS: u64 = 4_096;
+
+/// Where a phase's output token estimate came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputEstimateSource {
+    /// Reported by the provider for the phase's last successful run
+    LastRun,
+    /// The phase's `max_output_tokens` limit
+    MaxOutputTokens,
+    /// [`DEFAULT_OUTPUT_TOKENS`]
+    Default,
+    /// The phase is produced locally and makes no provider call
+    Local,
+}
+
+/// Estimated tokens and cost of one phase run
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseEstimate {
+    pub phase: PhaseId,
+    /// Resolved model name, empty when the provider picks its own default
+    pub model: String,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub output_source: OutputEstimateSource,
+    /// `None` when `[pricing]` has no entry for the model
+    pub cost: Option<f64>,
+}
+
+/// Estimated tokens and cost of a planned run of one spec
+#[derive(Debug, Clone, Serialize)]
+pub struct RunEstimate {
+    pub spec_id: String,
+    pub phases: Vec<PhaseEstimate>,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    /// Sum of the priced phases
+    pub cost: f64,
+    /// Models of phases that could not be priced, sorted and deduplicated
+    pub unpriced_models: Vec<String>,
+}
+
+impl RunEstimate {
+    fn new(spec_id: &str, phases: Vec<PhaseEstimate>) -> Self {
+        let mut unpriced_models: Vec<String> = phases
+            .iter()
+            .filter(|p| p.cost.is_none() && p.output_source != OutputEstimateSource::Local)
+            .map(|p| p.model.clone())
+            .collect();
+        unpriced_models.sort();
+        unpriced_models.dedup();
+        Self {
+            spec_id: spec_id.to_string(),
+            input_tokens: phases.iter().map(|p| p.input_tokens).sum(),
+            output_tokens: phases.iter().map(|p| p.output_tokens).sum(),
+            cost: phases.iter().filter_map(|p| p.cost).sum(),
+            phases,
+            unpriced_models,
+        }
+    }
+}
+
+/// Estimated token count of `text`
+#[must_use]
+pub fn estimate_tokens(text: &str) -> u64 {
+    (text.chars().count() as u64).div_ceil(CHARS_PER_TOKEN)
+}
+
+impl PhaseOrchestrator {
+    /// Estimate running `phases` in order without calling the provider
+    pub(crate) fn estimate_phases(
+        &self,
+        phases: &[PhaseId],
+        config: &OrchestratorConfig,
+    ) -> Result<RunEstimate> {
+        let cfg = self.config_from_orchestrator_config(config);
+        let provider = cfg.llm.provider.as_deref().unwrap_or("claude-cli");
+
+        let mut estimates = Vec::with_capacity(phases.len());
+        // Output of planned phases that have no artifact yet, read by later phases
+        let mut pending_upstream = 0;
+        for &phase_id in phases {
+            // Localize runs once per `[final] languages` entry
+            let runs = if phase_id == PhaseId::Localize {
+                localize_languages(config)
+                    .into_iter()
+                    .map(|language| localize_config(config, language))
+                    .collect()
+            } else {
+                vec![config.clone()]
+            };
+            for config in &runs {
+                let phase = self.get_phase_impl(phase_id, config)?;
+                let ctx = self.create_phase_context(phase_id, config)?;
+                let last_run = self
+                    .receipt_manager()
+                    .read_latest_successful_receipt(phase_id)?;
+
+                if phase.local_response(&ctx).is_some() {
+                    estimates.push(PhaseEstimate {
+                        phase: phase_id,
+                        model: "local".to_string(),
+                        input_tokens: 0,
+                        output_tokens: 0,
+                        output_source: OutputEstimateSource::Local,
+                        cost: Some(0.0),
+                    });
+                    continue;
+                }
+
+                let prompt = phase.prompt(&ctx);
+                let packet = phase
+                    .make_packet(&ctx)
+                    .with_context(|| format!("Failed to build {} packet", phase_id.as_str()))?;
+                let rendered = self.render_prompt(&prompt, &packet.content, config);
+                let input_tokens = estimate_tokens(&rendered) + pending_upstream;
+
+                let last_output = last_run
+                    .as_ref()
+                    .and_then(|r| r.llm.as_ref())
+                    .and_then(|llm| llm.tokens_output);
+                let max_output = cfg.phases.get(phase_id).and_then(|pc| pc.max_output_tokens);
+                let (output_tokens, output_source) = match (last_output, max_output) {
+                    (Some(tokens), _) => (tokens, OutputEstimateSource::LastRun),
+                    (None, Some(tokens)) => {
+                        (u64::from(tokens), OutputEstimateSource::MaxOutputTokens)
+                    }
+                    (None, None) => (DEFAULT_OUTPUT_TOKENS, OutputEstimateSource::Default),
+                };
+                if last_run.is_none() {
+                    pending_upstream += output_tokens;
+                }
+
+                let configured = Self::configured_model(&cfg, phase_id);
+                let resolved = resolve_model(provider, &configured);
+                let names = [
+                    Some(resolved.name.as_str()),
+                    resolved.alias.as_deref(),
+                    Some(configured.as_str()),
+                ];
+                let cost = cfg
+                    .pricing
+                    .price_for(names.into_iter().flatten())
+                    .map(|price| price.cost(input_tokens, output_tokens));
+
+                estimates.push(PhaseEstimate {
+                    phase: phase_id,
+                    model: resolved.name,
+                    input_tokens,
+                    output_tokens,
+                    output_source,
+                    cost,
+                });
+            }
+        }
+
+        Ok(RunEstimate::new(self.spec_id(), estimates))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_tokens_rounds_up() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("abcd"), 1);
+        assert_eq!(estimate_tokens("abcde"), 2);
+        // Characters, not bytes
+        assert_eq!(estimate_tokens("ééééé"), 2);
+    }
+
+    #[test]
+    fn test_run_estimate_totals_and_unpriced_models() {
+        let phase = |phase, model: &str, cost| PhaseEstimate {
+            phase,
+            model: model.to_string(),
+            input_tokens: 1_000,
+            output_tokens: 100,
+            output_source: OutputEstimateSource::Default,
+            cost,
+        };
+        let estimate = RunEstimate::new(
+            "demo",
+            vec![
+                phase(PhaseId::Requirements, "haiku", Some(0.5)),
+                phase(PhaseId::Design, "opus", None),
+                phase(PhaseId::Tasks, "opus", None),
+                phase(PhaseId::Review, "haiku", Some(0.25)),
+            ],
+        );
+        assert_eq!(estimate.input_tokens, 4_000);
+        assert_eq!(estimate.output_tokens, 400);
+        assert!((estimate.cost - 0.75).abs() < 1e-9);
+        assert_eq!(estimate.unpriced_models, ["opus"]);
+    }
+}
@@ -0,0 +1,105 @@
+//! Failure injection for resilience tests (`--inject-failure`)
+//!
+//! Integration tests and chaos drills use these points to exercise recovery
+//! paths without a misbehaving provider: partial-artifact salvage, lock
+//! release when a phase aborts, and `resume` after artifacts were promoted
+//! but no receipt was written. The point is carried in the orchestrator
+//! config map under `inject_failure`; nothing is injected when it is unset.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{PhaseError, XCheckerError};
+use crate::exit_codes;
+
+use super::OrchestratorConfig;
+
+/// Where in phase execution to fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailurePoint {
+    /// Abort after the packet and its context files are written, before the LLM call
+    AfterPacketBuild,
+    /// Cut the LLM response in half and report a provider failure
+    MidStream,
+    /// Abort instead of writing the phase receipt
+    BeforeReceiptWrite,
+}
+
+impl FailurePoint {
+    /// All points, in execution order
+    pub const ALL: [Self; 3] = [
+        Self::AfterPacketBuild,
+        Self::MidStream,
+        Self::BeforeReceiptWrite,
+    ];
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::AfterPacketBuild => "after-packet-build",
+            Self::MidStream => "mid-stream",
+            Self::BeforeReceiptWrite => "before-receipt-write",
+        }
+    }
+
+    /// The point requested in `config`, if any
+    #[must_use]
+    pub fn from_config(config: &OrchestratorConfig) -> Option<Self> {
+        config
+            .config
+            .get("inject_failure")
+            .and_then(|value| value.parse().ok())
+    }
+
+    /// Whether `config` requests this point
+    #[must_use]
+    pub(crate) fn is_set(self, config: &OrchestratorConfig) -> bool {
+        Self::from_config(config) == Some(self)
+    }
+
+    /// Error returned when an aborting point is reached
+    pub(crate) fn error(self, phase: &str) -> anyhow::Error {
+        tracing::warn!(
+            target: "xchecker::failpoint",
+            point = %self,
+            phase = %phase,
+            "Injected failure"
+        );
+        XCheckerError::Phase(PhaseError::InjectedFailure {
+            phase: phase.to_string(),
+            point: self.as_str().to_string(),
+        })
+        .into()
+    }
+
+    /// Truncate `response` as if the stream broke halfway, with a provider failure exit code
+    pub(crate) fn truncate_stream(response: String) -> (String, i32) {
+        let mut cut = response.len() / 2;
+        while !response.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        let mut truncated = response;
+        truncated.truncate(cut);
+        (truncated, exit_codes::codes::CLAUDE_FAILURE)
+    }
+}
+
+impl fmt::Display for FailurePoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for FailurePoint {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|point| point.as_str() == value)
+            .ok_or_else(|| {
+                let expected: Vec<&str> = Self::ALL.iter().map(|p| p.as_str()).collect();
+                format!("'{value}' (expected one of: {})", expected.join(", "))
+            })
+    }
+}
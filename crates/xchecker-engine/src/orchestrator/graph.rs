@@ -0,0 +1,67 @@
+//! Phase dependency graph queries.
+//!
+//! Dependencies come from each phase's `Phase::deps()`, so transition rules
+//! (which phases must precede another, which phases are optional) follow the
+//! phase implementations instead of being restated here.
+
+use crate::fixup::{FixupMode, FixupPhase};
+use crate::phase::Phase;
+use crate::phases::{
+    DesignPhase, FinalPhase, LocalizePhase, RequirementsPhase, ReviewPhase, TasksPhase,
+};
+use crate::types::PhaseId;
+use crate::validation::OutputLanguage;
+
+/// Phases whose artifacts `phase_id` is generated from
+#[must_use]
+pub(crate) fn phase_deps(phase_id: PhaseId) -> &'static [PhaseId] {
+    match phase_id {
+        PhaseId::Requirements => RequirementsPhase::new().deps(),
+        PhaseId::Design => DesignPhase::new().deps(),
+        PhaseId::Tasks => TasksPhase::new().deps(),
+        PhaseId::Review => ReviewPhase::new().deps(),
+        PhaseId::Fixup => FixupPhase::new_with_mode(FixupMode::Preview).deps(),
+        PhaseId::Final => FinalPhase::new().deps(),
+        // Dependencies do not vary with the target language
+        PhaseId::Localize => LocalizePhase::new(OutputLanguage::English).deps(),
+    }
+}
+
+/// Whether `phase_id` transitively depends on `ancestor`
+fn depends_on(phase_id: PhaseId, ancestor: PhaseId) -> bool {
+    phase_deps(phase_id)
+        .iter()
+        .any(|dep| *dep == ancestor || depends_on(*dep, ancestor))
+}
+
+/// Whether `phase_id` may be skipped: `final` does not depend on it and it is
+/// not built on `final` (review and fixup)
+#[must_use]
+pub fn is_optional_phase(phase_id: PhaseId) -> bool {
+    phase_id != PhaseId::Final
+        && !depends_on(PhaseId::Final, phase_id)
+        && !depends_on(phase_id, PhaseId::Final)
+}
+
+/// Names of the optional phases, in workflow order
+#[must_use]
+pub fn optional_phase_names() -> Vec<&'static str> {
+    PhaseId::ORDER
+        .into_iter()
+        .filter(|phase| is_optional_phase(*phase))
+        .map(|phase| phase.as_str())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_optional_phases_follow_the_graph() {
+        assert_eq!(optional_phase_names(), vec!["review", "fixup"]);
+        assert!(depends_on(PhaseId::Fixup, PhaseId::Tasks));
+        assert!(depends_on(PhaseId::Localize, PhaseId::Requirements));
+        assert!(!depends_on(PhaseId::Final, PhaseId::Review));
+    }
+}
@@ -32,7 +32,7 @@ use crate::spec_id::sanitize_spec_id;
 use crate::status::artifact::ArtifactManager;
 use crate::types::{PhaseId, StatusOutput};
 
-use super::{ExecutionResult, OrchestratorConfig, PhaseOrchestrator};
+use super::{ExecutionResult, OrchestratorConfig, PhaseOrchestrator, RunEstimate};
 
 /// The primary public API for embedding xchecker.
 ///
@@ -280,6 +280,149 @@ impl OrchestratorHandle {
                 .config
                 .insert("allow_links".to_string(), "true".to_string());
         }
+        if let Some(response_min_bytes) = config.defaults.response_min_bytes {
+            orch_config.config.insert(
+                "response_min_bytes".to_string(),
+                response_min_bytes.to_string(),
+            );
+        }
+        if let Some(response_retries) = config.defaults.response_retries {
+            orch_config
+                .config
+                .insert("response_retries".to_string(), response_retries.to_string());
+        }
+        if let Some(output_language) = &config.defaults.output_language {
+            orch_config
+                .config
+                .insert("output_language".to_string(), output_language.clone());
+        }
+        if let Some(fsync) = &config.defaults.fsync {
+            orch_config
+                .config
+                .insert("fsync".to_string(), fsync.clone());
+        }
+        if config.packet.transforms.manifests {
+            orch_config
+                .config
+                .insert("packet_transform_manifests".to_string(), "true".to_string());
+        }
+        if config.packet.transforms.lockfiles {
+            orch_config
+                .config
+                .insert("packet_transform_lockfiles".to_string(), "true".to_string());
+        }
+        if config.packet.transforms.openapi {
+            orch_config
+                .config
+                .insert("packet_transform_openapi".to_string(), "true".to_string());
+        }
+        if let Some(max_memory_bytes) = config.packet.max_memory_bytes {
+            orch_config.config.insert(
+                "packet_max_memory_bytes".to_string(),
+                max_memory_bytes.to_string(),
+            );
+        }
+        if let Some(spill_threshold_bytes) = config.packet.spill_threshold_bytes {
+            orch_config.config.insert(
+                "packet_spill_threshold_bytes".to_string(),
+                spill_threshold_bytes.to_string(),
+            );
+        }
+        if let Some(non_utf8) = &config.packet.non_utf8 {
+            orch_config
+                .config
+                .insert("packet_non_utf8".to_string(), non_utf8.clone());
+        }
+        if let Some(factor) = config.packet.overflow_shrink_factor {
+            orch_config.config.insert(
+                "packet_overflow_shrink_factor".to_string(),
+                factor.to_string(),
+            );
+        }
+        if let Some(retries) = config.packet.overflow_retries {
+            orch_config
+                .config
+                .insert("packet_overflow_retries".to_string(), retries.to_string());
+        }
+        if let Some(final_mode) = &config.final_.mode {
+            orch_config
+                .config
+                .insert("final_mode".to_string(), final_mode.clone());
+        }
+        if let Some(final_template) = &config.final_.template {
+            orch_config
+                .config
+                .insert("final_template".to_string(), final_template.clone());
+        }
+        if !config.final_.languages.is_empty() {
+            orch_config.config.insert(
+                "final_languages".to_string(),
+                config.final_.languages.join(","),
+            );
+        }
+        if let Some(badge) = &config.outputs.badge {
+            orch_config
+                .config
+                .insert("output_badge".to_string(), badge.clone());
+        }
+        if config.outputs.embed_config_snapshot {
+            orch_config.config.insert(
+                "output_embed_config_snapshot".to_string(),
+                "true".to_string(),
+            );
+        }
+        if config.outputs.front_matter {
+            orch_config
+                .config
+                .insert("output_front_matter".to_string(), "true".to_string());
+        }
+        let features = config.features.enabled();
+        if !features.is_empty() {
+            orch_config
+                .config
+                .insert("features".to_string(), features.join(","));
+        }
+        if let Some(mode) = &config.compliance.mode {
+            orch_config
+                .config
+                .insert("compliance_mode".to_string(), mode.clone());
+        }
+        if !config.compliance.patterns.is_empty() {
+            orch_config.config.insert(
+                "compliance_patterns".to_string(),
+                config.compliance.patterns.join("\n"),
+            );
+        }
+        if !config.compliance.ignore_patterns.is_empty() {
+            orch_config.config.insert(
+                "compliance_ignore_patterns".to_string(),
+                config.compliance.ignore_patterns.join("\n"),
+            );
+        }
+        if !config.redaction.paths.exclude.is_empty() {
+            orch_config.config.insert(
+                "redaction_paths_exclude".to_string(),
+                config.redaction.paths.exclude.join("\n"),
+            );
+        }
+        if !config.redaction.paths.mask.is_empty() {
+            orch_config.config.insert(
+                "redaction_paths_mask".to_string(),
+                config.redaction.paths.mask.join("\n"),
+            );
+        }
+        if let Some(max_bytes) = config.context_retention.max_bytes {
+            orch_config.config.insert(
+                "context_retention_max_bytes".to_string(),
+                max_bytes.to_string(),
+            );
+        }
+        if let Some(max_files) = config.context_retention.max_files {
+            orch_config.config.insert(
+                "context_retention_max_files".to_string(),
+                max_files.to_string(),
+            );
+        }
         if let Some(runner_mode) = &config.runner.mode {
             orch_config
                 .config
@@ -461,6 +604,39 @@ impl OrchestratorHandle {
             .await
     }
 
+    /// Re-run a phase even if it or later phases already completed.
+    ///
+    /// Dependencies must still be satisfied. Existing artifacts are replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if dependencies are not satisfied or execution fails.
+    pub async fn force_phase(&mut self, phase: PhaseId) -> Result<ExecutionResult> {
+        self.orchestrator.force_phase(phase, &self.config).await
+    }
+
+    /// Record an optional phase (review, fixup) as explicitly skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the phase is not optional, the transition is not legal,
+    /// or the receipt cannot be written.
+    pub fn skip_phase(&mut self, phase: PhaseId) -> Result<ExecutionResult> {
+        self.orchestrator.skip_phase(phase, &self.config)
+    }
+
+    /// Estimate the tokens and cost of running `phases` in order.
+    ///
+    /// Builds each phase's prompt and packet as a run would, without calling
+    /// the provider or writing receipts. See [`RunEstimate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a packet cannot be built or receipts cannot be read.
+    pub fn estimate(&self, phases: &[PhaseId]) -> Result<RunEstimate> {
+        self.orchestrator.estimate_phases(phases, &self.config)
+    }
+
     /// Execute all phases in sequence.
     ///
     /// Stops on first failure. Behavior matches the CLI `xchecker spec` command.
@@ -554,6 +730,7 @@ impl OrchestratorHandle {
     pub fn last_receipt_path(&self) -> Option<PathBuf> {
         // Check each phase in reverse order to find the most recent receipt
         let phases = [
+            PhaseId::Localize,
             PhaseId::Final,
             PhaseId::Fixup,
             PhaseId::Review,
@@ -631,7 +808,7 @@ impl OrchestratorHandle {
             Some(PhaseId::Tasks) => vec![PhaseId::Tasks, PhaseId::Review, PhaseId::Final],
             Some(PhaseId::Review) => vec![PhaseId::Review, PhaseId::Fixup, PhaseId::Final],
             Some(PhaseId::Fixup) => vec![PhaseId::Fixup, PhaseId::Final],
-            Some(PhaseId::Final) => vec![PhaseId::Final],
+            Some(PhaseId::Final | PhaseId::Localize) => vec![PhaseId::Final, PhaseId::Localize],
         })
     }
 
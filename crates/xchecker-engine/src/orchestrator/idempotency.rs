@@ -0,0 +1,212 @@
+//! Idempotency keys for safe external retries (`--idempotency-key`)
+//!
+//! A run started with an idempotency key records it in each receipt under
+//! [`RECEIPT_FLAG_IDEMPOTENCY_KEY`]. When the same phase is requested again
+//! with the same key and a successful receipt for it exists, that result is
+//! returned without re-executing the phase, so at-least-once callers (CI
+//! retries, MCP clients) avoid duplicate LLM spend and artifact churn.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use xchecker_utils::compression;
+
+use crate::exit_codes;
+use crate::receipt::ReceiptManager;
+use crate::types::{PhaseId, Receipt};
+
+use super::localize::localize_languages;
+use super::{ExecutionResult, OrchestratorConfig, PhaseOrchestrator, RECEIPT_FLAG_LANGUAGE};
+
+/// Receipt flag holding the idempotency key of the run that wrote it
+pub const RECEIPT_FLAG_IDEMPOTENCY_KEY: &str = "idempotency_key";
+
+/// Idempotency key of this run, if one was given
+pub(crate) fn idempotency_key(config: &OrchestratorConfig) -> Option<&str> {
+    config
+        .config
+        .get("idempotency_key")
+        .map(String::as_str)
+        .filter(|key| !key.is_empty())
+}
+
+impl PhaseOrchestrator {
+    /// Result of an earlier successful run of `phase_id` with this run's key
+    ///
+    /// Returns `None` when no key is set, no matching receipt exists, or an
+    /// artifact the receipt recorded is gone; the phase then runs normally.
+    /// Localize is replayed only when every configured language has a
+    /// matching receipt.
+    pub(crate) fn replay_idempotent(
+        &self,
+        phase_id: PhaseId,
+        config: &OrchestratorConfig,
+    ) -> Result<Option<ExecutionResult>> {
+        let Some(key) = idempotency_key(config) else {
+            return Ok(None);
+        };
+
+        let languages: Vec<Option<String>> = if phase_id == PhaseId::Localize {
+            localize_languages(config)
+                .into_iter()
+                .map(|language| Some(language.code().to_string()))
+                .collect()
+        } else {
+            vec![None]
+        };
+        if languages.is_empty() {
+            return Ok(None);
+        }
+
+        let receipts = self.receipt_manager().list_receipts()?;
+        let base_path = self.artifact_manager().base_path();
+        let mut artifact_paths = Vec::new();
+        let mut receipt_path = None;
+        for language in &languages {
+            let Some(receipt) = receipts.iter().rev().find(|receipt| {
+                receipt.phase == phase_id.as_str()
+                    && receipt.exit_code == exit_codes::codes::SUCCESS
+                    && !receipt.is_skipped()
+                    && receipt
+                        .flags
+                        .get(RECEIPT_FLAG_IDEMPOTENCY_KEY)
+                        .map(String::as_str)
+                        == Some(key)
+                    && receipt.flags.get(RECEIPT_FLAG_LANGUAGE) == language.as_ref()
+            }) else {
+                return Ok(None);
+            };
+
+            for output in &receipt.outputs {
+                // Artifacts over the compression threshold are stored as `.zst`
+                let path = base_path.join(&output.path);
+                let Some(stored) = [path.clone(), compression::compressed_path(&path)]
+                    .into_iter()
+                    .find(|path| path.exists())
+                else {
+                    return Ok(None);
+                };
+                artifact_paths.push(stored.into_std_path_buf());
+            }
+            receipt_path = self.receipt_file(receipt);
+        }
+
+        tracing::info!(
+            "Reusing {} result for idempotency key {key}; skipping execution",
+            phase_id.as_str()
+        );
+        Ok(Some(ExecutionResult {
+            phase: phase_id,
+            success: true,
+            exit_code: exit_codes::codes::SUCCESS,
+            artifact_paths,
+            receipt_path,
+            error: None,
+        }))
+    }
+
+    /// Path of `receipt` on disk, compressed or not
+    fn receipt_file(&self, receipt: &Receipt) -> Option<PathBuf> {
+        let name = ReceiptManager::receipt_file_name(&receipt.phase, receipt.emitted_at);
+        let path = self.receipt_manager().receipts_path().join(name);
+        [path.clone(), path.with_extension("json.zst")]
+            .into_iter()
+            .find(|path| path.exists())
+            .map(camino::Utf8PathBuf::into_std_path_buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paths::with_isolated_home;
+
+    fn keyed_config(key: &str) -> OrchestratorConfig {
+        let mut config = OrchestratorConfig {
+            dry_run: true,
+            ..OrchestratorConfig::default()
+        };
+        config
+            .config
+            .insert("idempotency_key".to_string(), key.to_string());
+        config
+    }
+
+    #[tokio::test]
+    async fn test_repeated_idempotency_key_replays_completed_result() {
+        let _home = with_isolated_home();
+        let orchestrator = PhaseOrchestrator::new("idempotency-replay").unwrap();
+        let config = keyed_config("ci-run-42");
+
+        let first = orchestrator
+            .resume_from_phase(PhaseId::Requirements, &config)
+            .await
+            .unwrap();
+        assert!(first.success);
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            receipt
+                .flags
+                .get(RECEIPT_FLAG_IDEMPOTENCY_KEY)
+                .map(String::as_str),
+            Some("ci-run-42")
+        );
+
+        // Design has completed, so re-running requirements would normally be
+        // an illegal transition; the retry is answered from the receipt
+        orchestrator
+            .resume_from_phase(PhaseId::Design, &keyed_config("ci-run-43"))
+            .await
+            .unwrap();
+        let replay = orchestrator
+            .resume_from_phase(PhaseId::Requirements, &config)
+            .await
+            .unwrap();
+        assert!(replay.success);
+        assert_eq!(replay.receipt_path, first.receipt_path);
+        assert_eq!(replay.artifact_paths.len(), receipt.outputs.len());
+        assert_eq!(
+            orchestrator
+                .receipt_manager()
+                .list_receipts()
+                .unwrap()
+                .len(),
+            2
+        );
+
+        // Artifacts compressed after the run still count as present
+        let base_path = orchestrator.artifact_manager().base_path();
+        for output in &receipt.outputs {
+            let path = base_path.join(&output.path);
+            let bytes = std::fs::read(&path).unwrap();
+            std::fs::write(
+                compression::compressed_path(&path),
+                compression::compress(&bytes, 3).unwrap(),
+            )
+            .unwrap();
+            std::fs::remove_file(&path).unwrap();
+        }
+        let replay = orchestrator
+            .replay_idempotent(PhaseId::Requirements, &config)
+            .unwrap()
+            .unwrap();
+        assert!(
+            replay
+                .artifact_paths
+                .iter()
+                .all(|path| compression::is_compressed(path))
+        );
+
+        // A different key runs the phase
+        assert!(
+            orchestrator
+                .replay_idempotent(PhaseId::Requirements, &keyed_config("ci-run-44"))
+                .unwrap()
+                .is_none()
+        );
+    }
+}
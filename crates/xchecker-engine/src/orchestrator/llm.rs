@@ -5,18 +5,39 @@
 use anyhow::Result;
 use std::collections::HashMap;
 use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::time::Duration;
 
 use crate::config::{
-    ClaudeConfig, Config, Defaults, GeminiConfig, LlmConfig, PhaseConfig, PhasesConfig,
-    PromptTemplate, RunnerConfig, SecurityConfig, Selectors,
+    ClaudeConfig, ComplianceConfig, Config, ContextRetentionConfig, Defaults, DoneConfig,
+    FeaturesConfig, FinalConfig, GeminiConfig, GitHooksConfig, LlmConfig, OutputsConfig,
+    PacketConfig, PhaseConfig, PhasesConfig, PricingConfig, PromptTemplate, RedactionConfig,
+    RunnerConfig, SecurityConfig, Selectors,
 };
-use crate::error::XCheckerError;
+use crate::error::{PhaseError, XCheckerError};
+use crate::exit_codes;
 use crate::hooks::HooksConfig;
-use crate::llm::{LlmBackend, LlmFallbackInfo, LlmInvocation, LlmResult, Message};
+use crate::llm::models::resolve_model;
+use crate::llm::params::GenerationParam;
+use crate::llm::{LlmBackend, LlmError, LlmFallbackInfo, LlmInvocation, LlmResult, Message, Role};
+use crate::packet::Packet;
+use crate::phase::{Phase, PhaseContext};
+use crate::types::PacketAttempt;
 use crate::types::PhaseId;
+use crate::validation::response::{
+    DEFAULT_MIN_RESPONSE_BYTES, DEFAULT_RESPONSE_RETRIES, ResponseValidator,
+};
+use crate::validation::{OutputLanguage, sanitize_response};
 
 use super::{OrchestratorConfig, PhaseOrchestrator, PhaseTimeout};
 
+/// Extension recording how often a crashed provider was restarted
+pub(crate) const PROVIDER_RESTARTS_EXTENSION: &str = "provider_restarts";
+
+/// Delay before the first provider restart; later restarts wait longer
+const PROVIDER_RESTART_DELAY: Duration = Duration::from_millis(500);
+
 /// Metadata from Claude CLI execution for receipt generation.
 ///
 /// Internal type used to track LLM execution details that get written to receipts.
@@ -25,6 +46,8 @@ use super::{OrchestratorConfig, PhaseOrchestrator, PhaseTimeout};
 pub(crate) struct ClaudeExecutionMetadata {
     pub model_alias: Option<String>,
     pub model_full_name: String,
+    /// Receipt warning when the model is scheduled for retirement
+    pub model_deprecation: Option<String>,
     pub claude_cli_version: String,
     pub fallback_used: bool,
     pub runner: String,
@@ -32,6 +55,43 @@ pub(crate) struct ClaudeExecutionMetadata {
     pub stderr_tail: Option<String>,
 }
 
+/// Per-phase generation parameters split by provider support.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct GenerationSettings {
+    provider: String,
+    /// Parameters passed to the backend, with their invocation metadata value
+    applied: Vec<(GenerationParam, serde_json::Value)>,
+    /// Parameters configured but not supported by the provider
+    ignored: Vec<GenerationParam>,
+}
+
+impl GenerationSettings {
+    /// Effective parameters as receipt flags (stop sequences as a JSON array)
+    pub(crate) fn receipt_flags(&self) -> impl Iterator<Item = (String, String)> + '_ {
+        self.applied.iter().map(|(param, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            (param.name().to_string(), value)
+        })
+    }
+
+    /// One receipt warning per ignored parameter
+    pub(crate) fn warnings(&self) -> Vec<String> {
+        self.ignored
+            .iter()
+            .map(|param| {
+                format!(
+                    "generation_param_ignored: provider '{}' does not support {}",
+                    self.provider,
+                    param.name()
+                )
+            })
+            .collect()
+    }
+}
+
 /// Error wrapper that preserves fallback warning metadata on invocation failures.
 #[derive(Debug)]
 pub(crate) struct LlmInvocationError {
@@ -117,7 +177,10 @@ fn apply_overrides_from_map(config: &mut Config, overrides: &HashMap<String, Str
         .or_else(|| overrides.get("claude_cli_path"))
     {
         if config.llm.claude.is_none() {
-            config.llm.claude = Some(ClaudeConfig { binary: None });
+            config.llm.claude = Some(ClaudeConfig {
+                binary: None,
+                max_restarts: None,
+            });
         }
         if let Some(claude_config) = config.llm.claude.as_mut() {
             claude_config.binary = Some(claude_binary_path.clone());
@@ -131,6 +194,7 @@ fn apply_overrides_from_map(config: &mut Config, overrides: &HashMap<String, Str
                 binary: None,
                 default_model: None,
                 profiles: None,
+                max_restarts: None,
             });
         }
         if let Some(gemini_config) = config.llm.gemini.as_mut() {
@@ -144,6 +208,7 @@ fn apply_overrides_from_map(config: &mut Config, overrides: &HashMap<String, Str
                 binary: None,
                 default_model: None,
                 profiles: None,
+                max_restarts: None,
             });
         }
         if let Some(gemini_config) = config.llm.gemini.as_mut() {
@@ -161,12 +226,31 @@ fn apply_overrides_from_map(config: &mut Config, overrides: &HashMap<String, Str
         let phase_timeout = overrides
             .get(&format!("phases.{phase_name}.phase_timeout"))
             .and_then(|s| s.parse::<u64>().ok());
+        let temperature = overrides
+            .get(&format!("phases.{phase_name}.temperature"))
+            .and_then(|s| s.parse::<f32>().ok());
+        let max_output_tokens = overrides
+            .get(&format!("phases.{phase_name}.max_output_tokens"))
+            .and_then(|s| s.parse::<u32>().ok());
+        // Stop sequences are passed as a JSON array
+        let stop_sequences = overrides
+            .get(&format!("phases.{phase_name}.stop_sequences"))
+            .and_then(|s| serde_json::from_str::<Vec<String>>(s).ok());
 
-        if model.is_some() || max_turns.is_some() || phase_timeout.is_some() {
+        if model.is_some()
+            || max_turns.is_some()
+            || phase_timeout.is_some()
+            || temperature.is_some()
+            || max_output_tokens.is_some()
+            || stop_sequences.is_some()
+        {
             *target = Some(PhaseConfig {
                 model,
                 max_turns,
                 phase_timeout,
+                temperature,
+                max_output_tokens,
+                stop_sequences,
             });
         }
     };
@@ -177,6 +261,172 @@ fn apply_overrides_from_map(config: &mut Config, overrides: &HashMap<String, Str
     apply_phase_override("review", &mut config.phases.review);
     apply_phase_override("fixup", &mut config.phases.fixup);
     apply_phase_override("final", &mut config.phases.final_);
+    apply_phase_override("localize", &mut config.phases.localize);
+}
+
+/// Default fraction of the packet kept after a context overflow.
+const DEFAULT_OVERFLOW_SHRINK_FACTOR: f64 = 0.5;
+
+/// Default number of shrink-and-retry attempts after a context overflow.
+const DEFAULT_OVERFLOW_RETRIES: u32 = 2;
+
+/// Resolve the packet shrink factor and retry budget from the config map.
+fn overflow_shrink_settings(orc_config: &OrchestratorConfig) -> (f64, u32) {
+    let factor = orc_config
+        .config
+        .get("packet_overflow_shrink_factor")
+        .and_then(|s| s.parse::<f64>().ok())
+        .filter(|f| *f > 0.0 && *f < 1.0)
+        .unwrap_or(DEFAULT_OVERFLOW_SHRINK_FACTOR);
+    let retries = orc_config
+        .config
+        .get("packet_overflow_retries")
+        .and_then(|s| s.parse::<u32>().ok())
+        .unwrap_or(DEFAULT_OVERFLOW_RETRIES);
+    (factor, retries)
+}
+
+/// Whether an invocation failed because the packet exceeded the context window.
+fn is_context_overflow(err: &anyhow::Error) -> bool {
+    let llm_err = if let Some(invocation_err) = err.downcast_ref::<LlmInvocationError>() {
+        invocation_err.error()
+    } else if let Some(xchecker_err) = err.downcast_ref::<XCheckerError>() {
+        xchecker_err
+    } else {
+        return false;
+    };
+    matches!(llm_err, XCheckerError::Llm(LlmError::ContextOverflow(_)))
+}
+
+/// Budget of `packet` scaled by `factor`, based on what the packet actually used.
+fn shrunk_budget(packet: &Packet, factor: f64) -> (usize, usize) {
+    let budget = packet.budget_usage();
+    let scale = |used: usize, limit: usize| {
+        let scaled = (used.min(limit) as f64 * factor) as usize;
+        scaled.max(1)
+    };
+    (
+        scale(budget.bytes_used, budget.max_bytes),
+        scale(budget.lines_used, budget.max_lines),
+    )
+}
+
+fn packet_attempt(packet: &Packet, context_overflow: bool) -> PacketAttempt {
+    let budget = packet.budget_usage();
+    PacketAttempt {
+        max_bytes: budget.max_bytes,
+        max_lines: budget.max_lines,
+        bytes_used: budget.bytes_used,
+        lines_used: budget.lines_used,
+        context_overflow,
+    }
+}
+
+/// Resolve the configured output language, if any.
+fn output_language(orc_config: &OrchestratorConfig) -> Option<OutputLanguage> {
+    orc_config
+        .config
+        .get("output_language")
+        .and_then(|code| OutputLanguage::from_code(code))
+}
+
+/// Append the output language instruction, if configured, to a phase prompt.
+fn with_output_language(prompt: &str, orc_config: &OrchestratorConfig) -> String {
+    match output_language(orc_config) {
+        Some(language) => format!("{prompt}\n\n{}", language.prompt_instruction()),
+        None => prompt.to_string(),
+    }
+}
+
+/// Resolve the configured prompt template, falling back to the default.
+fn prompt_template(cfg: &Config) -> PromptTemplate {
+    cfg.llm
+        .prompt_template
+        .as_deref()
+        .and_then(|name| PromptTemplate::parse(name).ok())
+        .unwrap_or(PromptTemplate::Default)
+}
+
+/// Render messages as plain text, one `=== role ===` section per message.
+fn render_messages(messages: &[Message]) -> String {
+    let mut out = String::new();
+    for message in messages {
+        let role = match message.role {
+            Role::System => "system",
+            Role::User => "user",
+            Role::Assistant => "assistant",
+        };
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&format!("=== {role} ===\n{}\n", message.content));
+    }
+    out
+}
+
+/// Resolve the response validator and retry budget from the config map.
+fn response_validation_settings(orc_config: &OrchestratorConfig) -> (ResponseValidator, u32) {
+    let min_bytes = orc_config
+        .config
+        .get("response_min_bytes")
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(DEFAULT_MIN_RESPONSE_BYTES);
+    let retries = orc_config
+        .config
+        .get("response_retries")
+        .and_then(|s| s.parse::<u32>().ok())
+        .unwrap_or(DEFAULT_RESPONSE_RETRIES);
+
+    (
+        ResponseValidator::new(min_bytes).with_language(output_language(orc_config)),
+        retries,
+    )
+}
+
+/// Whether an invocation outcome is a provider crash rather than a reported error
+///
+/// CLI providers that exit non-zero after writing nothing to stdout crashed
+/// (signal, missing runtime, startup panic); output before the exit means the
+/// provider reported a failure and the response is kept as a partial artifact.
+fn is_provider_crash(result: &Result<LlmResult, LlmError>) -> bool {
+    match result {
+        Err(LlmError::ProviderCrash { .. }) => true,
+        Err(_) => false,
+        Ok(result) => {
+            provider_exit_code(result).is_some_and(|code| code != 0)
+                && result.timed_out != Some(true)
+                && result.raw_response.trim().is_empty()
+        }
+    }
+}
+
+/// Receipt classification of a non-zero provider exit
+pub(crate) fn provider_failure(result: &LlmResult) -> &'static str {
+    if result.raw_response.trim().is_empty() {
+        "provider_crash"
+    } else {
+        "provider_error"
+    }
+}
+
+fn provider_exit_code(result: &LlmResult) -> Option<i64> {
+    result.extensions.get("exit_code").and_then(|v| v.as_i64())
+}
+
+/// Sum two optional token counts; unknown only if both are unknown
+fn add_tokens(a: Option<u64>, b: Option<u64>) -> Option<u64> {
+    match (a, b) {
+        (None, None) => None,
+        (a, b) => Some(a.unwrap_or(0) + b.unwrap_or(0)),
+    }
+}
+
+fn invocation_metadata_str(invocation: &LlmInvocation, key: &str) -> Option<String> {
+    invocation
+        .metadata
+        .get(key)
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
 }
 
 fn build_messages_from_template(
@@ -263,6 +513,16 @@ impl PhaseOrchestrator {
                 phases: PhasesConfig::default(),
                 hooks: HooksConfig::default(),
                 security: SecurityConfig::default(),
+                packet: PacketConfig::default(),
+                final_: FinalConfig::default(),
+                outputs: OutputsConfig::default(),
+                compliance: ComplianceConfig::default(),
+                redaction: RedactionConfig::default(),
+                context_retention: ContextRetentionConfig::default(),
+                git_hooks: GitHooksConfig::default(),
+                done: DoneConfig::default(),
+                features: FeaturesConfig::default(),
+                pricing: PricingConfig::default(),
                 source_attribution: HashMap::new(),
             }
         };
@@ -282,12 +542,19 @@ impl PhaseOrchestrator {
     pub(crate) fn make_llm_backend(
         &self,
         orc_config: &OrchestratorConfig,
-    ) -> Result<(Box<dyn LlmBackend>, Option<LlmFallbackInfo>), XCheckerError> {
+    ) -> Result<(Arc<dyn LlmBackend>, Option<LlmFallbackInfo>), XCheckerError> {
+        // An injected backend bypasses provider construction and fallback
+        if let Some(backend) = &orc_config.llm_backend {
+            return Ok((Arc::clone(&backend.0), None));
+        }
+
         // Build a Config from OrchestratorConfig
         let cfg = self.config_from_orchestrator_config(orc_config);
 
         // Use the factory function to construct the appropriate backend
-        crate::llm::from_config_with_fallback(&cfg).map_err(XCheckerError::Llm)
+        let (backend, fallback_info) =
+            crate::llm::from_config_with_fallback(&cfg).map_err(XCheckerError::Llm)?;
+        Ok((Arc::from(backend), fallback_info))
     }
 
     /// Build `LlmInvocation` from packet and phase context.
@@ -321,42 +588,58 @@ impl PhaseOrchestrator {
         // Note: We don't force "haiku" for claude-cli here because:
         // - If fallback to a different provider happens, the wrong model would be used
         // - Each backend should handle its own default model selection
-        let phase_model = match phase_id {
-            PhaseId::Requirements => cfg.phases.requirements.as_ref(),
-            PhaseId::Design => cfg.phases.design.as_ref(),
-            PhaseId::Tasks => cfg.phases.tasks.as_ref(),
-            PhaseId::Review => cfg.phases.review.as_ref(),
-            PhaseId::Fixup => cfg.phases.fixup.as_ref(),
-            PhaseId::Final => cfg.phases.final_.as_ref(),
-        }
-        .and_then(|pc| pc.model.clone())
-        .filter(|model| !model.is_empty());
+        let model = Self::configured_model(&cfg, phase_id);
 
-        let model = phase_model.unwrap_or_else(|| {
-            cfg.defaults
-                .model
-                .clone()
-                .filter(|m| !m.is_empty())
-                .unwrap_or_default()
-        });
+        // Resolve aliases to the provider's full model name (see llm::models)
+        let resolved = resolve_model(provider, &model);
+        let deprecation = resolved.deprecation_warning();
+        if let Some(warning) = &deprecation {
+            tracing::warn!(
+                target: "xchecker::llm",
+                spec_id = %self.spec_id,
+                phase = %phase_id.as_str(),
+                "{warning}"
+            );
+        }
+        let model = resolved.name;
 
         // Get timeout from config with minimum enforcement
         let timeout = PhaseTimeout::from_config(orc_config).duration;
 
         // Build messages using the configured prompt template, including packet context.
-        let template = cfg
-            .llm
-            .prompt_template
-            .as_deref()
-            .and_then(|name| PromptTemplate::parse(name).ok())
-            .unwrap_or(PromptTemplate::Default);
-
-        let messages = build_messages_from_template(template, prompt, packet);
+        let messages = build_messages_from_template(prompt_template(&cfg), prompt, packet);
 
         // Create invocation
         let mut invocation =
             LlmInvocation::new(&self.spec_id, phase_id.as_str(), model, timeout, messages);
 
+        if let Some(alias) = resolved.alias {
+            invocation
+                .metadata
+                .insert("model_alias".to_string(), serde_json::Value::String(alias));
+        }
+        if let Some(warning) = deprecation {
+            invocation.metadata.insert(
+                "model_deprecation".to_string(),
+                serde_json::Value::String(warning),
+            );
+        }
+
+        let generation = Self::generation_settings_for(&cfg, phase_id);
+        for warning in generation.warnings() {
+            tracing::warn!(
+                target: "xchecker::llm",
+                spec_id = %self.spec_id,
+                phase = %phase_id.as_str(),
+                "{warning}"
+            );
+        }
+        for (param, value) in generation.applied {
+            invocation
+                .metadata
+                .insert(param.metadata_key().to_string(), value);
+        }
+
         if let Some(scenario) = orc_config.config.get("claude_scenario") {
             invocation.metadata.insert(
                 "claude_scenario".to_string(),
@@ -383,6 +666,178 @@ impl PhaseOrchestrator {
         invocation
     }
 
+    /// Model configured for `phase_id`: the phase override, else the global
+    /// default, else empty (the backend picks its own default).
+    pub(crate) fn configured_model(cfg: &Config, phase_id: PhaseId) -> String {
+        let phase_model = cfg
+            .phases
+            .get(phase_id)
+            .and_then(|pc| pc.model.clone())
+            .filter(|model| !model.is_empty());
+
+        phase_model.unwrap_or_else(|| {
+            cfg.defaults
+                .model
+                .clone()
+                .filter(|m| !m.is_empty())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Render the exact messages that would be sent for this prompt and packet.
+    ///
+    /// Uses the same template and output-language instruction as
+    /// `run_llm_invocation`, so the text matches what the provider receives.
+    pub(crate) fn render_prompt(
+        &self,
+        prompt: &str,
+        packet: &str,
+        orc_config: &OrchestratorConfig,
+    ) -> String {
+        let cfg = self.config_from_orchestrator_config(orc_config);
+        let prompt = with_output_language(prompt, orc_config);
+        render_messages(&build_messages_from_template(
+            prompt_template(&cfg),
+            &prompt,
+            packet,
+        ))
+    }
+
+    /// Generation parameters configured for `phase_id`, split by whether the
+    /// configured provider supports them.
+    pub(crate) fn generation_settings(
+        &self,
+        phase_id: PhaseId,
+        orc_config: &OrchestratorConfig,
+    ) -> GenerationSettings {
+        Self::generation_settings_for(&self.config_from_orchestrator_config(orc_config), phase_id)
+    }
+
+    fn generation_settings_for(cfg: &Config, phase_id: PhaseId) -> GenerationSettings {
+        let provider = cfg.llm.provider.as_deref().unwrap_or("claude-cli");
+        let mut settings = GenerationSettings {
+            provider: provider.to_string(),
+            ..GenerationSettings::default()
+        };
+        let Some(phase) = cfg.phases.get(phase_id) else {
+            return settings;
+        };
+
+        for param in GenerationParam::ALL {
+            let value = match param {
+                // Round-trip through the decimal form so 0.3 stays 0.3 as f64
+                GenerationParam::Temperature => phase
+                    .temperature
+                    .and_then(|t| t.to_string().parse::<f64>().ok())
+                    .map(serde_json::Value::from),
+                GenerationParam::MaxOutputTokens => {
+                    phase.max_output_tokens.map(serde_json::Value::from)
+                }
+                GenerationParam::StopSequences => phase
+                    .stop_sequences
+                    .as_ref()
+                    .filter(|stops| !stops.is_empty())
+                    .map(|stops| serde_json::json!(stops)),
+            };
+            let Some(value) = value else { continue };
+            if param.supported_by(provider) {
+                settings.applied.push((param, value));
+            } else {
+                settings.ignored.push(param);
+            }
+        }
+        settings
+    }
+
+    /// Execute the LLM invocation, rebuilding `packet` at a smaller budget
+    /// after each context overflow.
+    ///
+    /// Each rebuilt packet is secret-scanned again before it is sent. When a
+    /// retry happened, every packet sent is recorded in
+    /// `packet.evidence.attempts`; the last overflow error is returned once
+    /// the retry budget is spent or the packet cannot shrink further.
+    /// Response bytes are added to `progress` as the provider sends them.
+    pub(crate) async fn run_llm_invocation_with_shrink(
+        &self,
+        phase: &dyn Phase,
+        phase_context: &PhaseContext,
+        prompt: &str,
+        packet: &mut Packet,
+        config: &OrchestratorConfig,
+        progress: &Arc<AtomicU64>,
+    ) -> Result<(
+        String,
+        i32,
+        Option<ClaudeExecutionMetadata>,
+        Option<LlmResult>,
+        Option<String>,
+    )> {
+        let phase_id = phase.id();
+        let (factor, max_retries) = overflow_shrink_settings(config);
+        let mut attempts = Vec::new();
+
+        loop {
+            let result = self
+                .run_llm_invocation(prompt, &packet.content, phase_id, config, progress)
+                .await;
+            let overflow = result.as_ref().err().is_some_and(is_context_overflow);
+            if overflow || !attempts.is_empty() {
+                attempts.push(packet_attempt(packet, overflow));
+            }
+            if !overflow || attempts.len() > max_retries as usize {
+                if attempts.len() > 1 {
+                    packet.evidence.attempts = attempts;
+                }
+                return result;
+            }
+
+            let (max_bytes, max_lines) = shrunk_budget(packet, factor);
+            tracing::warn!(
+                target: "xchecker::packet",
+                spec_id = %self.spec_id,
+                phase = %phase_id.as_str(),
+                bytes_used = packet.budget_usage().bytes_used,
+                max_bytes,
+                max_lines,
+                "Provider reported a context overflow; rebuilding packet at a smaller budget"
+            );
+
+            let mut shrunk_context = phase_context.clone();
+            shrunk_context
+                .config
+                .insert("packet_max_bytes".to_string(), max_bytes.to_string());
+            shrunk_context
+                .config
+                .insert("packet_max_lines".to_string(), max_lines.to_string());
+            let mut shrunk = match phase.make_packet(&shrunk_context) {
+                Ok(shrunk) => shrunk,
+                Err(e) => {
+                    // Required upstream files alone no longer fit; report the overflow
+                    tracing::warn!(
+                        target: "xchecker::packet",
+                        spec_id = %self.spec_id,
+                        phase = %phase_id.as_str(),
+                        "Packet cannot shrink further: {e}"
+                    );
+                    if attempts.len() > 1 {
+                        packet.evidence.attempts = attempts;
+                    }
+                    return result;
+                }
+            };
+
+            if config.redactor.has_secrets(&shrunk.content, "packet")? {
+                return Err(XCheckerError::Phase(PhaseError::ExecutionFailed {
+                    phase: phase_id.as_str().to_string(),
+                    code: exit_codes::codes::SECRET_DETECTED,
+                })
+                .into());
+            }
+            Self::scan_packet_compliance(&mut shrunk, config)?;
+            *packet = shrunk;
+        }
+    }
+
     /// Execute LLM invocation using the backend abstraction.
     ///
     /// Internal helper that invokes the LLM backend and converts results to the format
@@ -397,6 +852,7 @@ impl PhaseOrchestrator {
         packet: &str,
         phase_id: PhaseId,
         config: &OrchestratorConfig,
+        progress: &Arc<AtomicU64>,
     ) -> Result<(
         String,
         i32,
@@ -404,21 +860,136 @@ impl PhaseOrchestrator {
         Option<LlmResult>,
         Option<String>,
     )> {
-        // Build LLM invocation
-        let invocation = self.build_llm_invocation(phase_id, prompt, packet, config);
+        // Add the output language instruction to every phase prompt
+        let prompt = with_output_language(prompt, config);
+
+        // Build LLM invocation; the backend reports bytes to the phase heartbeat
+        let invocation = self
+            .build_llm_invocation(phase_id, &prompt, packet, config)
+            .with_progress(progress.clone());
 
         // Get backend
         let (backend, fallback_info) = self.make_llm_backend(config)?;
+        let cfg = self.config_from_orchestrator_config(config);
+        let provider = match &fallback_info {
+            Some(info) => info.fallback_provider.clone(),
+            None => cfg
+                .llm
+                .provider
+                .clone()
+                .unwrap_or_else(|| "claude-cli".to_string()),
+        };
+        let max_restarts = cfg.llm.max_restarts(&provider);
         let fallback_warning = fallback_info.map(|info| info.warning_message());
         let fallback_warning_for_error = fallback_warning.clone();
 
-        // Invoke LLM
-        let llm_result = backend.invoke(invocation).await.map_err(|err| {
-            anyhow::Error::new(LlmInvocationError::new(
-                XCheckerError::Llm(err),
-                fallback_warning_for_error.clone(),
-            ))
-        })?;
+        // Invoke LLM, re-invoking when the response fails post-stream validation
+        let (validator, max_retries) = response_validation_settings(config);
+        let mut attempts: u32 = 0;
+        let mut restarts: u32 = 0;
+        // Tokens consumed by responses that failed validation still count
+        let mut retried_tokens: (Option<u64>, Option<u64>) = (None, None);
+        let llm_result = loop {
+            let spinner = xchecker_utils::console::Spinner::start(format!(
+                "Waiting for provider response ({} phase)",
+                phase_id.as_str()
+            ));
+            let result = backend.invoke(invocation.clone()).await;
+            drop(spinner);
+
+            // A provider that died before writing anything is restarted a
+            // bounded number of times without counting as a validation attempt
+            if is_provider_crash(&result) && restarts < max_restarts {
+                restarts += 1;
+                tracing::warn!(
+                    target: "xchecker::llm",
+                    spec_id = %self.spec_id,
+                    phase = %phase_id.as_str(),
+                    provider = %provider,
+                    restart = restarts,
+                    max_restarts,
+                    "Provider exited without output; restarting"
+                );
+                tokio::time::sleep(PROVIDER_RESTART_DELAY * restarts).await;
+                continue;
+            }
+
+            attempts += 1;
+            let mut result = result.map_err(|err| {
+                let err = match err {
+                    LlmError::ProviderCrash { message, .. } => {
+                        LlmError::ProviderCrash { message, restarts }
+                    }
+                    other => other,
+                };
+                anyhow::Error::new(LlmInvocationError::new(
+                    XCheckerError::Llm(err),
+                    fallback_warning_for_error.clone(),
+                ))
+            })?;
+
+            // Invisible characters would change artifact hashes without any visible difference
+            let sanitized = sanitize_response(&result.raw_response);
+            if sanitized.changed() {
+                let summary = sanitized.to_string();
+                result.raw_response = sanitized.text;
+                result =
+                    result.with_extension("response_sanitized", serde_json::Value::String(summary));
+            }
+
+            // Non-zero exits are handled by the caller (partial artifact + failure receipt)
+            let provider_failed = provider_exit_code(&result).is_some_and(|code| code != 0);
+            if provider_failed {
+                break result;
+            }
+
+            match validator.validate(&result.raw_response) {
+                Ok(()) => break result,
+                Err(issues) => {
+                    let checks: Vec<String> = issues
+                        .iter()
+                        .map(|issue| issue.code().to_string())
+                        .collect();
+                    tracing::warn!(
+                        target: "xchecker::llm",
+                        spec_id = %self.spec_id,
+                        phase = %phase_id.as_str(),
+                        attempt = attempts,
+                        checks = %checks.join(","),
+                        "Provider response failed validation"
+                    );
+
+                    retried_tokens.0 = add_tokens(retried_tokens.0, result.tokens_input);
+                    retried_tokens.1 = add_tokens(retried_tokens.1, result.tokens_output);
+
+                    if attempts > max_retries {
+                        return Err(anyhow::Error::new(LlmInvocationError::new(
+                            XCheckerError::Llm(LlmError::InvalidResponse { checks, attempts }),
+                            fallback_warning_for_error.clone(),
+                        )));
+                    }
+                }
+            }
+        };
+        let llm_result = if attempts > 1 {
+            let mut llm_result = llm_result.with_extension(
+                "response_validation_attempts",
+                serde_json::Value::from(attempts),
+            );
+            llm_result.tokens_input = add_tokens(llm_result.tokens_input, retried_tokens.0);
+            llm_result.tokens_output = add_tokens(llm_result.tokens_output, retried_tokens.1);
+            llm_result
+        } else {
+            llm_result
+        };
+        let llm_result = if restarts > 0 {
+            llm_result.with_extension(
+                PROVIDER_RESTARTS_EXTENSION,
+                serde_json::Value::from(restarts),
+            )
+        } else {
+            llm_result
+        };
         let llm_result = if let Some(ref warning) = fallback_warning {
             llm_result.with_extension(
                 "llm_fallback_warning",
@@ -463,8 +1034,9 @@ impl PhaseOrchestrator {
         // For V11, we need to convert LlmResult back to the format expected by existing code
         // This maintains compatibility while using the new abstraction
         let metadata = ClaudeExecutionMetadata {
-            model_alias: None, // LlmResult doesn't track alias yet
+            model_alias: invocation_metadata_str(&invocation, "model_alias"),
             model_full_name: llm_result.model_used.clone(),
+            model_deprecation: invocation_metadata_str(&invocation, "model_deprecation"),
             claude_cli_version,
             fallback_used,
             runner,
@@ -489,9 +1061,12 @@ impl PhaseOrchestrator {
 
 #[cfg(test)]
 mod tests {
-    use super::build_messages_from_template;
+    use super::{
+        OrchestratorConfig, build_messages_from_template, output_language, render_messages,
+    };
     use crate::config::PromptTemplate;
     use crate::llm::Role;
+    use crate::validation::OutputLanguage;
 
     #[test]
     fn build_messages_default_includes_packet() {
@@ -541,4 +1116,29 @@ mod tests {
         assert_eq!(messages[1].role, Role::User);
         assert_eq!(messages[1].content, "Write summary");
     }
+
+    #[test]
+    fn render_messages_labels_each_role() {
+        let messages = build_messages_from_template(
+            PromptTemplate::OpenAiCompatible,
+            "Write summary",
+            "context here",
+        );
+
+        assert_eq!(
+            render_messages(&messages),
+            "=== system ===\nYou are xchecker. Follow the instructions and use the provided context.\n\n=== user ===\nWrite summary\n\nContext:\ncontext here\n"
+        );
+    }
+
+    #[test]
+    fn output_language_resolved_from_config_map() {
+        let mut config = OrchestratorConfig::default();
+        assert_eq!(output_language(&config), None);
+
+        config
+            .config
+            .insert("output_language".to_string(), "de".to_string());
+        assert_eq!(output_language(&config), Some(OutputLanguage::German));
+    }
 }
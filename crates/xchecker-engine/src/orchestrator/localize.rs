@@ -0,0 +1,149 @@
+//! Localize phase execution (`[final] languages`)
+//!
+//! The Localize phase translates `50-final.md` into one language per run. A
+//! `localize` run executes the phase once for every configured language, in
+//! order, so each translation `50-final.<code>.md` gets its own receipt
+//! flagged with [`RECEIPT_FLAG_LANGUAGE`].
+
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::error::{PhaseError, XCheckerError};
+use crate::phases::LocalizePhase;
+use crate::types::PhaseId;
+use crate::validation::OutputLanguage;
+
+use super::{ExecutionResult, OrchestratorConfig, PhaseOrchestrator};
+
+/// Receipt flag naming the language a Localize run translated into
+pub const RECEIPT_FLAG_LANGUAGE: &str = "language";
+
+/// Config key selecting the target language of a single Localize run
+const LOCALIZE_LANGUAGE_KEY: &str = "localize_language";
+
+/// Languages from `[final] languages`, in configured order without repeats
+pub(crate) fn localize_languages(config: &OrchestratorConfig) -> Vec<OutputLanguage> {
+    let mut languages = Vec::new();
+    for language in config
+        .config
+        .get("final_languages")
+        .into_iter()
+        .flat_map(|codes| codes.split(','))
+        .filter_map(OutputLanguage::from_code)
+    {
+        if !languages.contains(&language) {
+            languages.push(language);
+        }
+    }
+    languages
+}
+
+/// Configuration for the Localize run translating into `language`
+///
+/// The language also becomes the run's output language, so the prompt asks
+/// for it and the response is checked against it.
+pub(crate) fn localize_config(
+    config: &OrchestratorConfig,
+    language: OutputLanguage,
+) -> OrchestratorConfig {
+    let mut config = config.clone();
+    for key in [LOCALIZE_LANGUAGE_KEY, "output_language"] {
+        config
+            .config
+            .insert(key.to_string(), language.code().to_string());
+    }
+    config
+}
+
+/// The Localize phase of a configuration built by [`localize_config`]
+pub(crate) fn localize_phase(config: &OrchestratorConfig) -> Result<LocalizePhase> {
+    config
+        .config
+        .get(LOCALIZE_LANGUAGE_KEY)
+        .and_then(|code| OutputLanguage::from_code(code))
+        .map(LocalizePhase::new)
+        .ok_or_else(|| no_languages_error().into())
+}
+
+/// Language recorded in the receipts of a Localize run's configuration
+pub(crate) fn receipt_language(config: &OrchestratorConfig) -> Option<&str> {
+    config.config.get(LOCALIZE_LANGUAGE_KEY).map(String::as_str)
+}
+
+fn no_languages_error() -> XCheckerError {
+    XCheckerError::Phase(PhaseError::ContextCreationFailed {
+        phase: PhaseId::Localize.as_str().to_string(),
+        reason: "no target language is configured; list languages in `[final] languages`"
+            .to_string(),
+    })
+}
+
+impl PhaseOrchestrator {
+    /// Run the Localize phase once for each language in `[final] languages`
+    ///
+    /// Stops at the first language that fails and returns its result;
+    /// otherwise returns the last run's result with every run's artifacts.
+    pub(crate) async fn execute_localize(
+        &self,
+        config: &OrchestratorConfig,
+    ) -> Result<ExecutionResult> {
+        let languages = localize_languages(config);
+        if languages.is_empty() {
+            return Err(no_languages_error().into());
+        }
+
+        let mut artifact_paths = Vec::new();
+        let mut last = None;
+        for (i, language) in languages.into_iter().enumerate() {
+            if i > 0 {
+                // Receipt names have one-second resolution; start the next
+                // language in a new second so its receipt does not replace this one
+                let elapsed = chrono::Utc::now().timestamp_subsec_millis().min(999);
+                tokio::time::sleep(Duration::from_millis(u64::from(1_000 - elapsed))).await;
+            }
+
+            let config = localize_config(config, language);
+            let phase = localize_phase(&config)?;
+            let result = self.execute_phase_with_resume(&phase, &config).await?;
+            if !result.success {
+                return Ok(result);
+            }
+            artifact_paths.extend(result.artifact_paths.iter().cloned());
+            last = Some(result);
+        }
+
+        let mut result = last.expect("at least one language was localized");
+        result.artifact_paths = artifact_paths;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_localize_languages_keep_order_and_skip_repeats() {
+        let mut config = OrchestratorConfig::default();
+        assert!(localize_languages(&config).is_empty());
+        assert!(localize_phase(&config).is_err());
+
+        config
+            .config
+            .insert("final_languages".to_string(), "fr,DE,fr".to_string());
+        let languages = localize_languages(&config);
+        assert_eq!(languages, [OutputLanguage::French, OutputLanguage::German]);
+
+        let german = localize_config(&config, languages[1]);
+        assert_eq!(receipt_language(&german), Some("de"));
+        assert_eq!(
+            german.config.get("output_language").map(String::as_str),
+            Some("de")
+        );
+        assert_eq!(
+            localize_phase(&german).unwrap().artifact_name(),
+            "50-final.de.md"
+        );
+    }
+}
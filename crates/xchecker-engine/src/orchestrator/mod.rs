@@ -4,11 +4,27 @@
 //! the Phase trait, `ArtifactManager`, and Receipt system to execute
 //! phases end-to-end with proper error handling and state management.
 
+mod estimate;
+mod failpoint;
+mod graph;
 mod handle;
+mod idempotency;
 mod llm;
+mod localize;
 mod phase_exec;
+mod references;
+mod sinks;
 mod workflow;
 
+pub use self::estimate::{
+    CHARS_PER_TOKEN, DEFAULT_OUTPUT_TOKENS, OutputEstimateSource, PhaseEstimate, RunEstimate,
+    estimate_tokens,
+};
+pub use self::failpoint::FailurePoint;
+pub use self::graph::{is_optional_phase, optional_phase_names};
+pub use self::idempotency::RECEIPT_FLAG_IDEMPOTENCY_KEY;
+pub use self::localize::RECEIPT_FLAG_LANGUAGE;
+
 #[allow(unused_imports)]
 pub use self::handle::OrchestratorHandle;
 
@@ -20,12 +36,13 @@ pub use self::phase_exec::ExecutionResult;
 pub(crate) use self::workflow::{PhaseExecution, PhaseExecutionResult, WorkflowResult};
 
 use anyhow::{Context, Result};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::time::Duration;
 
 use crate::config::Selectors;
-use crate::error::{PhaseError, XCheckerError};
+use crate::error::{ConfigError, PhaseError, XCheckerError};
 use crate::hooks::HooksConfig;
+use crate::llm::LlmBackend;
 use crate::receipt::ReceiptManager;
 use crate::status::artifact::ArtifactManager;
 use crate::types::PhaseId;
@@ -110,6 +127,51 @@ pub struct OrchestratorConfig {
     pub redactor: Arc<crate::redaction::SecretRedactor>,
     /// Hooks configuration for pre/post phase scripts.
     pub hooks: Option<HooksConfig>,
+    /// LLM backend used instead of the configured provider.
+    ///
+    /// Lets embedders and tests (e.g. with `FakeLlmClient` from the
+    /// `test-utils` feature) drive phases without spawning provider processes.
+    /// Provider fallback is not applied to an injected backend.
+    pub llm_backend: Option<LlmBackendOverride>,
+}
+
+impl OrchestratorConfig {
+    /// Effective configuration as dotted keys, with values redacted
+    ///
+    /// Uses the full configuration when available and falls back to the
+    /// flattened config map otherwise.
+    #[must_use]
+    pub fn effective_config_snapshot(&self) -> BTreeMap<String, String> {
+        let values: BTreeMap<String, String> = match &self.full_config {
+            Some(full_config) => full_config.flattened(),
+            None => self
+                .config
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        };
+        values
+            .into_iter()
+            .map(|(key, value)| (key, self.redactor.redact_string(&value)))
+            .collect()
+    }
+
+    /// Use `backend` for every LLM invocation instead of the configured provider
+    #[must_use]
+    pub fn with_llm_backend(mut self, backend: Arc<dyn LlmBackend>) -> Self {
+        self.llm_backend = Some(LlmBackendOverride(backend));
+        self
+    }
+}
+
+/// Shared LLM backend injected through [`OrchestratorConfig::llm_backend`]
+#[derive(Clone)]
+pub struct LlmBackendOverride(pub Arc<dyn LlmBackend>);
+
+impl std::fmt::Debug for LlmBackendOverride {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LlmBackendOverride(..)")
+    }
 }
 
 /// Phase timeout configuration with sensible defaults.
@@ -212,16 +274,7 @@ impl PhaseOrchestrator {
     /// Check if we can resume from a specific phase
     fn can_resume_from_phase(&self, phase_id: PhaseId) -> Result<bool> {
         // Check dependencies are satisfied
-        let deps = match phase_id {
-            PhaseId::Requirements => &[][..],
-            PhaseId::Design => &[PhaseId::Requirements][..],
-            PhaseId::Tasks => &[PhaseId::Design][..],
-            PhaseId::Review => &[PhaseId::Tasks][..],
-            PhaseId::Fixup => &[PhaseId::Review][..],
-            PhaseId::Final => &[PhaseId::Tasks][..], // Can skip review/fixup
-        };
-
-        for dep_phase in deps {
+        for dep_phase in graph::phase_deps(phase_id) {
             if !self.artifact_manager.phase_completed(*dep_phase) {
                 return Ok(false);
             }
@@ -281,7 +334,8 @@ impl PhaseOrchestrator {
             Some(PhaseId::Tasks) => vec![PhaseId::Tasks, PhaseId::Review, PhaseId::Final],
             Some(PhaseId::Review) => vec![PhaseId::Review, PhaseId::Fixup, PhaseId::Final],
             Some(PhaseId::Fixup) => vec![PhaseId::Fixup, PhaseId::Final],
-            Some(PhaseId::Final) => vec![PhaseId::Final], // Can re-run final
+            // Can re-run final, and re-localize after it
+            Some(PhaseId::Final | PhaseId::Localize) => vec![PhaseId::Final, PhaseId::Localize],
         };
 
         // Check if target phase is in the list of legal next phases
@@ -307,6 +361,7 @@ impl PhaseOrchestrator {
     fn get_current_phase(&self) -> Result<Option<PhaseId>> {
         // Check each phase in reverse order to find the last completed one
         let phases = [
+            PhaseId::Localize,
             PhaseId::Final,
             PhaseId::Fixup,
             PhaseId::Review,
@@ -328,16 +383,7 @@ impl PhaseOrchestrator {
 
     /// Check that all dependencies for a phase are satisfied
     fn check_dependencies_satisfied(&self, phase_id: PhaseId) -> Result<(), XCheckerError> {
-        let deps = match phase_id {
-            PhaseId::Requirements => &[][..],
-            PhaseId::Design => &[PhaseId::Requirements][..],
-            PhaseId::Tasks => &[PhaseId::Design][..],
-            PhaseId::Review => &[PhaseId::Tasks][..],
-            PhaseId::Fixup => &[PhaseId::Review][..],
-            PhaseId::Final => &[PhaseId::Tasks][..], // Can skip review/fixup
-        };
-
-        for dep_phase in deps {
+        for dep_phase in graph::phase_deps(phase_id) {
             // Check if we have a successful receipt for the dependency
             let receipt_result = self
                 .receipt_manager
@@ -354,7 +400,8 @@ impl PhaseOrchestrator {
                 })?;
 
             if let Some(receipt) = receipt_result {
-                if receipt.exit_code != 0 {
+                // A skipped dependency has no artifacts to build on
+                if receipt.exit_code != 0 || receipt.is_skipped() {
                     return Err(XCheckerError::Phase(PhaseError::DependencyNotSatisfied {
                         phase: phase_id.as_str().to_string(),
                         dependency: dep_phase.as_str().to_string(),
@@ -371,6 +418,60 @@ impl PhaseOrchestrator {
         Ok(())
     }
 
+    /// Validate that a phase may be recorded as skipped.
+    ///
+    /// Only optional phases can be skipped, and only once each of their
+    /// dependencies has a successful or skipped receipt (e.g. skipping fixup
+    /// after a skipped review). Both rules come from the phase graph.
+    fn validate_skip(&self, phase_id: PhaseId) -> Result<(), XCheckerError> {
+        if !graph::is_optional_phase(phase_id) {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "skip_phase".to_string(),
+                value: format!(
+                    "'{}' cannot be skipped; only optional phases ({}) can be skipped",
+                    phase_id.as_str(),
+                    graph::optional_phase_names().join(", ")
+                ),
+            }));
+        }
+
+        let current_phase = self.get_current_phase().map_err(|e| {
+            XCheckerError::Phase(PhaseError::ContextCreationFailed {
+                phase: phase_id.as_str().to_string(),
+                reason: format!("Failed to determine current phase: {e}"),
+            })
+        })?;
+
+        for dep_phase in graph::phase_deps(phase_id) {
+            // Skipped receipts carry exit code 0, so they count as reached here
+            let reached = self
+                .receipt_manager
+                .read_latest_receipt(*dep_phase)
+                .map_err(|e| {
+                    XCheckerError::Phase(PhaseError::ContextCreationFailed {
+                        phase: phase_id.as_str().to_string(),
+                        reason: format!(
+                            "Failed to read receipt for dependency {}: {}",
+                            dep_phase.as_str(),
+                            e
+                        ),
+                    })
+                })?
+                .is_some_and(|receipt| receipt.exit_code == 0);
+            if !reached {
+                return Err(XCheckerError::Phase(PhaseError::InvalidTransition {
+                    from: current_phase.map_or_else(
+                        || "none (fresh spec)".to_string(),
+                        |p| p.as_str().to_string(),
+                    ),
+                    to: phase_id.as_str().to_string(),
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get the spec ID.
     ///
     /// Returns the identifier for the spec managed by this orchestrator.
@@ -571,6 +672,7 @@ mod tests {
             strict_validation: false,
             redactor: std::sync::Arc::new(crate::redaction::SecretRedactor::default()),
             hooks: None,
+            llm_backend: None,
         };
 
         let result = orchestrator.execute_requirements_phase(&config).await;
@@ -588,6 +690,763 @@ mod tests {
         assert!(execution_result.error.is_none());
     }
 
+    fn fake_backend_config(fake: &Arc<crate::llm::FakeLlmClient>) -> OrchestratorConfig {
+        OrchestratorConfig {
+            redactor: std::sync::Arc::new(crate::redaction::SecretRedactor::default()),
+            ..Default::default()
+        }
+        .with_llm_backend(fake.clone())
+    }
+
+    #[tokio::test]
+    async fn test_fake_llm_backend_records_receipt_metadata() {
+        let (orchestrator, _guard) = setup_test_with_guard("fake-llm");
+        let response = orchestrator.simulate_claude_response(PhaseId::Requirements, "");
+        let fake = Arc::new(crate::llm::FakeLlmClient::new().with_response(response));
+
+        let result = orchestrator
+            .execute_requirements_phase(&fake_backend_config(&fake))
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert_eq!(fake.invocations().len(), 1);
+        assert_eq!(fake.remaining(), 0);
+
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        let llm = receipt.llm.expect("receipt should record LLM metadata");
+        assert_eq!(llm.provider.as_deref(), Some(crate::llm::FAKE_PROVIDER));
+        assert_eq!(llm.model_used.as_deref(), Some(crate::llm::FAKE_MODEL));
+    }
+
+    #[tokio::test]
+    async fn test_invisible_response_characters_do_not_change_hashes() {
+        let (orchestrator, _guard) = setup_test_with_guard("sanitize-response");
+        let clean = orchestrator.simulate_claude_response(PhaseId::Requirements, "");
+        let noisy = format!(
+            "\u{FEFF}{}",
+            clean.replace('\n', "\r\n").replacen(' ', " \u{200B}", 3)
+        );
+
+        let mut hashes = Vec::new();
+        for response in [clean, noisy] {
+            let fake = Arc::new(crate::llm::FakeLlmClient::new().with_response(response));
+            let result = orchestrator
+                .execute_requirements_phase(&fake_backend_config(&fake))
+                .await
+                .unwrap();
+            assert!(result.success);
+            let receipt = orchestrator
+                .receipt_manager()
+                .read_latest_receipt(PhaseId::Requirements)
+                .unwrap()
+                .unwrap();
+            let artifact = std::fs::read(
+                orchestrator
+                    .artifact_manager()
+                    .artifacts_path()
+                    .join("00-requirements.md"),
+            )
+            .unwrap();
+            hashes.push((
+                (
+                    receipt
+                        .outputs
+                        .iter()
+                        .find(|o| o.path == "artifacts/00-requirements.md")
+                        .map(|o| o.blake3_canonicalized.clone()),
+                    blake3::hash(&artifact).to_hex().to_string(),
+                ),
+                receipt
+                    .warnings
+                    .iter()
+                    .find(|w| w.starts_with("response_sanitized:"))
+                    .cloned(),
+            ));
+            // Receipt names have one-second resolution
+            tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+        }
+
+        assert_eq!(hashes[0].0, hashes[1].0);
+        assert_eq!(hashes[0].1, None);
+        let warning = hashes[1].1.as_deref().expect("sanitation warning");
+        assert!(
+            warning.contains("removed 3 zero-width character(s)"),
+            "{warning}"
+        );
+        assert!(
+            warning.contains("removed 1 byte order mark(s)"),
+            "{warning}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_phase_run_writes_event_log() {
+        use crate::receipt::events::{EventLog, RunEvent, read_events};
+
+        let (orchestrator, _guard) = setup_test_with_guard("event-log");
+        let response = orchestrator.simulate_claude_response(PhaseId::Requirements, "");
+        let fake = Arc::new(crate::llm::FakeLlmClient::new().with_response(response));
+
+        let result = orchestrator
+            .execute_requirements_phase(&fake_backend_config(&fake))
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        let run_id = receipt.flags.get("run_id").expect("run_id flag");
+        let events = read_events(&EventLog::path(
+            orchestrator.receipt_manager().receipts_path(),
+            run_id,
+        ))
+        .unwrap();
+
+        let names: Vec<String> = events
+            .iter()
+            .map(|e| serde_json::to_value(&e.event).unwrap()["event"].to_string())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "\"phase_started\"",
+                "\"packet_built\"",
+                "\"packet_scanned\"",
+                "\"llm_started\"",
+                "\"llm_finished\"",
+                "\"artifacts_promoted\"",
+                "\"phase_finished\"",
+            ]
+        );
+        let receipt_name = result.receipt_path.unwrap();
+        let receipt_name = receipt_name.file_name().unwrap().to_str().unwrap();
+        assert_eq!(
+            events.last().unwrap().event,
+            RunEvent::PhaseFinished {
+                success: true,
+                exit_code: Some(0),
+                receipt: Some(format!("receipts/{receipt_name}")),
+                error: None,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_front_matter_prepended_and_hashed_separately() {
+        let (orchestrator, _guard) = setup_test_with_guard("front-matter");
+        let response = orchestrator.simulate_claude_response(PhaseId::Requirements, "");
+        let fake = Arc::new(crate::llm::FakeLlmClient::new().with_response(response));
+        let mut config = fake_backend_config(&fake);
+        config
+            .config
+            .insert("output_front_matter".to_string(), "true".to_string());
+
+        let result = orchestrator
+            .execute_requirements_phase(&config)
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let receipt_path = result.receipt_path.expect("receipt written");
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        let run_id = receipt.flags.get("run_id").expect("run_id flag").clone();
+
+        let content = std::fs::read_to_string(
+            orchestrator
+                .artifact_manager()
+                .artifacts_path()
+                .join("00-requirements.md"),
+        )
+        .unwrap();
+        let (front_matter, body) = crate::canonicalization::split_front_matter(&content);
+        let front_matter = front_matter.expect("front matter present");
+        assert!(front_matter.contains(&format!("run_id: \"{run_id}\"")));
+        assert!(front_matter.contains(&format!("spec_id: \"{}\"", orchestrator.spec_id())));
+        let receipt_name = receipt_path.file_name().unwrap().to_str().unwrap();
+        assert!(front_matter.contains(&format!("receipt: \"receipts/{receipt_name}\"")));
+
+        let output = receipt
+            .outputs
+            .iter()
+            .find(|o| o.path == "artifacts/00-requirements.md")
+            .unwrap();
+        let body_hash = crate::canonicalization::Canonicalizer::new()
+            .hash_canonicalized(body, crate::types::FileType::Markdown)
+            .unwrap();
+        assert_eq!(output.blake3_canonicalized, body_hash);
+        assert!(front_matter.contains(&format!("body_blake3: \"{body_hash}\"")));
+        assert!(output.blake3_front_matter.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_prompt_only_writes_prompt_without_invoking_provider() {
+        let (orchestrator, _guard) = setup_test_with_guard("prompt-only");
+        let fake = Arc::new(crate::llm::FakeLlmClient::new());
+        let mut config = fake_backend_config(&fake);
+        config
+            .config
+            .insert("prompt_only".to_string(), "true".to_string());
+
+        let result = orchestrator
+            .execute_requirements_phase(&config)
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert!(result.receipt_path.is_none());
+        assert!(fake.invocations().is_empty());
+        assert_eq!(result.artifact_paths.len(), 1);
+
+        let prompt_path = &result.artifact_paths[0];
+        assert!(prompt_path.ends_with("context/requirements-prompt.txt"));
+        let prompt = std::fs::read_to_string(prompt_path).unwrap();
+        assert!(prompt.starts_with("=== user ===\n"));
+        assert!(
+            orchestrator
+                .receipt_manager()
+                .read_latest_receipt(PhaseId::Requirements)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fsync_policy_recorded_in_receipt() {
+        let (orchestrator, _guard) = setup_test_with_guard("fsync-batch");
+        let response = orchestrator.simulate_claude_response(PhaseId::Requirements, "");
+        let fake = Arc::new(crate::llm::FakeLlmClient::new().with_response(response));
+        let mut config = fake_backend_config(&fake);
+        config
+            .config
+            .insert("fsync".to_string(), "batch".to_string());
+
+        let result = orchestrator.execute_requirements_phase(&config).await;
+        crate::atomic_write::set_fsync_policy(crate::atomic_write::FsyncPolicy::Always);
+        assert!(result.unwrap().success);
+
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            receipt.flags.get("fsync").map(String::as_str),
+            Some("batch")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stale_artifact_numbering_blocks_phase() {
+        let (orchestrator, _guard) = setup_test_with_guard("stale-numbering");
+        let fake = Arc::new(crate::llm::FakeLlmClient::new());
+        let config = fake_backend_config(&fake);
+        let artifacts = orchestrator.artifact_manager().artifacts_path();
+        std::fs::write(artifacts.join("05-requirements.md"), "# Old\n").unwrap();
+
+        let err = orchestrator
+            .execute_requirements_phase(&config)
+            .await
+            .unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("05-requirements.md -> 00-requirements.md"));
+        assert!(message.contains("migrate-artifacts"));
+        assert!(fake.invocations().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_kill_switch_halts_phase_with_operator_halt_receipt() {
+        let (orchestrator, _guard) = setup_test_with_guard("kill-switch");
+        let fake = Arc::new(crate::llm::FakeLlmClient::new());
+        std::fs::write(crate::paths::xchecker_home().join("KILL"), "").unwrap();
+
+        let result = orchestrator
+            .execute_requirements_phase(&fake_backend_config(&fake))
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert_eq!(result.exit_code, 11);
+        assert!(fake.invocations().is_empty());
+
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        assert_eq!(receipt.exit_code, 11);
+        assert_eq!(
+            receipt.error_kind,
+            Some(crate::types::ErrorKind::OperatorHalt)
+        );
+        assert!(
+            receipt
+                .warnings
+                .iter()
+                .any(|w| w.starts_with("operator_halt:"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_model_alias_resolved_and_deprecation_recorded() {
+        let (orchestrator, _guard) = setup_test_with_guard("fake-llm-alias");
+        let response = orchestrator.simulate_claude_response(PhaseId::Requirements, "");
+        let fake = Arc::new(crate::llm::FakeLlmClient::new().with_response(response));
+        let mut config = fake_backend_config(&fake);
+        config
+            .config
+            .insert("model".to_string(), "claude-3-5-sonnet-latest".to_string());
+
+        let result = orchestrator
+            .execute_requirements_phase(&config)
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let invocation = &fake.invocations()[0];
+        assert_eq!(invocation.model, "claude-3-5-sonnet-20241022");
+
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            receipt.model_alias.as_deref(),
+            Some("claude-3-5-sonnet-latest")
+        );
+        assert!(
+            receipt
+                .warnings
+                .iter()
+                .any(|w| w.starts_with("model_deprecated:")),
+            "{:?}",
+            receipt.warnings
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generation_params_threaded_and_recorded() {
+        let (orchestrator, _guard) = setup_test_with_guard("fake-llm-generation");
+        let response = orchestrator.simulate_claude_response(PhaseId::Requirements, "");
+        let fake = Arc::new(crate::llm::FakeLlmClient::new().with_response(response));
+        let mut config = fake_backend_config(&fake);
+        for (key, value) in [
+            ("phases.requirements.temperature", "0.3"),
+            ("phases.requirements.max_output_tokens", "4096"),
+            ("phases.requirements.stop_sequences", r#"["END"]"#),
+        ] {
+            config.config.insert(key.to_string(), value.to_string());
+        }
+
+        let result = orchestrator
+            .execute_requirements_phase(&config)
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        // claude-cli (the default provider) has no stop sequence support
+        let invocation = &fake.invocations()[0];
+        assert_eq!(invocation.metadata["temperature"], serde_json::json!(0.3));
+        assert_eq!(invocation.metadata["max_tokens"], serde_json::json!(4096));
+        assert!(!invocation.metadata.contains_key("stop_sequences"));
+
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        assert_eq!(receipt.flags["temperature"], "0.3");
+        assert_eq!(receipt.flags["max_output_tokens"], "4096");
+        assert!(!receipt.flags.contains_key("stop_sequences"));
+        assert!(
+            receipt.warnings.contains(
+                &"generation_param_ignored: provider 'claude-cli' does not support stop_sequences"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_estimate_prices_planned_phases_without_running_them() {
+        let (orchestrator, _guard) = setup_test_with_guard("estimate");
+        let mut full_config = crate::config::Config::minimal_for_testing();
+        full_config.defaults.model = Some("haiku".to_string());
+        full_config.pricing.models.insert(
+            "haiku".to_string(),
+            crate::config::ModelPrice {
+                input_per_mtok: 1.0,
+                output_per_mtok: 5.0,
+            },
+        );
+        let mut config = OrchestratorConfig {
+            full_config: Some(full_config),
+            redactor: Arc::new(crate::redaction::SecretRedactor::default()),
+            ..Default::default()
+        };
+        config.config.insert(
+            "phases.design.max_output_tokens".to_string(),
+            "2000".to_string(),
+        );
+
+        let estimate = orchestrator
+            .estimate_phases(&[PhaseId::Requirements, PhaseId::Design], &config)
+            .unwrap();
+        let [requirements, design] = estimate.phases.as_slice() else {
+            panic!("expected two phases: {:?}", estimate.phases);
+        };
+        assert_eq!(requirements.output_tokens, DEFAULT_OUTPUT_TOKENS);
+        assert_eq!(requirements.output_source, OutputEstimateSource::Default);
+        assert_eq!(design.output_tokens, 2000);
+        assert_eq!(design.output_source, OutputEstimateSource::MaxOutputTokens);
+        // Requirements doesn't exist yet, so design reads its estimated output
+        assert!(design.input_tokens > DEFAULT_OUTPUT_TOKENS);
+
+        let expected = requirements.cost.unwrap() + design.cost.unwrap();
+        assert!((estimate.cost - expected).abs() < 1e-12);
+        assert!(estimate.unpriced_models.is_empty());
+        assert!(
+            orchestrator
+                .receipt_manager()
+                .list_receipts()
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_localize_estimates_one_run_per_language() {
+        let (orchestrator, _guard) = setup_test_with_guard("estimate-localize");
+        let mut config = OrchestratorConfig {
+            full_config: Some(crate::config::Config::minimal_for_testing()),
+            redactor: Arc::new(crate::redaction::SecretRedactor::default()),
+            ..Default::default()
+        };
+        config
+            .config
+            .insert("final_languages".to_string(), "de,fr".to_string());
+
+        let estimate = orchestrator
+            .estimate_phases(&[PhaseId::Localize], &config)
+            .unwrap();
+        assert_eq!(estimate.phases.len(), 2);
+        assert!(
+            estimate
+                .phases
+                .iter()
+                .all(|phase| phase.phase == PhaseId::Localize)
+        );
+
+        // Without languages there is nothing to localize
+        config.config.remove("final_languages");
+        let estimate = orchestrator
+            .estimate_phases(&[PhaseId::Localize], &config)
+            .unwrap();
+        assert!(estimate.phases.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_context_overflow_retries_with_shrunk_packet() {
+        let (orchestrator, _guard) = setup_test_with_guard("fake-llm-overflow");
+        let response = orchestrator.simulate_claude_response(PhaseId::Requirements, "");
+        let fake = Arc::new(
+            crate::llm::FakeLlmClient::new()
+                .with_error(crate::llm::LlmError::ContextOverflow(
+                    "prompt is too long".to_string(),
+                ))
+                .with_response(response),
+        );
+
+        let result = orchestrator
+            .execute_requirements_phase(&fake_backend_config(&fake))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(fake.invocations().len(), 2);
+
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        let attempts = &receipt.packet.attempts;
+        assert_eq!(attempts.len(), 2);
+        assert!(attempts[0].context_overflow);
+        assert!(!attempts[1].context_overflow);
+        assert!(attempts[1].max_bytes < attempts[0].max_bytes);
+        assert_eq!(receipt.packet.max_bytes, attempts[1].max_bytes);
+    }
+
+    #[tokio::test]
+    async fn test_context_overflow_fails_after_retry_budget() {
+        let (orchestrator, _guard) = setup_test_with_guard("fake-llm-overflow-exhausted");
+        let overflow = || crate::llm::LlmError::ContextOverflow("prompt is too long".to_string());
+        let fake = Arc::new(
+            crate::llm::FakeLlmClient::new()
+                .with_error(overflow())
+                .with_error(overflow()),
+        );
+        let mut config = fake_backend_config(&fake);
+        config
+            .config
+            .insert("packet_overflow_retries".to_string(), "1".to_string());
+
+        let result = orchestrator
+            .execute_requirements_phase(&config)
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert_eq!(result.exit_code, crate::exit_codes::codes::PACKET_OVERFLOW);
+
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        assert_eq!(receipt.packet.attempts.len(), 2);
+        assert!(receipt.packet.attempts.iter().all(|a| a.context_overflow));
+    }
+
+    #[tokio::test]
+    async fn test_fake_llm_backend_retries_invalid_response() {
+        let (orchestrator, _guard) = setup_test_with_guard("fake-llm-retry");
+        let response = orchestrator.simulate_claude_response(PhaseId::Requirements, "");
+        let fake = Arc::new(
+            crate::llm::FakeLlmClient::new()
+                .with_response("too short")
+                .with_response(response),
+        );
+
+        let result = orchestrator
+            .execute_requirements_phase(&fake_backend_config(&fake))
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert_eq!(fake.invocations().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fake_llm_backend_provider_failure_writes_partial() {
+        let (orchestrator, _guard) = setup_test_with_guard("fake-llm-partial");
+        let fake = Arc::new(crate::llm::FakeLlmClient::new().with_exit_code(
+            "# Requirements Document\n\nIntro.\n\n## Requirements\n\n### Requirement 1\n\nHalf",
+            1,
+        ));
+
+        let result = orchestrator
+            .execute_requirements_phase(&fake_backend_config(&fake))
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert_ne!(result.exit_code, 0);
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        assert_ne!(receipt.exit_code, 0);
+        assert_eq!(
+            receipt.flags.get("provider_failure").map(String::as_str),
+            Some("provider_error")
+        );
+
+        // The partial is trimmed to its last complete section and annotated
+        let partial = orchestrator
+            .artifact_manager()
+            .read_partial_artifact(PhaseId::Requirements)
+            .unwrap();
+        assert!(partial.contains("## Requirements"));
+        assert!(!partial.contains("Requirement 1"));
+        assert!(partial.contains(xchecker_extraction::TRUNCATION_MARKER));
+    }
+
+    #[tokio::test]
+    async fn test_fake_llm_backend_restarts_crashed_provider() {
+        let (orchestrator, _guard) = setup_test_with_guard("fake-llm-crash");
+        let response = orchestrator.simulate_claude_response(PhaseId::Requirements, "");
+        let fake = Arc::new(
+            crate::llm::FakeLlmClient::new()
+                .with_exit_code("", 139)
+                .with_response(response),
+        );
+
+        let result = orchestrator
+            .execute_requirements_phase(&fake_backend_config(&fake))
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert_eq!(fake.invocations().len(), 2);
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        assert!(
+            receipt
+                .warnings
+                .contains(&"provider_restarts:1".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fake_llm_backend_crash_after_restarts_is_classified() {
+        let (orchestrator, _guard) = setup_test_with_guard("fake-llm-crash-loop");
+        let fake = Arc::new(
+            crate::llm::FakeLlmClient::new()
+                .with_exit_code("", 139)
+                .with_exit_code("", 139),
+        );
+
+        let result = orchestrator
+            .execute_requirements_phase(&fake_backend_config(&fake))
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert_eq!(fake.invocations().len(), 2);
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            receipt.flags.get("provider_failure").map(String::as_str),
+            Some("provider_crash")
+        );
+        assert!(
+            receipt
+                .warnings
+                .contains(&"provider_restarts:1".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compliance_block_prevents_llm_invocation() {
+        let (orchestrator, _guard) = setup_test_with_guard("compliance-block");
+        std::fs::write(
+            crate::paths::spec_root(orchestrator.spec_id()).join("notes.md"),
+            "# Notes\n\nINTERNAL USE ONLY\n",
+        )
+        .unwrap();
+        let fake = Arc::new(crate::llm::FakeLlmClient::new());
+        let mut config = fake_backend_config(&fake);
+        config
+            .config
+            .insert("compliance_mode".to_string(), "block".to_string());
+
+        let result = orchestrator
+            .execute_requirements_phase(&config)
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert_eq!(
+            result.exit_code,
+            crate::exit_codes::codes::COMPLIANCE_BLOCKED
+        );
+        assert!(fake.invocations().is_empty());
+
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            receipt.error_kind,
+            Some(crate::types::ErrorKind::ComplianceBlocked)
+        );
+        assert!(!receipt.packet.compliance.is_empty());
+        let finding = &receipt.packet.compliance[0];
+        assert!(finding.path.ends_with("notes.md"));
+        assert_eq!(finding.pattern_id, "distribution_marker");
+        assert_eq!(finding.line, 3);
+    }
+
+    #[tokio::test]
+    async fn test_compliance_warn_records_findings_and_proceeds() {
+        let (orchestrator, _guard) = setup_test_with_guard("compliance-warn");
+        std::fs::write(
+            crate::paths::spec_root(orchestrator.spec_id()).join("notes.md"),
+            "# Notes\n\nINTERNAL USE ONLY\n",
+        )
+        .unwrap();
+        let response = orchestrator.simulate_claude_response(PhaseId::Requirements, "");
+        let fake = Arc::new(crate::llm::FakeLlmClient::new().with_response(response));
+        let mut config = fake_backend_config(&fake);
+        config
+            .config
+            .insert("compliance_mode".to_string(), "warn".to_string());
+
+        let result = orchestrator
+            .execute_requirements_phase(&config)
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        assert!(!receipt.packet.compliance.is_empty());
+        assert!(
+            receipt
+                .warnings
+                .iter()
+                .any(|w| w.starts_with("compliance: "))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_receipt_records_redacted_config_snapshot() {
+        let (orchestrator, _guard) = setup_test_with_guard("config-snapshot");
+        let response = orchestrator.simulate_claude_response(PhaseId::Requirements, "");
+        let fake = Arc::new(crate::llm::FakeLlmClient::new().with_response(response));
+        let mut config = fake_backend_config(&fake);
+        let token = format!("ghp_{}", "a".repeat(36));
+        config
+            .config
+            .insert("note".to_string(), format!("token {token}"));
+        config.config.insert(
+            "output_embed_config_snapshot".to_string(),
+            "true".to_string(),
+        );
+
+        let result = orchestrator
+            .execute_requirements_phase(&config)
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Requirements)
+            .unwrap()
+            .unwrap();
+        let snapshot = receipt.config_snapshot.expect("snapshot embedded");
+        assert!(!snapshot["note"].contains(&token));
+        assert_eq!(
+            receipt.config_hash,
+            Some(crate::receipt::config_snapshot_hash(&snapshot).unwrap())
+        );
+        assert_eq!(snapshot, config.effective_config_snapshot());
+    }
+
     #[test]
     fn test_phase_context_creation() {
         // Test phase context structure without file system operations
@@ -716,6 +1575,7 @@ This is a generated requirements document for spec {}. The system will provide c
                     files: vec![],
                     max_bytes: 65536,
                     max_lines: 1200,
+                    ..Default::default()
                 };
 
                 let mut budget = xchecker_packet::BudgetUsage::new(65536, 1200);
@@ -812,6 +1672,7 @@ This is a generated requirements document for spec {}. The system will provide c
                     ],
                     max_bytes: 65536,
                     max_lines: 1200,
+                    ..Default::default()
                 };
 
                 let mut budget = xchecker_packet::BudgetUsage::new(65536, 1200);
@@ -859,6 +1720,7 @@ This is a generated requirements document for spec {}. The system will provide c
             strict_validation: false,
             redactor: std::sync::Arc::new(crate::redaction::SecretRedactor::default()),
             hooks: None,
+            llm_backend: None,
         };
 
         // Execute the phase
@@ -894,4 +1756,93 @@ This is a generated requirements document for spec {}. The system will provide c
         assert_eq!(second_file["range"], "L1-L50");
         assert_eq!(second_file["blake3_pre_redaction"], "def456");
     }
+
+    #[test]
+    fn test_context_file_content_redacted_unless_raw() {
+        let (orchestrator, _guard) = setup_test_with_guard("context-redaction");
+        let token = format!("ghp_{}", "a".repeat(36));
+        let content = format!("token = {token}");
+
+        let mut config = OrchestratorConfig {
+            dry_run: true,
+            config: HashMap::new(),
+            full_config: None,
+            selectors: None,
+            strict_validation: false,
+            redactor: std::sync::Arc::new(crate::redaction::SecretRedactor::default()),
+            hooks: None,
+            llm_backend: None,
+        };
+
+        let (redacted, was_redacted) = orchestrator
+            .context_file_content(&content, &config)
+            .unwrap();
+        assert!(was_redacted);
+        assert!(!redacted.contains(&token));
+
+        config
+            .config
+            .insert("raw_context".to_string(), "true".to_string());
+        let (raw, was_redacted) = orchestrator
+            .context_file_content(&content, &config)
+            .unwrap();
+        assert!(!was_redacted);
+        assert_eq!(raw, content);
+    }
+
+    #[tokio::test]
+    async fn test_skip_and_force_phase() {
+        let (orchestrator, _guard) = setup_test_with_guard("skip-force");
+
+        let config = OrchestratorConfig {
+            dry_run: true,
+            config: HashMap::new(),
+            full_config: None,
+            selectors: None,
+            strict_validation: false,
+            redactor: std::sync::Arc::new(crate::redaction::SecretRedactor::default()),
+            hooks: None,
+            llm_backend: None,
+        };
+
+        // Only Review and Fixup can be skipped, and only once reachable
+        assert!(orchestrator.skip_phase(PhaseId::Review, &config).is_err());
+        assert!(orchestrator.skip_phase(PhaseId::Tasks, &config).is_err());
+
+        orchestrator
+            .execute_requirements_phase(&config)
+            .await
+            .unwrap();
+        orchestrator.execute_design_phase(&config).await.unwrap();
+        orchestrator.execute_tasks_phase(&config).await.unwrap();
+
+        let skipped = orchestrator.skip_phase(PhaseId::Review, &config).unwrap();
+        assert!(skipped.success);
+        assert!(skipped.artifact_paths.is_empty());
+        let receipt = orchestrator
+            .receipt_manager()
+            .read_latest_receipt(PhaseId::Review)
+            .unwrap()
+            .unwrap();
+        assert!(receipt.is_skipped());
+
+        // A skipped phase does not satisfy dependencies of later phases
+        let err = orchestrator
+            .resume_from_phase(PhaseId::Fixup, &config)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().to_lowercase().contains("review"));
+
+        // A skipped dependency still lets the next optional phase be skipped
+        let skipped = orchestrator.skip_phase(PhaseId::Fixup, &config).unwrap();
+        assert!(skipped.success);
+
+        // Forcing re-runs an earlier phase out of order
+        let forced = orchestrator
+            .force_phase(PhaseId::Tasks, &config)
+            .await
+            .unwrap();
+        assert!(forced.success);
+        assert_eq!(forced.phase, PhaseId::Tasks);
+    }
 }
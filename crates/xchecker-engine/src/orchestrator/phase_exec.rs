@@ -7,18 +7,29 @@ use std::path::PathBuf;
 
 use anyhow::{Context, Result};
 
+use crate::atomic_write::FsyncPolicy;
 use crate::error::{PhaseError, XCheckerError};
 use crate::exit_codes;
 use crate::fixup::{FixupMode, FixupPhase};
 use crate::hooks::{HookContext, HookExecutor, HookType, execute_and_process_hook};
-use crate::packet::PacketBuilder;
+use crate::lock::{DEFAULT_REPO_LOCK_WAIT, HeartbeatWriter, RepoLock};
+use crate::packet::{ComplianceMode, ComplianceScanner, Packet, PacketBuilder};
 use crate::phase::{Phase, PhaseContext};
-use crate::phases::{DesignPhase, RequirementsPhase, ReviewPhase, TasksPhase};
-use crate::status::artifact::{Artifact, ArtifactType};
-use crate::types::{ErrorKind, FileType, LlmInfo, PacketEvidence, PhaseId, PipelineInfo};
-
-use super::llm::{ClaudeExecutionMetadata, LlmInvocationError};
-use super::{OrchestratorConfig, PhaseOrchestrator, PhaseTimeout};
+use crate::phases::review_diff::{self, ReviewBaseline};
+use crate::phases::{DesignPhase, FinalPhase, RequirementsPhase, ReviewPhase, TasksPhase};
+use crate::receipt::events::{EventLog, RunEvent};
+use crate::status::artifact::{Artifact, ArtifactFrontMatter, ArtifactType, ContextRetention};
+use crate::types::{
+    ComplianceFinding, ErrorKind, FileType, InputConversion, LlmInfo, PacketEvidence, PhaseId,
+    PipelineInfo, RECEIPT_FLAG_SKIPPED, Receipt,
+};
+
+use super::idempotency::{self, RECEIPT_FLAG_IDEMPOTENCY_KEY};
+use super::llm::{
+    ClaudeExecutionMetadata, LlmInvocationError, PROVIDER_RESTARTS_EXTENSION, provider_failure,
+};
+use super::localize::{self, RECEIPT_FLAG_LANGUAGE};
+use super::{FailurePoint, OrchestratorConfig, PhaseOrchestrator, PhaseTimeout};
 
 /// Result of executing a phase through the orchestrator.
 ///
@@ -155,6 +166,21 @@ pub(crate) struct PhaseCoreOutput {
     pub llm_fallback_warning: Option<String>,
     /// Postprocessed artifacts with parsed content from LLM response
     pub phase_result: xchecker_phase_api::PhaseResult,
+    /// Whether context files written for this phase were redacted
+    pub context_redacted: bool,
+}
+
+/// How often a running phase checks for the kill switch file
+const KILL_SWITCH_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Resolve once the kill switch file appears, with its path
+async fn wait_for_kill_switch() -> camino::Utf8PathBuf {
+    loop {
+        if let Some(path) = crate::paths::kill_switch_engaged() {
+            return path;
+        }
+        tokio::time::sleep(KILL_SWITCH_POLL_INTERVAL).await;
+    }
 }
 
 /// Execute a phase with timeout enforcement
@@ -286,14 +312,128 @@ impl PhaseOrchestrator {
         phase_id: PhaseId,
         config: &OrchestratorConfig,
     ) -> Result<ExecutionResult> {
+        // A retry with a completed run's idempotency key reuses its result
+        if let Some(result) = self.replay_idempotent(phase_id, config)? {
+            return Ok(result);
+        }
+
         // Validate transition before execution (FR-ORC-001, FR-ORC-002)
         self.validate_transition(phase_id)?;
 
+        let result = self.execute_phase_by_id(phase_id, config).await;
+        self.run_output_sinks(config);
+        result
+    }
+
+    /// Re-run a phase even if it or later phases already completed.
+    ///
+    /// Skips the phase-sequence check (so e.g. `tasks` can be re-run after
+    /// `review`) but still requires the phase's dependencies to be satisfied.
+    /// Existing artifacts for the phase are replaced.
+    ///
+    /// # Errors
+    /// Returns error if dependencies are not satisfied or execution fails.
+    pub async fn force_phase(
+        &self,
+        phase_id: PhaseId,
+        config: &OrchestratorConfig,
+    ) -> Result<ExecutionResult> {
+        if let Some(result) = self.replay_idempotent(phase_id, config)? {
+            return Ok(result);
+        }
+
+        self.check_dependencies_satisfied(phase_id)?;
+
+        let result = self.execute_phase_by_id(phase_id, config).await;
+        self.run_output_sinks(config);
+        result
+    }
+
+    /// Execute a phase by ID; Localize runs once per `[final] languages` entry
+    async fn execute_phase_by_id(
+        &self,
+        phase_id: PhaseId,
+        config: &OrchestratorConfig,
+    ) -> Result<ExecutionResult> {
+        if phase_id == PhaseId::Localize {
+            return self.execute_localize(config).await;
+        }
+
         // Use phase factory to get the appropriate phase implementation
         let phase = self.get_phase_impl(phase_id, config)?;
         self.execute_phase_with_resume(phase.as_ref(), config).await
     }
 
+    /// Record a phase as explicitly skipped.
+    ///
+    /// Only optional phases (review, fixup) can be skipped, and only when the
+    /// transition to them is legal. The skip is written to the receipt chain
+    /// with exit code 0 and the `skipped` flag so status reports it as skipped
+    /// rather than missing. Phases that consume a skipped phase's artifacts
+    /// (fixup after a skipped review) remain blocked.
+    ///
+    /// # Errors
+    /// Returns error if the phase cannot be skipped or the receipt cannot be written.
+    pub fn skip_phase(
+        &self,
+        phase_id: PhaseId,
+        config: &OrchestratorConfig,
+    ) -> Result<ExecutionResult> {
+        self.validate_skip(phase_id)?;
+
+        let mut flags = HashMap::new();
+        flags.insert("phase".to_string(), phase_id.as_str().to_string());
+        flags.insert(RECEIPT_FLAG_SKIPPED.to_string(), "true".to_string());
+
+        let configured_model = config.config.get("model").map_or("unknown", |s| s.as_str());
+        let configured_runner = config
+            .config
+            .get("runner_mode")
+            .map_or("unknown", |s| s.as_str());
+
+        let receipt = self.receipt_manager().create_receipt_with_redactor(
+            config.redactor.as_ref(),
+            self.spec_id(),
+            phase_id,
+            0,
+            vec![], // Skipped phases produce no outputs
+            env!("CARGO_PKG_VERSION"),
+            "unknown", // No LLM invocation
+            configured_model,
+            None,
+            flags,
+            PacketEvidence {
+                files: vec![],
+                max_bytes: 0,
+                max_lines: 0,
+                ..Default::default()
+            },
+            None,
+            None,
+            vec!["phase_skipped".to_string()],
+            None,
+            configured_runner,
+            None,
+            None,
+            None,
+            None,
+            Some(PipelineInfo {
+                execution_strategy: Some("controlled".to_string()),
+            }),
+        );
+        let receipt_path = self.write_receipt_with_config(receipt, config)?;
+        self.run_output_sinks(config);
+
+        Ok(ExecutionResult {
+            phase: phase_id,
+            success: true,
+            exit_code: 0,
+            artifact_paths: vec![],
+            receipt_path: Some(receipt_path.into_std_path_buf()),
+            error: None,
+        })
+    }
+
     /// Execute a phase with timeout handling
     pub(crate) async fn execute_phase_with_timeout_handling(
         &self,
@@ -340,11 +480,7 @@ impl PhaseOrchestrator {
             timeout_seconds
         );
 
-        let partial_filename = format!(
-            "{:02}-{}.partial.md",
-            self.get_phase_number(phase_id),
-            phase_id.as_str().to_lowercase()
-        );
+        let partial_filename = phase_id.artifact_name("partial.md");
 
         // Store partial artifact
         let partial_artifact = Artifact {
@@ -364,6 +500,7 @@ impl PhaseOrchestrator {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         };
 
         let mut flags = HashMap::new();
@@ -405,7 +542,7 @@ impl PhaseOrchestrator {
             pipeline_info,
         );
 
-        let receipt_path = self.receipt_manager().write_receipt(&receipt)?;
+        let receipt_path = self.write_receipt_with_config(receipt, config)?;
 
         Ok(ExecutionResult {
             phase: phase_id,
@@ -417,8 +554,79 @@ impl PhaseOrchestrator {
         })
     }
 
+    /// Write the receipt for a phase stopped by the kill switch at `kill_switch`
+    fn handle_operator_halt(
+        &self,
+        phase_id: PhaseId,
+        kill_switch: &camino::Utf8Path,
+        config: &OrchestratorConfig,
+    ) -> Result<ExecutionResult> {
+        let reason = PhaseError::OperatorHalt {
+            phase: phase_id.as_str().to_string(),
+            path: kill_switch.to_string(),
+        }
+        .to_string();
+        tracing::warn!("{reason}");
+
+        let packet_evidence = PacketEvidence {
+            files: vec![],
+            max_bytes: 65536,
+            max_lines: 1200,
+            ..Default::default()
+        };
+
+        let mut flags = HashMap::new();
+        flags.insert("phase".to_string(), phase_id.as_str().to_string());
+
+        let warnings = vec![format!("operator_halt:{kill_switch}")];
+        let pipeline_info = Some(PipelineInfo {
+            execution_strategy: Some("controlled".to_string()),
+        });
+
+        let configured_model = config.config.get("model").map_or("unknown", |s| s.as_str());
+        let configured_runner = config
+            .config
+            .get("runner_mode")
+            .map_or("unknown", |s| s.as_str());
+
+        let receipt = self.receipt_manager().create_receipt_with_redactor(
+            config.redactor.as_ref(),
+            self.spec_id(),
+            phase_id,
+            exit_codes::codes::OPERATOR_HALT,
+            vec![],
+            env!("CARGO_PKG_VERSION"),
+            "unknown",
+            configured_model,
+            None,
+            flags,
+            packet_evidence,
+            None,
+            None,
+            warnings,
+            None,
+            configured_runner,
+            None,
+            Some(ErrorKind::OperatorHalt),
+            Some(reason.clone()),
+            None,
+            pipeline_info,
+        );
+
+        let receipt_path = self.write_receipt_with_config(receipt, config)?;
+
+        Ok(ExecutionResult {
+            phase: phase_id,
+            success: false,
+            exit_code: exit_codes::codes::OPERATOR_HALT,
+            artifact_paths: vec![],
+            receipt_path: Some(receipt_path.into_std_path_buf()),
+            error: Some(reason),
+        })
+    }
+
     /// Execute a phase with resume support (handles partial artifacts)
-    async fn execute_phase_with_resume(
+    pub(crate) async fn execute_phase_with_resume(
         &self,
         phase: &dyn Phase,
         config: &OrchestratorConfig,
@@ -429,7 +637,7 @@ impl PhaseOrchestrator {
         let has_partial = self.artifact_manager().has_partial_artifact(phase_id);
 
         if has_partial {
-            println!(
+            tracing::info!(
                 "Found partial artifact for {} phase from previous failed run",
                 phase_id.as_str()
             );
@@ -439,7 +647,7 @@ impl PhaseOrchestrator {
                 // 1. Continue from partial (not implemented yet)
                 // 2. Start fresh (delete partial and re-run)
                 // For now, we'll delete the partial and start fresh
-                println!("Deleting partial artifact and starting fresh...");
+                tracing::info!("Deleting partial artifact and starting fresh...");
                 self.artifact_manager().delete_partial_artifact(phase_id)?;
             }
         }
@@ -452,7 +660,7 @@ impl PhaseOrchestrator {
             // Delete any partial artifacts on success (R4.5)
             if let Err(e) = self.artifact_manager().delete_partial_artifact(phase_id) {
                 // Log warning but don't fail the operation
-                eprintln!("Warning: Failed to clean up partial artifact: {e}");
+                tracing::warn!("Failed to clean up partial artifact: {e}");
             }
         }
 
@@ -491,6 +699,8 @@ impl PhaseOrchestrator {
         config: &OrchestratorConfig,
     ) -> Result<PhaseCoreOutput> {
         let phase_id = phase.id();
+        // Heartbeat sidecar lets status/doctor tell a live phase from a hung one
+        let heartbeat = HeartbeatWriter::start(self.spec_id(), phase_id.as_str());
 
         // Create phase context
         let phase_context = self.create_phase_context(phase_id, config)?;
@@ -502,7 +712,7 @@ impl PhaseOrchestrator {
         let prompt = phase.prompt(&phase_context);
 
         // Step 2: Build packet (FR-ORC-003)
-        let packet = phase.make_packet(&phase_context).map_err(|e| {
+        let mut packet = phase.make_packet(&phase_context).map_err(|e| {
             XCheckerError::Phase(PhaseError::PacketCreationFailed {
                 phase: phase_id.as_str().to_string(),
                 reason: e.to_string(),
@@ -523,8 +733,6 @@ impl PhaseOrchestrator {
             "Built packet for phase"
         );
 
-        let packet_evidence = packet.evidence.clone();
-
         // Step 3: Scan for secrets (FR-ORC-003, FR-SEC)
         let redactor = config.redactor.as_ref();
 
@@ -537,10 +745,23 @@ impl PhaseOrchestrator {
             .into());
         }
 
-        // Store packet for debugging/preview
+        // Step 3b: License/compliance scan; findings are kept in the packet evidence
+        if Self::scan_packet_compliance(&mut packet, config)? == ComplianceMode::Block
+            && !packet.evidence.compliance.is_empty()
+        {
+            return Err(XCheckerError::Phase(PhaseError::ComplianceBlocked {
+                phase: phase_id.as_str().to_string(),
+                findings: compliance_summary(&packet.evidence.compliance),
+            })
+            .into());
+        }
+
+        // Store packet for debugging/preview (redacted unless --raw-context)
+        let (context_content, context_redacted) =
+            self.context_file_content(&packet.content, config)?;
         let _packet_preview_path = self
             .artifact_manager()
-            .store_context_file(&format!("{}-packet", phase_id.as_str()), &packet.content)?;
+            .store_context_file(&format!("{}-packet", phase_id.as_str()), &context_content)?;
 
         // Step 4: Write full debug packet if --debug-packet flag is set (FR-PKT-006, FR-PKT-007)
         // Only write after secret scan passes; file is excluded from receipts
@@ -562,16 +783,41 @@ impl PhaseOrchestrator {
             })?;
 
             if let Err(e) =
-                temp_builder.write_debug_packet(&packet.content, phase_id.as_str(), &context_dir)
+                temp_builder.write_debug_packet(&context_content, phase_id.as_str(), &context_dir)
             {
                 // Log warning but don't fail the operation (debug packet is optional)
-                eprintln!("Warning: Failed to write debug packet: {e}");
+                tracing::warn!("Failed to write debug packet: {e}");
             }
         }
 
-        // Step 5: Execute LLM (or simulate in dry-run mode)
+        // Write the rendered prompt if --dump-prompt or --prompt-only is set
+        let _prompt_dump_path =
+            self.write_prompt_dump(phase_id, &prompt, &packet.content, config)?;
+
+        // Keep context/ within its quota now that this phase's files are written
+        let _context_rotations = self.rotate_context_files(phase_id, config);
+
+        if FailurePoint::AfterPacketBuild.is_set(config) {
+            return Err(FailurePoint::AfterPacketBuild.error(phase_id.as_str()));
+        }
+
+        // Step 5: Execute LLM (or simulate in dry-run mode, or run locally)
         let (claude_response, claude_exit_code, claude_metadata, llm_result, llm_fallback_warning) =
-            if config.dry_run {
+            if let Some(local) = phase.local_response(&phase_context) {
+                let response = local.with_context(|| {
+                    format!(
+                        "Failed to produce local response for phase: {}",
+                        phase_id.as_str()
+                    )
+                })?;
+                (
+                    response,
+                    0,
+                    Some(Self::local_execution_metadata()),
+                    None,
+                    None,
+                )
+            } else if config.dry_run {
                 let simulated_llm = self.simulate_llm_result(phase_id);
                 let simulated_metadata = super::llm::ClaudeExecutionMetadata {
                     model_alias: None,
@@ -581,9 +827,12 @@ impl PhaseOrchestrator {
                     runner: "simulated".to_string(),
                     runner_distro: None,
                     stderr_tail: None,
+                    model_deprecation: None,
                 };
+                let response = self.simulate_claude_response(phase_id, &prompt);
+                heartbeat.record_bytes(response.len() as u64);
                 (
-                    self.simulate_claude_response(phase_id, &prompt),
+                    response,
                     0,
                     Some(simulated_metadata),
                     Some(simulated_llm),
@@ -591,9 +840,24 @@ impl PhaseOrchestrator {
                 )
             } else {
                 // Use new LLM backend abstraction (V11: Claude CLI only)
-                self.run_llm_invocation(&prompt, &packet.content, phase_id, config)
-                    .await?
+                self.run_llm_invocation_with_shrink(
+                    phase,
+                    &phase_context,
+                    &prompt,
+                    &mut packet,
+                    config,
+                    &heartbeat.progress(),
+                )
+                .await?
             };
+        // Evidence is taken after the call so context-overflow retries are recorded
+        let packet_evidence = packet.evidence.clone();
+
+        let (claude_response, claude_exit_code) = if FailurePoint::MidStream.is_set(config) {
+            FailurePoint::truncate_stream(claude_response)
+        } else {
+            (claude_response, claude_exit_code)
+        };
 
         // Step 6: Postprocess Claude response (only if LLM succeeded)
         let phase_result = if claude_exit_code == 0 {
@@ -643,16 +907,74 @@ impl PhaseOrchestrator {
             llm_result,
             llm_fallback_warning,
             phase_result,
+            context_redacted,
         })
     }
 
     /// Execute a single phase with full orchestration
+    ///
+    /// Each step is recorded in the run's event log
+    /// (`receipts/<run-id>.events.ndjson`), ending with `phase_finished`.
     pub(crate) async fn execute_phase(
         &self,
         phase: &dyn Phase,
         config: &OrchestratorConfig,
+    ) -> Result<ExecutionResult> {
+        let mut events = EventLog::start(
+            self.receipt_manager().receipts_path(),
+            self.spec_id(),
+            phase.id().as_str(),
+        );
+        events.record(RunEvent::PhaseStarted);
+
+        // The kill switch is checked before the phase starts and polled while
+        // it runs; dropping the steps cancels the provider call in flight
+        let result = match crate::paths::kill_switch_engaged() {
+            Some(path) => self.handle_operator_halt(phase.id(), &path, config),
+            None => {
+                let outcome = tokio::select! {
+                    result = self.execute_phase_steps(phase, config, &mut events) => Ok(result),
+                    path = wait_for_kill_switch() => Err(path),
+                };
+                match outcome {
+                    Ok(result) => result,
+                    Err(path) => self.handle_operator_halt(phase.id(), &path, config),
+                }
+            }
+        };
+        events.record(match &result {
+            Ok(result) => RunEvent::PhaseFinished {
+                success: result.success,
+                exit_code: Some(result.exit_code),
+                receipt: result
+                    .receipt_path
+                    .as_deref()
+                    .and_then(std::path::Path::file_name)
+                    .map(|name| format!("receipts/{}", name.to_string_lossy())),
+                error: result.error.clone(),
+            },
+            Err(e) => RunEvent::PhaseFinished {
+                success: false,
+                exit_code: None,
+                receipt: None,
+                error: Some(format!("{e:#}")),
+            },
+        });
+        result
+    }
+
+    /// The steps of [`execute_phase`](Self::execute_phase), recording progress in `events`
+    async fn execute_phase_steps(
+        &self,
+        phase: &dyn Phase,
+        config: &OrchestratorConfig,
+        events: &mut EventLog,
     ) -> Result<ExecutionResult> {
         let phase_id = phase.id();
+        // Heartbeat sidecar lets status/doctor tell a live phase from a hung one
+        let heartbeat = HeartbeatWriter::start(self.spec_id(), phase_id.as_str());
+        let fsync = fsync_policy(config);
+        crate::atomic_write::set_fsync_policy(fsync);
         let pipeline_info = Some(PipelineInfo {
             execution_strategy: Some("controlled".to_string()),
         });
@@ -661,6 +983,7 @@ impl PhaseOrchestrator {
         self.artifact_manager()
             .remove_stale_partial_dir()
             .with_context(|| "Failed to remove stale .partial/ directory")?;
+        self.check_artifact_numbering(phase_id)?;
 
         // Step 1: Validate transition (already done before calling this method)
         // Step 2: Acquire exclusive lock (already done in constructor)
@@ -671,6 +994,16 @@ impl PhaseOrchestrator {
         // Check dependencies (Requirements phase has no deps)
         self.check_phase_dependencies(phase)?;
 
+        // Applied fixups and hooks write to a working tree other specs may share
+        let _repo_lock = if mutates_working_tree(phase_id, config) {
+            Some(
+                RepoLock::acquire(self.spec_id(), DEFAULT_REPO_LOCK_WAIT)
+                    .map_err(XCheckerError::Lock)?,
+            )
+        } else {
+            None
+        };
+
         // Execute pre-phase hook if configured
         // Hooks run from invocation CWD so relative paths like ./scripts/... work
         let mut hook_warnings: Vec<String> = Vec::new();
@@ -692,9 +1025,13 @@ impl PhaseOrchestrator {
             .await
             {
                 Ok(outcome) => {
-                    if let Some(warning) = outcome.warning() {
-                        hook_warnings.push(warning.to_warning_string());
-                    }
+                    let warning = outcome.warning().map(|w| w.to_warning_string());
+                    events.record(RunEvent::HookFinished {
+                        hook: "pre_phase".to_string(),
+                        success: outcome.should_continue(),
+                        warning: warning.clone(),
+                    });
+                    hook_warnings.extend(warning);
                     if !outcome.should_continue() {
                         // Pre-hook failed with on_fail=fail - abort phase but still create receipt
                         let error_reason = format!(
@@ -707,9 +1044,11 @@ impl PhaseOrchestrator {
                             files: vec![],
                             max_bytes: 65536,
                             max_lines: 1200,
+                            ..Default::default()
                         };
                         let mut flags = HashMap::new();
                         flags.insert("phase".to_string(), phase_id.as_str().to_string());
+                        flags.insert("run_id".to_string(), events.run_id().to_string());
                         flags.insert("hook_failure".to_string(), "pre_phase".to_string());
 
                         // Use config values for truthful failure receipts (no hard-coded metadata)
@@ -744,7 +1083,7 @@ impl PhaseOrchestrator {
                             pipeline_info.clone(),
                         );
 
-                        let receipt_path = self.receipt_manager().write_receipt(&receipt)?;
+                        let receipt_path = self.write_receipt_with_config(receipt, config)?;
 
                         return Ok(ExecutionResult {
                             phase: phase_id,
@@ -759,15 +1098,22 @@ impl PhaseOrchestrator {
                 Err(e) => {
                     // Hook execution error - treat as failure but still create receipt
                     let error_reason = format!("Pre-phase hook error: {}", e);
+                    events.record(RunEvent::HookFinished {
+                        hook: "pre_phase".to_string(),
+                        success: false,
+                        warning: Some(format!("hook_error:pre_phase:{e}")),
+                    });
 
                     // Create failure receipt for hook error (audit trail requirement)
                     let packet_evidence = PacketEvidence {
                         files: vec![],
                         max_bytes: 65536,
                         max_lines: 1200,
+                        ..Default::default()
                     };
                     let mut flags = HashMap::new();
                     flags.insert("phase".to_string(), phase_id.as_str().to_string());
+                    flags.insert("run_id".to_string(), events.run_id().to_string());
                     flags.insert("hook_error".to_string(), "pre_phase".to_string());
 
                     // Use config values for truthful failure receipts (no hard-coded metadata)
@@ -802,7 +1148,7 @@ impl PhaseOrchestrator {
                         pipeline_info.clone(),
                     );
 
-                    let receipt_path = self.receipt_manager().write_receipt(&receipt)?;
+                    let receipt_path = self.write_receipt_with_config(receipt, config)?;
 
                     return Ok(ExecutionResult {
                         phase: phase_id,
@@ -820,7 +1166,7 @@ impl PhaseOrchestrator {
         let prompt = phase.prompt(&phase_context);
 
         // Step 3: Build packet (FR-ORC-003)
-        let packet = phase.make_packet(&phase_context).map_err(|e| {
+        let mut packet = phase.make_packet(&phase_context).map_err(|e| {
             XCheckerError::Phase(PhaseError::PacketCreationFailed {
                 phase: phase_id.as_str().to_string(),
                 reason: e.to_string(),
@@ -840,18 +1186,29 @@ impl PhaseOrchestrator {
             lines_limit = budget.max_lines,
             "Built packet for phase"
         );
+        events.record(RunEvent::PacketBuilt {
+            packet_hash: packet.hash().to_string(),
+            files: packet.evidence.files.len(),
+            bytes: budget.bytes_used,
+            lines: budget.lines_used,
+        });
 
         // Step 4: Scan for secrets (FR-ORC-003, FR-SEC)
         let redactor = config.redactor.as_ref();
 
         // Check for secrets in the packet content
         if redactor.has_secrets(&packet.content, "packet")? {
+            events.record(RunEvent::PacketScanned {
+                allowed: false,
+                compliance_findings: 0,
+            });
             let matches = redactor.scan_for_secrets(&packet.content, "packet")?;
 
             // Create error receipt for secret detection (FR-SEC, FR-EXIT)
             let packet_evidence = packet.evidence.clone();
             let mut flags = HashMap::new();
             flags.insert("phase".to_string(), phase_id.as_str().to_string());
+            flags.insert("run_id".to_string(), events.run_id().to_string());
 
             let secret_patterns: Vec<String> =
                 matches.iter().map(|m| m.pattern_id.clone()).collect();
@@ -884,7 +1241,7 @@ impl PhaseOrchestrator {
                 pipeline_info.clone(),
             );
 
-            let receipt_path = self.receipt_manager().write_receipt(&receipt)?;
+            let receipt_path = self.write_receipt_with_config(receipt, config)?;
 
             return Ok(ExecutionResult {
                 phase: phase_id,
@@ -896,10 +1253,78 @@ impl PhaseOrchestrator {
             });
         }
 
-        // Store packet for debugging/preview
+        // Step 4b: License/compliance scan (`[compliance]`)
+        let compliance_mode = Self::scan_packet_compliance(&mut packet, config)?;
+        if !packet.evidence.compliance.is_empty() {
+            let summary = compliance_summary(&packet.evidence.compliance);
+
+            if compliance_mode == ComplianceMode::Block {
+                events.record(RunEvent::PacketScanned {
+                    allowed: false,
+                    compliance_findings: packet.evidence.compliance.len(),
+                });
+                let mut flags = HashMap::new();
+                flags.insert("phase".to_string(), phase_id.as_str().to_string());
+                flags.insert("run_id".to_string(), events.run_id().to_string());
+                let error_reason = format!("Compliance scan blocked packet: {summary}");
+
+                let receipt = self.receipt_manager().create_receipt_with_redactor(
+                    config.redactor.as_ref(),
+                    self.spec_id(),
+                    phase_id,
+                    exit_codes::codes::COMPLIANCE_BLOCKED,
+                    vec![],
+                    env!("CARGO_PKG_VERSION"),
+                    "0.8.1", // Default Claude CLI version
+                    "haiku", // Default model
+                    None,    // No model alias
+                    flags,
+                    packet.evidence.clone(),
+                    None, // No stderr_tail
+                    None, // No stderr_redacted
+                    vec!["Compliance scan prevented LLM invocation".to_string()],
+                    None,     // No fallback
+                    "native", // Default runner
+                    None,     // No runner distro
+                    Some(ErrorKind::ComplianceBlocked),
+                    Some(error_reason.clone()),
+                    None, // No diff_context
+                    pipeline_info.clone(),
+                );
+
+                let receipt_path = self.write_receipt_with_config(receipt, config)?;
+
+                return Ok(ExecutionResult {
+                    phase: phase_id,
+                    success: false,
+                    exit_code: exit_codes::codes::COMPLIANCE_BLOCKED,
+                    artifact_paths: vec![],
+                    receipt_path: Some(receipt_path.into_std_path_buf()),
+                    error: Some(error_reason),
+                });
+            }
+
+            hook_warnings.push(format!("compliance: {summary}"));
+        }
+        events.record(RunEvent::PacketScanned {
+            allowed: true,
+            compliance_findings: packet.evidence.compliance.len(),
+        });
+
+        // Encoding/newline conversions change what was hashed; surface them
+        if !packet.evidence.input_conversions.is_empty() {
+            hook_warnings.push(format!(
+                "input_normalized: {}",
+                input_conversion_summary(&packet.evidence.input_conversions)
+            ));
+        }
+
+        // Store packet for debugging/preview (redacted unless --raw-context)
+        let (context_content, context_redacted) =
+            self.context_file_content(&packet.content, config)?;
         let _packet_preview_path = self
             .artifact_manager()
-            .store_context_file(&format!("{}-packet", phase_id.as_str()), &packet.content)?;
+            .store_context_file(&format!("{}-packet", phase_id.as_str()), &context_content)?;
 
         // Write full debug packet if --debug-packet flag is set (FR-PKT-006, FR-PKT-007)
         // Only write after secret scan passes; file is excluded from receipts
@@ -922,16 +1347,61 @@ impl PhaseOrchestrator {
             })?;
 
             if let Err(e) =
-                temp_builder.write_debug_packet(&packet.content, phase_id.as_str(), &context_dir)
+                temp_builder.write_debug_packet(&context_content, phase_id.as_str(), &context_dir)
             {
                 // Log warning but don't fail the operation (debug packet is optional)
-                eprintln!("Warning: Failed to write debug packet: {e}");
+                tracing::warn!("Failed to write debug packet: {e}");
             }
         }
 
+        // Write the rendered prompt if --dump-prompt or --prompt-only is set
+        let prompt_dump_path =
+            self.write_prompt_dump(phase_id, &prompt, &packet.content, config)?;
+
+        // Keep context/ within its quota now that this phase's files are written
+        hook_warnings.extend(self.rotate_context_files(phase_id, config));
+
+        if FailurePoint::AfterPacketBuild.is_set(config) {
+            return Err(FailurePoint::AfterPacketBuild.error(phase_id.as_str()));
+        }
+
+        // --prompt-only stops before the provider call; no receipt is written
+        if let Some(prompt_path) = prompt_dump_path.filter(|_| prompt_only(config)) {
+            return Ok(ExecutionResult {
+                phase: phase_id,
+                success: true,
+                exit_code: exit_codes::codes::SUCCESS,
+                artifact_paths: vec![prompt_path.into_std_path_buf()],
+                receipt_path: None,
+                error: None,
+            });
+        }
+
         // Execute LLM (or simulate in dry-run mode)
         let mut llm_fallback_warning: Option<String> = None;
-        let (claude_response, claude_exit_code, claude_metadata, llm_result) = if config.dry_run {
+        let local_response = phase.local_response(&phase_context);
+        let mode = if local_response.is_some() {
+            "local"
+        } else if config.dry_run {
+            "dry_run"
+        } else {
+            "provider"
+        };
+        events.record(RunEvent::LlmStarted {
+            mode: mode.to_string(),
+        });
+        let (claude_response, claude_exit_code, claude_metadata, llm_result) = if let Some(local) =
+            local_response
+        {
+            // Deterministic phases (e.g. Final in assemble mode) skip the LLM entirely
+            let response = local.with_context(|| {
+                format!(
+                    "Failed to produce local response for phase: {}",
+                    phase_id.as_str()
+                )
+            })?;
+            (response, 0, Some(Self::local_execution_metadata()), None)
+        } else if config.dry_run {
             let simulated_llm = self.simulate_llm_result(phase_id);
             let simulated_metadata = super::llm::ClaudeExecutionMetadata {
                 model_alias: None,
@@ -941,17 +1411,22 @@ impl PhaseOrchestrator {
                 runner: "simulated".to_string(),
                 runner_distro: None,
                 stderr_tail: None,
+                model_deprecation: None,
             };
-            (
-                self.simulate_claude_response(phase_id, &prompt),
-                0,
-                Some(simulated_metadata),
-                Some(simulated_llm),
-            )
+            let response = self.simulate_claude_response(phase_id, &prompt);
+            heartbeat.record_bytes(response.len() as u64);
+            (response, 0, Some(simulated_metadata), Some(simulated_llm))
         } else {
             // Use new LLM backend abstraction (V11: Claude CLI only)
             match self
-                .run_llm_invocation(&prompt, &packet.content, phase_id, config)
+                .run_llm_invocation_with_shrink(
+                    phase,
+                    &phase_context,
+                    &prompt,
+                    &mut packet,
+                    config,
+                    &heartbeat.progress(),
+                )
                 .await
             {
                 Ok((response, exit_code, metadata, result, fallback_warning)) => {
@@ -980,6 +1455,11 @@ impl PhaseOrchestrator {
                             let packet_evidence = packet.evidence.clone();
                             let mut flags = HashMap::new();
                             flags.insert("phase".to_string(), phase_id.as_str().to_string());
+                            flags.insert("run_id".to_string(), events.run_id().to_string());
+                            flags.insert(
+                                "context_redacted".to_string(),
+                                context_redacted.to_string(),
+                            );
 
                             // Use config values for truthful failure receipts (no hard-coded metadata)
                             let configured_model =
@@ -1021,7 +1501,7 @@ impl PhaseOrchestrator {
                             // Attach LlmInfo with budget_exhausted flag
                             receipt.llm = Some(LlmInfo::for_budget_exhaustion());
 
-                            let receipt_path = self.receipt_manager().write_receipt(&receipt)?;
+                            let receipt_path = self.write_receipt_with_config(receipt, config)?;
 
                             return Ok(ExecutionResult {
                                 phase: phase_id,
@@ -1036,6 +1516,11 @@ impl PhaseOrchestrator {
                         let packet_evidence = packet.evidence.clone();
                         let mut flags = HashMap::new();
                         flags.insert("phase".to_string(), phase_id.as_str().to_string());
+                        flags.insert("run_id".to_string(), events.run_id().to_string());
+                        flags.insert("context_redacted".to_string(), context_redacted.to_string());
+                        if let Some(failure) = llm_err.provider_failure() {
+                            flags.insert("provider_failure".to_string(), failure.to_string());
+                        }
 
                         // Use config values for truthful failure receipts (no hard-coded metadata)
                         let configured_model =
@@ -1068,6 +1553,9 @@ impl PhaseOrchestrator {
                             timed_out: None,
                             timeout_seconds: Some(invocation.timeout.as_secs()),
                             budget_exhausted: None,
+                            requests: None,
+                            provider_usage: None,
+                            process_usage: None,
                         };
 
                         let mut warnings = Vec::new();
@@ -1077,6 +1565,22 @@ impl PhaseOrchestrator {
                                 llm_info.timeout_seconds = Some(duration.as_secs());
                                 warnings.push(format!("phase_timeout:{}", duration.as_secs()));
                             }
+                            crate::llm::LlmError::InvalidResponse { checks, attempts } => {
+                                llm_info.timed_out = Some(false);
+                                llm_info.requests = Some(*attempts);
+                                warnings.extend(
+                                    checks
+                                        .iter()
+                                        .map(|check| format!("response_validation:{check}")),
+                                );
+                            }
+                            crate::llm::LlmError::ProviderCrash { restarts, .. } => {
+                                llm_info.timed_out = Some(false);
+                                warnings.push(format!("llm_error:{}", llm_err));
+                                if *restarts > 0 {
+                                    warnings.push(format!("provider_restarts:{restarts}"));
+                                }
+                            }
                             _ => {
                                 llm_info.timed_out = Some(false);
                                 warnings.push(format!("llm_error:{}", llm_err));
@@ -1112,7 +1616,7 @@ impl PhaseOrchestrator {
 
                         receipt.llm = Some(llm_info);
 
-                        let receipt_path = self.receipt_manager().write_receipt(&receipt)?;
+                        let receipt_path = self.write_receipt_with_config(receipt, config)?;
 
                         return Ok(ExecutionResult {
                             phase: phase_id,
@@ -1128,21 +1632,41 @@ impl PhaseOrchestrator {
                 }
             }
         };
+        let (claude_response, claude_exit_code) = if FailurePoint::MidStream.is_set(config) {
+            FailurePoint::truncate_stream(claude_response)
+        } else {
+            (claude_response, claude_exit_code)
+        };
+        events.record(RunEvent::LlmFinished {
+            exit_code: claude_exit_code,
+            bytes: claude_response.len(),
+        });
+        hook_warnings.extend(
+            llm_result
+                .as_ref()
+                .and_then(|result| result.extensions.get("response_sanitized"))
+                .and_then(|summary| summary.as_str())
+                .map(|summary| format!("response_sanitized: {summary}")),
+        );
+        let provider_restarts = llm_result
+            .as_ref()
+            .and_then(|result| result.extensions.get(PROVIDER_RESTARTS_EXTENSION))
+            .and_then(|restarts| restarts.as_u64())
+            .map(|restarts| format!("provider_restarts:{restarts}"));
+        hook_warnings.extend(provider_restarts.clone());
 
         // Handle Claude CLI failure (R4.3)
         if claude_exit_code != 0 {
-            // Save partial output as required by R4.3
-            let partial_filename = format!(
-                "{:02}-{}.partial.md",
-                self.get_phase_number(phase_id),
-                phase_id.as_str().to_lowercase()
-            );
+            // Save partial output as required by R4.3, trimmed to the last
+            // complete section so it is usable for manual continuation
+            let partial_filename = phase_id.artifact_name("partial.md");
+            let salvaged = xchecker_extraction::salvage_partial_markdown(&claude_response);
 
             let partial_result = self.artifact_manager().store_artifact(&Artifact {
                 name: partial_filename.clone(),
-                content: claude_response.clone(),
+                content: salvaged.content.clone(),
                 artifact_type: ArtifactType::Partial,
-                blake3_hash: blake3::hash(claude_response.as_bytes())
+                blake3_hash: blake3::hash(salvaged.content.as_bytes())
                     .to_hex()
                     .to_string(),
             })?;
@@ -1152,8 +1676,18 @@ impl PhaseOrchestrator {
             // Use the actual packet evidence from the packet that was created
             let packet_evidence = packet.evidence.clone();
 
+            let generation = self.generation_settings(phase_id, config);
             let mut flags = HashMap::new();
             flags.insert("phase".to_string(), phase_id.as_str().to_string());
+            flags.insert("run_id".to_string(), events.run_id().to_string());
+            flags.insert("context_redacted".to_string(), context_redacted.to_string());
+            flags.extend(generation.receipt_flags());
+            if let Some(result) = &llm_result {
+                flags.insert(
+                    "provider_failure".to_string(),
+                    provider_failure(result).to_string(),
+                );
+            }
 
             let (model_alias, model_full_name) = if let Some(metadata) = &claude_metadata {
                 (
@@ -1165,9 +1699,22 @@ impl PhaseOrchestrator {
             };
 
             let mut warnings = vec!["Phase execution failed with non-zero exit code".to_string()];
+            if salvaged.dropped_bytes > 0 {
+                warnings.push(format!(
+                    "Partial output trimmed to {} complete section(s); {} trailing bytes dropped",
+                    salvaged.kept_sections, salvaged.dropped_bytes
+                ));
+            }
             if let Some(ref warning) = llm_fallback_warning {
                 warnings.push(warning.clone());
             }
+            warnings.extend(provider_restarts);
+            warnings.extend(
+                claude_metadata
+                    .as_ref()
+                    .and_then(|m| m.model_deprecation.clone()),
+            );
+            warnings.extend(generation.warnings());
 
             let mut receipt = self.receipt_manager().create_receipt_with_redactor(
                 config.redactor.as_ref(),
@@ -1201,7 +1748,7 @@ impl PhaseOrchestrator {
 
             receipt.llm = llm_result.map(|result| result.into_llm_info());
 
-            let receipt_path = self.receipt_manager().write_receipt(&receipt)?;
+            let receipt_path = self.write_receipt_with_config(receipt, config)?;
 
             // Create enhanced error with stderr information (R4.3)
             let stderr_info = claude_metadata
@@ -1233,7 +1780,7 @@ impl PhaseOrchestrator {
         }
 
         // Process Claude response
-        let phase_result = phase
+        let mut phase_result = phase
             .postprocess(&claude_response, &phase_context)
             .with_context(|| {
                 format!(
@@ -1242,6 +1789,26 @@ impl PhaseOrchestrator {
                 )
             })?;
 
+        // Fix the receipt timestamp up front so front matter can name the receipt
+        let emitted_at = chrono::Utc::now();
+        let front_matter = self.artifact_front_matter(
+            phase_id,
+            claude_metadata.as_ref(),
+            emitted_at,
+            events.run_id(),
+            config,
+        );
+        if let Some(front_matter) = &front_matter {
+            for artifact in &mut phase_result.artifacts {
+                if artifact.artifact_type == ArtifactType::Markdown {
+                    artifact.content = front_matter.prepend(&artifact.content)?;
+                    artifact.blake3_hash = blake3::hash(artifact.content.as_bytes())
+                        .to_hex()
+                        .to_string();
+                }
+            }
+        }
+
         // Step 7: Write partial artifacts to .partial/ subdirectory (FR-ORC-004)
         let mut artifact_paths = Vec::new();
         let mut output_hashes = Vec::new();
@@ -1301,13 +1868,36 @@ impl PhaseOrchestrator {
 
             artifact_paths.push(final_path.into_std_path_buf());
         }
+        events.record(RunEvent::ArtifactsPromoted {
+            artifacts: phase_result
+                .artifacts
+                .iter()
+                .map(|artifact| format!("artifacts/{}", artifact.name))
+                .collect(),
+        });
 
         // Step 9: Create and write receipt (FR-ORC-005, FR-ORC-006)
         // Use the actual packet evidence from the packet that was created
         let packet_evidence = packet.evidence.clone();
 
+        let generation = self.generation_settings(phase_id, config);
         let mut flags = HashMap::new();
         flags.insert("phase".to_string(), phase_id.as_str().to_string());
+        flags.insert("run_id".to_string(), events.run_id().to_string());
+        flags.insert("context_redacted".to_string(), context_redacted.to_string());
+        flags.insert("fsync".to_string(), fsync.as_str().to_string());
+        flags.extend(generation.receipt_flags());
+        // A differential review records the receipt its packet was diffed against
+        if phase_id == PhaseId::Review
+            && review_diff::is_enabled(&config.config)
+            && let Some(baseline) =
+                ReviewBaseline::latest(self.artifact_manager().base_path().as_std_path())?
+        {
+            flags.insert(
+                review_diff::RECEIPT_FLAG_BASELINE.to_string(),
+                baseline.receipt,
+            );
+        }
 
         let (model_alias, model_full_name) = if let Some(metadata) = &claude_metadata {
             (
@@ -1325,6 +1915,13 @@ impl PhaseOrchestrator {
         if let Some(warning) = llm_fallback_warning {
             warnings.push(warning);
         }
+        warnings.extend(
+            claude_metadata
+                .as_ref()
+                .and_then(|m| m.model_deprecation.clone()),
+        );
+        warnings.extend(generation.warnings());
+        warnings.extend(self.reference_warnings(phase_id));
 
         let mut receipt = self.receipt_manager().create_receipt_with_redactor(
             config.redactor.as_ref(),
@@ -1357,11 +1954,17 @@ impl PhaseOrchestrator {
         );
         // Set LLM info from the invocation result (V11+ multi-provider support)
         receipt.llm = llm_result.map(|r| r.into_llm_info());
+        if front_matter.is_some() {
+            // Keep the receipt file name in sync with the artifacts' front matter
+            receipt.emitted_at = emitted_at;
+        }
 
         let receipt_path = self
-            .receipt_manager()
-            .write_receipt(&receipt)
+            .write_receipt_with_config(receipt, config)
             .with_context(|| format!("Failed to write receipt for phase: {}", phase_id.as_str()))?;
+        // Artifacts and receipt become durable together under `fsync = "batch"`
+        crate::atomic_write::flush_pending_writes()
+            .with_context(|| format!("Failed to fsync outputs of phase: {}", phase_id.as_str()))?;
 
         // Execute post-phase hook if configured (runs on success)
         // Hooks run from invocation CWD so relative paths like ./scripts/... work
@@ -1385,6 +1988,11 @@ impl PhaseOrchestrator {
             .await
             {
                 Ok(outcome) => {
+                    events.record(RunEvent::HookFinished {
+                        hook: "post_phase".to_string(),
+                        success: outcome.should_continue(),
+                        warning: outcome.warning().map(|w| w.to_warning_string()),
+                    });
                     // Log any warnings from successful or failed hooks
                     if let Some(warning) = outcome.warning() {
                         tracing::warn!(
@@ -1403,6 +2011,11 @@ impl PhaseOrchestrator {
                     }
                 }
                 Err(e) => {
+                    events.record(RunEvent::HookFinished {
+                        hook: "post_phase".to_string(),
+                        success: false,
+                        warning: Some(format!("hook_error:post_phase:{e}")),
+                    });
                     // Log hook execution errors but don't fail the phase
                     // (artifacts are already created at this point)
                     tracing::warn!(
@@ -1424,6 +2037,136 @@ impl PhaseOrchestrator {
         })
     }
 
+    /// Prepare packet content for context files
+    ///
+    /// Context files are passed through the configured `SecretRedactor` unless
+    /// `raw_context` is set. Returns the content and whether it was redacted.
+    pub(crate) fn context_file_content(
+        &self,
+        content: &str,
+        config: &OrchestratorConfig,
+    ) -> Result<(String, bool)> {
+        if config
+            .config
+            .get("raw_context")
+            .is_some_and(|s| s == "true")
+        {
+            return Ok((content.to_string(), false));
+        }
+        let redacted = config.redactor.redact_content(content, "context")?;
+        Ok((redacted.content, true))
+    }
+
+    /// Front matter for this run's Markdown artifacts (`[outputs] front_matter`)
+    ///
+    /// `run_id` is the run's event log id, which the receipt also records in
+    /// its `run_id` flag.
+    pub(crate) fn artifact_front_matter(
+        &self,
+        phase_id: PhaseId,
+        metadata: Option<&ClaudeExecutionMetadata>,
+        emitted_at: chrono::DateTime<chrono::Utc>,
+        run_id: &str,
+        config: &OrchestratorConfig,
+    ) -> Option<ArtifactFrontMatter> {
+        if config
+            .config
+            .get("output_front_matter")
+            .is_none_or(|s| s != "true")
+        {
+            return None;
+        }
+
+        let phase = phase_id.as_str();
+        Some(ArtifactFrontMatter {
+            spec_id: self.spec_id().to_string(),
+            phase: phase.to_string(),
+            run_id: run_id.to_string(),
+            model: metadata.map_or_else(|| "haiku".to_string(), |m| m.model_full_name.clone()),
+            receipt: format!(
+                "receipts/{}",
+                crate::receipt::ReceiptManager::receipt_file_name(phase, emitted_at)
+            ),
+        })
+    }
+
+    /// Write the fully rendered prompt to `context/<phase>-prompt.txt`
+    ///
+    /// Only runs when `dump_prompt` or `prompt_only` is set. The text is
+    /// redacted like other context files unless `raw_context` is set.
+    /// Returns the path written, if any.
+    pub(crate) fn write_prompt_dump(
+        &self,
+        phase_id: PhaseId,
+        prompt: &str,
+        packet: &str,
+        config: &OrchestratorConfig,
+    ) -> Result<Option<camino::Utf8PathBuf>> {
+        let enabled = config
+            .config
+            .get("dump_prompt")
+            .is_some_and(|s| s == "true")
+            || prompt_only(config);
+        if !enabled {
+            return Ok(None);
+        }
+
+        let rendered = self.render_prompt(prompt, packet, config);
+        let (content, _redacted) = self.context_file_content(&rendered, config)?;
+        let path = self
+            .artifact_manager()
+            .store_context_file(&format!("{}-prompt", phase_id.as_str()), &content)?;
+        Ok(Some(path))
+    }
+
+    /// Apply the `[context_retention]` quota after this phase's context files are written
+    ///
+    /// The running phase's own context files are never rotated. Returns one
+    /// receipt warning per removed file; rotation failures are logged and do
+    /// not fail the phase.
+    pub(crate) fn rotate_context_files(
+        &self,
+        phase_id: PhaseId,
+        config: &OrchestratorConfig,
+    ) -> Vec<String> {
+        let retention = ContextRetention {
+            max_bytes: config
+                .config
+                .get("context_retention_max_bytes")
+                .and_then(|s| s.parse().ok()),
+            max_files: config
+                .config
+                .get("context_retention_max_files")
+                .and_then(|s| s.parse().ok()),
+        };
+        let phase = phase_id.as_str();
+        let keep = [
+            format!("{phase}-packet.txt"),
+            format!("{phase}-packet-debug.txt"),
+            format!("{phase}-packet.manifest.json"),
+            format!("{phase}-prompt.txt"),
+        ];
+        let keep: Vec<&str> = keep.iter().map(String::as_str).collect();
+
+        match self
+            .artifact_manager()
+            .enforce_context_retention(retention, &keep)
+        {
+            Ok(rotated) => rotated
+                .into_iter()
+                .map(|r| format!("context_rotated: {} ({} bytes)", r.file, r.bytes))
+                .collect(),
+            Err(e) => {
+                tracing::warn!(
+                    target: "xchecker::context",
+                    phase = %phase,
+                    "Failed to enforce context_retention quota: {e:#}"
+                );
+                Vec::new()
+            }
+        }
+    }
+
     /// Create phase context for execution
     pub(crate) fn create_phase_context(
         &self,
@@ -1479,6 +2222,31 @@ impl PhaseOrchestrator {
         Ok(())
     }
 
+    /// Refuse to run against artifacts numbered under a different phase order
+    ///
+    /// Stale numbering would leave the old files invisible to dependency
+    /// lookups while new ones are written beside them.
+    pub(crate) fn check_artifact_numbering(&self, phase_id: PhaseId) -> Result<()> {
+        let stale = self.artifact_manager().stale_artifact_numbering()?;
+        if stale.is_empty() {
+            return Ok(());
+        }
+
+        let renames: Vec<String> = stale
+            .iter()
+            .map(|r| format!("{} -> {}", r.from, r.to))
+            .collect();
+        Err(XCheckerError::Phase(PhaseError::ContextCreationFailed {
+            phase: phase_id.as_str().to_string(),
+            reason: format!(
+                "artifacts are numbered for a different phase order ({}); run `xchecker migrate-artifacts {}`",
+                renames.join(", "),
+                self.spec_id()
+            ),
+        })
+        .into())
+    }
+
     /// Create a simulated LlmResult for dry-run mode
     /// This ensures receipts have complete LLM metadata even during testing
     pub(crate) fn simulate_llm_result(&self, _phase_id: PhaseId) -> crate::llm::LlmResult {
@@ -1493,6 +2261,92 @@ impl PhaseOrchestrator {
         .with_extension("dry_run", serde_json::json!(true))
     }
 
+    /// Record the effective configuration in a receipt, then write it
+    ///
+    /// Every receipt gets `config_hash`; the redacted snapshot itself is
+    /// embedded only with `[outputs] embed_config_snapshot = true`.
+    pub(crate) fn write_receipt_with_config(
+        &self,
+        mut receipt: Receipt,
+        config: &OrchestratorConfig,
+    ) -> Result<camino::Utf8PathBuf> {
+        if FailurePoint::BeforeReceiptWrite.is_set(config) {
+            return Err(FailurePoint::BeforeReceiptWrite.error(&receipt.phase));
+        }
+        // Record the experimental features the run had enabled
+        if let Some(features) = config.config.get("features") {
+            receipt
+                .flags
+                .insert("features".to_string(), features.clone());
+        }
+        if let Some(key) = idempotency::idempotency_key(config) {
+            receipt
+                .flags
+                .insert(RECEIPT_FLAG_IDEMPOTENCY_KEY.to_string(), key.to_string());
+        }
+        if let Some(language) = localize::receipt_language(config) {
+            receipt
+                .flags
+                .insert(RECEIPT_FLAG_LANGUAGE.to_string(), language.to_string());
+        }
+        let snapshot = config.effective_config_snapshot();
+        receipt.config_hash = Some(crate::receipt::config_snapshot_hash(&snapshot)?);
+        if config
+            .config
+            .get("output_embed_config_snapshot")
+            .is_some_and(|value| value == "true")
+        {
+            receipt.config_snapshot = Some(snapshot);
+        }
+        self.receipt_manager().write_receipt(&receipt)
+    }
+
+    /// Run the license/compliance scanner over a packet (`[compliance]`)
+    ///
+    /// Findings are stored in the packet evidence so that every receipt written
+    /// for this run records them; the caller decides from the returned mode
+    /// whether they warn or block.
+    pub(crate) fn scan_packet_compliance(
+        packet: &mut Packet,
+        config: &OrchestratorConfig,
+    ) -> Result<ComplianceMode> {
+        let mode = config
+            .config
+            .get("compliance_mode")
+            .map_or(ComplianceMode::Off, |mode| ComplianceMode::parse(mode));
+        if mode == ComplianceMode::Off {
+            return Ok(mode);
+        }
+
+        let list = |key: &str| -> Vec<String> {
+            config
+                .config
+                .get(key)
+                .map(|value| value.lines().map(str::to_string).collect())
+                .unwrap_or_default()
+        };
+        let scanner = ComplianceScanner::new(
+            &list("compliance_patterns"),
+            &list("compliance_ignore_patterns"),
+        )?;
+        packet.evidence.compliance = scanner.scan_packet(&packet.content);
+        Ok(mode)
+    }
+
+    /// Execution metadata for phases that produce their response without an LLM
+    pub(crate) fn local_execution_metadata() -> super::llm::ClaudeExecutionMetadata {
+        super::llm::ClaudeExecutionMetadata {
+            model_alias: None,
+            model_full_name: "none".to_string(),
+            claude_cli_version: "none".to_string(),
+            fallback_used: false,
+            runner: "local".to_string(),
+            runner_distro: None,
+            stderr_tail: None,
+            model_deprecation: None,
+        }
+    }
+
     /// Simulate Claude CLI response for testing/dry-run
     pub(crate) fn simulate_claude_response(&self, _phase_id: PhaseId, _prompt: &str) -> String {
         match _phase_id {
@@ -1610,7 +2464,7 @@ The system implements comprehensive error handling with:
   - Create directory structure for phases, artifacts, and receipts
   - Define Phase trait with separated concerns (prompt, make_packet, postprocess)
   - Implement PhaseId enum and basic dependency system
-  - _Requirements: R10.1, R10.3_
+  - _Requirements: R2.1, R3.1_
 
 - [ ] 2. Implement Requirements phase
 - [ ] 2.1 Create RequirementsPhase struct
@@ -1663,7 +2517,7 @@ The system implements comprehensive error handling with:
   - Test Requirements → Design → Tasks flow
   - Verify dependency checking works correctly
   - Test artifact propagation between phases
-  - _Requirements: R1.1, R4.2_
+  - _Requirements: R1.1, R2.3_
 
 ## Milestone 3: Orchestrator Integration
 
@@ -1672,7 +2526,7 @@ The system implements comprehensive error handling with:
   - Implement execute_design_phase method
   - Implement execute_tasks_phase method
   - Update dependency checking logic
-  - _Requirements: R1.1, R4.2_
+  - _Requirements: R1.1, R2.3_
 
 - [ ] 5.2 Enhance Claude response simulation
   - Add realistic responses for Design phase
@@ -1693,18 +2547,6 @@ The system implements comprehensive error handling with:
         }
     }
 
-    /// Get the phase number for artifact naming
-    pub(crate) const fn get_phase_number(&self, phase_id: PhaseId) -> u8 {
-        match phase_id {
-            PhaseId::Requirements => 0,
-            PhaseId::Design => 10,
-            PhaseId::Tasks => 20,
-            PhaseId::Review => 30,
-            PhaseId::Fixup => 40,
-            PhaseId::Final => 50,
-        }
-    }
-
     /// Get a phase implementation by ID (phase factory)
     /// This method creates the appropriate Phase trait object for the given phase ID
     pub(crate) fn get_phase_impl(
@@ -1732,7 +2574,57 @@ The system implements comprehensive error handling with:
 
                 Ok(Box::new(FixupPhase::new_with_mode(fixup_mode)))
             }
-            PhaseId::Final => Err(anyhow::anyhow!("Final phase not yet implemented")),
+            PhaseId::Final => Ok(Box::new(FinalPhase::new())),
+            PhaseId::Localize => Ok(Box::new(localize::localize_phase(config)?)),
         }
     }
 }
+
+/// Whether `--prompt-only` asked to stop once the prompt is written.
+fn prompt_only(config: &OrchestratorConfig) -> bool {
+    config
+        .config
+        .get("prompt_only")
+        .is_some_and(|s| s == "true")
+}
+
+/// Whether the phase applies fixups or runs hooks, and so needs the repo lock
+fn mutates_working_tree(phase_id: PhaseId, config: &OrchestratorConfig) -> bool {
+    let applies_fixups = phase_id == PhaseId::Fixup
+        && config
+            .config
+            .get("apply_fixups")
+            .is_some_and(|s| s == "true");
+    let runs_hooks = config.hooks.as_ref().is_some_and(|hooks| {
+        hooks.get_pre_phase_hook(phase_id).is_some()
+            || hooks.get_post_phase_hook(phase_id).is_some()
+    });
+    applies_fixups || runs_hooks
+}
+
+/// Fsync policy from `[defaults] fsync`; invalid values were rejected at load
+fn fsync_policy(config: &OrchestratorConfig) -> FsyncPolicy {
+    config
+        .config
+        .get("fsync")
+        .and_then(|policy| policy.parse().ok())
+        .unwrap_or_default()
+}
+
+/// One-line summary of compliance findings for warnings and error reasons
+fn compliance_summary(findings: &[ComplianceFinding]) -> String {
+    findings
+        .iter()
+        .map(|f| format!("{}:{} ({})", f.path, f.line, f.pattern_id))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// One-line summary of input normalizations for warnings
+fn input_conversion_summary(conversions: &[InputConversion]) -> String {
+    conversions
+        .iter()
+        .map(|c| format!("{} ({} x{})", c.path, c.kind, c.count))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
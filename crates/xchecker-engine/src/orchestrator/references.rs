@@ -0,0 +1,120 @@
+//! Dangling-reference warnings for design and tasks artifacts
+//!
+//! After Design and Tasks store their documents, the requirement IDs, file
+//! links, and heading anchors they mention are resolved against the spec's
+//! requirements, its other documents, and the repository (the working
+//! directory). References that point nowhere become receipt warnings of the
+//! form `dangling_reference:<kind>:<reference> (<document>:<line>)`; the
+//! phase still succeeds.
+
+use crate::status::artifact::ArtifactType;
+use crate::types::PhaseId;
+use crate::validation::ReferenceChecker;
+
+use super::PhaseOrchestrator;
+
+impl PhaseOrchestrator {
+    /// Warnings for references in `phase_id`'s stored documents that do not resolve
+    ///
+    /// Only Design and Tasks are checked. Documents that cannot be read are
+    /// skipped rather than failing the phase.
+    pub(crate) fn reference_warnings(&self, phase_id: PhaseId) -> Vec<String> {
+        if !matches!(phase_id, PhaseId::Design | PhaseId::Tasks) {
+            return Vec::new();
+        }
+
+        let documents: Vec<(String, String)> = match self.artifact_manager().list_artifacts() {
+            Ok(names) => names
+                .into_iter()
+                // Compressed artifacts are listed as `.md.zst` but read by name
+                .map(|name| {
+                    name.strip_suffix(".zst")
+                        .map_or(name.clone(), str::to_string)
+                })
+                .filter(|name| name.ends_with(".md"))
+                .filter_map(|name| {
+                    let content = self
+                        .artifact_manager()
+                        .read_artifact(&name, ArtifactType::Markdown)
+                        .ok()?;
+                    Some((name, content))
+                })
+                .collect(),
+            Err(e) => {
+                tracing::debug!("Skipping reference check: {e}");
+                return Vec::new();
+            }
+        };
+
+        let phase_of = |name: &str| PhaseId::parse_artifact_name(name).map(|(_, phase, _)| phase);
+        let mut checker = ReferenceChecker::new();
+        if let Ok(root) = std::env::current_dir() {
+            checker = checker.with_repo_root(root);
+        }
+        for (name, content) in &documents {
+            if phase_of(name) == Some(PhaseId::Requirements) {
+                checker = checker.with_requirements(content);
+            }
+            checker = checker.with_document(name, content);
+        }
+
+        let mut warnings = Vec::new();
+        for (name, content) in &documents {
+            if phase_of(name) != Some(phase_id) {
+                continue;
+            }
+            for dangling in checker.check(content) {
+                tracing::warn!("{name}: {dangling}");
+                warnings.push(dangling.warning(name));
+            }
+        }
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paths::with_isolated_home;
+    use crate::status::artifact::Artifact;
+
+    fn store(orchestrator: &PhaseOrchestrator, name: &str, content: &str) {
+        orchestrator
+            .artifact_manager()
+            .store_artifact(&Artifact::new(
+                name.to_string(),
+                content.to_string(),
+                ArtifactType::Markdown,
+            ))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_reference_warnings_flag_unknown_requirements_and_anchors() {
+        let _home = with_isolated_home();
+        let orchestrator = PhaseOrchestrator::new("reference-warnings").unwrap();
+        store(
+            &orchestrator,
+            "00-requirements.md",
+            "# Requirements\n\n### Requirement 1\n\n1. WHEN x THEN y SHALL z\n",
+        );
+        store(
+            &orchestrator,
+            "10-design.md",
+            "# Design\n\n## Overview\n\nCovers R1.1 and R2 (see [overview](#overview), [req](00-requirements.md#requirement-3)).\n",
+        );
+
+        assert_eq!(
+            orchestrator.reference_warnings(PhaseId::Design),
+            [
+                "dangling_reference:requirement:R2 (10-design.md:5)",
+                "dangling_reference:anchor:00-requirements.md#requirement-3 (10-design.md:5)",
+            ]
+        );
+        assert!(
+            orchestrator
+                .reference_warnings(PhaseId::Requirements)
+                .is_empty()
+        );
+    }
+}
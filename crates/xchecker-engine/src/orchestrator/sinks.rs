@@ -0,0 +1,104 @@
+//! Output sinks regenerated on run completion
+//!
+//! Sinks derive files outside the spec directory (such as README badges) from
+//! the receipt chain. They run after every phase run, pass or fail, and never
+//! fail the run themselves: errors are reported as warnings.
+
+use anyhow::Result;
+use camino::Utf8PathBuf;
+
+use super::{OrchestratorConfig, PhaseOrchestrator};
+use crate::status::badge::write_badge;
+use crate::types::Receipt;
+
+/// A file regenerated from a spec's receipts when a run completes
+pub(crate) trait OutputSink {
+    /// Short name used in warnings
+    fn name(&self) -> &'static str;
+
+    /// Regenerate the output from the spec's chronological receipts
+    fn on_run_complete(&self, receipts: &[Receipt]) -> Result<()>;
+}
+
+/// Writes the spec's SVG status badge (`[outputs] badge`)
+pub(crate) struct BadgeSink {
+    path: Utf8PathBuf,
+}
+
+impl BadgeSink {
+    /// Create a sink for `template`, substituting `{spec_id}`
+    pub(crate) fn new(template: &str, spec_id: &str) -> Self {
+        Self {
+            path: Utf8PathBuf::from(template.replace("{spec_id}", spec_id)),
+        }
+    }
+}
+
+impl OutputSink for BadgeSink {
+    fn name(&self) -> &'static str {
+        "badge"
+    }
+
+    fn on_run_complete(&self, receipts: &[Receipt]) -> Result<()> {
+        write_badge(&self.path, receipts)
+    }
+}
+
+/// Build the sinks enabled by the orchestrator configuration
+pub(crate) fn sinks_from_config(
+    spec_id: &str,
+    config: &OrchestratorConfig,
+) -> Vec<Box<dyn OutputSink>> {
+    let mut sinks: Vec<Box<dyn OutputSink>> = Vec::new();
+    if let Some(template) = config.config.get("output_badge") {
+        sinks.push(Box::new(BadgeSink::new(template, spec_id)));
+    }
+    sinks
+}
+
+impl PhaseOrchestrator {
+    /// Regenerate all configured output sinks from the current receipts
+    pub(crate) fn run_output_sinks(&self, config: &OrchestratorConfig) {
+        let sinks = sinks_from_config(self.spec_id(), config);
+        if sinks.is_empty() {
+            return;
+        }
+
+        let receipts = match self.receipt_manager().list_receipts() {
+            Ok(receipts) => receipts,
+            Err(e) => {
+                tracing::warn!("Failed to read receipts for output sinks: {e}");
+                return;
+            }
+        };
+
+        for sink in sinks {
+            if let Err(e) = sink.on_run_complete(&receipts) {
+                tracing::warn!("Failed to update {} output: {e}", sink.name());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sinks_from_config_substitutes_spec_id() {
+        let mut config = OrchestratorConfig::default();
+        assert!(sinks_from_config("my-spec", &config).is_empty());
+
+        config.config.insert(
+            "output_badge".to_string(),
+            "badges/{spec_id}.svg".to_string(),
+        );
+        let sinks = sinks_from_config("my-spec", &config);
+        assert_eq!(sinks.len(), 1);
+        assert_eq!(sinks[0].name(), "badge");
+        assert_eq!(
+            BadgeSink::new("badges/{spec_id}.svg", "my-spec").path,
+            "badges/my-spec.svg"
+        );
+    }
+}
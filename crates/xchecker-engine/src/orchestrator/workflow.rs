@@ -9,7 +9,7 @@ use anyhow::{Context, Result};
 use crate::error::{PhaseError, XCheckerError};
 use crate::fixup::{FixupMode, FixupPhase};
 use crate::phase::Phase;
-use crate::phases::{DesignPhase, RequirementsPhase, ReviewPhase, TasksPhase};
+use crate::phases::{DesignPhase, FinalPhase, RequirementsPhase, ReviewPhase, TasksPhase};
 use crate::types::{FileType, PhaseId, PipelineInfo};
 
 use super::{OrchestratorConfig, PhaseOrchestrator};
@@ -99,12 +99,12 @@ impl PhaseOrchestrator {
         while current_phase_index < standard_phases.len() {
             let phase_id = standard_phases[current_phase_index];
 
-            // Skip Final phase for now (not implemented)
-            if phase_id == PhaseId::Final {
+            // Final is opt-in for complete workflows: run it only when `[final]` is configured
+            if phase_id == PhaseId::Final && !config.config.contains_key("final_mode") {
                 break;
             }
 
-            println!("Executing phase: {}", phase_id.as_str());
+            tracing::info!("Executing phase: {}", phase_id.as_str());
 
             let result = match self
                 .execute_single_phase_with_rewind_support(phase_id, config)
@@ -167,7 +167,7 @@ impl PhaseOrchestrator {
                         standard_phases.iter().position(|&p| p == target_phase)
                     {
                         current_phase_index = target_index;
-                        println!(
+                        tracing::info!(
                             "Rewinding to phase: {} (rewind #{}/{})",
                             target_phase.as_str(),
                             rewind_count,
@@ -260,11 +260,14 @@ impl PhaseOrchestrator {
                     .await?
             }
             PhaseId::Final => {
-                return Err(XCheckerError::Phase(PhaseError::InvalidTransition {
-                    from: "current state".to_string(),
-                    to: "Final phase not yet implemented".to_string(),
-                })
-                .into());
+                let phase = FinalPhase::new();
+                self.execute_phase_with_next_step_handling(&phase, config)
+                    .await?
+            }
+            PhaseId::Localize => {
+                let phase = super::localize::localize_phase(config)?;
+                self.execute_phase_with_next_step_handling(&phase, config)
+                    .await?
             }
         };
 
@@ -365,6 +368,12 @@ impl PhaseOrchestrator {
         // Prepare receipt flags
         let mut flags = std::collections::HashMap::new();
         flags.insert("phase".to_string(), phase_id.as_str().to_string());
+        flags.insert(
+            "context_redacted".to_string(),
+            core.context_redacted.to_string(),
+        );
+        let generation = self.generation_settings(phase_id, config);
+        flags.extend(generation.receipt_flags());
 
         // Extract rewind information from phase_result.next_step (FR-WORKFLOW)
         let (rewind_triggered, rewind_target) = match &core.phase_result.next_step {
@@ -392,6 +401,13 @@ impl PhaseOrchestrator {
         if let Some(warning) = &core.llm_fallback_warning {
             warnings.push(warning.clone());
         }
+        warnings.extend(
+            core.claude_metadata
+                .as_ref()
+                .and_then(|m| m.model_deprecation.clone()),
+        );
+        warnings.extend(generation.warnings());
+        warnings.extend(self.reference_warnings(phase_id));
 
         let mut receipt = self.receipt_manager().create_receipt_with_redactor(
             config.redactor.as_ref(),
@@ -429,8 +445,7 @@ impl PhaseOrchestrator {
         receipt.llm = core.llm_result.map(|r| r.into_llm_info());
 
         let _receipt_path = self
-            .receipt_manager()
-            .write_receipt(&receipt)
+            .write_receipt_with_config(receipt, config)
             .with_context(|| format!("Failed to write receipt for phase: {}", phase_id.as_str()))?;
 
         Ok(PhaseExecutionResult {
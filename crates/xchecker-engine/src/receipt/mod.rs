@@ -1,12 +0,0 @@
-mod emit;
-mod errors;
-mod hash;
-mod model;
-mod writer;
-
-pub use errors::write_error_receipt_and_exit;
-pub use model::ReceiptManager;
-pub use writer::add_rename_retry_warning;
-
-#[cfg(test)]
-mod tests;
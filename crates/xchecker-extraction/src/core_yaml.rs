@@ -0,0 +1,530 @@
+//! Typed models for the `.core.yaml` phase artifacts
+//!
+//! Each phase writes a `.core.yaml` next to its Markdown artifact with the
+//! counts produced by the `summarize_*` functions and lists reserved for
+//! structured extraction. [`RequirementsCore`], [`DesignCore`], and
+//! [`TasksCore`] are the contract for those files: phases serialize through
+//! them, and consumers parse with `from_yaml`, which checks the phase and the
+//! major version. Reading is lenient (unknown keys are ignored and missing
+//! counts default to zero) so artifacts written by older builds still parse.
+//!
+//! `json_schema()` emits the JSON Schema of each model; the published copies
+//! live in `schemas/<phase>-core.v1.json`.
+
+use crate::{DesignSummary, RequirementsSummary, TasksSummary};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value as JsonValue, json};
+
+/// Version written into every core artifact
+pub const CORE_YAML_VERSION: &str = "1.0";
+
+/// Entry of a list reserved for structured extraction (always empty today)
+pub type ReservedEntry = serde_yaml::Value;
+
+/// Errors from parsing a core artifact
+#[derive(Debug, thiserror::Error)]
+pub enum CoreYamlError {
+    #[error("invalid core YAML: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
+    #[error("core YAML is for phase '{found}', expected '{expected}'")]
+    WrongPhase {
+        expected: &'static str,
+        found: String,
+    },
+
+    #[error("unsupported core YAML version '{found}' (expected {CORE_YAML_VERSION})")]
+    UnsupportedVersion { found: String },
+}
+
+/// Counts extracted from the requirements document
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RequirementsCoreMetadata {
+    pub total_requirements: usize,
+    pub total_user_stories: usize,
+    pub total_acceptance_criteria: usize,
+    pub total_nfrs: usize,
+    pub has_nfrs: bool,
+}
+
+/// Content of `00-requirements.core.yaml`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequirementsCore {
+    pub spec_id: String,
+    /// Always `requirements`
+    pub phase: String,
+    pub version: String,
+    #[serde(default)]
+    pub metadata: RequirementsCoreMetadata,
+    #[serde(default)]
+    pub requirements: Vec<ReservedEntry>,
+    #[serde(default)]
+    pub nfrs: Vec<ReservedEntry>,
+    #[serde(default)]
+    pub dependencies: Vec<ReservedEntry>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Counts extracted from the design document
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DesignCoreMetadata {
+    pub has_architecture_section: bool,
+    pub has_mermaid_diagrams: bool,
+    pub total_components: usize,
+    pub total_interfaces: usize,
+    pub total_data_models: usize,
+}
+
+/// Architecture lists of the design core artifact
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DesignArchitecture {
+    pub components: Vec<ReservedEntry>,
+    pub interfaces: Vec<ReservedEntry>,
+    pub data_flow: Vec<ReservedEntry>,
+}
+
+/// Content of `10-design.core.yaml`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DesignCore {
+    pub spec_id: String,
+    /// Always `design`
+    pub phase: String,
+    pub version: String,
+    #[serde(default)]
+    pub metadata: DesignCoreMetadata,
+    #[serde(default)]
+    pub architecture: DesignArchitecture,
+    #[serde(default)]
+    pub data_models: Vec<ReservedEntry>,
+    #[serde(default)]
+    pub error_handling: Vec<ReservedEntry>,
+    #[serde(default)]
+    pub testing_strategy: Vec<ReservedEntry>,
+    #[serde(default)]
+    pub requirements_dependencies: Vec<ReservedEntry>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Counts extracted from the tasks document
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TasksCoreMetadata {
+    pub total_tasks: usize,
+    pub total_subtasks: usize,
+    pub total_milestones: usize,
+    pub total_dependencies: usize,
+}
+
+/// Content of `20-tasks.core.yaml`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TasksCore {
+    pub spec_id: String,
+    /// Always `tasks`
+    pub phase: String,
+    pub version: String,
+    #[serde(default)]
+    pub metadata: TasksCoreMetadata,
+    #[serde(default)]
+    pub tasks: Vec<ReservedEntry>,
+    #[serde(default)]
+    pub dependencies: Vec<ReservedEntry>,
+    #[serde(default)]
+    pub requirements_coverage: Vec<ReservedEntry>,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl RequirementsCore {
+    pub const PHASE: &'static str = "requirements";
+
+    /// Core artifact for `spec_id` from the extracted counts
+    #[must_use]
+    pub fn new(spec_id: &str, summary: &RequirementsSummary, generated_at: DateTime<Utc>) -> Self {
+        Self {
+            spec_id: spec_id.to_string(),
+            phase: Self::PHASE.to_string(),
+            version: CORE_YAML_VERSION.to_string(),
+            metadata: RequirementsCoreMetadata {
+                total_requirements: summary.requirement_count,
+                total_user_stories: summary.user_story_count,
+                total_acceptance_criteria: summary.acceptance_criteria_count,
+                total_nfrs: summary.nfr_count,
+                has_nfrs: summary.nfr_count > 0,
+            },
+            requirements: Vec::new(),
+            nfrs: Vec::new(),
+            dependencies: Vec::new(),
+            generated_at,
+        }
+    }
+
+    /// Parse a requirements core artifact
+    ///
+    /// # Errors
+    ///
+    /// Fails on invalid YAML, another phase's artifact, or an unsupported version.
+    pub fn from_yaml(yaml: &str) -> Result<Self, CoreYamlError> {
+        let core: Self = parse(yaml)?;
+        check_header(Self::PHASE, &core.phase, &core.version)?;
+        Ok(core)
+    }
+
+    /// Render the artifact as YAML
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        render(self, &self.spec_id, Self::PHASE)
+    }
+
+    /// JSON Schema of the artifact (published as `schemas/requirements-core.v1.json`)
+    #[must_use]
+    pub fn json_schema() -> JsonValue {
+        core_schema(
+            Self::PHASE,
+            json!({
+                "total_requirements": count(),
+                "total_user_stories": count(),
+                "total_acceptance_criteria": count(),
+                "total_nfrs": count(),
+                "has_nfrs": { "type": "boolean" }
+            }),
+            json!({
+                "requirements": reserved(),
+                "nfrs": reserved(),
+                "dependencies": reserved()
+            }),
+        )
+    }
+}
+
+impl DesignCore {
+    pub const PHASE: &'static str = "design";
+
+    /// Core artifact for `spec_id` from the extracted counts
+    #[must_use]
+    pub fn new(spec_id: &str, summary: &DesignSummary, generated_at: DateTime<Utc>) -> Self {
+        Self {
+            spec_id: spec_id.to_string(),
+            phase: Self::PHASE.to_string(),
+            version: CORE_YAML_VERSION.to_string(),
+            metadata: DesignCoreMetadata {
+                has_architecture_section: summary.has_architecture,
+                has_mermaid_diagrams: summary.has_diagrams,
+                total_components: summary.component_count,
+                total_interfaces: summary.interface_count,
+                total_data_models: summary.data_model_count,
+            },
+            architecture: DesignArchitecture::default(),
+            data_models: Vec::new(),
+            error_handling: Vec::new(),
+            testing_strategy: Vec::new(),
+            requirements_dependencies: Vec::new(),
+            generated_at,
+        }
+    }
+
+    /// Parse a design core artifact
+    ///
+    /// # Errors
+    ///
+    /// Fails on invalid YAML, another phase's artifact, or an unsupported version.
+    pub fn from_yaml(yaml: &str) -> Result<Self, CoreYamlError> {
+        let core: Self = parse(yaml)?;
+        check_header(Self::PHASE, &core.phase, &core.version)?;
+        Ok(core)
+    }
+
+    /// Render the artifact as YAML
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        render(self, &self.spec_id, Self::PHASE)
+    }
+
+    /// JSON Schema of the artifact (published as `schemas/design-core.v1.json`)
+    #[must_use]
+    pub fn json_schema() -> JsonValue {
+        core_schema(
+            Self::PHASE,
+            json!({
+                "has_architecture_section": { "type": "boolean" },
+                "has_mermaid_diagrams": { "type": "boolean" },
+                "total_components": count(),
+                "total_interfaces": count(),
+                "total_data_models": count()
+            }),
+            json!({
+                "architecture": {
+                    "type": "object",
+                    "required": ["components", "interfaces", "data_flow"],
+                    "additionalProperties": false,
+                    "properties": {
+                        "components": reserved(),
+                        "interfaces": reserved(),
+                        "data_flow": reserved()
+                    }
+                },
+                "data_models": reserved(),
+                "error_handling": reserved(),
+                "testing_strategy": reserved(),
+                "requirements_dependencies": reserved()
+            }),
+        )
+    }
+}
+
+impl TasksCore {
+    pub const PHASE: &'static str = "tasks";
+
+    /// Core artifact for `spec_id` from the extracted counts
+    #[must_use]
+    pub fn new(spec_id: &str, summary: &TasksSummary, generated_at: DateTime<Utc>) -> Self {
+        Self {
+            spec_id: spec_id.to_string(),
+            phase: Self::PHASE.to_string(),
+            version: CORE_YAML_VERSION.to_string(),
+            metadata: TasksCoreMetadata {
+                total_tasks: summary.task_count,
+                total_subtasks: summary.subtask_count,
+                total_milestones: summary.milestone_count,
+                total_dependencies: summary.dependency_count,
+            },
+            tasks: Vec::new(),
+            dependencies: Vec::new(),
+            requirements_coverage: Vec::new(),
+            generated_at,
+        }
+    }
+
+    /// Parse a tasks core artifact
+    ///
+    /// # Errors
+    ///
+    /// Fails on invalid YAML, another phase's artifact, or an unsupported version.
+    pub fn from_yaml(yaml: &str) -> Result<Self, CoreYamlError> {
+        let core: Self = parse(yaml)?;
+        check_header(Self::PHASE, &core.phase, &core.version)?;
+        Ok(core)
+    }
+
+    /// Render the artifact as YAML
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        render(self, &self.spec_id, Self::PHASE)
+    }
+
+    /// JSON Schema of the artifact (published as `schemas/tasks-core.v1.json`)
+    #[must_use]
+    pub fn json_schema() -> JsonValue {
+        core_schema(
+            Self::PHASE,
+            json!({
+                "total_tasks": count(),
+                "total_subtasks": count(),
+                "total_milestones": count(),
+                "total_dependencies": count()
+            }),
+            json!({
+                "tasks": reserved(),
+                "dependencies": reserved(),
+                "requirements_coverage": reserved()
+            }),
+        )
+    }
+}
+
+fn parse<T: DeserializeOwned>(yaml: &str) -> Result<T, CoreYamlError> {
+    Ok(serde_yaml::from_str(yaml)?)
+}
+
+fn check_header(expected: &'static str, phase: &str, version: &str) -> Result<(), CoreYamlError> {
+    if phase != expected {
+        return Err(CoreYamlError::WrongPhase {
+            expected,
+            found: phase.to_string(),
+        });
+    }
+    if version.split('.').next() != CORE_YAML_VERSION.split('.').next() {
+        return Err(CoreYamlError::UnsupportedVersion {
+            found: version.to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn render<T: Serialize>(core: &T, spec_id: &str, phase: &str) -> Result<String, serde_yaml::Error> {
+    Ok(format!(
+        "# Core {phase} data for spec {spec_id}\n\
+         # This file contains structured data extracted from the {phase} document\n\n{}",
+        serde_yaml::to_string(core)?
+    ))
+}
+
+fn count() -> JsonValue {
+    json!({ "type": "integer", "minimum": 0 })
+}
+
+fn reserved() -> JsonValue {
+    json!({
+        "type": "array",
+        "description": "Reserved for structured extraction; currently always empty"
+    })
+}
+
+/// Schema shared by every core artifact, with the phase-specific metadata
+/// counts and top-level lists filled in
+fn core_schema(phase: &str, metadata: JsonValue, lists: JsonValue) -> JsonValue {
+    let metadata_keys: Vec<&String> = metadata
+        .as_object()
+        .map_or(Vec::new(), |m| m.keys().collect());
+    let mut properties = json!({
+        "spec_id": { "type": "string", "minLength": 1 },
+        "phase": { "const": phase },
+        "version": { "type": "string", "pattern": "^1\\.[0-9]+$" },
+        "metadata": {
+            "type": "object",
+            "required": metadata_keys,
+            "additionalProperties": false,
+            "properties": metadata
+        },
+        "generated_at": { "type": "string", "format": "date-time" }
+    });
+    let mut required = vec!["spec_id", "phase", "version", "metadata", "generated_at"];
+    if let (Some(properties), Some(lists)) = (properties.as_object_mut(), lists.as_object()) {
+        for (key, schema) in lists {
+            properties.insert(key.clone(), schema.clone());
+        }
+    }
+    required.extend(
+        lists
+            .as_object()
+            .into_iter()
+            .flat_map(|l| l.keys().map(String::as_str)),
+    );
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": format!("xchecker {phase} core artifact v1"),
+        "description": format!("Content of the {phase} phase .core.yaml artifact, shown as JSON"),
+        "type": "object",
+        "required": required,
+        "additionalProperties": false,
+        "properties": properties
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{summarize_design, summarize_requirements, summarize_tasks};
+
+    fn fixed_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_core_artifacts_round_trip() {
+        let requirements = RequirementsCore::new(
+            "demo",
+            &summarize_requirements("### Requirement 1\n**User Story:** As a user\n**NFR-1:** x\n"),
+            fixed_time(),
+        );
+        let yaml = requirements.to_yaml().unwrap();
+        assert!(yaml.starts_with("# Core requirements data for spec demo\n"));
+        assert_eq!(RequirementsCore::from_yaml(&yaml).unwrap(), requirements);
+        assert!(requirements.metadata.has_nfrs);
+
+        let design = DesignCore::new(
+            "demo",
+            &summarize_design("## Architecture\n### Component: Api\n"),
+            fixed_time(),
+        );
+        assert_eq!(
+            DesignCore::from_yaml(&design.to_yaml().unwrap()).unwrap(),
+            design
+        );
+
+        let tasks = TasksCore::new(
+            "demo",
+            &summarize_tasks("## Task 1\n- [ ] a\n"),
+            fixed_time(),
+        );
+        assert_eq!(
+            TasksCore::from_yaml(&tasks.to_yaml().unwrap()).unwrap(),
+            tasks
+        );
+        assert_eq!(tasks.metadata.total_subtasks, 1);
+    }
+
+    #[test]
+    fn test_wrong_phase_and_version_are_rejected() {
+        let tasks = TasksCore::new("demo", &TasksSummary::default(), fixed_time());
+        let yaml = tasks.to_yaml().unwrap();
+        assert!(matches!(
+            DesignCore::from_yaml(&yaml),
+            Err(CoreYamlError::WrongPhase {
+                expected: "design",
+                ..
+            })
+        ));
+
+        let future = yaml.replace("version: '1.0'", "version: '2.0'");
+        assert!(matches!(
+            TasksCore::from_yaml(&future),
+            Err(CoreYamlError::UnsupportedVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_legacy_artifact_parses() {
+        // Written by an early build: quoted scalars, comments, extra metadata keys
+        let legacy = r#"# Core design data for spec demo-time-api
+spec_id: "demo-time-api"
+phase: "design"
+version: "1.0"
+metadata:
+  has_architecture_section: false  # Would be parsed from markdown
+  has_components_section: false
+architecture:
+  components: []
+  interfaces: []
+  data_flow: []
+data_models: []
+generated_at: "2025-12-05T23:31:14.183523400+00:00"
+"#;
+        let core = DesignCore::from_yaml(legacy).unwrap();
+        assert_eq!(core.spec_id, "demo-time-api");
+        assert_eq!(core.metadata, DesignCoreMetadata::default());
+        assert!(core.testing_strategy.is_empty());
+    }
+
+    #[test]
+    fn test_json_schema_lists_every_serialized_field() {
+        let core = RequirementsCore::new("demo", &RequirementsSummary::default(), fixed_time());
+        let json = serde_json::to_value(&core).unwrap();
+        let schema = RequirementsCore::json_schema();
+        let mut fields: Vec<&String> = json.as_object().unwrap().keys().collect();
+        let mut required: Vec<&str> = schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap())
+            .collect();
+        fields.sort();
+        required.sort_unstable();
+        assert_eq!(fields, required);
+    }
+}
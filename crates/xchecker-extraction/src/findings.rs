@@ -0,0 +1,231 @@
+//! Extraction of individual findings from a review document
+//!
+//! Review output is free-form markdown, but models reliably list issues as
+//! bullet or numbered items under headings such as `## Design Issues`. Each
+//! top-level list item outside code fences becomes a [`ReviewFinding`];
+//! indented lines and nested items are folded into its body. Items under
+//! summary-style headings (summary, strengths, conclusion) are not findings.
+
+/// Maximum length of a finding title, in characters
+const MAX_TITLE_CHARS: usize = 80;
+
+/// Headings whose list items describe the review rather than issues
+const NON_FINDING_HEADINGS: [&str; 5] =
+    ["summary", "strength", "positive", "conclusion", "overview"];
+
+/// Severity words recognized at the start of a finding
+const SEVERITIES: [&str; 5] = ["critical", "high", "medium", "low", "minor"];
+
+/// One issue raised by the review
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewFinding {
+    /// Short title: the first sentence of the item, without markdown emphasis
+    pub title: String,
+    /// Full text of the item, including nested lines
+    pub body: String,
+    /// Nearest heading above the item, if any
+    pub section: Option<String>,
+    /// Severity from a leading `[High]`, `**Critical:**`, or `Severity: low`
+    pub severity: Option<String>,
+}
+
+/// Extract the findings of a review document, in document order
+#[must_use]
+pub fn extract_review_findings(markdown: &str) -> Vec<ReviewFinding> {
+    let mut findings = Vec::new();
+    let mut section: Option<String> = None;
+    let mut current: Option<Vec<String>> = None;
+    let mut in_fence = false;
+
+    let mut finish = |lines: Option<Vec<String>>, section: &Option<String>| {
+        if let Some(finding) = lines.and_then(|lines| build_finding(&lines, section.clone())) {
+            findings.push(finding);
+        }
+    };
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            // Code (e.g. fixup diffs) ends the item it follows
+            finish(current.take(), &section);
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        if let Some(heading) = trimmed.strip_prefix('#') {
+            finish(current.take(), &section);
+            section = Some(heading.trim_start_matches('#').trim().to_string());
+            continue;
+        }
+
+        let indented = line.starts_with("  ") || line.starts_with('\t');
+        match list_item_text(trimmed) {
+            Some(text) if !indented => {
+                finish(current.take(), &section);
+                if !is_summary_section(section.as_deref()) {
+                    current = Some(vec![text.to_string()]);
+                }
+            }
+            _ if trimmed.is_empty() => {}
+            _ => {
+                if let Some(lines) = current.as_mut() {
+                    if indented {
+                        lines.push(trimmed.to_string());
+                    } else {
+                        // A top-level paragraph ends the list
+                        finish(current.take(), &section);
+                    }
+                }
+            }
+        }
+    }
+    finish(current.take(), &section);
+    findings
+}
+
+/// Text of a bullet (`-`, `*`, `+`) or numbered (`1.`, `1)`) list item
+fn list_item_text(line: &str) -> Option<&str> {
+    if let Some(rest) = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))
+    {
+        return Some(rest.trim());
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits == 0 {
+        return None;
+    }
+    line[digits..]
+        .strip_prefix(". ")
+        .or_else(|| line[digits..].strip_prefix(") "))
+        .map(str::trim)
+}
+
+fn is_summary_section(section: Option<&str>) -> bool {
+    section.is_some_and(|heading| {
+        let heading = heading.to_lowercase();
+        NON_FINDING_HEADINGS
+            .iter()
+            .any(|word| heading.contains(word))
+    })
+}
+
+fn build_finding(lines: &[String], section: Option<String>) -> Option<ReviewFinding> {
+    // Task checkboxes inside a review are not findings
+    let first = lines.first()?;
+    if first.starts_with("[ ]") || first.starts_with("[x]") || first.starts_with("[X]") {
+        return None;
+    }
+
+    let (severity, first) = split_severity(first);
+    let mut body_lines = vec![first.to_string()];
+    body_lines.extend(lines[1..].iter().cloned());
+    let body = body_lines.join("\n").trim().to_string();
+
+    let plain = strip_emphasis(first);
+    let title = title_from(&plain);
+    if title.is_empty() {
+        return None;
+    }
+    Some(ReviewFinding {
+        title,
+        body,
+        section,
+        severity,
+    })
+}
+
+/// Split a leading severity marker off the item text
+fn split_severity(text: &str) -> (Option<String>, &str) {
+    let lower = text.to_lowercase();
+    for severity in SEVERITIES {
+        for prefix in [
+            format!("[{severity}]"),
+            format!("**{severity}:**"),
+            format!("**{severity}**:"),
+            format!("**[{severity}]**"),
+            format!("({severity})"),
+            format!("{severity}:"),
+            format!("severity: {severity}"),
+        ] {
+            // Guard the slice: lowercasing can change byte lengths outside ASCII
+            if lower.starts_with(&prefix)
+                && let Some(rest) = text.get(prefix.len()..)
+            {
+                let rest = rest.trim_start_matches([' ', '-', ':', '—']);
+                return (Some(severity.to_string()), rest);
+            }
+        }
+    }
+    (None, text)
+}
+
+fn strip_emphasis(text: &str) -> String {
+    text.replace("**", "").replace("__", "").replace('`', "")
+}
+
+/// First sentence (or the text before a colon label), capped at
+/// [`MAX_TITLE_CHARS`]
+fn title_from(text: &str) -> String {
+    let text = text.trim();
+    let end = text
+        .char_indices()
+        .find(|&(idx, c)| {
+            matches!(c, '.' | '!' | '?')
+                && text[idx + c.len_utf8()..]
+                    .chars()
+                    .next()
+                    .is_none_or(char::is_whitespace)
+        })
+        .map_or(text.len(), |(idx, _)| idx);
+    let sentence = text[..end].trim().trim_end_matches(':');
+
+    if sentence.chars().count() <= MAX_TITLE_CHARS {
+        return sentence.to_string();
+    }
+    let cut: String = sentence.chars().take(MAX_TITLE_CHARS - 1).collect();
+    let cut = cut.rsplit_once(' ').map_or(cut.as_str(), |(head, _)| head);
+    format!("{}…", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REVIEW: &str = "# Review\n\n## Summary\n\n- Requirements are well structured.\n\n## Design Issues\n\n1. **[High]** Retry policy is unspecified. The design never says how many times\n   payment capture is retried.\n   - Affects requirement 3\n2. Medium: `CacheLayer` has no eviction policy\n\n## Task Gaps\n\n- [ ] not a finding\n- Missing migration task for the `payments` table.\n\nFIXUP PLAN:\n\n```diff\n- removed line\n+ added line\n```\n";
+
+    #[test]
+    fn test_extracts_findings_with_sections_and_severity() {
+        let findings = extract_review_findings(REVIEW);
+        assert_eq!(findings.len(), 3);
+
+        assert_eq!(findings[0].title, "Retry policy is unspecified");
+        assert_eq!(findings[0].severity.as_deref(), Some("high"));
+        assert_eq!(findings[0].section.as_deref(), Some("Design Issues"));
+        assert!(findings[0].body.contains("payment capture is retried."));
+        assert!(findings[0].body.ends_with("- Affects requirement 3"));
+
+        assert_eq!(findings[1].title, "CacheLayer has no eviction policy");
+        assert_eq!(findings[1].severity.as_deref(), Some("medium"));
+
+        assert_eq!(
+            findings[2].title,
+            "Missing migration task for the payments table"
+        );
+        assert_eq!(findings[2].section.as_deref(), Some("Task Gaps"));
+        assert_eq!(findings[2].severity, None);
+    }
+
+    #[test]
+    fn test_long_titles_are_truncated_at_a_word() {
+        let review = format!("## Issues\n\n- {}\n", "word ".repeat(40));
+        let findings = extract_review_findings(&review);
+        assert!(findings[0].title.ends_with("word…"));
+        assert!(findings[0].title.chars().count() <= MAX_TITLE_CHARS);
+        assert!(extract_review_findings("No issues found.\n").is_empty());
+    }
+}
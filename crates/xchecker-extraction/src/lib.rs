@@ -11,9 +11,19 @@
 //! - Provide useful metrics for dashboards and gate checks
 //!
 //! Future B3.1 will add structured extraction of full requirement/design objects.
+//! The `.core.yaml` artifacts built from these summaries are typed in
+//! [`core_yaml`].
 
 use regex::Regex;
 
+pub mod core_yaml;
+mod findings;
+mod salvage;
+
+pub use core_yaml::{CORE_YAML_VERSION, CoreYamlError, DesignCore, RequirementsCore, TasksCore};
+pub use findings::{ReviewFinding, extract_review_findings};
+pub use salvage::{SalvagedPartial, TRUNCATION_MARKER, salvage_partial_markdown};
+
 /// Summary statistics extracted from a requirements markdown document
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct RequirementsSummary {
@@ -0,0 +1,165 @@
+//! Salvage of partial markdown from phases that failed mid-stream
+//!
+//! A response cut off by a provider failure usually ends inside a section,
+//! sometimes inside a code fence. Salvage trims it back to the last complete
+//! section and appends a truncation marker, so the `.partial.md` can be read,
+//! continued by hand, or fed back as resume context without a dangling tail.
+
+/// Marker comment that opens every truncation notice
+pub const TRUNCATION_MARKER: &str = "<!-- xchecker:truncated -->";
+
+/// Result of salvaging a partial response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SalvagedPartial {
+    /// Trimmed markdown with the truncation notice appended
+    pub content: String,
+    /// Number of complete sections (headings) kept
+    pub kept_sections: usize,
+    /// Bytes of the raw response dropped by trimming
+    pub dropped_bytes: usize,
+}
+
+/// Trim a partial markdown response to its last complete section
+///
+/// Heuristics, applied to lines outside fenced code blocks:
+/// - With two or more headings, the last section is assumed incomplete and
+///   everything from its heading onwards is dropped.
+/// - Otherwise the text is cut at the last paragraph break (blank line).
+/// - If nothing can be cut, an unterminated code fence is closed instead.
+///
+/// A truncation notice is always appended, even when nothing was dropped.
+#[must_use]
+pub fn salvage_partial_markdown(raw: &str) -> SalvagedPartial {
+    let raw = raw.trim_end();
+    let mut headings = Vec::new();
+    let mut paragraph_breaks = Vec::new();
+    let mut in_fence = false;
+    let mut offset = 0;
+
+    for line in raw.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        } else if !in_fence {
+            if trimmed.starts_with('#') {
+                headings.push(offset);
+            } else if trimmed.is_empty() {
+                paragraph_breaks.push(offset);
+            }
+        }
+        offset += line.len();
+    }
+
+    let cut = if headings.len() >= 2 {
+        headings.last().copied()
+    } else {
+        paragraph_breaks.last().copied().filter(|&at| at > 0)
+    };
+
+    let (mut kept, dropped_bytes) = match cut {
+        Some(at) => (raw[..at].trim_end().to_string(), raw.len() - at),
+        None => {
+            let mut kept = raw.to_string();
+            if in_fence {
+                kept.push_str("\n```");
+            }
+            (kept, 0)
+        }
+    };
+    let kept_sections = headings.iter().filter(|&&at| at < kept.len()).count();
+
+    let notice = if kept.is_empty() {
+        "> **Partial output:** the phase failed before any content was produced.".to_string()
+    } else if dropped_bytes > 0 {
+        format!(
+            "> **Partial output:** the phase failed mid-stream. The response was trimmed \
+             to its last complete section ({dropped_bytes} trailing bytes dropped)."
+        )
+    } else {
+        "> **Partial output:** the phase failed mid-stream. The content above may be incomplete."
+            .to_string()
+    };
+
+    if !kept.is_empty() {
+        kept.push_str("\n\n");
+    }
+    kept.push_str(TRUNCATION_MARKER);
+    kept.push('\n');
+    kept.push_str(&notice);
+    kept.push('\n');
+
+    SalvagedPartial {
+        content: kept,
+        kept_sections,
+        dropped_bytes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_salvage_drops_incomplete_last_section() {
+        let raw = "# Requirements\n\nIntro.\n\n## Requirement 1\n\nDone.\n\n## Requirement 2\n\nHalf a sent";
+        let salvaged = salvage_partial_markdown(raw);
+
+        assert!(
+            salvaged
+                .content
+                .starts_with("# Requirements\n\nIntro.\n\n## Requirement 1\n\nDone.\n\n")
+        );
+        assert!(!salvaged.content.contains("Requirement 2"));
+        assert!(salvaged.content.contains(TRUNCATION_MARKER));
+        assert_eq!(salvaged.kept_sections, 2);
+        assert_eq!(
+            salvaged.dropped_bytes,
+            "## Requirement 2\n\nHalf a sent".len()
+        );
+    }
+
+    #[test]
+    fn test_salvage_ignores_headings_inside_code_fences() {
+        let raw = "# Design\n\n```sh\n# not a heading\n```\n\n## API\n\nGET /";
+        let salvaged = salvage_partial_markdown(raw);
+
+        assert!(salvaged.content.contains("# not a heading\n```"));
+        assert!(!salvaged.content.contains("## API"));
+        assert_eq!(salvaged.kept_sections, 1);
+    }
+
+    #[test]
+    fn test_salvage_single_section_cuts_at_paragraph() {
+        let raw = "# Tasks\n\n- [ ] 1. First\n\n- [ ] 2. Sec";
+        let salvaged = salvage_partial_markdown(raw);
+
+        assert!(
+            salvaged
+                .content
+                .starts_with("# Tasks\n\n- [ ] 1. First\n\n")
+        );
+        assert!(!salvaged.content.contains("2. Sec"));
+    }
+
+    #[test]
+    fn test_salvage_closes_unterminated_fence() {
+        let raw = "```rust\nfn main() {";
+        let salvaged = salvage_partial_markdown(raw);
+
+        assert!(
+            salvaged
+                .content
+                .starts_with("```rust\nfn main() {\n```\n\n")
+        );
+        assert_eq!(salvaged.dropped_bytes, 0);
+    }
+
+    #[test]
+    fn test_salvage_empty_response() {
+        let salvaged = salvage_partial_markdown("  \n");
+
+        assert!(salvaged.content.starts_with(TRUNCATION_MARKER));
+        assert!(salvaged.content.contains("before any content"));
+        assert_eq!(salvaged.kept_sections, 0);
+    }
+}
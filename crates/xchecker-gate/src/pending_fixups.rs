@@ -6,19 +6,23 @@ use anyhow::Result;
 use std::path::Path;
 
 use crate::types::PendingFixupsStats;
+use xchecker_utils::compression;
+use xchecker_utils::types::PhaseId;
 
 /// Get pending fixups for a spec
 ///
 /// Analyzes the review artifact to determine if there are pending fixups.
 pub fn pending_fixups_for_spec(base_path: &Path) -> PendingFixupsStats {
-    let review_md_path = base_path.join("artifacts").join("30-review.md");
+    let review_md_path = base_path
+        .join("artifacts")
+        .join(PhaseId::Review.artifact_name("md"));
 
-    if !review_md_path.exists() {
+    if !compression::exists(&review_md_path) {
         return PendingFixupsStats::default();
     }
 
-    // Read the review content
-    let review_content = match std::fs::read_to_string(&review_md_path) {
+    // Read the review content (stored compressed when large)
+    let review_content = match compression::read_to_string(&review_md_path) {
         Ok(content) => content,
         Err(_) => return PendingFixupsStats::default(),
     };
@@ -173,4 +177,22 @@ mod tests {
         assert_eq!(stats.targets, 1);
         assert!(stats.est_added > 0);
     }
+
+    #[test]
+    fn test_pending_fixups_reads_compressed_review() {
+        let temp = tempfile::tempdir().unwrap();
+        let base_path = temp.path().join("spec");
+        std::fs::create_dir_all(base_path.join("artifacts")).unwrap();
+
+        let review_content = "```diff\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1 +1,2 @@\n fn a() {}\n+fn b() {}\n```\n";
+        std::fs::write(
+            base_path.join("artifacts").join("30-review.md.zst"),
+            compression::compress(review_content.as_bytes(), 3).unwrap(),
+        )
+        .unwrap();
+
+        let stats = pending_fixups_for_spec(&base_path);
+        assert_eq!(stats.targets, 1);
+        assert!(stats.est_added > 0);
+    }
 }
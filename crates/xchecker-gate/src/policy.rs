@@ -107,8 +107,9 @@ pub fn parse_phase(phase_str: &str) -> Result<PhaseId> {
         "review" => Ok(PhaseId::Review),
         "fixup" => Ok(PhaseId::Fixup),
         "final" => Ok(PhaseId::Final),
+        "localize" => Ok(PhaseId::Localize),
         _ => anyhow::bail!(
-            "Unknown phase '{}'. Valid phases: requirements, design, tasks, review, fixup, final",
+            "Unknown phase '{}'. Valid phases: requirements, design, tasks, review, fixup, final, localize",
             phase_str
         ),
     }
@@ -4,7 +4,7 @@
 //! which offers direct access to Claude models through their native API.
 
 use crate::LlmError;
-use crate::http_client::HttpClient;
+use crate::http_client::{HttpClient, read_json_body};
 use crate::types::{LlmBackend, LlmInvocation, LlmResult, Message, Role};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -104,7 +104,7 @@ impl AnthropicBackend {
             .as_ref()
             .and_then(|a| a.base_url.clone());
 
-        // Get default model from config
+        // Get default model from config, resolving aliases
         let default_model = config
             .llm
             .anthropic
@@ -116,7 +116,8 @@ impl AnthropicBackend {
                      Please set [llm.anthropic] model = \"model-name\"."
                         .to_string(),
                 )
-            })?;
+            })
+            .map(|model| crate::models::resolve_model("anthropic", &model).name)?;
 
         // Get default parameters from config
         let default_params = HttpParams {
@@ -237,6 +238,7 @@ impl LlmBackend for AnthropicBackend {
             max_tokens: params.max_tokens,
             temperature: params.temperature,
             system: system_prompt,
+            stop_sequences: inv.stop_sequences(),
         };
 
         // Build HTTP request
@@ -254,9 +256,10 @@ impl LlmBackend for AnthropicBackend {
             .await?;
 
         // Parse response
-        let response_body: AnthropicResponse = response.json().await.map_err(|e| {
-            LlmError::Transport(format!("Failed to parse Anthropic response: {}", e))
-        })?;
+        let response_body: AnthropicResponse =
+            read_json_body(response, &inv).await.map_err(|e| {
+                LlmError::Transport(format!("Failed to parse Anthropic response: {}", e))
+            })?;
 
         // Extract text content from content blocks
         let mut content_parts = Vec::new();
@@ -280,10 +283,13 @@ impl LlmBackend for AnthropicBackend {
         // Build result
         let mut result = LlmResult::new(content, "anthropic", model);
 
-        // Add token counts if available
+        // Add token counts if available, keeping the full usage object for receipts
         if let Some(usage) = response_body.usage {
-            result.tokens_input = Some(usage.input_tokens);
-            result.tokens_output = Some(usage.output_tokens);
+            if let Ok(tokens) = serde_json::from_value::<Usage>(usage.clone()) {
+                result.tokens_input = Some(tokens.input_tokens);
+                result.tokens_output = Some(tokens.output_tokens);
+            }
+            result = result.with_provider_usage(usage);
         }
 
         // Set timeout status (false since we got a response)
@@ -317,13 +323,16 @@ struct AnthropicRequest {
     temperature: f32,
     #[serde(skip_serializing_if = "Option::is_none")]
     system: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    stop_sequences: Vec<String>,
 }
 
 /// Anthropic response body
 #[derive(Debug, Clone, Deserialize)]
 struct AnthropicResponse {
     content: Vec<ContentBlock>,
-    usage: Option<Usage>,
+    /// Kept raw so cache and tier fields reach `provider_usage`
+    usage: Option<serde_json::Value>,
 }
 
 /// Content block in Anthropic response
@@ -505,6 +514,32 @@ mod tests {
         assert_eq!(anthropic_messages.len(), 2);
     }
 
+    #[test]
+    fn test_request_includes_stop_sequences_only_when_set() {
+        let mut request = AnthropicRequest {
+            model: "claude".to_string(),
+            messages: vec![],
+            max_tokens: 1024,
+            temperature: 0.5,
+            system: None,
+            stop_sequences: vec![],
+        };
+        let body = serde_json::to_value(&request).unwrap();
+        assert!(body.get("stop_sequences").is_none());
+
+        let inv = LlmInvocation::new(
+            "test-spec",
+            "test-phase",
+            "",
+            Duration::from_secs(60),
+            vec![],
+        )
+        .with_metadata("stop_sequences", serde_json::json!(["END"]));
+        request.stop_sequences = inv.stop_sequences();
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["stop_sequences"], serde_json::json!(["END"]));
+    }
+
     #[test]
     fn test_new_from_config_missing_api_key() {
         // Use a unique env var name to avoid conflicts with other tests
@@ -274,6 +274,7 @@ impl ClaudeCliBackend {
                             metadata.output_tokens = usage
                                 .get("output_tokens")
                                 .and_then(serde_json::Value::as_u64);
+                            metadata.usage = Some(usage.clone());
                         }
 
                         metadata.model = message
@@ -317,6 +318,8 @@ impl ClaudeCliBackend {
 struct StreamMetadata {
     input_tokens: Option<u64>,
     output_tokens: Option<u64>,
+    /// Raw `usage` object from the final message
+    usage: Option<serde_json::Value>,
     model: Option<String>,
     #[allow(dead_code)]
     stop_reason: Option<String>,
@@ -349,7 +352,12 @@ impl LlmBackend for ClaudeCliBackend {
         // Execute Claude CLI with timeout
         let mut response = self
             .runner
-            .execute_claude(&args, &prompt, Some(inv.timeout))
+            .execute_claude_with_progress(
+                &args,
+                &prompt,
+                Some(inv.timeout),
+                inv.progress.as_deref(),
+            )
             .await
             .map_err(|e| match e {
                 xchecker_runner::RunnerError::Timeout { timeout_seconds } => LlmError::Timeout {
@@ -365,6 +373,16 @@ impl LlmBackend for ClaudeCliBackend {
             });
         }
 
+        if response.exit_code != 0
+            && (crate::types::is_context_overflow(&response.stderr)
+                || crate::types::is_context_overflow(&response.stdout))
+        {
+            return Err(LlmError::ContextOverflow(format!(
+                "Claude CLI rejected the prompt as too long (exit code {})",
+                response.exit_code
+            )));
+        }
+
         let mut fallback_used = false;
         let mut stream_metadata = StreamMetadata::default();
 
@@ -385,7 +403,12 @@ impl LlmBackend for ClaudeCliBackend {
                             };
                             response = self
                                 .runner
-                                .execute_claude(&fallback_args, &prompt, Some(inv.timeout))
+                                .execute_claude_with_progress(
+                                    &fallback_args,
+                                    &prompt,
+                                    Some(inv.timeout),
+                                    inv.progress.as_deref(),
+                                )
                                 .await
                                 .map_err(|e| match e {
                                     xchecker_runner::RunnerError::Timeout { timeout_seconds } => {
@@ -430,6 +453,12 @@ impl LlmBackend for ClaudeCliBackend {
         {
             result = result.with_tokens(input, output);
         }
+        if let Some(usage) = stream_metadata.usage.clone() {
+            result = result.with_provider_usage(usage);
+        }
+        if let Some(usage) = response.process_usage {
+            result = result.with_process_usage(usage);
+        }
 
         // Set timeout status
         result = result.with_timeout(false);
@@ -480,4 +509,26 @@ mod tests {
         assert!(prompt.contains("User: Hello!"));
         assert!(prompt.contains("Assistant: Hi there!"));
     }
+
+    #[test]
+    fn test_stream_json_keeps_raw_usage() {
+        let output = concat!(
+            r#"{"type":"content_block_delta","delta":{"text":"Hello"}}"#,
+            "\n",
+            r#"{"type":"message_stop","message":{"model":"haiku","usage":{"input_tokens":10,"output_tokens":5,"cache_creation_input_tokens":3}}}"#,
+        );
+
+        let (content, metadata) = ClaudeCliBackend::parse_stream_json_output(output).unwrap();
+
+        assert_eq!(content, "Hello");
+        assert_eq!(metadata.input_tokens, Some(10));
+        assert_eq!(
+            metadata.usage,
+            Some(serde_json::json!({
+                "input_tokens": 10,
+                "output_tokens": 5,
+                "cache_creation_input_tokens": 3
+            }))
+        );
+    }
 }
@@ -0,0 +1,219 @@
+//! In-process scripted LLM backend for tests
+//!
+//! `FakeLlmClient` implements [`LlmBackend`] without spawning processes or
+//! making network calls. Each invocation pops the next scripted reply, so
+//! orchestration logic (validation retries, partial artifacts, receipt
+//! metadata) can be exercised deterministically and fast.
+
+use async_trait::async_trait;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use crate::types::{LlmBackend, LlmInvocation, LlmResult};
+use xchecker_utils::error::LlmError;
+
+/// Provider name reported by [`FakeLlmClient`] results
+pub const FAKE_PROVIDER: &str = "fake";
+
+/// Model reported when the invocation does not name one
+pub const FAKE_MODEL: &str = "fake-model";
+
+/// A single scripted reply
+#[derive(Debug)]
+enum ScriptedReply {
+    /// Successful response text with an optional provider exit code
+    Text {
+        response: String,
+        exit_code: Option<i32>,
+    },
+    /// Fully specified result returned verbatim
+    Result(Box<LlmResult>),
+    /// Invocation failure
+    Error(LlmError),
+}
+
+/// Scripted LLM backend for tests
+///
+/// Replies are consumed in order; invoking past the end of the script fails
+/// with `LlmError::Misconfiguration` so a test never silently reuses a reply.
+///
+/// # Example
+///
+/// ```rust
+/// use xchecker_llm::{FakeLlmClient, LlmError};
+///
+/// let fake = FakeLlmClient::new()
+///     .with_error(LlmError::Transport("connection reset".to_string()))
+///     .with_response("# Requirements Document\n...");
+/// assert_eq!(fake.remaining(), 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct FakeLlmClient {
+    script: Mutex<VecDeque<ScriptedReply>>,
+    invocations: Mutex<Vec<LlmInvocation>>,
+}
+
+impl FakeLlmClient {
+    /// Create a fake with an empty script
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a successful response
+    #[must_use]
+    pub fn with_response(self, response: impl Into<String>) -> Self {
+        self.push(ScriptedReply::Text {
+            response: response.into(),
+            exit_code: None,
+        })
+    }
+
+    /// Queue a response reported with a non-zero provider exit code
+    ///
+    /// The orchestrator treats this like a CLI provider that exited with an
+    /// error after producing partial output.
+    #[must_use]
+    pub fn with_exit_code(self, response: impl Into<String>, exit_code: i32) -> Self {
+        self.push(ScriptedReply::Text {
+            response: response.into(),
+            exit_code: Some(exit_code),
+        })
+    }
+
+    /// Queue a fully specified result (tokens, extensions, provider name)
+    #[must_use]
+    pub fn with_result(self, result: LlmResult) -> Self {
+        self.push(ScriptedReply::Result(Box::new(result)))
+    }
+
+    /// Queue an invocation failure
+    #[must_use]
+    pub fn with_error(self, error: LlmError) -> Self {
+        self.push(ScriptedReply::Error(error))
+    }
+
+    /// Number of scripted replies not yet consumed
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.script.lock().expect("fake script lock poisoned").len()
+    }
+
+    /// Invocations received so far, in order
+    #[must_use]
+    pub fn invocations(&self) -> Vec<LlmInvocation> {
+        self.invocations
+            .lock()
+            .expect("fake invocation lock poisoned")
+            .clone()
+    }
+
+    fn push(self, reply: ScriptedReply) -> Self {
+        self.script
+            .lock()
+            .expect("fake script lock poisoned")
+            .push_back(reply);
+        self
+    }
+}
+
+#[async_trait]
+impl LlmBackend for FakeLlmClient {
+    async fn invoke(&self, inv: LlmInvocation) -> Result<LlmResult, LlmError> {
+        let model = if inv.model.is_empty() {
+            FAKE_MODEL.to_string()
+        } else {
+            inv.model.clone()
+        };
+        let progress = inv.progress.clone();
+
+        let call_number = {
+            let mut invocations = self
+                .invocations
+                .lock()
+                .expect("fake invocation lock poisoned");
+            invocations.push(inv);
+            invocations.len()
+        };
+
+        let reply = self
+            .script
+            .lock()
+            .expect("fake script lock poisoned")
+            .pop_front();
+
+        match reply {
+            Some(ScriptedReply::Text {
+                response,
+                exit_code,
+            }) => {
+                if let Some(progress) = progress {
+                    progress.fetch_add(response.len() as u64, std::sync::atomic::Ordering::Relaxed);
+                }
+                let result = LlmResult::new(response, FAKE_PROVIDER, model);
+                Ok(match exit_code {
+                    Some(code) => result.with_extension("exit_code", serde_json::json!(code)),
+                    None => result,
+                })
+            }
+            Some(ScriptedReply::Result(result)) => Ok(*result),
+            Some(ScriptedReply::Error(error)) => Err(error),
+            None => Err(LlmError::Misconfiguration(format!(
+                "FakeLlmClient script exhausted on invocation {call_number}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Message;
+    use std::time::Duration;
+
+    fn invocation(model: &str) -> LlmInvocation {
+        LlmInvocation::new(
+            "spec-1",
+            "requirements",
+            model,
+            Duration::from_secs(5),
+            vec![Message::user("hello")],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_fake_replays_script_in_order() {
+        let fake = FakeLlmClient::new()
+            .with_error(LlmError::Transport("reset".to_string()))
+            .with_response("second")
+            .with_exit_code("partial", 1);
+
+        assert!(matches!(
+            fake.invoke(invocation("")).await,
+            Err(LlmError::Transport(_))
+        ));
+
+        let result = fake.invoke(invocation("sonnet")).await.unwrap();
+        assert_eq!(result.raw_response, "second");
+        assert_eq!(result.provider, FAKE_PROVIDER);
+        assert_eq!(result.model_used, "sonnet");
+
+        let result = fake.invoke(invocation("")).await.unwrap();
+        assert_eq!(result.model_used, FAKE_MODEL);
+        assert_eq!(
+            result.extensions.get("exit_code"),
+            Some(&serde_json::json!(1))
+        );
+
+        assert_eq!(fake.remaining(), 0);
+        assert_eq!(fake.invocations().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_fake_fails_when_script_exhausted() {
+        let fake = FakeLlmClient::new();
+
+        let err = fake.invoke(invocation("")).await.unwrap_err();
+        assert!(err.to_string().contains("script exhausted on invocation 1"));
+    }
+}
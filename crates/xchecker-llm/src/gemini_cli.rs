@@ -3,12 +3,15 @@
 //! Provides LLM backend implementation for Gemini CLI, wrapping the existing Runner
 //! infrastructure for process control, timeouts, and output buffering.
 
+use crate::runner::usage::wait_with_usage;
 use crate::runner::{BufferConfig, CommandSpec, Runner, WslOptions};
 use crate::{LlmBackend, LlmError, LlmInvocation, LlmResult, Message, Role};
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::process::Stdio;
+use std::time::Instant;
+use tokio::io::AsyncReadExt;
 use tokio::time::timeout;
 use xchecker_utils::types::RunnerMode;
 
@@ -101,15 +104,14 @@ impl GeminiCliBackend {
             claude_path: cfg.runner.claude_path.clone(),
         };
 
-        // 4. Get default model from config
-        let default_model = if let Some(gemini_config) = &cfg.llm.gemini {
-            gemini_config
-                .default_model
-                .clone()
-                .unwrap_or_else(|| "gemini-2.0-flash-lite".to_string())
-        } else {
-            "gemini-2.0-flash-lite".to_string()
-        };
+        // 4. Get default model from config, resolving aliases
+        let resolve = |model: &str| crate::models::resolve_model("gemini-cli", model).name;
+        let default_model = cfg
+            .llm
+            .gemini
+            .as_ref()
+            .and_then(|gemini_config| gemini_config.default_model.as_deref())
+            .map_or_else(|| "gemini-2.0-flash-lite".to_string(), resolve);
 
         // 5. Load profiles from config
         let mut profiles = HashMap::new();
@@ -122,8 +124,8 @@ impl GeminiCliBackend {
                     GeminiProfile {
                         model: profile_config
                             .model
-                            .clone()
-                            .unwrap_or_else(|| default_model.clone()),
+                            .as_deref()
+                            .map_or_else(|| default_model.clone(), resolve),
                         max_tokens: profile_config.max_tokens,
                     },
                 );
@@ -247,9 +249,12 @@ impl LlmBackend for GeminiCliBackend {
 
         // Convert to TokioCommand for async execution
         let mut cmd = cmd_spec.to_tokio_command();
+        // A timed-out child is killed when dropped; tokio reaps it, so the
+        // kill cannot hit a reused pid
         cmd.stdin(Stdio::null())
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
 
         // Set process group on Unix for killpg support
         #[cfg(unix)]
@@ -266,44 +271,80 @@ impl LlmBackend for GeminiCliBackend {
         }
 
         // Execute with timeout
-        let child = cmd
+        let started = Instant::now();
+        let mut child = cmd
             .spawn()
             .map_err(|e| LlmError::Transport(format!("Failed to spawn Gemini CLI: {e}")))?;
+        let (Some(mut stdout_pipe), Some(mut stderr_pipe)) =
+            (child.stdout.take(), child.stderr.take())
+        else {
+            return Err(LlmError::Transport(
+                "Failed to capture Gemini CLI output".to_string(),
+            ));
+        };
 
-        let output = if let Some(timeout_duration) = Some(inv.timeout) {
-            match timeout(timeout_duration, child.wait_with_output()).await {
-                Ok(Ok(output)) => output,
-                Ok(Err(e)) => {
-                    return Err(LlmError::Transport(format!(
-                        "Failed to wait for Gemini CLI: {e}"
-                    )));
-                }
-                Err(_) => {
-                    return Err(LlmError::Timeout {
-                        duration: inv.timeout,
-                    });
+        // Read both pipes to EOF, then reap the process with its resource usage
+        let run = async {
+            let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+            // Stdout is read in chunks so progress is reported as it arrives
+            let read_stdout = async {
+                let mut chunk = vec![0u8; 8192];
+                loop {
+                    let n = stdout_pipe.read(&mut chunk).await?;
+                    if n == 0 {
+                        return Ok::<_, std::io::Error>(());
+                    }
+                    stdout.extend_from_slice(&chunk[..n]);
+                    inv.record_progress(n);
                 }
+            };
+            let (stdout_read, stderr_read) =
+                tokio::join!(read_stdout, stderr_pipe.read_to_end(&mut stderr));
+            stdout_read?;
+            stderr_read?;
+            let (status, usage) = wait_with_usage(&mut child, started).await?;
+            Ok::<_, std::io::Error>((status, stdout, stderr, usage))
+        };
+
+        let (status, stdout, stderr, process_usage) = match timeout(inv.timeout, run).await {
+            Ok(Ok(output)) => output,
+            Ok(Err(e)) => {
+                return Err(LlmError::Transport(format!(
+                    "Failed to wait for Gemini CLI: {e}"
+                )));
+            }
+            Err(_) => {
+                return Err(LlmError::Timeout {
+                    duration: inv.timeout,
+                });
             }
-        } else {
-            child
-                .wait_with_output()
-                .await
-                .map_err(|e| LlmError::Transport(format!("Failed to wait for Gemini CLI: {e}")))?
         };
 
         // Check exit status
-        if !output.status.success() {
+        if !status.success() {
+            if crate::types::is_context_overflow(&String::from_utf8_lossy(&stderr)) {
+                return Err(LlmError::ContextOverflow(format!(
+                    "Gemini CLI rejected the prompt as too long (status: {status})"
+                )));
+            }
+            // Nothing written before the exit: the process crashed rather
+            // than reporting an error, so the orchestrator may restart it
+            if stdout.iter().all(u8::is_ascii_whitespace) {
+                return Err(LlmError::ProviderCrash {
+                    message: format!("Gemini CLI exited with status: {status}"),
+                    restarts: 0,
+                });
+            }
             return Err(LlmError::Transport(format!(
-                "Gemini CLI exited with status: {}",
-                output.status
+                "Gemini CLI exited with status: {status}"
             )));
         }
 
         // Treat stdout as opaque text (requirement 3.4.2)
-        let raw_response = String::from_utf8_lossy(&output.stdout).to_string();
+        let raw_response = String::from_utf8_lossy(&stdout).to_string();
 
         // Capture stderr and apply 2 KiB limit (requirement 3.4.3)
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        let stderr = String::from_utf8_lossy(&stderr).to_string();
         let stderr_redacted = if stderr.len() > 2048 {
             format!("{}... [truncated to 2 KiB]", &stderr[..2048])
         } else {
@@ -316,6 +357,7 @@ impl LlmBackend for GeminiCliBackend {
         // Set timeout status
         result = result.with_timeout(false);
         result = result.with_timeout_seconds(inv.timeout.as_secs());
+        result = result.with_process_usage(process_usage);
 
         // Add stderr to extensions if present (already redacted to ≤ 2 KiB)
         if !stderr_redacted.is_empty() {
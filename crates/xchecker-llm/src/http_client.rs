@@ -3,8 +3,9 @@
 //! This module provides a shared `reqwest::Client` configured once per process,
 //! with timeout and retry policies for reliable HTTP communication with LLM providers.
 
-use crate::LlmError;
+use crate::{LlmError, LlmInvocation};
 use reqwest::{Client, Response, StatusCode};
+use serde::de::DeserializeOwned;
 use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, warn};
@@ -80,6 +81,8 @@ impl HttpClient {
     /// Returns `LlmError` for various failure scenarios:
     /// - `LlmError::ProviderAuth` for 401/403 errors
     /// - `LlmError::ProviderQuota` for 429 errors
+    /// - `LlmError::ContextOverflow` for 413 errors and 400 errors reporting a
+    ///   prompt longer than the context window
     /// - `LlmError::ProviderOutage` for 5xx errors (after retries)
     /// - `LlmError::Timeout` for timeouts
     /// - `LlmError::Transport` for network errors (after retries)
@@ -121,6 +124,20 @@ impl HttpClient {
 
                     // Check for error status codes
                     if status.is_client_error() {
+                        // Context overflows come back as 400/413 with an explanatory body
+                        if matches!(
+                            status,
+                            StatusCode::BAD_REQUEST | StatusCode::PAYLOAD_TOO_LARGE
+                        ) {
+                            let body = response.text().await.unwrap_or_default();
+                            if status == StatusCode::PAYLOAD_TOO_LARGE
+                                || crate::types::is_context_overflow(&body)
+                            {
+                                return Err(LlmError::ContextOverflow(format!(
+                                    "{provider_name} rejected the prompt as too long: {status}"
+                                )));
+                            }
+                        }
                         return Err(map_client_error(status, provider_name));
                     }
 
@@ -185,6 +202,20 @@ impl HttpClient {
     }
 }
 
+/// Read a response body as JSON, reporting bytes to `inv`'s progress counter
+/// as each chunk arrives
+pub(crate) async fn read_json_body<T: DeserializeOwned>(
+    mut response: Response,
+    inv: &LlmInvocation,
+) -> Result<T, Box<dyn std::error::Error + Send + Sync>> {
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        inv.record_progress(chunk.len());
+        body.extend_from_slice(&chunk);
+    }
+    Ok(serde_json::from_slice(&body)?)
+}
+
 /// Map HTTP client error status codes to LlmError variants
 ///
 /// This function maps 4xx errors to appropriate LlmError types:
@@ -7,9 +7,13 @@
 mod anthropic_backend;
 mod budgeted_backend;
 mod claude_cli;
+#[cfg(any(test, feature = "test-utils"))]
+mod fake;
 mod gemini_cli;
 pub(crate) mod http_client;
+pub mod models;
 mod openrouter_backend;
+pub mod params;
 mod types;
 
 #[cfg(test)]
@@ -27,6 +31,10 @@ pub use types::{
 };
 pub use xchecker_utils::error::LlmError;
 
+// Scripted in-process backend for orchestration tests
+#[cfg(any(test, feature = "test-utils"))]
+pub use fake::{FAKE_MODEL, FAKE_PROVIDER, FakeLlmClient};
+
 // Test-only exports - hidden from documentation
 #[doc(hidden)]
 pub use budgeted_backend::BudgetedBackend;
@@ -138,15 +146,17 @@ pub fn from_config_with_fallback(
                 let reason = redact_error_message_for_logging(&primary_error.to_string());
 
                 // Log warning about fallback usage (redacted)
-                eprintln!(
-                    "Warning: Primary provider '{}' failed during construction: {}. Attempting fallback provider '{}'.",
-                    provider, reason, fallback_provider
+                tracing::warn!(
+                    "Primary provider '{}' failed during construction: {}. Attempting fallback provider '{}'.",
+                    provider,
+                    reason,
+                    fallback_provider
                 );
 
                 // Attempt to construct fallback backend
                 match construct_backend_for_provider(fallback_provider, config) {
                     Ok(fallback_backend) => {
-                        eprintln!(
+                        tracing::info!(
                             "Successfully constructed fallback provider '{}'. This usage will be recorded in receipt warnings.",
                             fallback_provider
                         );
@@ -161,8 +171,8 @@ pub fn from_config_with_fallback(
                     }
                     Err(fallback_error) => {
                         // Both primary and fallback failed
-                        eprintln!(
-                            "Error: Fallback provider '{}' also failed: {}",
+                        tracing::error!(
+                            "Fallback provider '{}' also failed: {}",
                             fallback_provider,
                             redact_error_message_for_logging(&fallback_error.to_string())
                         );
@@ -227,6 +237,22 @@ pub fn from_config(config: &Config) -> Result<Box<dyn LlmBackend>, LlmError> {
     Ok(backend)
 }
 
+/// Create an LLM backend for a specific provider, ignoring `[llm] provider`.
+///
+/// Used by diagnostics that exercise every configured provider (primary and
+/// fallback) independently. No fallback is attempted.
+///
+/// # Errors
+///
+/// Returns `LlmError::Unsupported` if the provider is unknown, or
+/// `LlmError::Misconfiguration` if provider-specific configuration is invalid.
+pub fn from_config_for_provider(
+    provider: &str,
+    config: &Config,
+) -> Result<Box<dyn LlmBackend>, LlmError> {
+    construct_backend_for_provider(provider, config)
+}
+
 #[cfg(test)]
 mod factory_tests {
     use super::*;
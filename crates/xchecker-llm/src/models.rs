@@ -0,0 +1,293 @@
+//! Model alias registry
+//!
+//! Maps the short names users put in config (`sonnet`, `flash`, ...) to the
+//! model names each provider expects, and records which models the
+//! provider has scheduled for retirement. The orchestrator resolves every
+//! configured model through [`resolve_model`] before invoking a backend, so
+//! receipts carry both the requested alias and the full name, and a
+//! deprecation warning is emitted when an alias points at a sunset model.
+//!
+//! Names that are not in the registry pass through unchanged; the registry
+//! never rejects a model the provider might still accept.
+
+use serde::Serialize;
+
+/// A model known to a provider
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ModelInfo {
+    /// Full model name passed to the provider
+    pub name: &'static str,
+    /// Short names that resolve to this model
+    pub aliases: Vec<&'static str>,
+    /// Date (YYYY-MM-DD) the provider retires or retired the model
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sunset: Option<&'static str>,
+    /// Suggested replacement for a sunset model
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<&'static str>,
+}
+
+impl ModelInfo {
+    /// Whether the provider has announced a retirement date for this model
+    #[must_use]
+    pub const fn is_deprecated(&self) -> bool {
+        self.sunset.is_some()
+    }
+}
+
+/// Registry row: name, aliases, and `(sunset, replacement)` for retired models
+type Entry = (
+    &'static str,
+    &'static [&'static str],
+    Option<(&'static str, &'static str)>,
+);
+
+/// Claude model families; the Claude CLI resolves these to the current version itself
+const CLAUDE_FAMILIES: &[Entry] = &[
+    ("sonnet", &["sonnet-latest"], None),
+    ("opus", &["opus-latest"], None),
+    ("haiku", &["haiku-latest"], None),
+];
+
+/// Versioned Claude models, valid for both the Claude CLI and the Anthropic API
+const CLAUDE_MODELS: &[Entry] = &[
+    ("claude-sonnet-4-5-20250929", &["claude-sonnet-4-5"], None),
+    ("claude-opus-4-1-20250805", &["claude-opus-4-1"], None),
+    ("claude-haiku-4-5-20251001", &["claude-haiku-4-5"], None),
+    ("claude-sonnet-4-20250514", &["claude-sonnet-4-0"], None),
+    ("claude-opus-4-20250514", &["claude-opus-4-0"], None),
+    (
+        "claude-3-7-sonnet-20250219",
+        &["claude-3-7-sonnet-latest"],
+        None,
+    ),
+    (
+        "claude-3-5-haiku-20241022",
+        &["claude-3-5-haiku-latest"],
+        None,
+    ),
+    ("claude-3-haiku-20240307", &[], None),
+    (
+        "claude-3-5-sonnet-20241022",
+        &["claude-3-5-sonnet-latest"],
+        Some(("2025-10-22", "claude-sonnet-4-5-20250929")),
+    ),
+    (
+        "claude-3-5-sonnet-20240620",
+        &[],
+        Some(("2025-10-22", "claude-sonnet-4-5-20250929")),
+    ),
+    (
+        "claude-3-opus-20240229",
+        &["claude-3-opus-latest"],
+        Some(("2026-01-05", "claude-opus-4-1-20250805")),
+    ),
+    (
+        "claude-3-sonnet-20240229",
+        &[],
+        Some(("2025-07-21", "claude-sonnet-4-5-20250929")),
+    ),
+];
+
+/// The Anthropic API needs versioned names, so families are aliases there
+const ANTHROPIC_FAMILY_ALIASES: &[(&str, &str)] = &[
+    ("claude-sonnet-4-5-20250929", "sonnet"),
+    ("claude-opus-4-1-20250805", "opus"),
+    ("claude-haiku-4-5-20251001", "haiku"),
+];
+
+const GEMINI_MODELS: &[Entry] = &[
+    ("gemini-2.5-pro", &["pro"], None),
+    ("gemini-2.5-flash", &["flash"], None),
+    ("gemini-2.5-flash-lite", &["flash-lite"], None),
+    ("gemini-2.0-flash", &[], None),
+    ("gemini-2.0-flash-lite", &[], None),
+    (
+        "gemini-1.5-pro",
+        &[],
+        Some(("2025-09-24", "gemini-2.5-pro")),
+    ),
+    (
+        "gemini-1.5-flash",
+        &[],
+        Some(("2025-09-24", "gemini-2.5-flash")),
+    ),
+];
+
+const OPENROUTER_MODELS: &[Entry] = &[
+    ("anthropic/claude-sonnet-4.5", &["sonnet"], None),
+    ("anthropic/claude-opus-4.1", &["opus"], None),
+    ("anthropic/claude-haiku-4.5", &["haiku"], None),
+    ("google/gemini-2.5-pro", &["gemini-pro"], None),
+    ("google/gemini-2.5-flash", &["gemini-flash"], None),
+    (
+        "anthropic/claude-3.5-sonnet",
+        &[],
+        Some(("2025-10-22", "anthropic/claude-sonnet-4.5")),
+    ),
+];
+
+/// Providers with a model table, in display order
+pub const PROVIDERS: [&str; 4] = ["claude-cli", "anthropic", "gemini-cli", "openrouter"];
+
+/// Models known for `provider` (empty for unknown providers)
+#[must_use]
+pub fn provider_models(provider: &str) -> Vec<ModelInfo> {
+    let tables: &[&[Entry]] = match provider {
+        "claude-cli" => &[CLAUDE_FAMILIES, CLAUDE_MODELS],
+        "anthropic" => &[CLAUDE_MODELS],
+        "gemini-cli" => &[GEMINI_MODELS],
+        "openrouter" => &[OPENROUTER_MODELS],
+        _ => &[],
+    };
+    tables
+        .iter()
+        .flat_map(|table| table.iter())
+        .map(|&(name, aliases, retired)| {
+            let mut aliases = aliases.to_vec();
+            if provider == "anthropic" {
+                aliases.extend(
+                    ANTHROPIC_FAMILY_ALIASES
+                        .iter()
+                        .filter(|(model, _)| *model == name)
+                        .map(|(_, alias)| *alias),
+                );
+            }
+            ModelInfo {
+                name,
+                aliases,
+                sunset: retired.map(|(sunset, _)| sunset),
+                replacement: retired.map(|(_, replacement)| replacement),
+            }
+        })
+        .collect()
+}
+
+/// A configured model name resolved against the registry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedModel {
+    /// Model name to pass to the provider
+    pub name: String,
+    /// The alias that was requested, when it differs from `name`
+    pub alias: Option<String>,
+    /// Registry entry, when the model is known
+    pub info: Option<ModelInfo>,
+}
+
+impl ResolvedModel {
+    /// Receipt warning for a model the provider has scheduled for retirement
+    #[must_use]
+    pub fn deprecation_warning(&self) -> Option<String> {
+        let info = self.info.as_ref()?;
+        let sunset = info.sunset?;
+        let requested = match &self.alias {
+            Some(alias) => format!("'{alias}' (resolves to {})", self.name),
+            None => format!("'{}'", self.name),
+        };
+        let replacement = info
+            .replacement
+            .map_or_else(String::new, |r| format!("; use '{r}' instead"));
+        Some(format!(
+            "model_deprecated: {requested} is retired by the provider as of {sunset}{replacement}"
+        ))
+    }
+}
+
+/// Resolve `requested` (an alias or full name) for `provider`
+///
+/// Empty and unknown names are returned unchanged with no registry entry.
+#[must_use]
+pub fn resolve_model(provider: &str, requested: &str) -> ResolvedModel {
+    let models = provider_models(provider);
+    if let Some(info) = models.iter().find(|m| m.name == requested) {
+        return ResolvedModel {
+            name: requested.to_string(),
+            alias: None,
+            info: Some(info.clone()),
+        };
+    }
+    if let Some(info) = models.into_iter().find(|m| m.aliases.contains(&requested)) {
+        return ResolvedModel {
+            name: info.name.to_string(),
+            alias: Some(requested.to_string()),
+            info: Some(info),
+        };
+    }
+    ResolvedModel {
+        name: requested.to_string(),
+        alias: None,
+        info: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aliases_resolve_per_provider() {
+        // The Claude CLI resolves families itself; the HTTP API needs versions
+        let cli = resolve_model("claude-cli", "sonnet-latest");
+        assert_eq!(cli.name, "sonnet");
+        assert_eq!(cli.alias.as_deref(), Some("sonnet-latest"));
+        assert_eq!(resolve_model("claude-cli", "haiku").name, "haiku");
+
+        let api = resolve_model("anthropic", "sonnet");
+        assert_eq!(api.name, "claude-sonnet-4-5-20250929");
+        assert_eq!(api.alias.as_deref(), Some("sonnet"));
+
+        let openrouter = resolve_model("openrouter", "sonnet");
+        assert_eq!(openrouter.name, "anthropic/claude-sonnet-4.5");
+
+        let full = resolve_model("anthropic", "claude-opus-4-1-20250805");
+        assert_eq!(full.alias, None);
+        assert!(full.info.is_some());
+    }
+
+    #[test]
+    fn test_unknown_models_pass_through() {
+        let resolved = resolve_model("gemini-cli", "gemini-experimental");
+        assert_eq!(resolved.name, "gemini-experimental");
+        assert_eq!(resolved.info, None);
+        assert_eq!(resolved.deprecation_warning(), None);
+
+        assert_eq!(resolve_model("custom", "sonnet").name, "sonnet");
+    }
+
+    #[test]
+    fn test_sunset_models_warn() {
+        let resolved = resolve_model("anthropic", "claude-3-5-sonnet-latest");
+        let warning = resolved.deprecation_warning().unwrap();
+        assert!(warning.starts_with("model_deprecated: 'claude-3-5-sonnet-latest'"));
+        assert!(warning.contains("2025-10-22"));
+        assert!(warning.contains("claude-sonnet-4-5-20250929"));
+
+        assert_eq!(
+            resolve_model("claude-cli", "haiku").deprecation_warning(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_registry_entries_are_unique() {
+        for provider in PROVIDERS {
+            let models = provider_models(provider);
+            let mut names: Vec<&str> = models
+                .iter()
+                .flat_map(|m| std::iter::once(m.name).chain(m.aliases.iter().copied()))
+                .collect();
+            let count = names.len();
+            names.sort_unstable();
+            names.dedup();
+            assert_eq!(names.len(), count, "duplicate name or alias for {provider}");
+            for model in models.iter().filter(|m| m.is_deprecated()) {
+                let replacement = model.replacement.unwrap();
+                assert!(
+                    models
+                        .iter()
+                        .any(|m| m.name == replacement && !m.is_deprecated())
+                );
+            }
+        }
+    }
+}
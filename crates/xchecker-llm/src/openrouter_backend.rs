@@ -4,7 +4,7 @@
 //! access to multiple models through a unified OpenAI-compatible API.
 
 use crate::LlmError;
-use crate::http_client::HttpClient;
+use crate::http_client::{HttpClient, read_json_body};
 use crate::types::{LlmBackend, LlmInvocation, LlmResult, Message, Role};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -107,7 +107,7 @@ impl OpenRouterBackend {
             .as_ref()
             .and_then(|or| or.base_url.clone());
 
-        // Get default model from config
+        // Get default model from config, resolving aliases
         let default_model = config
             .llm
             .openrouter
@@ -119,7 +119,8 @@ impl OpenRouterBackend {
                      Please set [llm.openrouter] model = \"model-name\"."
                         .to_string(),
                 )
-            })?;
+            })
+            .map(|model| crate::models::resolve_model("openrouter", &model).name)?;
 
         // Get default parameters from config
         let default_params = HttpParams {
@@ -217,6 +218,7 @@ impl LlmBackend for OpenRouterBackend {
             max_tokens: params.max_tokens,
             temperature: params.temperature,
             stream: false,
+            stop: inv.stop_sequences(),
         };
 
         // Build HTTP request
@@ -235,9 +237,10 @@ impl LlmBackend for OpenRouterBackend {
             .await?;
 
         // Parse response
-        let response_body: OpenRouterResponse = response.json().await.map_err(|e| {
-            LlmError::Transport(format!("Failed to parse OpenRouter response: {}", e))
-        })?;
+        let response_body: OpenRouterResponse =
+            read_json_body(response, &inv).await.map_err(|e| {
+                LlmError::Transport(format!("Failed to parse OpenRouter response: {}", e))
+            })?;
 
         // Extract content from first choice
         let choice = response_body.choices.first().ok_or_else(|| {
@@ -258,10 +261,13 @@ impl LlmBackend for OpenRouterBackend {
         // Build result
         let mut result = LlmResult::new(content, "openrouter", model);
 
-        // Add token counts if available
+        // Add token counts if available, keeping the full usage object for receipts
         if let Some(usage) = response_body.usage {
-            result.tokens_input = Some(usage.prompt_tokens);
-            result.tokens_output = Some(usage.completion_tokens);
+            if let Ok(tokens) = serde_json::from_value::<Usage>(usage.clone()) {
+                result.tokens_input = Some(tokens.prompt_tokens);
+                result.tokens_output = Some(tokens.completion_tokens);
+            }
+            result = result.with_provider_usage(usage);
         }
 
         // Set timeout status (false since we got a response)
@@ -301,13 +307,16 @@ struct OpenRouterRequest {
     max_tokens: u32,
     temperature: f32,
     stream: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    stop: Vec<String>,
 }
 
 /// OpenRouter response body (OpenAI-compatible)
 #[derive(Debug, Clone, Deserialize)]
 struct OpenRouterResponse {
     choices: Vec<Choice>,
-    usage: Option<Usage>,
+    /// Kept raw so cost and cached-token details reach `provider_usage`
+    usage: Option<serde_json::Value>,
 }
 
 /// Choice in OpenRouter response
@@ -0,0 +1,76 @@
+//! Generation parameters shared across providers
+//!
+//! Per-phase config (`[phases.<phase>]`) can set a temperature, an output
+//! token cap, and stop sequences. The orchestrator passes them to every
+//! backend through [`LlmInvocation::metadata`](crate::LlmInvocation) under
+//! [`GenerationParam::metadata_key`]; parameters a provider cannot honour
+//! are dropped before the call and reported as receipt warnings.
+
+/// A generation parameter configurable per phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenerationParam {
+    Temperature,
+    MaxOutputTokens,
+    StopSequences,
+}
+
+impl GenerationParam {
+    /// All parameters, in receipt order
+    pub const ALL: [Self; 3] = [
+        Self::Temperature,
+        Self::MaxOutputTokens,
+        Self::StopSequences,
+    ];
+
+    /// Config key and receipt flag name
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Temperature => "temperature",
+            Self::MaxOutputTokens => "max_output_tokens",
+            Self::StopSequences => "stop_sequences",
+        }
+    }
+
+    /// Invocation metadata key read by the backends
+    #[must_use]
+    pub const fn metadata_key(self) -> &'static str {
+        match self {
+            Self::Temperature => "temperature",
+            Self::MaxOutputTokens => "max_tokens",
+            Self::StopSequences => "stop_sequences",
+        }
+    }
+
+    /// Whether `provider` honours this parameter
+    ///
+    /// Unknown providers are assumed to support everything, so custom
+    /// backends are not flooded with warnings.
+    #[must_use]
+    pub fn supported_by(self, provider: &str) -> bool {
+        match provider {
+            // The Claude CLI has no flag for stop sequences
+            "claude-cli" => self != Self::StopSequences,
+            // The Gemini CLI only exposes an output token cap
+            "gemini-cli" => self == Self::MaxOutputTokens,
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_support() {
+        for param in GenerationParam::ALL {
+            assert!(param.supported_by("anthropic"));
+            assert!(param.supported_by("openrouter"));
+        }
+        assert!(GenerationParam::Temperature.supported_by("claude-cli"));
+        assert!(!GenerationParam::StopSequences.supported_by("claude-cli"));
+        assert!(GenerationParam::MaxOutputTokens.supported_by("gemini-cli"));
+        assert!(!GenerationParam::Temperature.supported_by("gemini-cli"));
+    }
+}
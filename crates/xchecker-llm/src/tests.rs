@@ -170,6 +170,48 @@ mod llm_tests {
         );
     }
 
+    #[test]
+    fn test_provider_usage_reaches_llm_info_verbatim() {
+        let usage = serde_json::json!({
+            "input_tokens": 1200,
+            "output_tokens": 300,
+            "cache_read_input_tokens": 800,
+            "service_tier": "standard"
+        });
+        let info = LlmResult::new("Response", "anthropic", "sonnet")
+            .with_provider_usage(usage.clone())
+            .into_llm_info();
+        assert_eq!(info.provider_usage, Some(usage));
+
+        // Empty or non-object usage is not recorded
+        let empty = LlmResult::new("Response", "anthropic", "sonnet")
+            .with_provider_usage(serde_json::json!({}))
+            .with_provider_usage(serde_json::json!(42));
+        assert!(empty.extensions.is_empty());
+        assert_eq!(empty.into_llm_info().provider_usage, None);
+    }
+
+    #[test]
+    fn test_process_usage_reaches_llm_info() {
+        let usage = xchecker_runner::ProcessUsage {
+            wall_time_ms: 2500,
+            user_cpu_ms: Some(900),
+            system_cpu_ms: Some(120),
+            peak_rss_bytes: None,
+        };
+        let info = LlmResult::new("Response", "gemini-cli", "gemini-2.0-flash")
+            .with_process_usage(usage)
+            .into_llm_info();
+        assert_eq!(info.process_usage, Some(usage));
+
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["process_usage"]["wall_time_ms"], 2500);
+        assert!(json["process_usage"].get("peak_rss_bytes").is_none());
+
+        let none = LlmResult::new("Response", "anthropic", "sonnet").into_llm_info();
+        assert_eq!(none.process_usage, None);
+    }
+
     #[test]
     fn test_llm_result_serialization() {
         let result = LlmResult::new("Test response", "test-provider", "test-model")
@@ -189,6 +231,25 @@ mod llm_tests {
         assert_eq!(deserialized.timeout_seconds, Some(600));
     }
 
+    #[test]
+    fn test_context_overflow_detection() {
+        use crate::types::is_context_overflow;
+
+        assert!(is_context_overflow(
+            r#"{"type":"error","error":{"type":"invalid_request_error","message":"prompt is too long: 210000 tokens > 200000 maximum"}}"#
+        ));
+        assert!(is_context_overflow(
+            r#"{"error":{"code":"context_length_exceeded"}}"#
+        ));
+        assert!(is_context_overflow(
+            "The input token count (1200000) exceeds the maximum number of tokens allowed"
+        ));
+        assert!(is_context_overflow("Error: Prompt is too long"));
+        assert!(!is_context_overflow(
+            r#"{"error":{"message":"max_tokens: Field required"}}"#
+        ));
+    }
+
     #[test]
     fn test_llm_error_display() {
         let err = LlmError::Transport("Connection failed".to_string());
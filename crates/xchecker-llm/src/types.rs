@@ -3,11 +3,32 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
 use crate::LlmError;
+use xchecker_runner::ProcessUsage;
 use xchecker_utils::types::LlmInfo;
 
+/// Phrases providers use when the prompt exceeds the model's context window
+const CONTEXT_OVERFLOW_MARKERS: &[&str] = &[
+    "prompt is too long",
+    "context_length_exceeded",
+    "maximum context length",
+    "context window",
+    "exceeds the maximum number of tokens",
+    "input is too long",
+];
+
+/// Whether a provider error message reports a context-window overflow
+pub(crate) fn is_context_overflow(message: &str) -> bool {
+    let message = message.to_lowercase();
+    CONTEXT_OVERFLOW_MARKERS
+        .iter()
+        .any(|marker| message.contains(marker))
+}
+
 /// Execution strategy determining how LLMs interact with the system
 /// Reserved for future multi-strategy support (V15+); currently only Controlled is used
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -98,6 +119,9 @@ pub struct LlmInvocation {
     pub messages: Vec<Message>,
     /// Provider-specific metadata (e.g., temperature, top_p, max_tokens)
     pub metadata: HashMap<String, serde_json::Value>,
+    /// Counter the backend adds response bytes to as it reads them, so the
+    /// phase heartbeat can tell a streaming call from a stalled one
+    pub progress: Option<Arc<AtomicU64>>,
 }
 
 impl LlmInvocation {
@@ -117,6 +141,21 @@ impl LlmInvocation {
             timeout,
             messages,
             metadata: HashMap::new(),
+            progress: None,
+        }
+    }
+
+    /// Report response bytes read to `progress`
+    #[must_use]
+    pub fn with_progress(mut self, progress: Arc<AtomicU64>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Add `bytes` read from the provider to the progress counter, if any
+    pub fn record_progress(&self, bytes: usize) {
+        if let Some(progress) = &self.progress {
+            progress.fetch_add(bytes as u64, Ordering::Relaxed);
         }
     }
 
@@ -127,6 +166,21 @@ impl LlmInvocation {
         self.metadata.insert(key.into(), value);
         self
     }
+
+    /// Stop sequences requested in `metadata["stop_sequences"]`
+    #[must_use]
+    pub fn stop_sequences(&self) -> Vec<String> {
+        self.metadata
+            .get("stop_sequences")
+            .and_then(|v| v.as_array())
+            .map(|stops| {
+                stops
+                    .iter()
+                    .filter_map(|s| s.as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 }
 
 /// Result from an LLM backend invocation
@@ -172,6 +226,14 @@ impl LlmFallbackInfo {
     }
 }
 
+/// Extension key for the provider-reported usage object
+/// (see [`LlmResult::with_provider_usage`])
+pub const PROVIDER_USAGE_EXTENSION: &str = "provider_usage";
+
+/// Extension key for the provider subprocess resource usage
+/// (see [`LlmResult::with_process_usage`])
+pub const PROCESS_USAGE_EXTENSION: &str = "process_usage";
+
 impl LlmResult {
     /// Create a new LLM result
     #[must_use]
@@ -215,6 +277,31 @@ impl LlmResult {
         self
     }
 
+    /// Record the provider-reported usage object verbatim
+    ///
+    /// Each backend passes the usage object from wherever its provider puts
+    /// it in the response; it is copied to the receipt's `llm.provider_usage`
+    /// unchanged. Empty or non-object values are ignored.
+    #[must_use]
+    pub fn with_provider_usage(self, usage: serde_json::Value) -> Self {
+        if usage.as_object().is_some_and(|fields| !fields.is_empty()) {
+            self.with_extension(PROVIDER_USAGE_EXTENSION, usage)
+        } else {
+            self
+        }
+    }
+
+    /// Record the resources the provider CLI process consumed
+    ///
+    /// Copied to the receipt's `llm.process_usage`.
+    #[must_use]
+    pub fn with_process_usage(self, usage: ProcessUsage) -> Self {
+        match serde_json::to_value(usage) {
+            Ok(value) => self.with_extension(PROCESS_USAGE_EXTENSION, value),
+            Err(_) => self,
+        }
+    }
+
     /// Add an extension field
     #[must_use]
     pub fn with_extension(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
@@ -230,6 +317,18 @@ impl LlmResult {
             .extensions
             .get("budget_exhausted")
             .and_then(|v| v.as_bool());
+        // Set by the orchestrator when invalid responses were retried
+        let requests = self
+            .extensions
+            .get("response_validation_attempts")
+            .and_then(|v| v.as_u64())
+            .and_then(|attempts| u32::try_from(attempts).ok())
+            .or(Some(1));
+        let provider_usage = self.extensions.get(PROVIDER_USAGE_EXTENSION).cloned();
+        let process_usage = self
+            .extensions
+            .get(PROCESS_USAGE_EXTENSION)
+            .and_then(|v| serde_json::from_value(v.clone()).ok());
 
         LlmInfo {
             provider: Some(self.provider),
@@ -239,6 +338,9 @@ impl LlmResult {
             timed_out: self.timed_out,
             timeout_seconds: self.timeout_seconds,
             budget_exhausted,
+            requests,
+            provider_usage,
+            process_usage,
         }
     }
 }
@@ -0,0 +1,238 @@
+//! Phase heartbeat written next to the spec lock
+//!
+//! A lock only says that some process claimed a spec; it cannot tell a phase
+//! that is waiting on a slow provider from one whose process hung or died.
+//! While a phase executes, [`HeartbeatWriter`] rewrites `.lock.heartbeat` in
+//! the spec root every [`HEARTBEAT_INTERVAL_SECS`] with the phase, the time,
+//! and the bytes received so far. Providers add bytes to the writer's
+//! [`progress`](HeartbeatWriter::progress) counter as they read them, and the
+//! heartbeat records when that count last advanced. The sidecar is removed
+//! when the phase ends, so a heartbeat that exists but has stopped updating
+//! means the run is gone, and one whose byte count has not moved for
+//! [`HEARTBEAT_STALL_INTERVALS`] beats means the provider has stalled.
+
+use camino::Utf8PathBuf;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::process;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Seconds between heartbeat writes while a phase executes
+pub const HEARTBEAT_INTERVAL_SECS: u64 = 15;
+
+/// A heartbeat older than this is considered silent (hung or dead run)
+pub const HEARTBEAT_SILENT_AFTER_SECS: u64 = 120;
+
+/// Beats without new provider bytes after which a run is considered stalled
+pub const HEARTBEAT_STALL_INTERVALS: u64 = 20;
+
+/// Seconds without new provider bytes after which a run is considered stalled
+pub const HEARTBEAT_STALLED_AFTER_SECS: u64 = HEARTBEAT_INTERVAL_SECS * HEARTBEAT_STALL_INTERVALS;
+
+/// File name of the heartbeat sidecar inside the spec root
+const HEARTBEAT_FILE: &str = ".lock.heartbeat";
+
+/// Progress record of the phase currently executing for a spec
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Heartbeat {
+    /// Process executing the phase
+    pub pid: u32,
+    /// Spec being executed
+    pub spec_id: String,
+    /// Phase being executed (e.g. `design`)
+    pub phase: String,
+    /// When the phase started
+    pub started_at: DateTime<Utc>,
+    /// When the heartbeat was last written
+    pub updated_at: DateTime<Utc>,
+    /// Provider response bytes received so far
+    pub bytes_streamed: u64,
+    /// When `bytes_streamed` last advanced (absent before the first byte)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress_at: Option<DateTime<Utc>>,
+}
+
+/// Liveness of a run, judged from its heartbeat
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeartbeatState {
+    /// Updated within [`HEARTBEAT_SILENT_AFTER_SECS`]: the phase is making progress
+    Active,
+    /// Still updating, but no provider bytes for [`HEARTBEAT_STALLED_AFTER_SECS`]
+    Stalled,
+    /// Stopped updating: the phase is hung or its process died
+    Silent,
+}
+
+impl HeartbeatState {
+    /// Stable lowercase name used in status and doctor output
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Stalled => "stalled",
+            Self::Silent => "silent",
+        }
+    }
+}
+
+impl Heartbeat {
+    /// Path of the heartbeat sidecar for a spec
+    #[must_use]
+    pub fn path(spec_id: &str) -> Utf8PathBuf {
+        crate::spec_root(spec_id).join(HEARTBEAT_FILE)
+    }
+
+    /// Load the heartbeat for a spec, if a phase is (or was) executing
+    ///
+    /// Unreadable or partially written sidecars are treated as absent.
+    #[must_use]
+    pub fn load(spec_id: &str) -> Option<Self> {
+        let content = fs::read_to_string(Self::path(spec_id)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Seconds since the heartbeat was last written (0 if written in the future)
+    #[must_use]
+    pub fn age_secs(&self) -> u64 {
+        u64::try_from((Utc::now() - self.updated_at).num_seconds()).unwrap_or(0)
+    }
+
+    /// Seconds since provider bytes last arrived, or since the phase started
+    /// if none have
+    #[must_use]
+    pub fn progress_age_secs(&self) -> u64 {
+        let since = self.progress_at.unwrap_or(self.started_at);
+        u64::try_from((Utc::now() - since).num_seconds()).unwrap_or(0)
+    }
+
+    /// Whether the run is still making progress
+    #[must_use]
+    pub fn state(&self) -> HeartbeatState {
+        if self.age_secs() > HEARTBEAT_SILENT_AFTER_SECS {
+            HeartbeatState::Silent
+        } else if self.progress_age_secs() > HEARTBEAT_STALLED_AFTER_SECS {
+            HeartbeatState::Stalled
+        } else {
+            HeartbeatState::Active
+        }
+    }
+}
+
+/// Heartbeats of every spec with a phase executing (or left behind by a dead run)
+///
+/// Sorted by spec ID. Returns an empty list when no specs exist.
+#[must_use]
+pub fn list_heartbeats() -> Vec<Heartbeat> {
+    let Ok(entries) = fs::read_dir(crate::xchecker_home().join("specs")) else {
+        return Vec::new();
+    };
+    let mut heartbeats: Vec<Heartbeat> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|spec_id| Heartbeat::load(&spec_id))
+        .collect();
+    heartbeats.sort_by(|a, b| a.spec_id.cmp(&b.spec_id));
+    heartbeats
+}
+
+/// Background writer that keeps a spec's heartbeat fresh while a phase runs
+///
+/// Writes immediately, then every interval; dropping the writer stops it and
+/// removes the sidecar.
+pub struct HeartbeatWriter {
+    path: Utf8PathBuf,
+    bytes: Arc<AtomicU64>,
+    stop: Arc<(Mutex<bool>, Condvar)>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl HeartbeatWriter {
+    /// Start writing heartbeats for `phase` of `spec_id`
+    #[must_use]
+    pub fn start(spec_id: &str, phase: &str) -> Self {
+        Self::start_with_interval(spec_id, phase, Duration::from_secs(HEARTBEAT_INTERVAL_SECS))
+    }
+
+    /// Start writing heartbeats with a custom interval
+    #[must_use]
+    pub fn start_with_interval(spec_id: &str, phase: &str, interval: Duration) -> Self {
+        // Resolve the path on the caller's thread (honours test home overrides)
+        let path = Heartbeat::path(spec_id);
+        let bytes = Arc::new(AtomicU64::new(0));
+        let stop = Arc::new((Mutex::new(false), Condvar::new()));
+
+        let mut heartbeat = Heartbeat {
+            pid: process::id(),
+            spec_id: spec_id.to_string(),
+            phase: phase.to_string(),
+            started_at: Utc::now(),
+            updated_at: Utc::now(),
+            bytes_streamed: 0,
+            progress_at: None,
+        };
+        let (thread_path, thread_bytes, thread_stop) = (path.clone(), bytes.clone(), stop.clone());
+
+        let handle = thread::spawn(move || {
+            let (stopped, signal) = &*thread_stop;
+            let Ok(mut stopped_guard) = stopped.lock() else {
+                return;
+            };
+            loop {
+                heartbeat.updated_at = Utc::now();
+                let bytes = thread_bytes.load(Ordering::Relaxed);
+                if bytes > heartbeat.bytes_streamed {
+                    heartbeat.bytes_streamed = bytes;
+                    heartbeat.progress_at = Some(heartbeat.updated_at);
+                }
+                // Best effort: a missed beat only makes the run look slower
+                if let Ok(json) = serde_json::to_string_pretty(&heartbeat) {
+                    let _ = crate::write_file_atomic(&thread_path, &json);
+                }
+
+                let Ok((guard, _)) = signal.wait_timeout(stopped_guard, interval) else {
+                    return;
+                };
+                stopped_guard = guard;
+                if *stopped_guard {
+                    return;
+                }
+            }
+        });
+
+        Self {
+            path,
+            bytes,
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// Add provider response bytes received; reported on the next beat
+    pub fn record_bytes(&self, bytes: u64) {
+        self.bytes.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// Counter of provider response bytes, shared with the provider so it can
+    /// report bytes as they are read
+    #[must_use]
+    pub fn progress(&self) -> Arc<AtomicU64> {
+        self.bytes.clone()
+    }
+}
+
+impl Drop for HeartbeatWriter {
+    fn drop(&mut self) {
+        let (stopped, signal) = &*self.stop;
+        if let Ok(mut stopped) = stopped.lock() {
+            *stopped = true;
+            signal.notify_all();
+        }
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        let _ = fs::remove_file(&self.path);
+    }
+}
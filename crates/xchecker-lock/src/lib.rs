@@ -9,6 +9,9 @@
 //! - `lock.json`: legacy execution lock for model / CLI drift detection
 //! - `flow.lock`: reproducibility envelope for the governed flow boundary
 //! - `promotions/<phase>.lock`: trust object for artifacts promoted through a gate
+//!
+//! While a phase executes, a heartbeat sidecar (`.lock.heartbeat`) records its
+//! progress so a hung run can be told apart from an active one.
 
 use anyhow::Result;
 use camino::Utf8PathBuf;
@@ -20,7 +23,14 @@ use std::fs;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::process;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+mod heartbeat;
+
+pub use heartbeat::{
+    HEARTBEAT_INTERVAL_SECS, HEARTBEAT_SILENT_AFTER_SECS, HEARTBEAT_STALL_INTERVALS,
+    HEARTBEAT_STALLED_AFTER_SECS, Heartbeat, HeartbeatState, HeartbeatWriter, list_heartbeats,
+};
 
 // Thread-local override used only in tests to avoid process-global env races.
 thread_local! {
@@ -30,6 +40,12 @@ thread_local! {
 /// Default age threshold for considering a lock stale (in seconds)
 const DEFAULT_STALE_THRESHOLD_SECS: u64 = 3600; // 1 hour
 
+/// How long a phase waits for another spec to release the repo lock
+pub const DEFAULT_REPO_LOCK_WAIT: Duration = Duration::from_secs(300);
+
+/// Interval between repo lock attempts while waiting
+const REPO_LOCK_POLL: Duration = Duration::from_millis(200);
+
 /// Lock information stored in the lock file
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LockInfo {
@@ -157,7 +173,7 @@ pub struct PromotionLock {
 }
 
 /// Drift pair showing locked vs current value
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct DriftPair {
     /// Value from lockfile
     pub locked: String,
@@ -197,6 +213,15 @@ pub enum LockError {
         age_secs: u64,
     },
 
+    #[error(
+        "Working tree is busy: spec '{holder_spec}' (PID {pid}) is modifying it; gave up after {waited_secs}s"
+    )]
+    RepoBusy {
+        holder_spec: String,
+        pid: u32,
+        waited_secs: u64,
+    },
+
     #[error("Lock file is corrupted or invalid: {reason}")]
     CorruptedLock { reason: String },
 
@@ -839,8 +864,9 @@ impl FileLock {
         // Atomic rename to mark as stale
         match fs::rename(lock_path, &stale_path) {
             Ok(()) => {
-                // Best-effort cleanup of stale file (ignore errors)
+                // Best-effort cleanup of stale file and the old run's heartbeat (ignore errors)
                 let _ = fs::remove_file(&stale_path);
+                let _ = fs::remove_file(Heartbeat::path(spec_id));
                 Ok(())
             }
             Err(e) if e.kind() == io::ErrorKind::NotFound => {
@@ -1019,6 +1045,13 @@ impl FileLock {
 
         let is_stale = lock_age > ttl_seconds;
 
+        // A heartbeat from the lock holder overrides age-based guesses: a fresh
+        // one means the phase is progressing even if the PID check cannot see
+        // the process; a silent one from a dead process means the run is gone.
+        let heartbeat =
+            Heartbeat::load(spec_id).filter(|heartbeat| heartbeat.pid == existing_lock.pid);
+        let heartbeat_state = heartbeat.as_ref().map(Heartbeat::state);
+
         // Check if the process is still running
         if Self::is_process_running(existing_lock.pid) {
             // Process is running - this is a fresh lock
@@ -1034,6 +1067,20 @@ impl FileLock {
             return Ok(());
         }
 
+        match heartbeat_state {
+            // A stalled run is still beating, so its process is alive
+            Some(HeartbeatState::Active | HeartbeatState::Stalled) if !force => {
+                let created_ago = Self::format_duration_since(existing_lock.created_at);
+                return Err(LockError::ConcurrentExecution {
+                    spec_id: spec_id.to_string(),
+                    pid: existing_lock.pid,
+                    created_ago,
+                });
+            }
+            Some(HeartbeatState::Silent) => return Ok(()),
+            _ => {}
+        }
+
         // Process is not running - check staleness
         if is_stale {
             if force {
@@ -1174,6 +1221,134 @@ impl Drop for FileLock {
     }
 }
 
+/// Repo-level advisory lock held while a phase writes to the working tree
+///
+/// Distinct from the per-spec [`FileLock`]: two specs never contend for each
+/// other's spec lock, yet fixups they apply and hooks they run mutate the same
+/// checkout. Phases that do either hold this lock, so those writes are
+/// serialized instead of interleaved. It is an OS file lock on a file that
+/// persists, so it is released when the holder exits or crashes and never
+/// goes stale.
+pub struct RepoLock {
+    /// Locked file; unlocked on drop
+    file: fs::File,
+    /// Holder recorded in the lock file
+    lock_info: LockInfo,
+}
+
+impl RepoLock {
+    /// Acquire the lock for the working tree containing the current directory
+    ///
+    /// Waits up to `wait` for another holder to finish, then fails with
+    /// [`LockError::RepoBusy`] naming it.
+    pub fn acquire(spec_id: &str, wait: Duration) -> Result<Self, LockError> {
+        Self::acquire_at(&Self::path(), spec_id, wait)
+    }
+
+    /// Acquire the repo lock stored at `path`
+    pub fn acquire_at(path: &Path, spec_id: &str, wait: Duration) -> Result<Self, LockError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+
+        let started = Instant::now();
+        loop {
+            match file.try_lock() {
+                Ok(()) => break,
+                Err(fs::TryLockError::WouldBlock) if started.elapsed() < wait => {
+                    std::thread::sleep(REPO_LOCK_POLL.min(wait.saturating_sub(started.elapsed())));
+                }
+                Err(fs::TryLockError::WouldBlock) => {
+                    // Best effort: Windows denies reads of a locked range
+                    let holder = Self::holder_at(path);
+                    return Err(LockError::RepoBusy {
+                        holder_spec: holder
+                            .as_ref()
+                            .map_or_else(|| "unknown".to_string(), |h| h.spec_id.clone()),
+                        pid: holder.map_or(0, |h| h.pid),
+                        waited_secs: started.elapsed().as_secs(),
+                    });
+                }
+                Err(fs::TryLockError::Error(e)) => return Err(LockError::Io(e)),
+            }
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let lock_info = LockInfo {
+            pid: process::id(),
+            start_time: now,
+            created_at: now,
+            spec_id: spec_id.to_string(),
+            xchecker_version: env!("CARGO_PKG_VERSION").to_string(),
+        };
+        let lock_json =
+            serde_json::to_string(&lock_info).map_err(|e| LockError::AcquisitionFailed {
+                reason: format!("Failed to serialize repo lock info: {e}"),
+            })?;
+        file.set_len(0)?;
+        file.write_all(lock_json.as_bytes())?;
+
+        Ok(Self { file, lock_info })
+    }
+
+    /// Location of the repo lock for the current directory
+    ///
+    /// Inside a git checkout the lock lives in its `.git` directory, so specs
+    /// under different `XCHECKER_HOME`s still share it; elsewhere it lives in
+    /// the xchecker home.
+    #[must_use]
+    pub fn path() -> PathBuf {
+        let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+        cwd.ancestors()
+            .map(|dir| dir.join(".git"))
+            .find(|git| git.is_dir())
+            .map_or_else(
+                || xchecker_home().join("repo.lock").into(),
+                |git| git.join("xchecker-repo.lock"),
+            )
+    }
+
+    /// Holder recorded in the repo lock at `path`, if it can be read
+    ///
+    /// The record outlives its holder; it only names the current holder while
+    /// acquisition is failing.
+    #[must_use]
+    pub fn holder_at(path: &Path) -> Option<LockInfo> {
+        let content = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Holder information written when this lock was acquired
+    #[must_use]
+    pub const fn lock_info(&self) -> &LockInfo {
+        &self.lock_info
+    }
+}
+
+impl std::fmt::Debug for RepoLock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RepoLock")
+            .field("lock_info", &self.lock_info)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for RepoLock {
+    /// Unlock explicitly; closing the file would release it as well
+    fn drop(&mut self) {
+        let _ = self.file.unlock();
+    }
+}
+
 /// Utility functions for lock management
 pub mod utils {
     use super::{
@@ -1241,6 +1416,7 @@ mod tests {
     use super::*;
 
     use std::fs;
+    use std::time::Duration;
     use tempfile::TempDir;
 
     fn setup_test_env() -> TempDir {
@@ -1337,6 +1513,163 @@ mod tests {
         assert_eq!(lock.spec_id(), spec_id);
     }
 
+    fn write_dead_lock(spec_id: &str, created_at: u64) {
+        let lock_path = FileLock::get_lock_path(spec_id);
+        fs::create_dir_all(lock_path.parent().unwrap()).unwrap();
+        let lock_info = LockInfo {
+            pid: 99999, // Non-existent PID
+            start_time: 0,
+            created_at,
+            spec_id: spec_id.to_string(),
+            xchecker_version: "0.1.0".to_string(),
+        };
+        fs::write(
+            &lock_path,
+            serde_json::to_string_pretty(&lock_info).unwrap(),
+        )
+        .unwrap();
+    }
+
+    fn write_heartbeat(spec_id: &str, age_secs: i64) {
+        let updated_at = Utc::now() - chrono::Duration::seconds(age_secs);
+        let heartbeat = Heartbeat {
+            pid: 99999,
+            spec_id: spec_id.to_string(),
+            phase: "design".to_string(),
+            started_at: updated_at,
+            updated_at,
+            bytes_streamed: 0,
+            progress_at: None,
+        };
+        fs::write(
+            Heartbeat::path(spec_id),
+            serde_json::to_string_pretty(&heartbeat).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_silent_heartbeat_from_dead_process_is_reclaimed() {
+        let _temp_dir = setup_test_env();
+        let spec_id = "test-spec-silent-heartbeat";
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        // Recent lock from a dead process is refused without a heartbeat...
+        write_dead_lock(spec_id, now);
+        assert!(matches!(
+            FileLock::acquire(spec_id, false, None),
+            Err(LockError::ConcurrentExecution { .. })
+        ));
+
+        // ...but reclaimed once its heartbeat has gone silent
+        write_heartbeat(spec_id, HEARTBEAT_SILENT_AFTER_SECS as i64 + 60);
+        let lock = FileLock::acquire(spec_id, false, None).unwrap();
+        assert_eq!(lock.spec_id(), spec_id);
+        assert!(Heartbeat::load(spec_id).is_none());
+    }
+
+    #[test]
+    fn test_active_heartbeat_blocks_stale_takeover() {
+        let _temp_dir = setup_test_env();
+        let spec_id = "test-spec-active-heartbeat";
+
+        // An old lock would be stale, but the heartbeat shows the phase progressing
+        write_dead_lock(spec_id, 0);
+        write_heartbeat(spec_id, 5);
+        assert!(matches!(
+            FileLock::acquire(spec_id, false, None),
+            Err(LockError::ConcurrentExecution { .. })
+        ));
+
+        // --force still wins
+        assert!(FileLock::acquire(spec_id, true, None).is_ok());
+    }
+
+    #[test]
+    fn test_heartbeat_writer_updates_and_removes_sidecar() {
+        let _temp_dir = setup_test_env();
+        let spec_id = "test-spec-heartbeat-writer";
+        fs::create_dir_all(spec_root(spec_id)).unwrap();
+
+        let writer =
+            HeartbeatWriter::start_with_interval(spec_id, "tasks", Duration::from_millis(20));
+        // Providers report bytes through the shared progress counter
+        writer
+            .progress()
+            .fetch_add(512, std::sync::atomic::Ordering::Relaxed);
+
+        let mut heartbeat = None;
+        for _ in 0..100 {
+            heartbeat = Heartbeat::load(spec_id).filter(|h| h.bytes_streamed == 512);
+            if heartbeat.is_some() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        let heartbeat = heartbeat.expect("heartbeat should report streamed bytes");
+        assert_eq!(heartbeat.phase, "tasks");
+        assert_eq!(heartbeat.pid, process::id());
+        assert_eq!(heartbeat.state(), HeartbeatState::Active);
+        assert!(heartbeat.progress_at.is_some());
+
+        drop(writer);
+        assert!(!Heartbeat::path(spec_id).exists());
+    }
+
+    #[test]
+    fn test_heartbeat_without_new_bytes_is_stalled() {
+        let now = Utc::now();
+        let long_ago = now - chrono::Duration::seconds(HEARTBEAT_STALLED_AFTER_SECS as i64 + 60);
+        let mut heartbeat = Heartbeat {
+            pid: process::id(),
+            spec_id: "test-spec-stalled".to_string(),
+            phase: "design".to_string(),
+            started_at: long_ago,
+            updated_at: now,
+            bytes_streamed: 0,
+            progress_at: None,
+        };
+        // Beating, but no provider output since the phase started
+        assert_eq!(heartbeat.state(), HeartbeatState::Stalled);
+
+        heartbeat.bytes_streamed = 4096;
+        heartbeat.progress_at = Some(long_ago);
+        assert_eq!(heartbeat.state(), HeartbeatState::Stalled);
+
+        heartbeat.progress_at = Some(now);
+        assert_eq!(heartbeat.state(), HeartbeatState::Active);
+
+        // Silence outranks a stall: the beat itself stopped
+        heartbeat.updated_at = long_ago;
+        assert_eq!(heartbeat.state(), HeartbeatState::Silent);
+    }
+
+    #[test]
+    fn test_repo_lock_serializes_specs_sharing_a_tree() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(".git").join("xchecker-repo.lock");
+
+        let held = RepoLock::acquire_at(&path, "spec-a", Duration::ZERO).unwrap();
+        assert_eq!(held.lock_info().spec_id, "spec-a");
+
+        let err = RepoLock::acquire_at(&path, "spec-b", Duration::from_millis(50)).unwrap_err();
+        match err {
+            LockError::RepoBusy { holder_spec, .. } => {
+                // Windows keeps the holder record unreadable while it is locked
+                assert!(cfg!(windows) || holder_spec == "spec-a");
+            }
+            other => panic!("expected RepoBusy, got {other:?}"),
+        }
+
+        drop(held);
+        let next = RepoLock::acquire_at(&path, "spec-b", Duration::ZERO).unwrap();
+        assert_eq!(RepoLock::holder_at(&path).unwrap().spec_id, "spec-b");
+        drop(next);
+    }
+
     #[test]
     fn test_clean_operation_checks() {
         let _temp_dir = setup_test_env();
@@ -1788,7 +2121,9 @@ mod tests {
         requirements.parent_receipt_path = Some("receipts/requirements.json".to_string());
         requirements.parent_packet_lineage = vec!["packet-req-1".to_string()];
         requirements.warnings = vec!["minor-style-warning".to_string()];
-        requirements.save().expect("Failed to save requirements promotion");
+        requirements
+            .save()
+            .expect("Failed to save requirements promotion");
 
         let mut design = PromotionLock::new(
             spec_id.to_string(),
@@ -1801,7 +2136,8 @@ mod tests {
             }],
         );
         design.approved_by = Some("policy-engine".to_string());
-        design.parent_packet_lineage = vec!["packet-req-1".to_string(), "packet-design-1".to_string()];
+        design.parent_packet_lineage =
+            vec!["packet-req-1".to_string(), "packet-design-1".to_string()];
         design.save().expect("Failed to save design promotion");
 
         let loaded = PromotionLock::load(spec_id, "requirements")
@@ -1,5 +1,7 @@
-use super::model::{CandidateFile, SelectedFile};
+use super::memory::{HeldContent, MemoryBudget, MemoryTracker};
+use super::model::{CandidateFile, SelectedFile, input_conversions};
 use super::selectors::ContentSelector;
+use super::transforms::{MAX_TRANSFORM_INPUT_BYTES, PacketTransforms};
 use crate::{BudgetUsage, Packet};
 use anyhow::{Context, Result};
 use blake3::Hasher;
@@ -8,9 +10,14 @@ use std::fs;
 use std::sync::{Arc, Mutex};
 use std::thread;
 use xchecker_config::Selectors;
-use xchecker_redaction::SecretRedactor;
+use xchecker_redaction::paths::MASKED_FILE_PLACEHOLDER;
+use xchecker_redaction::{SecretPathAction, SecretPathRules, SecretRedactor};
 use xchecker_utils::cache::InsightCache;
+use xchecker_utils::compression;
+use xchecker_utils::console::ProgressBar;
 use xchecker_utils::error::XCheckerError;
+use xchecker_utils::ignore_file::IgnoreFile;
+use xchecker_utils::input_encoding::{NonUtf8Policy, normalize_input};
 use xchecker_utils::logging::Logger;
 use xchecker_utils::types::{FileEvidence, PacketEvidence, Priority};
 
@@ -34,6 +41,14 @@ pub struct PacketBuilder {
     max_bytes: usize,
     /// Maximum lines allowed in packet
     max_lines: usize,
+    /// Built-in content transformers (manifest, lockfile, and API document summaries)
+    transforms: PacketTransforms,
+    /// Optional cap on file contents held in memory during assembly
+    memory_budget: Option<MemoryBudget>,
+    /// Handling of input files that are not valid UTF-8
+    non_utf8: NonUtf8Policy,
+    /// `[redaction.paths]` rules excluding or masking files by path
+    secret_paths: SecretPathRules,
 }
 
 impl PacketBuilder {
@@ -45,6 +60,10 @@ impl PacketBuilder {
             cache: None,
             max_bytes: DEFAULT_PACKET_MAX_BYTES,
             max_lines: DEFAULT_PACKET_MAX_LINES,
+            transforms: PacketTransforms::none(),
+            memory_budget: None,
+            non_utf8: NonUtf8Policy::Reject,
+            secret_paths: SecretPathRules::none(),
         })
     }
 
@@ -57,6 +76,10 @@ impl PacketBuilder {
             cache: Some(InsightCache::new(cache_dir)?),
             max_bytes: DEFAULT_PACKET_MAX_BYTES,
             max_lines: DEFAULT_PACKET_MAX_LINES,
+            transforms: PacketTransforms::none(),
+            memory_budget: None,
+            non_utf8: NonUtf8Policy::Reject,
+            secret_paths: SecretPathRules::none(),
         })
     }
 
@@ -75,6 +98,10 @@ impl PacketBuilder {
             cache: None,
             max_bytes: DEFAULT_PACKET_MAX_BYTES,
             max_lines: DEFAULT_PACKET_MAX_LINES,
+            transforms: PacketTransforms::none(),
+            memory_budget: None,
+            non_utf8: NonUtf8Policy::Reject,
+            secret_paths: SecretPathRules::none(),
         })
     }
 
@@ -97,6 +124,10 @@ impl PacketBuilder {
             cache: None,
             max_bytes,
             max_lines,
+            transforms: PacketTransforms::none(),
+            memory_budget: None,
+            non_utf8: NonUtf8Policy::Reject,
+            secret_paths: SecretPathRules::none(),
         })
     }
 
@@ -109,6 +140,10 @@ impl PacketBuilder {
             cache: None,
             max_bytes,
             max_lines,
+            transforms: PacketTransforms::none(),
+            memory_budget: None,
+            non_utf8: NonUtf8Policy::Reject,
+            secret_paths: SecretPathRules::none(),
         })
     }
 
@@ -125,6 +160,10 @@ impl PacketBuilder {
             cache: Some(InsightCache::new(cache_dir)?),
             max_bytes,
             max_lines,
+            transforms: PacketTransforms::none(),
+            memory_budget: None,
+            non_utf8: NonUtf8Policy::Reject,
+            secret_paths: SecretPathRules::none(),
         })
     }
 
@@ -142,6 +181,10 @@ impl PacketBuilder {
             cache: None,
             max_bytes,
             max_lines,
+            transforms: PacketTransforms::none(),
+            memory_budget: None,
+            non_utf8: NonUtf8Policy::Reject,
+            secret_paths: SecretPathRules::none(),
         }
     }
 
@@ -160,6 +203,10 @@ impl PacketBuilder {
             cache: None,
             max_bytes,
             max_lines,
+            transforms: PacketTransforms::none(),
+            memory_budget: None,
+            non_utf8: NonUtf8Policy::Reject,
+            secret_paths: SecretPathRules::none(),
         }
     }
 
@@ -179,6 +226,10 @@ impl PacketBuilder {
             cache,
             max_bytes,
             max_lines,
+            transforms: PacketTransforms::none(),
+            memory_budget: None,
+            non_utf8: NonUtf8Policy::Reject,
+            secret_paths: SecretPathRules::none(),
         }
     }
 
@@ -234,6 +285,58 @@ impl PacketBuilder {
         self
     }
 
+    /// Skip files matched by a project `.xcheckerignore`
+    ///
+    /// Skipped files are counted in the packet evidence (`ignored_files`).
+    #[must_use]
+    pub fn with_ignore_file(mut self, ignore_file: IgnoreFile) -> Self {
+        self.selector = self.selector.ignore_file(ignore_file);
+        self
+    }
+
+    /// Enable built-in content transformers
+    ///
+    /// Matching files (e.g. `Cargo.toml`, `package-lock.json`) are replaced in
+    /// the packet by a compact summary. Evidence still records the hash of the
+    /// original file content.
+    #[must_use]
+    pub const fn with_transforms(mut self, transforms: PacketTransforms) -> Self {
+        self.transforms = transforms;
+        self
+    }
+
+    /// Cap the file contents held in memory during packet assembly
+    ///
+    /// Exceeding the budget fails with `XCheckerError::PacketMemoryExceeded`;
+    /// processed files at or above the spill threshold go to temp files instead.
+    #[must_use]
+    pub const fn with_memory_budget(mut self, budget: MemoryBudget) -> Self {
+        self.memory_budget = Some(budget);
+        self
+    }
+
+    /// Set how input files that are not valid UTF-8 are handled
+    ///
+    /// BOMs and CRLF/CR line endings are always normalized before hashing;
+    /// this only decides whether invalid UTF-8 fails the build (the default)
+    /// or is replaced with U+FFFD.
+    #[must_use]
+    pub const fn with_non_utf8_policy(mut self, policy: NonUtf8Policy) -> Self {
+        self.non_utf8 = policy;
+        self
+    }
+
+    /// Exclude or mask files by path before their content is read
+    ///
+    /// Masked files appear in the packet with their path and
+    /// `[REDACTED:secret_file]` as content; they are never read, so their
+    /// evidence hash is that of the placeholder.
+    #[must_use]
+    pub fn with_secret_paths(mut self, rules: SecretPathRules) -> Self {
+        self.secret_paths = rules;
+        self
+    }
+
     /// Build a packet from the given base path and phase context
     /// Returns a Packet with content and evidence, or fails pre-Claude if budget exceeded
     pub fn build_packet(
@@ -244,16 +347,24 @@ impl PacketBuilder {
         _logger: Option<&Logger>,
     ) -> Result<Packet> {
         // Select candidates using lazy selection (no content reading yet)
-        let candidates = self
+        let (candidates, ignored_files) = self
             .selector
-            .select_candidates(base_path)
+            .select_candidates_and_ignored(base_path)
             .with_context(|| format!("Failed to select files from {base_path}"))?;
 
         // Prepare for parallel processing
         // Extract cache to wrap in Arc<Mutex>
         let cache_arc = self.cache.take().map(|c| Arc::new(Mutex::new(c)));
         let redactor_ref = &self.redactor;
+        let secret_paths = &self.secret_paths;
+        let transforms = self.transforms;
+        let non_utf8 = self.non_utf8;
         let max_file_size = self.selector.get_max_file_size();
+        let memory = MemoryTracker::new(self.memory_budget.unwrap_or(MemoryBudget::UNLIMITED));
+        let memory_ref = &memory;
+        let progress =
+            ProgressBar::new(format!("Building {phase} packet"), candidates.len() as u64);
+        let progress_ref = &progress;
 
         // Process files in parallel
         // We use std::thread::scope to allow sharing references (like redactor_ref)
@@ -279,9 +390,14 @@ impl PacketBuilder {
                             max_file_size,
                             phase,
                             redactor_ref,
+                            secret_paths,
+                            transforms,
+                            non_utf8,
                             cache_clone.as_ref(),
+                            memory_ref,
                         );
                         results.push(result);
+                        progress_ref.inc();
                     }
                     results
                 });
@@ -302,6 +418,7 @@ impl PacketBuilder {
             }
             Ok(all_results)
         })?;
+        progress.finish();
 
         // Restore cache to self
         if let Some(arc) = cache_arc {
@@ -317,13 +434,14 @@ impl PacketBuilder {
         let mut budget = BudgetUsage::new(self.max_bytes, self.max_lines);
         let mut packet_content = String::new();
         let mut included_files = Vec::new();
+        let mut input_conversions = Vec::new();
 
         // Separate Upstream and Other results to apply budget logic
         // process_results corresponds 1:1 to candidates
         let mut upstream_results = Vec::new();
         let mut other_results = Vec::new();
 
-        for (candidate, result) in candidates.iter().zip(process_results.into_iter()) {
+        for (candidate, result) in candidates.iter().zip(process_results) {
             if candidate.priority == Priority::Upstream {
                 upstream_results.push((candidate, result));
             } else {
@@ -335,7 +453,9 @@ impl PacketBuilder {
         for (_candidate, result) in upstream_results {
             // Propagate errors from processing
             match result {
-                Ok(Some((file, file_content, content_size, line_count))) => {
+                Ok(Some((file, held, content_size, line_count))) => {
+                    let file_content = held.into_string(&memory)?;
+
                     // Add file content to packet
                     let redacted_path = self.redactor.redact_string(file.path.as_str());
                     packet_content.push_str(&format!("=== {} ===\n", redacted_path));
@@ -345,6 +465,8 @@ impl PacketBuilder {
                     // Update budget
                     budget.add_content(content_size, line_count);
 
+                    input_conversions.extend(file.conversions);
+
                     // Create file evidence
                     let evidence = FileEvidence {
                         path: file.path.to_string(),
@@ -376,12 +498,14 @@ impl PacketBuilder {
         // Second pass: Add other files until budget is reached
         for (_candidate, result) in other_results {
             match result {
-                Ok(Some((file, file_content, content_size, line_count))) => {
+                Ok(Some((file, held, content_size, line_count))) => {
                     // Check if this file would exceed budget
                     if budget.would_exceed(content_size, line_count) {
                         // Skip this file to stay within budget
+                        held.release(&memory);
                         continue;
                     }
+                    let file_content = held.into_string(&memory)?;
 
                     // Add file content to packet
                     let redacted_path = self.redactor.redact_string(file.path.as_str());
@@ -392,6 +516,8 @@ impl PacketBuilder {
                     // Update budget
                     budget.add_content(content_size, line_count);
 
+                    input_conversions.extend(file.conversions);
+
                     // Create file evidence
                     let evidence = FileEvidence {
                         path: file.path.to_string(),
@@ -414,6 +540,10 @@ impl PacketBuilder {
             files: included_files,
             max_bytes: self.max_bytes,
             max_lines: self.max_lines,
+            compliance: vec![],
+            input_conversions,
+            attempts: vec![],
+            ignored_files,
         };
 
         // Always write packet preview for context (redacted content)
@@ -440,13 +570,31 @@ impl PacketBuilder {
 
 /// Helper function to process a single candidate file in parallel.
 /// This encapsulates reading, hashing, redaction, and cache interaction.
+#[allow(clippy::too_many_arguments)]
 fn process_candidate_file(
     candidate: &CandidateFile,
     max_file_size: u64,
     phase: &str,
     redactor: &SecretRedactor,
+    secret_paths: &SecretPathRules,
+    transforms: PacketTransforms,
+    non_utf8: NonUtf8Policy,
     cache: Option<&Arc<Mutex<InsightCache>>>,
-) -> Result<Option<(SelectedFile, String, usize, usize)>> {
+    memory: &MemoryTracker,
+) -> Result<Option<(SelectedFile, HeldContent, usize, usize)>> {
+    // Secret files named by path are decided before anything is read
+    match secret_paths.action(candidate.path.as_str()) {
+        Some(SecretPathAction::Exclude) => {
+            tracing::debug!("Excluding secret file by path: {}", candidate.path);
+            return Ok(None);
+        }
+        Some(SecretPathAction::Mask) => {
+            tracing::debug!("Masking secret file by path: {}", candidate.path);
+            return masked_candidate_file(candidate, memory).map(Some);
+        }
+        None => {}
+    }
+
     // DoS protection: check file size before reading
     let metadata = fs::metadata(&candidate.path)
         .with_context(|| format!("Failed to get file metadata: {}", candidate.path))?;
@@ -455,6 +603,13 @@ fn process_candidate_file(
         return Ok(None);
     }
 
+    // Transformed files only contribute their summary, so allow larger inputs
+    let max_file_size = if transforms.applies_to(&candidate.path) {
+        max_file_size.max(MAX_TRANSFORM_INPUT_BYTES)
+    } else {
+        max_file_size
+    };
+
     if metadata.len() > max_file_size {
         // For upstream files (critical context), fail hard if they exceed the limit
         if candidate.priority == Priority::Upstream {
@@ -476,9 +631,29 @@ fn process_candidate_file(
         return Ok(None);
     }
 
-    // Read content
-    let content = fs::read_to_string(&candidate.path)
+    // Account for the raw read before allocating it
+    let mut raw_bytes = usize::try_from(metadata.len()).unwrap_or(usize::MAX);
+    memory.reserve(raw_bytes, &candidate.path)?;
+
+    // Read content, normalizing encoding and line endings before anything is hashed
+    let mut bytes = fs::read(&candidate.path)
         .with_context(|| format!("Failed to read file: {}", candidate.path))?;
+    // Compressed artifacts (`.zst`) are packed as their original text
+    if compression::is_compressed(candidate.path.as_std_path()) {
+        bytes = compression::decompress(&bytes)
+            .with_context(|| format!("Failed to decompress file: {}", candidate.path))?;
+        memory.release(raw_bytes);
+        raw_bytes = bytes.len();
+        memory.reserve(raw_bytes, &candidate.path)?;
+    }
+    let normalized = normalize_input(bytes, non_utf8).with_context(|| {
+        format!(
+            "Failed to read file: {} (set [packet] non_utf8 = \"lossy\" to replace invalid bytes)",
+            candidate.path
+        )
+    })?;
+    let conversions = input_conversions(candidate.path.as_str(), &normalized.conversions);
+    let content = normalized.text;
 
     // Scan for secrets immediately after reading
     if redactor.has_secrets(&content, candidate.path.as_ref())? {
@@ -504,13 +679,28 @@ fn process_candidate_file(
     let line_count_raw = content.lines().count();
     let byte_count_raw = content.len();
 
+    // Replace transformed files with their summaries; evidence keeps the original hash
+    let content = match transforms.apply(&candidate.path, &content) {
+        Some(summary) => {
+            tracing::debug!(
+                "Transformed {} ({} bytes -> {} bytes)",
+                candidate.path,
+                byte_count_raw,
+                summary.len()
+            );
+            summary
+        }
+        None => content,
+    };
+
     let selected_file = SelectedFile {
         path: candidate.path.clone(),
-        content: content.clone(), // Clone needed for SelectedFile
+        content: String::new(), // Carried separately as redacted content
         priority: candidate.priority,
         blake3_pre_redaction: blake3_pre_redaction.clone(),
         line_count: line_count_raw,
         byte_count: byte_count_raw,
+        conversions,
     };
 
     // Cache Logic Inlined
@@ -586,12 +776,33 @@ fn process_candidate_file(
     let content_size = file_content.len() + candidate.path.as_str().len() + 10;
     let line_count = file_content.lines().count() + 3;
 
-    Ok(Some((
-        selected_file,
-        file_content,
-        content_size,
-        line_count,
-    )))
+    // The raw read is no longer held; only the processed content is kept
+    drop(content);
+    memory.release(raw_bytes);
+    let held = memory.hold(file_content, &candidate.path)?;
+
+    Ok(Some((selected_file, held, content_size, line_count)))
+}
+
+/// A candidate matched by a `[redaction.paths] mask` rule, with the
+/// placeholder standing in for its unread content
+fn masked_candidate_file(
+    candidate: &CandidateFile,
+    memory: &MemoryTracker,
+) -> Result<(SelectedFile, HeldContent, usize, usize)> {
+    let placeholder = MASKED_FILE_PLACEHOLDER.to_string();
+    let selected_file = SelectedFile {
+        path: candidate.path.clone(),
+        content: String::new(),
+        priority: candidate.priority,
+        blake3_pre_redaction: blake3::hash(placeholder.as_bytes()).to_hex().to_string(),
+        line_count: 1,
+        byte_count: placeholder.len(),
+        conversions: Vec::new(),
+    };
+    let content_size = placeholder.len() + candidate.path.as_str().len() + 10;
+    let held = memory.hold(placeholder, &candidate.path)?;
+    Ok((selected_file, held, content_size, 1 + 3))
 }
 
 impl Default for PacketBuilder {
@@ -652,6 +863,141 @@ mod packet_builder_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_packet_memory_budget_fails_with_precise_error() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().join("repo"))?;
+        let context_dir = Utf8PathBuf::try_from(temp_dir.path().join("context"))?;
+        fs::create_dir_all(&base_path)?;
+        fs::write(base_path.join("README.md"), "x".repeat(4096))?;
+
+        let mut builder = PacketBuilder::new()?.with_memory_budget(MemoryBudget::new(1024));
+        let err = builder
+            .build_packet(&base_path, "requirements", &context_dir, None)
+            .unwrap_err();
+        match err.downcast_ref::<XCheckerError>() {
+            Some(XCheckerError::PacketMemoryExceeded {
+                used_bytes,
+                limit_bytes,
+                path,
+            }) => {
+                assert_eq!(*used_bytes, 4096);
+                assert_eq!(*limit_bytes, 1024);
+                assert!(path.ends_with("README.md"));
+            }
+            other => panic!("expected PacketMemoryExceeded, got {other:?}"),
+        }
+
+        // Spilling processed content keeps the same input within budget
+        let mut builder = PacketBuilder::new()?
+            .with_memory_budget(MemoryBudget::new(4096).with_spill_threshold(1024));
+        let packet = builder.build_packet(&base_path, "requirements", &context_dir, None)?;
+        assert!(packet.content.contains(&"x".repeat(4096)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_packet_transforms_replace_api_document_content() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().join("repo"))?;
+        let context_dir = Utf8PathBuf::try_from(temp_dir.path().join("context"))?;
+        fs::create_dir_all(&base_path)?;
+
+        let spec = "openapi: 3.0.3\ninfo:\n  title: Billing\n  version: \"1.2\"\npaths:\n  /invoices:\n    get:\n      summary: List invoices\n";
+        fs::write(base_path.join("openapi.yaml"), spec)?;
+
+        let mut plain = PacketBuilder::new()?;
+        let packet = plain.build_packet(&base_path, "requirements", &context_dir, None)?;
+        assert!(packet.content.contains("openapi: 3.0.3"));
+
+        let mut builder = PacketBuilder::new()?.with_transforms(PacketTransforms {
+            openapi: true,
+            ..PacketTransforms::none()
+        });
+        let packet = builder.build_packet(&base_path, "requirements", &context_dir, None)?;
+        assert!(
+            packet
+                .content
+                .contains("# API Specification: Billing (v1.2)")
+        );
+        assert!(!packet.content.contains("openapi: 3.0.3"));
+
+        // Evidence still hashes the original file
+        let expected_hash = blake3::hash(spec.as_bytes()).to_hex().to_string();
+        assert_eq!(packet.evidence.files[0].blake3_pre_redaction, expected_hash);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_packet_transforms_replace_manifest_content() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().join("repo"))?;
+        let context_dir = Utf8PathBuf::try_from(temp_dir.path().join("context"))?;
+        fs::create_dir_all(&base_path)?;
+
+        let manifest =
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n\n[dependencies]\nanyhow = \"1\"\n";
+        fs::write(base_path.join("Cargo.toml"), manifest)?;
+
+        let mut plain = PacketBuilder::new()?;
+        let packet = plain.build_packet(&base_path, "requirements", &context_dir, None)?;
+        assert!(packet.content.contains("[dependencies]"));
+
+        let mut builder = PacketBuilder::new()?.with_transforms(PacketTransforms {
+            manifests: true,
+            lockfiles: false,
+            openapi: false,
+        });
+        let packet = builder.build_packet(&base_path, "requirements", &context_dir, None)?;
+        assert!(packet.content.contains("[manifest summary: Cargo.toml]"));
+        assert!(packet.content.contains("package: demo 0.1.0"));
+        assert!(!packet.content.contains("[dependencies]"));
+
+        // Evidence still hashes the original file
+        let expected_hash = blake3::hash(manifest.as_bytes()).to_hex().to_string();
+        assert_eq!(packet.evidence.files[0].blake3_pre_redaction, expected_hash);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_inputs_normalized_before_hashing() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().join("repo"))?;
+        let context_dir = Utf8PathBuf::try_from(temp_dir.path().join("context"))?;
+        fs::create_dir_all(&base_path)?;
+
+        // A CRLF checkout with a BOM hashes like the LF original
+        fs::write(base_path.join("notes.md"), "\u{FEFF}# Notes\r\nline\r\n")?;
+        let mut builder = PacketBuilder::new()?;
+        let packet = builder.build_packet(&base_path, "requirements", &context_dir, None)?;
+        let expected_hash = blake3::hash(b"# Notes\nline\n").to_hex().to_string();
+        assert_eq!(packet.evidence.files[0].blake3_pre_redaction, expected_hash);
+        let kinds: Vec<_> = packet
+            .evidence
+            .input_conversions
+            .iter()
+            .map(|c| c.kind.as_str())
+            .collect();
+        assert_eq!(kinds, vec!["bom_stripped", "newlines_normalized"]);
+
+        // Invalid UTF-8 is rejected unless the lossy policy is selected
+        fs::write(base_path.join("notes.md"), b"caf\xE9\n")?;
+        let mut strict = PacketBuilder::new()?;
+        assert!(
+            strict
+                .build_packet(&base_path, "requirements", &context_dir, None)
+                .is_err()
+        );
+        let mut lossy = PacketBuilder::new()?.with_non_utf8_policy(NonUtf8Policy::Lossy);
+        let packet = lossy.build_packet(&base_path, "requirements", &context_dir, None)?;
+        assert!(packet.content.contains("caf\u{FFFD}"));
+        assert_eq!(packet.evidence.input_conversions[0].kind, "lossy_utf8");
+
+        Ok(())
+    }
+
     #[test]
     fn test_budget_overflow_handling() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -788,6 +1134,44 @@ mod packet_builder_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_secret_path_rules_exclude_and_mask_before_reading() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+        let token = test_support::github_pat();
+
+        // Both files would fail secret detection if their content were read
+        fs::create_dir_all(base_path.join("credentials"))?;
+        fs::write(
+            base_path.join("credentials/prod.yaml"),
+            format!("github_token: {token}"),
+        )?;
+        fs::write(
+            base_path.join("deploy.yaml"),
+            format!("github_token: {token}"),
+        )?;
+        fs::write(base_path.join("README.md"), "# Project")?;
+
+        let rules = SecretPathRules::new(
+            &["credentials/*.yaml".to_string()],
+            &["deploy.yaml".to_string()],
+        )?;
+        let mut builder = PacketBuilder::new()?.with_secret_paths(rules);
+        let packet = builder.build_packet(&base_path, "test", &context_dir, None)?;
+
+        assert!(!packet.content.contains(&token));
+        assert!(!packet.content.contains("prod.yaml"));
+        assert!(
+            packet
+                .content
+                .contains(&format!("deploy.yaml ===\n{MASKED_FILE_PLACEHOLDER}"))
+        );
+        assert_eq!(packet.evidence.files.len(), 2);
+
+        Ok(())
+    }
+
     #[test]
     fn test_redaction_applied_to_packet_content() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -1351,6 +1735,45 @@ mod packet_builder_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_xcheckerignore_files_are_skipped_and_counted() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let context_dir = base_path.join("context");
+
+        fs::create_dir_all(base_path.join("drafts"))?;
+        fs::write(base_path.join("README.md"), "# Test Project")?;
+        fs::write(base_path.join("drafts/idea.md"), "half-baked")?;
+        fs::write(base_path.join("notes.txt"), "scratch")?;
+        fs::write(
+            base_path.join(".xcheckerignore"),
+            "drafts/\n*.txt\n!keep.txt\n",
+        )?;
+
+        let mut builder =
+            PacketBuilder::new()?.with_ignore_file(IgnoreFile::load(base_path.as_std_path())?);
+        let packet = builder.build_packet(&base_path, "requirements", &context_dir, None)?;
+
+        let paths: Vec<&str> = packet
+            .evidence
+            .files
+            .iter()
+            .map(|f| f.path.as_str())
+            .collect();
+        assert_eq!(paths.len(), 1, "{paths:?}");
+        assert!(paths[0].ends_with("README.md"));
+        assert_eq!(packet.evidence.ignored_files, 2);
+        assert!(!packet.content.contains("half-baked"));
+
+        // Without an ignore file the count stays zero and is not serialized
+        let mut plain = PacketBuilder::new()?;
+        let packet = plain.build_packet(&base_path, "requirements", &context_dir, None)?;
+        assert_eq!(packet.evidence.ignored_files, 0);
+        assert!(!serde_json::to_string(&packet.evidence)?.contains("ignored_files"));
+
+        Ok(())
+    }
+
     // ===== Filename Redaction Security Tests =====
 
     #[test]
@@ -0,0 +1,219 @@
+//! License/compliance scanning of packet contents
+//!
+//! Secret detection keeps credentials out of packets; this scanner covers a
+//! different risk: sending code under restrictive licenses or marked as
+//! proprietary to an external provider. Findings are attributed to the packet
+//! file they occur in (via the `=== path ===` section headers) and recorded in
+//! the receipt's packet evidence. Whether a finding warns or blocks is decided
+//! by the caller from [`ComplianceMode`].
+
+use anyhow::{Context, Result};
+use regex::{Regex, RegexSet};
+use xchecker_utils::types::ComplianceFinding;
+
+/// Definition of a built-in compliance pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompliancePatternDef {
+    /// Unique identifier for the pattern (e.g., "gpl_license")
+    pub id: &'static str,
+    /// The regex pattern string
+    pub regex: &'static str,
+    /// Human-readable description for documentation
+    pub description: &'static str,
+}
+
+/// Built-in restrictive license and proprietary marker patterns
+pub static DEFAULT_COMPLIANCE_PATTERNS: &[CompliancePatternDef] = &[
+    CompliancePatternDef {
+        id: "gpl_license",
+        regex: r"(?i)GNU (Affero )?General Public License|SPDX-License-Identifier:\s*(A?GPL)-",
+        description: "GPL or AGPL license header",
+    },
+    CompliancePatternDef {
+        id: "lgpl_license",
+        regex: r"(?i)GNU Lesser General Public License|SPDX-License-Identifier:\s*LGPL-",
+        description: "LGPL license header",
+    },
+    CompliancePatternDef {
+        id: "sspl_license",
+        regex: r"(?i)Server Side Public License|SPDX-License-Identifier:\s*SSPL-",
+        description: "Server Side Public License header",
+    },
+    CompliancePatternDef {
+        id: "bsl_license",
+        regex: r"(?i)Business Source License|SPDX-License-Identifier:\s*BUSL-",
+        description: "Business Source License header",
+    },
+    CompliancePatternDef {
+        id: "proprietary_notice",
+        regex: r"(?i)proprietary and confidential|all rights reserved\.?\s+unauthorized",
+        description: "Proprietary/confidential notice",
+    },
+    CompliancePatternDef {
+        id: "distribution_marker",
+        regex: r"(?i)\b(internal use only|do not distribute)\b",
+        description: "Internal-use or do-not-distribute marker",
+    },
+];
+
+/// What the orchestrator does with compliance findings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ComplianceMode {
+    /// Scanning disabled
+    #[default]
+    Off,
+    /// Record findings and add receipt warnings
+    Warn,
+    /// Record findings and fail the phase before the LLM call
+    Block,
+}
+
+impl ComplianceMode {
+    /// Parse a `[compliance] mode` value; unknown values disable scanning
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "warn" => Self::Warn,
+            "block" => Self::Block,
+            _ => Self::Off,
+        }
+    }
+}
+
+/// Scanner for restrictive license headers and proprietary markers
+#[derive(Debug)]
+pub struct ComplianceScanner {
+    ids: Vec<String>,
+    set: RegexSet,
+}
+
+impl ComplianceScanner {
+    /// Build a scanner from the built-in patterns plus `extra` regexes
+    ///
+    /// Extra patterns are reported as `extra_pattern_<index>`. Built-in
+    /// pattern IDs listed in `ignore` are skipped.
+    pub fn new(extra: &[String], ignore: &[String]) -> Result<Self> {
+        let mut ids = Vec::new();
+        let mut sources = Vec::new();
+
+        for def in DEFAULT_COMPLIANCE_PATTERNS {
+            if !ignore.iter().any(|id| id == def.id) {
+                ids.push(def.id.to_string());
+                sources.push(def.regex.to_string());
+            }
+        }
+        for (idx, pattern) in extra.iter().enumerate() {
+            ids.push(format!("extra_pattern_{idx}"));
+            sources.push(pattern.clone());
+        }
+
+        // Compile individually first so errors name the offending pattern
+        for (id, source) in ids.iter().zip(&sources) {
+            Regex::new(source).with_context(|| {
+                format!("Failed to compile compliance pattern '{id}': {source}")
+            })?;
+        }
+        let set = RegexSet::new(&sources).context("Failed to build compliance pattern set")?;
+
+        Ok(Self { ids, set })
+    }
+
+    /// Scan packet content, attributing each match to its packet file
+    ///
+    /// Each pattern is reported at most once per file, at its first matching
+    /// line. Content before the first `=== path ===` header is attributed to
+    /// `packet`.
+    #[must_use]
+    pub fn scan_packet(&self, content: &str) -> Vec<ComplianceFinding> {
+        let mut findings: Vec<ComplianceFinding> = Vec::new();
+        let mut path = "packet";
+        let mut line_in_file = 0;
+
+        for line in content.lines() {
+            if let Some(header) = line
+                .strip_prefix("=== ")
+                .and_then(|rest| rest.strip_suffix(" ==="))
+            {
+                path = header;
+                line_in_file = 0;
+                continue;
+            }
+            line_in_file += 1;
+
+            for idx in self.set.matches(line).iter() {
+                let id = &self.ids[idx];
+                let seen = findings
+                    .iter()
+                    .any(|f| f.path == path && &f.pattern_id == id);
+                if !seen {
+                    findings.push(ComplianceFinding {
+                        path: path.to_string(),
+                        pattern_id: id.clone(),
+                        line: line_in_file,
+                    });
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_attributes_findings_to_files() {
+        let scanner = ComplianceScanner::new(&[], &[]).unwrap();
+        let content = "=== src/lib.rs ===\n// SPDX-License-Identifier: MIT\nfn a() {}\n\
+                       === vendor/gpl.c ===\n/*\n * This program is free software under the\n \
+                       * GNU General Public License v3.\n * GNU General Public License again\n */\n\
+                       === notes.md ===\nINTERNAL USE ONLY\n";
+
+        let findings = scanner.scan_packet(content);
+
+        assert_eq!(
+            findings,
+            vec![
+                ComplianceFinding {
+                    path: "vendor/gpl.c".to_string(),
+                    pattern_id: "gpl_license".to_string(),
+                    line: 3,
+                },
+                ComplianceFinding {
+                    path: "notes.md".to_string(),
+                    pattern_id: "distribution_marker".to_string(),
+                    line: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extra_and_ignored_patterns() {
+        let scanner = ComplianceScanner::new(
+            &["(?i)acme confidential".to_string()],
+            &["distribution_marker".to_string()],
+        )
+        .unwrap();
+
+        let findings = scanner.scan_packet("=== a.md ===\nACME Confidential\nDo not distribute\n");
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].pattern_id, "extra_pattern_0");
+    }
+
+    #[test]
+    fn test_invalid_extra_pattern_is_rejected() {
+        let err = ComplianceScanner::new(&["(unclosed".to_string()], &[]).unwrap_err();
+        assert!(err.to_string().contains("extra_pattern_0"));
+    }
+
+    #[test]
+    fn test_mode_parsing() {
+        assert_eq!(ComplianceMode::parse("warn"), ComplianceMode::Warn);
+        assert_eq!(ComplianceMode::parse("block"), ComplianceMode::Block);
+        assert_eq!(ComplianceMode::parse("off"), ComplianceMode::Off);
+    }
+}
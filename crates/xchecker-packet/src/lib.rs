@@ -8,9 +8,12 @@ use xchecker_utils::types::PacketEvidence;
 
 mod budget;
 mod builder;
+mod compliance;
+mod memory;
 mod model;
 mod render;
 mod selectors;
+mod transforms;
 
 /// A packet of content prepared for Claude CLI consumption.
 #[derive(Debug, Clone)]
@@ -121,5 +124,10 @@ impl BudgetUsage {
 }
 
 pub use builder::{DEFAULT_PACKET_MAX_BYTES, DEFAULT_PACKET_MAX_LINES, PacketBuilder};
+pub use compliance::{
+    ComplianceMode, CompliancePatternDef, ComplianceScanner, DEFAULT_COMPLIANCE_PATTERNS,
+};
+pub use memory::{DEFAULT_SPILL_THRESHOLD_BYTES, MemoryBudget};
 pub use model::{PriorityRules, SelectedFile};
 pub use selectors::ContentSelector;
+pub use transforms::{MAX_TRANSFORM_INPUT_BYTES, PacketTransforms};
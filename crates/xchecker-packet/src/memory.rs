@@ -0,0 +1,190 @@
+//! Memory budget for packet assembly
+//!
+//! Packet building reads every candidate file before the byte/line budget is
+//! applied, so a pathological selection can hold far more content in memory
+//! than ever reaches the packet. [`MemoryBudget`] caps the bytes held at once:
+//! processed contents at or above the spill threshold are moved to temp files,
+//! and exceeding the cap fails with [`XCheckerError::PacketMemoryExceeded`]
+//! instead of letting the process get OOM-killed.
+
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use std::fs;
+use std::io::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tempfile::NamedTempFile;
+use xchecker_utils::error::XCheckerError;
+
+/// Default size at which processed file contents are spilled to disk (1 MiB)
+pub const DEFAULT_SPILL_THRESHOLD_BYTES: usize = 1024 * 1024;
+
+/// Limits on memory held by file contents during packet assembly
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryBudget {
+    /// Maximum bytes of file content held in memory at once
+    pub max_bytes: usize,
+    /// Processed contents at least this large are spilled to temp files
+    pub spill_threshold_bytes: usize,
+}
+
+impl MemoryBudget {
+    /// No memory cap and no spilling
+    pub const UNLIMITED: Self = Self {
+        max_bytes: usize::MAX,
+        spill_threshold_bytes: usize::MAX,
+    };
+
+    /// Create a budget with the default spill threshold
+    #[must_use]
+    pub const fn new(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            spill_threshold_bytes: DEFAULT_SPILL_THRESHOLD_BYTES,
+        }
+    }
+
+    /// Override the spill threshold
+    #[must_use]
+    pub const fn with_spill_threshold(mut self, bytes: usize) -> Self {
+        self.spill_threshold_bytes = bytes;
+        self
+    }
+}
+
+/// Thread-safe accounting of bytes held against a [`MemoryBudget`]
+#[derive(Debug)]
+pub(crate) struct MemoryTracker {
+    budget: MemoryBudget,
+    resident: AtomicUsize,
+}
+
+impl MemoryTracker {
+    pub(crate) const fn new(budget: MemoryBudget) -> Self {
+        Self {
+            budget,
+            resident: AtomicUsize::new(0),
+        }
+    }
+
+    /// Account for `bytes` read from `path`, failing if the budget is exceeded
+    pub(crate) fn reserve(&self, bytes: usize, path: &Utf8Path) -> Result<(), XCheckerError> {
+        let used = self
+            .resident
+            .fetch_add(bytes, Ordering::SeqCst)
+            .saturating_add(bytes);
+        if used > self.budget.max_bytes {
+            return Err(XCheckerError::PacketMemoryExceeded {
+                used_bytes: used,
+                limit_bytes: self.budget.max_bytes,
+                path: path.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Release bytes previously reserved
+    pub(crate) fn release(&self, bytes: usize) {
+        self.resident.fetch_sub(bytes, Ordering::SeqCst);
+    }
+
+    /// Bytes currently held
+    #[cfg(test)]
+    pub(crate) fn resident(&self) -> usize {
+        self.resident.load(Ordering::SeqCst)
+    }
+
+    /// Hold processed content in memory or spill it to disk, per the budget
+    pub(crate) fn hold(&self, content: String, path: &Utf8Path) -> Result<HeldContent> {
+        if content.len() >= self.budget.spill_threshold_bytes {
+            return HeldContent::spill(&content);
+        }
+        self.reserve(content.len(), path)?;
+        Ok(HeldContent::InMemory(content))
+    }
+}
+
+/// Processed file content awaiting packet assembly
+#[derive(Debug)]
+pub(crate) enum HeldContent {
+    /// Content kept in memory and counted against the budget
+    InMemory(String),
+    /// Content written to a temp file, removed when dropped
+    Spilled(NamedTempFile),
+}
+
+impl HeldContent {
+    fn spill(content: &str) -> Result<Self> {
+        let mut file = NamedTempFile::new().context("Failed to create packet spill file")?;
+        file.write_all(content.as_bytes())
+            .context("Failed to write packet spill file")?;
+        file.flush().context("Failed to flush packet spill file")?;
+        Ok(Self::Spilled(file))
+    }
+
+    /// Discard the content, releasing in-memory bytes from the tracker
+    pub(crate) fn release(self, tracker: &MemoryTracker) {
+        if let Self::InMemory(content) = self {
+            tracker.release(content.len());
+        }
+    }
+
+    /// Take the content back, releasing in-memory bytes from the tracker
+    pub(crate) fn into_string(self, tracker: &MemoryTracker) -> Result<String> {
+        match self {
+            Self::InMemory(content) => {
+                tracker.release(content.len());
+                Ok(content)
+            }
+            Self::Spilled(file) => {
+                fs::read_to_string(file.path()).context("Failed to read packet spill file")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserve_fails_past_limit_with_precise_error() {
+        let tracker = MemoryTracker::new(MemoryBudget::new(100));
+        tracker.reserve(60, Utf8Path::new("a.md")).unwrap();
+
+        let err = tracker.reserve(50, Utf8Path::new("b.md")).unwrap_err();
+        match err {
+            XCheckerError::PacketMemoryExceeded {
+                used_bytes,
+                limit_bytes,
+                path,
+            } => {
+                assert_eq!(used_bytes, 110);
+                assert_eq!(limit_bytes, 100);
+                assert_eq!(path, "b.md");
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[test]
+    fn test_large_content_is_spilled_and_not_counted() {
+        let tracker = MemoryTracker::new(MemoryBudget::new(10).with_spill_threshold(8));
+        let path = Utf8Path::new("big.md");
+
+        let held = tracker.hold("0123456789abcdef".to_string(), path).unwrap();
+        assert!(matches!(held, HeldContent::Spilled(_)));
+        assert_eq!(tracker.resident(), 0);
+        assert_eq!(held.into_string(&tracker).unwrap(), "0123456789abcdef");
+    }
+
+    #[test]
+    fn test_in_memory_content_is_released_on_take() {
+        let tracker = MemoryTracker::new(MemoryBudget::new(10));
+        let held = tracker
+            .hold("small".to_string(), Utf8Path::new("s.md"))
+            .unwrap();
+        assert_eq!(tracker.resident(), 5);
+        assert_eq!(held.into_string(&tracker).unwrap(), "small");
+        assert_eq!(tracker.resident(), 0);
+    }
+}
@@ -1,6 +1,7 @@
 use camino::Utf8PathBuf;
 use globset::{Glob, GlobSet, GlobSetBuilder};
-use xchecker_utils::types::Priority;
+use xchecker_utils::input_encoding::Conversion;
+use xchecker_utils::types::{InputConversion, Priority};
 
 /// Priority rules defining the selection order
 /// Order: *.core.yaml (non-evictable) → SPEC/ADR/REPORT → README/SCHEMA → misc
@@ -71,6 +72,23 @@ pub struct SelectedFile {
     /// Number of bytes in the file
     #[allow(dead_code)] // Metadata for budget tracking
     pub byte_count: usize,
+    /// Encoding and newline conversions applied when the file was read
+    pub conversions: Vec<InputConversion>,
+}
+
+/// Evidence entries for the conversions applied to `path`, each logged as a warning
+pub(crate) fn input_conversions(path: &str, conversions: &[Conversion]) -> Vec<InputConversion> {
+    conversions
+        .iter()
+        .map(|conversion| {
+            tracing::warn!("Normalized input {path}: {conversion}");
+            InputConversion {
+                path: path.to_string(),
+                kind: conversion.kind.as_str().to_string(),
+                count: conversion.count,
+            }
+        })
+        .collect()
 }
 
 /// Represents a candidate file for selection (lazy loading)
@@ -18,8 +18,13 @@ impl PacketBuilder {
 
         let preview_path = context_dir.join(format!("{}-packet.txt", phase.to_lowercase()));
 
+        // Preview is written before the orchestrator's secret scan, so redact it here
+        let redacted = self
+            .redactor()
+            .redact_content(content, preview_path.as_str())?;
+
         // Write packet preview
-        write_file_atomic(&preview_path, content)
+        write_file_atomic(&preview_path, &redacted.content)
             .with_context(|| format!("Failed to write packet preview to: {preview_path}"))?;
 
         Ok(())
@@ -1,4 +1,4 @@
-use super::model::{CandidateFile, PriorityRules, SelectedFile};
+use super::model::{CandidateFile, PriorityRules, SelectedFile, input_conversions};
 use anyhow::{Context, Result};
 use blake3::Hasher;
 use camino::{Utf8Path, Utf8PathBuf};
@@ -8,6 +8,9 @@ use std::io::Read;
 use std::thread;
 use tracing::warn;
 use xchecker_config::Selectors;
+use xchecker_utils::compression;
+use xchecker_utils::ignore_file::IgnoreFile;
+use xchecker_utils::input_encoding::{NonUtf8Policy, normalize_input};
 use xchecker_utils::types::Priority;
 
 // Import centralized security exclusion patterns from xchecker-config
@@ -45,6 +48,8 @@ pub struct ContentSelector {
     allow_symlinks: bool,
     /// Maximum file size in bytes (default: 10MB)
     max_file_size: u64,
+    /// Project `.xcheckerignore` patterns applied after include/exclude
+    ignore_file: IgnoreFile,
 }
 
 impl ContentSelector {
@@ -88,6 +93,7 @@ impl ContentSelector {
             priority_rules: PriorityRules::default(),
             allow_symlinks: false,
             max_file_size: DEFAULT_MAX_FILE_SIZE,
+            ignore_file: IgnoreFile::empty(),
         })
     }
 
@@ -117,6 +123,16 @@ impl ContentSelector {
         self
     }
 
+    /// Skip files matched by a project `.xcheckerignore`.
+    ///
+    /// Ignored files that the include patterns select are counted by
+    /// [`select_candidates_and_ignored`](Self::select_candidates_and_ignored).
+    #[must_use]
+    pub fn ignore_file(mut self, ignore_file: IgnoreFile) -> Self {
+        self.ignore_file = ignore_file;
+        self
+    }
+
     /// Get the maximum file size limit in bytes.
     #[must_use]
     pub const fn get_max_file_size(&self) -> u64 {
@@ -146,6 +162,7 @@ impl ContentSelector {
             priority_rules: PriorityRules::default(),
             allow_symlinks: false,
             max_file_size: DEFAULT_MAX_FILE_SIZE,
+            ignore_file: IgnoreFile::empty(),
         })
     }
 
@@ -181,6 +198,7 @@ impl ContentSelector {
                     priority_rules: PriorityRules::default(),
                     allow_symlinks: false,
                     max_file_size: DEFAULT_MAX_FILE_SIZE,
+                    ignore_file: IgnoreFile::empty(),
                 })
             }
             None => Self::new(),
@@ -190,7 +208,8 @@ impl ContentSelector {
     /// Determine the priority of a file based on its path
     #[must_use]
     pub fn get_priority(&self, path: &Utf8Path) -> Priority {
-        let path_str = path.as_str();
+        // Compressed artifacts rank as the file they decompress to
+        let path_str = compression::logical_name(path.as_str());
 
         // *.core.yaml files are always Upstream priority (non-evictable)
         if path_str.ends_with(".core.yaml") {
@@ -231,7 +250,7 @@ impl ContentSelector {
     /// Check if a file should be included based on include/exclude patterns
     #[must_use]
     pub fn should_include(&self, path: &Utf8Path) -> bool {
-        let path_str = path.as_str();
+        let path_str = compression::logical_name(path.as_str());
 
         // First check if excluded
         if self.is_excluded(path) {
@@ -249,10 +268,20 @@ impl ContentSelector {
     /// For large datasets (>= 500 files), priority assignment is parallelized
     /// using `std::thread::scope` to improve performance on multi-core systems.
     pub fn select_candidates(&self, base_path: &Utf8Path) -> Result<Vec<CandidateFile>> {
+        Ok(self.select_candidates_and_ignored(base_path)?.0)
+    }
+
+    /// Like [`select_candidates`](Self::select_candidates), also returning how
+    /// many files the include patterns selected but `.xcheckerignore` skipped
+    pub fn select_candidates_and_ignored(
+        &self,
+        base_path: &Utf8Path,
+    ) -> Result<(Vec<CandidateFile>, usize)> {
         let mut paths = Vec::new();
+        let mut ignored = 0;
 
         // Walk the directory tree, passing root for symlink sandbox validation
-        self.walk_directory_paths(base_path, base_path, &mut paths)?;
+        self.walk_directory_paths(base_path, base_path, &mut paths, &mut ignored)?;
 
         let num_threads = thread::available_parallelism().map_or(1, |n| n.get());
 
@@ -308,7 +337,7 @@ impl ContentSelector {
             }
         });
 
-        Ok(candidates)
+        Ok((candidates, ignored))
     }
 
     /// Select files from a directory with priority-based ordering
@@ -372,13 +401,25 @@ impl ContentSelector {
                         }
 
                         // Pre-allocate buffer to avoid reallocations
-                        let mut content = String::with_capacity(metadata.len() as usize);
+                        let mut bytes = Vec::with_capacity(metadata.len() as usize);
 
                         // Security: Use take() to enforce hard limit on read size
                         // This prevents DoS if the file grows concurrently (TOCTOU race)
                         file.take(self.max_file_size + 1)
-                            .read_to_string(&mut content)
+                            .read_to_end(&mut bytes)
+                            .with_context(|| format!("Failed to read file: {}", candidate.path))?;
+                        if compression::is_compressed(candidate.path.as_std_path()) {
+                            bytes = compression::decompress(&bytes).with_context(|| {
+                                format!("Failed to decompress file: {}", candidate.path)
+                            })?;
+                        }
+
+                        // Normalize BOMs and line endings so hashes match across platforms
+                        let normalized = normalize_input(bytes, NonUtf8Policy::Reject)
                             .with_context(|| format!("Failed to read file: {}", candidate.path))?;
+                        let content = normalized.text;
+                        let conversions =
+                            input_conversions(candidate.path.as_str(), &normalized.conversions);
 
                         // Post-read size check: handles case where file grew during read
                         if content.len() as u64 > self.max_file_size {
@@ -416,6 +457,7 @@ impl ContentSelector {
                             blake3_pre_redaction,
                             line_count,
                             byte_count,
+                            conversions,
                         });
                     }
                     Ok(chunk_results)
@@ -447,11 +489,15 @@ impl ContentSelector {
     /// - When `allow_symlinks` is true, symlinks are only followed if their
     ///   canonical path is within the `root` directory (sandbox validation)
     /// - Broken symlinks or canonicalization failures result in skipping (fail-closed)
+    ///
+    /// Directories matched by `.xcheckerignore` are still walked so the files
+    /// they hide can be counted in `ignored`.
     fn walk_directory_paths(
         &self,
         root: &Utf8Path,
         dir: &Utf8Path,
         paths: &mut Vec<Utf8PathBuf>,
+        ignored: &mut usize,
     ) -> Result<()> {
         if !dir.exists() {
             return Ok(());
@@ -490,8 +536,12 @@ impl ContentSelector {
                 if self.is_excluded(&path) {
                     continue;
                 }
-                self.walk_directory_paths(root, &path, paths)?;
+                self.walk_directory_paths(root, &path, paths, ignored)?;
             } else if self.should_include(&path) {
+                if self.ignore_file.is_ignored(path.as_std_path(), false) {
+                    *ignored += 1;
+                    continue;
+                }
                 // Just collect the path; DoS protection happens in select_files
                 paths.push(path);
             }
@@ -595,6 +645,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_compressed_artifacts_select_as_their_original() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let base_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())?;
+        let design = "# Design\n\nLarge design document.\n";
+        fs::write(
+            base_path.join("10-design.core.yaml.zst"),
+            compression::compress(b"upstream: content", compression::DEFAULT_LEVEL)?,
+        )?;
+        fs::write(
+            base_path.join("10-design.md.zst"),
+            compression::compress(design.as_bytes(), compression::DEFAULT_LEVEL)?,
+        )?;
+        fs::write(base_path.join("blob.bin.zst"), b"not selected")?;
+
+        let selector = ContentSelector::new()?;
+        let files = selector.select_files(&base_path)?;
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].priority, Priority::Upstream);
+        assert_eq!(files[0].content, "upstream: content");
+        assert_eq!(files[1].content, design);
+
+        Ok(())
+    }
+
     #[test]
     fn test_content_selector_from_selectors_uses_defaults_when_none() -> Result<()> {
         let selector = ContentSelector::from_selectors(None)?;
@@ -0,0 +1,529 @@
+//! Built-in packet content transformers
+//!
+//! Transformers replace verbose, machine-oriented files with compact summaries
+//! before budgeting. Manifests (`Cargo.toml`, `package.json`) become dependency
+//! overviews, lockfiles (`Cargo.lock`, `package-lock.json`) become resolved
+//! package lists, and API descriptions (`openapi.yaml`, `*.schema.json`) become
+//! operation and schema summaries. Files that fail to parse are passed through
+//! unchanged.
+
+use camino::Utf8Path;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use xchecker_utils::source::openapi;
+
+/// Maximum input size accepted by transformers.
+///
+/// Lockfiles routinely exceed the packet file-size limit; since only the summary
+/// enters the packet, transform-eligible files may be read up to this size.
+pub const MAX_TRANSFORM_INPUT_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Number of packages listed per line in lockfile summaries
+const PACKAGES_PER_LINE: usize = 8;
+
+/// Which built-in transformers are enabled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PacketTransforms {
+    /// Summarize `Cargo.toml` / `package.json`
+    pub manifests: bool,
+    /// Summarize `Cargo.lock` / `package-lock.json`
+    pub lockfiles: bool,
+    /// Summarize OpenAPI / Swagger / JSON Schema documents
+    pub openapi: bool,
+}
+
+/// File kinds recognized by the built-in transformers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TransformKind {
+    CargoManifest,
+    NpmManifest,
+    CargoLock,
+    NpmLock,
+    ApiDocument,
+}
+
+impl PacketTransforms {
+    /// Create a transform set with no transformers enabled
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            manifests: false,
+            lockfiles: false,
+            openapi: false,
+        }
+    }
+
+    /// Returns true if an enabled transformer handles this path
+    #[must_use]
+    pub fn applies_to(&self, path: &Utf8Path) -> bool {
+        self.kind_for(path).is_some()
+    }
+
+    fn kind_for(&self, path: &Utf8Path) -> Option<TransformKind> {
+        let kind = match path.file_name()? {
+            "Cargo.toml" => TransformKind::CargoManifest,
+            "package.json" => TransformKind::NpmManifest,
+            "Cargo.lock" => TransformKind::CargoLock,
+            "package-lock.json" => TransformKind::NpmLock,
+            name if is_api_document(name) => TransformKind::ApiDocument,
+            _ => return None,
+        };
+        let enabled = match kind {
+            TransformKind::CargoManifest | TransformKind::NpmManifest => self.manifests,
+            TransformKind::CargoLock | TransformKind::NpmLock => self.lockfiles,
+            TransformKind::ApiDocument => self.openapi,
+        };
+        enabled.then_some(kind)
+    }
+
+    /// Transform file content, returning `None` if no transformer applies or
+    /// the content could not be parsed
+    #[must_use]
+    pub fn apply(&self, path: &Utf8Path, content: &str) -> Option<String> {
+        match self.kind_for(path)? {
+            TransformKind::CargoManifest => summarize_cargo_manifest(content),
+            TransformKind::NpmManifest => summarize_npm_manifest(content),
+            TransformKind::CargoLock => summarize_cargo_lock(content),
+            TransformKind::NpmLock => summarize_npm_lock(content),
+            TransformKind::ApiDocument => openapi::summarize(content)
+                .ok()
+                .map(|summary| summary.markdown),
+        }
+    }
+}
+
+/// Whether `name` is conventionally an API description document:
+/// `openapi.*` / `swagger.*` (optionally prefixed, e.g. `billing.openapi.yaml`)
+/// or `*.schema.json` / `*.schema.yaml`
+fn is_api_document(name: &str) -> bool {
+    let Some((stem, ext)) = name.rsplit_once('.') else {
+        return false;
+    };
+    if !matches!(ext, "json" | "yaml" | "yml") {
+        return false;
+    }
+    let kind = stem.rsplit('.').next().unwrap_or(stem);
+    matches!(kind, "openapi" | "swagger") || (stem.contains('.') && kind == "schema")
+}
+
+fn summarize_cargo_manifest(content: &str) -> Option<String> {
+    let manifest: toml::Table = toml::from_str(content).ok()?;
+    let mut out = String::from("[manifest summary: Cargo.toml]\n");
+
+    if let Some(package) = manifest.get("package").and_then(toml::Value::as_table) {
+        let field = |key: &str| {
+            package.get(key).map(|v| match v {
+                toml::Value::String(s) => s.clone(),
+                toml::Value::Table(t) if t.contains_key("workspace") => "workspace".to_string(),
+                other => other.to_string(),
+            })
+        };
+        let _ = writeln!(
+            out,
+            "package: {} {}",
+            field("name").unwrap_or_else(|| "?".to_string()),
+            field("version").unwrap_or_else(|| "?".to_string())
+        );
+        if let Some(edition) = field("edition") {
+            let _ = writeln!(out, "edition: {edition}");
+        }
+    }
+
+    if let Some(workspace) = manifest.get("workspace").and_then(toml::Value::as_table) {
+        if let Some(members) = workspace.get("members").and_then(toml::Value::as_array) {
+            let members: Vec<&str> = members.iter().filter_map(toml::Value::as_str).collect();
+            let _ = writeln!(
+                out,
+                "workspace members ({}): {}",
+                members.len(),
+                members.join(", ")
+            );
+        }
+        if let Some(deps) = workspace
+            .get("dependencies")
+            .and_then(toml::Value::as_table)
+        {
+            write_cargo_deps(&mut out, "workspace.dependencies", deps);
+        }
+    }
+
+    for section in ["dependencies", "dev-dependencies", "build-dependencies"] {
+        if let Some(deps) = manifest.get(section).and_then(toml::Value::as_table) {
+            write_cargo_deps(&mut out, section, deps);
+        }
+    }
+
+    if let Some(targets) = manifest.get("target").and_then(toml::Value::as_table) {
+        for (cfg, target) in targets {
+            for section in ["dependencies", "dev-dependencies", "build-dependencies"] {
+                if let Some(deps) = target.get(section).and_then(toml::Value::as_table) {
+                    write_cargo_deps(&mut out, &format!("target.'{cfg}'.{section}"), deps);
+                }
+            }
+        }
+    }
+
+    if let Some(features) = manifest.get("features").and_then(toml::Value::as_table) {
+        let _ = writeln!(out, "features ({}):", features.len());
+        for (name, enables) in features {
+            let enables: Vec<&str> = enables
+                .as_array()
+                .map(|a| a.iter().filter_map(toml::Value::as_str).collect())
+                .unwrap_or_default();
+            let _ = writeln!(out, "  {name} = [{}]", enables.join(", "));
+        }
+    }
+
+    Some(out)
+}
+
+fn write_cargo_deps(out: &mut String, section: &str, deps: &toml::Table) {
+    let _ = writeln!(out, "{section} ({}):", deps.len());
+    for (name, spec) in deps {
+        let mut line = format!("  {name}");
+        match spec {
+            toml::Value::String(version) => {
+                let _ = write!(line, " {version}");
+            }
+            toml::Value::Table(table) => {
+                if let Some(version) = table.get("version").and_then(toml::Value::as_str) {
+                    let _ = write!(line, " {version}");
+                }
+                if table.get("workspace").and_then(toml::Value::as_bool) == Some(true) {
+                    line.push_str(" (workspace)");
+                }
+                if let Some(path) = table.get("path").and_then(toml::Value::as_str) {
+                    let _ = write!(line, " path={path}");
+                }
+                if let Some(git) = table.get("git").and_then(toml::Value::as_str) {
+                    let _ = write!(line, " git={git}");
+                }
+                if let Some(package) = table.get("package").and_then(toml::Value::as_str) {
+                    let _ = write!(line, " package={package}");
+                }
+                if table.get("optional").and_then(toml::Value::as_bool) == Some(true) {
+                    line.push_str(" optional");
+                }
+                if table.get("default-features").and_then(toml::Value::as_bool) == Some(false) {
+                    line.push_str(" no-default-features");
+                }
+                if let Some(features) = table.get("features").and_then(toml::Value::as_array) {
+                    let features: Vec<&str> =
+                        features.iter().filter_map(toml::Value::as_str).collect();
+                    let _ = write!(line, " features=[{}]", features.join(", "));
+                }
+            }
+            _ => {}
+        }
+        let _ = writeln!(out, "{line}");
+    }
+}
+
+fn summarize_npm_manifest(content: &str) -> Option<String> {
+    let manifest: serde_json::Value = serde_json::from_str(content).ok()?;
+    let manifest = manifest.as_object()?;
+    let mut out = String::from("[manifest summary: package.json]\n");
+
+    let field = |key: &str| manifest.get(key).and_then(serde_json::Value::as_str);
+    let _ = writeln!(
+        out,
+        "package: {} {}",
+        field("name").unwrap_or("?"),
+        field("version").unwrap_or("?")
+    );
+    if let Some(module_type) = field("type") {
+        let _ = writeln!(out, "type: {module_type}");
+    }
+
+    if let Some(workspaces) = manifest.get("workspaces").and_then(|w| {
+        w.as_array()
+            .or_else(|| w.get("packages").and_then(serde_json::Value::as_array))
+    }) {
+        let members: Vec<&str> = workspaces.iter().filter_map(|v| v.as_str()).collect();
+        let _ = writeln!(
+            out,
+            "workspaces ({}): {}",
+            members.len(),
+            members.join(", ")
+        );
+    }
+
+    if let Some(scripts) = manifest
+        .get("scripts")
+        .and_then(serde_json::Value::as_object)
+    {
+        let names: Vec<&str> = scripts.keys().map(String::as_str).collect();
+        let _ = writeln!(out, "scripts ({}): {}", names.len(), names.join(", "));
+    }
+
+    for section in [
+        "dependencies",
+        "devDependencies",
+        "peerDependencies",
+        "optionalDependencies",
+    ] {
+        if let Some(deps) = manifest.get(section).and_then(serde_json::Value::as_object) {
+            let _ = writeln!(out, "{section} ({}):", deps.len());
+            for (name, version) in deps {
+                let _ = writeln!(out, "  {name} {}", version.as_str().unwrap_or("?"));
+            }
+        }
+    }
+
+    Some(out)
+}
+
+fn summarize_cargo_lock(content: &str) -> Option<String> {
+    let lock: toml::Table = toml::from_str(content).ok()?;
+    let packages = lock.get("package").and_then(toml::Value::as_array)?;
+
+    let mut versions: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for package in packages {
+        let name = package.get("name").and_then(toml::Value::as_str);
+        let version = package.get("version").and_then(toml::Value::as_str);
+        if let (Some(name), Some(version)) = (name, version) {
+            versions.entry(name).or_default().push(version);
+        }
+    }
+
+    let mut out = String::from("[lockfile summary: Cargo.lock]\n");
+    if let Some(version) = lock.get("version").and_then(toml::Value::as_integer) {
+        let _ = writeln!(out, "lockfile version: {version}");
+    }
+    write_lock_packages(&mut out, packages.len(), &versions);
+    Some(out)
+}
+
+fn summarize_npm_lock(content: &str) -> Option<String> {
+    let lock: serde_json::Value = serde_json::from_str(content).ok()?;
+    let mut versions: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    let mut total = 0;
+
+    if let Some(packages) = lock.get("packages").and_then(serde_json::Value::as_object) {
+        // lockfileVersion 2/3: keys are install paths like "node_modules/a/node_modules/b"
+        for (path, entry) in packages {
+            let Some(name) = path
+                .rsplit("node_modules/")
+                .next()
+                .filter(|_| !path.is_empty())
+            else {
+                continue;
+            };
+            if let Some(version) = entry.get("version").and_then(serde_json::Value::as_str) {
+                versions.entry(name).or_default().push(version);
+                total += 1;
+            }
+        }
+    } else if let Some(deps) = lock
+        .get("dependencies")
+        .and_then(serde_json::Value::as_object)
+    {
+        // lockfileVersion 1: top-level dependency map
+        for (name, entry) in deps {
+            if let Some(version) = entry.get("version").and_then(serde_json::Value::as_str) {
+                versions.entry(name).or_default().push(version);
+                total += 1;
+            }
+        }
+    } else {
+        return None;
+    }
+
+    let mut out = String::from("[lockfile summary: package-lock.json]\n");
+    if let Some(version) = lock
+        .get("lockfileVersion")
+        .and_then(serde_json::Value::as_u64)
+    {
+        let _ = writeln!(out, "lockfile version: {version}");
+    }
+    write_lock_packages(&mut out, total, &versions);
+    Some(out)
+}
+
+fn write_lock_packages(out: &mut String, total: usize, versions: &BTreeMap<&str, Vec<&str>>) {
+    let _ = writeln!(
+        out,
+        "resolved packages: {total} ({} unique names)",
+        versions.len()
+    );
+
+    let duplicates: Vec<String> = versions
+        .iter()
+        .filter_map(|(name, v)| {
+            let mut unique = v.clone();
+            unique.sort_unstable();
+            unique.dedup();
+            (unique.len() > 1).then(|| format!("{name} ({})", unique.join(", ")))
+        })
+        .collect();
+    if !duplicates.is_empty() {
+        let _ = writeln!(out, "multiple versions: {}", duplicates.join("; "));
+    }
+
+    let entries: Vec<String> = versions
+        .iter()
+        .map(|(name, v)| {
+            let mut unique = v.clone();
+            unique.sort_unstable();
+            unique.dedup();
+            format!("{name}@{}", unique.join("|"))
+        })
+        .collect();
+    let _ = writeln!(out, "packages:");
+    for chunk in entries.chunks(PACKAGES_PER_LINE) {
+        let _ = writeln!(out, "  {}", chunk.join(" "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: PacketTransforms = PacketTransforms {
+        manifests: true,
+        lockfiles: true,
+        openapi: true,
+    };
+
+    #[test]
+    fn test_disabled_transforms_do_not_apply() {
+        let path = Utf8Path::new("crate/Cargo.toml");
+        assert!(!PacketTransforms::none().applies_to(path));
+        assert!(PacketTransforms::none().apply(path, "[package]").is_none());
+
+        let manifests_only = PacketTransforms {
+            manifests: true,
+            lockfiles: false,
+            openapi: false,
+        };
+        assert!(manifests_only.applies_to(path));
+        assert!(!manifests_only.applies_to(Utf8Path::new("Cargo.lock")));
+    }
+
+    #[test]
+    fn test_cargo_manifest_summary() {
+        let manifest = r#"
+[package]
+name = "demo"
+version = "0.3.1"
+edition = "2021"
+
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+anyhow = "1"
+local = { path = "../local", optional = true }
+shared = { workspace = true }
+
+[dev-dependencies]
+tempfile = "3"
+
+[features]
+default = ["local"]
+"#;
+        let summary = ALL.apply(Utf8Path::new("Cargo.toml"), manifest).unwrap();
+        assert!(summary.contains("package: demo 0.3.1"));
+        assert!(summary.contains("edition: 2021"));
+        assert!(summary.contains("dependencies (4):"));
+        assert!(summary.contains("  serde 1.0 features=[derive]"));
+        assert!(summary.contains("  anyhow 1"));
+        assert!(summary.contains("  local path=../local optional"));
+        assert!(summary.contains("  shared (workspace)"));
+        assert!(summary.contains("dev-dependencies (1):"));
+        assert!(summary.contains("  default = [local]"));
+    }
+
+    #[test]
+    fn test_npm_manifest_summary() {
+        let manifest = r#"{
+            "name": "web",
+            "version": "2.0.0",
+            "scripts": {"build": "vite build", "test": "vitest"},
+            "dependencies": {"react": "^18.2.0"},
+            "devDependencies": {"vite": "^5.0.0"}
+        }"#;
+        let summary = ALL
+            .apply(Utf8Path::new("web/package.json"), manifest)
+            .unwrap();
+        assert!(summary.contains("package: web 2.0.0"));
+        assert!(summary.contains("scripts (2): build, test"));
+        assert!(summary.contains("  react ^18.2.0"));
+        assert!(summary.contains("devDependencies (1):"));
+    }
+
+    #[test]
+    fn test_cargo_lock_summary() {
+        let lock = r#"
+version = 4
+
+[[package]]
+name = "syn"
+version = "1.0.109"
+
+[[package]]
+name = "syn"
+version = "2.0.48"
+
+[[package]]
+name = "quote"
+version = "1.0.35"
+"#;
+        let summary = ALL.apply(Utf8Path::new("Cargo.lock"), lock).unwrap();
+        assert!(summary.contains("lockfile version: 4"));
+        assert!(summary.contains("resolved packages: 3 (2 unique names)"));
+        assert!(summary.contains("multiple versions: syn (1.0.109, 2.0.48)"));
+        assert!(summary.contains("quote@1.0.35 syn@1.0.109|2.0.48"));
+    }
+
+    #[test]
+    fn test_npm_lock_summary() {
+        let lock = r#"{
+            "lockfileVersion": 3,
+            "packages": {
+                "": {"name": "web", "version": "2.0.0"},
+                "node_modules/react": {"version": "18.2.0"},
+                "node_modules/a/node_modules/react": {"version": "17.0.2"}
+            }
+        }"#;
+        let summary = ALL.apply(Utf8Path::new("package-lock.json"), lock).unwrap();
+        assert!(summary.contains("resolved packages: 2 (1 unique names)"));
+        assert!(summary.contains("react@17.0.2|18.2.0"));
+    }
+
+    #[test]
+    fn test_unparseable_content_passes_through() {
+        assert!(
+            ALL.apply(Utf8Path::new("Cargo.toml"), "not = [toml")
+                .is_none()
+        );
+        assert!(ALL.apply(Utf8Path::new("package.json"), "{").is_none());
+    }
+
+    #[test]
+    fn test_openapi_document_summary() {
+        let spec = r#"
+openapi: 3.0.3
+info:
+  title: Billing
+  version: "1.2"
+paths:
+  /invoices:
+    get:
+      summary: List invoices
+"#;
+        for name in ["openapi.yaml", "api/billing.openapi.yml", "swagger.json"] {
+            assert!(ALL.applies_to(Utf8Path::new(name)), "{name}");
+        }
+        assert!(ALL.applies_to(Utf8Path::new("schemas/invoice.schema.json")));
+        assert!(!ALL.applies_to(Utf8Path::new("config.yaml")));
+        assert!(!PacketTransforms::none().applies_to(Utf8Path::new("openapi.yaml")));
+
+        let summary = ALL.apply(Utf8Path::new("openapi.yaml"), spec).unwrap();
+        assert!(summary.contains("# API Specification: Billing (v1.2)"));
+        assert!(summary.contains("/invoices"));
+        // Not an API description after all: passed through unchanged
+        assert!(
+            ALL.apply(Utf8Path::new("openapi.yaml"), "name: demo\n")
+                .is_none()
+        );
+    }
+}
@@ -28,7 +28,6 @@ pub enum NextStep {
     /// Rewind to a previous phase (used by fixup system)
     Rewind { to: PhaseId },
     /// Complete the entire workflow
-    #[allow(dead_code)] // Reserved for workflow completion signaling
     Complete,
 }
 
@@ -121,6 +120,15 @@ pub trait Phase {
     /// appropriate artifacts for this phase, handling any necessary
     /// parsing and validation.
     fn postprocess(&self, raw: &str, ctx: &PhaseContext) -> Result<PhaseResult>;
+
+    /// Produce the phase response locally instead of invoking the LLM
+    ///
+    /// Phases that can run deterministically (such as the Final phase in
+    /// `assemble` mode) return `Some`; the orchestrator then skips the LLM
+    /// call and passes the returned text to `postprocess()`.
+    fn local_response(&self, _ctx: &PhaseContext) -> Option<Result<String>> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -3,16 +3,39 @@
 //! This module contains specific implementations of each phase in the
 //! spec generation workflow, starting with Requirements phase.
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
 
-use xchecker_extraction::{summarize_design, summarize_requirements, summarize_tasks};
-use xchecker_packet::{DEFAULT_PACKET_MAX_BYTES, DEFAULT_PACKET_MAX_LINES, Packet, PacketBuilder};
+use xchecker_extraction::{
+    DesignCore, RequirementsCore, TasksCore, summarize_design, summarize_requirements,
+    summarize_tasks,
+};
+use xchecker_packet::{
+    DEFAULT_PACKET_MAX_BYTES, DEFAULT_PACKET_MAX_LINES, MemoryBudget, Packet, PacketBuilder,
+    PacketTransforms,
+};
 use xchecker_phase_api::{NextStep, Phase, PhaseContext, PhaseMetadata, PhaseResult};
+use xchecker_redaction::SecretPathRules;
 use xchecker_status::artifact::{Artifact, ArtifactType};
+use xchecker_utils::canonicalization::split_front_matter;
+use xchecker_utils::compression;
+use xchecker_utils::ignore_file::IgnoreFile;
+use xchecker_utils::input_encoding::NonUtf8Policy;
 use xchecker_utils::types::PhaseId;
 use xchecker_utils::types::{FileEvidence, PacketEvidence};
-use xchecker_validation::OutputValidator;
+use xchecker_validation::{OutputLanguage, OutputValidator};
+
+pub mod review_diff;
+
+/// Artifacts the review phase reads, in packet order, with their headings
+const REVIEWED_ARTIFACTS: [(PhaseId, &str, &str); 6] = [
+    (PhaseId::Requirements, "md", "Requirements Document"),
+    (PhaseId::Requirements, "core.yaml", "Requirements Core Data"),
+    (PhaseId::Design, "md", "Design Document"),
+    (PhaseId::Design, "core.yaml", "Design Core Data"),
+    (PhaseId::Tasks, "md", "Tasks Document"),
+    (PhaseId::Tasks, "core.yaml", "Tasks Core Data"),
+];
 
 /// Common anti-summary instructions appended to all generative phase prompts.
 /// This prevents LLM from outputting meta-commentary instead of actual content.
@@ -53,10 +76,59 @@ fn packet_limits_from_config(ctx: &PhaseContext) -> (usize, usize) {
     (max_bytes, max_lines)
 }
 
+fn packet_transforms_from_config(ctx: &PhaseContext) -> PacketTransforms {
+    let enabled = |key: &str| ctx.config.get(key).is_some_and(|value| value == "true");
+    PacketTransforms {
+        manifests: enabled("packet_transform_manifests"),
+        lockfiles: enabled("packet_transform_lockfiles"),
+        openapi: enabled("packet_transform_openapi"),
+    }
+}
+
+fn packet_memory_budget_from_config(ctx: &PhaseContext) -> Option<MemoryBudget> {
+    let parse = |key: &str| {
+        ctx.config
+            .get(key)
+            .and_then(|value| value.parse::<usize>().ok())
+    };
+    let budget = MemoryBudget::new(parse("packet_max_memory_bytes")?);
+    Some(match parse("packet_spill_threshold_bytes") {
+        Some(threshold) => budget.with_spill_threshold(threshold),
+        None => budget,
+    })
+}
+
+fn packet_non_utf8_policy_from_config(ctx: &PhaseContext) -> NonUtf8Policy {
+    ctx.config
+        .get("packet_non_utf8")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_default()
+}
+
+fn secret_path_rules_from_config(ctx: &PhaseContext) -> Result<SecretPathRules> {
+    let list = |key: &str| -> Vec<String> {
+        ctx.config
+            .get(key)
+            .map(|patterns| patterns.lines().map(str::to_string).collect())
+            .unwrap_or_default()
+    };
+    SecretPathRules::new(
+        &list("redaction_paths_exclude"),
+        &list("redaction_paths_mask"),
+    )
+}
+
 fn build_packet_builder(ctx: &PhaseContext) -> Result<PacketBuilder> {
     let (max_bytes, max_lines) = packet_limits_from_config(ctx);
-    let builder =
-        PacketBuilder::with_selectors_and_limits(ctx.selectors.as_ref(), max_bytes, max_lines)?;
+    let mut builder =
+        PacketBuilder::with_selectors_and_limits(ctx.selectors.as_ref(), max_bytes, max_lines)?
+            .with_transforms(packet_transforms_from_config(ctx))
+            .with_non_utf8_policy(packet_non_utf8_policy_from_config(ctx))
+            .with_secret_paths(secret_path_rules_from_config(ctx)?)
+            .with_ignore_file(IgnoreFile::project_root()?);
+    if let Some(budget) = packet_memory_budget_from_config(ctx) {
+        builder = builder.with_memory_budget(budget);
+    }
 
     // Set redactor from context
     // Note: PacketBuilder doesn't have a direct redactor setter yet,
@@ -197,10 +269,7 @@ Please analyze the problem statement above and create structured requirements fo
             // Always log validation issues
             for err in &errors {
                 let redacted_err = ctx.redactor.redact_string(&err.to_string());
-                eprintln!(
-                    "[WARN] Validation issue in requirements output: {}",
-                    redacted_err
-                );
+                tracing::warn!("Validation issue in requirements output: {}", redacted_err);
             }
 
             // In strict mode, fail the phase
@@ -214,7 +283,7 @@ Please analyze the problem statement above and create structured requirements fo
 
         // Create main requirements.md artifact
         let requirements_artifact = Artifact {
-            name: "00-requirements.md".to_string(),
+            name: PhaseId::Requirements.artifact_name("md"),
             content: requirements_content.clone(),
             artifact_type: ArtifactType::Markdown,
             blake3_hash: blake3::hash(requirements_content.as_bytes())
@@ -225,7 +294,7 @@ Please analyze the problem statement above and create structured requirements fo
         // Create a core YAML artifact with structured data
         let core_yaml_content = self.generate_core_yaml(&requirements_content, ctx)?;
         let core_yaml_artifact = Artifact {
-            name: "00-requirements.core.yaml".to_string(),
+            name: PhaseId::Requirements.artifact_name("core.yaml"),
             content: core_yaml_content.clone(),
             artifact_type: ArtifactType::CoreYaml,
             blake3_hash: blake3::hash(core_yaml_content.as_bytes())
@@ -256,45 +325,7 @@ impl RequirementsPhase {
         // B3.0: Extract summary metadata from markdown
         let summary = summarize_requirements(requirements_md);
 
-        let yaml_content = format!(
-            r#"# Core requirements data for spec {}
-# This file contains structured data extracted from the requirements document
-
-spec_id: "{}"
-phase: "requirements"
-version: "1.0"
-
-# Metadata about requirements (B3.0 extraction)
-metadata:
-  total_requirements: {}
-  total_user_stories: {}
-  total_acceptance_criteria: {}
-  total_nfrs: {}
-  has_nfrs: {}
-
-# Structured requirements data (B3.1 - future)
-requirements: []
-
-# Non-functional requirements (B3.1 - future)
-nfrs: []
-
-# Dependencies and relationships
-dependencies: []
-
-# Generated timestamp
-generated_at: "{}"
-"#,
-            ctx.spec_id,
-            ctx.spec_id,
-            summary.requirement_count,
-            summary.user_story_count,
-            summary.acceptance_criteria_count,
-            summary.nfr_count,
-            summary.nfr_count > 0,
-            chrono::Utc::now().to_rfc3339()
-        );
-
-        Ok(yaml_content)
+        Ok(RequirementsCore::new(&ctx.spec_id, &summary, chrono::Utc::now()).to_yaml()?)
     }
 }
 
@@ -415,7 +446,7 @@ Please analyze the requirements and create a comprehensive design document follo
             // Always log validation issues
             for err in &errors {
                 let redacted_err = ctx.redactor.redact_string(&err.to_string());
-                eprintln!("[WARN] Validation issue in design output: {}", redacted_err);
+                tracing::warn!("Validation issue in design output: {}", redacted_err);
             }
 
             // In strict mode, fail the phase
@@ -429,7 +460,7 @@ Please analyze the requirements and create a comprehensive design document follo
 
         // Create main design.md artifact
         let design_artifact = Artifact {
-            name: "10-design.md".to_string(),
+            name: PhaseId::Design.artifact_name("md"),
             content: design_content.clone(),
             artifact_type: ArtifactType::Markdown,
             blake3_hash: blake3::hash(design_content.as_bytes()).to_hex().to_string(),
@@ -438,7 +469,7 @@ Please analyze the requirements and create a comprehensive design document follo
         // Create a core YAML artifact with structured design data
         let core_yaml_content = self.generate_core_yaml(&design_content, ctx)?;
         let core_yaml_artifact = Artifact {
-            name: "10-design.core.yaml".to_string(),
+            name: PhaseId::Design.artifact_name("core.yaml"),
             content: core_yaml_content.clone(),
             artifact_type: ArtifactType::CoreYaml,
             blake3_hash: blake3::hash(core_yaml_content.as_bytes())
@@ -467,54 +498,7 @@ impl DesignPhase {
         // B3.0: Extract summary metadata from markdown
         let summary = summarize_design(design_md);
 
-        let yaml_content = format!(
-            r#"# Core design data for spec {}
-# This file contains structured data extracted from the design document
-
-spec_id: "{}"
-phase: "design"
-version: "1.0"
-
-# Metadata about the design (B3.0 extraction)
-metadata:
-  has_architecture_section: {}
-  has_mermaid_diagrams: {}
-  total_components: {}
-  total_interfaces: {}
-  total_data_models: {}
-
-# Structured design data (B3.1 - future)
-architecture:
-  components: []
-  interfaces: []
-  data_flow: []
-
-# Data models (B3.1 - future)
-data_models: []
-
-# Error handling strategies (B3.1 - future)
-error_handling: []
-
-# Testing strategies (B3.1 - future)
-testing_strategy: []
-
-# Dependencies on requirements
-requirements_dependencies: []
-
-# Generated timestamp
-generated_at: "{}"
-"#,
-            ctx.spec_id,
-            ctx.spec_id,
-            summary.has_architecture,
-            summary.has_diagrams,
-            summary.component_count,
-            summary.interface_count,
-            summary.data_model_count,
-            chrono::Utc::now().to_rfc3339()
-        );
-
-        Ok(yaml_content)
+        Ok(DesignCore::new(&ctx.spec_id, &summary, chrono::Utc::now()).to_yaml()?)
     }
 }
 
@@ -635,7 +619,7 @@ Please analyze the design and requirements to create a comprehensive implementat
             // Always log validation issues
             for err in &errors {
                 let redacted_err = ctx.redactor.redact_string(&err.to_string());
-                eprintln!("[WARN] Validation issue in tasks output: {}", redacted_err);
+                tracing::warn!("Validation issue in tasks output: {}", redacted_err);
             }
 
             // In strict mode, fail the phase
@@ -649,7 +633,7 @@ Please analyze the design and requirements to create a comprehensive implementat
 
         // Create main tasks.md artifact
         let tasks_artifact = Artifact {
-            name: "20-tasks.md".to_string(),
+            name: PhaseId::Tasks.artifact_name("md"),
             content: tasks_content.clone(),
             artifact_type: ArtifactType::Markdown,
             blake3_hash: blake3::hash(tasks_content.as_bytes()).to_hex().to_string(),
@@ -658,7 +642,7 @@ Please analyze the design and requirements to create a comprehensive implementat
         // Create a core YAML artifact with structured tasks data
         let core_yaml_content = self.generate_core_yaml(&tasks_content, ctx)?;
         let core_yaml_artifact = Artifact {
-            name: "20-tasks.core.yaml".to_string(),
+            name: PhaseId::Tasks.artifact_name("core.yaml"),
             content: core_yaml_content.clone(),
             artifact_type: ArtifactType::CoreYaml,
             blake3_hash: blake3::hash(core_yaml_content.as_bytes())
@@ -687,43 +671,7 @@ impl TasksPhase {
         // B3.0: Extract summary metadata from markdown
         let summary = summarize_tasks(tasks_md);
 
-        let yaml_content = format!(
-            r#"# Core tasks data for spec {}
-# This file contains structured data extracted from the tasks document
-
-spec_id: "{}"
-phase: "tasks"
-version: "1.0"
-
-# Metadata about tasks (B3.0 extraction)
-metadata:
-  total_tasks: {}
-  total_subtasks: {}
-  total_milestones: {}
-  total_dependencies: {}
-
-# Structured tasks data (B3.1 - future)
-tasks: []
-
-# Task dependencies and ordering (B3.1 - future)
-dependencies: []
-
-# Requirements coverage (B3.1 - future)
-requirements_coverage: []
-
-# Generated timestamp
-generated_at: "{}"
-"#,
-            ctx.spec_id,
-            ctx.spec_id,
-            summary.task_count,
-            summary.subtask_count,
-            summary.milestone_count,
-            summary.dependency_count,
-            chrono::Utc::now().to_rfc3339()
-        );
-
-        Ok(yaml_content)
+        Ok(TasksCore::new(&ctx.spec_id, &summary, chrono::Utc::now()).to_yaml()?)
     }
 }
 
@@ -763,7 +711,7 @@ impl Phase for ReviewPhase {
     }
 
     fn prompt(&self, ctx: &PhaseContext) -> String {
-        format!(
+        let mut prompt = format!(
             r"You are a senior technical reviewer conducting a comprehensive review of the generated specification.
 
 Your task is to review the complete specification (requirements, design, and tasks) and identify any gaps, inconsistencies, or issues that need to be addressed.
@@ -807,7 +755,23 @@ Phase: Review
 
 Please conduct a thorough review of the specification artifacts and provide your assessment.",
             ctx.spec_id
-        )
+        );
+
+        let differential = review_diff::is_enabled(&ctx.config)
+            && matches!(
+                review_diff::ReviewBaseline::latest(&ctx.spec_dir),
+                Ok(Some(_))
+            );
+        if differential {
+            prompt.push_str(
+                "
+
+# Differential Re-Review
+
+The specification was reviewed before. The packet contains only what changed since that review: changed artifacts appear as unified diffs (line numbers refer to the current files) and unchanged artifacts are listed by name. Focus on whether the changes resolve earlier findings or introduce new issues, and base any fixup diffs on the current file contents.",
+            );
+        }
+        prompt
     }
 
     fn make_packet(&self, ctx: &PhaseContext) -> Result<Packet> {
@@ -821,157 +785,90 @@ Please conduct a thorough review of the specification artifacts and provide your
         content.push_str(&format!("Base Directory: {}\n", ctx.spec_dir.display()));
         content.push('\n');
 
-        // Include all previous phase artifacts for comprehensive review
-        content.push_str("=== COMPLETE SPECIFICATION FOR REVIEW ===\n");
-
-        // Requirements artifacts
-        let requirements_md_path = ctx.spec_dir.join("artifacts").join("00-requirements.md");
-        let requirements_yaml_path = ctx
-            .spec_dir
-            .join("artifacts")
-            .join("00-requirements.core.yaml");
-
-        if requirements_md_path.exists() {
-            match std::fs::read_to_string(&requirements_md_path) {
-                Ok(requirements_content) => {
-                    content.push_str("--- Requirements Document (00-requirements.md) ---\n");
-                    content.push_str(&requirements_content);
-                    content.push_str("\n\n");
-
-                    files.push(FileEvidence {
-                        path: "artifacts/00-requirements.md".to_string(),
-                        range: None,
-                        blake3_pre_redaction: blake3::hash(requirements_content.as_bytes())
-                            .to_hex()
-                            .to_string(),
-                        priority: xchecker_utils::types::Priority::Upstream,
-                    });
-                }
-                Err(e) => {
-                    content.push_str(&format!("Error reading requirements.md: {e}\n"));
-                }
-            }
+        // The last successful review is the diff baseline in differential mode;
+        // its snapshots are kept either way so a later run can diff against them
+        let previous = review_diff::ReviewBaseline::latest(&ctx.spec_dir)?;
+        let baseline = previous
+            .as_ref()
+            .filter(|_| review_diff::is_enabled(&ctx.config));
+
+        match baseline {
+            Some(baseline) => content.push_str(&format!(
+                "=== CHANGES SINCE LAST REVIEW ({}) ===\n",
+                baseline.receipt
+            )),
+            // Include all previous phase artifacts for comprehensive review
+            None => content.push_str("=== COMPLETE SPECIFICATION FOR REVIEW ===\n"),
         }
 
-        if requirements_yaml_path.exists() {
-            match std::fs::read_to_string(&requirements_yaml_path) {
-                Ok(yaml_content) => {
-                    content
-                        .push_str("--- Requirements Core Data (00-requirements.core.yaml) ---\n");
-                    content.push_str(&yaml_content);
-                    content.push_str("\n\n");
-
-                    files.push(FileEvidence {
-                        path: "artifacts/00-requirements.core.yaml".to_string(),
-                        range: None,
-                        blake3_pre_redaction: blake3::hash(yaml_content.as_bytes())
-                            .to_hex()
-                            .to_string(),
-                        priority: xchecker_utils::types::Priority::Upstream,
-                    });
-                }
-                Err(e) => {
-                    content.push_str(&format!("Error reading requirements.core.yaml: {e}\n"));
-                }
+        let mut unchanged = Vec::new();
+        for (phase, suffix, label) in REVIEWED_ARTIFACTS {
+            let name = phase.artifact_name(suffix);
+            let path = ctx.spec_dir.join("artifacts").join(&name);
+            if !compression::exists(&path) {
+                continue;
             }
-        }
-
-        // Design artifacts
-        let design_md_path = ctx.spec_dir.join("artifacts").join("10-design.md");
-        let design_yaml_path = ctx.spec_dir.join("artifacts").join("10-design.core.yaml");
-
-        if design_md_path.exists() {
-            match std::fs::read_to_string(&design_md_path) {
-                Ok(design_content) => {
-                    content.push_str("--- Design Document (10-design.md) ---\n");
-                    content.push_str(&design_content);
-                    content.push_str("\n\n");
-
-                    files.push(FileEvidence {
-                        path: "artifacts/10-design.md".to_string(),
-                        range: None,
-                        blake3_pre_redaction: blake3::hash(design_content.as_bytes())
-                            .to_hex()
-                            .to_string(),
-                        priority: xchecker_utils::types::Priority::Upstream,
-                    });
-                }
+            let text = match compression::read_to_string(&path) {
+                Ok(text) => text,
                 Err(e) => {
-                    content.push_str(&format!("Error reading design.md: {e}\n"));
-                }
-            }
-        }
-
-        if design_yaml_path.exists() {
-            match std::fs::read_to_string(&design_yaml_path) {
-                Ok(yaml_content) => {
-                    content.push_str("--- Design Core Data (10-design.core.yaml) ---\n");
-                    content.push_str(&yaml_content);
-                    content.push_str("\n\n");
-
-                    files.push(FileEvidence {
-                        path: "artifacts/10-design.core.yaml".to_string(),
-                        range: None,
-                        blake3_pre_redaction: blake3::hash(yaml_content.as_bytes())
-                            .to_hex()
-                            .to_string(),
-                        priority: xchecker_utils::types::Priority::Upstream,
-                    });
+                    content.push_str(&format!("Error reading {name}: {e}\n"));
+                    continue;
                 }
-                Err(e) => {
-                    content.push_str(&format!("Error reading design.core.yaml: {e}\n"));
+            };
+            let hash = blake3::hash(text.as_bytes()).to_hex().to_string();
+            let evidence_path = format!("artifacts/{name}");
+            review_diff::store_snapshot(&ctx.spec_dir, &hash, &text)?;
+
+            match baseline.map(|baseline| baseline.hashes.get(&evidence_path)) {
+                None => {
+                    content.push_str(&format!("--- {label} ({name}) ---\n{text}\n\n"));
                 }
-            }
-        }
-
-        // Tasks artifacts
-        let tasks_md_path = ctx.spec_dir.join("artifacts").join("20-tasks.md");
-        let tasks_yaml_path = ctx.spec_dir.join("artifacts").join("20-tasks.core.yaml");
-
-        if tasks_md_path.exists() {
-            match std::fs::read_to_string(&tasks_md_path) {
-                Ok(tasks_content) => {
-                    content.push_str("--- Tasks Document (20-tasks.md) ---\n");
-                    content.push_str(&tasks_content);
-                    content.push_str("\n\n");
-
-                    files.push(FileEvidence {
-                        path: "artifacts/20-tasks.md".to_string(),
-                        range: None,
-                        blake3_pre_redaction: blake3::hash(tasks_content.as_bytes())
-                            .to_hex()
-                            .to_string(),
-                        priority: xchecker_utils::types::Priority::Upstream,
-                    });
+                Some(Some(old_hash)) if *old_hash == hash => unchanged.push(name),
+                Some(Some(old_hash)) => {
+                    let diff = review_diff::read_snapshot(&ctx.spec_dir, old_hash)
+                        .and_then(|old| review_diff::changes(&old, &text, &evidence_path));
+                    match diff {
+                        Some(diff) => content.push_str(&format!(
+                            "--- {label} ({name}): changed since last review ---\n```diff\n{diff}```\n\n"
+                        )),
+                        None => content.push_str(&format!(
+                            "--- {label} ({name}): changed since last review, full text ---\n{text}\n\n"
+                        )),
+                    }
                 }
-                Err(e) => {
-                    content.push_str(&format!("Error reading tasks.md: {e}\n"));
+                Some(None) => {
+                    content.push_str(&format!(
+                        "--- {label} ({name}): new since last review ---\n{text}\n\n"
+                    ));
                 }
             }
+
+            files.push(FileEvidence {
+                path: evidence_path,
+                range: None,
+                blake3_pre_redaction: hash,
+                priority: xchecker_utils::types::Priority::Upstream,
+            });
         }
 
-        if tasks_yaml_path.exists() {
-            match std::fs::read_to_string(&tasks_yaml_path) {
-                Ok(yaml_content) => {
-                    content.push_str("--- Tasks Core Data (20-tasks.core.yaml) ---\n");
-                    content.push_str(&yaml_content);
-                    content.push_str("\n\n");
-
-                    files.push(FileEvidence {
-                        path: "artifacts/20-tasks.core.yaml".to_string(),
-                        range: None,
-                        blake3_pre_redaction: blake3::hash(yaml_content.as_bytes())
-                            .to_hex()
-                            .to_string(),
-                        priority: xchecker_utils::types::Priority::Upstream,
-                    });
-                }
-                Err(e) => {
-                    content.push_str(&format!("Error reading tasks.core.yaml: {e}\n"));
-                }
-            }
+        if !unchanged.is_empty() {
+            content.push_str(&format!(
+                "--- Unchanged since last review (not repeated): {} ---\n",
+                unchanged.join(", ")
+            ));
         }
 
+        let keep: std::collections::HashSet<&str> = files
+            .iter()
+            .map(|file| file.blake3_pre_redaction.as_str())
+            .chain(
+                previous
+                    .iter()
+                    .flat_map(|previous| previous.hashes.values().map(String::as_str)),
+            )
+            .collect();
+        review_diff::prune_snapshots(&ctx.spec_dir, &keep)?;
+
         // Compute hash of packet content
         let blake3_hash = blake3::hash(content.as_bytes()).to_hex().to_string();
 
@@ -982,6 +879,7 @@ Please conduct a thorough review of the specification artifacts and provide your
             files,
             max_bytes,
             max_lines,
+            ..Default::default()
         };
 
         let mut budget_used = xchecker_packet::BudgetUsage::new(max_bytes, max_lines);
@@ -995,7 +893,7 @@ Please conduct a thorough review of the specification artifacts and provide your
 
         // Create the main review.md artifact
         let review_artifact = Artifact {
-            name: "30-review.md".to_string(),
+            name: PhaseId::Review.artifact_name("md"),
             content: review_content.clone(),
             artifact_type: ArtifactType::Markdown,
             blake3_hash: blake3::hash(review_content.as_bytes()).to_hex().to_string(),
@@ -1015,7 +913,7 @@ Please conduct a thorough review of the specification artifacts and provide your
         // Create a core YAML artifact with structured review data
         let core_yaml_content = self.generate_core_yaml(&review_content, ctx, has_fixup_markers)?;
         let core_yaml_artifact = Artifact {
-            name: "30-review.core.yaml".to_string(),
+            name: PhaseId::Review.artifact_name("core.yaml"),
             content: core_yaml_content.clone(),
             artifact_type: ArtifactType::CoreYaml,
             blake3_hash: blake3::hash(core_yaml_content.as_bytes())
@@ -1091,6 +989,332 @@ impl Default for ReviewPhase {
     }
 }
 
+/// Default template used by the Final phase in `assemble` mode
+pub const DEFAULT_FINAL_TEMPLATE: &str = "# Final Specification: {{spec_id}}
+
+## Requirements
+
+{{requirements}}
+
+## Design
+
+{{design}}
+
+## Implementation Tasks
+
+{{tasks}}
+
+## Review
+
+{{review}}
+";
+
+/// Prior phases composed by the Final phase; each name doubles as placeholder
+const FINAL_SOURCES: [PhaseId; 4] = [
+    PhaseId::Requirements,
+    PhaseId::Design,
+    PhaseId::Tasks,
+    PhaseId::Review,
+];
+
+/// Implementation of Final phase
+///
+/// This phase composes the requirements, design, tasks, and review artifacts
+/// into a single `50-final.md` document. By default the LLM synthesizes the
+/// document; with `[final] mode = "assemble"` the prior artifacts are rendered
+/// into a template deterministically and no LLM call is made.
+#[derive(Debug, Clone)]
+pub struct FinalPhase;
+
+impl FinalPhase {
+    /// Create a new Final phase instance
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Whether the context selects deterministic assembly over LLM synthesis
+    fn is_assemble_mode(ctx: &PhaseContext) -> bool {
+        ctx.config
+            .get("final_mode")
+            .is_some_and(|mode| mode == "assemble")
+    }
+
+    /// Read a prior artifact, returning `None` if it has not been generated
+    fn read_artifact(ctx: &PhaseContext, name: &str) -> Option<String> {
+        compression::read_to_string(&ctx.spec_dir.join("artifacts").join(name)).ok()
+    }
+
+    /// Render prior artifacts into the configured (or default) template
+    ///
+    /// Missing artifacts render as a placeholder note so the output stays
+    /// stable regardless of which optional phases ran.
+    fn assemble(ctx: &PhaseContext) -> Result<String> {
+        let template = match ctx.config.get("final_template") {
+            Some(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read final template: {path}"))?,
+            None => DEFAULT_FINAL_TEMPLATE.to_string(),
+        };
+
+        let mut rendered = template.replace("{{spec_id}}", &ctx.spec_id);
+        for phase in FINAL_SOURCES {
+            let placeholder = phase.as_str();
+            let body = Self::read_artifact(ctx, &phase.artifact_name("md"))
+                .map(|content| split_front_matter(&content).1.trim().to_string())
+                .unwrap_or_else(|| format!("_No {placeholder} artifact was generated._"));
+            rendered = rendered.replace(&format!("{{{{{placeholder}}}}}"), &body);
+        }
+
+        Ok(rendered)
+    }
+}
+
+impl Phase for FinalPhase {
+    fn id(&self) -> PhaseId {
+        PhaseId::Final
+    }
+
+    fn deps(&self) -> &'static [PhaseId] {
+        // Final phase depends on Tasks phase; Review is included when present
+        &[PhaseId::Tasks]
+    }
+
+    fn can_resume(&self) -> bool {
+        true
+    }
+
+    fn prompt(&self, ctx: &PhaseContext) -> String {
+        format!(
+            r"You are a technical writer producing the final, consolidated specification document.
+
+Your task is to synthesize the requirements, design, implementation tasks, and review findings into a single coherent document.
+
+# Guidelines
+
+1. Preserve every requirement and acceptance criterion; do not invent new ones.
+2. Reflect any accepted review findings in the relevant sections.
+3. Keep the design and task sections consistent with the requirements.
+4. Remove duplication between artifacts while keeping cross-references intact.
+
+# Output Format
+
+Produce a Markdown document with these top-level sections:
+- # Final Specification: {}
+- ## Requirements
+- ## Design
+- ## Implementation Tasks
+- ## Review
+
+Spec ID: {}
+Phase: Final
+
+Please produce the final specification document.",
+            ctx.spec_id, ctx.spec_id
+        )
+    }
+
+    fn make_packet(&self, ctx: &PhaseContext) -> Result<Packet> {
+        let mut content = String::new();
+        let mut files = Vec::new();
+
+        content.push_str("=== SPEC GENERATION CONTEXT ===\n");
+        content.push_str(&format!("Spec ID: {}\n", ctx.spec_id));
+        content.push_str("Phase: Final\n");
+        content.push_str(&format!("Base Directory: {}\n", ctx.spec_dir.display()));
+        content.push('\n');
+
+        content.push_str("=== PRIOR ARTIFACTS FOR COMPOSITION ===\n");
+        for phase in FINAL_SOURCES {
+            let name = phase.artifact_name("md");
+            let Some(artifact_content) = Self::read_artifact(ctx, &name) else {
+                continue;
+            };
+            content.push_str(&format!("--- {name} ---\n"));
+            content.push_str(&artifact_content);
+            content.push_str("\n\n");
+
+            files.push(FileEvidence {
+                path: format!("artifacts/{name}"),
+                range: None,
+                blake3_pre_redaction: blake3::hash(artifact_content.as_bytes())
+                    .to_hex()
+                    .to_string(),
+                priority: xchecker_utils::types::Priority::Upstream,
+            });
+        }
+
+        let blake3_hash = blake3::hash(content.as_bytes()).to_hex().to_string();
+        let (max_bytes, max_lines) = packet_limits_from_config(ctx);
+
+        let evidence = PacketEvidence {
+            files,
+            max_bytes,
+            max_lines,
+            ..Default::default()
+        };
+
+        let mut budget_used = xchecker_packet::BudgetUsage::new(max_bytes, max_lines);
+        budget_used.add_content(content.len(), content.lines().count());
+
+        Ok(Packet::new(content, blake3_hash, evidence, budget_used))
+    }
+
+    fn postprocess(&self, raw: &str, _ctx: &PhaseContext) -> Result<PhaseResult> {
+        let final_content = format!("{}\n", raw.trim());
+
+        let final_artifact = Artifact {
+            name: PhaseId::Final.artifact_name("md"),
+            content: final_content.clone(),
+            artifact_type: ArtifactType::Markdown,
+            blake3_hash: blake3::hash(final_content.as_bytes()).to_hex().to_string(),
+        };
+
+        Ok(PhaseResult {
+            artifacts: vec![final_artifact],
+            next_step: NextStep::Complete,
+            metadata: PhaseMetadata::default(),
+        })
+    }
+
+    fn local_response(&self, ctx: &PhaseContext) -> Option<Result<String>> {
+        Self::is_assemble_mode(ctx).then(|| Self::assemble(ctx))
+    }
+}
+
+impl Default for FinalPhase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implementation of Localize phase
+///
+/// This optional phase translates `50-final.md` into one target language per
+/// run, producing `50-final.<code>.md`. The orchestrator runs it once for each
+/// language in `[final] languages`.
+#[derive(Debug, Clone)]
+pub struct LocalizePhase {
+    language: OutputLanguage,
+}
+
+impl LocalizePhase {
+    /// Create a Localize phase translating into `language`
+    #[must_use]
+    pub const fn new(language: OutputLanguage) -> Self {
+        Self { language }
+    }
+
+    /// The language this phase translates into
+    #[must_use]
+    pub const fn language(&self) -> OutputLanguage {
+        self.language
+    }
+
+    /// Name of the translated artifact, e.g. `50-final.de.md`
+    #[must_use]
+    pub fn artifact_name(&self) -> String {
+        PhaseId::Final.artifact_name(&format!("{}.md", self.language.code()))
+    }
+}
+
+impl Phase for LocalizePhase {
+    fn id(&self) -> PhaseId {
+        PhaseId::Localize
+    }
+
+    fn deps(&self) -> &'static [PhaseId] {
+        &[PhaseId::Final]
+    }
+
+    fn can_resume(&self) -> bool {
+        true
+    }
+
+    fn prompt(&self, ctx: &PhaseContext) -> String {
+        let language = self.language.name();
+        format!(
+            r"You are a professional technical translator.
+
+Your task is to translate the final specification document into {language}.
+
+# Guidelines
+
+1. Translate all prose, headings, and table text into {language}.
+2. Keep the Markdown structure unchanged: the same headings, lists, tables, and links in the same order.
+3. Do not translate code blocks, inline code, file paths, identifiers, or requirement and task IDs.
+4. Do not add, remove, or summarize content.
+
+Output only the translated document.
+
+Spec ID: {}
+Phase: Localize
+
+Please translate the final specification document into {language}.",
+            ctx.spec_id
+        )
+    }
+
+    fn make_packet(&self, ctx: &PhaseContext) -> Result<Packet> {
+        let mut content = String::new();
+        let mut files = Vec::new();
+
+        content.push_str("=== SPEC GENERATION CONTEXT ===\n");
+        content.push_str(&format!("Spec ID: {}\n", ctx.spec_id));
+        content.push_str("Phase: Localize\n");
+        content.push_str(&format!("Target Language: {}\n", self.language.name()));
+        content.push('\n');
+
+        // The Final dependency guarantees the document exists when the phase runs
+        let name = PhaseId::Final.artifact_name("md");
+        if let Ok(final_content) =
+            compression::read_to_string(&ctx.spec_dir.join("artifacts").join(&name))
+        {
+            content.push_str(&format!("=== DOCUMENT TO TRANSLATE: {name} ===\n"));
+            content.push_str(split_front_matter(&final_content).1);
+            content.push('\n');
+
+            files.push(FileEvidence {
+                path: format!("artifacts/{name}"),
+                range: None,
+                blake3_pre_redaction: blake3::hash(final_content.as_bytes()).to_hex().to_string(),
+                priority: xchecker_utils::types::Priority::Upstream,
+            });
+        }
+
+        let blake3_hash = blake3::hash(content.as_bytes()).to_hex().to_string();
+        let (max_bytes, max_lines) = packet_limits_from_config(ctx);
+
+        let evidence = PacketEvidence {
+            files,
+            max_bytes,
+            max_lines,
+            ..Default::default()
+        };
+
+        let mut budget_used = xchecker_packet::BudgetUsage::new(max_bytes, max_lines);
+        budget_used.add_content(content.len(), content.lines().count());
+
+        Ok(Packet::new(content, blake3_hash, evidence, budget_used))
+    }
+
+    fn postprocess(&self, raw: &str, _ctx: &PhaseContext) -> Result<PhaseResult> {
+        let content = format!("{}\n", raw.trim());
+
+        let artifact = Artifact {
+            name: self.artifact_name(),
+            blake3_hash: blake3::hash(content.as_bytes()).to_hex().to_string(),
+            content,
+            artifact_type: ArtifactType::Markdown,
+        };
+
+        Ok(PhaseResult {
+            artifacts: vec![artifact],
+            next_step: NextStep::Complete,
+            metadata: PhaseMetadata::default(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1203,9 +1427,10 @@ This is a test requirements document.
 
         assert!(result.is_ok());
         let yaml_content = result.unwrap();
-        assert!(yaml_content.contains("spec_id: \"test-123\""));
-        assert!(yaml_content.contains("phase: \"requirements\""));
-        assert!(yaml_content.contains("version: \"1.0\""));
+        let core = RequirementsCore::from_yaml(&yaml_content).unwrap();
+        assert_eq!(core.spec_id, "test-123");
+        assert_eq!(core.phase, "requirements");
+        assert_eq!(core.version, "1.0");
     }
 
     #[test]
@@ -1274,4 +1499,166 @@ This is a test requirements document.
         assert!(prompt.contains("FIXUP PLAN"));
         assert!(prompt.contains("test-123"));
     }
+
+    #[test]
+    fn test_review_phase_differential_packet_sends_only_changes() {
+        use xchecker_status::receipt::ReceiptManager;
+
+        let (mut ctx, _temp_dir) = create_test_context();
+        let artifacts_dir = ctx.spec_dir.join("artifacts");
+        std::fs::create_dir_all(&artifacts_dir).unwrap();
+        let design: String = (1..=40).map(|n| format!("Design line {n}\n")).collect();
+        std::fs::write(artifacts_dir.join("00-requirements.md"), "# Req\n\nR1\n").unwrap();
+        std::fs::write(artifacts_dir.join("10-design.md"), &design).unwrap();
+
+        // First review: full packet, recorded in a successful receipt
+        let phase = ReviewPhase::new();
+        let first = phase.make_packet(&ctx).unwrap();
+        assert!(first.content.contains("COMPLETE SPECIFICATION FOR REVIEW"));
+        let base = Utf8PathBuf::try_from(ctx.spec_dir.clone()).unwrap();
+        let manager = ReceiptManager::new(&base);
+        let receipt = manager.create_receipt(
+            "test-123",
+            PhaseId::Review,
+            0,
+            vec![],
+            "0.1.0",
+            "0.8.1",
+            "haiku",
+            None,
+            HashMap::new(),
+            first.evidence.clone(),
+            None,
+            None,
+            vec![],
+            None,
+            "native",
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        manager.write_receipt(&receipt).unwrap();
+
+        std::fs::write(
+            artifacts_dir.join("10-design.md"),
+            design.replace("Design line 20\n", "Design line twenty\n"),
+        )
+        .unwrap();
+        std::fs::write(artifacts_dir.join("20-tasks.md"), "- [ ] T1\n").unwrap();
+
+        // Not requested: still the full specification
+        let full = phase.make_packet(&ctx).unwrap();
+        assert!(full.content.contains("Design line 1\n"));
+
+        ctx.config
+            .insert(review_diff::CONFIG_KEY.to_string(), "true".to_string());
+        assert!(phase.prompt(&ctx).contains("Differential Re-Review"));
+        let packet = phase.make_packet(&ctx).unwrap();
+        assert!(
+            packet
+                .content
+                .contains("CHANGES SINCE LAST REVIEW (review-")
+        );
+        assert!(
+            packet
+                .content
+                .contains("-Design line 20\n+Design line twenty\n")
+        );
+        assert!(!packet.content.contains("Design line 1\n"));
+        assert!(!packet.content.contains("R1"));
+        assert!(
+            packet
+                .content
+                .contains("Unchanged since last review (not repeated): 00-requirements.md")
+        );
+        // New since the last review, so sent in full
+        assert!(packet.content.contains("- [ ] T1"));
+        assert_eq!(packet.evidence.files.len(), 3);
+    }
+
+    #[test]
+    fn test_final_phase_basic_properties() {
+        let phase = FinalPhase::new();
+
+        assert_eq!(phase.id(), PhaseId::Final);
+        assert_eq!(phase.deps(), &[PhaseId::Tasks]);
+        assert!(phase.can_resume());
+    }
+
+    #[test]
+    fn test_final_phase_assemble_mode_renders_prior_artifacts() {
+        let (mut ctx, _temp_dir) = create_test_context();
+        let artifacts_dir = ctx.spec_dir.join("artifacts");
+        std::fs::create_dir_all(&artifacts_dir).unwrap();
+        std::fs::write(artifacts_dir.join("00-requirements.md"), "# Req\n\nR1\n").unwrap();
+        std::fs::write(artifacts_dir.join("10-design.md"), "# Design\n").unwrap();
+        std::fs::write(artifacts_dir.join("20-tasks.md"), "- [ ] T1\n").unwrap();
+
+        let phase = FinalPhase::new();
+        assert!(phase.local_response(&ctx).is_none());
+
+        ctx.config
+            .insert("final_mode".to_string(), "assemble".to_string());
+        let first = phase.local_response(&ctx).unwrap().unwrap();
+        let second = phase.local_response(&ctx).unwrap().unwrap();
+        assert_eq!(first, second);
+        assert!(first.starts_with("# Final Specification: test-123"));
+        assert!(first.contains("# Req\n\nR1"));
+        assert!(first.contains("- [ ] T1"));
+        assert!(first.contains("_No review artifact was generated._"));
+
+        let result = phase.postprocess(&first, &ctx).unwrap();
+        assert_eq!(result.artifacts.len(), 1);
+        assert_eq!(result.artifacts[0].name, "50-final.md");
+    }
+
+    #[test]
+    fn test_final_phase_assemble_mode_uses_custom_template() {
+        let (mut ctx, temp_dir) = create_test_context();
+        let template_path = temp_dir.path().join("final.md.tmpl");
+        std::fs::write(&template_path, "Spec {{spec_id}}: {{tasks}}").unwrap();
+        ctx.config
+            .insert("final_mode".to_string(), "assemble".to_string());
+        ctx.config.insert(
+            "final_template".to_string(),
+            template_path.display().to_string(),
+        );
+
+        let rendered = FinalPhase::new().local_response(&ctx).unwrap().unwrap();
+
+        assert_eq!(
+            rendered,
+            "Spec test-123: _No tasks artifact was generated._"
+        );
+    }
+
+    #[test]
+    fn test_localize_phase_translates_final_into_language_artifact() {
+        let (ctx, _temp_dir) = create_test_context();
+        let phase = LocalizePhase::new(OutputLanguage::German);
+
+        assert_eq!(phase.id(), PhaseId::Localize);
+        assert_eq!(phase.deps(), &[PhaseId::Final]);
+        assert!(phase.prompt(&ctx).contains("into German"));
+        assert!(phase.make_packet(&ctx).unwrap().evidence.files.is_empty());
+
+        let artifacts_dir = ctx.spec_dir.join("artifacts");
+        std::fs::create_dir_all(&artifacts_dir).unwrap();
+        std::fs::write(
+            artifacts_dir.join("50-final.md"),
+            "---\nspec_id: test-123\n---\n# Final Specification\n",
+        )
+        .unwrap();
+        let packet = phase.make_packet(&ctx).unwrap();
+        assert!(packet.content.contains("# Final Specification"));
+        assert!(!packet.content.contains("spec_id: test-123"));
+        assert_eq!(packet.evidence.files[0].path, "artifacts/50-final.md");
+
+        let result = phase.postprocess("# Finale Spezifikation\n", &ctx).unwrap();
+        assert_eq!(result.artifacts.len(), 1);
+        assert_eq!(result.artifacts[0].name, "50-final.de.md");
+        assert_eq!(result.artifacts[0].content, "# Finale Spezifikation\n");
+    }
 }
@@ -0,0 +1,153 @@
+//! Differential re-review: send only what changed since the last review
+//!
+//! Every review snapshots the artifacts it reads into
+//! `context/review-baseline/`, keyed by their BLAKE3 hash. With
+//! `review_differential` enabled (`xchecker resume --phase review
+//! --differential`), the packet compares the current artifacts against the
+//! hashes recorded in the last successful review receipt: unchanged files are
+//! named but not repeated, and changed files are sent as section-aligned
+//! unified diffs against their snapshot. Files with no snapshot to diff
+//! against, or whose diff would be larger than the file, are sent in full.
+//! Without a successful review to compare against, the packet is the full
+//! specification.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use xchecker_status::receipt::ReceiptManager;
+use xchecker_utils::mddiff::{self, MarkdownDiff};
+use xchecker_utils::types::PhaseId;
+
+/// Config key that enables differential re-review
+pub const CONFIG_KEY: &str = "review_differential";
+
+/// Receipt flag naming the review receipt a differential packet was diffed against
+pub const RECEIPT_FLAG_BASELINE: &str = "review_baseline";
+
+/// Directory, relative to the spec, holding snapshots of reviewed artifacts
+const SNAPSHOT_DIR: &str = "context/review-baseline";
+
+/// Whether differential re-review was requested
+#[must_use]
+pub fn is_enabled(config: &HashMap<String, String>) -> bool {
+    config.get(CONFIG_KEY).is_some_and(|v| v == "true")
+}
+
+/// The last successful review of a spec
+#[derive(Debug, Clone)]
+pub struct ReviewBaseline {
+    /// File name of the review receipt
+    pub receipt: String,
+    /// BLAKE3 of each artifact the review read, keyed by packet path
+    pub hashes: HashMap<String, String>,
+}
+
+impl ReviewBaseline {
+    /// Read the latest successful review receipt of the spec at `spec_dir`
+    pub fn latest(spec_dir: &Path) -> Result<Option<Self>> {
+        let base = Utf8PathBuf::try_from(spec_dir.to_path_buf())
+            .map_err(|e| anyhow::anyhow!("Invalid UTF-8 path: {e}"))?;
+        let Some(receipt) =
+            ReceiptManager::new(&base).read_latest_successful_receipt(PhaseId::Review)?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            receipt: ReceiptManager::receipt_file_name(&receipt.phase, receipt.emitted_at),
+            hashes: receipt
+                .packet
+                .files
+                .into_iter()
+                .map(|file| (file.path, file.blake3_pre_redaction))
+                .collect(),
+        }))
+    }
+}
+
+/// Keep `content` (whose BLAKE3 is `hash`) for later reviews to diff against
+pub(crate) fn store_snapshot(spec_dir: &Path, hash: &str, content: &str) -> Result<()> {
+    let path = snapshot_path(spec_dir, hash)?;
+    if path.exists() {
+        return Ok(());
+    }
+    xchecker_utils::paths::ensure_dir_all(spec_dir.join(SNAPSHOT_DIR))
+        .with_context(|| format!("Failed to create {SNAPSHOT_DIR}"))?;
+    xchecker_utils::atomic_write::write_file_atomic(&path, content)
+        .with_context(|| format!("Failed to write review snapshot: {path}"))?;
+    Ok(())
+}
+
+/// The snapshot stored under `hash`, if there is one
+pub(crate) fn read_snapshot(spec_dir: &Path, hash: &str) -> Option<String> {
+    std::fs::read_to_string(snapshot_path(spec_dir, hash).ok()?).ok()
+}
+
+/// Remove snapshots whose hash is not in `keep`
+pub(crate) fn prune_snapshots(spec_dir: &Path, keep: &HashSet<&str>) -> Result<()> {
+    let dir = spec_dir.join(SNAPSHOT_DIR);
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Ok(());
+    };
+    for entry in entries {
+        let path = entry?.path();
+        let hash = path.file_stem().and_then(|stem| stem.to_str());
+        if hash.is_some_and(|hash| !keep.contains(hash)) {
+            std::fs::remove_file(&path)
+                .with_context(|| format!("Failed to remove review snapshot: {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+fn snapshot_path(spec_dir: &Path, hash: &str) -> Result<Utf8PathBuf> {
+    Utf8PathBuf::try_from(spec_dir.join(SNAPSHOT_DIR).join(format!("{hash}.txt")))
+        .map_err(|e| anyhow::anyhow!("Invalid UTF-8 path: {e}"))
+}
+
+/// Section-aligned unified diff of `old` → `new` for `path`
+///
+/// Returns `None` when the diff would be no smaller than `new` itself, in
+/// which case the new text should be sent whole.
+pub(crate) fn changes(old: &str, new: &str, path: &str) -> Option<String> {
+    let diff = mddiff::unified_diff(
+        &MarkdownDiff::default(),
+        old,
+        new,
+        path,
+        mddiff::DEFAULT_CONTEXT_LINES,
+    );
+    (!diff.is_empty() && diff.len() < new.len()).then_some(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changes_fall_back_to_full_text_when_not_smaller() {
+        let old: String = (1..=40).map(|n| format!("line {n}\n")).collect();
+        let edited = old.replace("line 10\n", "line ten\n");
+        let diff = changes(&old, &edited, "artifacts/10-design.md").unwrap();
+        assert!(diff.contains("@@ -7,7 +7,7 @@\n"));
+        assert!(diff.contains("-line 10\n+line ten\n"));
+
+        assert!(changes(&old, "rewritten\n", "artifacts/10-design.md").is_none());
+        assert!(changes(&old, &old, "artifacts/10-design.md").is_none());
+    }
+
+    #[test]
+    fn test_snapshots_round_trip_and_prune() {
+        let temp = tempfile::TempDir::new().unwrap();
+        store_snapshot(temp.path(), "keep", "kept\n").unwrap();
+        store_snapshot(temp.path(), "drop", "dropped\n").unwrap();
+
+        prune_snapshots(temp.path(), &HashSet::from(["keep"])).unwrap();
+        assert_eq!(
+            read_snapshot(temp.path(), "keep").as_deref(),
+            Some("kept\n")
+        );
+        assert!(read_snapshot(temp.path(), "drop").is_none());
+    }
+}
@@ -0,0 +1,228 @@
+//! Field-level comparison of two receipts (`xchecker diff-receipts`)
+//!
+//! Two runs over the same inputs always differ in their timestamps, so a
+//! plain JSON diff buries the interesting changes. This comparison flattens
+//! both receipts to dotted field paths, drops the fields expected to vary
+//! between runs, and tags each remaining difference with a category so that
+//! model, flag, and output-hash changes can be spotted first.
+//!
+//! Receipts are compared as raw JSON, so fields unknown to this build (or
+//! missing from older receipts) are still reported.
+
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Fields expected to differ between runs with identical inputs
+pub const VOLATILE_FIELDS: &[&str] = &["emitted_at", "stderr_tail", "stderr_redacted"];
+
+/// What kind of change a differing field represents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffCategory {
+    /// Model, provider, or CLI version
+    Model,
+    /// CLI flags and effective configuration
+    Flags,
+    /// Hashes of the phase's output files
+    Outputs,
+    /// Exit code and error fields
+    Outcome,
+    /// Warnings recorded during the run
+    Warnings,
+    /// Packet construction evidence
+    Packet,
+    /// Anything else
+    Other,
+}
+
+impl DiffCategory {
+    fn for_path(path: &str) -> Self {
+        let root = path.split(['.', '[']).next().unwrap_or(path);
+        match root {
+            "model_full_name" | "model_alias" | "claude_cli_version" | "xchecker_version"
+            | "runner" | "runner_distro" => Self::Model,
+            "llm" if path.starts_with("llm.provider") || path.starts_with("llm.model_used") => {
+                Self::Model
+            }
+            "flags" | "config_hash" | "config_snapshot" | "pipeline" | "diff_context" => {
+                Self::Flags
+            }
+            "outputs" => Self::Outputs,
+            "exit_code" | "error_kind" | "error_reason" | "fallback_used" | "suggested_actions" => {
+                Self::Outcome
+            }
+            "warnings" => Self::Warnings,
+            "packet" => Self::Packet,
+            _ => Self::Other,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Model => "model",
+            Self::Flags => "flags",
+            Self::Outputs => "outputs",
+            Self::Outcome => "outcome",
+            Self::Warnings => "warnings",
+            Self::Packet => "packet",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// A field whose value differs between two receipts
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReceiptFieldDiff {
+    /// Dotted path of the field; list entries keyed by `path` appear as
+    /// `outputs[artifacts/00-requirements.md].blake3_canonicalized`
+    pub path: String,
+    pub category: DiffCategory,
+    /// Value in the first receipt (`None` when absent)
+    pub left: Option<Value>,
+    /// Value in the second receipt (`None` when absent)
+    pub right: Option<Value>,
+}
+
+/// Compare two receipts field by field
+///
+/// Volatile fields are skipped. Lists of objects with a `path` field (outputs,
+/// packet files) are matched by path rather than position, and lists of
+/// strings (warnings) are compared as sets. Differences are ordered by
+/// category, then path.
+#[must_use]
+pub fn diff_receipts(left: &Value, right: &Value) -> Vec<ReceiptFieldDiff> {
+    let mut left_fields = BTreeMap::new();
+    let mut right_fields = BTreeMap::new();
+    flatten(left, String::new(), &mut left_fields);
+    flatten(right, String::new(), &mut right_fields);
+
+    let paths: BTreeSet<&String> = left_fields.keys().chain(right_fields.keys()).collect();
+    let mut diffs: Vec<ReceiptFieldDiff> = paths
+        .into_iter()
+        .filter(|path| !VOLATILE_FIELDS.contains(&path.as_str()))
+        .filter_map(|path| {
+            let l = left_fields.get(path);
+            let r = right_fields.get(path);
+            (l != r).then(|| ReceiptFieldDiff {
+                path: path.clone(),
+                category: DiffCategory::for_path(path),
+                left: l.cloned(),
+                right: r.cloned(),
+            })
+        })
+        .collect();
+    diffs.sort_by(|a, b| (a.category, &a.path).cmp(&(b.category, &b.path)));
+    diffs
+}
+
+fn flatten(value: &Value, prefix: String, out: &mut BTreeMap<String, Value>) {
+    let join = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{prefix}.{key}")
+        }
+    };
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                flatten(child, join(key), out);
+            }
+        }
+        Value::Array(items) if !items.is_empty() && items.iter().all(Value::is_string) => {
+            // Order-insensitive: each distinct string is its own field
+            for item in items {
+                if let Value::String(s) = item {
+                    out.insert(format!("{prefix}[{s}]"), Value::Bool(true));
+                }
+            }
+        }
+        Value::Array(items) if !items.is_empty() && keyed_by_path(items) => {
+            for item in items {
+                if let Some(Value::String(key)) = item.get("path") {
+                    flatten(item, format!("{prefix}[{key}]"), out);
+                }
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (index, item) in items.iter().enumerate() {
+                flatten(item, format!("{prefix}[{index}]"), out);
+            }
+        }
+        // Empty lists and nulls read the same as an absent field
+        Value::Array(_) | Value::Null => {}
+        scalar => {
+            out.insert(prefix, scalar.clone());
+        }
+    }
+}
+
+/// Whether every item is an object with a distinct string `path`
+fn keyed_by_path(items: &[Value]) -> bool {
+    let mut seen = BTreeSet::new();
+    items
+        .iter()
+        .all(|item| matches!(item.get("path"), Some(Value::String(p)) if seen.insert(p.as_str())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn receipt(model: &str, hash: &str, warnings: &[&str], emitted_at: &str) -> Value {
+        json!({
+            "schema_version": "1",
+            "emitted_at": emitted_at,
+            "spec_id": "demo",
+            "phase": "requirements",
+            "model_full_name": model,
+            "flags": {"strict": "false"},
+            "outputs": [
+                {"path": "artifacts/00-requirements.core.yaml", "blake3_canonicalized": "aaa"},
+                {"path": "artifacts/00-requirements.md", "blake3_canonicalized": hash},
+            ],
+            "warnings": warnings,
+            "exit_code": 0,
+            "error_kind": null,
+        })
+    }
+
+    #[test]
+    fn test_identical_inputs_ignore_volatile_fields() {
+        let a = receipt("sonnet", "bbb", &["w1", "w2"], "2025-01-01T00:00:00Z");
+        let b = receipt("sonnet", "bbb", &["w2", "w1"], "2025-06-01T12:00:00Z");
+        assert!(diff_receipts(&a, &b).is_empty());
+    }
+
+    #[test]
+    fn test_semantic_differences_are_categorized() {
+        let a = receipt("sonnet", "bbb", &["w1"], "2025-01-01T00:00:00Z");
+        let mut b = receipt("opus", "ccc", &["w2"], "2025-01-01T00:00:00Z");
+        b["outputs"].as_array_mut().unwrap().swap(0, 1);
+        b["flags"]["strict"] = json!("true");
+
+        let diffs = diff_receipts(&a, &b);
+        let summary: Vec<(&str, DiffCategory)> = diffs
+            .iter()
+            .map(|d| (d.path.as_str(), d.category))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("model_full_name", DiffCategory::Model),
+                ("flags.strict", DiffCategory::Flags),
+                (
+                    "outputs[artifacts/00-requirements.md].blake3_canonicalized",
+                    DiffCategory::Outputs
+                ),
+                ("warnings[w1]", DiffCategory::Warnings),
+                ("warnings[w2]", DiffCategory::Warnings),
+            ]
+        );
+        assert_eq!(diffs[3].right, None);
+        assert_eq!(diffs[4].left, None);
+    }
+}
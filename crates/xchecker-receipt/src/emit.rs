@@ -137,6 +137,9 @@ impl ReceiptManager {
             diff_context,
             llm: None, // Will be set by orchestrator when ClaudeResponse is available
             pipeline,
+            suggested_actions: vec![],
+            config_hash: None,
+            config_snapshot: None,
         }
     }
 }
@@ -18,6 +18,7 @@ pub(super) const fn error_to_exit_code_and_kind(error: &XCheckerError) -> (i32,
 
         // Packet overflow before Claude invocation
         XCheckerError::PacketOverflow { .. } => (7, ErrorKind::PacketOverflow),
+        XCheckerError::PacketMemoryExceeded { .. } => (7, ErrorKind::PacketOverflow),
 
         // Secret detection (redaction hard stop)
         XCheckerError::SecretDetected { .. } => (8, ErrorKind::SecretDetected),
@@ -29,6 +30,8 @@ pub(super) const fn error_to_exit_code_and_kind(error: &XCheckerError) -> (i32,
         // Phase errors
         XCheckerError::Phase(phase_err) => match phase_err {
             PhaseError::Timeout { .. } => (10, ErrorKind::PhaseTimeout),
+            PhaseError::OperatorHalt { .. } => (11, ErrorKind::OperatorHalt),
+            PhaseError::ComplianceBlocked { .. } => (12, ErrorKind::ComplianceBlocked),
             // Invalid transitions are CLI argument errors (FR-ORC-001, FR-ORC-002)
             PhaseError::InvalidTransition { .. } => (2, ErrorKind::CliArgs),
             PhaseError::DependencyNotSatisfied { .. } => (2, ErrorKind::CliArgs),
@@ -98,6 +101,7 @@ pub fn write_error_receipt_and_exit(
             files: vec![],
             max_bytes: 0,
             max_lines: 0,
+            ..Default::default()
         },
         outputs: vec![],
         exit_code,
@@ -110,6 +114,9 @@ pub fn write_error_receipt_and_exit(
         diff_context: None,
         llm: None,      // No LLM info for early errors
         pipeline: None, // No pipeline info for early errors
+        suggested_actions: vec![],
+        config_hash: None,
+        config_snapshot: None,
     };
 
     // Try to write the receipt, but don't fail if we can't
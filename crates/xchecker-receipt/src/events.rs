@@ -0,0 +1,311 @@
+//! Per-run event log written next to the receipts
+//!
+//! Receipts record how a phase ended; they do not say when each step ran.
+//! While a phase executes, [`EventLog`] appends one JCS-canonical JSON object
+//! per step to `receipts/<run-id>.events.ndjson`, so tools that were not
+//! watching the run can rebuild its timeline afterwards. Each line carries an
+//! [`EventRecord`] envelope; the `event` field names the [`RunEvent`] variant.
+//! The format is described by `schemas/events.v1.json`.
+//!
+//! The log is best effort: a write failure is logged once and disables the
+//! log, but never fails the phase. Lines are flushed as they are written, so a
+//! crashed run leaves a valid prefix of its timeline.
+
+use camino::{Utf8Path, Utf8PathBuf};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+
+/// Schema version written into every event line
+pub const EVENTS_SCHEMA_VERSION: &str = "1";
+
+/// Suffix of event log files inside the receipts directory
+pub const EVENTS_FILE_SUFFIX: &str = ".events.ndjson";
+
+/// One step of a phase run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum RunEvent {
+    /// The phase began executing
+    PhaseStarted,
+    /// A pre- or post-phase hook finished
+    HookFinished {
+        /// `pre_phase` or `post_phase`
+        hook: String,
+        /// Whether the hook allowed the phase to continue
+        success: bool,
+        /// Warning the hook left in the receipt, if any
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        warning: Option<String>,
+    },
+    /// The context packet was assembled
+    PacketBuilt {
+        /// BLAKE3 hash of the packet content
+        packet_hash: String,
+        /// Files included in the packet
+        files: usize,
+        /// Bytes used against the packet budget
+        bytes: usize,
+        /// Lines used against the packet budget
+        lines: usize,
+    },
+    /// The packet was scanned for secrets and compliance findings
+    PacketScanned {
+        /// Whether the packet may be sent to the provider
+        allowed: bool,
+        /// Compliance findings recorded for the packet
+        compliance_findings: usize,
+    },
+    /// The provider invocation began
+    LlmStarted {
+        /// `provider`, `dry_run`, or `local` (no provider call)
+        mode: String,
+    },
+    /// The provider invocation returned
+    LlmFinished {
+        /// Exit code of the invocation
+        exit_code: i32,
+        /// Response bytes received
+        bytes: usize,
+    },
+    /// Artifacts were promoted from staging to their final names
+    ArtifactsPromoted {
+        /// Artifact paths relative to the spec root
+        artifacts: Vec<String>,
+    },
+    /// The phase finished, successfully or not
+    PhaseFinished {
+        /// Whether the phase succeeded
+        success: bool,
+        /// Exit code of the phase
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        exit_code: Option<i32>,
+        /// Receipt path relative to the spec root, if one was written
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        receipt: Option<String>,
+        /// Error message when the phase failed
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// The run stopped without finishing (timeout, cancellation, or panic)
+    PhaseAborted,
+}
+
+/// One line of an event log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventRecord {
+    /// Always [`EVENTS_SCHEMA_VERSION`]
+    pub schema_version: String,
+    /// Run the event belongs to (also the log's file stem)
+    pub run_id: String,
+    /// Position of the event in the run, starting at 0
+    pub seq: u64,
+    /// When the event was recorded
+    pub ts: DateTime<Utc>,
+    /// Spec being executed
+    pub spec_id: String,
+    /// Phase being executed
+    pub phase: String,
+    /// The event itself
+    #[serde(flatten)]
+    pub event: RunEvent,
+}
+
+/// Appends the events of one phase run to `receipts/<run-id>.events.ndjson`
+///
+/// Dropping a log whose run never recorded [`RunEvent::PhaseFinished`] appends
+/// [`RunEvent::PhaseAborted`], which is how timeouts and cancellations show up.
+#[derive(Debug)]
+pub struct EventLog {
+    path: Utf8PathBuf,
+    file: Option<File>,
+    failed: bool,
+    run_id: String,
+    spec_id: String,
+    phase: String,
+    seq: u64,
+    finished: bool,
+}
+
+impl EventLog {
+    /// Start the log for a run of `phase` of `spec_id` beginning now
+    ///
+    /// The run id is derived from the spec, phase, and start time. Nothing is
+    /// written until the first [`record`](Self::record).
+    #[must_use]
+    pub fn start(receipts_dir: &Utf8Path, spec_id: &str, phase: &str) -> Self {
+        let started_at = Utc::now();
+        let run_id = run_id(spec_id, phase, started_at);
+        Self {
+            path: Self::path(receipts_dir, &run_id),
+            file: None,
+            failed: false,
+            run_id,
+            spec_id: spec_id.to_string(),
+            phase: phase.to_string(),
+            seq: 0,
+            finished: false,
+        }
+    }
+
+    /// Path of the event log for `run_id`
+    #[must_use]
+    pub fn path(receipts_dir: &Utf8Path, run_id: &str) -> Utf8PathBuf {
+        receipts_dir.join(format!("{run_id}{EVENTS_FILE_SUFFIX}"))
+    }
+
+    /// Id of this run
+    #[must_use]
+    pub fn run_id(&self) -> &str {
+        &self.run_id
+    }
+
+    /// Append `event` to the log
+    pub fn record(&mut self, event: RunEvent) {
+        if matches!(event, RunEvent::PhaseFinished { .. }) {
+            self.finished = true;
+        }
+        let record = EventRecord {
+            schema_version: EVENTS_SCHEMA_VERSION.to_string(),
+            run_id: self.run_id.clone(),
+            seq: self.seq,
+            ts: Utc::now(),
+            spec_id: self.spec_id.clone(),
+            phase: self.phase.clone(),
+            event,
+        };
+        self.seq += 1;
+        if self.failed {
+            return;
+        }
+        if let Err(e) = self.append(&record) {
+            self.failed = true;
+            tracing::warn!("Disabling event log {}: {e:#}", self.path);
+        }
+    }
+
+    fn append(&mut self, record: &EventRecord) -> anyhow::Result<()> {
+        let mut line = serde_json_canonicalizer::to_string(record)?;
+        line.push('\n');
+        let file = match &mut self.file {
+            Some(file) => file,
+            None => {
+                if let Some(parent) = self.path.parent() {
+                    xchecker_utils::paths::ensure_dir_all(parent)?;
+                }
+                self.file.insert(
+                    OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(&self.path)?,
+                )
+            }
+        };
+        file.write_all(line.as_bytes())?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+impl Drop for EventLog {
+    fn drop(&mut self) {
+        if !self.finished && self.seq > 0 {
+            self.record(RunEvent::PhaseAborted);
+        }
+    }
+}
+
+/// Read every event of a log, in order
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a line is not a valid event.
+pub fn read_events(path: &Utf8Path) -> anyhow::Result<Vec<EventRecord>> {
+    use anyhow::Context;
+
+    let content =
+        std::fs::read_to_string(path).with_context(|| format!("Failed to read {path}"))?;
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("Invalid event on line {} of {path}", index + 1))
+        })
+        .collect()
+}
+
+/// Run id for a run of `phase` of `spec_id` started at `started_at`
+fn run_id(spec_id: &str, phase: &str, started_at: DateTime<Utc>) -> String {
+    let seed = format!(
+        "{spec_id}:{phase}:{}",
+        started_at.to_rfc3339_opts(chrono::SecondsFormat::Nanos, true)
+    );
+    blake3::hash(seed.as_bytes()).to_hex()[..16].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_event_log_appends_sequenced_lines() {
+        let temp = TempDir::new().unwrap();
+        let receipts = Utf8Path::from_path(temp.path()).unwrap().join("receipts");
+
+        let mut log = EventLog::start(&receipts, "demo", "design");
+        let path = EventLog::path(&receipts, log.run_id());
+        assert!(!path.exists(), "nothing is written before the first event");
+
+        log.record(RunEvent::PhaseStarted);
+        log.record(RunEvent::LlmFinished {
+            exit_code: 0,
+            bytes: 42,
+        });
+        log.record(RunEvent::PhaseFinished {
+            success: true,
+            exit_code: Some(0),
+            receipt: Some("receipts/design-20250101_000000.json".to_string()),
+            error: None,
+        });
+        let run_id = log.run_id().to_string();
+        drop(log);
+
+        let events = read_events(&path).unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events.iter().map(|e| e.seq).collect::<Vec<_>>(), [0, 1, 2]);
+        assert!(
+            events
+                .iter()
+                .all(|e| e.run_id == run_id && e.phase == "design")
+        );
+        assert!(events.windows(2).all(|pair| pair[0].ts <= pair[1].ts));
+        assert_eq!(events[0].event, RunEvent::PhaseStarted);
+
+        let first_line = std::fs::read_to_string(&path).unwrap();
+        assert!(first_line.starts_with("{\"event\":\"phase_started\","));
+    }
+
+    #[test]
+    fn test_unfinished_run_is_marked_aborted() {
+        let temp = TempDir::new().unwrap();
+        let receipts = Utf8Path::from_path(temp.path()).unwrap();
+
+        let mut log = EventLog::start(receipts, "demo", "tasks");
+        let path = EventLog::path(receipts, log.run_id());
+        log.record(RunEvent::PhaseStarted);
+        drop(log);
+
+        let events = read_events(&path).unwrap();
+        assert_eq!(events.last().unwrap().event, RunEvent::PhaseAborted);
+
+        // A log that never recorded anything leaves no file behind
+        let log = EventLog::start(receipts, "demo", "review");
+        let path = EventLog::path(receipts, log.run_id());
+        drop(log);
+        assert!(!path.exists());
+    }
+}
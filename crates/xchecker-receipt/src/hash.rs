@@ -1,4 +1,5 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
 
 use xchecker_utils::error::XCheckerError;
 use xchecker_utils::types::{FileHash, FileType};
@@ -7,6 +8,9 @@ use super::ReceiptManager;
 
 impl ReceiptManager {
     /// Create a file hash for an artifact using canonicalization
+    ///
+    /// Markdown front matter is hashed separately from the body, so adding
+    /// generation metadata does not change `blake3_canonicalized`.
     pub fn create_file_hash(
         &self,
         file_path: &str,
@@ -17,10 +21,30 @@ impl ReceiptManager {
         let blake3_hash = self
             .canonicalizer
             .hash_canonicalized_with_context(content, file_type, phase)?;
+        let front_matter_hash = match file_type {
+            FileType::Markdown => self.canonicalizer.hash_front_matter(content).map_err(|e| {
+                XCheckerError::CanonicalizationFailed {
+                    phase: phase.to_string(),
+                    reason: e.to_string(),
+                }
+            })?,
+            _ => None,
+        };
 
         Ok(FileHash {
             path: file_path.to_string(),
             blake3_canonicalized: blake3_hash,
+            blake3_front_matter: front_matter_hash,
         })
     }
 }
+
+/// BLAKE3 of a configuration snapshot's JCS-canonical JSON (64 hex chars)
+///
+/// Recorded as `config_hash` in receipts so runs can be matched to the
+/// configuration that produced them after config files change.
+pub fn config_snapshot_hash(snapshot: &BTreeMap<String, String>) -> Result<String> {
+    let json_bytes = serde_json_canonicalizer::to_vec(snapshot)
+        .context("Failed to canonicalize configuration snapshot")?;
+    Ok(blake3::hash(&json_bytes).to_hex().to_string())
+}
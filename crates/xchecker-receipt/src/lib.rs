@@ -1,14 +1,21 @@
+mod compare;
 pub mod dossier;
 mod emit;
 mod errors;
+pub mod events;
 mod hash;
+mod migrate;
 mod model;
 pub mod route;
+pub mod usage;
 mod writer;
 
+pub use compare::{DiffCategory, ReceiptFieldDiff, VOLATILE_FIELDS, diff_receipts};
 pub use errors::write_error_receipt_and_exit;
+pub use hash::config_snapshot_hash;
+pub use migrate::{CURRENT_RECEIPT_SCHEMA, ReceiptMigrationReport, upgrade_receipt_value};
 pub use model::ReceiptManager;
-pub use writer::add_rename_retry_warning;
+pub use writer::{ReceiptSnapshot, add_rename_retry_warning};
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,220 @@
+//! Upgrade receipts written by pre-1.0 builds to the current v1 schema
+//!
+//! Older builds wrote receipts with a `timestamp` field instead of
+//! `emitted_at`, without a `schema_version`, and without several fields that
+//! are now required. Rather than teaching every reader to accept those shapes,
+//! `xchecker migrate-receipts` rewrites them once, in place, after copying the
+//! originals to a backup directory next to `receipts/`.
+
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use serde_json::{Map, Value, json};
+use std::fs;
+
+use xchecker_utils::atomic_write::{write_bytes_atomic, write_file_atomic};
+use xchecker_utils::compression;
+use xchecker_utils::types::{ErrorKind, Receipt};
+
+use super::ReceiptManager;
+
+/// Target schema version written by the migration
+pub const CURRENT_RECEIPT_SCHEMA: &str = "1";
+
+/// Top-level receipt fields written by older builds and renamed since
+pub const LEGACY_RECEIPT_FIELDS: &[&str] = &["timestamp"];
+
+/// Outcome of migrating a spec's receipts
+#[derive(Debug, Default)]
+pub struct ReceiptMigrationReport {
+    /// Receipt file names that were (or, in a dry run, would be) rewritten
+    pub migrated: Vec<String>,
+    /// Number of receipts already in the current schema
+    pub current: usize,
+    /// Receipt file names that could not be migrated, with the reason
+    pub failed: Vec<(String, String)>,
+    /// Directory holding the original copies of migrated receipts
+    pub backup_path: Option<Utf8PathBuf>,
+}
+
+impl ReceiptManager {
+    /// Upgrade all legacy receipts for a spec to the current schema
+    ///
+    /// Receipts that already match the current schema are left untouched.
+    /// Before anything is rewritten, the originals are copied to
+    /// `receipts-backup-<timestamp>/` beside the receipts directory. With
+    /// `dry_run`, nothing is written and the report lists what would change.
+    pub fn migrate_receipts(&self, spec_id: &str, dry_run: bool) -> Result<ReceiptMigrationReport> {
+        let mut report = ReceiptMigrationReport::default();
+        if !self.receipts_path.exists() {
+            return Ok(report);
+        }
+
+        let mut names: Vec<String> = fs::read_dir(&self.receipts_path)?
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter(|name| compression::logical_name(name).ends_with(".json"))
+            .collect();
+        names.sort();
+
+        let mut upgraded = Vec::new();
+        for name in names {
+            let path = self.receipts_path.join(&name);
+            let result = compression::read_to_string(path.as_std_path())
+                .map_err(anyhow::Error::from)
+                .and_then(|content| Ok(serde_json::from_str::<Value>(&content)?))
+                .and_then(|value| {
+                    upgrade_receipt_value(value, spec_id, phase_from_file_name(&name))
+                });
+            match result {
+                Ok((_, false)) => report.current += 1,
+                Ok((receipt, true)) => upgraded.push((name, receipt)),
+                Err(e) => report.failed.push((name, format!("{e:#}"))),
+            }
+        }
+
+        report.migrated = upgraded.iter().map(|(name, _)| name.clone()).collect();
+        if dry_run || upgraded.is_empty() {
+            return Ok(report);
+        }
+
+        let backup_path = self.receipts_path.with_file_name(format!(
+            "receipts-backup-{}",
+            chrono::Utc::now().format("%Y%m%d_%H%M%S")
+        ));
+        xchecker_utils::paths::ensure_dir_all(&backup_path)
+            .with_context(|| format!("Failed to create backup directory: {backup_path}"))?;
+        for (name, _) in &upgraded {
+            fs::copy(self.receipts_path.join(name), backup_path.join(name))
+                .with_context(|| format!("Failed to back up receipt {name}"))?;
+        }
+        report.backup_path = Some(backup_path);
+
+        for (name, receipt) in &upgraded {
+            let path = self.receipts_path.join(name);
+            let json_content = Self::emit_receipt_jcs(receipt)?;
+            // Compressed receipts stay compressed under the same name
+            if compression::is_compressed(path.as_std_path()) {
+                let level = compression::compression_policy().level;
+                let bytes = compression::compress(json_content.as_bytes(), level)?;
+                write_bytes_atomic(&path, &bytes)
+            } else {
+                write_file_atomic(&path, &json_content)
+            }
+            .with_context(|| format!("Failed to write migrated receipt: {path}"))?;
+        }
+        self.bump_generation()?;
+
+        Ok(report)
+    }
+}
+
+impl ReceiptManager {
+    /// Legacy top-level fields still present in this spec's receipts
+    ///
+    /// Returns each field of [`LEGACY_RECEIPT_FIELDS`] found in at least one
+    /// receipt, in table order. Unreadable receipts are ignored.
+    #[must_use]
+    pub fn legacy_fields_in_use(&self) -> Vec<&'static str> {
+        let Ok(entries) = fs::read_dir(&self.receipts_path) else {
+            return Vec::new();
+        };
+        let receipts: Vec<Map<String, Value>> = entries
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                let name = path.file_name()?.to_str()?;
+                if !compression::logical_name(name).ends_with(".json") {
+                    return None;
+                }
+                serde_json::from_str(&compression::read_to_string(&path).ok()?).ok()
+            })
+            .collect();
+
+        LEGACY_RECEIPT_FIELDS
+            .iter()
+            .copied()
+            .filter(|field| receipts.iter().any(|obj| obj.contains_key(*field)))
+            .collect()
+    }
+}
+
+/// Upgrade a parsed receipt to the current schema
+///
+/// Returns the upgraded receipt and whether anything had to change. Fields
+/// that cannot be recovered from the legacy receipt are filled with the same
+/// placeholders used for early error receipts (`"unknown"`, empty lists).
+pub fn upgrade_receipt_value(
+    value: Value,
+    spec_id: &str,
+    phase_hint: Option<&str>,
+) -> Result<(Receipt, bool)> {
+    let Value::Object(mut obj) = value else {
+        anyhow::bail!("receipt is not a JSON object");
+    };
+    let mut changed = false;
+
+    // Renamed in 1.0
+    if let Some(timestamp) = obj.remove("timestamp") {
+        obj.entry("emitted_at").or_insert(timestamp);
+        changed = true;
+    }
+
+    if obj.get("schema_version").and_then(Value::as_str) != Some(CURRENT_RECEIPT_SCHEMA) {
+        obj.insert("schema_version".into(), json!(CURRENT_RECEIPT_SCHEMA));
+        changed = true;
+    }
+
+    // Legacy builds could record error kinds that no longer exist
+    if let Some(kind) = obj.get("error_kind")
+        && !kind.is_null()
+        && serde_json::from_value::<ErrorKind>(kind.clone()).is_err()
+    {
+        obj.insert("error_kind".into(), json!("unknown"));
+        changed = true;
+    }
+
+    let failed = obj.get("error_kind").is_some_and(|k| !k.is_null())
+        || obj.get("error_reason").is_some_and(|r| !r.is_null());
+    let defaults = [
+        ("spec_id", json!(spec_id)),
+        ("phase", json!(phase_hint.unwrap_or("unknown"))),
+        ("xchecker_version", json!("unknown")),
+        ("claude_cli_version", json!("unknown")),
+        ("model_full_name", json!("unknown")),
+        ("canonicalization_version", json!("yaml-v1,md-v1")),
+        ("canonicalization_backend", json!("jcs-rfc8785")),
+        ("flags", json!({})),
+        ("runner", json!("unknown")),
+        (
+            "packet",
+            json!({ "files": [], "max_bytes": 0, "max_lines": 0 }),
+        ),
+        ("outputs", json!([])),
+        ("exit_code", json!(if failed { 1 } else { 0 })),
+        ("warnings", json!([])),
+    ];
+    changed |= fill_missing(&mut obj, defaults);
+
+    let receipt = serde_json::from_value(Value::Object(obj))
+        .context("receipt does not match the v1 schema after migration")?;
+    Ok((receipt, changed))
+}
+
+/// Insert defaults for absent (or null) required fields; returns whether any were added
+fn fill_missing(
+    obj: &mut Map<String, Value>,
+    defaults: impl IntoIterator<Item = (&'static str, Value)>,
+) -> bool {
+    let mut changed = false;
+    for (key, default) in defaults {
+        let slot = obj.entry(key).or_insert(Value::Null);
+        if slot.is_null() {
+            *slot = default;
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Receipt files are named `<phase>-<timestamp>.json`
+fn phase_from_file_name(name: &str) -> Option<&str> {
+    name.split_once('-').map(|(phase, _)| phase)
+}
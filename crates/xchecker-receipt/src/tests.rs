@@ -25,12 +25,14 @@ fn test_create_receipt() {
     let outputs = vec![FileHash {
         path: "artifacts/00-requirements.md".to_string(),
         blake3_canonicalized: "abc123".to_string(),
+        blake3_front_matter: None,
     }];
 
     let packet = PacketEvidence {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let mut flags = std::collections::HashMap::new();
@@ -104,12 +106,14 @@ fn test_write_and_read_receipt() {
     let outputs = vec![FileHash {
         path: "artifacts/00-requirements.md".to_string(),
         blake3_canonicalized: "abc123".to_string(),
+        blake3_front_matter: None,
     }];
 
     let packet = PacketEvidence {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -172,12 +176,14 @@ fn test_list_receipts() {
     let outputs = vec![FileHash {
         path: "artifacts/00-requirements.md".to_string(),
         blake3_canonicalized: "abc123".to_string(),
+        blake3_front_matter: None,
     }];
 
     let packet = PacketEvidence {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -211,6 +217,275 @@ fn test_list_receipts() {
     assert_eq!(receipts[0].spec_id, "test-spec");
 }
 
+fn minimal_receipt(manager: &ReceiptManager, phase: PhaseId) -> Receipt {
+    manager.create_receipt(
+        "test-spec",
+        phase,
+        0,
+        vec![],
+        "0.1.0",
+        "0.8.1",
+        "haiku",
+        None,
+        HashMap::new(),
+        PacketEvidence {
+            files: vec![],
+            max_bytes: 65536,
+            max_lines: 1200,
+            ..Default::default()
+        },
+        None,
+        None, // stderr_redacted
+        vec![],
+        None,
+        "native",
+        None,
+        None,
+        None,
+        None, // diff_context,
+        None, // pipeline
+    )
+}
+
+#[test]
+fn test_generation_increments_on_write() {
+    let (manager, _temp_dir) = create_test_manager();
+    assert_eq!(manager.generation(), 0);
+
+    manager
+        .write_receipt(&minimal_receipt(&manager, PhaseId::Requirements))
+        .unwrap();
+    assert_eq!(manager.generation(), 1);
+
+    manager
+        .write_receipt(&minimal_receipt(&manager, PhaseId::Design))
+        .unwrap();
+    assert_eq!(manager.generation(), 2);
+
+    let snapshot = manager.snapshot().unwrap();
+    assert_eq!(snapshot.generation, 2);
+    assert_eq!(snapshot.receipts.len(), 2);
+}
+
+#[test]
+fn test_concurrent_writes_bump_generation_once_each() {
+    let (manager, _temp_dir) = create_test_manager();
+    let manager = std::sync::Arc::new(manager);
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let manager = std::sync::Arc::clone(&manager);
+            std::thread::spawn(move || {
+                let receipt = minimal_receipt(&manager, PhaseId::Requirements);
+                for _ in 0..5 {
+                    manager.write_receipt(&receipt).unwrap();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(manager.generation(), 40);
+}
+
+#[test]
+fn test_read_latest_successful_receipt_skips_failures() {
+    let (manager, _temp_dir) = create_test_manager();
+    assert!(
+        manager
+            .read_latest_successful_receipt(PhaseId::Review)
+            .unwrap()
+            .is_none()
+    );
+
+    let ok = minimal_receipt(&manager, PhaseId::Review);
+    manager.write_receipt(&ok).unwrap();
+
+    let mut failed = minimal_receipt(&manager, PhaseId::Review);
+    failed.emitted_at = ok.emitted_at + chrono::Duration::seconds(1);
+    failed.exit_code = 70;
+    manager.write_receipt(&failed).unwrap();
+
+    let latest = manager
+        .read_latest_successful_receipt(PhaseId::Review)
+        .unwrap()
+        .unwrap();
+    assert_eq!(latest.emitted_at, ok.emitted_at);
+    assert_eq!(latest.exit_code, 0);
+}
+
+#[test]
+fn test_compressed_receipts_are_read_transparently() {
+    let (manager, _temp_dir) = create_test_manager();
+
+    let requirements = manager
+        .write_receipt(&minimal_receipt(&manager, PhaseId::Requirements))
+        .unwrap();
+    assert!(requirements.as_str().ends_with(".json"));
+
+    // A receipt stored compressed (as written above the compression threshold)
+    let mut design = minimal_receipt(&manager, PhaseId::Design);
+    design.emitted_at += chrono::Duration::seconds(1);
+    let json = ReceiptManager::emit_receipt_jcs(&design).unwrap();
+    let name = ReceiptManager::receipt_file_name("design", design.emitted_at);
+    let compressed = xchecker_utils::compression::compress(
+        json.as_bytes(),
+        xchecker_utils::compression::DEFAULT_LEVEL,
+    )
+    .unwrap();
+    std::fs::write(
+        manager.receipts_path().join(format!("{name}.zst")),
+        compressed,
+    )
+    .unwrap();
+
+    let receipts = manager.list_receipts().unwrap();
+    assert_eq!(receipts.len(), 2);
+    assert_eq!(receipts[1].phase, "design");
+
+    let latest = manager
+        .read_latest_receipt(PhaseId::Design)
+        .unwrap()
+        .unwrap();
+    assert_eq!(latest.emitted_at, design.emitted_at);
+}
+
+#[test]
+fn test_failed_receipt_records_suggested_actions() {
+    let (manager, _temp_dir) = create_test_manager();
+
+    let success = minimal_receipt(&manager, PhaseId::Requirements);
+    manager.write_receipt(&success).unwrap();
+
+    let mut failed = minimal_receipt(&manager, PhaseId::Design);
+    failed.exit_code = 70;
+    failed.error_kind = Some(ErrorKind::ClaudeFailure);
+    failed.error_reason = Some("Provider authentication error: 401".to_string());
+    failed.llm = Some(xchecker_utils::types::LlmInfo {
+        provider: Some("claude-cli".to_string()),
+        model_used: None,
+        tokens_input: None,
+        tokens_output: None,
+        timed_out: None,
+        timeout_seconds: None,
+        budget_exhausted: None,
+        requests: None,
+        provider_usage: None,
+        process_usage: None,
+    });
+    manager.write_receipt(&failed).unwrap();
+
+    let receipts = manager.list_receipts().unwrap();
+    assert!(receipts[0].suggested_actions.is_empty());
+    assert_eq!(receipts[1].suggested_actions, failed.remediation());
+    assert!(receipts[1].suggested_actions[0].contains("claude auth login"));
+}
+
+#[test]
+fn test_migrate_legacy_receipts() {
+    let (manager, _temp_dir) = create_test_manager();
+    manager
+        .write_receipt(&minimal_receipt(&manager, PhaseId::Requirements))
+        .unwrap();
+
+    let legacy = r#"{"timestamp":"2024-03-01T12:00:00Z","phase":"design","exit_code":70,"error_kind":"provider_crash","error_reason":"boom","outputs":[]}"#;
+    let legacy_name = "design-20240301_120000.json";
+    std::fs::write(manager.receipts_path().join(legacy_name), legacy).unwrap();
+    std::fs::write(manager.receipts_path().join("tasks-bad.json"), "[]").unwrap();
+
+    // Legacy receipts are unreadable until migrated
+    assert_eq!(manager.list_receipts().unwrap().len(), 1);
+    assert_eq!(manager.legacy_fields_in_use(), ["timestamp"]);
+
+    let dry = manager.migrate_receipts("test-spec", true).unwrap();
+    assert_eq!(dry.migrated, vec![legacy_name.to_string()]);
+    assert_eq!(dry.current, 1);
+    assert_eq!(dry.failed.len(), 1);
+    assert!(dry.backup_path.is_none());
+    assert_eq!(manager.list_receipts().unwrap().len(), 1);
+
+    let generation = manager.generation();
+    let report = manager.migrate_receipts("test-spec", false).unwrap();
+    assert_eq!(report.migrated, vec![legacy_name.to_string()]);
+    assert_eq!(manager.generation(), generation + 1);
+
+    let backup = report.backup_path.unwrap();
+    assert_eq!(
+        std::fs::read_to_string(backup.join(legacy_name)).unwrap(),
+        legacy
+    );
+
+    let receipts = manager.list_receipts().unwrap();
+    assert_eq!(receipts.len(), 2);
+    let migrated = &receipts[0];
+    assert_eq!(migrated.schema_version, "1");
+    assert_eq!(migrated.spec_id, "test-spec");
+    assert_eq!(migrated.phase, "design");
+    assert_eq!(migrated.exit_code, 70);
+    assert_eq!(migrated.error_kind, Some(ErrorKind::Unknown));
+    assert_eq!(
+        migrated.emitted_at.to_rfc3339(),
+        "2024-03-01T12:00:00+00:00"
+    );
+
+    // A second run finds nothing left to do
+    let again = manager.migrate_receipts("test-spec", false).unwrap();
+    assert!(again.migrated.is_empty());
+    assert_eq!(again.current, 2);
+    assert!(manager.legacy_fields_in_use().is_empty());
+}
+
+#[test]
+fn test_migrate_compressed_legacy_receipt() {
+    let (manager, _temp_dir) = create_test_manager();
+    let legacy =
+        r#"{"timestamp":"2024-03-01T12:00:00Z","phase":"design","exit_code":0,"outputs":[]}"#;
+    let legacy_name = "design-20240301_120000.json.zst";
+    std::fs::create_dir_all(manager.receipts_path()).unwrap();
+    std::fs::write(
+        manager.receipts_path().join(legacy_name),
+        xchecker_utils::compression::compress(legacy.as_bytes(), 3).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(manager.legacy_fields_in_use(), ["timestamp"]);
+
+    let report = manager.migrate_receipts("test-spec", false).unwrap();
+    assert_eq!(report.migrated, vec![legacy_name.to_string()]);
+
+    // Rewritten in place, still compressed
+    let path = manager.receipts_path().join(legacy_name);
+    assert!(
+        std::fs::read(&path)
+            .unwrap()
+            .starts_with(&[0x28, 0xb5, 0x2f, 0xfd])
+    );
+    let receipts = manager.list_receipts().unwrap();
+    assert_eq!(receipts.len(), 1);
+    assert_eq!(receipts[0].phase, "design");
+    assert!(manager.legacy_fields_in_use().is_empty());
+}
+
+#[test]
+fn test_list_receipts_skips_partial_receipt() {
+    let (manager, _temp_dir) = create_test_manager();
+    manager
+        .write_receipt(&minimal_receipt(&manager, PhaseId::Requirements))
+        .unwrap();
+
+    // Simulate a receipt caught mid-write by a non-atomic writer
+    std::fs::write(
+        manager.receipts_path.join("design-20240101_000000.json"),
+        "{\"schema_version\": \"1\", \"spec_",
+    )
+    .unwrap();
+
+    let receipts = manager.list_receipts().unwrap();
+    assert_eq!(receipts.len(), 1);
+    assert_eq!(receipts[0].phase, "requirements");
+}
+
 #[test]
 fn test_receipt_json_serialization() {
     let (manager, _temp_dir) = create_test_manager();
@@ -219,10 +494,12 @@ fn test_receipt_json_serialization() {
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized: "abc123def456".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/00-requirements.core.yaml".to_string(),
             blake3_canonicalized: "789xyz012".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
@@ -230,6 +507,7 @@ fn test_receipt_json_serialization() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let mut flags = std::collections::HashMap::new();
@@ -306,12 +584,14 @@ fn test_new_fields_serialization() {
     let outputs = vec![FileHash {
         path: "artifacts/00-requirements.md".to_string(),
         blake3_canonicalized: "abc123".to_string(),
+        blake3_front_matter: None,
     }];
 
     let packet = PacketEvidence {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let mut flags = std::collections::HashMap::new();
@@ -394,6 +674,7 @@ fn test_diff_context_in_receipt() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     // Test with diff_context set to 0 (unidiff-zero enabled)
@@ -493,6 +774,7 @@ requirements:
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -557,6 +839,7 @@ fn test_error_receipt_creation() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let error_receipt = manager.create_error_receipt(
@@ -603,6 +886,7 @@ fn test_error_receipt_with_different_error_kinds() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     // Test SecretDetected error
@@ -670,12 +954,14 @@ fn test_optional_fields_in_receipt() {
     let outputs = vec![FileHash {
         path: "artifacts/00-requirements.md".to_string(),
         blake3_canonicalized: "abc123".to_string(),
+        blake3_front_matter: None,
     }];
 
     let packet = PacketEvidence {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     // Test with all optional fields set
@@ -753,12 +1039,14 @@ fn test_atomic_write_creates_file() {
     let outputs = vec![FileHash {
         path: "artifacts/00-requirements.md".to_string(),
         blake3_canonicalized: "abc123".to_string(),
+        blake3_front_matter: None,
     }];
 
     let packet = PacketEvidence {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -807,10 +1095,12 @@ fn test_jcs_emission_byte_identical() {
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized: "abc123".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized: "def456".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
@@ -818,6 +1108,7 @@ fn test_jcs_emission_byte_identical() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let mut flags = std::collections::HashMap::new();
@@ -877,6 +1168,7 @@ fn test_receipt_listing_chronological_order() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     // Create multiple receipts for different phases with different timestamps
@@ -886,6 +1178,7 @@ fn test_receipt_listing_chronological_order() {
         let outputs = vec![FileHash {
             path: format!("artifacts/0{i}-test.md"),
             blake3_canonicalized: format!("hash{i}"),
+            blake3_front_matter: None,
         }];
 
         let receipt = manager.create_receipt(
@@ -937,6 +1230,7 @@ fn test_read_latest_receipt_returns_most_recent() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     // Create multiple receipts for the same phase
@@ -944,6 +1238,7 @@ fn test_read_latest_receipt_returns_most_recent() {
         let outputs = vec![FileHash {
             path: format!("artifacts/version-{i}.md"),
             blake3_canonicalized: format!("hash{i}"),
+            blake3_front_matter: None,
         }];
 
         let receipt = manager.create_receipt(
@@ -993,6 +1288,7 @@ fn test_receipt_edge_case_empty_outputs() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     // Create receipt with no outputs (error case)
@@ -1040,6 +1336,7 @@ fn test_receipt_edge_case_large_warnings_list() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     // Create a large warnings list
@@ -1088,12 +1385,14 @@ fn test_receipt_edge_case_special_characters_in_fields() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     // Create receipt with special characters
     let outputs = vec![FileHash {
         path: "artifacts/test-with-unicode-🚀.md".to_string(),
         blake3_canonicalized: "abc123".to_string(),
+        blake3_front_matter: None,
     }];
 
     let mut flags = std::collections::HashMap::new();
@@ -1151,6 +1450,7 @@ fn test_receipt_with_missing_optional_fields() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -1201,6 +1501,7 @@ fn test_receipt_with_empty_strings() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -1243,6 +1544,7 @@ fn test_receipt_with_very_long_strings() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -1280,6 +1582,7 @@ fn test_receipt_with_unicode_content() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -1319,6 +1622,7 @@ fn test_receipt_with_special_characters() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -1358,6 +1662,7 @@ fn test_receipt_with_maximum_outputs() {
         outputs.push(FileHash {
             path: format!("artifacts/file-{i}.md"),
             blake3_canonicalized: format!("hash{i:064}"),
+            blake3_front_matter: None,
         });
     }
 
@@ -1365,6 +1670,7 @@ fn test_receipt_with_maximum_outputs() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -1416,6 +1722,7 @@ fn test_receipt_with_all_error_kinds() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         };
 
         let error_kind_clone = error_kind.clone();
@@ -1455,6 +1762,7 @@ fn test_receipt_with_negative_exit_code() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -1491,6 +1799,7 @@ fn test_receipt_with_large_exit_code() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -1527,6 +1836,7 @@ fn test_receipt_serialization_with_null_values() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -1668,3 +1978,45 @@ fn test_error_kind_mapping() {
         assert_eq!(kind, expected_kind);
     }
 }
+
+#[test]
+fn test_token_usage_aggregates_per_phase() {
+    use xchecker_utils::types::LlmInfo;
+
+    let (manager, _temp_dir) = create_test_manager();
+    let llm = |requests, tokens_input, tokens_output| LlmInfo {
+        provider: Some("anthropic".to_string()),
+        model_used: None,
+        tokens_input,
+        tokens_output,
+        timed_out: None,
+        timeout_seconds: None,
+        budget_exhausted: None,
+        requests,
+        provider_usage: None,
+        process_usage: None,
+    };
+
+    // No provider requests yet
+    let skipped = minimal_receipt(&manager, PhaseId::Review);
+    assert_eq!(usage::token_usage(std::slice::from_ref(&skipped)), None);
+
+    let mut design = minimal_receipt(&manager, PhaseId::Design);
+    design.llm = Some(llm(Some(2), Some(900), Some(300)));
+    let mut requirements = minimal_receipt(&manager, PhaseId::Requirements);
+    requirements.llm = Some(llm(None, Some(1000), Some(400)));
+    let mut rerun = minimal_receipt(&manager, PhaseId::Requirements);
+    rerun.llm = Some(llm(None, None, None));
+    let mut exhausted = minimal_receipt(&manager, PhaseId::Tasks);
+    exhausted.llm = Some(LlmInfo::for_budget_exhaustion());
+
+    let usage = usage::token_usage(&[design, requirements, rerun, exhausted, skipped]).unwrap();
+    let phases: Vec<_> = usage.phases.iter().map(|p| p.phase.as_str()).collect();
+    assert_eq!(phases, vec!["requirements", "design"]);
+    assert_eq!(usage.phases[0].counts.requests, 2);
+    assert_eq!(usage.phases[0].counts.tokens_input, 1000);
+    assert_eq!(usage.phases[0].counts.requests_without_usage, 1);
+    assert_eq!(usage.total.requests, 4);
+    assert_eq!(usage.total.tokens_input, 1900);
+    assert_eq!(usage.total.tokens_output, 700);
+}
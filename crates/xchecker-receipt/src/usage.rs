@@ -0,0 +1,77 @@
+//! Provider consumption aggregated from receipts
+//!
+//! Every receipt with LLM metadata contributes its request count and reported
+//! tokens to its phase. Receipts from before request counting was recorded
+//! count as one request when a provider is named; receipts without a provider
+//! (skipped phases, budget exhaustion, secret-scan blocks) count as none.
+
+use xchecker_utils::types::{PhaseTokenUsage, Receipt, TokenCounts, TokenUsage};
+
+/// Pipeline order used to list phases
+const PHASE_ORDER: [&str; 7] = [
+    "requirements",
+    "design",
+    "tasks",
+    "review",
+    "fixup",
+    "final",
+    "localize",
+];
+
+/// Sum requests and tokens over `receipts`, per phase and in total
+///
+/// Returns `None` when no receipt records a provider request.
+#[must_use]
+pub fn token_usage(receipts: &[Receipt]) -> Option<TokenUsage> {
+    let mut by_phase: Vec<PhaseTokenUsage> = Vec::new();
+    for receipt in receipts {
+        let Some(llm) = &receipt.llm else { continue };
+        let requests = u64::from(
+            llm.requests
+                .unwrap_or_else(|| u32::from(llm.provider.is_some())),
+        );
+        if requests == 0 {
+            continue;
+        }
+
+        let index = match by_phase.iter().position(|p| p.phase == receipt.phase) {
+            Some(index) => index,
+            None => {
+                by_phase.push(PhaseTokenUsage {
+                    phase: receipt.phase.clone(),
+                    counts: TokenCounts::default(),
+                });
+                by_phase.len() - 1
+            }
+        };
+        let counts = &mut by_phase[index].counts;
+        counts.requests += requests;
+        if llm.tokens_input.is_none() && llm.tokens_output.is_none() {
+            counts.requests_without_usage += requests;
+        }
+        counts.tokens_input += llm.tokens_input.unwrap_or(0);
+        counts.tokens_output += llm.tokens_output.unwrap_or(0);
+    }
+
+    if by_phase.is_empty() {
+        return None;
+    }
+    by_phase.sort_by_key(|p| {
+        PHASE_ORDER
+            .iter()
+            .position(|phase| *phase == p.phase)
+            .unwrap_or(PHASE_ORDER.len())
+    });
+
+    let mut total = TokenCounts::default();
+    for phase in &by_phase {
+        total.requests += phase.counts.requests;
+        total.tokens_input += phase.counts.tokens_input;
+        total.tokens_output += phase.counts.tokens_output;
+        total.requests_without_usage += phase.counts.requests_without_usage;
+    }
+    Some(TokenUsage {
+        phases: by_phase,
+        total,
+    })
+}
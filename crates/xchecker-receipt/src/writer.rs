@@ -1,15 +1,52 @@
 use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
+use chrono::{DateTime, Utc};
+use std::borrow::Cow;
 use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
 
-use xchecker_utils::atomic_write::write_file_atomic;
+use xchecker_utils::atomic_write::{write_bytes_atomic, write_file_atomic};
+use xchecker_utils::compression;
 use xchecker_utils::error::XCheckerError;
 use xchecker_utils::types::{PhaseId, Receipt};
 
 use super::ReceiptManager;
 
+/// Name of the generation marker file inside the receipts directory
+const GENERATION_FILE: &str = ".generation";
+
+/// Lock file serializing generation bumps across processes
+const GENERATION_LOCK_FILE: &str = ".generation.lock";
+
+/// Delay before re-reading a receipt that failed to parse
+const READ_RETRY_DELAY: Duration = Duration::from_millis(25);
+
+/// Number of times a snapshot is retaken when a writer races the reader
+const SNAPSHOT_ATTEMPTS: u32 = 3;
+
+/// A consistent view of a spec's receipts
+#[derive(Debug, Clone)]
+pub struct ReceiptSnapshot {
+    /// Generation marker value the receipts were read under
+    pub generation: u64,
+    /// Receipts in chronological order
+    pub receipts: Vec<Receipt>,
+}
+
 impl ReceiptManager {
+    /// File name of the receipt for `phase` emitted at `emitted_at`
+    #[must_use]
+    pub fn receipt_file_name(phase: &str, emitted_at: DateTime<Utc>) -> String {
+        format!("{}-{}.json", phase, emitted_at.format("%Y%m%d_%H%M%S"))
+    }
+
     /// Write a receipt to disk using atomic operations with JCS canonical JSON
+    ///
+    /// Receipts over the compression threshold (`[defaults]
+    /// compress_threshold_bytes`) are written as `<name>.json.zst`; the
+    /// returned path is the file actually written.
     pub fn write_receipt(&self, receipt: &Receipt) -> Result<Utf8PathBuf> {
         // Ensure receipts directory exists (ignore benign races)
         xchecker_utils::paths::ensure_dir_all(&self.receipts_path).with_context(|| {
@@ -20,24 +57,115 @@ impl ReceiptManager {
         })?;
 
         // Generate receipt filename with emitted_at timestamp
-        let timestamp_str = receipt.emitted_at.format("%Y%m%d_%H%M%S").to_string();
-        let filename = format!("{}-{}.json", receipt.phase, timestamp_str);
+        let filename = Self::receipt_file_name(&receipt.phase, receipt.emitted_at);
         let receipt_path = self.receipts_path.join(&filename);
 
+        // Failed receipts carry the same remediation the CLI prints
+        let mut receipt = Cow::Borrowed(receipt);
+        if receipt.suggested_actions.is_empty() && receipt.error_kind.is_some() {
+            receipt.to_mut().suggested_actions = receipt.remediation();
+        }
+
         // Serialize receipt to canonical JSON using JCS (RFC 8785)
-        let json_content = Self::emit_receipt_jcs(receipt)?;
+        let json_content = Self::emit_receipt_jcs(&receipt)?;
 
         // Write using atomic operation (tempfile → fsync → rename)
-        write_file_atomic(&receipt_path, &json_content).map_err(|e| {
-            XCheckerError::ReceiptWriteFailed {
-                path: receipt_path.to_string(),
-                reason: e.to_string(),
+        let compressed =
+            compression::compress_for_storage(json_content.as_bytes()).map_err(|e| {
+                XCheckerError::ReceiptWriteFailed {
+                    path: receipt_path.to_string(),
+                    reason: format!("compression failed: {e}"),
+                }
+            })?;
+        let receipt_path = match compressed {
+            Some(bytes) => {
+                let zst_path = compression::compressed_path(&receipt_path);
+                write_bytes_atomic(&zst_path, &bytes).map_err(|e| {
+                    XCheckerError::ReceiptWriteFailed {
+                        path: zst_path.to_string(),
+                        reason: e.to_string(),
+                    }
+                })?;
+                zst_path
             }
-        })?;
+            None => {
+                write_file_atomic(&receipt_path, &json_content).map_err(|e| {
+                    XCheckerError::ReceiptWriteFailed {
+                        path: receipt_path.to_string(),
+                        reason: e.to_string(),
+                    }
+                })?;
+                receipt_path
+            }
+        };
+
+        // Bump the generation marker only after the receipt is fully in place,
+        // so readers that observe the new generation also observe the receipt
+        self.bump_generation()?;
 
         Ok(receipt_path)
     }
 
+    /// Increment the generation marker after receipts change on disk
+    ///
+    /// The read-increment-write runs under an exclusive lock on
+    /// `.generation.lock`, so concurrent writers never publish the same
+    /// generation. The marker itself is still replaced atomically, so readers
+    /// need no lock.
+    pub(super) fn bump_generation(&self) -> Result<()> {
+        let generation_path = self.receipts_path.join(GENERATION_FILE);
+        let lock_path = self.receipts_path.join(GENERATION_LOCK_FILE);
+        let write_failed =
+            |path: &Utf8PathBuf, e: &dyn std::fmt::Display| XCheckerError::ReceiptWriteFailed {
+                path: path.to_string(),
+                reason: e.to_string(),
+            };
+
+        let lock_file = fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_path)
+            .map_err(|e| write_failed(&lock_path, &e))?;
+        lock_file.lock().map_err(|e| write_failed(&lock_path, &e))?;
+
+        let result = write_file_atomic(&generation_path, &format!("{}\n", self.generation() + 1))
+            .map_err(|e| write_failed(&generation_path, &e));
+        // Dropping the handle would release the lock too; unlock explicitly so
+        // the release is visible before returning
+        let _ = lock_file.unlock();
+        result?;
+        Ok(())
+    }
+
+    /// Current receipt generation (incremented on every receipt write, 0 if none)
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        fs::read_to_string(self.receipts_path.join(GENERATION_FILE))
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .unwrap_or(0)
+    }
+
+    /// Take a consistent snapshot of all receipts
+    ///
+    /// Retakes the listing if a receipt was written while reading, so callers
+    /// never combine receipts from different generations.
+    pub fn snapshot(&self) -> Result<ReceiptSnapshot> {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let generation = self.generation();
+            let receipts = self.list_receipts()?;
+            if attempt >= SNAPSHOT_ATTEMPTS || self.generation() == generation {
+                return Ok(ReceiptSnapshot {
+                    generation,
+                    receipts,
+                });
+            }
+        }
+    }
+
     /// Read the most recent receipt for a given phase
     pub fn read_latest_receipt(&self, phase: PhaseId) -> Result<Option<Receipt>> {
         let phase_str = phase.as_str();
@@ -53,7 +181,7 @@ impl ReceiptManager {
             let entry = entry?;
             if let Some(filename) = entry.file_name().to_str()
                 && filename.starts_with(&format!("{phase_str}-"))
-                && filename.ends_with(".json")
+                && is_receipt_file(filename)
             {
                 phase_receipts.push(entry.path());
             }
@@ -63,20 +191,30 @@ impl ReceiptManager {
             return Ok(None);
         }
 
-        // Sort by filename (which includes timestamp) to get the latest
+        // Sort by filename (which includes timestamp) to get the latest;
+        // a trailing `.zst` sorts after the same timestamp, so order holds
         phase_receipts.sort();
         let latest_path = phase_receipts.last().unwrap();
 
         // Read and deserialize the latest receipt
-        let content = fs::read_to_string(latest_path)
+        let receipt = read_receipt_with_retry(latest_path)
             .with_context(|| format!("Failed to read receipt: {latest_path:?}"))?;
 
-        let receipt: Receipt = serde_json::from_str(&content)
-            .with_context(|| format!("Failed to deserialize receipt: {latest_path:?}"))?;
-
         Ok(Some(receipt))
     }
 
+    /// Read the most recent receipt for `phase` that succeeded
+    ///
+    /// Failed and skipped receipts are passed over, so this is the last run
+    /// whose packet and outputs were actually accepted.
+    pub fn read_latest_successful_receipt(&self, phase: PhaseId) -> Result<Option<Receipt>> {
+        Ok(self
+            .list_receipts()?
+            .into_iter()
+            .rev()
+            .find(|r| r.phase == phase.as_str() && r.exit_code == 0 && !r.is_skipped()))
+    }
+
     /// List all receipts in chronological order
     pub fn list_receipts(&self) -> Result<Vec<Receipt>> {
         if !self.receipts_path.exists() {
@@ -88,30 +226,52 @@ impl ReceiptManager {
         for entry in fs::read_dir(&self.receipts_path)? {
             let entry = entry?;
             if let Some(filename) = entry.file_name().to_str()
-                && filename.ends_with(".json")
+                && is_receipt_file(filename)
             {
-                let content = fs::read_to_string(entry.path())?;
-                let receipt: Receipt = serde_json::from_str(&content)?;
-                receipts.push(receipt);
+                // Skip receipts that are still unreadable after a retry rather than
+                // failing the whole listing (e.g. a file being replaced mid-read)
+                match read_receipt_with_retry(&entry.path()) {
+                    Ok(receipt) => receipts.push(receipt),
+                    Err(e) => tracing::warn!(
+                        "Skipping unreadable receipt {} (receipts from pre-1.0 builds can be \
+                         upgraded with `xchecker migrate-receipts`): {e:#}",
+                        entry.path().display()
+                    ),
+                }
             }
         }
 
         // Sort by emitted_at timestamp
-        receipts.sort_by(|a, b| a.emitted_at.cmp(&b.emitted_at));
+        receipts.sort_by_key(|a| a.emitted_at);
 
         Ok(receipts)
     }
 
-    /// Test seam; not part of public API stability guarantees.
-    ///
-    /// Get the path to the receipts directory.
-    #[cfg_attr(not(test), allow(dead_code))]
+    /// Path to the receipts directory (also holds the run event logs)
     #[must_use]
     pub const fn receipts_path(&self) -> &Utf8PathBuf {
         &self.receipts_path
     }
 }
 
+/// Whether `filename` is a receipt, plain (`.json`) or compressed (`.json.zst`)
+fn is_receipt_file(filename: &str) -> bool {
+    compression::logical_name(filename).ends_with(".json")
+}
+
+/// Read and parse a receipt, retrying once after a short delay on failure
+fn read_receipt_with_retry(path: &Path) -> Result<Receipt> {
+    let read = || -> Result<Receipt> {
+        let content = compression::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    };
+
+    read().or_else(|_| {
+        thread::sleep(READ_RETRY_DELAY);
+        read()
+    })
+}
+
 /// Helper function to append `rename_retry_count` to receipt warnings
 ///
 /// This function is used on Windows when atomic rename operations require
@@ -126,12 +286,12 @@ impl ReceiptManager {
 ///
 /// ```
 /// let mut warnings = vec![];
-/// xchecker_engine::receipt::add_rename_retry_warning(&mut warnings, Some(3));
+/// xchecker_receipt::add_rename_retry_warning(&mut warnings, Some(3));
 /// assert_eq!(warnings.len(), 1);
 /// assert_eq!(warnings[0], "rename_retry_count: 3");
 ///
 /// let mut warnings2 = vec![];
-/// xchecker_engine::receipt::add_rename_retry_warning(&mut warnings2, None);
+/// xchecker_receipt::add_rename_retry_warning(&mut warnings2, None);
 /// assert_eq!(warnings2.len(), 0);
 /// ```
 #[allow(dead_code)] // Receipt utility for tracking atomic write retries
@@ -8,6 +8,10 @@ use regex::{Regex, RegexSet};
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
+pub mod paths;
+
+pub use paths::{SecretPathAction, SecretPathRules};
+
 // =========================================================================
 // Canonical Pattern Definitions
 // =========================================================================
@@ -462,7 +466,7 @@ impl SecretRedactor {
             .collect();
 
         // Sort by ID for deterministic behavior
-        all_patterns.sort_by(|(id1, _), (id2, _)| id1.cmp(id2));
+        all_patterns.sort_by_key(|(id, _)| *id);
 
         for (id, regex) in all_patterns {
             if self.is_pattern_ignored(id) {
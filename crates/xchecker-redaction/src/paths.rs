@@ -0,0 +1,151 @@
+//! Path-based rules for secret-bearing files (`[redaction.paths]`)
+//!
+//! Content patterns catch tokens embedded in source, but structured secret
+//! files (credential YAML, service account JSON, keystores) are secrets from
+//! the first byte to the last and often contain nothing a regex recognizes.
+//! [`SecretPathRules`] decides from a file's path alone whether it is left
+//! out of a packet or included as a placeholder, before its content is read.
+//!
+//! Well-known secret files (`.env*`, `*.pem`, SSH keys, keystores) are
+//! already excluded during file selection by the mandatory
+//! `ALWAYS_EXCLUDE_PATTERNS`; these rules add project-specific paths.
+//!
+//! Patterns are globs. A pattern without a `/` matches the file name at any
+//! depth (`*.tfstate`); one with a `/` matches the end of the path
+//! (`config/credentials/*.yml`). `*` does not cross directory separators.
+
+use anyhow::{Context, Result};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+
+/// Placeholder that replaces the content of a masked file
+pub const MASKED_FILE_PLACEHOLDER: &str = "[REDACTED:secret_file]";
+
+/// What happens to a file matched by a path rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretPathAction {
+    /// Leave the file out of the packet
+    Exclude,
+    /// Include the file's path with [`MASKED_FILE_PLACEHOLDER`] as its content
+    Mask,
+}
+
+/// Path rules deciding which files are secrets regardless of content
+///
+/// A file matching both lists is masked.
+#[derive(Debug, Clone)]
+pub struct SecretPathRules {
+    exclude: GlobSet,
+    mask: GlobSet,
+}
+
+impl SecretPathRules {
+    /// Rules from `[redaction.paths] exclude` and `mask`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first pattern that is not a valid glob.
+    pub fn new(exclude: &[String], mask: &[String]) -> Result<Self> {
+        Ok(Self {
+            exclude: glob_set(exclude)?,
+            mask: glob_set(mask)?,
+        })
+    }
+
+    /// Rules that match nothing
+    #[must_use]
+    pub fn none() -> Self {
+        Self {
+            exclude: GlobSet::empty(),
+            mask: GlobSet::empty(),
+        }
+    }
+
+    /// Whether no rule is configured
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.exclude.is_empty() && self.mask.is_empty()
+    }
+
+    /// Action for the file at `path`, or `None` if no rule matches
+    #[must_use]
+    pub fn action(&self, path: &str) -> Option<SecretPathAction> {
+        let path = path.replace('\\', "/");
+        if self.mask.is_match(&path) {
+            Some(SecretPathAction::Mask)
+        } else if self.exclude.is_match(&path) {
+            Some(SecretPathAction::Exclude)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for SecretPathRules {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+fn glob_set(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let anchored = if pattern.starts_with("**/") || pattern.starts_with('/') {
+            pattern.clone()
+        } else {
+            format!("**/{pattern}")
+        };
+        let glob = GlobBuilder::new(&anchored)
+            .literal_separator(true)
+            .build()
+            .with_context(|| format!("Invalid secret path pattern '{pattern}'"))?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .context("Failed to compile secret path patterns")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterns(patterns: &[&str]) -> Vec<String> {
+        patterns.iter().map(|p| (*p).to_string()).collect()
+    }
+
+    #[test]
+    fn test_rules_match_file_names_at_any_depth_and_path_suffixes() {
+        let rules = SecretPathRules::new(
+            &patterns(&["*.tfstate", "config/credentials/*.yml"]),
+            &patterns(&["service-account.json", "config/credentials/README.yml"]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            rules.action("/repo/infra/prod.tfstate"),
+            Some(SecretPathAction::Exclude)
+        );
+        assert_eq!(
+            rules.action("app\\config\\credentials\\prod.yml"),
+            Some(SecretPathAction::Exclude)
+        );
+        assert_eq!(rules.action("config/credentials/nested/prod.yml"), None);
+        assert_eq!(
+            rules.action("deploy/service-account.json"),
+            Some(SecretPathAction::Mask)
+        );
+        // Mask wins over exclude
+        assert_eq!(
+            rules.action("config/credentials/README.yml"),
+            Some(SecretPathAction::Mask)
+        );
+        assert_eq!(rules.action("src/main.rs"), None);
+    }
+
+    #[test]
+    fn test_empty_and_invalid_rules() {
+        assert!(SecretPathRules::none().is_empty());
+        assert_eq!(SecretPathRules::default().action(".env"), None);
+        assert!(SecretPathRules::new(&patterns(&["["]), &[]).is_err());
+    }
+}
@@ -1,6 +1,8 @@
 use crate::command_spec::CommandSpec;
 use crate::error::RunnerError;
+use crate::shell::Shell;
 use crate::types::RunnerMode;
+use crate::wsl::wsl_exec_command;
 use std::process::Stdio;
 
 use super::exec::Runner;
@@ -40,14 +42,15 @@ impl Runner {
     /// Test if native Claude CLI is available
     pub fn test_native_claude() -> Result<(), RunnerError> {
         // Use CommandSpec for consistent argv-style execution
-        let output = CommandSpec::new("claude")
-            .arg("--version")
+        let spec = CommandSpec::new("claude").arg("--version");
+        let command_line = Shell::detect().render(&spec);
+        let output = spec
             .to_command()
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .output()
             .map_err(|e| RunnerError::NativeExecutionFailed {
-                reason: format!("Failed to execute 'claude --version': {e}"),
+                reason: format!("Failed to execute `{command_line}`: {e}"),
             })?;
 
         if output.status.success() {
@@ -55,24 +58,31 @@ impl Runner {
         } else {
             Err(RunnerError::NativeExecutionFailed {
                 reason: format!(
-                    "'claude --version' failed with exit code: {}",
+                    "`{command_line}` failed with exit code: {}",
                     output.status.code().unwrap_or(-1)
                 ),
             })
         }
     }
 
-    /// Test if WSL Claude CLI is available
+    /// Test if WSL Claude CLI is available in the default distro
     pub fn test_wsl_claude() -> Result<(), RunnerError> {
-        // Use CommandSpec for consistent argv-style execution
-        let output = CommandSpec::new("wsl")
-            .args(["-e", "claude", "--version"])
+        Self::test_wsl_command(&wsl_exec_command(
+            None,
+            &CommandSpec::new("claude").arg("--version"),
+        ))
+    }
+
+    /// Run a `wsl ... --version` probe, naming the exact command on failure
+    fn test_wsl_command(spec: &CommandSpec) -> Result<(), RunnerError> {
+        let command_line = Shell::detect().render(spec);
+        let output = spec
             .to_command()
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .output()
             .map_err(|e| RunnerError::WslNotAvailable {
-                reason: format!("Failed to execute 'wsl -e claude --version': {e}"),
+                reason: format!("Failed to execute `{command_line}`: {e}"),
             })?;
 
         if output.status.success() {
@@ -80,7 +90,7 @@ impl Runner {
         } else {
             Err(RunnerError::WslExecutionFailed {
                 reason: format!(
-                    "'wsl -e claude --version' failed with exit code: {}",
+                    "`{command_line}` failed with exit code: {}",
                     output.status.code().unwrap_or(-1)
                 ),
             })
@@ -96,9 +106,9 @@ impl Runner {
             }
             RunnerMode::Native => self.test_native_claude_with_path(),
             RunnerMode::Wsl => {
-                // Validate WSL is available
+                // Validate WSL is available with the configured distro and claude path
                 if cfg!(target_os = "windows") {
-                    Self::test_wsl_claude()
+                    Self::test_wsl_command(&self.wsl_command_spec(&["--version".to_string()]))
                 } else {
                     Err(RunnerError::ConfigurationInvalid {
                         reason: "WSL runner mode is only supported on Windows".to_string(),
@@ -131,6 +141,8 @@ impl Runner {
                 if let Some(claude_path) = &self.wsl_options.claude_path {
                     desc.push_str(&format!(" (claude path: {claude_path})"));
                 }
+                let command = self.wsl_command_spec(&[]);
+                desc.push_str(&format!(": {}", Shell::detect().render(&command)));
                 desc
             }
         }
@@ -183,6 +195,11 @@ mod tests {
         assert!(runner.description().contains("WSL execution"));
         assert!(runner.description().contains("Ubuntu-22.04"));
         assert!(runner.description().contains("/usr/local/bin/claude"));
+        assert!(
+            runner
+                .description()
+                .contains("wsl -d Ubuntu-22.04 --exec /usr/local/bin/claude")
+        );
     }
 
     #[cfg(not(target_os = "windows"))]
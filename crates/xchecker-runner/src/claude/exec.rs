@@ -1,10 +1,12 @@
 use std::process::Stdio;
-use std::time::Duration;
+use std::sync::atomic::AtomicU64;
+use std::time::{Duration, Instant};
 use tokio::io::AsyncWriteExt;
 use tokio::time::timeout;
 
 use crate::error::RunnerError;
 use crate::ring_buffer::RingBuffer;
+use crate::shell::Shell;
 use crate::types::RunnerMode;
 
 use super::io::{PipeReadError, drain_pipes, read_pipes_until_exit};
@@ -106,6 +108,18 @@ impl Runner {
         args: &[String],
         stdin_content: &str,
         timeout_duration: Option<Duration>,
+    ) -> Result<ClaudeResponse, RunnerError> {
+        self.execute_claude_with_progress(args, stdin_content, timeout_duration, None)
+            .await
+    }
+
+    /// Execute Claude CLI, adding stdout bytes to `progress` as they are read
+    pub async fn execute_claude_with_progress(
+        &self,
+        args: &[String],
+        stdin_content: &str,
+        timeout_duration: Option<Duration>,
+        progress: Option<&AtomicU64>,
     ) -> Result<ClaudeResponse, RunnerError> {
         // Resolve Auto mode to actual mode
         let actual_mode = match self.mode {
@@ -116,11 +130,11 @@ impl Runner {
         // Execute based on resolved mode
         match actual_mode {
             RunnerMode::Native | RunnerMode::Auto => {
-                self.execute_native(args, stdin_content, timeout_duration)
+                self.execute_native(args, stdin_content, timeout_duration, progress)
                     .await
             }
             RunnerMode::Wsl => {
-                self.execute_wsl(args, stdin_content, timeout_duration)
+                self.execute_wsl(args, stdin_content, timeout_duration, progress)
                     .await
             }
         }
@@ -132,9 +146,11 @@ impl Runner {
         args: &[String],
         stdin_content: &str,
         timeout_duration: Option<Duration>,
+        progress: Option<&AtomicU64>,
     ) -> Result<ClaudeResponse, RunnerError> {
+        let spec = self.native_command_spec(args);
         #[allow(unused_mut)]
-        let mut cmd = self.native_command_spec(args).to_tokio_command();
+        let mut cmd = spec.to_tokio_command();
 
         // Set process group on Unix for killpg support
         #[cfg(unix)]
@@ -153,9 +169,10 @@ impl Runner {
         self.execute_with_command(
             cmd,
             RunnerMode::Native,
-            "claude",
+            &Shell::detect().render(&spec),
             stdin_content,
             timeout_duration,
+            progress,
         )
         .await
     }
@@ -166,11 +183,19 @@ impl Runner {
         args: &[String],
         stdin_content: &str,
         timeout_duration: Option<Duration>,
+        progress: Option<&AtomicU64>,
     ) -> Result<ClaudeResponse, RunnerError> {
-        let cmd = self.wsl_command_spec(args).to_tokio_command();
+        let spec = self.wsl_command_spec(args);
 
         let mut response = self
-            .execute_with_command(cmd, RunnerMode::Wsl, "wsl", stdin_content, timeout_duration)
+            .execute_with_command(
+                spec.to_tokio_command(),
+                RunnerMode::Wsl,
+                &Shell::detect().render(&spec),
+                stdin_content,
+                timeout_duration,
+                progress,
+            )
             .await?;
         response.runner_distro = self.get_wsl_distro_name();
         Ok(response)
@@ -180,20 +205,27 @@ impl Runner {
         &self,
         mut cmd: tokio::process::Command,
         runner_used: RunnerMode,
-        label: &str,
+        command_line: &str,
         stdin_content: &str,
         timeout_duration: Option<Duration>,
+        progress: Option<&AtomicU64>,
     ) -> Result<ClaudeResponse, RunnerError> {
         cmd.stdin(Stdio::piped())
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
+            .stderr(Stdio::piped())
+            // A cancelled phase (kill switch) must not leave the CLI running
+            .kill_on_drop(true);
 
         // Create Job Object on Windows for process tree termination
         #[cfg(windows)]
         let job = platform::create_job_object()?;
 
+        let started = Instant::now();
         let mut child = cmd.spawn().map_err(|e| {
-            execution_failed(runner_used, format!("Failed to spawn {label} process: {e}"))
+            execution_failed(
+                runner_used,
+                format!("Failed to spawn `{command_line}`: {e}"),
+            )
         })?;
 
         // Assign to Job Object on Windows
@@ -208,7 +240,7 @@ impl Runner {
                 .map_err(|e| {
                     execution_failed(
                         runner_used,
-                        format!("Failed to write to {label} stdin: {e}"),
+                        format!("Failed to write to `{command_line}` stdin: {e}"),
                     )
                 })?;
             drop(stdin); // Close stdin
@@ -228,7 +260,7 @@ impl Runner {
         let mut stdout_buffer = RingBuffer::new(self.buffer_config.stdout_cap_bytes);
         let mut stderr_buffer = RingBuffer::new(self.buffer_config.stderr_cap_bytes);
 
-        let status = if let Some(duration) = timeout_duration {
+        let (status, process_usage) = if let Some(duration) = timeout_duration {
             // Store child ID before consuming it
             let child_id = child.id();
 
@@ -238,6 +270,8 @@ impl Runner {
                 &mut stderr_pipe,
                 &mut stdout_buffer,
                 &mut stderr_buffer,
+                started,
+                progress,
             );
 
             match timeout(duration, read_future).await {
@@ -270,11 +304,21 @@ impl Runner {
                 &mut stderr_pipe,
                 &mut stdout_buffer,
                 &mut stderr_buffer,
+                started,
+                progress,
             )
             .await
             .map_err(|err| map_pipe_error(runner_used, err))?
         };
 
+        // The job also covers any processes the CLI started
+        #[cfg(windows)]
+        let process_usage = {
+            let mut process_usage = process_usage;
+            platform::add_job_usage(&job, &mut process_usage);
+            process_usage
+        };
+
         let stdout = stdout_buffer.to_string();
         let stderr = stderr_buffer.to_string();
         let ndjson_result = Self::parse_ndjson(&stdout);
@@ -291,6 +335,7 @@ impl Runner {
             stderr_truncated: stderr_buffer.was_truncated(),
             stdout_total_bytes: stdout_buffer.total_bytes_written(),
             stderr_total_bytes: stderr_buffer.total_bytes_written(),
+            process_usage: Some(process_usage),
         })
     }
 }
@@ -359,4 +404,29 @@ mod tests {
         assert_eq!(runner.buffer_config.stderr_cap_bytes, 512);
         assert_eq!(runner.buffer_config.stderr_receipt_cap_bytes, 256);
     }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_reports_stdout_progress() {
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        // `cat` echoes the prompt, standing in for a provider CLI
+        let runner = Runner::new(
+            RunnerMode::Native,
+            WslOptions {
+                distro: None,
+                claude_path: Some("cat".to_string()),
+            },
+        );
+        let progress = AtomicU64::new(0);
+        let prompt = "x".repeat(20_000);
+
+        let response = runner
+            .execute_claude_with_progress(&[], &prompt, None, Some(&progress))
+            .await
+            .unwrap();
+
+        assert_eq!(response.exit_code, 0);
+        assert_eq!(progress.load(Ordering::Relaxed), prompt.len() as u64);
+    }
 }
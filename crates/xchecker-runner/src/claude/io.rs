@@ -1,11 +1,13 @@
 use std::io;
 use std::process::ExitStatus;
-use std::time::Duration;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 use tokio::io::AsyncReadExt;
 use tokio::process::{Child, ChildStderr, ChildStdout};
 use tokio::time::timeout;
 
 use crate::ring_buffer::RingBuffer;
+use crate::usage::{ProcessUsage, wait_with_usage};
 
 #[derive(Debug)]
 pub(crate) enum PipeReadError {
@@ -20,7 +22,9 @@ pub(crate) async fn read_pipes_until_exit(
     stderr_pipe: &mut ChildStderr,
     stdout_buffer: &mut RingBuffer,
     stderr_buffer: &mut RingBuffer,
-) -> Result<ExitStatus, PipeReadError> {
+    started: Instant,
+    progress: Option<&AtomicU64>,
+) -> Result<(ExitStatus, ProcessUsage), PipeReadError> {
     let mut stdout_buf = vec![0u8; 8192];
     let mut stderr_buf = vec![0u8; 8192];
 
@@ -29,7 +33,12 @@ pub(crate) async fn read_pipes_until_exit(
             stdout_result = stdout_pipe.read(&mut stdout_buf) => {
                 match stdout_result {
                     Ok(0) => break, // EOF
-                    Ok(n) => stdout_buffer.write(&stdout_buf[..n]),
+                    Ok(n) => {
+                        stdout_buffer.write(&stdout_buf[..n]);
+                        if let Some(progress) = progress {
+                            progress.fetch_add(n as u64, Ordering::Relaxed);
+                        }
+                    }
                     Err(err) => return Err(PipeReadError::Stdout(err)),
                 }
             }
@@ -43,8 +52,9 @@ pub(crate) async fn read_pipes_until_exit(
         }
     }
 
-    let status = child.wait().await.map_err(PipeReadError::Wait)?;
-    Ok(status)
+    wait_with_usage(child, started)
+        .await
+        .map_err(PipeReadError::Wait)
 }
 
 pub(crate) async fn drain_pipes(
@@ -10,7 +10,7 @@ mod windows;
 mod windows_job;
 
 #[cfg(windows)]
-pub(crate) use windows_job::{assign_to_job, create_job_object};
+pub(crate) use windows_job::{add_job_usage, assign_to_job, create_job_object};
 
 pub(crate) async fn terminate_process_by_pid(
     pid: u32,
@@ -91,6 +91,55 @@ pub(crate) fn assign_to_job(
     Ok(())
 }
 
+/// Fold the job's accounting into `usage`
+///
+/// CPU times become totals over every process that ran in the job, and peak
+/// memory the peak committed memory of its largest process, so a CLI that
+/// starts through a shim (e.g. `claude.cmd` launching Node) is measured by
+/// what the real work used. Queries that fail leave `usage` unchanged.
+pub(crate) fn add_job_usage(job: &JobObjectHandle, usage: &mut crate::usage::ProcessUsage) {
+    use crate::usage::ticks_to_ms;
+    use winapi::um::jobapi2::QueryInformationJobObject;
+    use winapi::um::winnt::{
+        JOBOBJECT_BASIC_ACCOUNTING_INFORMATION, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+        JobObjectBasicAccountingInformation, JobObjectExtendedLimitInformation,
+    };
+
+    let handle: winapi::um::winnt::HANDLE = job.handle.0.cast();
+
+    // SAFETY: the info structs are plain old data, all-zero is a valid value,
+    // and each query is given a live local of exactly the size passed
+    unsafe {
+        let mut accounting: JOBOBJECT_BASIC_ACCOUNTING_INFORMATION = std::mem::zeroed();
+        if QueryInformationJobObject(
+            handle,
+            JobObjectBasicAccountingInformation,
+            (&raw mut accounting).cast(),
+            std::mem::size_of::<JOBOBJECT_BASIC_ACCOUNTING_INFORMATION>() as u32,
+            std::ptr::null_mut(),
+        ) != 0
+        {
+            let user = u64::try_from(*accounting.TotalUserTime.QuadPart()).unwrap_or(0);
+            let kernel = u64::try_from(*accounting.TotalKernelTime.QuadPart()).unwrap_or(0);
+            usage.user_cpu_ms = Some(ticks_to_ms(user));
+            usage.system_cpu_ms = Some(ticks_to_ms(kernel));
+        }
+
+        let mut limits: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+        if QueryInformationJobObject(
+            handle,
+            JobObjectExtendedLimitInformation,
+            (&raw mut limits).cast(),
+            std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+            std::ptr::null_mut(),
+        ) != 0
+            && limits.PeakProcessMemoryUsed > 0
+        {
+            usage.peak_rss_bytes = Some(limits.PeakProcessMemoryUsed as u64);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::create_job_object;
@@ -1,5 +1,6 @@
 use crate::ndjson::NdjsonResult;
 use crate::types::RunnerMode;
+use crate::usage::ProcessUsage;
 
 /// Configuration options for WSL execution
 #[derive(Debug, Clone, Default)]
@@ -61,6 +62,8 @@ pub struct ClaudeResponse {
     /// Total bytes written to stderr (including truncated)
     #[allow(dead_code)] // Buffer management metadata
     pub stderr_total_bytes: usize,
+    /// Wall time, CPU time, and peak memory of the CLI process
+    pub process_usage: Option<ProcessUsage>,
 }
 
 impl ClaudeResponse {
@@ -133,6 +136,7 @@ mod tests {
             stderr_truncated: false,
             stdout_total_bytes: 0,
             stderr_total_bytes: 20,
+            process_usage: None,
         };
 
         let stderr_receipt = response.stderr_for_receipt(2048);
@@ -156,6 +160,7 @@ mod tests {
             stderr_truncated: false,
             stdout_total_bytes: 0,
             stderr_total_bytes: 3000,
+            process_usage: None,
         };
 
         let stderr_receipt = response.stderr_for_receipt(2048);
@@ -181,6 +186,7 @@ mod tests {
             stderr_truncated: false,
             stdout_total_bytes: 0,
             stderr_total_bytes: 2048,
+            process_usage: None,
         };
 
         let stderr_receipt = response.stderr_for_receipt(2048);
@@ -205,6 +211,7 @@ mod tests {
             stderr_truncated: false,
             stdout_total_bytes: 0,
             stderr_total_bytes: stderr.len(),
+            process_usage: None,
         };
 
         let stderr_receipt = response.stderr_for_receipt(10);
@@ -1,4 +1,5 @@
 use crate::command_spec::CommandSpec;
+use crate::wsl::wsl_exec_command;
 use std::env;
 
 use super::exec::Runner;
@@ -43,15 +44,10 @@ impl Runner {
         // Get the claude path (default to "claude" if not specified)
         let claude_path = self.wsl_options.claude_path.as_deref().unwrap_or("claude");
 
-        // Build WSL command: wsl.exe --exec <claude_path> <args...>
-        // Use CommandSpec to ensure secure argument passing
-        let mut spec = CommandSpec::new("wsl");
-
-        // Add distro specification if provided
-        if let Some(distro) = &self.wsl_options.distro {
-            spec = spec.args(["-d", distro]);
-        }
-
-        spec.arg("--exec").arg(claude_path).args(args)
+        // Build WSL command: wsl.exe [-d <distro>] --exec <claude_path> <args...>
+        wsl_exec_command(
+            self.wsl_options.distro.as_deref(),
+            &CommandSpec::new(claude_path).args(args),
+        )
     }
 }
@@ -17,7 +17,9 @@ pub mod native;
 pub mod ndjson;
 pub mod process;
 pub mod ring_buffer;
+pub mod shell;
 pub mod types;
+pub mod usage;
 pub mod wsl;
 
 // Re-export everything from xchecker-runner submodules
@@ -27,5 +29,7 @@ pub use error::RunnerError;
 pub use native::NativeRunner;
 pub use process::{ProcessOutput, ProcessRunner};
 pub use ring_buffer::RingBuffer;
+pub use shell::Shell;
 pub use types::RunnerMode;
-pub use wsl::WslRunner;
+pub use usage::ProcessUsage;
+pub use wsl::{WslRunner, wsl_exec_command};
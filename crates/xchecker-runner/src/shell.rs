@@ -0,0 +1,266 @@
+//! Shell-aware rendering of commands for display
+//!
+//! Commands always run argv-style through [`CommandSpec`]; nothing here is
+//! used to spawn a process. When a command is shown to the user (error
+//! messages, runner descriptions, `doctor` hints) it is rendered for the shell
+//! they are most likely typing into, so copying it back works even when a
+//! path contains spaces, quotes, or shell metacharacters.
+
+use std::iter;
+
+use crate::command_spec::CommandSpec;
+
+/// Interactive shell a rendered command line is meant for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    /// `sh`, `bash`, `zsh`, and Git Bash / MSYS on Windows
+    Posix,
+    /// Windows PowerShell and PowerShell 7+
+    PowerShell,
+    /// `cmd.exe`
+    Cmd,
+}
+
+impl Shell {
+    /// Best guess at the shell that launched this process
+    #[must_use]
+    pub fn detect() -> Self {
+        Self::detect_with(cfg!(target_os = "windows"), |name| {
+            std::env::var(name).ok().filter(|value| !value.is_empty())
+        })
+    }
+
+    /// Detection logic behind [`Shell::detect`], with the platform and
+    /// environment lookup injected for testing
+    ///
+    /// Non-Windows platforms are always POSIX. On Windows, `MSYSTEM` marks
+    /// Git Bash / MSYS, `PROMPT` is exported by `cmd.exe` but not by
+    /// PowerShell, and `PSModulePath` is present under PowerShell.
+    pub fn detect_with(windows: bool, var: impl Fn(&str) -> Option<String>) -> Self {
+        if !windows || var("MSYSTEM").is_some() {
+            Self::Posix
+        } else if var("PROMPT").is_some() {
+            Self::Cmd
+        } else if var("PSModulePath").is_some() {
+            Self::PowerShell
+        } else {
+            Self::Cmd
+        }
+    }
+
+    /// Quote one argument so this shell passes it through unchanged
+    #[must_use]
+    pub fn quote(self, arg: &str) -> String {
+        match self {
+            Self::Posix => quote_posix(arg),
+            Self::PowerShell => quote_powershell(arg),
+            Self::Cmd => quote_cmd(arg),
+        }
+    }
+
+    /// Render a full command line for this shell
+    ///
+    /// PowerShell needs the call operator `&` to run a quoted program path.
+    /// Working directory and environment overrides are not rendered.
+    #[must_use]
+    pub fn render(self, spec: &CommandSpec) -> String {
+        let program = self.quote(&spec.program.to_string_lossy());
+        let call = if self == Self::PowerShell && program.starts_with('\'') {
+            "& "
+        } else {
+            ""
+        };
+        iter::once(format!("{call}{program}"))
+            .chain(
+                spec.args
+                    .iter()
+                    .map(|arg| self.quote(&arg.to_string_lossy())),
+            )
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+fn is_plain(arg: &str, extra: &[char]) -> bool {
+    !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=".contains(c) || extra.contains(&c))
+}
+
+/// Single quotes; an embedded `'` closes, escapes, and reopens the quote
+fn quote_posix(arg: &str) -> String {
+    if is_plain(arg, &['@', '%', '+', ',']) {
+        return arg.to_string();
+    }
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+/// Single quotes; an embedded `'` is doubled
+fn quote_powershell(arg: &str) -> String {
+    if is_plain(arg, &['\\']) {
+        return arg.to_string();
+    }
+    format!("'{}'", arg.replace('\'', "''"))
+}
+
+/// `CommandLineToArgvW` quoting, then every `cmd.exe` metacharacter
+/// (including the quotes themselves) caret-escaped so `cmd` leaves the
+/// argument alone and the program's argv parser sees the quoted form
+fn quote_cmd(arg: &str) -> String {
+    const METACHARACTERS: &[char] = &['(', ')', '%', '!', '^', '"', '<', '>', '&', '|'];
+
+    let argv = quote_argv(arg);
+    let mut out = String::with_capacity(argv.len());
+    for c in argv.chars() {
+        if METACHARACTERS.contains(&c) {
+            out.push('^');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Quote for the Microsoft C runtime argv parser used by most Windows
+/// programs, including `wsl.exe`
+fn quote_argv(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains([' ', '\t', '\n', '"']) {
+        return arg.to_string();
+    }
+
+    let mut out = String::from('"');
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                // Backslashes before a quote are doubled, plus one for the quote
+                out.extend(iter::repeat_n('\\', backslashes * 2 + 1));
+                out.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                out.extend(iter::repeat_n('\\', backslashes));
+                out.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+    // Trailing backslashes would escape the closing quote
+    out.extend(iter::repeat_n('\\', backslashes * 2));
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn detect(windows: bool, vars: &[(&str, &str)]) -> Shell {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect();
+        Shell::detect_with(windows, |name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn detects_shell_from_environment() {
+        assert_eq!(detect(false, &[("PROMPT", "$P$G")]), Shell::Posix);
+        assert_eq!(
+            detect(true, &[("PSModulePath", r"C:\Modules")]),
+            Shell::PowerShell
+        );
+        assert_eq!(
+            detect(true, &[("PSModulePath", r"C:\Modules"), ("PROMPT", "$P$G")]),
+            Shell::Cmd
+        );
+        assert_eq!(
+            detect(true, &[("MSYSTEM", "MINGW64"), ("PSModulePath", "x")]),
+            Shell::Posix
+        );
+        assert_eq!(detect(true, &[]), Shell::Cmd);
+    }
+
+    #[test]
+    fn posix_quoting() {
+        assert_eq!(Shell::Posix.quote("--version"), "--version");
+        assert_eq!(
+            Shell::Posix.quote("/opt/my tools/claude"),
+            "'/opt/my tools/claude'"
+        );
+        assert_eq!(Shell::Posix.quote("it's"), r"'it'\''s'");
+        assert_eq!(Shell::Posix.quote("$HOME"), "'$HOME'");
+        assert_eq!(Shell::Posix.quote(""), "''");
+    }
+
+    #[test]
+    fn powershell_quoting() {
+        assert_eq!(
+            Shell::PowerShell.quote(r"C:\tools\claude"),
+            r"C:\tools\claude"
+        );
+        assert_eq!(
+            Shell::PowerShell.quote(r"C:\Program Files\claude"),
+            r"'C:\Program Files\claude'"
+        );
+        assert_eq!(Shell::PowerShell.quote("it's"), "'it''s'");
+        assert_eq!(Shell::PowerShell.quote("$env:PATH"), "'$env:PATH'");
+        assert_eq!(Shell::PowerShell.quote("a,b"), "'a,b'");
+        assert_eq!(Shell::PowerShell.quote(""), "''");
+    }
+
+    #[test]
+    fn cmd_quoting() {
+        assert_eq!(Shell::Cmd.quote("--version"), "--version");
+        assert_eq!(
+            Shell::Cmd.quote(r"C:\Program Files\claude"),
+            r#"^"C:\Program Files\claude^""#
+        );
+        assert_eq!(Shell::Cmd.quote(r#"say "hi""#), r#"^"say \^"hi\^"^""#);
+        assert_eq!(
+            Shell::Cmd.quote(r"C:\dir with space\"),
+            r#"^"C:\dir with space\\^""#
+        );
+        assert_eq!(Shell::Cmd.quote("100%"), "100^%");
+        assert_eq!(Shell::Cmd.quote("a&b|c"), "a^&b^|c");
+        assert_eq!(Shell::Cmd.quote(""), r#"^"^""#);
+    }
+
+    #[test]
+    fn argv_quoting_round_trips_backslashes() {
+        assert_eq!(quote_argv(r"C:\plain\path"), r"C:\plain\path");
+        assert_eq!(quote_argv(r"a\\b c"), r#""a\\b c""#);
+        assert_eq!(quote_argv(r#"a\"b"#), r#""a\\\"b""#);
+    }
+
+    #[test]
+    fn render_wsl_command_with_spaces() {
+        let spec = CommandSpec::new("wsl")
+            .args(["-d", "Ubuntu 22.04", "--exec", "/opt/my tools/claude"])
+            .arg("--version");
+
+        assert_eq!(
+            Shell::Posix.render(&spec),
+            "wsl -d 'Ubuntu 22.04' --exec '/opt/my tools/claude' --version"
+        );
+        assert_eq!(
+            Shell::PowerShell.render(&spec),
+            "wsl -d 'Ubuntu 22.04' --exec '/opt/my tools/claude' --version"
+        );
+        assert_eq!(
+            Shell::Cmd.render(&spec),
+            r#"wsl -d ^"Ubuntu 22.04^" --exec ^"/opt/my tools/claude^" --version"#
+        );
+    }
+
+    #[test]
+    fn render_uses_call_operator_for_quoted_powershell_program() {
+        let spec = CommandSpec::new(r"C:\Program Files\Claude\claude.exe").arg("--version");
+        assert_eq!(
+            Shell::PowerShell.render(&spec),
+            r"& 'C:\Program Files\Claude\claude.exe' --version"
+        );
+    }
+}
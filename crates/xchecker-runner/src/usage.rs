@@ -0,0 +1,308 @@
+//! Resource usage of provider subprocesses
+//!
+//! [`wait_with_usage`] replaces `Child::wait` for provider CLIs so receipts can
+//! record how much wall time, CPU time, and memory an invocation took. On Unix
+//! the exited child is observed with `waitid(2)` and `WNOWAIT`, which on Linux
+//! also returns its `rusage`, and is then reaped by tokio; on Windows the
+//! process is queried with `GetProcessTimes` and `GetProcessMemoryInfo` once it
+//! has exited. Measurements that the platform cannot provide are `None`; a
+//! failed measurement never fails the invocation.
+
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::process::ExitStatus;
+use std::time::Instant;
+use tokio::process::Child;
+
+/// Resources consumed by one provider subprocess
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessUsage {
+    /// Time from spawn to exit in milliseconds
+    pub wall_time_ms: u64,
+    /// CPU time spent in user mode in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_cpu_ms: Option<u64>,
+    /// CPU time spent in the kernel in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_cpu_ms: Option<u64>,
+    /// Peak resident set size in bytes
+    ///
+    /// On Windows this is the peak working set, or for Claude CLI runs the
+    /// peak committed memory of the largest process in its Job Object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_rss_bytes: Option<u64>,
+}
+
+/// Wait for `child` to exit and measure what it consumed since `started`
+///
+/// `started` should be taken just before the child was spawned.
+///
+/// The child is always reaped through tokio, so a `Child` spawned with
+/// `kill_on_drop(true)` knows it has exited and never signals a reused pid.
+pub async fn wait_with_usage(
+    child: &mut Child,
+    started: Instant,
+) -> io::Result<(ExitStatus, ProcessUsage)> {
+    let Some(pid) = child.id() else {
+        // Already reaped by an earlier wait; only wall time is known
+        let status = child.wait().await?;
+        return Ok((status, ProcessUsage::since(started)));
+    };
+    platform::wait(child, pid, started).await
+}
+
+/// Convert a Windows duration in 100ns ticks to milliseconds
+#[cfg(windows)]
+pub(crate) const fn ticks_to_ms(ticks: u64) -> u64 {
+    ticks / 10_000
+}
+
+impl ProcessUsage {
+    /// Usage with only the wall time since `started` filled in
+    #[must_use]
+    pub fn since(started: Instant) -> Self {
+        Self {
+            wall_time_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+            ..Self::default()
+        }
+    }
+}
+
+#[cfg(unix)]
+mod platform {
+    use super::ProcessUsage;
+    use std::io;
+    use std::process::ExitStatus;
+    use std::time::Instant;
+    use tokio::process::Child;
+
+    pub(super) async fn wait(
+        child: &mut Child,
+        pid: u32,
+        started: Instant,
+    ) -> io::Result<(ExitStatus, ProcessUsage)> {
+        let id = libc::id_t::try_from(pid).map_err(io::Error::other)?;
+        let rusage = tokio::task::spawn_blocking(move || wait_exited(id))
+            .await
+            .map_err(io::Error::other)??;
+        let mut usage = ProcessUsage::since(started);
+        // The child is a zombie now; tokio reaps it and records the exit
+        let status = child.wait().await?;
+
+        if let Some(rusage) = rusage {
+            usage.user_cpu_ms = Some(timeval_ms(rusage.ru_utime));
+            usage.system_cpu_ms = Some(timeval_ms(rusage.ru_stime));
+            // ru_maxrss is in kilobytes
+            usage.peak_rss_bytes = u64::try_from(rusage.ru_maxrss)
+                .ok()
+                .map(|maxrss| maxrss.saturating_mul(1024));
+        }
+        Ok((status, usage))
+    }
+
+    /// Block until child `id` has exited without reaping it
+    ///
+    /// Linux fills in the zombie's `rusage` when asked through the raw
+    /// syscall; libc's `waitid` has no such argument, so other systems only
+    /// learn that the child exited.
+    #[cfg(target_os = "linux")]
+    fn wait_exited(id: libc::id_t) -> io::Result<Option<libc::rusage>> {
+        // SAFETY: siginfo_t and rusage are plain old data; all-zero is valid
+        let mut info: libc::siginfo_t = unsafe { std::mem::zeroed() };
+        let mut rusage: libc::rusage = unsafe { std::mem::zeroed() };
+        loop {
+            // SAFETY: both out-pointers refer to live locals
+            let ret = unsafe {
+                libc::syscall(
+                    libc::SYS_waitid,
+                    libc::P_PID,
+                    id,
+                    &raw mut info,
+                    libc::WEXITED | libc::WNOWAIT,
+                    &raw mut rusage,
+                )
+            };
+            if ret == 0 {
+                return Ok(Some(rusage));
+            }
+            let err = io::Error::last_os_error();
+            if err.kind() != io::ErrorKind::Interrupted {
+                return Err(err);
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn wait_exited(id: libc::id_t) -> io::Result<Option<libc::rusage>> {
+        // SAFETY: siginfo_t is plain old data; all-zero is a valid value
+        let mut info: libc::siginfo_t = unsafe { std::mem::zeroed() };
+        loop {
+            // SAFETY: the out-pointer refers to a live local
+            let ret = unsafe {
+                libc::waitid(
+                    libc::P_PID,
+                    id,
+                    &raw mut info,
+                    libc::WEXITED | libc::WNOWAIT,
+                )
+            };
+            if ret == 0 {
+                return Ok(None);
+            }
+            let err = io::Error::last_os_error();
+            if err.kind() != io::ErrorKind::Interrupted {
+                return Err(err);
+            }
+        }
+    }
+
+    fn timeval_ms(tv: libc::timeval) -> u64 {
+        let secs = u64::try_from(tv.tv_sec).unwrap_or(0);
+        let micros = u64::try_from(tv.tv_usec).unwrap_or(0);
+        secs.saturating_mul(1000).saturating_add(micros / 1000)
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use super::ProcessUsage;
+    use std::io;
+    use std::process::ExitStatus;
+    use std::time::Instant;
+    use tokio::process::Child;
+    use winapi::shared::minwindef::{DWORD, FALSE, FILETIME};
+    use winapi::um::handleapi::CloseHandle;
+    use winapi::um::processthreadsapi::{GetProcessTimes, OpenProcess};
+    use winapi::um::psapi::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+    use winapi::um::winnt::{HANDLE, PROCESS_QUERY_LIMITED_INFORMATION};
+
+    /// Process handle kept open so the exited process can still be queried
+    struct QueryHandle(HANDLE);
+
+    impl Drop for QueryHandle {
+        fn drop(&mut self) {
+            // SAFETY: the handle came from OpenProcess and is closed once
+            unsafe {
+                CloseHandle(self.0);
+            }
+        }
+    }
+
+    pub(super) async fn wait(
+        child: &mut Child,
+        pid: u32,
+        started: Instant,
+    ) -> io::Result<(ExitStatus, ProcessUsage)> {
+        // Opened before waiting: tokio closes its own handle once the child exits
+        // SAFETY: OpenProcess has no preconditions; a null result is handled
+        let handle = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, FALSE, pid) };
+        let handle = (!handle.is_null()).then(|| QueryHandle(handle));
+
+        let status = child.wait().await?;
+        let mut usage = ProcessUsage::since(started);
+        if let Some(handle) = handle {
+            query(&handle, &mut usage);
+        }
+        Ok((status, usage))
+    }
+
+    fn query(handle: &QueryHandle, usage: &mut ProcessUsage) {
+        let zero = FILETIME {
+            dwLowDateTime: 0,
+            dwHighDateTime: 0,
+        };
+        let (mut creation, mut exit, mut kernel, mut user) = (zero, zero, zero, zero);
+        // SAFETY: all out-pointers refer to live locals
+        let ok =
+            unsafe { GetProcessTimes(handle.0, &mut creation, &mut exit, &mut kernel, &mut user) };
+        if ok != 0 {
+            usage.user_cpu_ms = Some(super::ticks_to_ms(filetime_ticks(user)));
+            usage.system_cpu_ms = Some(super::ticks_to_ms(filetime_ticks(kernel)));
+        }
+
+        // SAFETY: PROCESS_MEMORY_COUNTERS is plain old data; all-zero is valid
+        let mut counters: PROCESS_MEMORY_COUNTERS = unsafe { std::mem::zeroed() };
+        let size = std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as DWORD;
+        // SAFETY: counters is a live local of the size passed
+        if unsafe { GetProcessMemoryInfo(handle.0, &mut counters, size) } != 0 {
+            usage.peak_rss_bytes = Some(counters.PeakWorkingSetSize as u64);
+        }
+    }
+
+    fn filetime_ticks(time: FILETIME) -> u64 {
+        (u64::from(time.dwHighDateTime) << 32) | u64::from(time.dwLowDateTime)
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod platform {
+    use super::ProcessUsage;
+    use std::io;
+    use std::process::ExitStatus;
+    use std::time::Instant;
+    use tokio::process::Child;
+
+    pub(super) async fn wait(
+        child: &mut Child,
+        _pid: u32,
+        started: Instant,
+    ) -> io::Result<(ExitStatus, ProcessUsage)> {
+        let status = child.wait().await?;
+        Ok((status, ProcessUsage::since(started)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_wait_with_usage_measures_child() {
+        let started = Instant::now();
+        // Busy-loop briefly so CPU time is non-zero on most machines
+        let mut child = tokio::process::Command::new("sh")
+            .arg("-c")
+            .arg("i=0; while [ $i -lt 20000 ]; do i=$((i+1)); done; exit 3")
+            .spawn()
+            .unwrap();
+
+        let (status, usage) = wait_with_usage(&mut child, started).await.unwrap();
+        assert_eq!(status.code(), Some(3));
+        if cfg!(target_os = "linux") {
+            assert!(usage.user_cpu_ms.is_some());
+            assert!(usage.system_cpu_ms.is_some());
+            assert!(usage.peak_rss_bytes.is_some_and(|rss| rss > 0));
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_wait_with_usage_leaves_reaping_to_tokio() {
+        let mut child = tokio::process::Command::new("sh")
+            .arg("-c")
+            .arg("exit 4")
+            .kill_on_drop(true)
+            .spawn()
+            .unwrap();
+
+        let (status, _) = wait_with_usage(&mut child, Instant::now()).await.unwrap();
+        assert_eq!(status.code(), Some(4));
+        // tokio saw the exit, so dropping the child sends no signal to the pid
+        assert_eq!(child.id(), None);
+        assert_eq!(child.try_wait().unwrap(), Some(status));
+        drop(child);
+    }
+
+    #[test]
+    fn test_process_usage_serialization_omits_unknown_fields() {
+        let usage = ProcessUsage {
+            wall_time_ms: 1500,
+            ..ProcessUsage::default()
+        };
+        let json = serde_json::to_value(usage).unwrap();
+        assert_eq!(json, serde_json::json!({ "wall_time_ms": 1500 }));
+        let back: ProcessUsage = serde_json::from_value(json).unwrap();
+        assert_eq!(back, usage);
+    }
+}
@@ -9,6 +9,36 @@ use super::{CommandSpec, ProcessOutput, ProcessRunner};
 // WslRunner - Secure WSL Process Execution
 // ============================================================================
 
+/// Wrap `cmd` as `wsl [-d <distro>] --exec <program> <args...>`
+///
+/// The single place WSL bridging commands are built: every element stays a
+/// discrete argument, so distro names and paths with spaces survive intact.
+/// Working directory and environment overrides carry over. Arguments are not
+/// validated here; [`WslRunner`] does that at its trust boundary.
+#[must_use]
+pub fn wsl_exec_command(distro: Option<&str>, cmd: &CommandSpec) -> CommandSpec {
+    let mut wsl_cmd = CommandSpec::new("wsl");
+    if let Some(distro) = distro {
+        wsl_cmd = wsl_cmd.arg("-d").arg(distro);
+    }
+
+    // --exec bypasses the distro's shell: arguments reach the program as-is
+    wsl_cmd = wsl_cmd.arg("--exec").arg(&cmd.program);
+    for arg in &cmd.args {
+        wsl_cmd = wsl_cmd.arg(arg);
+    }
+
+    if let Some(ref cwd) = cmd.cwd {
+        wsl_cmd = wsl_cmd.cwd(cwd);
+    }
+    if let Some(ref env) = cmd.env {
+        for (key, value) in env {
+            wsl_cmd = wsl_cmd.env(key, value);
+        }
+    }
+    wsl_cmd
+}
+
 /// WSL process runner for Windows.
 ///
 /// `WslRunner` provides secure process execution via WSL using argv-style APIs only.
@@ -32,7 +62,7 @@ use super::{CommandSpec, ProcessOutput, ProcessRunner};
 /// # Example
 ///
 /// ```rust,no_run
-/// use xchecker_utils::runner::{WslRunner, ProcessRunner, CommandSpec};
+/// use xchecker_runner::{WslRunner, ProcessRunner, CommandSpec};
 /// use std::time::Duration;
 ///
 /// let runner = WslRunner::new();
@@ -55,7 +85,7 @@ impl WslRunner {
     /// # Example
     ///
     /// ```rust
-    /// use xchecker_utils::runner::WslRunner;
+    /// use xchecker_runner::WslRunner;
     ///
     /// let runner = WslRunner::new();
     /// ```
@@ -73,7 +103,7 @@ impl WslRunner {
     /// # Example
     ///
     /// ```rust
-    /// use xchecker_utils::runner::WslRunner;
+    /// use xchecker_runner::WslRunner;
     ///
     /// let runner = WslRunner::with_distro("Ubuntu-22.04");
     /// ```
@@ -141,42 +171,8 @@ impl WslRunner {
             Self::validate_argument(arg)?;
         }
 
-        // Build the WSL command using argv-style APIs only
         // Command structure: wsl.exe [-d <distro>] --exec <program> <args...>
-        let mut wsl_cmd = CommandSpec::new("wsl");
-
-        // Add distro specification if provided (using discrete args, not string concat)
-        if let Some(ref distro) = self.distro {
-            wsl_cmd = wsl_cmd.arg("-d").arg(distro);
-        }
-
-        // Add --exec flag to bypass shell interpretation
-        // This is critical for security - it ensures arguments are passed directly
-        // to the target program without shell evaluation
-        wsl_cmd = wsl_cmd.arg("--exec");
-
-        // Add the original program as a discrete argument
-        wsl_cmd = wsl_cmd.arg(&cmd.program);
-
-        // Add all original arguments as discrete elements
-        // NO string concatenation occurs here - each arg is a separate element
-        for arg in &cmd.args {
-            wsl_cmd = wsl_cmd.arg(arg);
-        }
-
-        // Preserve working directory if specified
-        if let Some(ref cwd) = cmd.cwd {
-            wsl_cmd = wsl_cmd.cwd(cwd);
-        }
-
-        // Preserve environment variables if specified
-        if let Some(ref env) = cmd.env {
-            for (key, value) in env {
-                wsl_cmd = wsl_cmd.env(key, value);
-            }
-        }
-
-        Ok(wsl_cmd)
+        Ok(wsl_exec_command(self.distro.as_deref(), cmd))
     }
 }
 
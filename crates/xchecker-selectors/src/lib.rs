@@ -11,7 +11,8 @@ use globset::Glob;
 ///
 /// - `.env` and `.env.*` - Environment variable files
 /// - `*.pem`, `*.pfx`, `*.p12`, `*.key`, `*.p8` - Certificate/key files
-/// - `id_rsa`, `id_ed25519` - SSH private keys
+/// - `id_rsa`, `id_dsa`, `id_ecdsa`, `id_ed25519` - SSH private keys
+/// - `*.jks`, `*.keystore` - Java/Android keystores
 /// - `.ssh/**` - SSH configuration directory
 /// - `.aws/**` - AWS credentials directory
 /// - `.kube/**` - Kubernetes config directory
@@ -22,6 +23,8 @@ pub const ALWAYS_EXCLUDE_PATTERNS: &[&str] = &[
     "**/.env.*",
     "**/*.pem",
     "**/id_rsa",
+    "**/id_dsa",
+    "**/id_ecdsa",
     "**/id_ed25519",
     "**/.ssh/**",
     "**/*.pfx",
@@ -33,6 +36,8 @@ pub const ALWAYS_EXCLUDE_PATTERNS: &[&str] = &[
     "**/*.p8",
     "**/secrets.yaml",
     "**/secrets.yml",
+    "**/*.jks",
+    "**/*.keystore",
 ];
 
 /// Content selection configuration
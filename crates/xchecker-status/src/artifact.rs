@@ -4,10 +4,12 @@ use camino::Utf8PathBuf;
 use std::fs;
 use std::path::Path;
 
-use crate::atomic_write::{AtomicWriteResult, write_file_atomic};
+use crate::atomic_write::{AtomicWriteResult, write_bytes_atomic, write_file_atomic};
+use crate::canonicalization::Canonicalizer;
 use crate::lock::{FileLock, LockError};
 use crate::paths::{SandboxConfig, SandboxRoot};
-use crate::types::PhaseId;
+use crate::types::{FileType, PhaseId, SpecMetadata};
+use xchecker_utils::compression;
 
 /// Manages artifact storage with atomic writes and directory structure
 ///
@@ -57,6 +59,49 @@ impl Artifact {
     }
 }
 
+/// Generation metadata prepended to Markdown artifacts as YAML front matter
+/// (`[outputs] front_matter = true`)
+///
+/// Keeps artifacts traceable to the run and receipt that produced them after
+/// they are copied out of the spec directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtifactFrontMatter {
+    pub spec_id: String,
+    pub phase: String,
+    pub run_id: String,
+    pub model: String,
+    /// Receipt path relative to the spec directory
+    pub receipt: String,
+}
+
+impl ArtifactFrontMatter {
+    /// Prepend the front matter to `body`, recording the body's canonical hash
+    ///
+    /// `body_blake3` matches the artifact's `blake3_canonicalized` in the
+    /// receipt, since Markdown hashing ignores front matter.
+    pub fn prepend(&self, body: &str) -> Result<String> {
+        let body_hash = Canonicalizer::new().hash_canonicalized(body, FileType::Markdown)?;
+        let fields = [
+            ("spec_id", self.spec_id.as_str()),
+            ("phase", self.phase.as_str()),
+            ("run_id", self.run_id.as_str()),
+            ("model", self.model.as_str()),
+            ("receipt", self.receipt.as_str()),
+            ("body_blake3", body_hash.as_str()),
+        ];
+
+        let mut out = String::from("---\n");
+        for (key, value) in fields {
+            // JSON strings are valid double-quoted YAML scalars
+            let quoted = serde_json::to_string(value)?;
+            out.push_str(&format!("{key}: {quoted}\n"));
+        }
+        out.push_str("---\n");
+        out.push_str(body);
+        Ok(out)
+    }
+}
+
 /// Result of storing an artifact with atomic write metadata
 #[derive(Debug, Clone)]
 pub struct ArtifactStoreResult {
@@ -91,6 +136,43 @@ impl ArtifactType {
     }
 }
 
+/// Per-spec quota for the `context/` directory (`[context_retention]`)
+///
+/// Unset limits are not enforced; the default keeps every context file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ContextRetention {
+    /// Maximum total size of all context files in bytes
+    pub max_bytes: Option<u64>,
+    /// Maximum number of context files
+    pub max_files: Option<usize>,
+}
+
+impl ContextRetention {
+    /// Whether any limit is configured
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.max_bytes.is_some() || self.max_files.is_some()
+    }
+}
+
+/// A context file removed to bring the directory back under quota
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextRotation {
+    /// File name within `context/`
+    pub file: String,
+    /// Size of the removed file in bytes
+    pub bytes: u64,
+}
+
+/// An artifact whose number prefix disagrees with the current phase order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtifactRename {
+    /// Current file name within `artifacts/`
+    pub from: String,
+    /// File name under the current phase order
+    pub to: String,
+}
+
 impl ArtifactManager {
     /// Create a new `ArtifactManager` for the given spec ID
     ///
@@ -144,9 +226,17 @@ impl ArtifactManager {
 
     /// Ensure spec directory tree exists (called before lock acquisition)
     fn ensure_spec_dirs(base_path: &Utf8PathBuf) -> Result<()> {
-        // Create base path first (ignore benign races)
-        crate::paths::ensure_dir_all(base_path)
-            .with_context(|| format!("Failed to create base directory: {base_path}"))?;
+        // Create base path first (ignore benign races); a read-only home gets
+        // an actionable message rather than a bare permission error
+        if let Err(e) = crate::paths::ensure_dir_all(base_path) {
+            if crate::paths::is_read_only_error(&e) {
+                anyhow::bail!(
+                    "{}: {e}",
+                    crate::paths::read_only_home_hint(&crate::paths::xchecker_home())
+                );
+            }
+            return Err(e).with_context(|| format!("Failed to create base directory: {base_path}"));
+        }
 
         crate::paths::ensure_dir_all(base_path.join("artifacts"))
             .with_context(|| format!("Failed to create artifacts directory: {base_path}"))?;
@@ -244,7 +334,7 @@ impl ArtifactManager {
         if partial_dir.exists() {
             // Best-effort removal - don't fail if we can't remove it
             if let Err(e) = fs::remove_dir_all(&partial_dir) {
-                eprintln!("Warning: Failed to remove stale .partial/ directory: {e}");
+                tracing::warn!("Failed to remove stale .partial/ directory: {e}");
                 // Don't propagate the error - this is best-effort cleanup
             }
         }
@@ -273,27 +363,39 @@ impl ArtifactManager {
         let file_path_utf8 = Utf8PathBuf::from_path_buf(file_path)
             .map_err(|p| anyhow::anyhow!("Invalid UTF-8 path: {}", p.display()))?;
 
-        let atomic_result = self.write_file_atomic(&file_path_utf8, &artifact.content)?;
+        let (path, atomic_result) = self.write_stored(&file_path_utf8, &artifact.content)?;
         Ok(ArtifactStoreResult {
-            path: file_path_utf8,
+            path,
             atomic_write_result: atomic_result,
         })
     }
 
     /// Promote a partial staged artifact to its final location (FR-ORC-004)
     /// This atomically moves the artifact from .partial/ to artifacts/
+    ///
+    /// A staged artifact stored compressed is promoted as `<name>.zst`; the
+    /// returned path is the file actually written.
     pub fn promote_staged_to_final(&self, artifact_name: &str) -> Result<Utf8PathBuf> {
         // Validate both source and destination paths through sandbox
-        let partial_rel = format!(".partial/{}", artifact_name);
-        let final_rel = format!("artifacts/{}", artifact_name);
-
-        let partial_path = self.validate_path(&partial_rel)?;
-        let final_path = self.validate_path(&final_rel)?;
+        let plain_partial = self.validate_path(&format!(".partial/{artifact_name}"))?;
+        let (partial_path, final_path, stale_path) = if plain_partial.exists() {
+            (
+                plain_partial.clone(),
+                self.validate_path(&format!("artifacts/{artifact_name}"))?,
+                self.validate_path(&format!("artifacts/{artifact_name}.zst"))?,
+            )
+        } else {
+            (
+                self.validate_path(&format!(".partial/{artifact_name}.zst"))?,
+                self.validate_path(&format!("artifacts/{artifact_name}.zst"))?,
+                self.validate_path(&format!("artifacts/{artifact_name}"))?,
+            )
+        };
 
         if !partial_path.exists() {
             anyhow::bail!(
                 "Partial artifact does not exist: {}",
-                partial_path.display()
+                plain_partial.display()
             );
         }
 
@@ -308,6 +410,7 @@ impl ArtifactManager {
         fs::rename(&partial_path, &final_path).with_context(|| {
             format!("Failed to promote artifact from .partial/ to final: {artifact_name}")
         })?;
+        remove_if_exists(&stale_path)?;
 
         Utf8PathBuf::from_path_buf(final_path)
             .map_err(|p| anyhow::anyhow!("Invalid UTF-8 path: {}", p.display()))
@@ -316,9 +419,9 @@ impl ArtifactManager {
     /// Store an artifact using atomic write operations
     pub fn store_artifact(&self, artifact: &Artifact) -> Result<ArtifactStoreResult> {
         let file_path = self.get_artifact_path_validated(&artifact.name, artifact.artifact_type)?;
-        let atomic_result = self.write_file_atomic(&file_path, &artifact.content)?;
+        let (path, atomic_result) = self.write_stored(&file_path, &artifact.content)?;
         Ok(ArtifactStoreResult {
-            path: file_path,
+            path,
             atomic_write_result: atomic_result,
         })
     }
@@ -359,6 +462,75 @@ impl ArtifactManager {
         Ok(file_path_utf8)
     }
 
+    /// Remove the oldest context files until `context/` is within `retention`
+    ///
+    /// Files are rotated oldest-first by modification time (ties broken by
+    /// name). Files named in `keep` are never removed, so the files just
+    /// written for the running phase survive even when they alone exceed the
+    /// quota. Each removal is logged as a warning and returned.
+    pub fn enforce_context_retention(
+        &self,
+        retention: ContextRetention,
+        keep: &[&str],
+    ) -> Result<Vec<ContextRotation>> {
+        if !retention.is_enabled() {
+            return Ok(Vec::new());
+        }
+
+        let context_dir = self.context_path();
+        let entries = fs::read_dir(&context_dir)
+            .with_context(|| format!("Failed to read context directory: {context_dir}"))?;
+
+        let mut files = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if !metadata.is_file() {
+                continue;
+            }
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+            let modified = metadata.modified().unwrap_or(std::time::UNIX_EPOCH);
+            files.push((modified, name, metadata.len()));
+        }
+        files.sort();
+
+        let mut total_bytes: u64 = files.iter().map(|(_, _, len)| len).sum();
+        let mut total_files = files.len();
+        let over_quota = |bytes: u64, count: usize| {
+            retention.max_bytes.is_some_and(|max| bytes > max)
+                || retention.max_files.is_some_and(|max| count > max)
+        };
+
+        let mut rotated = Vec::new();
+        for (_, name, len) in files {
+            if !over_quota(total_bytes, total_files) {
+                break;
+            }
+            if keep.contains(&name.as_str()) {
+                continue;
+            }
+            let path = self.validate_path(&format!("context/{name}"))?;
+            fs::remove_file(&path)
+                .with_context(|| format!("Failed to rotate context file: {}", path.display()))?;
+            tracing::warn!(
+                target: "xchecker::context",
+                file = %name,
+                bytes = len,
+                "Rotated context file to stay within context_retention quota"
+            );
+            total_bytes -= len;
+            total_files -= 1;
+            rotated.push(ContextRotation {
+                file: name,
+                bytes: len,
+            });
+        }
+
+        Ok(rotated)
+    }
+
     /// Write content to a file using atomic operations (tempfile → fsync → rename)
     /// Returns the atomic write result with retry/fallback information
     fn write_file_atomic(&self, path: &Utf8PathBuf, content: &str) -> Result<AtomicWriteResult> {
@@ -366,6 +538,36 @@ impl ArtifactManager {
             .with_context(|| format!("Failed to atomically write file: {path}"))
     }
 
+    /// Write an artifact, compressed to `<path>.zst` when it exceeds the
+    /// compression threshold (`[defaults] compress_threshold_bytes`)
+    ///
+    /// Whichever form is not written is removed so readers never pick up a
+    /// stale copy. Returns the path actually written.
+    fn write_stored(
+        &self,
+        path: &Utf8PathBuf,
+        content: &str,
+    ) -> Result<(Utf8PathBuf, AtomicWriteResult)> {
+        let normalized = self.normalize_line_endings(content);
+        let compressed = compression::compress_for_storage(normalized.as_bytes())
+            .with_context(|| format!("Failed to compress artifact: {path}"))?;
+        let zst_path = compression::compressed_path(path);
+
+        match compressed {
+            Some(bytes) => {
+                let result = write_bytes_atomic(&zst_path, &bytes)
+                    .with_context(|| format!("Failed to atomically write file: {zst_path}"))?;
+                remove_if_exists(path.as_std_path())?;
+                Ok((zst_path, result))
+            }
+            None => {
+                let result = self.write_file_atomic(path, &normalized)?;
+                remove_if_exists(zst_path.as_std_path())?;
+                Ok((path.clone(), result))
+            }
+        }
+    }
+
     /// Normalize line endings to \n for all content
     fn normalize_line_endings(&self, content: &str) -> String {
         content.replace("\r\n", "\n").replace('\r', "\n")
@@ -416,23 +618,7 @@ impl ArtifactManager {
 
     /// Generate filename for a phase artifact
     fn get_phase_filename(&self, phase: PhaseId, artifact_type: ArtifactType) -> String {
-        let phase_number = self.get_phase_number(phase);
-        let phase_name = phase.as_str();
-        let extension = artifact_type.extension();
-
-        format!("{phase_number:02}-{phase_name}.{extension}")
-    }
-
-    /// Get the numeric prefix for a phase
-    const fn get_phase_number(&self, phase: PhaseId) -> u8 {
-        match phase {
-            PhaseId::Requirements => 0,
-            PhaseId::Design => 10,
-            PhaseId::Tasks => 20,
-            PhaseId::Review => 30,
-            PhaseId::Fixup => 40,
-            PhaseId::Final => 50,
-        }
+        phase.artifact_name(artifact_type.extension())
     }
 
     /// Get the base path for this spec
@@ -441,6 +627,11 @@ impl ArtifactManager {
         &self.base_path
     }
 
+    /// Load the spec's optional `spec.toml` metadata
+    pub fn spec_metadata(&self) -> Result<Option<SpecMetadata>> {
+        crate::metadata::load_spec_metadata(&self.base_path)
+    }
+
     /// Get the artifacts directory path
     #[must_use]
     pub fn artifacts_path(&self) -> Utf8PathBuf {
@@ -468,16 +659,16 @@ impl ArtifactManager {
     #[must_use]
     pub fn artifact_exists(&self, name: &str, artifact_type: ArtifactType) -> bool {
         match self.get_artifact_path_validated(name, artifact_type) {
-            Ok(path) => path.exists(),
+            Ok(path) => compression::exists(path.as_std_path()),
             Err(_) => false,
         }
     }
 
-    /// Read an existing artifact
+    /// Read an existing artifact, decompressing it if it is stored as `.zst`
     #[allow(dead_code)] // Test harness/utility method
     pub fn read_artifact(&self, name: &str, artifact_type: ArtifactType) -> Result<String> {
         let path = self.get_artifact_path_validated(name, artifact_type)?;
-        fs::read_to_string(path.as_std_path())
+        compression::read_to_string(path.as_std_path())
             .with_context(|| format!("Failed to read artifact: {path}"))
     }
 
@@ -501,8 +692,11 @@ impl ArtifactManager {
         let partial_path =
             self.get_artifact_path_validated(&partial_name, ArtifactType::Partial)?;
 
-        if partial_path.exists() {
-            fs::remove_file(partial_path.as_std_path())
+        for path in [
+            partial_path.clone(),
+            compression::compressed_path(&partial_path),
+        ] {
+            remove_if_exists(path.as_std_path())
                 .with_context(|| format!("Failed to delete partial artifact: {partial_path}"))?;
         }
 
@@ -524,21 +718,21 @@ impl ArtifactManager {
             self.get_artifact_path_validated(&partial_name, ArtifactType::Partial)?;
         let final_path = self.get_artifact_path_validated(&final_name, artifact_type)?;
 
-        if !partial_path.exists() {
+        if !compression::exists(partial_path.as_std_path()) {
             return Err(anyhow::anyhow!(
                 "Partial artifact does not exist: {partial_path}"
             ));
         }
 
         // Read partial content
-        let content = fs::read_to_string(partial_path.as_std_path())
+        let content = compression::read_to_string(partial_path.as_std_path())
             .with_context(|| format!("Failed to read partial artifact: {partial_path}"))?;
 
         // Write to final location atomically
-        let _atomic_result = self.write_file_atomic(&final_path, &content)?;
+        let (final_path, _atomic_result) = self.write_stored(&final_path, &content)?;
 
         // Delete the partial
-        fs::remove_file(partial_path.as_std_path()).with_context(|| {
+        self.delete_partial_artifact(phase).with_context(|| {
             format!("Failed to delete partial artifact after promotion: {partial_path}")
         })?;
 
@@ -600,6 +794,66 @@ impl ArtifactManager {
         artifacts.sort();
         Ok(artifacts)
     }
+
+    /// List phase artifacts numbered under a different phase order
+    ///
+    /// Each entry maps the file to the name [`PhaseId::artifact_name`] gives
+    /// it today. Files that are not phase artifacts are ignored.
+    pub fn stale_artifact_numbering(&self) -> Result<Vec<ArtifactRename>> {
+        Ok(self
+            .list_artifacts()?
+            .into_iter()
+            .filter_map(|from| {
+                let (number, phase, suffix) = PhaseId::parse_artifact_name(&from)?;
+                let to = phase.artifact_name(suffix);
+                (number != phase.number()).then_some(ArtifactRename { from, to })
+            })
+            .collect())
+    }
+
+    /// Rename stale-numbered artifacts to match the current phase order
+    ///
+    /// Every collision is checked before anything moves: if a target already
+    /// exists, or two files would land on the same name, nothing is renamed.
+    /// With `dry_run` the planned renames are returned without touching disk.
+    pub fn migrate_artifact_numbering(&self, dry_run: bool) -> Result<Vec<ArtifactRename>> {
+        let renames = self.stale_artifact_numbering()?;
+
+        let existing = self.list_artifacts()?;
+        let mut targets = std::collections::BTreeSet::new();
+        let conflicts: Vec<&str> = renames
+            .iter()
+            .filter(|r| existing.contains(&r.to) || !targets.insert(r.to.as_str()))
+            .map(|r| r.to.as_str())
+            .collect();
+        if !conflicts.is_empty() {
+            anyhow::bail!(
+                "Artifact renumbering would overwrite existing files: {}",
+                conflicts.join(", ")
+            );
+        }
+
+        if !dry_run {
+            for rename in &renames {
+                let from = self.validate_path(&format!("artifacts/{}", rename.from))?;
+                let to = self.validate_path(&format!("artifacts/{}", rename.to))?;
+                fs::rename(&from, &to).with_context(|| {
+                    format!("Failed to rename {} to {}", rename.from, rename.to)
+                })?;
+            }
+        }
+
+        Ok(renames)
+    }
+}
+
+/// Remove a file, treating an already-missing file as success
+fn remove_if_exists(path: &Path) -> Result<()> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e).with_context(|| format!("Failed to remove {}", path.display())),
+    }
 }
 
 #[cfg(test)]
@@ -624,6 +878,25 @@ mod tests {
         assert!(manager.context_path().exists());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_read_only_home_reports_workspace_hint() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = crate::paths::with_isolated_home();
+        fs::set_permissions(temp_dir.path(), fs::Permissions::from_mode(0o555)).unwrap();
+        // Root bypasses directory permissions, so only assert when the home is really read-only
+        let read_only = crate::paths::probe_writable(temp_dir.path()).is_err();
+
+        let result = ArtifactManager::new("read-only-home");
+        fs::set_permissions(temp_dir.path(), fs::Permissions::from_mode(0o755)).unwrap();
+
+        if read_only {
+            let message = result.err().expect("read-only home must fail").to_string();
+            assert!(message.contains("--workspace"), "{message}");
+        }
+    }
+
     #[test]
     fn test_line_ending_normalization() {
         let (manager, _temp_dir) = create_test_manager_with_id("test-spec-line-ending");
@@ -723,6 +996,53 @@ mod tests {
         assert!(path.to_string().ends_with("debug-info.txt"));
     }
 
+    #[test]
+    fn test_context_retention_rotates_oldest_first() {
+        let (manager, _temp_dir) = create_test_manager_with_id("test-spec-context-quota");
+
+        for (i, name) in ["a-packet", "b-packet", "c-packet"].iter().enumerate() {
+            let path = manager.store_context_file(name, "0123456789").unwrap();
+            let modified = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_000 + i as u64);
+            fs::File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(modified)
+                .unwrap();
+        }
+
+        // Unlimited retention never removes anything
+        let rotated = manager
+            .enforce_context_retention(ContextRetention::default(), &[])
+            .unwrap();
+        assert!(rotated.is_empty());
+
+        // Keeping the oldest file forces the next-oldest out instead
+        let retention = ContextRetention {
+            max_bytes: Some(25),
+            max_files: None,
+        };
+        let rotated = manager
+            .enforce_context_retention(retention, &["a-packet.txt"])
+            .unwrap();
+        assert_eq!(
+            rotated,
+            vec![ContextRotation {
+                file: "b-packet.txt".to_string(),
+                bytes: 10,
+            }]
+        );
+
+        let retention = ContextRetention {
+            max_bytes: None,
+            max_files: Some(1),
+        };
+        let rotated = manager.enforce_context_retention(retention, &[]).unwrap();
+        assert_eq!(rotated.len(), 1);
+        assert_eq!(rotated[0].file, "a-packet.txt");
+        assert!(manager.context_path().join("c-packet.txt").exists());
+    }
+
     #[test]
     fn test_partial_artifact_handling() {
         let (manager, _temp_dir) = create_test_manager_with_id("test-spec-partial");
@@ -752,6 +1072,61 @@ mod tests {
         assert!(!manager.has_partial_artifact(PhaseId::Requirements));
     }
 
+    #[test]
+    fn test_large_artifacts_are_stored_compressed() {
+        let (manager, _temp_dir) = create_test_manager_with_id("test-spec-compressed");
+        // Other tests write far less than the threshold, so the global policy is safe to flip here
+        compression::set_compression_policy(compression::CompressionPolicy {
+            threshold_bytes: Some(64 * 1024),
+            level: compression::DEFAULT_LEVEL,
+        });
+        let large = "## Component\n\nDetails of the design.\n".repeat(4096);
+        let artifact = Artifact::new(
+            "10-design.md".to_string(),
+            large.clone(),
+            ArtifactType::Markdown,
+        );
+        let staged = manager.store_partial_staged_artifact(&artifact);
+        let promoted = manager.promote_staged_to_final("10-design.md");
+        compression::set_compression_policy(compression::CompressionPolicy::default());
+
+        assert!(
+            staged
+                .unwrap()
+                .path
+                .as_str()
+                .ends_with(".partial/10-design.md.zst")
+        );
+        let promoted = promoted.unwrap();
+        assert!(promoted.as_str().ends_with("artifacts/10-design.md.zst"));
+        assert!(fs::metadata(&promoted).unwrap().len() < large.len() as u64);
+        assert!(!manager.artifacts_path().join("10-design.md").exists());
+
+        assert!(manager.artifact_exists("10-design.md", ArtifactType::Markdown));
+        assert_eq!(
+            manager
+                .read_artifact("10-design.md", ArtifactType::Markdown)
+                .unwrap(),
+            large
+        );
+
+        // Rewriting below the threshold replaces the compressed copy
+        manager
+            .store_artifact(&Artifact::new(
+                "10-design.md".to_string(),
+                "# Small\n".to_string(),
+                ArtifactType::Markdown,
+            ))
+            .unwrap();
+        assert!(!promoted.exists());
+        assert_eq!(
+            manager
+                .read_artifact("10-design.md", ArtifactType::Markdown)
+                .unwrap(),
+            "# Small\n"
+        );
+    }
+
     #[test]
     fn test_promote_partial_to_final() {
         let (manager, _temp_dir) = create_test_manager_with_id("test-spec-promote");
@@ -899,4 +1274,36 @@ mod tests {
         assert!(artifacts.contains(&"00-requirements.md".to_string()));
         assert!(artifacts.contains(&"00-requirements.core.yaml".to_string()));
     }
+
+    #[test]
+    fn test_migrate_artifact_numbering_renames_every_file_of_a_phase() {
+        let (manager, _temp_dir) = create_test_manager_with_id("test-spec-renumber");
+        let dir = manager.artifacts_path();
+        fs::write(dir.join("15-design.md"), "# Design").unwrap();
+        fs::write(dir.join("15-design.core.yaml"), "spec_id: x").unwrap();
+        fs::write(dir.join("00-requirements.md"), "# Req").unwrap();
+        fs::write(dir.join("notes.md"), "unrelated").unwrap();
+
+        let planned = manager.migrate_artifact_numbering(true).unwrap();
+        assert_eq!(planned.len(), 2);
+        assert!(dir.join("15-design.md").exists());
+
+        manager.migrate_artifact_numbering(false).unwrap();
+        assert!(dir.join("10-design.md").exists());
+        assert!(dir.join("10-design.core.yaml").exists());
+        assert!(!dir.join("15-design.md").exists());
+        assert!(manager.stale_artifact_numbering().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_migrate_artifact_numbering_refuses_collisions() {
+        let (manager, _temp_dir) = create_test_manager_with_id("test-spec-renumber-clash");
+        let dir = manager.artifacts_path();
+        fs::write(dir.join("10-design.md"), "current").unwrap();
+        fs::write(dir.join("15-design.md"), "stale").unwrap();
+
+        let err = manager.migrate_artifact_numbering(false).unwrap_err();
+        assert!(err.to_string().contains("10-design.md"));
+        assert!(dir.join("15-design.md").exists());
+    }
 }
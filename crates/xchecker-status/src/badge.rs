@@ -0,0 +1,223 @@
+//! Status badge generation for spec READMEs
+//!
+//! Renders a shields-style SVG badge (or a Markdown snippet) showing the
+//! latest phase of a spec and whether it passed. Output is derived only from
+//! receipts, so the same receipt chain always renders the same bytes.
+
+use anyhow::Result;
+use camino::Utf8Path;
+
+use crate::atomic_write::write_file_atomic;
+use crate::types::Receipt;
+
+/// Left-hand label shown on every badge
+pub const BADGE_LABEL: &str = "xchecker";
+
+const COLOR_PASSING: &str = "#4c1";
+const COLOR_FAILING: &str = "#e05d44";
+const COLOR_UNKNOWN: &str = "#9f9f9f";
+
+/// Approximate glyph width used to size badge segments (Verdana 11px)
+const CHAR_WIDTH: usize = 7;
+/// Horizontal padding per segment
+const SEGMENT_PADDING: usize = 10;
+
+/// Latest workflow state of a spec, as shown on its badge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Badge {
+    /// Right-hand message, e.g. `design passing`
+    pub message: String,
+    /// Fill color of the message segment
+    pub color: &'static str,
+}
+
+impl Badge {
+    /// Derive the badge from a chronologically ordered receipt list
+    ///
+    /// The last receipt determines the phase and pass/fail state; skipped
+    /// optional phases render as `skipped` and a spec with no receipts renders
+    /// as `not started`.
+    #[must_use]
+    pub fn from_receipts(receipts: &[Receipt]) -> Self {
+        match receipts.last() {
+            Some(receipt) if receipt.is_skipped() => Self {
+                message: format!("{} skipped", receipt.phase),
+                color: COLOR_UNKNOWN,
+            },
+            Some(receipt) if receipt.exit_code == 0 => Self {
+                message: format!("{} passing", receipt.phase),
+                color: COLOR_PASSING,
+            },
+            Some(receipt) => Self {
+                message: format!("{} failing", receipt.phase),
+                color: COLOR_FAILING,
+            },
+            None => Self {
+                message: "not started".to_string(),
+                color: COLOR_UNKNOWN,
+            },
+        }
+    }
+
+    /// Render the badge as a standalone SVG document
+    #[must_use]
+    pub fn to_svg(&self) -> String {
+        let label_width = segment_width(BADGE_LABEL);
+        let message_width = segment_width(&self.message);
+        let total_width = label_width + message_width;
+        let label_x = label_width * 5;
+        let message_x = (label_width * 10) + (message_width * 5);
+
+        format!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="{total_width}" height="20" role="img" aria-label="{label}: {message}">
+  <title>{label}: {message}</title>
+  <linearGradient id="s" x2="0" y2="100%">
+    <stop offset="0" stop-color="#bbb" stop-opacity=".1"/>
+    <stop offset="1" stop-opacity=".1"/>
+  </linearGradient>
+  <clipPath id="r"><rect width="{total_width}" height="20" rx="3" fill="#fff"/></clipPath>
+  <g clip-path="url(#r)">
+    <rect width="{label_width}" height="20" fill="#555"/>
+    <rect x="{label_width}" width="{message_width}" height="20" fill="{color}"/>
+    <rect width="{total_width}" height="20" fill="url(#s)"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="110">
+    <text x="{label_x}" y="140" transform="scale(.1)">{label}</text>
+    <text x="{message_x}" y="140" transform="scale(.1)">{message}</text>
+  </g>
+</svg>
+"##,
+            label = BADGE_LABEL,
+            message = escape_xml(&self.message),
+            color = self.color,
+        )
+    }
+
+    /// Static shields.io URL rendering the same badge
+    #[must_use]
+    pub fn shields_url(&self) -> String {
+        format!(
+            "https://img.shields.io/badge/{}-{}-{}",
+            shields_escape(BADGE_LABEL),
+            shields_escape(&self.message),
+            self.color.trim_start_matches('#')
+        )
+    }
+
+    /// Markdown image snippet for a README
+    ///
+    /// Points at `image` (e.g. a committed SVG path) when given, otherwise at
+    /// the equivalent shields.io URL.
+    #[must_use]
+    pub fn to_markdown(&self, spec_id: &str, image: Option<&str>) -> String {
+        let target = image.map_or_else(|| self.shields_url(), str::to_string);
+        format!("![{BADGE_LABEL}: {spec_id}]({target})")
+    }
+}
+
+/// Render and atomically write a spec's SVG badge to `path`
+pub fn write_badge(path: &Utf8Path, receipts: &[Receipt]) -> Result<()> {
+    write_file_atomic(path, &Badge::from_receipts(receipts).to_svg())?;
+    Ok(())
+}
+
+fn segment_width(text: &str) -> usize {
+    text.chars().count() * CHAR_WIDTH + SEGMENT_PADDING
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Escape text for a shields.io static badge path segment
+fn shields_escape(text: &str) -> String {
+    text.replace('-', "--")
+        .replace('_', "__")
+        .replace(' ', "%20")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PacketEvidence;
+    use chrono::{TimeZone, Utc};
+    use std::collections::HashMap;
+
+    fn receipt(phase: &str, exit_code: i32) -> Receipt {
+        Receipt {
+            schema_version: "1".to_string(),
+            emitted_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            spec_id: "spec-1".to_string(),
+            phase: phase.to_string(),
+            xchecker_version: "1.0.0".to_string(),
+            claude_cli_version: "0.8.1".to_string(),
+            model_full_name: "haiku".to_string(),
+            model_alias: None,
+            canonicalization_version: "yaml-v1,md-v1".to_string(),
+            canonicalization_backend: "jcs-rfc8785".to_string(),
+            flags: HashMap::new(),
+            runner: "native".to_string(),
+            runner_distro: None,
+            packet: PacketEvidence {
+                files: vec![],
+                max_bytes: 0,
+                max_lines: 0,
+                ..Default::default()
+            },
+            outputs: vec![],
+            exit_code,
+            error_kind: None,
+            error_reason: None,
+            stderr_tail: None,
+            stderr_redacted: None,
+            warnings: vec![],
+            fallback_used: None,
+            diff_context: None,
+            llm: None,
+            pipeline: None,
+            suggested_actions: vec![],
+            config_hash: None,
+            config_snapshot: None,
+        }
+    }
+
+    #[test]
+    fn test_badge_reflects_latest_receipt() {
+        let badge = Badge::from_receipts(&[receipt("requirements", 0), receipt("design", 1)]);
+        assert_eq!(badge.message, "design failing");
+        assert_eq!(badge.color, COLOR_FAILING);
+
+        let badge = Badge::from_receipts(&[receipt("requirements", 0)]);
+        assert_eq!(badge.message, "requirements passing");
+
+        let badge = Badge::from_receipts(&[]);
+        assert_eq!(badge.message, "not started");
+    }
+
+    #[test]
+    fn test_badge_svg_is_deterministic() {
+        let receipts = [receipt("tasks", 0)];
+        let svg = Badge::from_receipts(&receipts).to_svg();
+
+        assert_eq!(svg, Badge::from_receipts(&receipts).to_svg());
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains("xchecker: tasks passing"));
+    }
+
+    #[test]
+    fn test_badge_markdown_snippet() {
+        let badge = Badge::from_receipts(&[receipt("final", 0)]);
+
+        assert_eq!(
+            badge.to_markdown("my-spec", Some("docs/badge.svg")),
+            "![xchecker: my-spec](docs/badge.svg)"
+        );
+        assert_eq!(
+            badge.to_markdown("my-spec", None),
+            "![xchecker: my-spec](https://img.shields.io/badge/xchecker-final%20passing-4c1)"
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! Discovery of spec directories checked into a repository
+//!
+//! Specs normally live under `<XCHECKER_HOME>/specs/<id>`, but a repository
+//! can carry specs of its own: a committed `.xchecker/specs/<id>` tree, or any
+//! directory marked with a `spec.toml`. [`discover_specs`] walks a repository
+//! for both and reports each spec's latest completed phase and whether the
+//! home already knows about it; [`register_spec`] copies a discovered spec
+//! into the home so the rest of xchecker can find it by ID.
+
+use anyhow::{Context, Result, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use xchecker_utils::compression;
+
+use crate::artifact::ArtifactType;
+use crate::metadata::SPEC_METADATA_FILE;
+use crate::types::PhaseId;
+
+/// Directories never descended into while walking a repository
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules"];
+
+/// Per-spec lock files, never copied into the home on registration
+const LOCK_FILES: &[&str] = &[".lock", "flow.lock"];
+
+/// How a discovered spec relates to `<XCHECKER_HOME>/specs`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Registration {
+    /// The home spec directory is this directory
+    Registered,
+    /// No spec with this ID exists in the home
+    Unregistered,
+    /// The home has a different spec directory with the same ID
+    Conflict {
+        /// The existing home spec directory
+        existing: Utf8PathBuf,
+    },
+}
+
+impl Registration {
+    /// Stable name used in JSON output
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Registered => "registered",
+            Self::Unregistered => "unregistered",
+            Self::Conflict { .. } => "conflict",
+        }
+    }
+}
+
+/// A spec directory found inside a repository
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredSpec {
+    /// Spec ID (the directory name)
+    pub spec_id: String,
+    /// Path to the spec directory
+    pub path: Utf8PathBuf,
+    /// Latest phase with both Markdown and core YAML artifacts
+    pub latest_completed_phase: Option<PhaseId>,
+    /// Relationship to the home specs root
+    pub registration: Registration,
+}
+
+/// Nearest ancestor of `start` containing `.git`, or `start` itself
+#[must_use]
+pub fn repo_root(start: &Utf8Path) -> Utf8PathBuf {
+    start
+        .ancestors()
+        .find(|dir| dir.join(".git").exists())
+        .unwrap_or(start)
+        .to_path_buf()
+}
+
+/// Walk `repo_root` for spec directories, sorted by path
+///
+/// Every directory under a `.xchecker/specs/` directory is a spec, as is any
+/// directory containing `spec.toml`. Spec directories are not searched for
+/// nested specs. Hidden directories (except `.xchecker`), `target`, and
+/// `node_modules` are skipped.
+pub fn discover_specs(repo_root: &Utf8Path, specs_root: &Utf8Path) -> Result<Vec<DiscoveredSpec>> {
+    let mut dirs = Vec::new();
+    walk(repo_root, &mut dirs)?;
+    dirs.sort();
+    dirs.dedup();
+
+    let home_specs = specs_root.canonicalize_utf8().ok();
+    let mut specs = Vec::with_capacity(dirs.len());
+    for path in dirs {
+        let Some(spec_id) = path.file_name().map(str::to_string) else {
+            continue;
+        };
+        let registration = registration(&path, &spec_id, specs_root, home_specs.as_deref());
+        specs.push(DiscoveredSpec {
+            latest_completed_phase: latest_completed_phase(&path),
+            spec_id,
+            path,
+            registration,
+        });
+    }
+    Ok(specs)
+}
+
+fn walk(dir: &Utf8Path, found: &mut Vec<Utf8PathBuf>) -> Result<()> {
+    if dir.join(SPEC_METADATA_FILE).is_file() {
+        found.push(dir.to_path_buf());
+        return Ok(());
+    }
+
+    let entries = dir
+        .read_dir_utf8()
+        .with_context(|| format!("Failed to read directory: {dir}"))?;
+    for entry in entries {
+        let entry = entry?;
+        // Symlinks are not followed, so cycles cannot occur
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        let name = entry.file_name();
+        if name == ".xchecker" {
+            let specs = entry.path().join("specs");
+            if specs.is_dir() {
+                for spec in specs
+                    .read_dir_utf8()
+                    .with_context(|| format!("Failed to read directory: {specs}"))?
+                {
+                    let spec = spec?;
+                    if spec.file_type()?.is_dir() {
+                        found.push(spec.path().to_path_buf());
+                    }
+                }
+            }
+        } else if !name.starts_with('.') && !SKIPPED_DIRS.contains(&name) {
+            walk(entry.path(), found)?;
+        }
+    }
+    Ok(())
+}
+
+fn registration(
+    path: &Utf8Path,
+    spec_id: &str,
+    specs_root: &Utf8Path,
+    home_specs: Option<&Utf8Path>,
+) -> Registration {
+    let existing = specs_root.join(spec_id);
+    if !existing.exists() {
+        return Registration::Unregistered;
+    }
+    let same_dir = home_specs.is_some_and(|home| {
+        path.canonicalize_utf8()
+            .is_ok_and(|path| path == home.join(spec_id))
+    });
+    if same_dir {
+        Registration::Registered
+    } else {
+        Registration::Conflict { existing }
+    }
+}
+
+pub(crate) fn latest_completed_phase(spec_dir: &Utf8Path) -> Option<PhaseId> {
+    let artifacts = spec_dir.join("artifacts");
+    let stored = |artifact: ArtifactType, phase: PhaseId| {
+        compression::exists(
+            artifacts
+                .join(phase.artifact_name(artifact.extension()))
+                .as_std_path(),
+        )
+    };
+    PhaseId::ORDER.into_iter().rev().find(|&phase| {
+        stored(ArtifactType::Markdown, phase) && stored(ArtifactType::CoreYaml, phase)
+    })
+}
+
+/// Copy a discovered spec into `<specs_root>/<spec_id>`
+///
+/// Lock files are skipped. Fails without copying anything when the spec is
+/// not [`Registration::Unregistered`]. Returns the new home spec directory.
+pub fn register_spec(spec: &DiscoveredSpec, specs_root: &Utf8Path) -> Result<Utf8PathBuf> {
+    match &spec.registration {
+        Registration::Unregistered => {}
+        Registration::Registered => bail!("Spec '{}' is already registered", spec.spec_id),
+        Registration::Conflict { existing } => bail!(
+            "Spec '{}' already exists at {existing}; remove or rename one of them first",
+            spec.spec_id
+        ),
+    }
+
+    let target = specs_root.join(&spec.spec_id);
+    copy_dir(&spec.path, &target)
+        .with_context(|| format!("Failed to copy {} to {target}", spec.path))?;
+    Ok(target)
+}
+
+fn copy_dir(from: &Utf8Path, to: &Utf8Path) -> Result<()> {
+    crate::paths::ensure_dir_all(to)?;
+    for entry in from.read_dir_utf8()? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = to.join(entry.file_name());
+        if file_type.is_dir() {
+            copy_dir(entry.path(), &target)?;
+        } else if file_type.is_file() && !LOCK_FILES.contains(&entry.file_name()) {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn mkdir(path: &Utf8Path) {
+        std::fs::create_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn test_discover_specs_finds_markers_and_spec_trees() {
+        let temp = TempDir::new().unwrap();
+        let repo = Utf8Path::from_path(temp.path()).unwrap();
+        let home_specs = repo.join("home/specs");
+
+        let tree_spec = repo.join("service/.xchecker/specs/payments");
+        mkdir(&tree_spec.join("artifacts"));
+        for suffix in ["md", "core.yaml"] {
+            std::fs::write(
+                tree_spec
+                    .join("artifacts")
+                    .join(PhaseId::Design.artifact_name(suffix)),
+                "",
+            )
+            .unwrap();
+        }
+        let marked = repo.join("docs/specs/auth");
+        mkdir(&marked);
+        std::fs::write(marked.join(SPEC_METADATA_FILE), "title = \"Auth\"\n").unwrap();
+        mkdir(&repo.join("target/.xchecker/specs/ignored"));
+        mkdir(&repo.join(".hidden/.xchecker/specs/ignored"));
+        mkdir(&home_specs.join("auth"));
+
+        let specs = discover_specs(repo, &home_specs).unwrap();
+        let ids: Vec<&str> = specs.iter().map(|s| s.spec_id.as_str()).collect();
+        assert_eq!(ids, ["auth", "payments"]);
+
+        assert_eq!(
+            specs[0].registration,
+            Registration::Conflict {
+                existing: home_specs.join("auth")
+            }
+        );
+        assert_eq!(specs[0].latest_completed_phase, None);
+        assert_eq!(specs[1].registration, Registration::Unregistered);
+        assert_eq!(specs[1].latest_completed_phase, Some(PhaseId::Design));
+    }
+
+    #[test]
+    fn test_latest_completed_phase_counts_compressed_artifacts() {
+        let temp = TempDir::new().unwrap();
+        let spec = Utf8Path::from_path(temp.path()).unwrap();
+        mkdir(&spec.join("artifacts"));
+        for phase in [PhaseId::Requirements, PhaseId::Design] {
+            for suffix in ["md", "core.yaml"] {
+                std::fs::write(
+                    spec.join("artifacts")
+                        .join(format!("{}.zst", phase.artifact_name(suffix))),
+                    compression::compress(b"content", 3).unwrap(),
+                )
+                .unwrap();
+            }
+        }
+        assert_eq!(latest_completed_phase(spec), Some(PhaseId::Design));
+    }
+
+    #[test]
+    fn test_register_spec_copies_into_home() {
+        let temp = TempDir::new().unwrap();
+        let repo = Utf8Path::from_path(temp.path()).unwrap();
+        let home_specs = repo.join("home/specs");
+
+        let spec_dir = repo.join(".xchecker/specs/payments");
+        mkdir(&spec_dir.join("artifacts"));
+        std::fs::write(spec_dir.join("artifacts/00-requirements.md"), "# Req").unwrap();
+        std::fs::write(spec_dir.join(".lock"), "{}").unwrap();
+
+        let specs = discover_specs(repo, &home_specs).unwrap();
+        let target = register_spec(&specs[0], &home_specs).unwrap();
+        assert_eq!(target, home_specs.join("payments"));
+        assert!(target.join("artifacts/00-requirements.md").is_file());
+        assert!(!target.join(".lock").exists());
+
+        // The original is now shadowed by a home copy, not the same directory
+        let specs = discover_specs(repo, &home_specs).unwrap();
+        assert!(matches!(
+            specs[0].registration,
+            Registration::Conflict { .. }
+        ));
+        assert!(register_spec(&specs[0], &home_specs).is_err());
+    }
+
+    #[test]
+    fn test_spec_inside_home_is_registered() {
+        let temp = TempDir::new().unwrap();
+        let repo = Utf8Path::from_path(temp.path()).unwrap();
+        let home_specs = repo.join(".xchecker/specs");
+        mkdir(&home_specs.join("payments"));
+
+        let specs = discover_specs(repo, &home_specs).unwrap();
+        assert_eq!(specs.len(), 1);
+        assert_eq!(specs[0].registration, Registration::Registered);
+    }
+}
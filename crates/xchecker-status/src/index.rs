@@ -0,0 +1,273 @@
+//! Concurrent indexing of the specs under a specs root
+//!
+//! `status --all` has to visit every spec directory, which for a home holding
+//! thousands of specs is dominated by filesystem latency. [`index_specs`] lists
+//! the specs root on one thread and loads each spec on a small pool of worker
+//! threads, handing results to the caller as soon as they are ready so output
+//! can be streamed instead of collected.
+//!
+//! Resource use is bounded regardless of the number of specs: the lister holds
+//! one directory handle, each worker opens at most one file at a time, and the
+//! channels between them hold at most `workers` entries each. Indexing stops
+//! early when its [`CancelToken`] is cancelled or the caller's callback fails.
+
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+
+use crate::metadata::load_spec_metadata;
+use crate::types::{PhaseId, SpecMetadata};
+
+/// Upper bound on worker threads, and so on files open at once
+pub const MAX_WORKERS: usize = 16;
+
+/// Shared flag that stops an in-progress index
+///
+/// Clones share the flag, so one clone can be handed to a signal handler or
+/// another thread while [`index_specs`] holds the other.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    /// Create a token that is not cancelled
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ask every index using this token to stop
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether [`cancel`](Self::cancel) has been called
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// One spec loaded by [`index_specs`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedSpec {
+    /// Spec ID (the directory name)
+    pub spec_id: String,
+    /// Latest phase with both Markdown and core YAML artifacts
+    pub latest_completed_phase: Option<PhaseId>,
+    /// Contents of `spec.toml`, if present
+    pub metadata: Option<SpecMetadata>,
+}
+
+/// A spec directory (or the specs root itself) that could not be read
+#[derive(Debug)]
+pub struct IndexFailure {
+    /// Directory that failed
+    pub path: Utf8PathBuf,
+    /// What went wrong
+    pub error: anyhow::Error,
+}
+
+/// Outcome of an [`index_specs`] call
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IndexSummary {
+    /// Results handed to the callback, including failures
+    pub delivered: usize,
+    /// Whether indexing stopped before visiting every spec
+    pub cancelled: bool,
+}
+
+/// Default worker count: available parallelism, capped at [`MAX_WORKERS`]
+#[must_use]
+pub fn default_workers() -> usize {
+    thread::available_parallelism()
+        .map_or(1, std::num::NonZeroUsize::get)
+        .min(MAX_WORKERS)
+}
+
+/// Index every spec directory under `specs_root` on `workers` threads
+///
+/// `on_spec` runs on the calling thread once per spec, in completion order
+/// (not sorted). If it returns an error, indexing is cancelled and that error
+/// is returned. A missing specs root indexes nothing. `workers` is clamped to
+/// `1..=MAX_WORKERS`.
+pub fn index_specs<F>(
+    specs_root: &Utf8Path,
+    workers: usize,
+    cancel: &CancelToken,
+    mut on_spec: F,
+) -> Result<IndexSummary>
+where
+    F: FnMut(Result<IndexedSpec, IndexFailure>) -> Result<()>,
+{
+    if !specs_root.exists() {
+        return Ok(IndexSummary::default());
+    }
+    let entries = specs_root
+        .read_dir_utf8()
+        .with_context(|| format!("Failed to read specs directory: {specs_root}"))?;
+    let workers = workers.clamp(1, MAX_WORKERS);
+
+    let (dir_tx, dir_rx) = mpsc::sync_channel::<Utf8PathBuf>(workers);
+    // Workers own the receiver, so the lister unblocks once they have all exited
+    let dir_rx = Arc::new(Mutex::new(dir_rx));
+    let (result_tx, result_rx) = mpsc::sync_channel(workers);
+
+    thread::scope(|scope| {
+        let lister_tx = result_tx.clone();
+        scope.spawn(move || {
+            for entry in entries {
+                if cancel.is_cancelled() {
+                    break;
+                }
+                let sent = match entry.and_then(|entry| Ok((entry.file_type()?, entry))) {
+                    Ok((file_type, entry)) if file_type.is_dir() => {
+                        dir_tx.send(entry.into_path()).is_ok()
+                    }
+                    Ok(_) => true,
+                    Err(e) => lister_tx
+                        .send(Err(IndexFailure {
+                            path: specs_root.to_path_buf(),
+                            error: anyhow::Error::new(e)
+                                .context(format!("Failed to read specs directory: {specs_root}")),
+                        }))
+                        .is_ok(),
+                };
+                if !sent {
+                    break;
+                }
+            }
+        });
+
+        for _ in 0..workers {
+            let dir_rx = Arc::clone(&dir_rx);
+            let result_tx = result_tx.clone();
+            scope.spawn(move || {
+                loop {
+                    let next = dir_rx.lock().ok().and_then(|rx| rx.recv().ok());
+                    let Some(dir) = next else { break };
+                    if cancel.is_cancelled() || result_tx.send(index_spec(dir)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(dir_rx);
+        drop(result_tx);
+
+        let mut summary = IndexSummary::default();
+        // Consuming the receiver drops it on early exit, which releases any
+        // worker blocked on a full channel
+        for result in result_rx {
+            if cancel.is_cancelled() {
+                break;
+            }
+            if let Err(e) = on_spec(result) {
+                cancel.cancel();
+                return Err(e);
+            }
+            summary.delivered += 1;
+        }
+        summary.cancelled = cancel.is_cancelled();
+        Ok(summary)
+    })
+}
+
+fn index_spec(dir: Utf8PathBuf) -> Result<IndexedSpec, IndexFailure> {
+    let spec_id = dir.file_name().unwrap_or_default().to_string();
+    match load_spec_metadata(&dir) {
+        Ok(metadata) => Ok(IndexedSpec {
+            latest_completed_phase: crate::discover::latest_completed_phase(&dir),
+            spec_id,
+            metadata,
+        }),
+        Err(error) => Err(IndexFailure {
+            error: error.context(format!("Failed to index spec '{spec_id}'")),
+            path: dir,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn make_specs(root: &Utf8Path, count: usize) {
+        for i in 0..count {
+            let spec = root.join(format!("spec-{i:03}"));
+            std::fs::create_dir_all(spec.join("artifacts")).unwrap();
+            if i % 2 == 0 {
+                for suffix in ["md", "core.yaml"] {
+                    std::fs::write(
+                        spec.join("artifacts")
+                            .join(PhaseId::Requirements.artifact_name(suffix)),
+                        "",
+                    )
+                    .unwrap();
+                }
+            }
+        }
+        std::fs::write(root.join("stray-file"), "").unwrap();
+    }
+
+    #[test]
+    fn test_index_specs_visits_every_spec() {
+        let temp = TempDir::new().unwrap();
+        let root = Utf8Path::from_path(temp.path()).unwrap();
+        make_specs(root, 40);
+        std::fs::write(root.join("spec-001/spec.toml"), "tags = [\"payments\"]\n").unwrap();
+
+        let mut specs = Vec::new();
+        let summary = index_specs(root, 4, &CancelToken::new(), |result| {
+            specs.push(result.unwrap());
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(summary.delivered, 40);
+        assert!(!summary.cancelled);
+        specs.sort_by(|a, b| a.spec_id.cmp(&b.spec_id));
+        assert_eq!(specs[0].latest_completed_phase, Some(PhaseId::Requirements));
+        assert_eq!(specs[1].latest_completed_phase, None);
+        assert_eq!(specs[1].metadata.as_ref().unwrap().tags, ["payments"]);
+        assert!(specs[0].metadata.is_none());
+    }
+
+    #[test]
+    fn test_index_specs_stops_when_cancelled() {
+        let temp = TempDir::new().unwrap();
+        let root = Utf8Path::from_path(temp.path()).unwrap();
+        make_specs(root, 100);
+
+        let cancel = CancelToken::new();
+        let summary = index_specs(root, 2, &cancel, |_| {
+            cancel.cancel();
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(summary.delivered, 1);
+        assert!(summary.cancelled);
+    }
+
+    #[test]
+    fn test_index_specs_callback_error_is_returned() {
+        let temp = TempDir::new().unwrap();
+        let root = Utf8Path::from_path(temp.path()).unwrap();
+        make_specs(root, 10);
+        std::fs::write(root.join("spec-003/spec.toml"), "tags = 1\n").unwrap();
+
+        let cancel = CancelToken::new();
+        let err = index_specs(root, 3, &cancel, |result| {
+            result.map(drop).map_err(|failure| failure.error)
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("spec-003"));
+        assert!(cancel.is_cancelled());
+
+        let missing = root.join("missing");
+        let summary = index_specs(&missing, 3, &CancelToken::new(), |_| Ok(())).unwrap();
+        assert_eq!(summary, IndexSummary::default());
+    }
+}
@@ -6,6 +6,13 @@
 //! # Modules
 //!
 //! - [`artifact`] - Artifact management with atomic writes and directory structure
+//! - [`badge`] - README status badge rendering
+//! - [`discover`] - Discovery of spec directories checked into a repository
+//! - [`index`] - Concurrent, cancellable indexing of every spec in a specs root
+//! - [`metadata`] - Spec metadata (`spec.toml`) loading and filtering
+//! - [`next_steps`] - Next-action recommendations from the phase graph and receipts
+//! - [`partials`] - Partial files left by failed or interrupted runs
+//! - [`site`] - Static HTML export for read-only dashboards
 //! - [`status`] - Status output generation
 
 pub use xchecker_receipt as receipt;
@@ -18,4 +25,11 @@ pub use xchecker_utils::paths;
 pub use xchecker_utils::types;
 
 pub mod artifact;
+pub mod badge;
+pub mod discover;
+pub mod index;
+pub mod metadata;
+pub mod next_steps;
+pub mod partials;
+pub mod site;
 pub mod status;
@@ -0,0 +1,256 @@
+//! Spec metadata (`spec.toml`) loading and filtering
+//!
+//! A spec directory may contain an optional `spec.toml` describing the spec:
+//!
+//! ```toml
+//! title = "Card payments retry"
+//! owners = ["payments-team"]
+//! tags = ["payments", "backend"]
+//! links = ["https://tracker.example.com/PAY-123"]
+//! ```
+//!
+//! Metadata is surfaced in status output and used to select specs with
+//! `--filter key=value` (`tag`, `owner`, or `title`). Destructive commands
+//! ask for confirmation (or `--yes`) when the invoking user is not one of the
+//! spec's `owners`; see [`foreign_owners`].
+
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::types::SpecMetadata;
+
+/// File name of the metadata file inside a spec directory
+pub const SPEC_METADATA_FILE: &str = "spec.toml";
+
+/// Load `spec.toml` from a spec directory, if present
+pub fn load_spec_metadata(spec_dir: &Utf8Path) -> Result<Option<SpecMetadata>> {
+    let path = spec_dir.join(SPEC_METADATA_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content =
+        std::fs::read_to_string(&path).with_context(|| format!("Failed to read {path}"))?;
+    let metadata = toml::from_str(&content).with_context(|| format!("Invalid {path}"))?;
+    Ok(Some(metadata))
+}
+
+/// List spec IDs under a specs root (`<XCHECKER_HOME>/specs`), sorted
+///
+/// Returns an empty list when the root does not exist.
+pub fn list_spec_ids(specs_root: &Utf8Path) -> Result<Vec<String>> {
+    if !specs_root.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut ids = Vec::new();
+    for entry in specs_root
+        .read_dir_utf8()
+        .with_context(|| format!("Failed to read specs directory: {specs_root}"))?
+    {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            ids.push(entry.file_name().to_string());
+        }
+    }
+    ids.sort();
+    Ok(ids)
+}
+
+/// Default specs root: `<XCHECKER_HOME>/specs`
+#[must_use]
+pub fn specs_root() -> Utf8PathBuf {
+    crate::paths::xchecker_home().join("specs")
+}
+
+/// Name of the invoking user (`$USER`, falling back to `$USERNAME`)
+#[must_use]
+pub fn invoking_user() -> Option<String> {
+    ["USER", "USERNAME"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|user| !user.is_empty())
+}
+
+/// Owners of a spec that `user` does not belong to
+///
+/// Returns `None` when the spec lists no owners or `user` is one of them;
+/// otherwise returns the owner list so callers can ask for confirmation.
+/// This is a guard against accidents in shared environments, not access
+/// control: anyone can pass `--yes`.
+#[must_use]
+pub fn foreign_owners<'a>(
+    metadata: Option<&'a SpecMetadata>,
+    user: Option<&str>,
+) -> Option<&'a [String]> {
+    let owners = metadata.map(|m| m.owners.as_slice()).unwrap_or_default();
+    if owners.is_empty() || user.is_some_and(|user| owners.iter().any(|owner| owner == user)) {
+        return None;
+    }
+    Some(owners)
+}
+
+/// Metadata field a [`SpecFilter`] matches against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecFilterKey {
+    /// Matches any entry of `tags`
+    Tag,
+    /// Matches any entry of `owners`
+    Owner,
+    /// Case-insensitive substring of `title`
+    Title,
+}
+
+/// A `key=value` filter over spec metadata (e.g. `tag=payments`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecFilter {
+    /// Field to match
+    pub key: SpecFilterKey,
+    /// Value to match
+    pub value: String,
+}
+
+impl SpecFilter {
+    /// Whether `metadata` satisfies this filter; specs without metadata never match
+    #[must_use]
+    pub fn matches(&self, metadata: Option<&SpecMetadata>) -> bool {
+        let Some(metadata) = metadata else {
+            return false;
+        };
+        match self.key {
+            SpecFilterKey::Tag => metadata.tags.iter().any(|tag| tag == &self.value),
+            SpecFilterKey::Owner => metadata.owners.iter().any(|owner| owner == &self.value),
+            SpecFilterKey::Title => metadata
+                .title
+                .as_ref()
+                .is_some_and(|title| title.to_lowercase().contains(&self.value.to_lowercase())),
+        }
+    }
+
+    /// Whether `metadata` satisfies every filter in `filters`
+    #[must_use]
+    pub fn matches_all(filters: &[Self], metadata: Option<&SpecMetadata>) -> bool {
+        filters.iter().all(|filter| filter.matches(metadata))
+    }
+}
+
+impl FromStr for SpecFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected key=value, got '{s}'"))?;
+        let key = match key.trim() {
+            "tag" => SpecFilterKey::Tag,
+            "owner" => SpecFilterKey::Owner,
+            "title" => SpecFilterKey::Title,
+            other => {
+                return Err(format!(
+                    "unknown filter key '{other}' (expected tag, owner, or title)"
+                ));
+            }
+        };
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(format!("filter '{s}' has an empty value"));
+        }
+        Ok(Self {
+            key,
+            value: value.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for SpecFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key = match self.key {
+            SpecFilterKey::Tag => "tag",
+            SpecFilterKey::Owner => "owner",
+            SpecFilterKey::Title => "title",
+        };
+        write!(f, "{key}={}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn metadata() -> SpecMetadata {
+        SpecMetadata {
+            title: Some("Card Payments Retry".to_string()),
+            owners: vec!["payments-team".to_string()],
+            tags: vec!["payments".to_string(), "backend".to_string()],
+            links: vec![],
+        }
+    }
+
+    #[test]
+    fn test_load_spec_metadata() {
+        let temp = TempDir::new().unwrap();
+        let dir = Utf8Path::from_path(temp.path()).unwrap();
+        assert_eq!(load_spec_metadata(dir).unwrap(), None);
+
+        std::fs::write(
+            dir.join(SPEC_METADATA_FILE),
+            "title = \"Card Payments Retry\"\nowners = [\"payments-team\"]\ntags = [\"payments\", \"backend\"]\n",
+        )
+        .unwrap();
+        assert_eq!(load_spec_metadata(dir).unwrap(), Some(metadata()));
+
+        std::fs::write(dir.join(SPEC_METADATA_FILE), "tags = \"not-a-list\"\n").unwrap();
+        assert!(load_spec_metadata(dir).is_err());
+    }
+
+    #[test]
+    fn test_filter_parse_and_match() {
+        let tag: SpecFilter = "tag=payments".parse().unwrap();
+        let owner: SpecFilter = "owner=search-team".parse().unwrap();
+        let title: SpecFilter = "title=payments".parse().unwrap();
+
+        assert!(tag.matches(Some(&metadata())));
+        assert!(!owner.matches(Some(&metadata())));
+        assert!(title.matches(Some(&metadata())));
+        assert!(!tag.matches(None));
+        assert!(!SpecFilter::matches_all(&[tag, owner], Some(&metadata())));
+
+        assert!("tag".parse::<SpecFilter>().is_err());
+        assert!("color=red".parse::<SpecFilter>().is_err());
+        assert!("tag=".parse::<SpecFilter>().is_err());
+    }
+
+    #[test]
+    fn test_foreign_owners() {
+        let metadata = metadata();
+        assert_eq!(foreign_owners(None, Some("alice")), None);
+        assert_eq!(
+            foreign_owners(Some(&SpecMetadata::default()), Some("alice")),
+            None
+        );
+        assert_eq!(foreign_owners(Some(&metadata), Some("payments-team")), None);
+        assert_eq!(
+            foreign_owners(Some(&metadata), Some("alice")),
+            Some(&["payments-team".to_string()][..])
+        );
+        assert_eq!(
+            foreign_owners(Some(&metadata), None),
+            Some(&["payments-team".to_string()][..])
+        );
+    }
+
+    #[test]
+    fn test_list_spec_ids_sorted() {
+        let temp = TempDir::new().unwrap();
+        let root = Utf8Path::from_path(temp.path()).unwrap();
+        assert!(list_spec_ids(&root.join("missing")).unwrap().is_empty());
+
+        std::fs::create_dir(root.join("b-spec")).unwrap();
+        std::fs::create_dir(root.join("a-spec")).unwrap();
+        std::fs::write(root.join("stray.txt"), "").unwrap();
+        assert_eq!(list_spec_ids(root).unwrap(), vec!["a-spec", "b-spec"]);
+    }
+}
@@ -0,0 +1,285 @@
+//! Next-action recommendations after a run
+//!
+//! Walks the phase graph in [`PhaseId::ORDER`] against the latest receipt of
+//! each phase and recommends what to do next: inspect and retry the first
+//! failed phase, rerun a phase whose dependency has been regenerated since,
+//! run the first phase that hasn't run, or resolve review findings before
+//! fixup. Fixup is only recommended while the review has pending changes;
+//! localize is opt-in and only recommended once it has run.
+
+use chrono::{DateTime, Utc};
+
+use crate::types::{NextStep, PhaseId, Receipt};
+
+/// Outcome of a phase's latest run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PhaseState {
+    NotStarted,
+    Failed,
+    /// Succeeded or was skipped at this time
+    Done(DateTime<Utc>),
+}
+
+/// Recommended next steps for `spec_id`, most pressing first
+///
+/// `receipts` may be in any order; `pending_fixups` is the number of files the
+/// latest review wants changed.
+#[must_use]
+pub fn recommend(spec_id: &str, receipts: &[Receipt], pending_fixups: u32) -> Vec<NextStep> {
+    let state = |phase: PhaseId| latest_state(receipts, phase);
+
+    for phase in PhaseId::ORDER {
+        let name = phase.as_str();
+        match state(phase) {
+            PhaseState::Failed => {
+                return vec![
+                    step(
+                        format!("See why the {name} phase failed and how to fix it"),
+                        format!("xchecker explain {spec_id} --phase {name}"),
+                    ),
+                    step(
+                        format!("Retry the {name} phase"),
+                        format!("xchecker resume {spec_id} --phase {name}"),
+                    ),
+                ];
+            }
+            _ if phase == PhaseId::Fixup && pending_fixups == 0 => {}
+            PhaseState::NotStarted if phase == PhaseId::Localize => {}
+            PhaseState::NotStarted if phase == PhaseId::Requirements => {
+                return vec![step(
+                    "Generate requirements from a problem statement".to_string(),
+                    format!("xchecker spec {spec_id}"),
+                )];
+            }
+            PhaseState::NotStarted if phase == PhaseId::Fixup => {
+                return fixup_steps(spec_id, pending_fixups);
+            }
+            PhaseState::NotStarted => {
+                return vec![step(
+                    format!("Continue to the {name} phase"),
+                    format!("xchecker resume {spec_id} --phase {name}"),
+                )];
+            }
+            PhaseState::Done(at) => {
+                let stale_dep = deps(phase)
+                    .iter()
+                    .copied()
+                    .find(|&dep| matches!(state(dep), PhaseState::Done(dep_at) if dep_at > at));
+                if let Some(dep) = stale_dep {
+                    if phase == PhaseId::Fixup {
+                        return fixup_steps(spec_id, pending_fixups);
+                    }
+                    return vec![step(
+                        format!(
+                            "Rerun the {name} phase: {} was regenerated after it",
+                            dep.as_str()
+                        ),
+                        format!("xchecker resume {spec_id} --phase {name}"),
+                    )];
+                }
+            }
+        }
+    }
+
+    vec![step(
+        "Every phase is complete; check the spec against your gate policy".to_string(),
+        format!("xchecker gate {spec_id}"),
+    )]
+}
+
+/// Review findings must be resolved before fixup applies them
+fn fixup_steps(spec_id: &str, pending_fixups: u32) -> Vec<NextStep> {
+    let files = if pending_fixups == 1 { "file" } else { "files" };
+    vec![
+        NextStep {
+            summary: format!(
+                "Resolve the review findings: the review proposes changes to {pending_fixups} {files}"
+            ),
+            command: None,
+        },
+        step(
+            "Preview the proposed changes".to_string(),
+            format!("xchecker resume {spec_id} --phase fixup"),
+        ),
+        step(
+            "Apply them".to_string(),
+            format!("xchecker resume {spec_id} --phase fixup --apply-fixups"),
+        ),
+    ]
+}
+
+/// Phases whose artifacts `phase` is generated from (mirrors each phase's `deps()`)
+fn deps(phase: PhaseId) -> &'static [PhaseId] {
+    match phase {
+        PhaseId::Requirements => &[],
+        PhaseId::Design => &[PhaseId::Requirements],
+        PhaseId::Tasks => &[PhaseId::Design],
+        PhaseId::Review | PhaseId::Final => &[PhaseId::Tasks],
+        PhaseId::Fixup => &[PhaseId::Review],
+        PhaseId::Localize => &[PhaseId::Final],
+    }
+}
+
+fn latest_state(receipts: &[Receipt], phase: PhaseId) -> PhaseState {
+    let latest = receipts
+        .iter()
+        .filter(|r| r.phase == phase.as_str())
+        .max_by_key(|r| r.emitted_at);
+    match latest {
+        None => PhaseState::NotStarted,
+        Some(r) if r.exit_code == 0 || r.is_skipped() => PhaseState::Done(r.emitted_at),
+        Some(_) => PhaseState::Failed,
+    }
+}
+
+fn step(summary: String, command: String) -> NextStep {
+    NextStep {
+        summary,
+        command: Some(command),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PacketEvidence;
+    use chrono::TimeZone;
+    use std::collections::HashMap;
+
+    fn receipt(phase: PhaseId, exit_code: i32, minute: u32) -> Receipt {
+        Receipt {
+            schema_version: "1".to_string(),
+            emitted_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, minute, 0).unwrap(),
+            spec_id: "demo".to_string(),
+            phase: phase.as_str().to_string(),
+            xchecker_version: "1.0.0".to_string(),
+            claude_cli_version: "0.8.1".to_string(),
+            model_full_name: "haiku".to_string(),
+            model_alias: None,
+            canonicalization_version: "yaml-v1,md-v1".to_string(),
+            canonicalization_backend: "jcs-rfc8785".to_string(),
+            flags: HashMap::new(),
+            runner: "native".to_string(),
+            runner_distro: None,
+            packet: PacketEvidence {
+                files: vec![],
+                max_bytes: 0,
+                max_lines: 0,
+                ..Default::default()
+            },
+            outputs: vec![],
+            exit_code,
+            error_kind: None,
+            error_reason: None,
+            stderr_tail: None,
+            stderr_redacted: None,
+            warnings: vec![],
+            fallback_used: None,
+            diff_context: None,
+            llm: None,
+            pipeline: None,
+            suggested_actions: vec![],
+            config_hash: None,
+            config_snapshot: None,
+        }
+    }
+
+    fn commands(steps: &[NextStep]) -> Vec<&str> {
+        steps.iter().filter_map(|s| s.command.as_deref()).collect()
+    }
+
+    #[test]
+    fn test_new_spec_starts_with_requirements() {
+        let steps = recommend("demo", &[], 0);
+        assert_eq!(commands(&steps), ["xchecker spec demo"]);
+    }
+
+    #[test]
+    fn test_next_unrun_phase_follows_the_graph() {
+        let receipts = [
+            receipt(PhaseId::Requirements, 0, 1),
+            receipt(PhaseId::Design, 0, 2),
+        ];
+        let steps = recommend("demo", &receipts, 0);
+        assert_eq!(commands(&steps), ["xchecker resume demo --phase tasks"]);
+    }
+
+    #[test]
+    fn test_failed_phase_is_explained_then_retried() {
+        let receipts = [
+            receipt(PhaseId::Requirements, 0, 1),
+            receipt(PhaseId::Design, 70, 2),
+        ];
+        let steps = recommend("demo", &receipts, 0);
+        assert_eq!(
+            commands(&steps),
+            [
+                "xchecker explain demo --phase design",
+                "xchecker resume demo --phase design"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_regenerated_dependency_makes_phase_stale() {
+        let receipts = [
+            receipt(PhaseId::Requirements, 0, 1),
+            receipt(PhaseId::Design, 0, 2),
+            receipt(PhaseId::Requirements, 0, 3),
+        ];
+        let steps = recommend("demo", &receipts, 0);
+        assert_eq!(commands(&steps), ["xchecker resume demo --phase design"]);
+        assert!(steps[0].summary.contains("requirements was regenerated"));
+    }
+
+    #[test]
+    fn test_pending_fixups_come_before_final() {
+        let receipts = [
+            receipt(PhaseId::Requirements, 0, 1),
+            receipt(PhaseId::Design, 0, 2),
+            receipt(PhaseId::Tasks, 0, 3),
+            receipt(PhaseId::Review, 0, 4),
+        ];
+        let steps = recommend("demo", &receipts, 2);
+        assert!(steps[0].summary.contains("2 files"));
+        assert!(steps[0].command.is_none());
+        assert_eq!(
+            commands(&steps),
+            [
+                "xchecker resume demo --phase fixup",
+                "xchecker resume demo --phase fixup --apply-fixups"
+            ]
+        );
+
+        let steps = recommend("demo", &receipts, 0);
+        assert_eq!(commands(&steps), ["xchecker resume demo --phase final"]);
+    }
+
+    #[test]
+    fn test_complete_spec_points_at_gate() {
+        let receipts: Vec<_> = PhaseId::ORDER
+            .iter()
+            .zip(1..)
+            .map(|(&phase, minute)| receipt(phase, 0, minute))
+            .collect();
+        let steps = recommend("demo", &receipts, 0);
+        assert_eq!(commands(&steps), ["xchecker gate demo"]);
+    }
+
+    #[test]
+    fn test_localize_is_recommended_only_after_it_has_run() {
+        let mut receipts: Vec<_> = PhaseId::ORDER
+            .iter()
+            .filter(|&&phase| phase != PhaseId::Localize)
+            .zip(1..)
+            .map(|(&phase, minute)| receipt(phase, 0, minute))
+            .collect();
+        let steps = recommend("demo", &receipts, 0);
+        assert_eq!(commands(&steps), ["xchecker gate demo"]);
+
+        receipts.push(receipt(PhaseId::Localize, 0, 10));
+        receipts.push(receipt(PhaseId::Final, 0, 11));
+        let steps = recommend("demo", &receipts, 0);
+        assert_eq!(commands(&steps), ["xchecker resume demo --phase localize"]);
+    }
+}
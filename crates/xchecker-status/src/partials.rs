@@ -0,0 +1,209 @@
+//! Detection and cleanup of partial files left by failed or interrupted runs
+//!
+//! A phase stages its outputs in `.partial/` and keeps `artifacts/*.partial.md`
+//! when the provider fails mid-response. Both are normally cleared when the
+//! next run of the phase starts; until then [`stale_partials`] lists them for
+//! `xchecker status` with their age and, when a receipt was written after
+//! them, the run that left them. [`clean_partials`] removes them on request.
+
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use chrono::{DateTime, Duration, Utc};
+
+use crate::types::{PhaseId, Receipt, StalePartial};
+use xchecker_utils::compression;
+
+/// Staging directory for a phase's outputs before promotion
+const STAGING_DIR: &str = ".partial";
+
+/// Suffix of partial artifacts kept from failed phases
+const PARTIAL_SUFFIX: &str = ".partial.md";
+
+/// How long after a partial file its run's receipt may be written and still
+/// be attributed to it
+const ORIGIN_WINDOW_SECS: i64 = 300;
+
+/// Partial files under the spec at `spec_root`, sorted by path
+///
+/// `receipts` are the spec's receipts in any order; the first receipt of the
+/// same phase written within five minutes after a file names its run.
+pub fn stale_partials(
+    spec_root: &Utf8Path,
+    receipts: &[Receipt],
+    now: DateTime<Utc>,
+) -> Result<Vec<StalePartial>> {
+    let mut partials = Vec::new();
+    for path in partial_paths(spec_root)? {
+        let modified: DateTime<Utc> = std::fs::metadata(spec_root.join(&path))
+            .and_then(|meta| meta.modified())
+            .with_context(|| format!("Failed to read modification time of {path}"))?
+            .into();
+        let name = compression::logical_name(path.file_name().unwrap_or_default());
+        let phase = PhaseId::parse_artifact_name(name).map(|(_, phase, _)| phase);
+
+        partials.push(StalePartial {
+            path: path.to_string(),
+            phase: phase.map(|phase| phase.as_str().to_string()),
+            modified_at: modified,
+            age_secs: u64::try_from((now - modified).num_seconds()).unwrap_or(0),
+            run_id: phase.and_then(|phase| origin_run(phase, modified, receipts)),
+        });
+    }
+    Ok(partials)
+}
+
+/// Remove every partial file under the spec at `spec_root`
+///
+/// Returns the removed paths relative to `spec_root`. The emptied `.partial/`
+/// directory is left in place.
+pub fn clean_partials(spec_root: &Utf8Path) -> Result<Vec<Utf8PathBuf>> {
+    let paths = partial_paths(spec_root)?;
+    for path in &paths {
+        let full = spec_root.join(path);
+        std::fs::remove_file(&full).with_context(|| format!("Failed to remove {full}"))?;
+    }
+    Ok(paths)
+}
+
+/// Paths, relative to `spec_root`, of staged files and partial artifacts
+fn partial_paths(spec_root: &Utf8Path) -> Result<Vec<Utf8PathBuf>> {
+    let mut paths = Vec::new();
+    for (dir, partial_only) in [(STAGING_DIR, false), ("artifacts", true)] {
+        let full = spec_root.join(dir);
+        if !full.is_dir() {
+            continue;
+        }
+        for entry in full
+            .read_dir_utf8()
+            .with_context(|| format!("Failed to read {full}"))?
+        {
+            let entry = entry?;
+            if !entry.file_type()?.is_file() {
+                continue;
+            }
+            let name = entry.file_name();
+            if partial_only && !compression::logical_name(name).ends_with(PARTIAL_SUFFIX) {
+                continue;
+            }
+            paths.push(Utf8PathBuf::from(dir).join(name));
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// `run_id` of the first receipt of `phase` written shortly after `modified`
+fn origin_run(phase: PhaseId, modified: DateTime<Utc>, receipts: &[Receipt]) -> Option<String> {
+    // Modification times can trail the receipt's timestamp by a moment
+    let earliest = modified - Duration::seconds(1);
+    let latest = modified + Duration::seconds(ORIGIN_WINDOW_SECS);
+    receipts
+        .iter()
+        .filter(|receipt| receipt.phase == phase.as_str())
+        .filter(|receipt| receipt.emitted_at >= earliest && receipt.emitted_at <= latest)
+        .min_by_key(|receipt| receipt.emitted_at)
+        .and_then(|receipt| receipt.flags.get("run_id").cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PacketEvidence;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn receipt(phase: PhaseId, emitted_at: DateTime<Utc>, run_id: &str) -> Receipt {
+        Receipt {
+            schema_version: "1".to_string(),
+            emitted_at,
+            spec_id: "demo".to_string(),
+            phase: phase.as_str().to_string(),
+            xchecker_version: "1.0.0".to_string(),
+            claude_cli_version: "0.8.1".to_string(),
+            model_full_name: "haiku".to_string(),
+            model_alias: None,
+            canonicalization_version: "yaml-v1,md-v1".to_string(),
+            canonicalization_backend: "jcs-rfc8785".to_string(),
+            flags: HashMap::from([("run_id".to_string(), run_id.to_string())]),
+            runner: "native".to_string(),
+            runner_distro: None,
+            packet: PacketEvidence {
+                files: vec![],
+                max_bytes: 0,
+                max_lines: 0,
+                ..Default::default()
+            },
+            outputs: vec![],
+            exit_code: 70,
+            error_kind: None,
+            error_reason: None,
+            stderr_tail: None,
+            stderr_redacted: None,
+            warnings: vec![],
+            fallback_used: None,
+            diff_context: None,
+            llm: None,
+            pipeline: None,
+            suggested_actions: vec![],
+            config_hash: None,
+            config_snapshot: None,
+        }
+    }
+
+    #[test]
+    fn test_stale_partials_report_phase_age_and_origin_run() {
+        let temp = TempDir::new().unwrap();
+        let root = Utf8Path::from_path(temp.path()).unwrap();
+        std::fs::create_dir_all(root.join(".partial")).unwrap();
+        std::fs::create_dir_all(root.join("artifacts")).unwrap();
+        std::fs::write(root.join(".partial/10-design.md"), "# Design").unwrap();
+        std::fs::write(root.join("artifacts/00-requirements.partial.md"), "# Req").unwrap();
+        std::fs::write(root.join("artifacts/00-requirements.md"), "# Req").unwrap();
+
+        let modified: DateTime<Utc> = std::fs::metadata(root.join(".partial/10-design.md"))
+            .unwrap()
+            .modified()
+            .unwrap()
+            .into();
+        let receipts = [
+            receipt(
+                PhaseId::Design,
+                modified - Duration::hours(1),
+                "design-run-0",
+            ),
+            receipt(
+                PhaseId::Design,
+                modified + Duration::seconds(2),
+                "design-run-1",
+            ),
+        ];
+
+        let now = modified + Duration::seconds(90);
+        let partials = stale_partials(root, &receipts, now).unwrap();
+        let summary: Vec<_> = partials
+            .iter()
+            .map(|p| (p.path.as_str(), p.phase.as_deref(), p.run_id.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    ".partial/10-design.md",
+                    Some("design"),
+                    Some("design-run-1")
+                ),
+                (
+                    "artifacts/00-requirements.partial.md",
+                    Some("requirements"),
+                    None
+                ),
+            ]
+        );
+        assert!(partials[0].age_secs >= 89);
+
+        let removed = clean_partials(root).unwrap();
+        assert_eq!(removed.len(), 2);
+        assert!(stale_partials(root, &[], now).unwrap().is_empty());
+        assert!(root.join("artifacts/00-requirements.md").exists());
+    }
+}
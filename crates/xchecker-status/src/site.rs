@@ -0,0 +1,426 @@
+//! Static HTML export of a spec for read-only dashboards
+//!
+//! `xchecker export-site` mirrors a spec into a self-contained directory that
+//! can be published as-is (e.g. to an internal GitHub Pages site): an index
+//! with the current status, per-phase token usage, the health score and trend
+//! charts, and a receipt log, plus one page per artifact. Everything is
+//! derived from the spec's artifacts and receipts only; packets and context
+//! files, which carry unredacted inputs, are never exported. The same spec
+//! state always renders the same bytes, so re-exporting an unchanged spec
+//! yields an empty diff (unless the health score's recency has decayed since).
+
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::fmt::Write as _;
+
+use crate::atomic_write::write_file_atomic;
+use crate::badge::Badge;
+use crate::receipt::ReceiptManager;
+use crate::receipt::usage::token_usage;
+use crate::types::{Receipt, SpecHealth};
+use xchecker_utils::compression;
+
+const COLOR_PASSING: &str = "#4c1";
+const COLOR_FAILING: &str = "#e05d44";
+const COLOR_SKIPPED: &str = "#9f9f9f";
+
+const CHART_WIDTH: usize = 640;
+const CHART_HEIGHT: usize = 160;
+
+const STYLE: &str = "body{font-family:system-ui,sans-serif;margin:2rem auto;max-width:960px;\
+padding:0 1rem;color:#222}table{border-collapse:collapse;width:100%;margin-bottom:1.5rem}\
+th,td{border:1px solid #ddd;padding:.3rem .5rem;text-align:left;font-size:.9rem}\
+th{background:#f5f5f5}pre{background:#f8f8f8;padding:1rem;overflow-x:auto;\
+white-space:pre-wrap}.pass{color:#2a7d0f}.fail{color:#c0392b}.muted{color:#777}";
+
+/// Export the spec at `spec_root` as a static site under `out`
+///
+/// Writes `index.html`, `badge.svg`, and for each artifact both a raw copy
+/// and an HTML view under `artifacts/`. `health`, when given, heads the
+/// trends section. Returns the written paths relative to `out`. The spec
+/// directory is only read.
+pub fn export_site(
+    spec_id: &str,
+    spec_root: &Utf8Path,
+    out: &Utf8Path,
+    health: Option<&SpecHealth>,
+) -> Result<Vec<Utf8PathBuf>> {
+    let receipts = ReceiptManager::new(&spec_root.to_path_buf())
+        .list_receipts()
+        .unwrap_or_default();
+    let artifacts = read_artifacts(&spec_root.join("artifacts"))?;
+
+    let mut written = Vec::new();
+    let mut write = |relative: Utf8PathBuf, content: &str| -> Result<()> {
+        let path = out.join(&relative);
+        write_file_atomic(&path, content).with_context(|| format!("Failed to write {path}"))?;
+        written.push(relative);
+        Ok(())
+    };
+
+    write(
+        Utf8PathBuf::from("index.html"),
+        &render_index(spec_id, &receipts, &artifacts, health),
+    )?;
+    write(
+        Utf8PathBuf::from("badge.svg"),
+        &Badge::from_receipts(&receipts).to_svg(),
+    )?;
+    for (name, content) in &artifacts {
+        write(Utf8PathBuf::from(format!("artifacts/{name}")), content)?;
+        write(
+            Utf8PathBuf::from(format!("artifacts/{name}.html")),
+            &render_artifact(spec_id, name, content),
+        )?;
+    }
+
+    Ok(written)
+}
+
+/// Artifact files as `(name, content)`, sorted by name
+fn read_artifacts(dir: &Utf8Path) -> Result<Vec<(String, String)>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut artifacts = Vec::new();
+    for entry in dir.read_dir_utf8()? {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+        // Compressed artifacts are published under their original name
+        let content = compression::read_to_string(entry.path().as_std_path())
+            .with_context(|| format!("Failed to read artifact {}", entry.path()))?;
+        let name = compression::logical_name(entry.file_name()).to_string();
+        artifacts.push((name, content));
+    }
+    artifacts.sort();
+    Ok(artifacts)
+}
+
+fn render_index(
+    spec_id: &str,
+    receipts: &[Receipt],
+    artifacts: &[(String, String)],
+    health: Option<&SpecHealth>,
+) -> String {
+    let mut body = String::new();
+    let _ = writeln!(
+        body,
+        "<h1>{}</h1>\n<p><img src=\"badge.svg\" alt=\"{}\"></p>",
+        escape_html(spec_id),
+        escape_html(&Badge::from_receipts(receipts).message)
+    );
+
+    body.push_str("<h2>Phases</h2>\n");
+    let latest = latest_per_phase(receipts);
+    if latest.is_empty() {
+        body.push_str("<p class=\"muted\">No phases have run yet.</p>\n");
+    } else {
+        body.push_str(
+            "<table>\n<tr><th>Phase</th><th>Result</th><th>Model</th><th>Last run</th><th>Outputs</th></tr>\n",
+        );
+        for receipt in latest {
+            let _ = writeln!(
+                body,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape_html(&receipt.phase),
+                outcome_cell(receipt),
+                escape_html(&receipt.model_full_name),
+                receipt.emitted_at.format("%Y-%m-%d %H:%M UTC"),
+                receipt.outputs.len()
+            );
+        }
+        body.push_str("</table>\n");
+    }
+
+    if let Some(usage) = token_usage(receipts) {
+        body.push_str("<h2>Provider usage</h2>\n<table>\n<tr><th>Phase</th><th>Requests</th><th>Input tokens</th><th>Output tokens</th></tr>\n");
+        let rows = usage
+            .phases
+            .iter()
+            .map(|p| (p.phase.as_str(), &p.counts))
+            .chain([("total", &usage.total)]);
+        for (phase, counts) in rows {
+            let _ = writeln!(
+                body,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape_html(phase),
+                counts.requests,
+                counts.tokens_input,
+                counts.tokens_output
+            );
+        }
+        body.push_str("</table>\n");
+    }
+
+    if !receipts.is_empty() || health.is_some() {
+        body.push_str("<h2>Trends</h2>\n");
+    }
+    if let Some(health) = health {
+        let _ = writeln!(
+            body,
+            "<p>Health score: <strong>{}</strong>/100</p>\n<table>\n<tr><th>Recency</th><th>Reliability</th><th>Findings</th><th>Doneness</th></tr>\n<tr><td>{}/20</td><td>{}/30</td><td>{}/30</td><td>{}/20</td></tr>\n</table>",
+            health.score, health.recency, health.reliability, health.findings, health.doneness
+        );
+    }
+    if !receipts.is_empty() {
+        let tokens: Vec<(u64, &str)> = receipts
+            .iter()
+            .map(|r| {
+                let llm = r.llm.as_ref();
+                let total = llm.and_then(|l| l.tokens_input).unwrap_or(0)
+                    + llm.and_then(|l| l.tokens_output).unwrap_or(0);
+                (total, outcome_color(r))
+            })
+            .collect();
+        body.push_str(&bar_chart("Tokens per run", &tokens));
+        let warnings: Vec<(u64, &str)> = receipts
+            .iter()
+            .map(|r| (r.warnings.len() as u64, outcome_color(r)))
+            .collect();
+        body.push_str(&bar_chart("Warnings per run", &warnings));
+    }
+
+    body.push_str("<h2>Artifacts</h2>\n");
+    if artifacts.is_empty() {
+        body.push_str("<p class=\"muted\">No artifacts.</p>\n");
+    } else {
+        body.push_str("<ul>\n");
+        for (name, _) in artifacts {
+            let name = escape_html(name);
+            let _ = writeln!(
+                body,
+                "<li><a href=\"artifacts/{name}.html\">{name}</a> (<a href=\"artifacts/{name}\">raw</a>)</li>"
+            );
+        }
+        body.push_str("</ul>\n");
+    }
+
+    if !receipts.is_empty() {
+        body.push_str("<h2>Receipts</h2>\n<table>\n<tr><th>Emitted</th><th>Phase</th><th>Exit</th><th>Model</th><th>Warnings</th><th>Error</th></tr>\n");
+        for receipt in receipts.iter().rev() {
+            let _ = writeln!(
+                body,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                receipt.emitted_at.format("%Y-%m-%d %H:%M:%S UTC"),
+                escape_html(&receipt.phase),
+                receipt.exit_code,
+                escape_html(&receipt.model_full_name),
+                receipt.warnings.len(),
+                escape_html(receipt.error_reason.as_deref().unwrap_or_default())
+            );
+        }
+        body.push_str("</table>\n");
+    }
+
+    page(&format!("{spec_id} · xchecker"), &body)
+}
+
+fn render_artifact(spec_id: &str, name: &str, content: &str) -> String {
+    let body = format!(
+        "<p><a href=\"../index.html\">← {}</a></p>\n<h1>{}</h1>\n<pre>{}</pre>\n",
+        escape_html(spec_id),
+        escape_html(name),
+        escape_html(content)
+    );
+    page(&format!("{name} · {spec_id}"), &body)
+}
+
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+<title>{}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        escape_html(title)
+    )
+}
+
+/// The latest receipt of each phase, in order of each phase's first run
+fn latest_per_phase(receipts: &[Receipt]) -> Vec<&Receipt> {
+    let mut latest: Vec<&Receipt> = Vec::new();
+    for receipt in receipts {
+        match latest.iter_mut().find(|r| r.phase == receipt.phase) {
+            Some(slot) => *slot = receipt,
+            None => latest.push(receipt),
+        }
+    }
+    latest
+}
+
+fn outcome_cell(receipt: &Receipt) -> String {
+    if receipt.is_skipped() {
+        "<span class=\"muted\">skipped</span>".to_string()
+    } else if receipt.exit_code == 0 {
+        "<span class=\"pass\">passed</span>".to_string()
+    } else {
+        format!(
+            "<span class=\"fail\">failed (exit {})</span>",
+            receipt.exit_code
+        )
+    }
+}
+
+fn outcome_color(receipt: &Receipt) -> &'static str {
+    if receipt.is_skipped() {
+        COLOR_SKIPPED
+    } else if receipt.exit_code == 0 {
+        COLOR_PASSING
+    } else {
+        COLOR_FAILING
+    }
+}
+
+/// Inline SVG bar chart, one bar per run in chronological order
+fn bar_chart(title: &str, bars: &[(u64, &str)]) -> String {
+    let max = bars
+        .iter()
+        .map(|(value, _)| *value)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let slot = CHART_WIDTH / bars.len().max(1);
+    let bar_width = slot.saturating_sub(2).max(1);
+
+    let mut svg = format!(
+        "<h3>{title}</h3>\n<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{CHART_WIDTH}\" \
+height=\"{CHART_HEIGHT}\" role=\"img\" aria-label=\"{title}\">\n\
+<rect width=\"{CHART_WIDTH}\" height=\"{CHART_HEIGHT}\" fill=\"#fafafa\"/>\n",
+        title = escape_html(title)
+    );
+    for (index, (value, color)) in bars.iter().enumerate() {
+        let height = usize::try_from(*value * CHART_HEIGHT as u64 / max).unwrap_or(CHART_HEIGHT);
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{}\" y=\"{}\" width=\"{bar_width}\" height=\"{height}\" fill=\"{color}\"><title>{value}</title></rect>",
+            index * slot,
+            CHART_HEIGHT - height
+        );
+    }
+    svg.push_str(&format!("</svg>\n<p class=\"muted\">max {max}</p>\n"));
+    svg
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PacketEvidence;
+    use chrono::{TimeZone, Utc};
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn receipt(phase: &str, exit_code: i32, minute: u32) -> Receipt {
+        Receipt {
+            schema_version: "1".to_string(),
+            emitted_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, minute, 0).unwrap(),
+            spec_id: "spec-1".to_string(),
+            phase: phase.to_string(),
+            xchecker_version: "1.0.0".to_string(),
+            claude_cli_version: "0.8.1".to_string(),
+            model_full_name: "haiku".to_string(),
+            model_alias: None,
+            canonicalization_version: "yaml-v1,md-v1".to_string(),
+            canonicalization_backend: "jcs-rfc8785".to_string(),
+            flags: HashMap::new(),
+            runner: "native".to_string(),
+            runner_distro: None,
+            packet: PacketEvidence {
+                files: vec![],
+                max_bytes: 0,
+                max_lines: 0,
+                ..Default::default()
+            },
+            outputs: vec![],
+            exit_code,
+            error_kind: None,
+            error_reason: (exit_code != 0).then(|| "<timeout>".to_string()),
+            stderr_tail: None,
+            stderr_redacted: None,
+            warnings: vec![],
+            fallback_used: None,
+            diff_context: None,
+            llm: None,
+            pipeline: None,
+            suggested_actions: vec![],
+            config_hash: None,
+            config_snapshot: None,
+        }
+    }
+
+    #[test]
+    fn test_latest_per_phase_keeps_first_run_order() {
+        let receipts = [
+            receipt("requirements", 1, 0),
+            receipt("requirements", 0, 1),
+            receipt("design", 1, 2),
+        ];
+        let latest = latest_per_phase(&receipts);
+        assert_eq!(latest.len(), 2);
+        assert_eq!(latest[0].phase, "requirements");
+        assert_eq!(latest[0].exit_code, 0);
+        assert_eq!(latest[1].phase, "design");
+    }
+
+    #[test]
+    fn test_export_site_writes_escaped_pages() {
+        let spec = TempDir::new().unwrap();
+        let spec_root = Utf8Path::from_path(spec.path()).unwrap();
+        std::fs::create_dir_all(spec_root.join("artifacts")).unwrap();
+        std::fs::write(
+            spec_root.join("artifacts/00-requirements.md"),
+            "# Requirements\n<script>alert(1)</script>\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(spec_root.join("context")).unwrap();
+        std::fs::write(spec_root.join("context/requirements-packet.txt"), "packet").unwrap();
+
+        let out = TempDir::new().unwrap();
+        let out_root = Utf8Path::from_path(out.path()).unwrap();
+        let written = export_site("spec-1", spec_root, out_root, None).unwrap();
+
+        assert_eq!(
+            written,
+            vec![
+                Utf8PathBuf::from("index.html"),
+                Utf8PathBuf::from("badge.svg"),
+                Utf8PathBuf::from("artifacts/00-requirements.md"),
+                Utf8PathBuf::from("artifacts/00-requirements.md.html"),
+            ]
+        );
+        let artifact_page =
+            std::fs::read_to_string(out_root.join("artifacts/00-requirements.md.html")).unwrap();
+        assert!(artifact_page.contains("&lt;script&gt;"));
+        assert!(!artifact_page.contains("<script>"));
+        let index = std::fs::read_to_string(out_root.join("index.html")).unwrap();
+        assert!(index.contains("No phases have run yet."));
+        assert!(!index.contains("Trends"));
+        assert!(index.contains("artifacts/00-requirements.md.html"));
+        assert!(!out_root.join("context").exists());
+    }
+
+    #[test]
+    fn test_index_renders_status_and_trends() {
+        let receipts = [receipt("requirements", 0, 0), receipt("design", 1, 1)];
+        let health = SpecHealth {
+            score: 65,
+            recency: 20,
+            reliability: 15,
+            findings: 30,
+            doneness: 0,
+        };
+        let index = render_index("spec-1", &receipts, &[], Some(&health));
+        assert!(index.contains("Health score: <strong>65</strong>/100"));
+        assert!(index.contains("<td>15/30</td>"));
+        assert!(index.contains("<span class=\"pass\">passed</span>"));
+        assert!(index.contains("failed (exit 1)"));
+        assert!(index.contains("&lt;timeout&gt;"));
+        assert!(index.contains("Tokens per run"));
+        assert_eq!(index.matches(COLOR_FAILING).count(), 2);
+    }
+}
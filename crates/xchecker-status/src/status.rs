@@ -114,6 +114,12 @@ impl StatusManager {
 
         let effective_config_map = Self::build_effective_config(effective_config, secret_redactor);
 
+        // Invalid metadata should not make status unavailable
+        let metadata = artifact_manager.spec_metadata().unwrap_or_else(|e| {
+            tracing::warn!("Ignoring spec metadata: {e:#}");
+            None
+        });
+
         // Use values from latest receipt if available, otherwise use sensible defaults
         Ok(StatusOutput {
             schema_version: "1".to_string(),
@@ -136,6 +142,7 @@ impl StatusManager {
             effective_config: effective_config_map,
             lock_drift,
             pending_fixups,
+            metadata,
         })
     }
 
@@ -382,6 +389,7 @@ mod tests {
             effective_config: BTreeMap::new(),
             lock_drift: None,
             pending_fixups: None,
+            metadata: None,
         };
 
         let result = StatusManager::emit_json(&status);
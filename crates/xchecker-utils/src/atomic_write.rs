@@ -6,18 +6,126 @@
 //! - Windows rename retry with exponential backoff (≤ 250ms total)
 //! - Cross-filesystem fallback (copy→fsync→replace)
 //! - Warning tracking for retries and fallbacks
+//! - A process-wide [`FsyncPolicy`] for filesystems where fsync is expensive
 
 use anyhow::{Context, Result};
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
+use std::fmt;
 use std::fs;
 use std::io::Write;
 use std::path::Path;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU8, Ordering};
 
 use tempfile::NamedTempFile;
 
 #[cfg(target_os = "windows")]
 use std::{thread, time::Duration};
 
+/// When atomic writes flush file contents to stable storage
+///
+/// The rename is atomic under every policy: readers see the old or the new
+/// file, never a torn one. The policies differ in what survives a crash or
+/// power loss before the OS writes its cache back:
+/// - `always` fsyncs each file before the rename; a completed write is durable.
+/// - `batch` renames immediately and fsyncs every pending file at once when
+///   [`flush_pending_writes`] runs (at the end of each phase). A crash before
+///   the flush can leave recently written files empty or at their old content.
+/// - `never` leaves flushing to the OS; the same loss window, with no bound.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FsyncPolicy {
+    #[default]
+    Always,
+    Batch,
+    Never,
+}
+
+impl FsyncPolicy {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Always => "always",
+            Self::Batch => "batch",
+            Self::Never => "never",
+        }
+    }
+
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::Batch,
+            2 => Self::Never,
+            _ => Self::Always,
+        }
+    }
+}
+
+impl fmt::Display for FsyncPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for FsyncPolicy {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "always" => Ok(Self::Always),
+            "batch" => Ok(Self::Batch),
+            "never" => Ok(Self::Never),
+            other => Err(format!(
+                "'{other}' (expected \"always\", \"batch\", or \"never\")"
+            )),
+        }
+    }
+}
+
+/// Policy applied by [`write_file_atomic`]; stored as `FsyncPolicy as u8`
+static FSYNC_POLICY: AtomicU8 = AtomicU8::new(FsyncPolicy::Always as u8);
+
+/// Files written under [`FsyncPolicy::Batch`] that have not been fsynced yet
+static PENDING_FSYNC: Mutex<Vec<Utf8PathBuf>> = Mutex::new(Vec::new());
+
+/// Set the fsync policy for the rest of the process
+pub fn set_fsync_policy(policy: FsyncPolicy) {
+    FSYNC_POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+/// The fsync policy currently applied to atomic writes
+#[must_use]
+pub fn fsync_policy() -> FsyncPolicy {
+    FsyncPolicy::from_u8(FSYNC_POLICY.load(Ordering::Relaxed))
+}
+
+/// Fsync every file written since the last flush under [`FsyncPolicy::Batch`]
+///
+/// Files removed or replaced since they were written are skipped. Returns the
+/// number of files synced.
+pub fn flush_pending_writes() -> Result<usize> {
+    let mut pending = std::mem::take(
+        &mut *PENDING_FSYNC
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner),
+    );
+    pending.sort();
+    pending.dedup();
+
+    let mut synced = 0;
+    for path in &pending {
+        // Windows only flushes handles opened for writing
+        let file = match fs::OpenOptions::new().write(true).open(path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e).with_context(|| format!("Failed to open {path} for fsync")),
+        };
+        file.sync_all()
+            .with_context(|| format!("Failed to fsync {path}"))?;
+        synced += 1;
+    }
+    Ok(synced)
+}
+
 /// Result of an atomic write operation
 #[derive(Debug, Clone, Default)]
 pub struct AtomicWriteResult {
@@ -31,6 +139,8 @@ pub struct AtomicWriteResult {
 
 /// Atomically write content to a file using temp file + fsync + rename
 ///
+/// Whether and when the fsync happens follows [`fsync_policy`].
+///
 /// This implements FR-FS-001 through FR-FS-005:
 /// - FR-FS-001: Write to temporary file first, fsync, then atomically rename
 /// - FR-FS-002: Windows rename retry with bounded exponential backoff (≤ 250ms)
@@ -38,10 +148,17 @@ pub struct AtomicWriteResult {
 /// - FR-FS-004: UTF-8 encoding with LF line endings
 /// - FR-FS-005: Cross-filesystem fallback (copy→fsync→replace)
 pub fn write_file_atomic(path: &Utf8Path, content: &str) -> Result<AtomicWriteResult> {
-    let mut result = AtomicWriteResult::default();
-
     // Normalize line endings to LF (FR-FS-004)
     let normalized_content = normalize_line_endings(content);
+    write_bytes_atomic(path, normalized_content.as_bytes())
+}
+
+/// Atomically write raw bytes (e.g. a compressed artifact) to a file
+///
+/// Same temp file + rename guarantees as [`write_file_atomic`], without line
+/// ending normalization.
+pub fn write_bytes_atomic(path: &Utf8Path, bytes: &[u8]) -> Result<AtomicWriteResult> {
+    let mut result = AtomicWriteResult::default();
 
     // Ensure parent directory exists
     if let Some(parent) = path.parent() {
@@ -56,14 +173,17 @@ pub fn write_file_atomic(path: &Utf8Path, content: &str) -> Result<AtomicWriteRe
 
     // Write content to temporary file
     temp_file
-        .write_all(normalized_content.as_bytes())
+        .write_all(bytes)
         .with_context(|| "Failed to write content to temporary file")?;
 
     // Ensure data is written to disk (FR-FS-001)
-    temp_file
-        .as_file()
-        .sync_all()
-        .with_context(|| "Failed to fsync temporary file")?;
+    let policy = fsync_policy();
+    if policy == FsyncPolicy::Always {
+        temp_file
+            .as_file()
+            .sync_all()
+            .with_context(|| "Failed to fsync temporary file")?;
+    }
 
     // Get the temp file path before attempting rename (for cross-filesystem fallback)
     let temp_path = temp_file.path().to_path_buf();
@@ -88,13 +208,20 @@ pub fn write_file_atomic(path: &Utf8Path, content: &str) -> Result<AtomicWriteRe
                 .push("Used cross-filesystem fallback (copy→fsync→replace)".to_string());
 
             // Fallback: copy→fsync→replace
-            cross_filesystem_copy_from_path(&temp_path, path)?;
+            cross_filesystem_copy_from_path(&temp_path, path, policy == FsyncPolicy::Always)?;
         }
         Err(e) => {
             return Err(e).with_context(|| format!("Failed to atomically write file: {path}"));
         }
     }
 
+    if policy == FsyncPolicy::Batch {
+        PENDING_FSYNC
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(path.to_path_buf());
+    }
+
     Ok(result)
 }
 
@@ -202,7 +329,7 @@ fn is_cross_filesystem_error(_err: &anyhow::Error) -> bool {
 }
 
 /// Perform cross-filesystem copy: copy→fsync→replace (FR-FS-005)
-fn cross_filesystem_copy_from_path(temp_path: &Path, target: &Utf8Path) -> Result<()> {
+fn cross_filesystem_copy_from_path(temp_path: &Path, target: &Utf8Path, sync: bool) -> Result<()> {
     // Read content from temp file
     let content = fs::read(temp_path)
         .with_context(|| "Failed to read temporary file for cross-filesystem copy")?;
@@ -218,10 +345,12 @@ fn cross_filesystem_copy_from_path(temp_path: &Path, target: &Utf8Path) -> Resul
         .with_context(|| "Failed to write content during cross-filesystem copy")?;
 
     // Fsync
-    target_temp
-        .as_file()
-        .sync_all()
-        .with_context(|| "Failed to fsync during cross-filesystem copy")?;
+    if sync {
+        target_temp
+            .as_file()
+            .sync_all()
+            .with_context(|| "Failed to fsync during cross-filesystem copy")?;
+    }
 
     // Atomic rename (should succeed since we're on the same filesystem now)
     target_temp
@@ -432,4 +561,33 @@ mod tests {
         // Note: \n will be preserved, but \r\n would be normalized
         assert!(read_content.contains("Special chars:"));
     }
+
+    #[test]
+    fn test_fsync_policy_parses_and_round_trips() {
+        for policy in [FsyncPolicy::Always, FsyncPolicy::Batch, FsyncPolicy::Never] {
+            assert_eq!(policy.as_str().parse::<FsyncPolicy>(), Ok(policy));
+        }
+        assert!("sometimes".parse::<FsyncPolicy>().is_err());
+    }
+
+    #[test]
+    fn test_batch_policy_defers_fsync_until_flush() {
+        let temp_dir = create_temp_dir();
+        let path_buf = temp_dir.path().join("batched.txt");
+        let file_path = Utf8Path::from_path(path_buf.as_path()).unwrap();
+        let removed_buf = temp_dir.path().join("removed.txt");
+        let removed_path = Utf8Path::from_path(removed_buf.as_path()).unwrap();
+
+        set_fsync_policy(FsyncPolicy::Batch);
+        write_file_atomic(file_path, "first").unwrap();
+        write_file_atomic(file_path, "second").unwrap();
+        write_file_atomic(removed_path, "gone").unwrap();
+        set_fsync_policy(FsyncPolicy::Always);
+        fs::remove_file(&removed_buf).unwrap();
+
+        assert_eq!(fs::read_to_string(&path_buf).unwrap(), "second");
+        // Repeated writes to one path sync once; removed files are skipped
+        assert!(flush_pending_writes().unwrap() >= 1);
+        assert_eq!(flush_pending_writes().unwrap(), 0);
+    }
 }
@@ -35,6 +35,38 @@ pub fn emit_jcs<T: Serialize>(value: &T) -> Result<String> {
     String::from_utf8(json_bytes).with_context(|| "JCS output contained invalid UTF-8")
 }
 
+/// Split a leading YAML front-matter block from a Markdown document.
+///
+/// Front matter is a `---` line at the very start of the document, a YAML
+/// mapping, and a closing `---` line. Returns the YAML (without delimiters)
+/// and the remaining body; documents without a well-formed block are
+/// returned unchanged as the body, so a leading horizontal rule is not
+/// mistaken for front matter.
+#[must_use]
+pub fn split_front_matter(content: &str) -> (Option<&str>, &str) {
+    let Some(rest) = content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))
+    else {
+        return (None, content);
+    };
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end_matches(['\r', '\n']) == "---" {
+            let yaml = &rest[..offset];
+            let is_mapping =
+                yaml.trim().is_empty() || serde_yaml::from_str::<serde_yaml::Mapping>(yaml).is_ok();
+            if !is_mapping {
+                break;
+            }
+            return (Some(yaml), &rest[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+    (None, content)
+}
+
 // Canonicalization version constants
 #[allow(dead_code)] // Reserved for future content-addressed storage
 pub const CANON_VERSION_YAML: &str = "yaml-v1";
@@ -154,7 +186,8 @@ impl Canonicalizer {
 
     /// Compute BLAKE3 hash of canonicalized content with `FileType` dispatch
     /// For YAML: uses JCS (RFC 8785) canonicalization of JSON representation
-    /// For Markdown: uses v1 normalization rules
+    /// For Markdown: uses v1 normalization rules on the body only; front matter
+    /// is hashed separately by [`Self::hash_front_matter`]
     /// For Text: uses basic line ending normalization
     pub fn hash_canonicalized(&self, content: &str, file_type: FileType) -> Result<String> {
         let hash_input = match file_type {
@@ -173,7 +206,7 @@ impl Canonicalizer {
                     .map(|bytes| String::from_utf8(bytes).unwrap())
                     .with_context(|| "Failed to canonicalize JSON using JCS")?
             }
-            FileType::Markdown => self.normalize_markdown(content)?,
+            FileType::Markdown => self.normalize_markdown(split_front_matter(content).1)?,
             FileType::Text => self.normalize_text(content),
         };
 
@@ -182,6 +215,17 @@ impl Canonicalizer {
         Ok(hasher.finalize().to_hex().to_string())
     }
 
+    /// Compute BLAKE3 hash of a Markdown document's front matter, if present
+    ///
+    /// The YAML block is hashed with the same JCS approach as YAML files, so
+    /// key order and formatting do not affect the result.
+    pub fn hash_front_matter(&self, content: &str) -> Result<Option<String>> {
+        match split_front_matter(content).0 {
+            Some(yaml) => self.hash_canonicalized(yaml, FileType::Yaml).map(Some),
+            None => Ok(None),
+        }
+    }
+
     /// Compute BLAKE3 hash of canonicalized content with error context
     pub fn hash_canonicalized_with_context(
         &self,
@@ -1098,4 +1142,49 @@ arabic: "مرحبا"
         let result2 = canonicalizer.hash_canonicalized(malformed, FileType::Yaml);
         assert!(result2.is_err());
     }
+
+    #[test]
+    fn test_front_matter_hashed_separately_from_body() {
+        let canonicalizer = Canonicalizer::new();
+        let body = "# Requirements\n\nThe system shall work.\n";
+        let with_meta = format!("---\nspec_id: demo\nrun_id: abc\n---\n{body}");
+        let reordered = format!("---\nrun_id: abc\nspec_id: demo\n---\n{body}");
+        let other_run = format!("---\nspec_id: demo\nrun_id: def\n---\n{body}");
+
+        assert_eq!(split_front_matter(&with_meta).1, body);
+
+        // Body hash ignores front matter entirely
+        let body_hash = canonicalizer
+            .hash_canonicalized(body, FileType::Markdown)
+            .unwrap();
+        assert_eq!(
+            canonicalizer
+                .hash_canonicalized(&with_meta, FileType::Markdown)
+                .unwrap(),
+            body_hash
+        );
+
+        // Metadata hash is key-order independent but sensitive to values
+        let meta_hash = canonicalizer.hash_front_matter(&with_meta).unwrap();
+        assert!(meta_hash.is_some());
+        assert_eq!(
+            canonicalizer.hash_front_matter(&reordered).unwrap(),
+            meta_hash
+        );
+        assert_ne!(
+            canonicalizer.hash_front_matter(&other_run).unwrap(),
+            meta_hash
+        );
+        assert_eq!(canonicalizer.hash_front_matter(body).unwrap(), None);
+    }
+
+    #[test]
+    fn test_leading_horizontal_rule_is_not_front_matter() {
+        let content = "---\nJust a rule, then text.\n---\n# Title\n";
+        assert_eq!(split_front_matter(content), (None, content));
+        assert_eq!(
+            split_front_matter("---\nno closing line\n"),
+            (None, "---\nno closing line\n")
+        );
+    }
 }
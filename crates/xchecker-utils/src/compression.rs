@@ -0,0 +1,208 @@
+//! Transparent zstd compression for large artifacts and receipts
+//!
+//! Files whose content exceeds the configured threshold are stored as
+//! `<name>.zst` next to where the plain file would go. Readers pass the
+//! logical (uncompressed) path to [`read_to_string`] and [`exists`] and get the
+//! original text back whichever form is on disk. Hashes recorded in receipts
+//! are always computed over the uncompressed content, so compressing a file
+//! never changes its receipt.
+//!
+//! The threshold and level are process-wide, set once from
+//! `[defaults] compress_threshold_bytes` / `compress_level`; compression is
+//! off until a threshold is set.
+
+use camino::{Utf8Path, Utf8PathBuf};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicI32, AtomicU64, Ordering};
+
+/// File extension appended to compressed files
+pub const EXTENSION: &str = "zst";
+
+/// zstd level used when `compress_level` is unset
+pub const DEFAULT_LEVEL: i32 = 3;
+
+/// Accepted range for `compress_level`
+pub const LEVEL_RANGE: std::ops::RangeInclusive<i32> = 1..=22;
+
+/// When content is stored compressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressionPolicy {
+    /// Content larger than this many bytes is compressed; `None` disables compression
+    pub threshold_bytes: Option<u64>,
+    /// zstd compression level
+    pub level: i32,
+}
+
+impl Default for CompressionPolicy {
+    fn default() -> Self {
+        Self {
+            threshold_bytes: None,
+            level: DEFAULT_LEVEL,
+        }
+    }
+}
+
+impl CompressionPolicy {
+    /// Whether content of `len` bytes should be stored compressed
+    #[must_use]
+    pub fn should_compress(&self, len: usize) -> bool {
+        self.threshold_bytes
+            .is_some_and(|threshold| len as u64 > threshold)
+    }
+}
+
+/// Threshold of the active policy; 0 means compression is off
+static THRESHOLD_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Level of the active policy
+static LEVEL: AtomicI32 = AtomicI32::new(DEFAULT_LEVEL);
+
+/// Set the compression policy for the rest of the process
+pub fn set_compression_policy(policy: CompressionPolicy) {
+    THRESHOLD_BYTES.store(policy.threshold_bytes.unwrap_or(0), Ordering::Relaxed);
+    LEVEL.store(policy.level, Ordering::Relaxed);
+}
+
+/// The active compression policy
+#[must_use]
+pub fn compression_policy() -> CompressionPolicy {
+    let threshold = THRESHOLD_BYTES.load(Ordering::Relaxed);
+    CompressionPolicy {
+        threshold_bytes: (threshold > 0).then_some(threshold),
+        level: LEVEL.load(Ordering::Relaxed),
+    }
+}
+
+/// Compress `content` when the active policy asks for it
+///
+/// Returns `None` when the content should be stored as-is.
+pub fn compress_for_storage(content: &[u8]) -> io::Result<Option<Vec<u8>>> {
+    let policy = compression_policy();
+    if !policy.should_compress(content.len()) {
+        return Ok(None);
+    }
+    compress(content, policy.level).map(Some)
+}
+
+/// Compress `content` into a single zstd frame at `level`
+pub fn compress(content: &[u8], level: i32) -> io::Result<Vec<u8>> {
+    zstd::encode_all(content, level)
+}
+
+/// Decompress a zstd frame
+pub fn decompress(bytes: &[u8]) -> io::Result<Vec<u8>> {
+    zstd::decode_all(bytes)
+}
+
+/// Path of the compressed form of `path` (`<path>.zst`)
+#[must_use]
+pub fn compressed_path(path: &Utf8Path) -> Utf8PathBuf {
+    Utf8PathBuf::from(format!("{path}.{EXTENSION}"))
+}
+
+/// Whether `path` names a compressed file
+#[must_use]
+pub fn is_compressed(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == EXTENSION)
+}
+
+/// File name with a trailing `.zst` removed
+#[must_use]
+pub fn logical_name(name: &str) -> &str {
+    name.strip_suffix(".zst").unwrap_or(name)
+}
+
+/// Whether `path` exists in plain or compressed form
+#[must_use]
+pub fn exists(path: &Path) -> bool {
+    path.exists() || with_zst(path).exists()
+}
+
+/// Read a file that may be stored compressed
+///
+/// `path` may name the plain file, in which case `<path>.zst` is read when the
+/// plain file is absent, or a `.zst` file directly.
+pub fn read_to_string(path: &Path) -> io::Result<String> {
+    let bytes = if is_compressed(path) {
+        decompress(&std::fs::read(path)?)?
+    } else {
+        match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                let compressed = with_zst(path);
+                if !compressed.exists() {
+                    return Err(e);
+                }
+                decompress(&std::fs::read(compressed)?)?
+            }
+            Err(e) => return Err(e),
+        }
+    };
+    String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn with_zst(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(EXTENSION);
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_policy_threshold() {
+        let policy = CompressionPolicy {
+            threshold_bytes: Some(10),
+            level: DEFAULT_LEVEL,
+        };
+        assert!(!policy.should_compress(10));
+        assert!(policy.should_compress(11));
+        assert!(!CompressionPolicy::default().should_compress(usize::MAX));
+    }
+
+    #[test]
+    fn test_read_to_string_falls_back_to_compressed_form() {
+        let temp = TempDir::new().unwrap();
+        let plain = temp.path().join("10-design.md");
+        let content = "# Design\n".repeat(100);
+        let compressed = compress(content.as_bytes(), DEFAULT_LEVEL).unwrap();
+        std::fs::write(with_zst(&plain), compressed).unwrap();
+
+        assert!(exists(&plain));
+        assert_eq!(read_to_string(&plain).unwrap(), content);
+        assert_eq!(read_to_string(&with_zst(&plain)).unwrap(), content);
+    }
+
+    #[test]
+    fn test_read_to_string_prefers_plain_file() {
+        let temp = TempDir::new().unwrap();
+        let plain = temp.path().join("00-requirements.md");
+        std::fs::write(&plain, "plain").unwrap();
+
+        assert_eq!(read_to_string(&plain).unwrap(), "plain");
+        assert!(!exists(&temp.path().join("missing.md")));
+        assert_eq!(
+            read_to_string(&temp.path().join("missing.md"))
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn test_logical_name_and_paths() {
+        assert_eq!(logical_name("10-design.md.zst"), "10-design.md");
+        assert_eq!(logical_name("10-design.md"), "10-design.md");
+        assert_eq!(
+            compressed_path(Utf8Path::new("receipts/design.json")),
+            Utf8PathBuf::from("receipts/design.json.zst")
+        );
+        assert!(is_compressed(Path::new("a.md.zst")));
+        assert!(!is_compressed(Path::new("a.md")));
+    }
+}
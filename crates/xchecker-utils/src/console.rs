@@ -0,0 +1,345 @@
+//! Terminal-aware console output
+//!
+//! Every human-facing status mark, spinner, and progress bar goes through this
+//! module so the CLI adapts in one place:
+//! - Color only when the stream is a terminal, `NO_COLOR` is unset, the run is
+//!   not in CI, and the command has not disabled it.
+//! - Spinners and progress bars redraw in place only on an interactive
+//!   terminal; piped output and CI logs get no animation frames or cursor
+//!   control, just the final result lines the command prints anyway.
+//! - Live output goes to stderr so stdout stays clean for JSON and pipes.
+//!
+//! Library callers get plain, silent behavior: progress is off until the CLI
+//! calls [`set_progress_enabled`].
+
+use std::io::{IsTerminal, Write};
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossterm::style::Stylize;
+
+pub use crossterm::style::Color;
+
+/// Forced off by commands that must never emit color (e.g. `xchecker ci`)
+static COLOR_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Whether spinners and progress bars may draw at all
+static PROGRESS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Minimum time between progress bar redraws
+const REDRAW_INTERVAL: Duration = Duration::from_millis(80);
+
+/// Width of the progress bar itself, excluding counters and label
+const BAR_WIDTH: usize = 24;
+
+/// Output stream a decision applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+impl Stream {
+    fn is_terminal(self) -> bool {
+        match self {
+            Self::Stdout => std::io::stdout().is_terminal(),
+            Self::Stderr => std::io::stderr().is_terminal(),
+        }
+    }
+}
+
+/// Disable color for the rest of the process
+pub fn disable_color() {
+    COLOR_DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// Allow (or forbid) spinners and progress bars for the rest of the process
+pub fn set_progress_enabled(enabled: bool) {
+    PROGRESS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether the process appears to run under a CI system
+///
+/// Checks the conventional `CI` variable (ignoring `false`/`0`) plus the
+/// variables of CI systems that do not set it.
+#[must_use]
+pub fn is_ci() -> bool {
+    let ci = std::env::var("CI").is_ok_and(|v| !v.is_empty() && v != "false" && v != "0");
+    ci || [
+        "GITHUB_ACTIONS",
+        "GITLAB_CI",
+        "BUILDKITE",
+        "TF_BUILD",
+        "JENKINS_URL",
+    ]
+    .iter()
+    .any(|var| std::env::var_os(var).is_some())
+}
+
+/// Whether color escapes may be written to `stream`
+#[must_use]
+pub fn color_enabled(stream: Stream) -> bool {
+    !COLOR_DISABLED.load(Ordering::Relaxed)
+        && std::env::var_os("NO_COLOR").is_none()
+        && !is_ci()
+        && stream.is_terminal()
+}
+
+/// Whether spinners and progress bars may redraw on stderr
+#[must_use]
+pub fn live_output() -> bool {
+    PROGRESS_ENABLED.load(Ordering::Relaxed) && !is_ci() && Stream::Stderr.is_terminal()
+}
+
+/// `text` in bold `color` (or just bold without a color) when color is
+/// enabled for `stream`, otherwise plain
+#[must_use]
+pub fn paint(text: &str, color: Option<Color>, stream: Stream) -> String {
+    if !color_enabled(stream) {
+        return text.to_string();
+    }
+    match color {
+        Some(color) => format!("{}", text.with(color).bold()),
+        None => format!("{}", text.bold()),
+    }
+}
+
+/// `text` in `color` without bold when color is enabled for `stream`,
+/// otherwise plain
+#[must_use]
+pub fn tint(text: &str, color: Color, stream: Stream) -> String {
+    if color_enabled(stream) {
+        format!("{}", text.with(color))
+    } else {
+        text.to_string()
+    }
+}
+
+/// `text` in reverse-video `color` when color is enabled for `stream`,
+/// otherwise plain; marks changed words inside a diff line
+#[must_use]
+pub fn highlight(text: &str, color: Color, stream: Stream) -> String {
+    if color_enabled(stream) {
+        format!("{}", text.with(color).reverse())
+    } else {
+        text.to_string()
+    }
+}
+
+/// Check mark (✓), green when color is enabled
+#[must_use]
+pub fn check_mark(stream: Stream) -> String {
+    paint("✓", Some(Color::Green), stream)
+}
+
+/// Warning mark (⚠), yellow when color is enabled
+#[must_use]
+pub fn warning_mark(stream: Stream) -> String {
+    paint("⚠", Some(Color::Yellow), stream)
+}
+
+/// Failure mark (✗), red when color is enabled
+#[must_use]
+pub fn cross_mark(stream: Stream) -> String {
+    paint("✗", Some(Color::Red), stream)
+}
+
+/// Success text on stdout, green when color is enabled
+#[must_use]
+pub fn success(text: &str) -> String {
+    paint(text, Some(Color::Green), Stream::Stdout)
+}
+
+/// Informational text on stdout, cyan when color is enabled
+#[must_use]
+pub fn info(text: &str) -> String {
+    paint(text, Some(Color::Cyan), Stream::Stdout)
+}
+
+/// Spinner for waits of unknown length, drawn on stderr
+///
+/// Draws nothing unless [`live_output`] holds. The line is cleared and the
+/// cursor restored on drop, including on panic.
+pub struct Spinner {
+    running: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl Spinner {
+    /// Start a spinner showing `message` and the elapsed seconds
+    #[must_use]
+    pub fn start(message: impl Into<String>) -> Self {
+        let running = Arc::new(AtomicBool::new(live_output()));
+        if !running.load(Ordering::Relaxed) {
+            return Self {
+                running,
+                handle: None,
+            };
+        }
+
+        let message = message.into();
+        let running_clone = running.clone();
+        // Hide cursor to prevent flickering
+        let _ = crossterm::execute!(std::io::stderr(), crossterm::cursor::Hide);
+
+        let handle = thread::spawn(move || {
+            let frames = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+            let started = Instant::now();
+            let mut i = 0;
+            while running_clone.load(Ordering::Relaxed) {
+                eprint!(
+                    "\r{} {message} ({}s)",
+                    frames[i],
+                    started.elapsed().as_secs()
+                );
+                let _ = std::io::stderr().flush();
+                i = (i + 1) % frames.len();
+                thread::sleep(REDRAW_INTERVAL);
+            }
+            clear_line();
+        });
+
+        Self {
+            running,
+            handle: Some(handle),
+        }
+    }
+}
+
+impl Drop for Spinner {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+            // Restore cursor
+            let _ = crossterm::execute!(std::io::stderr(), crossterm::cursor::Show);
+        }
+    }
+}
+
+/// Progress bar for work with a known number of steps, drawn on stderr
+///
+/// Safe to share across threads; [`ProgressBar::inc`] redraws at most every
+/// 80ms. Draws nothing unless [`live_output`] holds, and clears its line when
+/// finished or dropped.
+pub struct ProgressBar {
+    label: String,
+    total: u64,
+    current: AtomicU64,
+    live: bool,
+    last_draw: Mutex<Option<Instant>>,
+}
+
+impl ProgressBar {
+    /// Create a bar for `total` steps labelled `label`
+    #[must_use]
+    pub fn new(label: impl Into<String>, total: u64) -> Self {
+        Self {
+            label: label.into(),
+            total,
+            current: AtomicU64::new(0),
+            live: live_output() && total > 0,
+            last_draw: Mutex::new(None),
+        }
+    }
+
+    /// Advance by one step
+    pub fn inc(&self) {
+        let current = self.current.fetch_add(1, Ordering::Relaxed) + 1;
+        if !self.live {
+            return;
+        }
+        let Ok(mut last_draw) = self.last_draw.lock() else {
+            return;
+        };
+        if last_draw.is_some_and(|at| at.elapsed() < REDRAW_INTERVAL) && current < self.total {
+            return;
+        }
+        *last_draw = Some(Instant::now());
+        eprint!("\r{}", render_bar(&self.label, current, self.total));
+        let _ = std::io::stderr().flush();
+    }
+
+    /// Clear the bar's line
+    pub fn finish(&self) {
+        let drawn = self.last_draw.lock().map(|mut at| at.take().is_some());
+        if drawn.unwrap_or(false) {
+            clear_line();
+        }
+    }
+}
+
+impl Drop for ProgressBar {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+/// Render one progress line, e.g. `[######------] 12/24 Building packet`
+#[must_use]
+pub fn render_bar(label: &str, current: u64, total: u64) -> String {
+    let current = current.min(total);
+    let filled = (current * BAR_WIDTH as u64)
+        .checked_div(total)
+        .map_or(BAR_WIDTH, |filled| {
+            usize::try_from(filled).unwrap_or(BAR_WIDTH)
+        });
+    format!(
+        "[{}{}] {current}/{total} {label}",
+        "#".repeat(filled),
+        "-".repeat(BAR_WIDTH - filled)
+    )
+}
+
+fn clear_line() {
+    let _ = crossterm::execute!(
+        std::io::stderr(),
+        crossterm::terminal::Clear(crossterm::terminal::ClearType::CurrentLine)
+    );
+    eprint!("\r");
+    let _ = std::io::stderr().flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::EnvVarGuard;
+
+    #[test]
+    fn test_render_bar() {
+        assert_eq!(
+            render_bar("Building packet", 6, 12),
+            "[############------------] 6/12 Building packet"
+        );
+        assert_eq!(render_bar("x", 0, 4), format!("[{}] 0/4 x", "-".repeat(24)));
+        assert_eq!(render_bar("x", 9, 4), format!("[{}] 4/4 x", "#".repeat(24)));
+    }
+
+    #[test]
+    fn test_ci_and_no_color_disable_color_and_live_output() {
+        let _ci = EnvVarGuard::set("CI", "true");
+        assert!(is_ci());
+        assert!(!color_enabled(Stream::Stdout));
+        set_progress_enabled(true);
+        assert!(!live_output());
+        set_progress_enabled(false);
+
+        let _ci = EnvVarGuard::set("CI", "false");
+        let _no_color = EnvVarGuard::set("NO_COLOR", "1");
+        assert!(!color_enabled(Stream::Stdout));
+        assert_eq!(check_mark(Stream::Stdout), "✓");
+    }
+
+    #[test]
+    fn test_silent_without_live_output() {
+        set_progress_enabled(false);
+        let bar = ProgressBar::new("Building packet", 3);
+        bar.inc();
+        bar.inc();
+        assert_eq!(bar.current.load(Ordering::Relaxed), 2);
+        drop(Spinner::start("Waiting"));
+    }
+}
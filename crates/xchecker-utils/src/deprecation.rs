@@ -0,0 +1,167 @@
+//! Central registry of deprecated config keys, CLI flags, and schema fields.
+//!
+//! Everything xchecker still accepts but plans to remove is listed once in
+//! [`DEPRECATIONS`], with its replacement and the release that drops it.
+//! Callers that detect a deprecated item in use look it up by kind and name
+//! with [`notice`] and attach the result to the `deprecations` array of
+//! `status --json` and `doctor --json`, so migration pressure is visible to
+//! tooling as well as to people reading warnings.
+//!
+//! # Example
+//!
+//! ```rust
+//! use xchecker_utils::deprecation::{DeprecationKind, notice};
+//!
+//! let notice = notice(DeprecationKind::CliFlag, "--claude-path").unwrap();
+//! assert_eq!(notice.replacement, "--llm-claude-binary");
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What kind of interface a deprecation applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeprecationKind {
+    /// A key in `.xchecker/config.toml`
+    ConfigKey,
+    /// A command-line flag
+    CliFlag,
+    /// A field of a persisted JSON document (receipts, status, doctor)
+    SchemaField,
+}
+
+/// One row of the deprecation registry
+#[derive(Debug)]
+pub struct Deprecation {
+    /// Kind of interface the deprecated item belongs to
+    pub kind: DeprecationKind,
+    /// Deprecated name as the user writes it (`runner.claude_path`, `--claude-path`)
+    pub name: &'static str,
+    /// Name to use instead
+    pub replacement: &'static str,
+    /// First release that no longer accepts the deprecated name
+    pub removal_version: &'static str,
+    /// How to migrate
+    pub guidance: &'static str,
+}
+
+/// Everything currently deprecated, in no particular order
+pub static DEPRECATIONS: &[Deprecation] = &[
+    Deprecation {
+        kind: DeprecationKind::ConfigKey,
+        name: "runner.claude_path",
+        replacement: "llm.claude.binary",
+        removal_version: "2.0.0",
+        guidance: "Move the path from [runner] claude_path to [llm.claude] binary",
+    },
+    Deprecation {
+        kind: DeprecationKind::CliFlag,
+        name: "--claude-path",
+        replacement: "--llm-claude-binary",
+        removal_version: "2.0.0",
+        guidance: "Pass the Claude binary with --llm-claude-binary instead",
+    },
+    Deprecation {
+        kind: DeprecationKind::SchemaField,
+        name: "receipt.timestamp",
+        replacement: "receipt.emitted_at",
+        removal_version: "2.0.0",
+        guidance: "Rewrite old receipts with `xchecker migrate-receipts <id> --to v1`",
+    },
+];
+
+/// A deprecated item found in use, as emitted in JSON outputs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeprecationNotice {
+    /// Kind of interface the deprecated item belongs to
+    pub kind: DeprecationKind,
+    /// Deprecated name
+    pub name: String,
+    /// Name to use instead
+    pub replacement: String,
+    /// First release that no longer accepts the deprecated name
+    pub removal_version: String,
+    /// How to migrate
+    pub guidance: String,
+}
+
+impl From<&Deprecation> for DeprecationNotice {
+    fn from(deprecation: &Deprecation) -> Self {
+        Self {
+            kind: deprecation.kind,
+            name: deprecation.name.to_string(),
+            replacement: deprecation.replacement.to_string(),
+            removal_version: deprecation.removal_version.to_string(),
+            guidance: deprecation.guidance.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for DeprecationNotice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is deprecated and will be removed in {}; use {} ({})",
+            self.name, self.removal_version, self.replacement, self.guidance
+        )
+    }
+}
+
+/// Look up a registered deprecation by kind and name
+///
+/// Returns `None` for names that are not deprecated.
+#[must_use]
+pub fn notice(kind: DeprecationKind, name: &str) -> Option<DeprecationNotice> {
+    DEPRECATIONS
+        .iter()
+        .find(|deprecation| deprecation.kind == kind && deprecation.name == name)
+        .map(DeprecationNotice::from)
+}
+
+/// Sort notices by kind and name and drop duplicates, for stable output
+pub fn normalize(notices: &mut Vec<DeprecationNotice>) {
+    notices.sort_by(|a, b| (a.kind as u8, &a.name).cmp(&(b.kind as u8, &b.name)));
+    notices.dedup();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_names_are_unique_per_kind() {
+        for (i, a) in DEPRECATIONS.iter().enumerate() {
+            for b in &DEPRECATIONS[i + 1..] {
+                assert!(
+                    a.kind != b.kind || a.name != b.name,
+                    "duplicate deprecation {}",
+                    a.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_notice_lookup_and_serialization() {
+        assert!(notice(DeprecationKind::ConfigKey, "llm.claude.binary").is_none());
+        // Same name under a different kind is not deprecated
+        assert!(notice(DeprecationKind::ConfigKey, "--claude-path").is_none());
+
+        let notice = notice(DeprecationKind::ConfigKey, "runner.claude_path").unwrap();
+        let json = serde_json::to_value(&notice).unwrap();
+        assert_eq!(json["kind"], "config_key");
+        assert_eq!(json["replacement"], "llm.claude.binary");
+        assert_eq!(json["removal_version"], "2.0.0");
+        assert!(notice.to_string().contains("will be removed in 2.0.0"));
+    }
+
+    #[test]
+    fn test_normalize_sorts_and_dedups() {
+        let flag = notice(DeprecationKind::CliFlag, "--claude-path").unwrap();
+        let key = notice(DeprecationKind::ConfigKey, "runner.claude_path").unwrap();
+        let mut notices = vec![flag.clone(), key.clone(), flag.clone()];
+        normalize(&mut notices);
+        assert_eq!(notices, vec![key, flag]);
+    }
+}
@@ -98,6 +98,15 @@ pub enum XCheckerError {
         limit_lines: usize,
     },
 
+    #[error(
+        "Packet memory budget exceeded: {used_bytes} bytes > limit {limit_bytes} bytes while reading {path}"
+    )]
+    PacketMemoryExceeded {
+        used_bytes: usize,
+        limit_bytes: usize,
+        path: String,
+    },
+
     #[error("Concurrent execution detected for spec {id}")]
     ConcurrentExecution { id: String },
 
@@ -380,6 +389,12 @@ pub enum PhaseError {
     #[error("Phase {phase} was interrupted by user")]
     Interrupted { phase: String },
 
+    #[error("Phase {phase} halted by operator kill switch: {path}")]
+    OperatorHalt { phase: String, path: String },
+
+    #[error("Phase {phase} packet blocked by compliance scan: {findings}")]
+    ComplianceBlocked { phase: String, findings: String },
+
     #[error("Phase {phase} resource limit exceeded: {resource} ({limit})")]
     ResourceLimitExceeded {
         phase: String,
@@ -396,6 +411,9 @@ pub enum PhaseError {
 
     #[error("Phase {phase} produced partial output due to failure")]
     PartialOutputSaved { phase: String, partial_path: String },
+
+    #[error("Phase {phase} aborted by injected failure at {point}")]
+    InjectedFailure { phase: String, point: String },
 }
 
 impl UserFriendlyError for PhaseError {
@@ -430,6 +448,12 @@ impl UserFriendlyError for PhaseError {
             Self::Interrupted { phase } => {
                 format!("The {phase} phase was interrupted")
             }
+            Self::OperatorHalt { phase, path } => {
+                format!("The {phase} phase was halted because the kill switch {path} exists")
+            }
+            Self::ComplianceBlocked { phase, findings } => {
+                format!("The compliance scan blocked the {phase} phase packet: {findings}")
+            }
             Self::ResourceLimitExceeded {
                 phase,
                 resource,
@@ -452,6 +476,9 @@ impl UserFriendlyError for PhaseError {
             } => {
                 format!("The {phase} phase failed and partial output was saved to: {partial_path}")
             }
+            Self::InjectedFailure { phase, point } => {
+                format!("The {phase} phase was aborted at {point} by --inject-failure")
+            }
         }
     }
 
@@ -481,6 +508,12 @@ impl UserFriendlyError for PhaseError {
             Self::Interrupted { phase: _ } => {
                 Some("Phase execution can be interrupted by user signals (Ctrl+C) or system events.".to_string())
             }
+            Self::OperatorHalt { phase: _, path: _ } => {
+                Some("Every running xchecker instance stops between phases and during LLM calls while the kill switch file exists.".to_string())
+            }
+            Self::ComplianceBlocked { phase: _, findings: _ } => {
+                Some("In block mode the compliance scan stops packets with license or policy matches before any LLM call.".to_string())
+            }
             Self::ResourceLimitExceeded { phase: _, resource: _, limit: _ } => {
                 Some("Resource limits prevent excessive memory, disk, or network usage during phase execution.".to_string())
             }
@@ -490,6 +523,9 @@ impl UserFriendlyError for PhaseError {
             Self::PartialOutputSaved { phase: _, partial_path: _ } => {
                 Some("Partial outputs are saved when phases fail to help with debugging and recovery.".to_string())
             }
+            Self::InjectedFailure { phase: _, point: _ } => {
+                Some("Failure injection is a testing aid for exercising recovery paths.".to_string())
+            }
         }
     }
 
@@ -581,6 +617,18 @@ impl UserFriendlyError for PhaseError {
                 "Check partial outputs in .xchecker/specs/<id>/artifacts/".to_string(),
                 "Use --dry-run to test without making Claude calls".to_string(),
             ],
+            Self::OperatorHalt { phase, path } => vec![
+                format!("Remove {path} once the incident is resolved"),
+                format!(
+                    "Resume with: xchecker resume <id> --phase {}",
+                    phase.to_lowercase()
+                ),
+            ],
+            Self::ComplianceBlocked { phase: _, findings: _ } => vec![
+                "Review the compliance findings in the phase receipt".to_string(),
+                "Exclude the flagged files or list accepted built-in pattern IDs in compliance.ignore_patterns".to_string(),
+                "Set compliance.mode = \"warn\" to record findings without blocking".to_string(),
+            ],
             Self::ResourceLimitExceeded {
                 phase: _,
                 resource,
@@ -631,6 +679,13 @@ impl UserFriendlyError for PhaseError {
                 "Use the partial output to understand where the phase failed".to_string(),
                 "Try resuming the phase after addressing any issues".to_string(),
             ],
+            Self::InjectedFailure { phase, point: _ } => vec![
+                "Run again without --inject-failure to complete the phase".to_string(),
+                format!(
+                    "Resume with: xchecker resume <id> --phase {}",
+                    phase.to_lowercase()
+                ),
+            ],
         }
     }
 
@@ -667,6 +722,12 @@ pub enum SourceError {
     #[error("Filesystem path is not a directory: {path}")]
     FileSystemNotDirectory { path: String },
 
+    #[error("Filesystem path is outside the trusted source roots: {path}")]
+    FileSystemNotTrusted { path: String, allowed: String },
+
+    #[error("Failed to read {path}: {reason}")]
+    FileSystemReadFailed { path: String, reason: String },
+
     #[error("Stdin read failed: {reason}")]
     StdinReadFailed { reason: String },
 
@@ -701,6 +762,14 @@ impl UserFriendlyError for SourceError {
             Self::FileSystemNotDirectory { path } => {
                 format!("Path '{path}' is not a directory")
             }
+            Self::FileSystemNotTrusted { path, allowed } => {
+                format!(
+                    "Path '{path}' is outside the directories sources may be read from ({allowed})"
+                )
+            }
+            Self::FileSystemReadFailed { path, reason } => {
+                format!("Failed to read '{path}': {reason}")
+            }
             Self::StdinReadFailed { reason } => {
                 format!("Failed to read from standard input: {reason}")
             }
@@ -736,6 +805,12 @@ impl UserFriendlyError for SourceError {
             Self::FileSystemNotDirectory { .. } => {
                 Some("Filesystem source resolution expects a directory containing project files.".to_string())
             }
+            Self::FileSystemNotTrusted { .. } => {
+                Some("Filesystem sources are limited to `source_roots` in [security] (default: the project root) so a config cannot pull files like /etc or ~/.ssh into packets.".to_string())
+            }
+            Self::FileSystemReadFailed { .. } => {
+                Some("The path exists but reading it failed with an I/O error.".to_string())
+            }
             Self::StdinReadFailed { .. } => {
                 Some("Standard input is used when no other source is specified or when --source stdin is used.".to_string())
             }
@@ -809,6 +884,15 @@ impl UserFriendlyError for SourceError {
                 "Specify a directory path, not a file path".to_string(),
                 "Check that the path points to a directory".to_string(),
             ],
+            Self::FileSystemNotTrusted { .. } => vec![
+                "Run xchecker from the project that contains the source".to_string(),
+                "Pass --source-root <dir> to allow a directory outside the project".to_string(),
+                "Add a project subdirectory to `source_roots` under [security] in .xchecker/config.toml".to_string(),
+            ],
+            Self::FileSystemReadFailed { path, .. } => vec![
+                format!("Check that '{}' is a readable regular file", path),
+                "Retry once the file is no longer being written".to_string(),
+            ],
             Self::StdinReadFailed { .. } => vec![
                 "Provide input via pipe: echo 'problem statement' | xchecker spec <id>".to_string(),
                 "Use a different source: --source fs --repo /path/to/project".to_string(),
@@ -1417,6 +1501,15 @@ impl UserFriendlyError for LockError {
             } => {
                 format!("Stale lock detected for spec '{spec_id}' (PID {pid}, age {age_secs}s)")
             }
+            Self::RepoBusy {
+                holder_spec,
+                pid,
+                waited_secs,
+            } => {
+                format!(
+                    "Spec '{holder_spec}' (PID {pid}) is still modifying the working tree after {waited_secs}s"
+                )
+            }
             Self::CorruptedLock { reason } => {
                 format!("Lock file is corrupted or invalid: {reason}")
             }
@@ -1440,6 +1533,9 @@ impl UserFriendlyError for LockError {
             Self::StaleLock { .. } => {
                 Some("Stale locks can occur when xchecker processes are terminated unexpectedly. The lock system prevents accidental conflicts.".to_string())
             }
+            Self::RepoBusy { .. } => {
+                Some("Phases that apply fixups or run hooks hold a repo-level lock so two specs never interleave writes to the same working tree.".to_string())
+            }
             Self::CorruptedLock { .. } => {
                 Some("Lock files contain process information in JSON format. Corruption can occur due to disk issues or interrupted writes.".to_string())
             }
@@ -1469,6 +1565,10 @@ impl UserFriendlyError for LockError {
                 "Check system logs for any crashed xchecker processes".to_string(),
                 "Consider cleaning up old spec directories if they're no longer needed".to_string(),
             ],
+            Self::RepoBusy { holder_spec, pid, .. } => vec![
+                format!("Wait for spec '{}' (PID {}) to finish its phase, then retry", holder_spec, pid),
+                "Run specs that apply fixups or run hooks from separate worktrees (git worktree add) to let them proceed in parallel".to_string(),
+            ],
             Self::CorruptedLock { .. } => vec![
                 "Remove the corrupted lock file manually: rm .xchecker/specs/<spec_id>/.lock".to_string(),
                 "Check disk space and file system integrity".to_string(),
@@ -1512,7 +1612,9 @@ impl UserFriendlyError for LockError {
 
     fn category(&self) -> ErrorCategory {
         match self {
-            Self::ConcurrentExecution { .. } | Self::StaleLock { .. } => ErrorCategory::Concurrency,
+            Self::ConcurrentExecution { .. } | Self::StaleLock { .. } | Self::RepoBusy { .. } => {
+                ErrorCategory::Concurrency
+            }
             Self::CorruptedLock { .. } => ErrorCategory::Validation,
             Self::AcquisitionFailed { .. } | Self::ReleaseFailed { .. } => {
                 ErrorCategory::FileSystem
@@ -1637,6 +1739,37 @@ pub enum LlmError {
     /// Unsupported feature or provider
     #[error("Unsupported: {0}")]
     Unsupported(String),
+
+    /// Provider response failed post-stream validation (encoding, BOM, size)
+    #[error("Invalid response after {attempts} attempt(s): {}", checks.join(", "))]
+    InvalidResponse { checks: Vec<String>, attempts: u32 },
+
+    /// Prompt and packet exceed the model's context window
+    #[error("Context overflow: {0}")]
+    ContextOverflow(String),
+
+    /// Provider CLI exited abnormally before producing any output
+    #[error("Provider crashed: {message} (restarted {restarts} time(s))")]
+    ProviderCrash { message: String, restarts: u32 },
+}
+
+impl LlmError {
+    /// Receipt classification of a provider failure
+    ///
+    /// `provider_crash` when the provider process died without output,
+    /// `provider_error` when the provider reported an error; `None` for
+    /// failures on xchecker's side (timeouts, validation, configuration).
+    #[must_use]
+    pub fn provider_failure(&self) -> Option<&'static str> {
+        match self {
+            Self::ProviderCrash { .. } => Some("provider_crash"),
+            Self::Transport(_)
+            | Self::ProviderAuth(_)
+            | Self::ProviderQuota(_)
+            | Self::ProviderOutage(_) => Some("provider_error"),
+            _ => None,
+        }
+    }
 }
 
 impl UserFriendlyError for LlmError {
@@ -1657,6 +1790,16 @@ impl UserFriendlyError for LlmError {
             }
             Self::Misconfiguration(msg) => format!("LLM configuration error: {msg}"),
             Self::Unsupported(msg) => format!("LLM feature not supported: {msg}"),
+            Self::InvalidResponse { checks, attempts } => format!(
+                "LLM response rejected after {attempts} attempt(s): {}",
+                checks.join(", ")
+            ),
+            Self::ContextOverflow(msg) => {
+                format!("Packet does not fit the model's context window: {msg}")
+            }
+            Self::ProviderCrash { message, restarts } => {
+                format!("LLM provider crashed after {restarts} restart(s): {message}")
+            }
         }
     }
 
@@ -1690,6 +1833,18 @@ impl UserFriendlyError for LlmError {
             Self::Unsupported(_) => Some(
                 "Some LLM features are not yet supported in this version of xchecker.".to_string(),
             ),
+            Self::InvalidResponse { .. } => Some(
+                "Responses are checked for encoding problems, byte order marks, base64 dumps, and minimum size before any artifact is written."
+                    .to_string(),
+            ),
+            Self::ContextOverflow(_) => Some(
+                "The packet is rebuilt at a smaller budget and retried before this error is reported."
+                    .to_string(),
+            ),
+            Self::ProviderCrash { .. } => Some(
+                "The provider process exited without producing output and was restarted up to max_restarts times."
+                    .to_string(),
+            ),
         }
     }
 
@@ -1735,6 +1890,23 @@ impl UserFriendlyError for LlmError {
                 "Consider upgrading to a newer version of xchecker".to_string(),
                 "Use an alternative approach if available".to_string(),
             ],
+            Self::InvalidResponse { .. } => vec![
+                "Re-run the phase; malformed responses are often transient".to_string(),
+                "Increase response_retries in [defaults] to retry automatically".to_string(),
+                "Lower response_min_bytes if the phase legitimately produces short output"
+                    .to_string(),
+            ],
+            Self::ContextOverflow(_) => vec![
+                "Lower packet_max_bytes in [defaults] to send a smaller packet".to_string(),
+                "Increase overflow_retries or lower overflow_shrink_factor in [packet]".to_string(),
+                "Narrow [selectors] include patterns or use a model with a larger context window"
+                    .to_string(),
+            ],
+            Self::ProviderCrash { .. } => vec![
+                "Run the provider CLI directly to check that it starts cleanly".to_string(),
+                "Increase max_restarts in the provider's [llm.<provider>] section".to_string(),
+                "Consider using a fallback provider if configured".to_string(),
+            ],
         }
     }
 
@@ -1748,6 +1920,9 @@ impl UserFriendlyError for LlmError {
             Self::BudgetExceeded { .. } => ErrorCategory::ResourceLimits,
             Self::Misconfiguration(_) => ErrorCategory::Configuration,
             Self::Unsupported(_) => ErrorCategory::Configuration,
+            Self::InvalidResponse { .. } => ErrorCategory::ClaudeIntegration,
+            Self::ContextOverflow(_) => ErrorCategory::ResourceLimits,
+            Self::ProviderCrash { .. } => ErrorCategory::ClaudeIntegration,
         }
     }
 }
@@ -1779,6 +1954,15 @@ impl UserFriendlyError for XCheckerError {
                     "Packet size exceeded limits: {used_bytes} bytes/{used_lines} lines used, {limit_bytes} bytes/{limit_lines} lines allowed"
                 )
             }
+            Self::PacketMemoryExceeded {
+                used_bytes,
+                limit_bytes,
+                path,
+            } => {
+                format!(
+                    "Packet assembly exceeded its memory budget while reading {path}: {used_bytes} bytes held, {limit_bytes} bytes allowed"
+                )
+            }
             Self::ConcurrentExecution { id } => {
                 format!("Another xchecker process is already working on spec '{id}'")
             }
@@ -1831,6 +2015,9 @@ impl UserFriendlyError for XCheckerError {
             Self::PacketOverflow { used_bytes: _, used_lines: _, limit_bytes: _, limit_lines: _ } => {
                 Some("Packet size limits prevent excessive token usage and ensure Claude API calls remain efficient.".to_string())
             }
+            Self::PacketMemoryExceeded { .. } => {
+                Some("The packet memory budget stops packet assembly before pathological inputs exhaust system memory.".to_string())
+            }
             Self::ConcurrentExecution { id: _ } => {
                 Some("xchecker uses file locking to prevent data corruption from simultaneous executions.".to_string())
             }
@@ -1893,6 +2080,15 @@ impl UserFriendlyError for XCheckerError {
                 "Use more specific include/exclude patterns to reduce content".to_string(),
                 "Split large files into smaller, more focused pieces".to_string(),
             ],
+            Self::PacketMemoryExceeded { limit_bytes, .. } => vec![
+                format!(
+                    "Increase max_memory_bytes in [packet] (current limit: {})",
+                    limit_bytes
+                ),
+                "Lower spill_threshold_bytes in [packet] so large files are spilled to disk"
+                    .to_string(),
+                "Use more specific include/exclude patterns to reduce content".to_string(),
+            ],
             Self::ConcurrentExecution { id } => vec![
                 format!(
                     "Wait for the other process to complete or use 'xchecker status {}' to check progress",
@@ -1957,6 +2153,7 @@ impl UserFriendlyError for XCheckerError {
             Self::Io(_) => ErrorCategory::FileSystem,
             Self::SecretDetected { .. } => ErrorCategory::Security,
             Self::PacketOverflow { .. } => ErrorCategory::ResourceLimits,
+            Self::PacketMemoryExceeded { .. } => ErrorCategory::ResourceLimits,
             Self::ConcurrentExecution { .. } => ErrorCategory::Concurrency,
             Self::PacketPreviewTooLarge { .. } => ErrorCategory::ResourceLimits,
             Self::CanonicalizationFailed { .. } => ErrorCategory::Validation,
@@ -2079,6 +2276,7 @@ impl XCheckerError {
 
             // Packet overflow before Claude invocation
             XCheckerError::PacketOverflow { .. } => ExitCode::PACKET_OVERFLOW,
+            XCheckerError::PacketMemoryExceeded { .. } => ExitCode::PACKET_OVERFLOW,
 
             // Secret detection (redaction hard stop)
             XCheckerError::SecretDetected { .. } => ExitCode::SECRET_DETECTED,
@@ -2091,6 +2289,8 @@ impl XCheckerError {
             XCheckerError::Phase(phase_err) => {
                 match phase_err {
                     PhaseError::Timeout { .. } => ExitCode::PHASE_TIMEOUT,
+                    PhaseError::OperatorHalt { .. } => ExitCode::OPERATOR_HALT,
+                    PhaseError::ComplianceBlocked { .. } => ExitCode::COMPLIANCE_BLOCKED,
                     // Invalid transitions are CLI argument errors (FR-ORC-001, FR-ORC-002)
                     PhaseError::InvalidTransition { .. } => ExitCode::CLI_ARGS,
                     PhaseError::DependencyNotSatisfied { .. } => ExitCode::CLI_ARGS,
@@ -2114,6 +2314,9 @@ impl XCheckerError {
                     LlmError::Unsupported(_) => ExitCode::CLI_ARGS,
                     LlmError::Transport(_) => ExitCode::CLAUDE_FAILURE,
                     LlmError::BudgetExceeded { .. } => ExitCode::CLAUDE_FAILURE,
+                    LlmError::InvalidResponse { .. } => ExitCode::CLAUDE_FAILURE,
+                    LlmError::ContextOverflow(_) => ExitCode::PACKET_OVERFLOW,
+                    LlmError::ProviderCrash { .. } => ExitCode::CLAUDE_FAILURE,
                 }
             }
 
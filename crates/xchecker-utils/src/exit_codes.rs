@@ -92,6 +92,12 @@ impl ExitCode {
     /// Phase timeout - phase execution exceeded configured timeout
     pub const PHASE_TIMEOUT: ExitCode = ExitCode(10);
 
+    /// Operator halt - the kill switch file stopped the phase
+    pub const OPERATOR_HALT: ExitCode = ExitCode(11);
+
+    /// Compliance blocked - the license/compliance scan stopped the packet in block mode
+    pub const COMPLIANCE_BLOCKED: ExitCode = ExitCode(12);
+
     /// Claude failure - underlying Claude CLI invocation failed
     pub const CLAUDE_FAILURE: ExitCode = ExitCode(70);
 
@@ -148,6 +154,12 @@ pub mod codes {
     /// Phase timeout - phase execution exceeded configured timeout
     pub const PHASE_TIMEOUT: i32 = 10;
 
+    /// Operator halt - the kill switch file stopped the phase
+    pub const OPERATOR_HALT: i32 = 11;
+
+    /// Compliance blocked - the license/compliance scan stopped the packet in block mode
+    pub const COMPLIANCE_BLOCKED: i32 = 12;
+
     /// Claude failure - underlying Claude CLI invocation failed
     pub const CLAUDE_FAILURE: i32 = 70;
 }
@@ -161,6 +173,9 @@ pub fn error_to_exit_code_and_kind(error: &XCheckerError) -> (i32, ErrorKind) {
 
         // Packet overflow before Claude invocation
         XCheckerError::PacketOverflow { .. } => (codes::PACKET_OVERFLOW, ErrorKind::PacketOverflow),
+        XCheckerError::PacketMemoryExceeded { .. } => {
+            (codes::PACKET_OVERFLOW, ErrorKind::PacketOverflow)
+        }
 
         // Secret detection (redaction hard stop)
         XCheckerError::SecretDetected { .. } => (codes::SECRET_DETECTED, ErrorKind::SecretDetected),
@@ -174,6 +189,10 @@ pub fn error_to_exit_code_and_kind(error: &XCheckerError) -> (i32, ErrorKind) {
             use crate::error::PhaseError;
             match phase_err {
                 PhaseError::Timeout { .. } => (codes::PHASE_TIMEOUT, ErrorKind::PhaseTimeout),
+                PhaseError::OperatorHalt { .. } => (codes::OPERATOR_HALT, ErrorKind::OperatorHalt),
+                PhaseError::ComplianceBlocked { .. } => {
+                    (codes::COMPLIANCE_BLOCKED, ErrorKind::ComplianceBlocked)
+                }
                 // Invalid transitions are CLI argument errors (FR-ORC-001, FR-ORC-002)
                 PhaseError::InvalidTransition { .. } => (codes::CLI_ARGS, ErrorKind::CliArgs),
                 PhaseError::DependencyNotSatisfied { .. } => (codes::CLI_ARGS, ErrorKind::CliArgs),
@@ -199,6 +218,11 @@ pub fn error_to_exit_code_and_kind(error: &XCheckerError) -> (i32, ErrorKind) {
                 LlmError::BudgetExceeded { .. } => {
                     (codes::CLAUDE_FAILURE, ErrorKind::ClaudeFailure)
                 }
+                LlmError::InvalidResponse { .. } => {
+                    (codes::CLAUDE_FAILURE, ErrorKind::ClaudeFailure)
+                }
+                LlmError::ContextOverflow(_) => (codes::PACKET_OVERFLOW, ErrorKind::PacketOverflow),
+                LlmError::ProviderCrash { .. } => (codes::CLAUDE_FAILURE, ErrorKind::ClaudeFailure),
             }
         }
 
@@ -229,6 +253,8 @@ mod tests {
         assert_eq!(codes::SECRET_DETECTED, 8);
         assert_eq!(codes::LOCK_HELD, 9);
         assert_eq!(codes::PHASE_TIMEOUT, 10);
+        assert_eq!(codes::OPERATOR_HALT, 11);
+        assert_eq!(codes::COMPLIANCE_BLOCKED, 12);
         assert_eq!(codes::CLAUDE_FAILURE, 70);
     }
 
@@ -247,9 +273,15 @@ mod tests {
         let json = serde_json::to_string(&ErrorKind::LockHeld).unwrap();
         assert_eq!(json, r#""lock_held""#);
 
+        let json = serde_json::to_string(&ErrorKind::ComplianceBlocked).unwrap();
+        assert_eq!(json, r#""compliance_blocked""#);
+
         let json = serde_json::to_string(&ErrorKind::PhaseTimeout).unwrap();
         assert_eq!(json, r#""phase_timeout""#);
 
+        let json = serde_json::to_string(&ErrorKind::OperatorHalt).unwrap();
+        assert_eq!(json, r#""operator_halt""#);
+
         let json = serde_json::to_string(&ErrorKind::ClaudeFailure).unwrap();
         assert_eq!(json, r#""claude_failure""#);
 
@@ -324,6 +356,30 @@ mod tests {
         assert_eq!(kind, ErrorKind::PhaseTimeout);
     }
 
+    #[test]
+    fn test_operator_halt_mapping() {
+        let err = XCheckerError::Phase(PhaseError::OperatorHalt {
+            phase: "DESIGN".to_string(),
+            path: "/home/ops/.xchecker/KILL".to_string(),
+        });
+        let (code, kind) = (&err).into();
+        assert_eq!(code, codes::OPERATOR_HALT);
+        assert_eq!(kind, ErrorKind::OperatorHalt);
+        assert_eq!(err.to_exit_code(), ExitCode::OPERATOR_HALT);
+    }
+
+    #[test]
+    fn test_compliance_blocked_mapping() {
+        let err = XCheckerError::Phase(PhaseError::ComplianceBlocked {
+            phase: "DESIGN".to_string(),
+            findings: "gpl_license in src/vendored.rs:1".to_string(),
+        });
+        let (code, kind) = (&err).into();
+        assert_eq!(code, codes::COMPLIANCE_BLOCKED);
+        assert_eq!(kind, ErrorKind::ComplianceBlocked);
+        assert_eq!(err.to_exit_code(), ExitCode::COMPLIANCE_BLOCKED);
+    }
+
     #[test]
     fn test_phase_non_timeout_mapping() {
         let phase_err = PhaseError::ExecutionFailed {
@@ -421,6 +477,20 @@ mod tests {
         assert_eq!(kind, ErrorKind::ClaudeFailure);
     }
 
+    #[test]
+    fn test_llm_provider_crash_mapping() {
+        use crate::error::LlmError;
+        let llm_err = LlmError::ProviderCrash {
+            message: "Gemini CLI exited with status: signal 11".to_string(),
+            restarts: 1,
+        };
+        assert_eq!(llm_err.provider_failure(), Some("provider_crash"));
+        let err = XCheckerError::Llm(llm_err);
+        let (code, kind) = (&err).into();
+        assert_eq!(code, codes::CLAUDE_FAILURE);
+        assert_eq!(kind, ErrorKind::ClaudeFailure);
+    }
+
     #[test]
     fn test_llm_timeout_mapping() {
         use crate::error::LlmError;
@@ -477,6 +547,19 @@ mod tests {
         assert_eq!(kind, ErrorKind::ClaudeFailure);
     }
 
+    #[test]
+    fn test_llm_invalid_response_mapping() {
+        use crate::error::LlmError;
+        let llm_err = LlmError::InvalidResponse {
+            checks: vec!["byte_order_mark".to_string()],
+            attempts: 2,
+        };
+        let err = XCheckerError::Llm(llm_err);
+        let (code, kind) = (&err).into();
+        assert_eq!(code, codes::CLAUDE_FAILURE);
+        assert_eq!(kind, ErrorKind::ClaudeFailure);
+    }
+
     #[test]
     fn test_validation_failed_mapping() {
         // ValidationFailed maps to exit code 1 (general error) with Unknown kind
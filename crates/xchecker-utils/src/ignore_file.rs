@@ -0,0 +1,144 @@
+//! Project-level `.xcheckerignore` file
+//!
+//! A `.xcheckerignore` in the project root uses gitignore syntax (`#`
+//! comments, `!` negation, trailing `/` for directories, leading `/` to anchor
+//! at the root) and keeps files out of packets and directory sources on top of
+//! the configured selector globs. Patterns are matched against paths relative
+//! to the project root; paths outside it are never ignored.
+
+use anyhow::{Context, Result, anyhow};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::path::{Path, PathBuf};
+
+/// Name of the ignore file looked up in the project root
+pub const IGNORE_FILE_NAME: &str = ".xcheckerignore";
+
+/// Patterns loaded from a `.xcheckerignore` file
+#[derive(Debug, Clone)]
+pub struct IgnoreFile {
+    /// Directory the patterns are relative to
+    root: PathBuf,
+    /// Canonical form of `root`, for matching canonicalized paths
+    canonical_root: Option<PathBuf>,
+    matcher: Gitignore,
+}
+
+impl IgnoreFile {
+    /// An ignore file with no patterns
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            root: PathBuf::new(),
+            canonical_root: None,
+            matcher: Gitignore::empty(),
+        }
+    }
+
+    /// Load `<root>/.xcheckerignore`; a missing file yields no patterns
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or contains an
+    /// invalid pattern.
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(IGNORE_FILE_NAME);
+        if !path.is_file() {
+            return Ok(Self {
+                root: root.to_path_buf(),
+                ..Self::empty()
+            });
+        }
+
+        let mut builder = GitignoreBuilder::new(root);
+        if let Some(err) = builder.add(&path) {
+            return Err(anyhow!(err)).with_context(|| format!("Invalid {}", path.display()));
+        }
+        let matcher = builder
+            .build()
+            .with_context(|| format!("Invalid {}", path.display()))?;
+        Ok(Self {
+            root: root.to_path_buf(),
+            canonical_root: root.canonicalize().ok(),
+            matcher,
+        })
+    }
+
+    /// Load the ignore file of the project root (the current directory)
+    ///
+    /// # Errors
+    ///
+    /// See [`load`](Self::load).
+    pub fn project_root() -> Result<Self> {
+        let root = std::env::current_dir().context("Failed to read current directory")?;
+        Self::load(&root)
+    }
+
+    /// Whether the file had no patterns (or did not exist)
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.matcher.is_empty()
+    }
+
+    /// Whether `path` is ignored, either itself or through an ignored parent
+    ///
+    /// Relative paths are taken as relative to the project root.
+    #[must_use]
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        let relative = if path.is_relative() {
+            path
+        } else if let Ok(rest) = path.strip_prefix(&self.root) {
+            rest
+        } else if let Some(rest) = self
+            .canonical_root
+            .as_deref()
+            .and_then(|root| path.strip_prefix(root).ok())
+        {
+            rest
+        } else {
+            return false;
+        };
+        self.matcher
+            .matched_path_or_any_parents(relative, is_dir)
+            .is_ignore()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_gitignore_syntax_is_honored() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join(IGNORE_FILE_NAME),
+            "# generated output\n*.log\n!keep.log\n/drafts/\nnotes/**/*.md\n",
+        )
+        .unwrap();
+        let ignore = IgnoreFile::load(temp.path()).unwrap();
+        assert!(!ignore.is_empty());
+
+        assert!(ignore.is_ignored(&temp.path().join("build.log"), false));
+        assert!(ignore.is_ignored(Path::new("sub/build.log"), false));
+        assert!(!ignore.is_ignored(Path::new("keep.log"), false));
+        assert!(ignore.is_ignored(Path::new("drafts/idea.md"), false));
+        assert!(!ignore.is_ignored(Path::new("sub/drafts/idea.md"), false));
+        assert!(ignore.is_ignored(Path::new("notes/a/b.md"), false));
+        assert!(!ignore.is_ignored(Path::new("README.md"), false));
+
+        // Paths outside the project root are never ignored
+        assert!(!ignore.is_ignored(Path::new("/elsewhere/build.log"), false));
+    }
+
+    #[test]
+    fn test_missing_file_ignores_nothing() {
+        let temp = TempDir::new().unwrap();
+        let ignore = IgnoreFile::load(temp.path()).unwrap();
+        assert!(ignore.is_empty());
+        assert!(!ignore.is_ignored(Path::new("build.log"), false));
+    }
+}
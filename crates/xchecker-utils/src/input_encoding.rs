@@ -0,0 +1,296 @@
+//! Encoding and newline normalization for input files
+//!
+//! Inputs are normalized before they are hashed or placed in a packet, so the
+//! same file checked out with CRLF line endings or a BOM on one platform hashes
+//! identically to its plain LF checkout on another. A leading UTF-8 BOM is
+//! dropped, BOM-prefixed UTF-16 is decoded, and `\r\n` and lone `\r` line
+//! endings become `\n`. Bytes that are not valid UTF-8 are rejected or replaced
+//! with U+FFFD according to the [`NonUtf8Policy`]. Every change is reported as
+//! a [`Conversion`] so callers can surface it as a warning.
+
+use std::fmt;
+use std::str::FromStr;
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+const UTF16_LE_BOM: &[u8] = b"\xFF\xFE";
+const UTF16_BE_BOM: &[u8] = b"\xFE\xFF";
+
+/// What to do with input that is not valid UTF-8
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NonUtf8Policy {
+    /// Fail with [`NonUtf8Error`]
+    #[default]
+    Reject,
+    /// Replace invalid sequences with U+FFFD and report a conversion
+    Lossy,
+}
+
+impl NonUtf8Policy {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Reject => "reject",
+            Self::Lossy => "lossy",
+        }
+    }
+}
+
+impl FromStr for NonUtf8Policy {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "reject" => Ok(Self::Reject),
+            "lossy" => Ok(Self::Lossy),
+            other => Err(format!("'{other}' (expected \"reject\" or \"lossy\")")),
+        }
+    }
+}
+
+/// Kind of change made while normalizing an input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionKind {
+    /// A leading UTF-8 byte order mark was removed
+    BomStripped,
+    /// The input was UTF-16 (detected by its BOM) and was decoded
+    Utf16Decoded,
+    /// `\r\n` or lone `\r` line endings were converted to `\n`
+    NewlinesNormalized,
+    /// Invalid UTF-8 sequences were replaced with U+FFFD
+    LossyUtf8,
+}
+
+impl ConversionKind {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::BomStripped => "bom_stripped",
+            Self::Utf16Decoded => "utf16_decoded",
+            Self::NewlinesNormalized => "newlines_normalized",
+            Self::LossyUtf8 => "lossy_utf8",
+        }
+    }
+}
+
+/// One change made to an input, with the number of occurrences
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conversion {
+    pub kind: ConversionKind,
+    /// Line endings converted or invalid sequences replaced; 1 otherwise
+    pub count: usize,
+}
+
+impl fmt::Display for Conversion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ConversionKind::BomStripped => write!(f, "removed UTF-8 BOM"),
+            ConversionKind::Utf16Decoded => write!(f, "decoded from UTF-16"),
+            ConversionKind::NewlinesNormalized => {
+                write!(f, "normalized {} CRLF/CR line ending(s)", self.count)
+            }
+            ConversionKind::LossyUtf8 => {
+                write!(f, "replaced {} invalid UTF-8 sequence(s)", self.count)
+            }
+        }
+    }
+}
+
+/// Input rejected under [`NonUtf8Policy::Reject`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("not valid UTF-8 (first invalid byte at offset {offset})")]
+pub struct NonUtf8Error {
+    /// Byte offset of the first invalid sequence
+    pub offset: usize,
+}
+
+/// Normalized text and the changes made to produce it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedInput {
+    pub text: String,
+    pub conversions: Vec<Conversion>,
+}
+
+/// Decode and normalize raw input bytes
+pub fn normalize_input(
+    bytes: Vec<u8>,
+    policy: NonUtf8Policy,
+) -> Result<NormalizedInput, NonUtf8Error> {
+    let mut conversions = Vec::new();
+
+    let text = if let Some(rest) = bytes.strip_prefix(UTF16_LE_BOM) {
+        conversions.push(Conversion {
+            kind: ConversionKind::Utf16Decoded,
+            count: 1,
+        });
+        decode_utf16(rest, u16::from_le_bytes, policy, &mut conversions)?
+    } else if let Some(rest) = bytes.strip_prefix(UTF16_BE_BOM) {
+        conversions.push(Conversion {
+            kind: ConversionKind::Utf16Decoded,
+            count: 1,
+        });
+        decode_utf16(rest, u16::from_be_bytes, policy, &mut conversions)?
+    } else {
+        let bytes = if bytes.starts_with(UTF8_BOM) {
+            conversions.push(Conversion {
+                kind: ConversionKind::BomStripped,
+                count: 1,
+            });
+            bytes[UTF8_BOM.len()..].to_vec()
+        } else {
+            bytes
+        };
+        decode_utf8(bytes, policy, &mut conversions)?
+    };
+
+    let line_endings = text.matches('\r').count();
+    let text = if line_endings == 0 {
+        text
+    } else {
+        conversions.push(Conversion {
+            kind: ConversionKind::NewlinesNormalized,
+            count: line_endings,
+        });
+        text.replace("\r\n", "\n").replace('\r', "\n")
+    };
+
+    Ok(NormalizedInput { text, conversions })
+}
+
+fn decode_utf8(
+    bytes: Vec<u8>,
+    policy: NonUtf8Policy,
+    conversions: &mut Vec<Conversion>,
+) -> Result<String, NonUtf8Error> {
+    match String::from_utf8(bytes) {
+        Ok(text) => Ok(text),
+        Err(e) if policy == NonUtf8Policy::Reject => Err(NonUtf8Error {
+            offset: e.utf8_error().valid_up_to(),
+        }),
+        Err(e) => {
+            let bytes = e.into_bytes();
+            let replaced = bytes
+                .utf8_chunks()
+                .filter(|chunk| !chunk.invalid().is_empty())
+                .count();
+            conversions.push(Conversion {
+                kind: ConversionKind::LossyUtf8,
+                count: replaced,
+            });
+            Ok(String::from_utf8_lossy(&bytes).into_owned())
+        }
+    }
+}
+
+fn decode_utf16(
+    bytes: &[u8],
+    unit: fn([u8; 2]) -> u16,
+    policy: NonUtf8Policy,
+    conversions: &mut Vec<Conversion>,
+) -> Result<String, NonUtf8Error> {
+    // Offsets are reported relative to the start of the file, BOM included
+    const BOM_LEN: usize = 2;
+
+    let pairs = bytes.chunks_exact(2);
+    let odd_trailing_byte = !pairs.remainder().is_empty();
+    let mut text = String::with_capacity(bytes.len() / 2);
+    let mut replaced = 0;
+    let mut offset = BOM_LEN;
+    for decoded in char::decode_utf16(pairs.map(|pair| unit([pair[0], pair[1]]))) {
+        match decoded {
+            Ok(c) => {
+                text.push(c);
+                offset += c.len_utf16() * 2;
+            }
+            // An unpaired surrogate consumes one unit
+            Err(_) if policy == NonUtf8Policy::Reject => return Err(NonUtf8Error { offset }),
+            Err(_) => {
+                replaced += 1;
+                text.push(char::REPLACEMENT_CHARACTER);
+                offset += 2;
+            }
+        }
+    }
+    if odd_trailing_byte {
+        if policy == NonUtf8Policy::Reject {
+            return Err(NonUtf8Error {
+                offset: BOM_LEN + bytes.len() - 1,
+            });
+        }
+        replaced += 1;
+        text.push(char::REPLACEMENT_CHARACTER);
+    }
+
+    if replaced > 0 {
+        conversions.push(Conversion {
+            kind: ConversionKind::LossyUtf8,
+            count: replaced,
+        });
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(input: &NormalizedInput) -> Vec<ConversionKind> {
+        input.conversions.iter().map(|c| c.kind).collect()
+    }
+
+    #[test]
+    fn test_bom_and_mixed_newlines_normalize_to_plain_lf() {
+        let plain = normalize_input(b"a\nb\nc\n".to_vec(), NonUtf8Policy::Reject).unwrap();
+        assert_eq!(plain.text, "a\nb\nc\n");
+        assert!(plain.conversions.is_empty());
+
+        let windows =
+            normalize_input(b"\xEF\xBB\xBFa\r\nb\rc\n".to_vec(), NonUtf8Policy::Reject).unwrap();
+        assert_eq!(windows.text, plain.text);
+        assert_eq!(
+            kinds(&windows),
+            vec![
+                ConversionKind::BomStripped,
+                ConversionKind::NewlinesNormalized
+            ]
+        );
+        assert_eq!(windows.conversions[1].count, 2);
+        assert_eq!(
+            windows.conversions[1].to_string(),
+            "normalized 2 CRLF/CR line ending(s)"
+        );
+    }
+
+    #[test]
+    fn test_non_utf8_policy() {
+        let bytes = b"caf\xE9 \xFF\n".to_vec();
+        assert_eq!(
+            normalize_input(bytes.clone(), NonUtf8Policy::Reject),
+            Err(NonUtf8Error { offset: 3 })
+        );
+
+        let lossy = normalize_input(bytes, NonUtf8Policy::Lossy).unwrap();
+        assert_eq!(lossy.text, "caf\u{FFFD} \u{FFFD}\n");
+        assert_eq!(lossy.conversions[0].kind, ConversionKind::LossyUtf8);
+        assert_eq!(lossy.conversions[0].count, 2);
+
+        assert_eq!("lossy".parse(), Ok(NonUtf8Policy::Lossy));
+        assert!("latin1".parse::<NonUtf8Policy>().is_err());
+    }
+
+    #[test]
+    fn test_utf16_with_bom_is_decoded() {
+        let mut bytes = vec![0xFF, 0xFE];
+        for unit in "hé\r\n".encode_utf16() {
+            bytes.extend(unit.to_le_bytes());
+        }
+        let decoded = normalize_input(bytes, NonUtf8Policy::Reject).unwrap();
+        assert_eq!(decoded.text, "hé\n");
+        assert_eq!(
+            kinds(&decoded),
+            vec![
+                ConversionKind::Utf16Decoded,
+                ConversionKind::NewlinesNormalized
+            ]
+        );
+    }
+}
@@ -1,13 +1,20 @@
 pub mod atomic_write;
 pub mod cache;
 pub mod canonicalization;
+pub mod compression;
+pub mod console;
+pub mod deprecation;
 pub mod error;
 pub mod exit_codes;
 pub mod failure_taxonomy;
+pub mod ignore_file;
+pub mod input_encoding;
 pub use xchecker_lock as lock;
 pub mod logging;
+pub mod mddiff;
 pub mod paths;
 pub mod process_memory;
+pub mod remediation;
 pub mod ring_buffer;
 pub mod source;
 pub mod spec_id;
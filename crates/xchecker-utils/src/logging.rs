@@ -7,6 +7,7 @@
 
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU8, Ordering};
 use std::time::{Duration, Instant};
 use sysinfo::{Pid, System};
 use tracing::{Level, debug, error, info, span, warn};
@@ -18,34 +19,107 @@ use tracing_subscriber::{
 };
 use xchecker_redaction::SecretRedactor;
 
+/// How much the CLI and its subsystems report (`-q`, default, `-v`, `-vv`)
+///
+/// Every level keeps stdout for command output (results and contracted
+/// JSON); diagnostics from the orchestrator, packet builder, and providers go
+/// through `tracing` to stderr, filtered by this level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Verbosity {
+    /// `-q`: errors only, no progress output
+    Quiet,
+    /// Default: warnings and errors
+    #[default]
+    Normal,
+    /// `-v`: progress and timing information
+    Verbose,
+    /// `-vv`: everything, including debug events
+    Debug,
+}
+
+impl Verbosity {
+    /// Level from the CLI's `--quiet` flag and `-v` count
+    #[must_use]
+    pub fn from_flags(quiet: bool, verbose: u8) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Self::Quiet,
+            (false, 0) => Self::Normal,
+            (false, 1) => Self::Verbose,
+            (false, _) => Self::Debug,
+        }
+    }
+
+    /// Whether only errors should be reported
+    #[must_use]
+    pub fn is_quiet(self) -> bool {
+        self == Self::Quiet
+    }
+
+    /// Whether progress and timing details should be reported
+    #[must_use]
+    pub fn is_verbose(self) -> bool {
+        self >= Self::Verbose
+    }
+
+    /// `EnvFilter` directives used when `RUST_LOG` is unset
+    fn filter_directives(self) -> &'static str {
+        match self {
+            Self::Quiet => "error",
+            Self::Normal => "warn",
+            Self::Verbose => "xchecker=info,warn",
+            Self::Debug => "xchecker=debug,info",
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Quiet,
+            2 => Self::Verbose,
+            3 => Self::Debug,
+            _ => Self::Normal,
+        }
+    }
+}
+
+/// Process-wide verbosity, set once by the CLI
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+/// Set the verbosity for the rest of the process
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+/// Current process-wide verbosity ([`Verbosity::Normal`] unless the CLI set it)
+#[must_use]
+pub fn verbosity() -> Verbosity {
+    Verbosity::from_u8(VERBOSITY.load(Ordering::Relaxed))
+}
+
 /// Initialize tracing subscriber for structured logging (FR-OBS-001)
 ///
-/// Sets up tracing with either compact (default) or verbose format.
-/// Verbose format includes `spec_id`, phase, `duration_ms`, and `runner_mode` fields.
+/// Events go to stderr so stdout only carries command output. `RUST_LOG`
+/// overrides the level's filter. From [`Verbosity::Verbose`] up, the format
+/// includes targets and span timings (`spec_id`, phase, `duration_ms`,
+/// `runner_mode`); below it, a compact human-readable format is used.
 ///
-/// # Arguments
-/// * `verbose` - If true, use verbose format with structured fields
+/// Also records `verbosity` as the process-wide level (see [`verbosity`]).
 ///
 /// # Returns
 /// Result indicating success or failure of initialization
-#[allow(dead_code)] // Future-facing: used when CLI adds --verbose flag
-pub fn init_tracing(verbose: bool) -> Result<(), Box<dyn std::error::Error>> {
+pub fn init_tracing(verbosity: Verbosity) -> Result<(), Box<dyn std::error::Error>> {
+    set_verbosity(verbosity);
+
     let env_filter = EnvFilter::try_from_default_env()
-        .or_else(|_| {
-            if verbose {
-                EnvFilter::try_new("xchecker=debug,info")
-            } else {
-                EnvFilter::try_new("xchecker=info,warn")
-            }
-        })
-        .unwrap_or_else(|_| EnvFilter::new("info"));
+        .or_else(|_| EnvFilter::try_new(verbosity.filter_directives()))
+        .unwrap_or_else(|_| EnvFilter::new("warn"));
 
-    if verbose {
+    if verbosity.is_verbose() {
         // Verbose format: structured with all fields
         tracing_subscriber::registry()
             .with(env_filter)
             .with(
                 fmt::layer()
+                    .with_writer(std::io::stderr)
                     .with_target(true)
                     .with_thread_ids(false)
                     .with_thread_names(false)
@@ -61,6 +135,7 @@ pub fn init_tracing(verbose: bool) -> Result<(), Box<dyn std::error::Error>> {
             .with(env_filter)
             .with(
                 fmt::layer()
+                    .with_writer(std::io::stderr)
                     .with_target(false)
                     .with_thread_ids(false)
                     .with_thread_names(false)
@@ -374,8 +449,8 @@ impl Logger {
                 );
             }
 
-            // Also print to stdout for backward compatibility
-            println!("[{:>8.3}s] {}", elapsed.as_secs_f64(), sanitized_message);
+            // Human-readable line on stderr; stdout is reserved for command output
+            eprintln!("[{:>8.3}s] {}", elapsed.as_secs_f64(), sanitized_message);
         }
     }
 
@@ -411,8 +486,8 @@ impl Logger {
                 );
             }
 
-            // Also print to stdout for backward compatibility
-            println!("[{:>8.3}s] {}", elapsed.as_secs_f64(), sanitized_message);
+            // Human-readable line on stderr; stdout is reserved for command output
+            eprintln!("[{:>8.3}s] {}", elapsed.as_secs_f64(), sanitized_message);
         }
     }
 
@@ -950,21 +1025,30 @@ impl Logger {
 }
 
 /// Log doctor report to console (wires Doctor into logging)
+///
+/// Colors follow [`crate::console`]: plain text when stdout is piped, under
+/// CI, or with `NO_COLOR` set.
 pub fn log_doctor_report(report: &crate::types::DoctorOutput) {
+    use crate::console::{Stream, paint};
     use crate::types::CheckStatus;
-    use crossterm::style::{Attribute, Color, Stylize};
+    use crossterm::style::Color;
 
+    let out = Stream::Stdout;
     println!(
         "{}",
-        "🩺 xchecker Environment Health Check"
-            .with(Color::Cyan)
-            .attribute(Attribute::Bold)
+        paint(
+            "🩺 xchecker Environment Health Check",
+            Some(Color::Cyan),
+            out
+        )
     );
     println!(
         "{}",
-        "─────────────────────────────────────"
-            .with(Color::Cyan)
-            .attribute(Attribute::Bold)
+        paint(
+            "─────────────────────────────────────",
+            Some(Color::Cyan),
+            out
+        )
     );
     println!();
 
@@ -982,40 +1066,38 @@ pub fn log_doctor_report(report: &crate::types::DoctorOutput) {
         // Pass: "✓ Claude Path"
         // Warn: "⚠ Claude Path [WARN]"
         // Fail: "✗ Claude Path [FAIL]"
-        match check.status {
-            CheckStatus::Pass => {
-                println!(
-                    "{} {}",
-                    status_symbol.with(color).attribute(Attribute::Bold),
-                    formatted_name.attribute(Attribute::Bold)
-                );
-            }
-            CheckStatus::Warn => {
-                println!(
-                    "{} {} {}",
-                    status_symbol.with(color).attribute(Attribute::Bold),
-                    formatted_name.attribute(Attribute::Bold),
-                    "[WARN]".with(color).attribute(Attribute::Bold)
-                );
-            }
-            CheckStatus::Fail => {
-                println!(
-                    "{} {} {}",
-                    status_symbol.with(color).attribute(Attribute::Bold),
-                    formatted_name.attribute(Attribute::Bold),
-                    "[FAIL]".with(color).attribute(Attribute::Bold)
-                );
-            }
-        }
+        let tag = match check.status {
+            CheckStatus::Pass => String::new(),
+            CheckStatus::Warn => format!(" {}", paint("[WARN]", Some(color), out)),
+            CheckStatus::Fail => format!(" {}", paint("[FAIL]", Some(color), out)),
+        };
+        println!(
+            "{} {}{tag}",
+            paint(status_symbol, Some(color), out),
+            paint(&formatted_name, None, out)
+        );
 
         println!("  {}", check.details);
         println!();
     }
 
+    for notice in &report.deprecations {
+        println!(
+            "{} {}",
+            paint("⚠", Some(Color::Yellow), out),
+            paint(&format!("Deprecated: {notice}"), None, out)
+        );
+        println!();
+    }
+
     // Add separator
     println!(
         "{}",
-        "─────────────────────────────────────".with(Color::DarkGrey)
+        paint(
+            "─────────────────────────────────────",
+            Some(Color::DarkGrey),
+            out
+        )
     );
 
     let (overall_text, overall_color) = if report.ok {
@@ -1024,20 +1106,25 @@ pub fn log_doctor_report(report: &crate::types::DoctorOutput) {
         ("✗ ISSUES DETECTED", Color::Red)
     };
 
-    println!(
-        "{}",
-        overall_text.with(overall_color).attribute(Attribute::Bold)
-    );
+    println!("{}", paint(overall_text, Some(overall_color), out));
 
     if !report.ok {
         println!();
         println!(
             "{}",
-            "Tip: Run 'xchecker doctor --verbose' for detailed diagnostics.".with(Color::Yellow)
+            paint(
+                "Tip: Run 'xchecker doctor --verbose' for detailed diagnostics.",
+                Some(Color::Yellow),
+                out
+            )
         );
         println!(
             "{}",
-            "     See docs/guides/DOCTOR.md for troubleshooting steps.".with(Color::Yellow)
+            paint(
+                "     See docs/guides/DOCTOR.md for troubleshooting steps.",
+                Some(Color::Yellow),
+                out
+            )
         );
     }
 }
@@ -1216,7 +1303,7 @@ mod tests {
         // Test compact format initialization
         // Note: This will fail if tracing is already initialized in the test process
         // In real usage, init_tracing is called once at program start
-        let result = init_tracing(false);
+        let result = init_tracing(Verbosity::Normal);
         // May fail if already initialized, which is okay in tests
         assert!(result.is_ok() || result.is_err());
     }
@@ -1225,11 +1312,32 @@ mod tests {
     fn test_tracing_initialization_verbose() {
         // Test verbose format initialization
         // Note: This will fail if tracing is already initialized in the test process
-        let result = init_tracing(true);
+        let result = init_tracing(Verbosity::Verbose);
         // May fail if already initialized, which is okay in tests
         assert!(result.is_ok() || result.is_err());
     }
 
+    #[test]
+    fn test_verbosity_from_flags() {
+        assert_eq!(Verbosity::from_flags(false, 0), Verbosity::Normal);
+        assert_eq!(Verbosity::from_flags(false, 1), Verbosity::Verbose);
+        assert_eq!(Verbosity::from_flags(false, 4), Verbosity::Debug);
+        assert_eq!(Verbosity::from_flags(true, 0), Verbosity::Quiet);
+
+        assert!(Verbosity::Quiet.is_quiet());
+        assert!(!Verbosity::Normal.is_verbose());
+        assert!(Verbosity::Debug.is_verbose());
+        for level in [
+            Verbosity::Quiet,
+            Verbosity::Normal,
+            Verbosity::Verbose,
+            Verbosity::Debug,
+        ] {
+            assert_eq!(Verbosity::from_u8(level as u8), level);
+            assert!(EnvFilter::try_new(level.filter_directives()).is_ok());
+        }
+    }
+
     #[test]
     fn test_phase_span_creation() {
         // Test that phase span can be created without panic
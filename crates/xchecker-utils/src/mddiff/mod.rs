@@ -0,0 +1,992 @@
+//! Deterministic, Markdown-aware diffing
+//!
+//! Shared by everything that compares two versions of a spec file: fixup
+//! previews, differential re-review packets, and anything else that needs to
+//! show a human (or a model) what changed. Line-level edit scripts come from a
+//! selectable [`DiffEngine`]:
+//!
+//! - [`MyersDiff`]: the classic shortest edit script (git's default)
+//! - [`PatienceDiff`]: anchors on lines unique to both sides, which keeps
+//!   headings and list items aligned when surrounding text moves
+//! - [`HistogramDiff`]: anchors on the least frequent common lines, which
+//!   avoids matching blank lines and boilerplate across unrelated sections
+//! - [`MarkdownDiff`]: first aligns the documents section by section on their
+//!   headings, then diffs each pair of sections with one of the above, so an
+//!   edit never matches lines across unrelated sections
+//!
+//! [`word_diff`] refines a modified line to the words that changed, the useful
+//! granularity for prose; [`render_diff`] uses it to highlight them.
+//!
+//! Everything here is pure computation over the given text: the same inputs
+//! always produce the same output.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::console::{self, Color, Stream};
+
+mod sections;
+
+pub use sections::{MarkdownDiff, Section, sections};
+
+/// Common lines occurring more often than this are not used as histogram
+/// anchors; the region falls back to Myers instead
+const HISTOGRAM_MAX_OCCURRENCES: usize = 64;
+
+/// Lines of unchanged context around each hunk
+pub const DEFAULT_CONTEXT_LINES: usize = 3;
+
+/// One step of an edit script, by index into the old and new sequences
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffOp {
+    /// `old[old]` and `new[new]` are the same element
+    Equal { old: usize, new: usize },
+    /// `old[old]` was removed
+    Delete { old: usize },
+    /// `new[new]` was added
+    Insert { new: usize },
+}
+
+impl DiffOp {
+    /// The same step with its indices offset by `old_base` and `new_base`
+    const fn shifted(self, old_base: usize, new_base: usize) -> Self {
+        match self {
+            Self::Equal { old, new } => Self::Equal {
+                old: old + old_base,
+                new: new + new_base,
+            },
+            Self::Delete { old } => Self::Delete {
+                old: old + old_base,
+            },
+            Self::Insert { new } => Self::Insert {
+                new: new + new_base,
+            },
+        }
+    }
+}
+
+/// An algorithm producing an edit script between two sequences
+///
+/// Engines diff any sequence of string tokens: lines for file diffs, words
+/// for intra-line highlighting.
+pub trait DiffEngine: Send + Sync {
+    /// Stable lowercase name (e.g. `patience`)
+    fn name(&self) -> &'static str;
+
+    /// Edit script turning `old` into `new`, in order
+    fn diff(&self, old: &[&str], new: &[&str]) -> Vec<DiffOp>;
+}
+
+/// Selectable diff algorithm
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiffAlgorithm {
+    #[default]
+    Myers,
+    Patience,
+    Histogram,
+}
+
+impl DiffAlgorithm {
+    /// Every algorithm, in the order listed in help text
+    pub const ALL: [Self; 3] = [Self::Myers, Self::Patience, Self::Histogram];
+
+    /// Stable lowercase name used on the command line
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Myers => "myers",
+            Self::Patience => "patience",
+            Self::Histogram => "histogram",
+        }
+    }
+
+    /// Engine implementing this algorithm
+    #[must_use]
+    pub fn engine(self) -> Box<dyn DiffEngine> {
+        match self {
+            Self::Myers => Box::new(MyersDiff),
+            Self::Patience => Box::new(PatienceDiff),
+            Self::Histogram => Box::new(HistogramDiff),
+        }
+    }
+}
+
+impl FromStr for DiffAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.as_str() == s)
+            .ok_or_else(|| {
+                format!("unknown diff algorithm '{s}' (expected myers, patience, or histogram)")
+            })
+    }
+}
+
+impl fmt::Display for DiffAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Myers' O(ND) shortest edit script
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MyersDiff;
+
+impl DiffEngine for MyersDiff {
+    fn name(&self) -> &'static str {
+        "myers"
+    }
+
+    fn diff(&self, old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+        let mut ops = Vec::new();
+        diff_trimmed(old, new, 0, 0, &mut ops, &myers);
+        ops
+    }
+}
+
+/// Patience diff: recursively anchors on lines that occur exactly once on
+/// both sides, falling back to Myers between anchors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PatienceDiff;
+
+impl DiffEngine for PatienceDiff {
+    fn name(&self) -> &'static str {
+        "patience"
+    }
+
+    fn diff(&self, old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+        let mut ops = Vec::new();
+        diff_trimmed(old, new, 0, 0, &mut ops, &patience);
+        ops
+    }
+}
+
+/// Histogram diff: recursively anchors on the longest common run that
+/// contains the least frequent common line, falling back to Myers when every
+/// common line is too frequent
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HistogramDiff;
+
+impl DiffEngine for HistogramDiff {
+    fn name(&self) -> &'static str {
+        "histogram"
+    }
+
+    fn diff(&self, old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+        let mut ops = Vec::new();
+        diff_trimmed(old, new, 0, 0, &mut ops, &histogram);
+        ops
+    }
+}
+
+/// Inner algorithm over a region whose first and last elements differ
+type RegionDiff = dyn Fn(&[&str], &[&str], usize, usize, &mut Vec<DiffOp>);
+
+/// Emit the common prefix and suffix as equal, and diff the middle with `inner`
+fn diff_trimmed(
+    old: &[&str],
+    new: &[&str],
+    old_base: usize,
+    new_base: usize,
+    ops: &mut Vec<DiffOp>,
+    inner: &RegionDiff,
+) {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    for i in 0..prefix {
+        ops.push(DiffOp::Equal {
+            old: old_base + i,
+            new: new_base + i,
+        });
+    }
+
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+    let (old_mid_base, new_mid_base) = (old_base + prefix, new_base + prefix);
+    if old_mid.is_empty() || new_mid.is_empty() {
+        ops.extend((0..old_mid.len()).map(|i| DiffOp::Delete {
+            old: old_mid_base + i,
+        }));
+        ops.extend((0..new_mid.len()).map(|i| DiffOp::Insert {
+            new: new_mid_base + i,
+        }));
+    } else {
+        inner(old_mid, new_mid, old_mid_base, new_mid_base, ops);
+    }
+
+    for i in 0..suffix {
+        ops.push(DiffOp::Equal {
+            old: old_base + old.len() - suffix + i,
+            new: new_base + new.len() - suffix + i,
+        });
+    }
+}
+
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)] // indices fit in isize
+fn myers(old: &[&str], new: &[&str], old_base: usize, new_base: usize, ops: &mut Vec<DiffOp>) {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max = n + m;
+    let offset = max;
+    let mut v = vec![0isize; 2 * max as usize + 2];
+    let mut trace = Vec::new();
+
+    'search: for d in 0..=max {
+        trace.push(v.clone());
+        for k in (-d..=d).step_by(2) {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    // Walk the trace backwards from (n, m) to recover the path
+    let mut script = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let prev_k =
+            if k == -d || (k != d && v[(k - 1 + offset) as usize] < v[(k + 1 + offset) as usize]) {
+                k + 1
+            } else {
+                k - 1
+            };
+        let prev_x = v[(prev_k + offset) as usize];
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            script.push(DiffOp::Equal {
+                old: old_base + x as usize,
+                new: new_base + y as usize,
+            });
+        }
+        if d > 0 {
+            if x == prev_x {
+                script.push(DiffOp::Insert {
+                    new: new_base + prev_y as usize,
+                });
+            } else {
+                script.push(DiffOp::Delete {
+                    old: old_base + prev_x as usize,
+                });
+            }
+        }
+        (x, y) = (prev_x, prev_y);
+    }
+
+    ops.extend(script.into_iter().rev());
+}
+
+fn patience(old: &[&str], new: &[&str], old_base: usize, new_base: usize, ops: &mut Vec<DiffOp>) {
+    // (occurrences in old, index in old, occurrences in new, index in new)
+    let mut counts: HashMap<&str, (usize, usize, usize, usize)> = HashMap::new();
+    for (i, line) in old.iter().enumerate() {
+        let entry = counts.entry(line).or_default();
+        entry.0 += 1;
+        entry.1 = i;
+    }
+    for (j, line) in new.iter().enumerate() {
+        if let Some(entry) = counts.get_mut(line) {
+            entry.2 += 1;
+            entry.3 = j;
+        }
+    }
+
+    let mut unique: Vec<(usize, usize)> = counts
+        .values()
+        .filter(|(old_count, _, new_count, _)| *old_count == 1 && *new_count == 1)
+        .map(|(_, i, _, j)| (*i, *j))
+        .collect();
+    unique.sort_unstable();
+
+    let anchors = longest_increasing_by_new(&unique);
+    if anchors.is_empty() {
+        myers(old, new, old_base, new_base, ops);
+        return;
+    }
+
+    let (mut old_from, mut new_from) = (0, 0);
+    for (i, j) in anchors {
+        diff_trimmed(
+            &old[old_from..i],
+            &new[new_from..j],
+            old_base + old_from,
+            new_base + new_from,
+            ops,
+            &patience,
+        );
+        ops.push(DiffOp::Equal {
+            old: old_base + i,
+            new: new_base + j,
+        });
+        (old_from, new_from) = (i + 1, j + 1);
+    }
+    diff_trimmed(
+        &old[old_from..],
+        &new[new_from..],
+        old_base + old_from,
+        new_base + new_from,
+        ops,
+        &patience,
+    );
+}
+
+/// Longest subsequence of `pairs` (sorted by old index) whose new indices
+/// increase, found by patience sorting
+fn longest_increasing_by_new(pairs: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    // Top card of each pile (index into `pairs`) and each card's predecessor
+    let mut piles: Vec<usize> = Vec::new();
+    let mut predecessor = vec![None; pairs.len()];
+
+    for (idx, &(_, j)) in pairs.iter().enumerate() {
+        let pile = piles.partition_point(|&top| pairs[top].1 < j);
+        if pile > 0 {
+            predecessor[idx] = Some(piles[pile - 1]);
+        }
+        if pile == piles.len() {
+            piles.push(idx);
+        } else {
+            piles[pile] = idx;
+        }
+    }
+
+    let mut sequence = Vec::with_capacity(piles.len());
+    let mut cursor = piles.last().copied();
+    while let Some(idx) = cursor {
+        sequence.push(pairs[idx]);
+        cursor = predecessor[idx];
+    }
+    sequence.reverse();
+    sequence
+}
+
+fn histogram(old: &[&str], new: &[&str], old_base: usize, new_base: usize, ops: &mut Vec<DiffOp>) {
+    let mut positions: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (i, line) in old.iter().enumerate() {
+        positions.entry(line).or_default().push(i);
+    }
+
+    // Best anchor: (occurrences, old start, new start, length)
+    let mut best: Option<(usize, usize, usize, usize)> = None;
+    let mut skipped_frequent = false;
+    let mut j = 0;
+    while j < new.len() {
+        let mut next_j = j + 1;
+        if let Some(old_positions) = positions.get(new[j]) {
+            let occurrences = old_positions.len();
+            skipped_frequent |= occurrences > HISTOGRAM_MAX_OCCURRENCES;
+            let worth_checking = best.is_none_or(|(best_occ, ..)| occurrences <= best_occ);
+            if occurrences <= HISTOGRAM_MAX_OCCURRENCES && worth_checking {
+                for &i in old_positions {
+                    // Extend the match in both directions
+                    let back = old[..i]
+                        .iter()
+                        .rev()
+                        .zip(new[..j].iter().rev())
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    let forward = old[i..]
+                        .iter()
+                        .zip(&new[j..])
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    let (start_i, start_j, len) = (i - back, j - back, back + forward);
+                    // The run's rarest line decides its weight
+                    let run_occurrences = old[start_i..start_i + len]
+                        .iter()
+                        .map(|line| positions[line].len())
+                        .min()
+                        .unwrap_or(occurrences);
+
+                    let better = best.is_none_or(|(best_occ, _, _, best_len)| {
+                        run_occurrences < best_occ
+                            || (run_occurrences == best_occ && len > best_len)
+                    });
+                    if better {
+                        best = Some((run_occurrences, start_i, start_j, len));
+                        next_j = next_j.max(start_j + len);
+                    }
+                }
+            }
+        }
+        j = next_j;
+    }
+
+    let Some((_, start_i, start_j, len)) = best else {
+        if skipped_frequent {
+            myers(old, new, old_base, new_base, ops);
+        } else {
+            ops.extend((0..old.len()).map(|i| DiffOp::Delete { old: old_base + i }));
+            ops.extend((0..new.len()).map(|j| DiffOp::Insert { new: new_base + j }));
+        }
+        return;
+    };
+
+    diff_trimmed(
+        &old[..start_i],
+        &new[..start_j],
+        old_base,
+        new_base,
+        ops,
+        &histogram,
+    );
+    for offset in 0..len {
+        ops.push(DiffOp::Equal {
+            old: old_base + start_i + offset,
+            new: new_base + start_j + offset,
+        });
+    }
+    diff_trimmed(
+        &old[start_i + len..],
+        &new[start_j + len..],
+        old_base + start_i + len,
+        new_base + start_j + len,
+        ops,
+        &histogram,
+    );
+}
+
+/// A group of changes with surrounding context, as a range of the edit script
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Hunk {
+    old_start: usize,
+    old_len: usize,
+    new_start: usize,
+    new_len: usize,
+    ops: std::ops::Range<usize>,
+}
+
+impl Hunk {
+    /// `@@ -a,b +c,d @@` header (1-based, as in `diff -u`)
+    fn header(&self) -> String {
+        let start = |start: usize, len: usize| if len == 0 { start } else { start + 1 };
+        format!(
+            "@@ -{},{} +{},{} @@",
+            start(self.old_start, self.old_len),
+            self.old_len,
+            start(self.new_start, self.new_len),
+            self.new_len
+        )
+    }
+}
+
+/// Group an edit script into hunks with `context` equal lines around changes
+fn hunks(ops: &[DiffOp], context: usize) -> Vec<Hunk> {
+    let changes: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, op)| !matches!(op, DiffOp::Equal { .. }))
+        .map(|(idx, _)| idx)
+        .collect();
+    let Some(&first) = changes.first() else {
+        return Vec::new();
+    };
+
+    // Merge changes whose gap is small enough for their contexts to touch
+    let mut ranges = vec![(first, first)];
+    for &idx in &changes[1..] {
+        let last = ranges.last_mut().expect("ranges is non-empty");
+        if idx - last.1 <= 2 * context + 1 {
+            last.1 = idx;
+        } else {
+            ranges.push((idx, idx));
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(first, last)| {
+            let range = first.saturating_sub(context)..(last + context + 1).min(ops.len());
+            let old_start = ops[..range.start]
+                .iter()
+                .filter(|op| !matches!(op, DiffOp::Insert { .. }))
+                .count();
+            let new_start = ops[..range.start]
+                .iter()
+                .filter(|op| !matches!(op, DiffOp::Delete { .. }))
+                .count();
+            let slice = &ops[range.clone()];
+            Hunk {
+                old_start,
+                old_len: slice
+                    .iter()
+                    .filter(|op| !matches!(op, DiffOp::Insert { .. }))
+                    .count(),
+                new_start,
+                new_len: slice
+                    .iter()
+                    .filter(|op| !matches!(op, DiffOp::Delete { .. }))
+                    .count(),
+                ops: range,
+            }
+        })
+        .collect()
+}
+
+/// Unified diff of `old` to `new` for `path`, or an empty string when equal
+///
+/// The output is a valid patch (`--- a/path`, `+++ b/path`, hunks with
+/// `context` lines of context).
+#[must_use]
+pub fn unified_diff(
+    engine: &dyn DiffEngine,
+    old: &str,
+    new: &str,
+    path: &str,
+    context: usize,
+) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let ops = engine.diff(&old_lines, &new_lines);
+
+    let mut out = String::new();
+    for hunk in hunks(&ops, context) {
+        if out.is_empty() {
+            out.push_str(&format!("--- a/{path}\n+++ b/{path}\n"));
+        }
+        out.push_str(&hunk.header());
+        out.push('\n');
+        for op in &ops[hunk.ops] {
+            let line = match *op {
+                DiffOp::Equal { old, .. } => format!(" {}", old_lines[old]),
+                DiffOp::Delete { old } => format!("-{}", old_lines[old]),
+                DiffOp::Insert { new } => format!("+{}", new_lines[new]),
+            };
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Split a line into words, whitespace runs, and single punctuation marks
+#[must_use]
+pub fn tokenize_words(line: &str) -> Vec<&str> {
+    #[derive(PartialEq)]
+    enum Class {
+        Word,
+        Space,
+        Punct,
+    }
+    let class = |c: char| {
+        if c.is_alphanumeric() || c == '_' {
+            Class::Word
+        } else if c.is_whitespace() {
+            Class::Space
+        } else {
+            Class::Punct
+        }
+    };
+
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut chars = line.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        let current = class(c);
+        let ends_here =
+            current == Class::Punct || chars.peek().is_none_or(|&(_, next)| class(next) != current);
+        if ends_here {
+            let end = idx + c.len_utf8();
+            tokens.push(&line[start..end]);
+            start = end;
+        }
+    }
+    tokens
+}
+
+/// A token of a modified line, flagged `true` where it changed
+pub type WordToken<'a> = (bool, &'a str);
+
+/// Word-level comparison of a modified line: each side's tokens, flagged
+/// `true` where changed
+#[must_use]
+pub fn word_diff<'a>(
+    engine: &dyn DiffEngine,
+    old_line: &'a str,
+    new_line: &'a str,
+) -> (Vec<WordToken<'a>>, Vec<WordToken<'a>>) {
+    let old_tokens = tokenize_words(old_line);
+    let new_tokens = tokenize_words(new_line);
+    let mut old_side = Vec::with_capacity(old_tokens.len());
+    let mut new_side = Vec::with_capacity(new_tokens.len());
+
+    for op in engine.diff(&old_tokens, &new_tokens) {
+        match op {
+            DiffOp::Equal { old, new } => {
+                old_side.push((false, old_tokens[old]));
+                new_side.push((false, new_tokens[new]));
+            }
+            DiffOp::Delete { old } => old_side.push((true, old_tokens[old])),
+            DiffOp::Insert { new } => new_side.push((true, new_tokens[new])),
+        }
+    }
+    (old_side, new_side)
+}
+
+/// Human-review rendering of `old` to `new` for `path`
+///
+/// Colored for terminals (removed lines red, added lines green). With
+/// `word_level`, each removed line paired with an added line highlights only
+/// the words that changed; without color the pair is shown as one line with
+/// `[-removed-]{+added+}` markers, like `git diff --word-diff=plain`.
+#[must_use]
+pub fn render_diff(
+    engine: &dyn DiffEngine,
+    old: &str,
+    new: &str,
+    path: &str,
+    context: usize,
+    word_level: bool,
+    stream: Stream,
+) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let ops = engine.diff(&old_lines, &new_lines);
+    let color = console::color_enabled(stream);
+
+    let mut out = String::new();
+    for hunk in hunks(&ops, context) {
+        if out.is_empty() {
+            out.push_str(&console::paint(
+                &format!("--- a/{path}\n+++ b/{path}"),
+                None,
+                stream,
+            ));
+            out.push('\n');
+        }
+        out.push_str(&console::tint(&hunk.header(), Color::Cyan, stream));
+        out.push('\n');
+
+        let hunk_ops = &ops[hunk.ops];
+        let mut idx = 0;
+        while idx < hunk_ops.len() {
+            if let DiffOp::Equal { old, .. } = hunk_ops[idx] {
+                out.push_str(&format!(" {}\n", old_lines[old]));
+                idx += 1;
+                continue;
+            }
+
+            // A run of deletions followed by a run of insertions
+            let mut removed = Vec::new();
+            while let Some(&DiffOp::Delete { old }) = hunk_ops.get(idx) {
+                removed.push(old_lines[old]);
+                idx += 1;
+            }
+            let mut added = Vec::new();
+            while let Some(&DiffOp::Insert { new }) = hunk_ops.get(idx) {
+                added.push(new_lines[new]);
+                idx += 1;
+            }
+
+            let paired = if word_level {
+                removed.len().min(added.len())
+            } else {
+                0
+            };
+            if paired > 0 && !color {
+                for (old_line, new_line) in removed.iter().zip(&added).take(paired) {
+                    out.push_str(&format!(
+                        "~{}\n",
+                        plain_word_line(engine, old_line, new_line)
+                    ));
+                }
+            } else {
+                for (n, line) in removed.iter().enumerate() {
+                    let text = match added.get(n).filter(|_| n < paired) {
+                        Some(new_line) => {
+                            highlighted(&word_diff(engine, line, new_line).0, Color::Red, stream)
+                        }
+                        None => console::tint(line, Color::Red, stream),
+                    };
+                    out.push_str(&format!(
+                        "{}{text}\n",
+                        console::tint("-", Color::Red, stream)
+                    ));
+                }
+                for (n, line) in added.iter().enumerate() {
+                    let text = match removed.get(n).filter(|_| n < paired) {
+                        Some(old_line) => {
+                            highlighted(&word_diff(engine, old_line, line).1, Color::Green, stream)
+                        }
+                        None => console::tint(line, Color::Green, stream),
+                    };
+                    out.push_str(&format!(
+                        "{}{text}\n",
+                        console::tint("+", Color::Green, stream)
+                    ));
+                }
+                continue;
+            }
+            for line in &removed[paired..] {
+                out.push_str(&format!("-{line}\n"));
+            }
+            for line in &added[paired..] {
+                out.push_str(&format!("+{line}\n"));
+            }
+        }
+    }
+    out
+}
+
+/// One side of a word diff, with changed tokens highlighted
+fn highlighted(tokens: &[WordToken<'_>], color: Color, stream: Stream) -> String {
+    tokens
+        .iter()
+        .map(|&(changed, token)| {
+            if changed {
+                console::highlight(token, color, stream)
+            } else {
+                console::tint(token, color, stream)
+            }
+        })
+        .collect()
+}
+
+/// A modified line as one line with `[-removed-]{+added+}` markers
+fn plain_word_line(engine: &dyn DiffEngine, old_line: &str, new_line: &str) -> String {
+    let old_tokens = tokenize_words(old_line);
+    let new_tokens = tokenize_words(new_line);
+    let mut out = String::new();
+    let (mut removed, mut added) = (String::new(), String::new());
+    let flush = |out: &mut String, removed: &mut String, added: &mut String| {
+        if !removed.is_empty() {
+            out.push_str(&format!("[-{removed}-]"));
+            removed.clear();
+        }
+        if !added.is_empty() {
+            out.push_str(&format!("{{+{added}+}}"));
+            added.clear();
+        }
+    };
+
+    for op in engine.diff(&old_tokens, &new_tokens) {
+        match op {
+            DiffOp::Equal { old, .. } => {
+                flush(&mut out, &mut removed, &mut added);
+                out.push_str(old_tokens[old]);
+            }
+            DiffOp::Delete { old } => removed.push_str(old_tokens[old]),
+            DiffOp::Insert { new } => added.push_str(new_tokens[new]),
+        }
+    }
+    flush(&mut out, &mut removed, &mut added);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Apply an edit script, checking it is consistent with both sides
+    fn replay(old: &[&str], new: &[&str], ops: &[DiffOp]) -> Vec<String> {
+        let (mut next_old, mut next_new) = (0, 0);
+        let mut result = Vec::new();
+        for op in ops {
+            match *op {
+                DiffOp::Equal { old: i, new: j } => {
+                    assert_eq!((i, j), (next_old, next_new));
+                    assert_eq!(old[i], new[j]);
+                    result.push(old[i].to_string());
+                    (next_old, next_new) = (i + 1, j + 1);
+                }
+                DiffOp::Delete { old: i } => {
+                    assert_eq!(i, next_old);
+                    next_old += 1;
+                }
+                DiffOp::Insert { new: j } => {
+                    assert_eq!(j, next_new);
+                    result.push(new[j].to_string());
+                    next_new += 1;
+                }
+            }
+        }
+        assert_eq!((next_old, next_new), (old.len(), new.len()));
+        result
+    }
+
+    #[test]
+    fn test_engines_produce_valid_edit_scripts() {
+        let cases: [(&str, &str); 5] = [
+            ("", ""),
+            ("a\nb\nc", "a\nb\nc"),
+            ("a\nb\nc", ""),
+            ("a\nb\nc\na\nb\nc", "c\nb\na\nb\na\nc\nd"),
+            (
+                "# Title\n\nintro\n\n## One\n\ntext\n\n## Two\n\nmore",
+                "# Title\n\n## Two\n\nmore\n\nintro\n\n## One\n\nchanged text",
+            ),
+        ];
+        for algorithm in DiffAlgorithm::ALL {
+            let engine = algorithm.engine();
+            for (old, new) in cases {
+                let old: Vec<&str> = old.lines().collect();
+                let new: Vec<&str> = new.lines().collect();
+                let ops = engine.diff(&old, &new);
+                assert_eq!(replay(&old, &new, &ops), new, "{}", engine.name());
+            }
+        }
+    }
+
+    #[test]
+    fn test_myers_is_minimal_and_patience_anchors_on_unique_lines() {
+        let old = ["a", "b", "c", "a", "b", "b", "a"];
+        let new = ["c", "b", "a", "b", "a", "c"];
+        let edits = MyersDiff
+            .diff(&old, &new)
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Equal { .. }))
+            .count();
+        assert_eq!(edits, 5);
+
+        // The unique heading stays matched even though blank lines move around it
+        let old = ["", "x", "", "## Heading", "", "y"];
+        let new = ["## Heading", "", "x", "", "y"];
+        let ops = PatienceDiff.diff(&old, &new);
+        assert!(ops.contains(&DiffOp::Equal { old: 3, new: 0 }));
+    }
+
+    #[test]
+    fn test_unified_diff_format() {
+        let old = "one\ntwo\nthree\nfour\nfive\nsix\nseven\neight\nnine\n";
+        let new = "one\ntwo\nTHREE\nfour\nfive\nsix\nseven\neight\nnine\nten\n";
+        let diff = unified_diff(&HistogramDiff, old, new, "spec.md", 1);
+        assert_eq!(
+            diff,
+            "--- a/spec.md\n+++ b/spec.md\n@@ -2,3 +2,3 @@\n two\n-three\n+THREE\n four\n@@ -9,1 +9,2 @@\n nine\n+ten\n"
+        );
+        assert_eq!(unified_diff(&MyersDiff, old, old, "spec.md", 3), "");
+    }
+
+    #[test]
+    fn test_word_level_rendering_without_color() {
+        assert_eq!(
+            tokenize_words("The API, v2 rocks"),
+            vec!["The", " ", "API", ",", " ", "v2", " ", "rocks"]
+        );
+
+        let old = "intro\nThe system SHALL retry three times.\n";
+        let new = "intro\nThe system SHALL retry five times.\n";
+        // Output is piped in tests, so color is off
+        let rendered = render_diff(
+            &PatienceDiff,
+            old,
+            new,
+            "design.md",
+            3,
+            true,
+            Stream::Stdout,
+        );
+        assert!(rendered.contains("~The system SHALL retry [-three-]{+five+} times.\n"));
+
+        let rendered = render_diff(
+            &PatienceDiff,
+            old,
+            new,
+            "design.md",
+            3,
+            false,
+            Stream::Stdout,
+        );
+        assert!(rendered.contains("-The system SHALL retry three times.\n"));
+        assert!(rendered.contains("+The system SHALL retry five times.\n"));
+    }
+
+    fn side_text(side: &[WordToken<'_>]) -> String {
+        side.iter().map(|&(_, token)| token).collect()
+    }
+
+    /// Markdown-ish lines: headings, bullets, blank lines, fences, and prose
+    fn doc() -> impl Strategy<Value = Vec<String>> {
+        let line = prop_oneof![
+            Just("# Title".to_string()),
+            Just("## Design".to_string()),
+            Just("## Tasks".to_string()),
+            Just("### Acceptance Criteria".to_string()),
+            Just(String::new()),
+            Just("```".to_string()),
+            Just("- [ ] retry".to_string()),
+            "[a-c ]{0,6}",
+        ];
+        prop::collection::vec(line, 0..24)
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(128))]
+
+        #[test]
+        fn prop_every_engine_produces_a_valid_edit_script(old in doc(), new in doc()) {
+            let old: Vec<&str> = old.iter().map(String::as_str).collect();
+            let new: Vec<&str> = new.iter().map(String::as_str).collect();
+            for algorithm in DiffAlgorithm::ALL {
+                let engine = algorithm.engine();
+                let markdown = MarkdownDiff::new(engine.as_ref());
+                for engine in [engine.as_ref(), &markdown as &dyn DiffEngine] {
+                    let ops = engine.diff(&old, &new);
+                    prop_assert_eq!(replay(&old, &new, &ops), new.clone(), "{}", engine.name());
+                    // Deterministic: the same inputs give the same script
+                    prop_assert_eq!(engine.diff(&old, &new), ops);
+                }
+            }
+        }
+
+        #[test]
+        fn prop_unified_diff_is_empty_only_for_equal_lines(old in doc(), new in doc()) {
+            let old_text = old.join("\n");
+            let new_text = new.join("\n");
+            let diff = unified_diff(&MarkdownDiff::default(), &old_text, &new_text, "a.md", 3);
+            let equal = old_text.lines().eq(new_text.lines());
+            prop_assert_eq!(diff.is_empty(), equal);
+        }
+
+        #[test]
+        fn prop_sections_partition_the_document(lines in doc()) {
+            let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+            let sections = sections(&lines);
+            prop_assert_eq!(sections[0].level, 0);
+            prop_assert_eq!(sections[0].lines.start, 0);
+            prop_assert_eq!(sections.last().unwrap().lines.end, lines.len());
+            for pair in sections.windows(2) {
+                prop_assert_eq!(pair[0].lines.end, pair[1].lines.start);
+                prop_assert!(lines[pair[1].lines.start].starts_with('#'));
+            }
+        }
+
+        #[test]
+        fn prop_word_diff_sides_reassemble_their_lines(
+            old in "[a-z ,.]{0,40}",
+            new in "[a-z ,.]{0,40}",
+        ) {
+            prop_assert_eq!(tokenize_words(&old).concat(), old.clone());
+            let (old_side, new_side) = word_diff(&MyersDiff, &old, &new);
+            prop_assert_eq!(side_text(&old_side), old.clone());
+            prop_assert_eq!(side_text(&new_side), new.clone());
+            if old == new {
+                prop_assert!(old_side.iter().chain(&new_side).all(|&(changed, _)| !changed));
+            }
+        }
+    }
+}
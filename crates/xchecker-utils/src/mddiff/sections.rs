@@ -0,0 +1,232 @@
+//! Heading-based section alignment
+
+use std::ops::Range;
+
+use super::{DiffEngine, DiffOp, HistogramDiff};
+
+/// A heading-delimited section of a Markdown document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// ATX heading level (1-6), or 0 for the text before the first heading
+    pub level: usize,
+    /// Line indices of the section, heading included
+    pub lines: Range<usize>,
+}
+
+/// Split a document into sections at each ATX heading (`#` to `######`)
+///
+/// The first section is always the text before the first heading (level 0,
+/// possibly empty). Headings inside fenced code blocks are ignored. The
+/// sections are contiguous and cover every line.
+#[must_use]
+pub fn sections(lines: &[&str]) -> Vec<Section> {
+    // (level, first line) of each section
+    let mut starts = vec![(0, 0)];
+    let mut fence: Option<&str> = None;
+
+    for (idx, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        let marker = ["```", "~~~"]
+            .into_iter()
+            .find(|marker| trimmed.starts_with(marker));
+        match (fence, marker) {
+            (Some(open), Some(marker)) if open == marker => fence = None,
+            (None, Some(marker)) => fence = Some(marker),
+            _ => {}
+        }
+
+        if fence.is_none()
+            && marker.is_none()
+            && let Some(level) = heading_level(line)
+        {
+            starts.push((level, idx));
+        }
+    }
+
+    let ends = starts
+        .iter()
+        .skip(1)
+        .map(|&(_, start)| start)
+        .chain([lines.len()]);
+    starts
+        .iter()
+        .zip(ends)
+        .map(|(&(level, start), end)| Section {
+            level,
+            lines: start..end,
+        })
+        .collect()
+}
+
+/// Level of an ATX heading line, allowing up to three spaces of indentation
+fn heading_level(line: &str) -> Option<usize> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    if indent > 3 {
+        return None;
+    }
+    let rest = &line[indent..];
+    let level = rest.len() - rest.trim_start_matches('#').len();
+    let after = &rest[level..];
+    ((1..=6).contains(&level) && (after.is_empty() || after.starts_with([' ', '\t'])))
+        .then_some(level)
+}
+
+/// Section-aligned diff of Markdown documents
+///
+/// Sections are matched on their heading lines with the inner engine; each
+/// matched pair is then diffed line by line, and runs of unmatched sections
+/// between two matches (renamed, added, or removed sections) are diffed as
+/// one region. The result is a line-level edit script like any other
+/// engine's, but an edit inside one section can never be matched against an
+/// identical line (a blank line, a repeated "Acceptance Criteria" bullet) in
+/// another.
+#[derive(Clone, Copy)]
+pub struct MarkdownDiff<'a> {
+    inner: &'a dyn DiffEngine,
+}
+
+impl<'a> MarkdownDiff<'a> {
+    /// Align sections, then diff within them, with `inner`
+    #[must_use]
+    pub const fn new(inner: &'a dyn DiffEngine) -> Self {
+        Self { inner }
+    }
+
+    /// Append the inner engine's script for `old[o]` → `new[n]`
+    fn diff_region(
+        &self,
+        old: &[&str],
+        new: &[&str],
+        o: Range<usize>,
+        n: Range<usize>,
+        ops: &mut Vec<DiffOp>,
+    ) {
+        let (old_base, new_base) = (o.start, n.start);
+        ops.extend(
+            self.inner
+                .diff(&old[o], &new[n])
+                .into_iter()
+                .map(|op| op.shifted(old_base, new_base)),
+        );
+    }
+}
+
+impl Default for MarkdownDiff<'static> {
+    /// Section alignment over [`HistogramDiff`]
+    fn default() -> Self {
+        Self::new(&HistogramDiff)
+    }
+}
+
+impl std::fmt::Debug for MarkdownDiff<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownDiff")
+            .field("inner", &self.inner.name())
+            .finish()
+    }
+}
+
+impl DiffEngine for MarkdownDiff<'_> {
+    fn name(&self) -> &'static str {
+        "markdown"
+    }
+
+    fn diff(&self, old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+        let old_sections = sections(old);
+        let new_sections = sections(new);
+        // The preamble has no heading; "" never is one, so it only matches the other preamble
+        fn key<'a>(lines: &[&'a str], section: &Section) -> &'a str {
+            if section.level == 0 {
+                ""
+            } else {
+                lines[section.lines.start].trim()
+            }
+        }
+        let old_keys: Vec<&str> = old_sections.iter().map(|s| key(old, s)).collect();
+        let new_keys: Vec<&str> = new_sections.iter().map(|s| key(new, s)).collect();
+
+        let mut ops = Vec::with_capacity(old.len().max(new.len()));
+        let (mut old_from, mut new_from) = (0, 0);
+        for op in self.inner.diff(&old_keys, &new_keys) {
+            if let DiffOp::Equal { old: i, new: j } = op {
+                let (o, n) = (&old_sections[i].lines, &new_sections[j].lines);
+                self.diff_region(old, new, old_from..o.start, new_from..n.start, &mut ops);
+                self.diff_region(old, new, o.clone(), n.clone(), &mut ops);
+                (old_from, new_from) = (o.end, n.end);
+            }
+        }
+        self.diff_region(old, new, old_from..old.len(), new_from..new.len(), &mut ops);
+        ops
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mddiff::{MyersDiff, unified_diff};
+
+    #[test]
+    fn test_sections_split_on_headings_outside_fences() {
+        let doc = [
+            "intro",
+            "# Title",
+            "text",
+            "```",
+            "# not a heading",
+            "```",
+            "    # indented code",
+            "#hashtag",
+            "## Sub",
+        ];
+        assert_eq!(
+            sections(&doc),
+            vec![
+                Section {
+                    level: 0,
+                    lines: 0..1
+                },
+                Section {
+                    level: 1,
+                    lines: 1..8
+                },
+                Section {
+                    level: 2,
+                    lines: 8..9
+                },
+            ]
+        );
+        assert_eq!(
+            sections(&[]),
+            vec![Section {
+                level: 0,
+                lines: 0..0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_edits_stay_within_their_section() {
+        // A bullet moved from one section to the previous one is an insert in
+        // the first and a delete in the second, not a moved heading
+        let old = "## A\n- a\n## B\n- n\n- b\n";
+        let new = "## A\n- a\n- n\n## B\n- b\n";
+
+        let diff = unified_diff(&MarkdownDiff::new(&MyersDiff), old, new, "d.md", 0);
+        assert_eq!(
+            diff,
+            "--- a/d.md\n+++ b/d.md\n@@ -2,0 +3,1 @@\n+- n\n@@ -4,1 +4,0 @@\n-- n\n"
+        );
+    }
+
+    #[test]
+    fn test_renamed_section_is_diffed_against_its_predecessor() {
+        let old = "# Spec\n\n## Storage\n\nUse SQLite.\nKeep backups.\n";
+        let new = "# Spec\n\n## Persistence\n\nUse SQLite.\nKeep backups.\n";
+
+        let diff = unified_diff(&MarkdownDiff::default(), old, new, "d.md", 0);
+        assert_eq!(
+            diff,
+            "--- a/d.md\n+++ b/d.md\n@@ -3,1 +3,1 @@\n-## Storage\n+## Persistence\n"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use std::cell::RefCell;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -520,6 +520,35 @@ pub fn xchecker_home() -> Utf8PathBuf {
     Utf8PathBuf::from(".xchecker")
 }
 
+/// Environment variable naming a kill switch file other than `~/.xchecker/KILL`
+pub const KILL_SWITCH_ENV: &str = "XCHECKER_KILL_FILE";
+
+/// Resolve the fleet-wide kill switch file:
+/// 1) `<thread-local home>/KILL` (tests use this)
+/// 2) env `XCHECKER_KILL_FILE`
+/// 3) `~/.xchecker/KILL`
+///
+/// The default lives in the user's home rather than `XCHECKER_HOME`, so one
+/// file stops every instance regardless of working directory. `None` when
+/// no home directory can be determined.
+#[must_use]
+pub fn kill_switch_path() -> Option<Utf8PathBuf> {
+    if let Some(tl) = THREAD_HOME.with(|tl| tl.borrow().clone()) {
+        return Some(tl.join("KILL"));
+    }
+    if let Ok(p) = std::env::var(KILL_SWITCH_ENV) {
+        return Some(Utf8PathBuf::from(p));
+    }
+    let home = Utf8PathBuf::from_path_buf(dirs::home_dir()?).ok()?;
+    Some(home.join(".xchecker").join("KILL"))
+}
+
+/// The kill switch file, if it currently exists
+#[must_use]
+pub fn kill_switch_engaged() -> Option<Utf8PathBuf> {
+    kill_switch_path().filter(|path| path.exists())
+}
+
 /// Returns `<XCHECKER_HOME>/specs/<spec_id>`
 #[must_use]
 pub fn spec_root(spec_id: &str) -> Utf8PathBuf {
@@ -541,6 +570,40 @@ pub fn ensure_dir_all<P: AsRef<std::path::Path>>(p: P) -> std::io::Result<()> {
     }
 }
 
+/// Check that `dir` accepts writes by creating it and a short-lived probe file inside.
+///
+/// Used before a run touches the spec tree so that a read-only xchecker home
+/// (e.g. a locked-down CI image) is reported up front instead of as a raw I/O
+/// error from deep inside artifact staging.
+pub fn probe_writable<P: AsRef<Path>>(dir: P) -> std::io::Result<()> {
+    let dir = dir.as_ref();
+    ensure_dir_all(dir)?;
+    let probe = dir.join(format!(".xchecker-write-probe-{}", std::process::id()));
+    std::fs::write(&probe, b"")?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(())
+}
+
+/// True when `err` means the location cannot be written at all (as opposed to a transient failure)
+#[must_use]
+pub fn is_read_only_error(err: &std::io::Error) -> bool {
+    matches!(
+        err.kind(),
+        std::io::ErrorKind::PermissionDenied | std::io::ErrorKind::ReadOnlyFilesystem
+    )
+}
+
+/// Explain how to point xchecker at a writable workspace when `home` is read-only
+#[must_use]
+pub fn read_only_home_hint(home: &Utf8Path) -> String {
+    let fallback = std::env::temp_dir().join("xchecker");
+    format!(
+        "xchecker home '{home}' is not writable. Pass --workspace <dir> or set XCHECKER_HOME \
+         to a writable directory (for example: --workspace {})",
+        fallback.display()
+    )
+}
+
 /// Test helper: provides isolated workspace testing; not part of public API stability guarantees.
 ///
 /// Give this test a unique home under the system temp dir.
@@ -987,4 +1050,57 @@ mod tests {
         };
         assert_eq!(err1, err2);
     }
+
+    // ========================================================================
+    // Writable home probe tests
+    // ========================================================================
+
+    #[test]
+    fn test_probe_writable_creates_directory_and_leaves_no_probe() {
+        let temp = create_test_dir();
+        let home = temp.path().join("home");
+
+        probe_writable(&home).unwrap();
+
+        assert!(home.is_dir());
+        assert_eq!(std::fs::read_dir(&home).unwrap().count(), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_probe_writable_reports_read_only_directory() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = create_test_dir();
+        let home = temp.path().join("home");
+        std::fs::create_dir(&home).unwrap();
+        std::fs::set_permissions(&home, std::fs::Permissions::from_mode(0o555)).unwrap();
+
+        let result = probe_writable(&home);
+        std::fs::set_permissions(&home, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        // Root ignores directory permissions; only assert when the probe was refused
+        if let Err(e) = result {
+            assert!(is_read_only_error(&e));
+        }
+    }
+
+    #[test]
+    fn test_read_only_home_hint_mentions_workspace_override() {
+        let hint = read_only_home_hint(Utf8Path::new("/locked/.xchecker"));
+        assert!(hint.contains("/locked/.xchecker"));
+        assert!(hint.contains("--workspace"));
+        assert!(hint.contains("XCHECKER_HOME"));
+    }
+
+    #[test]
+    fn test_kill_switch_engaged_when_file_exists() {
+        let _home = with_isolated_home();
+        let path = kill_switch_path().unwrap();
+        assert_eq!(path, xchecker_home().join("KILL"));
+        assert_eq!(kill_switch_engaged(), None);
+
+        std::fs::write(&path, "billing incident").unwrap();
+        assert_eq!(kill_switch_engaged(), Some(path));
+    }
 }
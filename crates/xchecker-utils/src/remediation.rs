@@ -0,0 +1,397 @@
+//! Data-driven remediation suggestions for failed runs.
+//!
+//! Suggested actions are looked up from a single table keyed on the receipt
+//! [`ErrorKind`], the LLM provider in use, and substrings of the error reason.
+//! The CLI error output, `xchecker explain`, and the `suggested_actions` field
+//! of receipts all go through [`suggested_actions`], so a failure gets the same
+//! advice wherever it is surfaced.
+//!
+//! # Matching
+//!
+//! A rule applies when its error kind matches and, if set, its provider and at
+//! least one of its patterns match (patterns are case-insensitive substrings of
+//! the error reason). When several rules apply, the most specific one wins:
+//! a provider match outranks a pattern match, which outranks a kind-only rule.
+//! Ties go to the rule listed first.
+//!
+//! # Example
+//!
+//! ```rust
+//! use xchecker_utils::remediation::suggested_actions;
+//! use xchecker_utils::types::ErrorKind;
+//!
+//! let actions = suggested_actions(
+//!     &ErrorKind::ClaudeFailure,
+//!     Some("openrouter"),
+//!     "Provider authentication error: 401 Unauthorized",
+//! );
+//! assert!(actions[0].contains("OPENROUTER_API_KEY"));
+//! ```
+
+use crate::error::XCheckerError;
+use crate::exit_codes::error_to_exit_code_and_kind;
+use crate::types::ErrorKind;
+
+/// One row of the remediation table
+#[derive(Debug)]
+struct RemediationRule {
+    kind: ErrorKind,
+    /// Provider this rule is specific to (`None` applies to every provider)
+    provider: Option<&'static str>,
+    /// Case-insensitive substrings of the error reason (empty matches anything)
+    patterns: &'static [&'static str],
+    actions: &'static [&'static str],
+}
+
+const AUTH_PATTERNS: &[&str] = &["authentication", "unauthorized", "401", "403", "api key"];
+const QUOTA_PATTERNS: &[&str] = &["quota", "rate limit", "429"];
+
+static RULES: &[RemediationRule] = &[
+    // Configuration and CLI usage
+    RemediationRule {
+        kind: ErrorKind::CliArgs,
+        provider: None,
+        patterns: &["dependency"],
+        actions: &[
+            "Check which phases have completed with `xchecker status <id>`",
+            "Run the missing phase first with `xchecker resume <id> --phase <phase>`",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::CliArgs,
+        provider: None,
+        patterns: &["phase transition"],
+        actions: &[
+            "Check the current phase with `xchecker status <id>`",
+            "Run the earlier phases first, or use `xchecker resume <id> --force-phase <phase>` to re-run a phase the spec has moved past",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::CliArgs,
+        provider: Some("openrouter"),
+        patterns: AUTH_PATTERNS,
+        actions: &[
+            "Set the OPENROUTER_API_KEY environment variable",
+            "Run `xchecker doctor` to verify the provider configuration",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::CliArgs,
+        provider: Some("anthropic"),
+        patterns: AUTH_PATTERNS,
+        actions: &[
+            "Set the ANTHROPIC_API_KEY environment variable",
+            "Run `xchecker doctor` to verify the provider configuration",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::CliArgs,
+        provider: None,
+        patterns: &[],
+        actions: &[
+            "Check the command-line flags and .xchecker/config.toml for the reported value",
+            "Run `xchecker doctor` to validate the configuration",
+        ],
+    },
+    // Resource limits and security
+    RemediationRule {
+        kind: ErrorKind::PacketOverflow,
+        provider: None,
+        patterns: &[],
+        actions: &[
+            "Increase packet_max_bytes / packet_max_lines in [defaults]",
+            "Narrow the include patterns in [selectors] to reduce packet content",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::SecretDetected,
+        provider: None,
+        patterns: &[],
+        actions: &[
+            "Remove or redact the secret from the reported file",
+            "If it is a false positive, pass --ignore-secret-pattern <pattern>",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::LockHeld,
+        provider: None,
+        patterns: &[],
+        actions: &[
+            "Wait for the other xchecker process working on this spec to finish",
+            "If a previous run crashed, re-run with --force to override the stale lock",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::PhaseTimeout,
+        provider: None,
+        patterns: &[],
+        actions: &[
+            "Increase the timeout with --phase-timeout <secs> or phase_timeout in [defaults]",
+            "Reduce the packet size so the provider has less to process",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::OperatorHalt,
+        provider: None,
+        patterns: &[],
+        actions: &[
+            "Remove the kill switch file named in the error once the incident is resolved",
+            "Resume the halted phase with `xchecker resume <id> --phase <phase>`",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::ComplianceBlocked,
+        provider: None,
+        patterns: &[],
+        actions: &[
+            "Review the compliance findings in the receipt's packet evidence",
+            "Exclude the flagged files, or list accepted built-in pattern IDs in compliance.ignore_patterns",
+            "Set compliance.mode = \"warn\" to record findings without blocking",
+        ],
+    },
+    // LLM provider failures
+    RemediationRule {
+        kind: ErrorKind::ClaudeFailure,
+        provider: Some("claude-cli"),
+        patterns: AUTH_PATTERNS,
+        actions: &[
+            "Authenticate the Claude CLI with `claude auth login`",
+            "Verify the session with `claude auth status`",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::ClaudeFailure,
+        provider: Some("gemini-cli"),
+        patterns: AUTH_PATTERNS,
+        actions: &[
+            "Authenticate the Gemini CLI by running `gemini` once and signing in",
+            "Run `xchecker doctor` to verify the provider configuration",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::ClaudeFailure,
+        provider: Some("openrouter"),
+        patterns: AUTH_PATTERNS,
+        actions: &[
+            "Check that OPENROUTER_API_KEY is set and the key has not been revoked",
+            "Run `xchecker doctor` to verify the provider configuration",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::ClaudeFailure,
+        provider: Some("anthropic"),
+        patterns: AUTH_PATTERNS,
+        actions: &[
+            "Check that ANTHROPIC_API_KEY is set and the key has not been revoked",
+            "Run `xchecker doctor` to verify the provider configuration",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::ClaudeFailure,
+        provider: Some("openrouter"),
+        patterns: QUOTA_PATTERNS,
+        actions: &[
+            "Wait a few minutes and re-run the phase",
+            "Check the remaining credits on your OpenRouter account",
+            "Configure fallback_provider in [llm] to continue on another provider",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::ClaudeFailure,
+        provider: None,
+        patterns: QUOTA_PATTERNS,
+        actions: &[
+            "Wait a few minutes and re-run the phase",
+            "Configure fallback_provider in [llm] to continue on another provider",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::ClaudeFailure,
+        provider: None,
+        patterns: &["budget exceeded"],
+        actions: &[
+            "Raise the call budget with XCHECKER_OPENROUTER_BUDGET",
+            "Check which phases consumed the budget with `xchecker status <id>`",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::ClaudeFailure,
+        provider: None,
+        patterns: &["outage", "503", "502"],
+        actions: &[
+            "Check the provider's status page and re-run the phase later",
+            "Configure fallback_provider in [llm] to continue on another provider",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::ClaudeFailure,
+        provider: None,
+        patterns: &["invalid response"],
+        actions: &[
+            "Re-run the phase; malformed responses are often transient",
+            "Increase response_retries in [defaults] to retry automatically",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::ClaudeFailure,
+        provider: Some("claude-cli"),
+        patterns: &[],
+        actions: &[
+            "Verify the Claude CLI is installed with `claude --version`",
+            "Check authentication with `claude auth status`",
+            "Re-run with --verbose to see the provider's stderr",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::ClaudeFailure,
+        provider: None,
+        patterns: &[],
+        actions: &[
+            "Run `xchecker doctor` to check the configured LLM provider",
+            "Re-run with --verbose to see the provider's error output",
+        ],
+    },
+    RemediationRule {
+        kind: ErrorKind::Unknown,
+        provider: None,
+        patterns: &[],
+        actions: &[
+            "Re-run with --verbose for detailed output",
+            "Check the spec state with `xchecker status <id>`",
+        ],
+    },
+];
+
+impl RemediationRule {
+    /// Specificity score if this rule applies, `None` otherwise
+    fn score(&self, kind: &ErrorKind, provider: Option<&str>, reason: &str) -> Option<u8> {
+        if &self.kind != kind {
+            return None;
+        }
+
+        let mut score = 0;
+        if let Some(rule_provider) = self.provider {
+            if !provider.is_some_and(|p| p.eq_ignore_ascii_case(rule_provider)) {
+                return None;
+            }
+            score += 2;
+        }
+        if !self.patterns.is_empty() {
+            if !self.patterns.iter().any(|p| reason.contains(p)) {
+                return None;
+            }
+            score += 1;
+        }
+        Some(score)
+    }
+}
+
+/// Look up suggested actions for a failure
+///
+/// `provider` is the LLM provider name (e.g. `claude-cli`, `openrouter`) if
+/// known, and `reason` is the error reason as recorded in receipts. Returns an
+/// empty list only if no rule covers `kind`.
+#[must_use]
+pub fn suggested_actions(kind: &ErrorKind, provider: Option<&str>, reason: &str) -> Vec<String> {
+    let reason = reason.to_lowercase();
+    let mut best: Option<(&RemediationRule, u8)> = None;
+    for rule in RULES {
+        if let Some(score) = rule.score(kind, provider, &reason)
+            && best.is_none_or(|(_, best_score)| score > best_score)
+        {
+            best = Some((rule, score));
+        }
+    }
+
+    best.map(|(rule, _)| rule.actions.iter().map(ToString::to_string).collect())
+        .unwrap_or_default()
+}
+
+/// Look up suggested actions for an error
+///
+/// Uses the same error kind and reason that are written to receipts, so the
+/// CLI shows the same actions that a failed receipt records.
+#[must_use]
+pub fn suggested_actions_for_error(error: &XCheckerError, provider: Option<&str>) -> Vec<String> {
+    let (_, kind) = error_to_exit_code_and_kind(error);
+    suggested_actions(&kind, provider, &error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{LlmError, PhaseError};
+
+    #[test]
+    fn test_every_error_kind_has_a_fallback_rule() {
+        for kind in [
+            ErrorKind::CliArgs,
+            ErrorKind::PacketOverflow,
+            ErrorKind::SecretDetected,
+            ErrorKind::LockHeld,
+            ErrorKind::PhaseTimeout,
+            ErrorKind::OperatorHalt,
+            ErrorKind::ComplianceBlocked,
+            ErrorKind::ClaudeFailure,
+            ErrorKind::Unknown,
+        ] {
+            assert!(
+                !suggested_actions(&kind, None, "").is_empty(),
+                "no remediation for {kind:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_provider_rule_outranks_generic_rule() {
+        let reason = "Provider authentication error: 401";
+        let generic = suggested_actions(&ErrorKind::ClaudeFailure, None, reason);
+        let claude = suggested_actions(&ErrorKind::ClaudeFailure, Some("claude-cli"), reason);
+        let anthropic = suggested_actions(&ErrorKind::ClaudeFailure, Some("Anthropic"), reason);
+
+        assert!(generic[0].contains("xchecker doctor"));
+        assert!(claude[0].contains("claude auth login"));
+        assert!(anthropic[0].contains("ANTHROPIC_API_KEY"));
+    }
+
+    #[test]
+    fn test_pattern_rule_outranks_kind_only_rule() {
+        let quota = suggested_actions(
+            &ErrorKind::ClaudeFailure,
+            Some("gemini-cli"),
+            "Provider quota exceeded: 429 Too Many Requests",
+        );
+        assert!(quota.iter().any(|a| a.contains("fallback_provider")));
+
+        let unrelated = suggested_actions(&ErrorKind::ClaudeFailure, Some("gemini-cli"), "boom");
+        assert!(unrelated[0].contains("xchecker doctor"));
+    }
+
+    #[test]
+    fn test_actions_for_error_match_receipt_lookup() {
+        let error = XCheckerError::Phase(PhaseError::DependencyNotSatisfied {
+            phase: "design".to_string(),
+            dependency: "requirements".to_string(),
+        });
+        let actions = suggested_actions_for_error(&error, None);
+        assert_eq!(
+            actions,
+            suggested_actions(&ErrorKind::CliArgs, None, &error.to_string())
+        );
+        assert!(actions[1].contains("--phase"));
+
+        let error = XCheckerError::Phase(PhaseError::InvalidTransition {
+            from: "requirements".to_string(),
+            to: "tasks".to_string(),
+        });
+        let actions = suggested_actions_for_error(&error, None);
+        assert!(actions[1].contains("--force-phase"));
+
+        let error = XCheckerError::Llm(LlmError::BudgetExceeded {
+            limit: 20,
+            attempted: 21,
+        });
+        let actions = suggested_actions_for_error(&error, Some("openrouter"));
+        assert!(actions[0].contains("XCHECKER_OPENROUTER_BUDGET"));
+    }
+}
@@ -1,10 +1,14 @@
 //! Source resolution for different input types
 //!
-//! This module handles resolving different source types (GitHub, filesystem, stdin)
-//! and provides structured error reporting for resolution failures.
+//! This module handles resolving different source types (GitHub, filesystem, stdin,
+//! OpenAPI documents) and provides structured error reporting for resolution failures.
+
+pub mod openapi;
 
 pub use crate::error::SourceError;
-use std::path::PathBuf;
+use crate::ignore_file::{IGNORE_FILE_NAME, IgnoreFile};
+use crate::input_encoding::{NonUtf8Policy, normalize_input};
+use std::path::{Path, PathBuf};
 
 /// Source types supported by xchecker
 /// Reserved for future multi-source spec ingestion (GitHub issues, filesystem, stdin)
@@ -13,6 +17,7 @@ use std::path::PathBuf;
 pub enum SourceType {
     GitHub { owner: String, repo: String },
     FileSystem { path: PathBuf },
+    OpenApi { path: PathBuf },
     Stdin,
 }
 
@@ -26,6 +31,70 @@ pub struct SourceContent {
     pub metadata: std::collections::HashMap<String, String>,
 }
 
+/// Directories filesystem sources may be read from (`[security] source_roots`)
+///
+/// Paths are compared after canonicalization, so `..` segments and symlinks
+/// cannot lead out of an allowed root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceAllowlist {
+    roots: Vec<PathBuf>,
+}
+
+impl SourceAllowlist {
+    /// Allow the given directories; relative entries resolve against the
+    /// current directory and entries that do not exist are dropped
+    pub fn new<I, P>(roots: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        Self {
+            roots: roots
+                .into_iter()
+                .filter_map(|root| root.as_ref().canonicalize().ok())
+                .collect(),
+        }
+    }
+
+    /// The default allowlist: the project root (current working directory)
+    #[must_use]
+    pub fn project_root() -> Self {
+        Self::new(std::env::current_dir().ok())
+    }
+
+    /// Canonical allowed roots
+    #[must_use]
+    pub fn roots(&self) -> &[PathBuf] {
+        &self.roots
+    }
+
+    /// Reject `path` unless it lies under an allowed root
+    pub fn check(&self, path: &Path) -> Result<(), SourceError> {
+        let resolved = path
+            .canonicalize()
+            .map_err(|_| SourceError::FileSystemNotFound {
+                path: path.display().to_string(),
+            })?;
+        if self.roots.iter().any(|root| resolved.starts_with(root)) {
+            return Ok(());
+        }
+
+        let allowed: Vec<String> = self
+            .roots
+            .iter()
+            .map(|root| root.display().to_string())
+            .collect();
+        Err(SourceError::FileSystemNotTrusted {
+            path: path.display().to_string(),
+            allowed: if allowed.is_empty() {
+                "none".to_string()
+            } else {
+                allowed.join(", ")
+            },
+        })
+    }
+}
+
 /// Source resolver for different input types
 pub struct SourceResolver;
 
@@ -71,29 +140,41 @@ impl SourceResolver {
     }
 
     /// Resolve a filesystem source
-    pub fn resolve_filesystem(path: &PathBuf) -> Result<SourceContent, SourceError> {
+    ///
+    /// Paths outside `allowlist` are rejected before anything is read.
+    /// Directories are summarized as a file listing that skips files matched
+    /// by the project `.xcheckerignore`.
+    pub fn resolve_filesystem(
+        path: &PathBuf,
+        allowlist: &SourceAllowlist,
+    ) -> Result<SourceContent, SourceError> {
         if !path.exists() {
             return Err(SourceError::FileSystemNotFound {
                 path: path.display().to_string(),
             });
         }
+        allowlist.check(path)?;
 
+        let mut metadata = std::collections::HashMap::new();
         let content = if path.is_file() {
-            std::fs::read_to_string(path).map_err(|_| SourceError::FileSystemNotFound {
+            let bytes = std::fs::read(path).map_err(|_| SourceError::FileSystemNotFound {
                 path: path.display().to_string(),
-            })?
+            })?;
+            normalize_source(bytes, &path.display().to_string())?
         } else if path.is_dir() {
-            format!(
-                "Directory source: {}\n\nThis would contain a summary of the directory contents and relevant files.",
-                path.display()
-            )
+            let ignore = IgnoreFile::project_root().map_err(|e| SourceError::InvalidFormat {
+                reason: format!("{e:#}"),
+            })?;
+            let listing = DirectoryListing::collect(path, &ignore);
+            metadata.insert("files".to_string(), listing.files.len().to_string());
+            metadata.insert("ignored_files".to_string(), listing.ignored.to_string());
+            listing.render(path)
         } else {
             return Err(SourceError::FileSystemNotFound {
                 path: path.display().to_string(),
             });
         };
 
-        let mut metadata = std::collections::HashMap::new();
         metadata.insert("path".to_string(), path.display().to_string());
         metadata.insert(
             "type".to_string(),
@@ -107,16 +188,54 @@ impl SourceResolver {
         })
     }
 
+    /// Resolve an OpenAPI / JSON Schema source
+    ///
+    /// The document is condensed into a structured summary (operations, schemas,
+    /// auth) rather than included verbatim, so large API descriptions fit in a packet.
+    /// Paths outside `allowlist` are rejected before anything is read.
+    pub fn resolve_openapi(
+        path: &PathBuf,
+        allowlist: &SourceAllowlist,
+    ) -> Result<SourceContent, SourceError> {
+        if !path.is_file() {
+            return Err(SourceError::FileSystemNotFound {
+                path: path.display().to_string(),
+            });
+        }
+        allowlist.check(path)?;
+
+        let bytes = std::fs::read(path).map_err(|e| read_error(path, &e))?;
+        let raw = normalize_source(bytes, &path.display().to_string())?;
+        let summary = openapi::summarize(&raw)?;
+
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert("path".to_string(), path.display().to_string());
+        metadata.insert("type".to_string(), summary.kind.as_str().to_string());
+        metadata.insert(
+            "operations".to_string(),
+            summary.operation_count.to_string(),
+        );
+        metadata.insert("schemas".to_string(), summary.schema_count.to_string());
+        metadata.insert("original_bytes".to_string(), raw.len().to_string());
+
+        Ok(SourceContent {
+            source_type: SourceType::OpenApi { path: path.clone() },
+            content: summary.markdown,
+            metadata,
+        })
+    }
+
     /// Resolve stdin source
     pub fn resolve_stdin() -> Result<SourceContent, SourceError> {
         use std::io::Read;
 
-        let mut buffer = String::new();
+        let mut bytes = Vec::new();
         std::io::stdin()
-            .read_to_string(&mut buffer)
+            .read_to_end(&mut bytes)
             .map_err(|e| SourceError::StdinReadFailed {
                 reason: e.to_string(),
             })?;
+        let buffer = normalize_source(bytes, "stdin")?;
 
         if buffer.trim().is_empty() {
             return Err(SourceError::EmptyInput);
@@ -133,6 +252,106 @@ impl SourceResolver {
     }
 }
 
+/// Source error for a failed read of `path`, keeping the I/O cause
+fn read_error(path: &Path, e: &std::io::Error) -> SourceError {
+    let path = path.display().to_string();
+    match e.kind() {
+        std::io::ErrorKind::NotFound => SourceError::FileSystemNotFound { path },
+        std::io::ErrorKind::PermissionDenied => SourceError::FileSystemAccessDenied { path },
+        _ => SourceError::FileSystemReadFailed {
+            path,
+            reason: e.to_string(),
+        },
+    }
+}
+
+/// Directories never listed in a directory source
+const SKIPPED_DIRS: [&str; 3] = [".git", "target", "node_modules"];
+
+/// Most files named in a directory source summary
+const MAX_LISTED_FILES: usize = 500;
+
+/// Files of a directory source, relative to the directory and sorted
+#[derive(Debug, Default)]
+struct DirectoryListing {
+    files: Vec<String>,
+    /// Files skipped because `.xcheckerignore` matched them
+    ignored: usize,
+}
+
+impl DirectoryListing {
+    /// Walk `dir`, skipping symlinks, VCS and build directories, and files
+    /// matched by `ignore`; unreadable entries are skipped
+    fn collect(dir: &Path, ignore: &IgnoreFile) -> Self {
+        let mut listing = Self::default();
+        listing.walk(dir, dir, ignore);
+        listing.files.sort();
+        listing
+    }
+
+    fn walk(&mut self, root: &Path, dir: &Path, ignore: &IgnoreFile) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            let path = entry.path();
+            if file_type.is_dir() {
+                if !SKIPPED_DIRS.iter().any(|name| entry.file_name() == *name) {
+                    self.walk(root, &path, ignore);
+                }
+            } else if file_type.is_file() {
+                let absolute = std::path::absolute(&path).unwrap_or_else(|_| path.clone());
+                if ignore.is_ignored(&absolute, false) {
+                    self.ignored += 1;
+                } else if let Ok(relative) = path.strip_prefix(root) {
+                    self.files
+                        .push(relative.to_string_lossy().replace('\\', "/"));
+                }
+            }
+        }
+    }
+
+    fn render(&self, dir: &Path) -> String {
+        let mut out = format!(
+            "Directory source: {}\n\nFiles ({}):\n",
+            dir.display(),
+            self.files.len()
+        );
+        for file in self.files.iter().take(MAX_LISTED_FILES) {
+            out.push_str(&format!("- {file}\n"));
+        }
+        if self.files.len() > MAX_LISTED_FILES {
+            out.push_str(&format!(
+                "- ... and {} more\n",
+                self.files.len() - MAX_LISTED_FILES
+            ));
+        }
+        if self.ignored > 0 {
+            out.push_str(&format!(
+                "\n{} file(s) skipped by {IGNORE_FILE_NAME}\n",
+                self.ignored
+            ));
+        }
+        out
+    }
+}
+
+/// Decode source bytes, normalizing BOMs and line endings so spec inputs hash
+/// identically across platforms; each conversion is logged as a warning
+fn normalize_source(bytes: Vec<u8>, origin: &str) -> Result<String, SourceError> {
+    let normalized =
+        normalize_input(bytes, NonUtf8Policy::Reject).map_err(|e| SourceError::InvalidFormat {
+            reason: format!("{origin} is {e}"),
+        })?;
+    for conversion in &normalized.conversions {
+        tracing::warn!("Normalized source {origin}: {conversion}");
+    }
+    Ok(normalized.text)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,7 +384,7 @@ mod tests {
         // Use a path that doesn't exist on any platform (including Windows where /nonexistent
         // might resolve to a drive-relative path like D:\nonexistent)
         let path = PathBuf::from("__nonexistent_test_path_7f8e9d6c5b4a3__");
-        let result = SourceResolver::resolve_filesystem(&path);
+        let result = SourceResolver::resolve_filesystem(&path, &SourceAllowlist::project_root());
         assert!(result.is_err());
 
         if let Err(SourceError::FileSystemNotFound { path: error_path }) = result {
@@ -175,6 +394,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_filesystem_source_normalizes_encoding() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("problem.md");
+        std::fs::write(&path, b"\xEF\xBB\xBF# Problem\r\n\r\nShip it.\r\n").unwrap();
+        let allowlist = SourceAllowlist::new([temp.path()]);
+        let content = SourceResolver::resolve_filesystem(&path, &allowlist).unwrap();
+        assert_eq!(content.content, "# Problem\n\nShip it.\n");
+
+        std::fs::write(&path, b"caf\xE9\n").unwrap();
+        assert!(matches!(
+            SourceResolver::resolve_filesystem(&path, &allowlist),
+            Err(SourceError::InvalidFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn test_filesystem_source_outside_allowlist_rejected() {
+        let trusted = tempfile::TempDir::new().unwrap();
+        let outside = tempfile::TempDir::new().unwrap();
+        let secret = outside.path().join("id_rsa");
+        std::fs::write(&secret, "PRIVATE KEY").unwrap();
+        let allowlist = SourceAllowlist::new([trusted.path()]);
+
+        let direct = SourceResolver::resolve_filesystem(&secret, &allowlist);
+        assert!(matches!(
+            direct,
+            Err(SourceError::FileSystemNotTrusted { .. })
+        ));
+
+        // `..` cannot climb out of a trusted root
+        let escaped = trusted
+            .path()
+            .join("..")
+            .join(outside.path().file_name().unwrap())
+            .join("id_rsa");
+        let result = SourceResolver::resolve_openapi(&escaped, &allowlist);
+        assert!(matches!(
+            result,
+            Err(SourceError::FileSystemNotTrusted { .. })
+        ));
+    }
+
+    #[test]
+    fn test_openapi_source_resolution() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("api.yaml");
+        std::fs::write(
+            &path,
+            "openapi: 3.1.0\ninfo:\n  title: Tiny\n  version: '1'\npaths:\n  /ping:\n    get:\n      summary: Ping\n",
+        )
+        .unwrap();
+
+        let allowlist = SourceAllowlist::new([temp.path()]);
+        let content = SourceResolver::resolve_openapi(&path, &allowlist).unwrap();
+        assert!(matches!(content.source_type, SourceType::OpenApi { .. }));
+        assert!(content.content.contains("- `GET /ping` — Ping"));
+        assert_eq!(content.metadata.get("operations"), Some(&"1".to_string()));
+    }
+
+    #[test]
+    fn test_directory_listing_honors_ignore_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::create_dir_all(root.join("docs/drafts")).unwrap();
+        std::fs::create_dir_all(root.join(".git")).unwrap();
+        std::fs::write(root.join(IGNORE_FILE_NAME), "drafts/\n*.log\n").unwrap();
+        std::fs::write(root.join("docs/guide.md"), "guide").unwrap();
+        std::fs::write(root.join("docs/drafts/wip.md"), "wip").unwrap();
+        std::fs::write(root.join("run.log"), "log").unwrap();
+        std::fs::write(root.join(".git/HEAD"), "ref").unwrap();
+
+        let ignore = IgnoreFile::load(root).unwrap();
+        let listing = DirectoryListing::collect(root, &ignore);
+        assert_eq!(listing.files, [IGNORE_FILE_NAME, "docs/guide.md"]);
+        assert_eq!(listing.ignored, 2);
+
+        let rendered = listing.render(root);
+        assert!(rendered.contains("Files (2):\n"));
+        assert!(rendered.contains("- docs/guide.md\n"));
+        assert!(rendered.contains("2 file(s) skipped by .xcheckerignore"));
+    }
+
     #[test]
     fn test_source_error_user_friendly_messages() {
         let error = SourceError::GitHubAuthFailed {
@@ -0,0 +1,639 @@
+//! OpenAPI / JSON Schema transformer
+//!
+//! API description documents routinely run to thousands of lines, most of which
+//! is repetition that costs packet budget without helping the model. This module
+//! condenses an OpenAPI 3.x, Swagger 2.0, or standalone JSON Schema document into
+//! a compact Markdown summary of its operations, schemas, and authentication.
+
+use serde_json::{Map, Value};
+use std::fmt::Write as _;
+
+use crate::error::SourceError;
+
+/// Maximum number of properties listed per schema before truncating
+const MAX_PROPERTIES_PER_SCHEMA: usize = 16;
+
+/// Maximum number of enum values listed before truncating
+const MAX_ENUM_VALUES: usize = 6;
+
+/// Kind of API description document that was detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiDocumentKind {
+    /// OpenAPI 3.x (`openapi: 3.*`)
+    OpenApi3,
+    /// Swagger / OpenAPI 2.0 (`swagger: "2.0"`)
+    Swagger2,
+    /// Standalone JSON Schema document
+    JsonSchema,
+}
+
+impl ApiDocumentKind {
+    /// Human-readable label for the document kind
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::OpenApi3 => "OpenAPI 3",
+            Self::Swagger2 => "Swagger 2.0",
+            Self::JsonSchema => "JSON Schema",
+        }
+    }
+}
+
+/// Condensed summary of an API description document
+#[derive(Debug, Clone)]
+pub struct ApiSummary {
+    /// Detected document kind
+    pub kind: ApiDocumentKind,
+    /// Number of operations (method + path pairs)
+    pub operation_count: usize,
+    /// Number of named schemas
+    pub schema_count: usize,
+    /// Rendered Markdown summary
+    pub markdown: String,
+}
+
+/// Parse an OpenAPI / JSON Schema document (JSON or YAML) and summarize it
+pub fn summarize(content: &str) -> Result<ApiSummary, SourceError> {
+    let doc: Value = if content.trim_start().starts_with('{') {
+        serde_json::from_str(content).map_err(|e| SourceError::InvalidFormat {
+            reason: format!("API document is not valid JSON: {e}"),
+        })?
+    } else {
+        serde_yaml::from_str(content).map_err(|e| SourceError::InvalidFormat {
+            reason: format!("API document is not valid YAML: {e}"),
+        })?
+    };
+
+    let root = doc.as_object().ok_or_else(|| SourceError::InvalidFormat {
+        reason: "API document must be a mapping at the top level".to_string(),
+    })?;
+
+    let kind = if root.get("openapi").and_then(Value::as_str).is_some() {
+        ApiDocumentKind::OpenApi3
+    } else if root.contains_key("swagger") {
+        ApiDocumentKind::Swagger2
+    } else if root.contains_key("$schema")
+        || root.contains_key("properties")
+        || root.contains_key("$defs")
+        || root.contains_key("definitions")
+    {
+        ApiDocumentKind::JsonSchema
+    } else {
+        return Err(SourceError::InvalidFormat {
+            reason: "document is not OpenAPI, Swagger, or JSON Schema (missing 'openapi', 'swagger', or schema keys)".to_string(),
+        });
+    };
+
+    let mut out = String::new();
+    let info = root.get("info").and_then(Value::as_object);
+    let title = info
+        .and_then(|i| i.get("title"))
+        .or_else(|| root.get("title"))
+        .and_then(Value::as_str)
+        .unwrap_or("Untitled API");
+    let version = info.and_then(|i| i.get("version")).and_then(Value::as_str);
+
+    let _ = match version {
+        Some(v) => writeln!(out, "# API Specification: {title} (v{v})"),
+        None => writeln!(out, "# API Specification: {title}"),
+    };
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "Condensed from a {} document. Descriptions are truncated to their first line.",
+        kind.as_str()
+    );
+
+    let description = info
+        .and_then(|i| i.get("description"))
+        .or_else(|| root.get("description"))
+        .and_then(Value::as_str)
+        .map(first_line);
+    if let Some(description) = description.filter(|d| !d.is_empty()) {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "{description}");
+    }
+
+    render_servers(&mut out, root, kind);
+    render_auth(&mut out, root, kind);
+    let operation_count = render_paths(&mut out, root);
+
+    let schemas = match kind {
+        ApiDocumentKind::OpenApi3 => root
+            .get("components")
+            .and_then(|c| c.get("schemas"))
+            .and_then(Value::as_object),
+        ApiDocumentKind::Swagger2 => root.get("definitions").and_then(Value::as_object),
+        ApiDocumentKind::JsonSchema => root
+            .get("$defs")
+            .or_else(|| root.get("definitions"))
+            .and_then(Value::as_object),
+    };
+
+    let mut schema_count = 0;
+    if kind == ApiDocumentKind::JsonSchema && root.contains_key("properties") {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "## Root Schema");
+        let _ = writeln!(out);
+        let _ = writeln!(out, "- `{title}`{}", describe_object(&doc));
+    }
+    if let Some(schemas) = schemas.filter(|s| !s.is_empty()) {
+        schema_count = schemas.len();
+        let _ = writeln!(out);
+        let _ = writeln!(out, "## Schemas ({schema_count})");
+        let _ = writeln!(out);
+        for (name, schema) in schemas {
+            let _ = writeln!(out, "- `{name}`{}", describe_object(schema));
+        }
+    }
+
+    Ok(ApiSummary {
+        kind,
+        operation_count,
+        schema_count,
+        markdown: out,
+    })
+}
+
+fn render_servers(out: &mut String, root: &Map<String, Value>, kind: ApiDocumentKind) {
+    let servers: Vec<String> = match kind {
+        ApiDocumentKind::OpenApi3 => root
+            .get("servers")
+            .and_then(Value::as_array)
+            .map(|servers| {
+                servers
+                    .iter()
+                    .filter_map(|s| s.get("url").and_then(Value::as_str))
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        ApiDocumentKind::Swagger2 => root
+            .get("host")
+            .and_then(Value::as_str)
+            .map(|host| {
+                let base = root.get("basePath").and_then(Value::as_str).unwrap_or("");
+                vec![format!("{host}{base}")]
+            })
+            .unwrap_or_default(),
+        ApiDocumentKind::JsonSchema => Vec::new(),
+    };
+
+    if servers.is_empty() {
+        return;
+    }
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Servers");
+    let _ = writeln!(out);
+    for server in servers {
+        let _ = writeln!(out, "- {server}");
+    }
+}
+
+fn render_auth(out: &mut String, root: &Map<String, Value>, kind: ApiDocumentKind) {
+    let schemes = match kind {
+        ApiDocumentKind::OpenApi3 => root
+            .get("components")
+            .and_then(|c| c.get("securitySchemes"))
+            .and_then(Value::as_object),
+        ApiDocumentKind::Swagger2 => root.get("securityDefinitions").and_then(Value::as_object),
+        ApiDocumentKind::JsonSchema => None,
+    };
+    let Some(schemes) = schemes.filter(|s| !s.is_empty()) else {
+        return;
+    };
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Authentication");
+    let _ = writeln!(out);
+    for (name, scheme) in schemes {
+        let scheme_type = scheme
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown");
+        let detail = match scheme_type {
+            "http" => scheme
+                .get("scheme")
+                .and_then(Value::as_str)
+                .map(|s| format!("http {s}")),
+            "apiKey" => {
+                let key = scheme.get("name").and_then(Value::as_str).unwrap_or("?");
+                let location = scheme.get("in").and_then(Value::as_str).unwrap_or("?");
+                Some(format!("apiKey `{key}` in {location}"))
+            }
+            "oauth2" => {
+                let flows: Vec<&str> = scheme
+                    .get("flows")
+                    .and_then(Value::as_object)
+                    .map(|f| f.keys().map(String::as_str).collect())
+                    .or_else(|| scheme.get("flow").and_then(Value::as_str).map(|f| vec![f]))
+                    .unwrap_or_default();
+                Some(format!("oauth2 ({})", flows.join(", ")))
+            }
+            _ => None,
+        };
+        let _ = writeln!(
+            out,
+            "- `{name}`: {}",
+            detail.unwrap_or_else(|| scheme_type.to_string())
+        );
+    }
+
+    if let Some(global) = root.get("security").and_then(Value::as_array) {
+        let names = security_names(global);
+        if !names.is_empty() {
+            let _ = writeln!(out, "- Default requirement: {}", names.join(" | "));
+        }
+    }
+}
+
+/// Render operations grouped by path, returning the number of operations
+fn render_paths(out: &mut String, root: &Map<String, Value>) -> usize {
+    const METHODS: [&str; 8] = [
+        "get", "put", "post", "delete", "options", "head", "patch", "trace",
+    ];
+
+    let Some(paths) = root
+        .get("paths")
+        .and_then(Value::as_object)
+        .filter(|p| !p.is_empty())
+    else {
+        return 0;
+    };
+
+    let mut lines = Vec::new();
+    for (path, item) in paths {
+        let path_params = item.get("parameters").and_then(Value::as_array);
+        for method in METHODS {
+            let Some(op) = item.get(method) else {
+                continue;
+            };
+
+            let summary = op
+                .get("summary")
+                .or_else(|| op.get("description"))
+                .and_then(Value::as_str)
+                .map(first_line)
+                .unwrap_or_default();
+            let mut line = format!("- `{} {path}`", method.to_uppercase());
+            if !summary.is_empty() {
+                let _ = write!(line, " — {summary}");
+            }
+            if op.get("deprecated").and_then(Value::as_bool) == Some(true) {
+                line.push_str(" (deprecated)");
+            }
+            if let Some(security) = op.get("security").and_then(Value::as_array) {
+                let names = security_names(security);
+                if names.is_empty() {
+                    line.push_str(" [auth: none]");
+                } else {
+                    let _ = write!(line, " [auth: {}]", names.join(" | "));
+                }
+            }
+
+            let params: Vec<String> = path_params
+                .into_iter()
+                .flatten()
+                .chain(
+                    op.get("parameters")
+                        .and_then(Value::as_array)
+                        .into_iter()
+                        .flatten(),
+                )
+                .filter_map(describe_parameter)
+                .collect();
+            if !params.is_empty() {
+                let _ = write!(line, "\n  - params: {}", params.join(", "));
+            }
+
+            let body = op
+                .get("requestBody")
+                .and_then(|b| b.get("content"))
+                .and_then(first_media_schema);
+            if let Some(body) = body {
+                let _ = write!(line, "\n  - body: {}", type_name(body));
+            }
+
+            if let Some(responses) = op.get("responses").and_then(Value::as_object) {
+                let rendered: Vec<String> = responses
+                    .iter()
+                    .map(|(status, response)| {
+                        let schema = response
+                            .get("content")
+                            .and_then(first_media_schema)
+                            .or_else(|| response.get("schema"));
+                        match schema {
+                            Some(schema) => format!("{status} → {}", type_name(schema)),
+                            None => status.clone(),
+                        }
+                    })
+                    .collect();
+                if !rendered.is_empty() {
+                    let _ = write!(line, "\n  - responses: {}", rendered.join(", "));
+                }
+            }
+
+            lines.push(line);
+        }
+    }
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Operations ({})", lines.len());
+    let _ = writeln!(out);
+    for line in &lines {
+        let _ = writeln!(out, "{line}");
+    }
+
+    lines.len()
+}
+
+fn describe_parameter(param: &Value) -> Option<String> {
+    if let Some(reference) = param.get("$ref").and_then(Value::as_str) {
+        return Some(format!("ref {}", ref_name(reference)));
+    }
+    let name = param.get("name").and_then(Value::as_str)?;
+    let location = param.get("in").and_then(Value::as_str).unwrap_or("?");
+    if location == "body" {
+        // Swagger 2.0 body parameters carry the request schema
+        let schema = param
+            .get("schema")
+            .map(type_name)
+            .unwrap_or_else(|| "object".to_string());
+        return Some(format!("{name} (body: {schema})"));
+    }
+    let required = param.get("required").and_then(Value::as_bool) == Some(true);
+    Some(format!(
+        "{name}{} ({location})",
+        if required { "*" } else { "" }
+    ))
+}
+
+fn first_media_schema(content: &Value) -> Option<&Value> {
+    let content = content.as_object()?;
+    content
+        .get("application/json")
+        .or_else(|| content.values().next())
+        .and_then(|media| media.get("schema"))
+}
+
+fn security_names(requirements: &[Value]) -> Vec<String> {
+    requirements
+        .iter()
+        .filter_map(Value::as_object)
+        .filter(|req| !req.is_empty())
+        .map(|req| req.keys().cloned().collect::<Vec<_>>().join(" + "))
+        .collect()
+}
+
+/// Describe an object schema's properties as `: a*: string, b: [Item]`
+fn describe_object(schema: &Value) -> String {
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return format!(" ({})", type_name(schema));
+    };
+
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|r| r.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    let mut fields: Vec<String> = properties
+        .iter()
+        .take(MAX_PROPERTIES_PER_SCHEMA)
+        .map(|(name, prop)| {
+            let marker = if required.contains(&name.as_str()) {
+                "*"
+            } else {
+                ""
+            };
+            format!("{name}{marker}: {}", type_name(prop))
+        })
+        .collect();
+    if properties.len() > MAX_PROPERTIES_PER_SCHEMA {
+        fields.push(format!(
+            "… {} more",
+            properties.len() - MAX_PROPERTIES_PER_SCHEMA
+        ));
+    }
+
+    format!(": {}", fields.join(", "))
+}
+
+/// Compact type expression for a schema (`string`, `[User]`, `A | B`, ...)
+fn type_name(schema: &Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return ref_name(reference).to_string();
+    }
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        let mut rendered: Vec<String> = values
+            .iter()
+            .take(MAX_ENUM_VALUES)
+            .map(|v| match v {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .collect();
+        if values.len() > MAX_ENUM_VALUES {
+            rendered.push("…".to_string());
+        }
+        return format!("enum({})", rendered.join("|"));
+    }
+    for (key, separator) in [("oneOf", " | "), ("anyOf", " | "), ("allOf", " & ")] {
+        if let Some(variants) = schema.get(key).and_then(Value::as_array) {
+            return variants
+                .iter()
+                .map(type_name)
+                .collect::<Vec<_>>()
+                .join(separator);
+        }
+    }
+
+    match schema.get("type") {
+        Some(Value::String(t)) if t == "array" => {
+            let items = schema
+                .get("items")
+                .map(type_name)
+                .unwrap_or_else(|| "any".to_string());
+            format!("[{items}]")
+        }
+        Some(Value::String(t)) => match schema.get("format").and_then(Value::as_str) {
+            Some(format) => format!("{t}({format})"),
+            None => t.clone(),
+        },
+        Some(Value::Array(types)) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(" | "),
+        _ if schema.get("properties").is_some() => "object".to_string(),
+        _ => "any".to_string(),
+    }
+}
+
+fn ref_name(reference: &str) -> &str {
+    reference.rsplit('/').next().unwrap_or(reference)
+}
+
+fn first_line(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PETSTORE: &str = r##"
+openapi: 3.0.3
+info:
+  title: Petstore
+  version: 1.2.0
+  description: |
+    Manage pets.
+    Second line is dropped.
+servers:
+  - url: https://api.example.com/v1
+security:
+  - bearerAuth: []
+paths:
+  /pets:
+    get:
+      summary: List pets
+      parameters:
+        - name: limit
+          in: query
+      responses:
+        "200":
+          description: ok
+          content:
+            application/json:
+              schema:
+                type: array
+                items:
+                  $ref: "#/components/schemas/Pet"
+    post:
+      summary: Create a pet
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: "#/components/schemas/NewPet"
+      responses:
+        "201":
+          description: created
+  /health:
+    get:
+      summary: Health check
+      security: []
+      responses:
+        "200":
+          description: ok
+components:
+  securitySchemes:
+    bearerAuth:
+      type: http
+      scheme: bearer
+  schemas:
+    Pet:
+      type: object
+      required: [id, name]
+      properties:
+        id:
+          type: integer
+          format: int64
+        name:
+          type: string
+        status:
+          type: string
+          enum: [available, sold]
+    NewPet:
+      allOf:
+        - $ref: "#/components/schemas/Pet"
+"##;
+
+    #[test]
+    fn test_openapi3_summary() {
+        let summary = summarize(PETSTORE).unwrap();
+        assert_eq!(summary.kind, ApiDocumentKind::OpenApi3);
+        assert_eq!(summary.operation_count, 3);
+        assert_eq!(summary.schema_count, 2);
+
+        let md = &summary.markdown;
+        assert!(md.starts_with("# API Specification: Petstore (v1.2.0)\n"));
+        assert!(md.contains("Manage pets."));
+        assert!(!md.contains("Second line"));
+        assert!(md.contains("- https://api.example.com/v1"));
+        assert!(md.contains("- `bearerAuth`: http bearer"));
+        assert!(md.contains("- Default requirement: bearerAuth"));
+        assert!(md.contains("- `GET /pets` — List pets"));
+        assert!(md.contains("  - params: limit (query)"));
+        assert!(md.contains("  - responses: 200 → [Pet]"));
+        assert!(md.contains("  - body: NewPet"));
+        assert!(md.contains("- `GET /health` — Health check [auth: none]"));
+        assert!(
+            md.contains(
+                "- `Pet`: id*: integer(int64), name*: string, status: enum(available|sold)"
+            )
+        );
+        assert!(md.contains("- `NewPet` (Pet)"));
+    }
+
+    #[test]
+    fn test_swagger2_summary_from_json() {
+        let doc = r##"{
+            "swagger": "2.0",
+            "info": {"title": "Legacy", "version": "0.1"},
+            "host": "legacy.example.com",
+            "basePath": "/api",
+            "securityDefinitions": {"key": {"type": "apiKey", "name": "X-Key", "in": "header"}},
+            "paths": {"/items": {"post": {"parameters": [
+                {"name": "item", "in": "body", "schema": {"$ref": "#/definitions/Item"}}
+            ], "responses": {"200": {"schema": {"$ref": "#/definitions/Item"}}}}}},
+            "definitions": {"Item": {"properties": {"sku": {"type": "string"}}}}
+        }"##;
+
+        let summary = summarize(doc).unwrap();
+        assert_eq!(summary.kind, ApiDocumentKind::Swagger2);
+        assert!(summary.markdown.contains("- legacy.example.com/api"));
+        assert!(
+            summary
+                .markdown
+                .contains("- `key`: apiKey `X-Key` in header")
+        );
+        assert!(summary.markdown.contains("params: item (body: Item)"));
+        assert!(summary.markdown.contains("responses: 200 → Item"));
+        assert!(summary.markdown.contains("- `Item`: sku: string"));
+    }
+
+    #[test]
+    fn test_json_schema_summary() {
+        let doc = r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Config",
+            "type": "object",
+            "required": ["name"],
+            "properties": {"name": {"type": "string"}, "tags": {"type": "array", "items": {"type": "string"}}}
+        }"#;
+
+        let summary = summarize(doc).unwrap();
+        assert_eq!(summary.kind, ApiDocumentKind::JsonSchema);
+        assert_eq!(summary.operation_count, 0);
+        assert!(
+            summary
+                .markdown
+                .contains("- `Config`: name*: string, tags: [string]")
+        );
+    }
+
+    #[test]
+    fn test_unrecognized_document_rejected() {
+        let result = summarize("name: not an api\n");
+        assert!(matches!(result, Err(SourceError::InvalidFormat { .. })));
+
+        let result = summarize("{ not json");
+        assert!(matches!(result, Err(SourceError::InvalidFormat { .. })));
+    }
+}
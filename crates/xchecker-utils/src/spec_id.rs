@@ -82,9 +82,7 @@ pub fn sanitize_spec_id(id: &str) -> Result<String, SpecIdError> {
     if sanitized != id {
         let redacted_original = xchecker_redaction::redact_user_string(id);
         let redacted_sanitized = xchecker_redaction::redact_user_string(&sanitized);
-        eprintln!(
-            "Warning: spec ID sanitized from '{redacted_original}' to '{redacted_sanitized}'"
-        );
+        tracing::warn!("spec ID sanitized from '{redacted_original}' to '{redacted_sanitized}'");
     }
 
     Ok(sanitized)
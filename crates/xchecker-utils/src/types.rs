@@ -15,7 +15,7 @@ pub use crate::lock::{DriftPair, LockDrift};
 /// The standard workflow progresses through phases in this order:
 ///
 /// ```text
-/// Requirements → Design → Tasks → Review → Fixup → Final
+/// Requirements → Design → Tasks → Review → Fixup → Final → Localize
 /// ```
 ///
 /// # Dependencies
@@ -26,6 +26,7 @@ pub use crate::lock::{DriftPair, LockDrift};
 /// - `Review`: Requires `Tasks` to complete successfully
 /// - `Fixup`: Requires `Review` to complete successfully
 /// - `Final`: Requires `Fixup` to complete successfully
+/// - `Localize`: Requires `Final` to complete successfully
 ///
 /// # Example
 ///
@@ -57,6 +58,8 @@ pub enum PhaseId {
     Fixup,
     /// Final phase: completes the workflow and generates final artifacts.
     Final,
+    /// Localize phase: translates the final artifact into `[final] languages`.
+    Localize,
 }
 
 impl PhaseId {
@@ -83,10 +86,92 @@ impl PhaseId {
             Self::Review => "review",
             Self::Fixup => "fixup",
             Self::Final => "final",
+            Self::Localize => "localize",
         }
     }
+
+    /// Phases in phase-graph order; artifact numbers derive from this order.
+    pub const ORDER: [Self; 7] = [
+        Self::Requirements,
+        Self::Design,
+        Self::Tasks,
+        Self::Review,
+        Self::Fixup,
+        Self::Final,
+        Self::Localize,
+    ];
+
+    /// Artifact number of the phase (`0`, `10`, `20`, ...)
+    ///
+    /// Derived from the phase's position in [`Self::ORDER`], spaced by
+    /// [`PHASE_NUMBER_STEP`] so phases can be inserted without renumbering.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use xchecker_utils::types::PhaseId;
+    ///
+    /// assert_eq!(PhaseId::Requirements.number(), 0);
+    /// assert_eq!(PhaseId::Tasks.number(), 20);
+    /// ```
+    #[must_use]
+    pub const fn number(&self) -> u8 {
+        let mut i = 0;
+        while i < Self::ORDER.len() {
+            if Self::ORDER[i] as u8 == *self as u8 {
+                return i as u8 * PHASE_NUMBER_STEP;
+            }
+            i += 1;
+        }
+        panic!("phase missing from PhaseId::ORDER")
+    }
+
+    /// Artifact file name for this phase, e.g. `10-design.md` for suffix `md`
+    #[must_use]
+    pub fn artifact_name(&self, suffix: &str) -> String {
+        format!("{:02}-{}.{suffix}", self.number(), self.as_str())
+    }
+
+    /// Look up a phase by its canonical name
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ORDER.into_iter().find(|phase| phase.as_str() == name)
+    }
+
+    /// Parse an artifact file name into its number, phase, and suffix
+    ///
+    /// Returns `None` for names that are not `NN-<phase>.<suffix>`. The
+    /// number is returned as written, so stale numbering can be detected.
+    #[must_use]
+    pub fn parse_artifact_name(name: &str) -> Option<(u8, Self, &str)> {
+        let (number, rest) = name.split_once('-')?;
+        if number.len() != 2 || !number.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let (phase, suffix) = rest.split_once('.')?;
+        Some((number.parse().ok()?, Self::from_name(phase)?, suffix))
+    }
 }
 
+/// Gap between consecutive phase numbers in artifact names
+pub const PHASE_NUMBER_STEP: u8 = 10;
+
+/// Every phase appears once in [`PhaseId::ORDER`] and numbers fit two digits,
+/// so no two phases can produce the same artifact prefix.
+const _: () = {
+    let order = PhaseId::ORDER;
+    assert!((order.len() - 1) * (PHASE_NUMBER_STEP as usize) < 100);
+    let mut i = 0;
+    while i < order.len() {
+        let mut j = i + 1;
+        while j < order.len() {
+            assert!(order[i] as u8 != order[j] as u8, "duplicate phase in ORDER");
+            j += 1;
+        }
+        i += 1;
+    }
+};
+
 /// Priority levels for content selection in packet building
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Priority {
@@ -181,6 +266,18 @@ pub struct LlmInfo {
     pub timeout_seconds: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub budget_exhausted: Option<bool>,
+    /// Provider requests made for this phase run, including responses that
+    /// failed validation and were retried (absent in older receipts: 1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requests: Option<u32>,
+    /// Usage object exactly as the provider reported it for the accepted
+    /// response (token breakdowns, cache hits, billing tier)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_usage: Option<serde_json::Value>,
+    /// Wall time, CPU time, and peak memory of the provider CLI process
+    /// (CLI providers only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub process_usage: Option<crate::runner::ProcessUsage>,
 }
 
 impl LlmInfo {
@@ -199,6 +296,9 @@ impl LlmInfo {
             timed_out: None,
             timeout_seconds: None,
             budget_exhausted: Some(true),
+            requests: Some(0),
+            provider_usage: None,
+            process_usage: None,
         }
     }
 }
@@ -257,6 +357,49 @@ pub struct Receipt {
     pub llm: Option<LlmInfo>,
     /// Pipeline configuration metadata (V11+)
     pub pipeline: Option<PipelineInfo>,
+    /// Remediation steps for failed runs (see `remediation::suggested_actions`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suggested_actions: Vec<String>,
+    /// BLAKE3 of the redacted effective configuration (JCS-canonical)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_hash: Option<String>,
+    /// Redacted effective configuration as dotted keys
+    /// (`[outputs] embed_config_snapshot = true` only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_snapshot: Option<std::collections::BTreeMap<String, String>>,
+}
+
+/// Receipt flag marking a phase that was explicitly skipped (`--skip-phase`)
+pub const RECEIPT_FLAG_SKIPPED: &str = "skipped";
+
+impl Receipt {
+    /// Returns true if this receipt records an explicitly skipped phase
+    ///
+    /// Skipped phases have exit code 0 and no outputs; they advance the phase
+    /// sequence but do not satisfy dependencies that need the phase's artifacts.
+    #[must_use]
+    pub fn is_skipped(&self) -> bool {
+        self.flags
+            .get(RECEIPT_FLAG_SKIPPED)
+            .is_some_and(|v| v == "true")
+    }
+
+    /// Remediation steps for this receipt's failure (empty on success)
+    ///
+    /// Looked up from `error_kind`, the LLM provider, and `error_reason`; see
+    /// [`crate::remediation`].
+    #[must_use]
+    pub fn remediation(&self) -> Vec<String> {
+        let Some(kind) = &self.error_kind else {
+            return Vec::new();
+        };
+        let provider = self.llm.as_ref().and_then(|llm| llm.provider.as_deref());
+        crate::remediation::suggested_actions(
+            kind,
+            provider,
+            self.error_reason.as_deref().unwrap_or_default(),
+        )
+    }
 }
 
 /// Error kinds for receipt error tracking
@@ -269,12 +412,16 @@ pub enum ErrorKind {
     SecretDetected,
     LockHeld,
     PhaseTimeout,
+    /// The kill switch file stopped the phase
+    OperatorHalt,
+    /// The license/compliance scan blocked the packet
+    ComplianceBlocked,
     ClaudeFailure,
     Unknown,
 }
 
 /// Evidence of packet construction for auditability
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PacketEvidence {
     /// List of files included in the packet
     pub files: Vec<FileEvidence>,
@@ -282,6 +429,64 @@ pub struct PacketEvidence {
     pub max_bytes: usize,
     /// Maximum lines allowed in packet
     pub max_lines: usize,
+    /// Compliance scanner findings for the packet contents (empty when the
+    /// scanner is disabled or found nothing)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub compliance: Vec<ComplianceFinding>,
+    /// Encoding and newline conversions applied to included files before
+    /// hashing (empty when every file was already UTF-8 with LF endings)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub input_conversions: Vec<InputConversion>,
+    /// Packets sent to the provider, in order, when a context overflow
+    /// forced a rebuild at a smaller budget (empty when the first packet fit)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attempts: Vec<PacketAttempt>,
+    /// Files the selectors matched but `.xcheckerignore` kept out of the
+    /// packet (omitted when zero)
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub ignored_files: usize,
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)] // serde passes fields by reference
+const fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+/// One packet sent to the provider during context-overflow retries
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PacketAttempt {
+    /// Byte budget the packet was built with
+    pub max_bytes: usize,
+    /// Line budget the packet was built with
+    pub max_lines: usize,
+    /// Bytes actually used by the packet
+    pub bytes_used: usize,
+    /// Lines actually used by the packet
+    pub lines_used: usize,
+    /// Whether the provider rejected this packet as too large
+    pub context_overflow: bool,
+}
+
+/// A normalization applied to a packet input file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputConversion {
+    /// Path of the converted file
+    pub path: String,
+    /// `bom_stripped`, `utf16_decoded`, `newlines_normalized`, or `lossy_utf8`
+    pub kind: String,
+    /// Line endings converted or invalid sequences replaced; 1 otherwise
+    pub count: usize,
+}
+
+/// A restrictive license header or proprietary marker found in a packet file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplianceFinding {
+    /// Path of the packet file containing the match
+    pub path: String,
+    /// Identifier of the pattern that matched (e.g. `gpl_license`)
+    pub pattern_id: String,
+    /// 1-based line number of the match within the file
+    pub line: usize,
 }
 
 /// Evidence of a single file's inclusion in the packet
@@ -302,8 +507,11 @@ pub struct FileEvidence {
 pub struct FileHash {
     /// Path to the file relative to the spec directory
     pub path: String,
-    /// BLAKE3 hash of the canonicalized content
+    /// BLAKE3 hash of the canonicalized content (Markdown: body only)
     pub blake3_canonicalized: String,
+    /// BLAKE3 hash of the Markdown front matter, when the artifact has one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blake3_front_matter: Option<String>,
 }
 
 /// Status output for a spec, matching `schemas/status.v1.json`.
@@ -338,6 +546,7 @@ pub struct FileHash {
 ///     effective_config: BTreeMap::<String, ConfigValue>::new(),
 ///     lock_drift: None,
 ///     pending_fixups: None,
+///     metadata: None,
 /// };
 ///
 /// println!("Schema version: {}", status.schema_version);
@@ -380,6 +589,29 @@ pub struct StatusOutput {
     /// Pending fixup summary (counts only, no file contents).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pending_fixups: Option<PendingFixupsSummary>,
+    /// Spec metadata from the optional `spec.toml`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<SpecMetadata>,
+}
+
+/// Descriptive spec metadata loaded from the optional `spec.toml`
+///
+/// Used to label specs in status output and to filter fleets of specs by
+/// owner or tag.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpecMetadata {
+    /// Human-readable title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Owning people or teams
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub owners: Vec<String>,
+    /// Free-form tags (e.g. `payments`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Related links (tickets, design docs, dashboards)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<String>,
 }
 
 /// Doctor output structure for JSON emission (schema v1)
@@ -396,6 +628,47 @@ pub struct DoctorOutput {
     /// Cache statistics (wired from InsightCache)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cache_stats: Option<crate::cache::CacheStats>,
+    /// Provider round-trip measurements (`doctor --measure` only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub measurements: Vec<ProviderMeasurement>,
+    /// Deprecated config keys and CLI flags in use
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deprecations: Vec<crate::deprecation::DeprecationNotice>,
+}
+
+/// Timed round-trips against one configured LLM provider
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderMeasurement {
+    /// Provider name (e.g., "claude-cli", "openrouter")
+    pub provider: String,
+    /// Model reported by the provider (empty if no round-trip succeeded)
+    pub model: String,
+    /// Individual round-trips, in order
+    pub samples: Vec<MeasurementSample>,
+    /// Fastest successful round-trip in milliseconds
+    pub latency_min_ms: Option<u64>,
+    /// Median successful round-trip in milliseconds
+    pub latency_p50_ms: Option<u64>,
+    /// Slowest successful round-trip in milliseconds
+    pub latency_max_ms: Option<u64>,
+    /// Output tokens per second across successful round-trips
+    pub output_tokens_per_sec: Option<f64>,
+    /// Whether reported token counts look plausible
+    pub token_accounting: CheckStatus,
+}
+
+/// A single timed provider round-trip
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeasurementSample {
+    /// Wall-clock latency in milliseconds
+    pub latency_ms: u64,
+    /// Input tokens reported by the provider
+    pub tokens_input: Option<u64>,
+    /// Output tokens reported by the provider
+    pub tokens_output: Option<u64>,
+    /// Redacted error message if the round-trip failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 /// Individual health check result
@@ -584,6 +857,153 @@ pub struct StatusJsonOutput {
     /// Lock drift information if lockfile exists and drift detected
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lock_drift: Option<LockDrift>,
+    /// Receipt generation this status was computed from (0 if no receipts written)
+    #[serde(default)]
+    pub generation: u64,
+    /// Spec metadata from the optional `spec.toml`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<SpecMetadata>,
+    /// Phase currently executing, from its heartbeat sidecar
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_run: Option<ActiveRunInfo>,
+    /// Issue-tracker progress of the spec's tasks, from `xchecker sync-tasks`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_sync: Option<TaskSyncProgress>,
+    /// Provider requests and tokens summed over every receipt of the spec
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_usage: Option<TokenUsage>,
+    /// Deprecated config keys, CLI flags, and receipt fields in use
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deprecations: Vec<crate::deprecation::DeprecationNotice>,
+    /// Definition-of-done evaluation (absent without a `[done]` config section)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub done: Option<DoneStatus>,
+    /// Recommended follow-up actions, most pressing first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub next_steps: Vec<NextStep>,
+    /// Partial files left by failed or interrupted runs (empty while a run is active)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub partials: Vec<StalePartial>,
+}
+
+/// Cumulative provider consumption of a spec, per phase and in total
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    /// Phases with at least one provider request, in pipeline order
+    pub phases: Vec<PhaseTokenUsage>,
+    /// Sum over all phases
+    pub total: TokenCounts,
+}
+
+/// Provider consumption of one phase across all of its runs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhaseTokenUsage {
+    pub phase: String,
+    #[serde(flatten)]
+    pub counts: TokenCounts,
+}
+
+/// Request and token counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenCounts {
+    /// Provider requests made
+    pub requests: u64,
+    /// Input tokens reported by the provider
+    pub tokens_input: u64,
+    /// Output tokens reported by the provider
+    pub tokens_output: u64,
+    /// Requests whose provider did not report token counts (not included in the sums)
+    #[serde(default)]
+    pub requests_without_usage: u64,
+}
+
+/// Progress of the phase executing for a spec, from its heartbeat
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActiveRunInfo {
+    /// Phase being executed
+    pub phase: String,
+    /// Process executing the phase
+    pub pid: u32,
+    /// `"active"` while provider bytes arrive, `"stalled"` while the heartbeat
+    /// updates without new bytes, `"silent"` once it stops (hung or dead run)
+    pub state: String,
+    /// When the phase started
+    pub started_at: DateTime<Utc>,
+    /// Seconds since the last heartbeat
+    pub heartbeat_age_secs: u64,
+    /// Provider response bytes received so far
+    pub bytes_streamed: u64,
+}
+
+/// A partial file left behind by a failed or interrupted run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StalePartial {
+    /// Path relative to the spec directory (e.g. `artifacts/10-design.partial.md`)
+    pub path: String,
+    /// Phase the file belongs to, when its name identifies one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+    /// When the file was last written
+    pub modified_at: DateTime<Utc>,
+    /// Seconds since the file was last written
+    pub age_secs: u64,
+    /// Run that left the file, from the `run_id` of the receipt it wrote
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_id: Option<String>,
+}
+
+/// Tracker progress recorded in `20-tasks.sync.yaml` by `xchecker sync-tasks`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskSyncProgress {
+    /// `"github"` or `"jira"`
+    pub tracker: String,
+    /// Repository (`owner/name`) or Jira project key
+    pub target: String,
+    /// Tasks linked to tracker items
+    pub linked: usize,
+    /// Linked tasks whose item was done at the last pull
+    pub done: usize,
+    /// When completion status was last pulled (absent until the first pull)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pulled_at: Option<DateTime<Utc>>,
+}
+
+/// Result of evaluating the `[done]` criteria of config.toml for one spec
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DoneStatus {
+    /// Whether every configured criterion is met
+    pub done: bool,
+    /// One human-readable entry per unmet criterion
+    #[serde(default)]
+    pub unmet: Vec<String>,
+}
+
+/// 0–100 health score of a spec, for fleet overviews
+///
+/// `score` is the sum of the four components; each is worth at most the
+/// points noted on it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpecHealth {
+    /// Overall score, 0 (neglected or failing) to 100 (healthy)
+    pub score: u8,
+    /// How recently a phase ran (0–20)
+    pub recency: u8,
+    /// Share of successful runs among the most recent ones (0–30)
+    pub reliability: u8,
+    /// Unresolved review findings, weighted by severity (0–30)
+    pub findings: u8,
+    /// Definition-of-done criteria met (0–20)
+    pub doneness: u8,
+}
+
+/// A follow-up action recommended from the phase graph and receipt state
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NextStep {
+    /// What to do and why, in one sentence
+    pub summary: String,
+    /// Command that performs the action, when a single command does
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
 }
 
 /// Phase status information for compact status output
@@ -591,7 +1011,7 @@ pub struct StatusJsonOutput {
 pub struct PhaseStatusInfo {
     /// Phase identifier
     pub phase_id: String,
-    /// Phase status: "success", "failed", "not_started"
+    /// Phase status: "success", "failed", "skipped", "not_started"
     pub status: String,
     /// Receipt ID for the latest run (if any)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -613,6 +1033,12 @@ pub struct ResumeJsonOutput {
     pub current_inputs: CurrentInputs,
     /// Next steps hint for the user/agent
     pub next_steps: String,
+    /// Whether the phase is re-run out of order (`--force-phase`)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub force_phase: bool,
+    /// Optional phases recorded as skipped (`--skip-phase`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skip_phases: Vec<String>,
 }
 
 /// Current inputs available for a phase (high-level metadata only)
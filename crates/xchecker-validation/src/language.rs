@@ -0,0 +1,248 @@
+//! Output language selection and lightweight language detection
+//!
+//! Teams producing non-English specs set `output_language` in config. The
+//! orchestrator adds a language instruction to every phase prompt, and the
+//! response validation stage uses [`detect_language`] to catch responses written
+//! in the wrong language so they can be retried.
+//!
+//! Detection counts common function words per language over the prose of a
+//! response (code blocks and inline code are ignored). It only reports a
+//! language when there is enough prose and one language clearly dominates, so
+//! short or mixed responses are never flagged.
+
+use std::fmt;
+
+/// Minimum number of prose words before detection is attempted
+const MIN_PROSE_WORDS: usize = 40;
+
+/// Minimum number of stopword hits for the winning language
+const MIN_STOPWORD_HITS: usize = 8;
+
+/// The winning language must score at least this multiple of the runner-up
+const DOMINANCE_RATIO: usize = 2;
+
+/// Languages supported by `output_language`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutputLanguage {
+    English,
+    German,
+    French,
+    Spanish,
+    Italian,
+    Dutch,
+    Portuguese,
+}
+
+impl OutputLanguage {
+    /// All supported languages
+    pub const ALL: [Self; 7] = [
+        Self::English,
+        Self::German,
+        Self::French,
+        Self::Spanish,
+        Self::Italian,
+        Self::Dutch,
+        Self::Portuguese,
+    ];
+
+    /// Parse an ISO 639-1 language code (case-insensitive)
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::ALL
+            .into_iter()
+            .find(|lang| lang.code().eq_ignore_ascii_case(code))
+    }
+
+    /// ISO 639-1 language code
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::German => "de",
+            Self::French => "fr",
+            Self::Spanish => "es",
+            Self::Italian => "it",
+            Self::Dutch => "nl",
+            Self::Portuguese => "pt",
+        }
+    }
+
+    /// English name of the language
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::German => "German",
+            Self::French => "French",
+            Self::Spanish => "Spanish",
+            Self::Italian => "Italian",
+            Self::Dutch => "Dutch",
+            Self::Portuguese => "Portuguese",
+        }
+    }
+
+    /// Instruction appended to phase prompts
+    #[must_use]
+    pub fn prompt_instruction(self) -> String {
+        format!(
+            "# Output Language\n\
+             Write all prose in {name} ({code}). Keep Markdown section headings required \
+             by these instructions, code, identifiers, file paths, and YAML/JSON keys unchanged.",
+            name = self.name(),
+            code = self.code()
+        )
+    }
+
+    /// Supported codes, comma-separated (for error messages)
+    #[must_use]
+    pub fn supported_codes() -> String {
+        Self::ALL.map(Self::code).join(", ")
+    }
+
+    fn stopwords(self) -> &'static [&'static str] {
+        match self {
+            Self::English => &[
+                "the", "and", "is", "are", "of", "to", "that", "with", "for", "this", "be",
+                "should", "must", "when", "will", "which", "it", "not", "from", "by",
+            ],
+            Self::German => &[
+                "der", "die", "das", "und", "ist", "sind", "nicht", "mit", "ein", "eine", "für",
+                "auf", "wird", "werden", "dass", "soll", "muss", "den", "dem", "auch",
+            ],
+            Self::French => &[
+                "le", "la", "les", "et", "est", "sont", "des", "une", "pour", "dans", "qui",
+                "avec", "pas", "doit", "sur", "du", "au", "cette", "être", "aux",
+            ],
+            Self::Spanish => &[
+                "el", "los", "las", "y", "es", "son", "una", "para", "con", "del", "por", "debe",
+                "como", "está", "cuando", "puede", "también", "sus", "pero", "esta",
+            ],
+            Self::Italian => &[
+                "il", "gli", "della", "delle", "è", "sono", "per", "con", "che", "non", "deve",
+                "nel", "degli", "questo", "anche", "dei", "alla", "essere", "una", "viene",
+            ],
+            Self::Dutch => &[
+                "het", "een", "van", "zijn", "niet", "met", "voor", "op", "dat", "wordt", "moet",
+                "worden", "ook", "deze", "bij", "naar", "heeft", "kan", "als", "dit",
+            ],
+            Self::Portuguese => &[
+                "os", "um", "uma", "não", "para", "com", "do", "da", "dos", "das", "em", "deve",
+                "são", "está", "também", "ser", "pelo", "pela", "quando", "seu",
+            ],
+        }
+    }
+}
+
+impl fmt::Display for OutputLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Detect the dominant language of a response's prose
+///
+/// Returns `None` when there is too little prose or no language clearly
+/// dominates; callers should treat that as "no mismatch".
+#[must_use]
+pub fn detect_language(text: &str) -> Option<OutputLanguage> {
+    let prose = strip_code(text);
+    let words: Vec<String> = prose
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    if words.len() < MIN_PROSE_WORDS {
+        return None;
+    }
+
+    let mut scores: Vec<(OutputLanguage, usize)> = OutputLanguage::ALL
+        .into_iter()
+        .map(|lang| {
+            let stopwords = lang.stopwords();
+            let hits = words
+                .iter()
+                .filter(|w| stopwords.contains(&w.as_str()))
+                .count();
+            (lang, hits)
+        })
+        .collect();
+    scores.sort_by_key(|(_, hits)| std::cmp::Reverse(*hits));
+
+    let (best, best_hits) = scores[0];
+    let runner_up = scores[1].1;
+    (best_hits >= MIN_STOPWORD_HITS && best_hits >= runner_up * DOMINANCE_RATIO).then_some(best)
+}
+
+/// Remove fenced code blocks and inline code spans
+fn strip_code(text: &str) -> String {
+    let mut prose = String::with_capacity(text.len());
+    let mut in_fence = false;
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        for (i, segment) in line.split('`').enumerate() {
+            // Odd segments are inside inline code spans
+            if i % 2 == 0 {
+                prose.push_str(segment);
+                prose.push(' ');
+            }
+        }
+        prose.push('\n');
+    }
+    prose
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENGLISH: &str = "The system should validate the input before it is stored. \
+        When the user submits a form, the request must be checked for missing fields, \
+        and the response will include a list of errors. This is required for all forms \
+        that are shown to the user, and it should not block the page from loading. \
+        The validation is performed by the server and the results are cached.";
+
+    const GERMAN: &str = "Das System soll die Eingabe prüfen, bevor sie gespeichert wird. \
+        Wenn der Benutzer ein Formular absendet, muss die Anfrage auf fehlende Felder \
+        geprüft werden, und die Antwort enthält eine Liste der Fehler. Das ist für alle \
+        Formulare erforderlich, die dem Benutzer angezeigt werden, und es darf das Laden \
+        der Seite nicht blockieren. Die Prüfung wird auf dem Server durchgeführt und auch \
+        die Ergebnisse werden zwischengespeichert.";
+
+    #[test]
+    fn test_from_code() {
+        assert_eq!(
+            OutputLanguage::from_code("de"),
+            Some(OutputLanguage::German)
+        );
+        assert_eq!(
+            OutputLanguage::from_code(" EN "),
+            Some(OutputLanguage::English)
+        );
+        assert_eq!(OutputLanguage::from_code("xx"), None);
+        assert!(OutputLanguage::supported_codes().starts_with("en, de"));
+    }
+
+    #[test]
+    fn test_detects_english_and_german() {
+        assert_eq!(detect_language(ENGLISH), Some(OutputLanguage::English));
+        assert_eq!(detect_language(GERMAN), Some(OutputLanguage::German));
+    }
+
+    #[test]
+    fn test_short_text_is_undetermined() {
+        assert_eq!(detect_language("Das ist gut."), None);
+    }
+
+    #[test]
+    fn test_code_blocks_are_ignored() {
+        let text = format!("{GERMAN}\n\n```rust\n{ENGLISH}\n```\n`the and is of`\n");
+        assert_eq!(detect_language(&text), Some(OutputLanguage::German));
+    }
+}
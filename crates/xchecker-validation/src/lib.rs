@@ -8,6 +8,16 @@ use std::sync::LazyLock;
 use xchecker_utils::error::ValidationError;
 use xchecker_utils::types::PhaseId;
 
+pub mod language;
+pub mod references;
+pub mod response;
+pub mod sanitize;
+
+pub use language::{OutputLanguage, detect_language};
+pub use references::{DanglingReference, ReferenceChecker, ReferenceKind};
+pub use response::{ResponseIssue, ResponseValidator};
+pub use sanitize::{SanitizedResponse, sanitize_response};
+
 /// Patterns that indicate meta-commentary rather than actual content
 static META_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
     vec![
@@ -36,7 +46,7 @@ fn min_lines_for_phase(phase: PhaseId) -> usize {
         PhaseId::Tasks => 25, // Lowered from 40 for stub compatibility
         PhaseId::Review => 15,
         PhaseId::Fixup => 10,
-        PhaseId::Final => 5,
+        PhaseId::Final | PhaseId::Localize => 5,
     }
 }
 
@@ -48,7 +58,8 @@ fn required_headers_for_phase(phase: PhaseId) -> Vec<&'static str> {
         PhaseId::Tasks => vec!["# Implementation", "- [ ]", "- [x]"],
         PhaseId::Review => vec!["# Review", "## Review", "FIXUP PLAN"],
         PhaseId::Fixup => vec!["# Fixup", "Applied", "fixup"],
-        PhaseId::Final => vec![], // Final phase has no required headers
+        // Final phase has no required headers; Localize translates them
+        PhaseId::Final | PhaseId::Localize => vec![],
     }
 }
 
@@ -0,0 +1,370 @@
+//! Cross-phase reference checking for generated documents
+//!
+//! Design and tasks documents point back at earlier work: requirement IDs
+//! (`R2.1`, `NFR3`, or `2.1` in a `_Requirements: ..._` line), relative
+//! Markdown links to files (`[entry point](src/main.rs)`), and heading
+//! anchors (`[overview](#overview)`, `[login](00-requirements.md#requirement-1)`).
+//! [`ReferenceChecker`] resolves those against the spec's requirements, its
+//! other documents, and the repository, and reports the ones that point
+//! nowhere as [`DanglingReference`]s.
+//!
+//! Paths in code spans are not checked: tasks routinely name files that do
+//! not exist yet.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// Requirement IDs written as `R<n>`, `R<n>.<m>`, or `NFR<n>`
+static REQUIREMENT_ID: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(?:R\d+(?:\.\d+)?|NFR\d+)\b").unwrap());
+
+/// `### Requirement <n>` headings in a requirements document
+static REQUIREMENT_HEADING: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^#{2,4}\s+Requirement\s+(\d+)\b").unwrap());
+
+/// Numbered acceptance criteria under a requirement heading
+static CRITERION: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\s*(\d+)\.\s").unwrap());
+
+/// `_Requirements: 1.1, R2.3_` traceability lines in tasks
+static TRACE_LINE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"_Requirements:\s*([^_]*)_").unwrap());
+
+/// Inline Markdown links; the target stops at whitespace or `)`
+static LINK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[[^\]]*\]\(([^)\s]+)(?:\s+[^)]*)?\)").unwrap());
+
+/// URL schemes (`https:`, `mailto:`) that are never checked
+static URL_SCHEME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Za-z][A-Za-z0-9+.\-]*:").unwrap());
+
+/// What a dangling reference was meant to point at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReferenceKind {
+    /// A requirement or acceptance criterion ID
+    Requirement,
+    /// A file linked relative to the spec's artifacts or the repository
+    File,
+    /// A heading anchor in this or another spec document
+    Anchor,
+}
+
+impl ReferenceKind {
+    /// Stable identifier used in receipt warnings
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::Requirement => "requirement",
+            Self::File => "file",
+            Self::Anchor => "anchor",
+        }
+    }
+}
+
+/// A reference in a document that does not resolve
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DanglingReference {
+    /// 1-based line of the reference
+    pub line: usize,
+    pub kind: ReferenceKind,
+    /// The reference as written (requirement IDs normalized to `R<n>.<m>`)
+    pub reference: String,
+}
+
+impl DanglingReference {
+    /// Receipt warning for this reference in `document`
+    ///
+    /// Formatted `dangling_reference:<kind>:<reference> (<document>:<line>)`.
+    #[must_use]
+    pub fn warning(&self, document: &str) -> String {
+        format!(
+            "dangling_reference:{}:{} ({document}:{})",
+            self.kind.code(),
+            self.reference,
+            self.line
+        )
+    }
+}
+
+impl fmt::Display for DanglingReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} reference `{}` on line {} does not resolve",
+            self.kind.code(),
+            self.reference,
+            self.line
+        )
+    }
+}
+
+/// Resolves references in spec documents
+///
+/// Requirement IDs are checked only once a requirements document is known,
+/// and file links only once a repository root is set.
+#[derive(Debug, Clone, Default)]
+pub struct ReferenceChecker {
+    requirements: Option<BTreeSet<String>>,
+    documents: BTreeMap<String, BTreeSet<String>>,
+    repo_root: Option<PathBuf>,
+}
+
+impl ReferenceChecker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve requirement IDs against the IDs `markdown` defines
+    #[must_use]
+    pub fn with_requirements(mut self, markdown: &str) -> Self {
+        self.requirements = Some(requirement_ids(markdown));
+        self
+    }
+
+    /// Make the headings of spec document `name` available as link targets
+    #[must_use]
+    pub fn with_document(mut self, name: &str, markdown: &str) -> Self {
+        self.documents
+            .insert(name.to_string(), heading_anchors(markdown));
+        self
+    }
+
+    /// Resolve relative file links against `root`
+    #[must_use]
+    pub fn with_repo_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.repo_root = Some(root.into());
+        self
+    }
+
+    /// Dangling references in `markdown`, in line order
+    ///
+    /// Anchors without a file (`#overview`) resolve against `markdown`
+    /// itself. Code blocks are skipped.
+    #[must_use]
+    pub fn check(&self, markdown: &str) -> Vec<DanglingReference> {
+        let own_anchors = heading_anchors(markdown);
+        let mut dangling = BTreeSet::new();
+
+        for (line, text) in prose_lines(markdown) {
+            let mut dangle = |kind, reference: String| {
+                dangling.insert(DanglingReference {
+                    line,
+                    kind,
+                    reference,
+                });
+            };
+
+            if let Some(defined) = &self.requirements {
+                for id in referenced_requirements(text) {
+                    if !defined.contains(&id) {
+                        dangle(ReferenceKind::Requirement, id);
+                    }
+                }
+            }
+
+            for target in LINK.captures_iter(text).map(|c| c[1].to_string()) {
+                if URL_SCHEME.is_match(&target) || target.starts_with("//") {
+                    continue;
+                }
+                let (path, anchor) = match target.split_once('#') {
+                    Some((path, anchor)) => (path, Some(anchor)),
+                    None => (target.as_str(), None),
+                };
+
+                if path.is_empty() {
+                    if let Some(anchor) = anchor
+                        && !own_anchors.contains(anchor)
+                    {
+                        dangle(ReferenceKind::Anchor, target.clone());
+                    }
+                } else if let Some(anchors) = self.documents.get(path) {
+                    if let Some(anchor) = anchor
+                        && !anchors.contains(anchor)
+                    {
+                        dangle(ReferenceKind::Anchor, target.clone());
+                    }
+                } else if let Some(root) = &self.repo_root
+                    && !root.join(path.trim_start_matches("./")).exists()
+                {
+                    dangle(ReferenceKind::File, path.to_string());
+                }
+            }
+        }
+
+        dangling.into_iter().collect()
+    }
+}
+
+/// Requirement IDs a requirements document defines
+///
+/// `### Requirement <n>` defines `R<n>` and its numbered acceptance criteria
+/// define `R<n>.<m>`; IDs written out (`R3.2`, `NFR1`) define themselves.
+#[must_use]
+pub fn requirement_ids(markdown: &str) -> BTreeSet<String> {
+    let mut ids = BTreeSet::new();
+    // Requirement number and heading level whose criteria are being read
+    let mut current: Option<(String, usize)> = None;
+    for (_, line) in prose_lines(markdown) {
+        let level = line.len() - line.trim_start_matches('#').len();
+        if let Some(caps) = REQUIREMENT_HEADING.captures(line) {
+            let number = caps[1].to_string();
+            ids.insert(format!("R{number}"));
+            current = Some((number, level));
+        } else if level > 0 {
+            // `#### Acceptance Criteria` stays inside the requirement
+            current = current.filter(|(_, requirement_level)| level > *requirement_level);
+        } else if let (Some((requirement, _)), Some(caps)) = (&current, CRITERION.captures(line)) {
+            ids.insert(format!("R{requirement}.{}", &caps[1]));
+        }
+        ids.extend(
+            REQUIREMENT_ID
+                .find_iter(line)
+                .map(|m| m.as_str().to_string()),
+        );
+    }
+    ids
+}
+
+/// GitHub-style anchors of a document's headings
+#[must_use]
+pub fn heading_anchors(markdown: &str) -> BTreeSet<String> {
+    prose_lines(markdown)
+        .filter_map(|(_, line)| {
+            let title = line.trim_start_matches('#');
+            (title.len() < line.len() && title.starts_with(' ')).then(|| slug(title.trim()))
+        })
+        .collect()
+}
+
+/// Requirement IDs referenced on a line, with bare `_Requirements:` numbers
+/// normalized to `R<n>.<m>`
+fn referenced_requirements(line: &str) -> Vec<String> {
+    let mut ids: Vec<String> = REQUIREMENT_ID
+        .find_iter(line)
+        .map(|m| m.as_str().to_string())
+        .collect();
+    for caps in TRACE_LINE.captures_iter(line) {
+        ids.extend(
+            caps[1]
+                .split(',')
+                .map(str::trim)
+                .filter(|item| {
+                    !item.is_empty() && item.chars().all(|c| c.is_ascii_digit() || c == '.')
+                })
+                .map(|item| format!("R{item}")),
+        );
+    }
+    ids
+}
+
+fn slug(title: &str) -> String {
+    title
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            c if c.is_alphanumeric() => Some(c.to_ascii_lowercase()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Lines outside fenced code blocks, with 1-based line numbers
+fn prose_lines(markdown: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut in_fence = false;
+    markdown.lines().enumerate().filter_map(move |(i, line)| {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            return None;
+        }
+        (!in_fence).then_some((i + 1, line))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REQUIREMENTS: &str = "# Requirements Document
+
+## Requirements
+
+### Requirement 1
+
+**User Story:** As a user, I want to log in
+
+#### Acceptance Criteria
+
+1. WHEN credentials are valid THEN the system SHALL sign the user in
+2. IF credentials are invalid THEN the system SHALL show an error
+
+## Non-Functional Requirements
+
+**NFR1 Performance:** Sign-in completes within 200 ms
+";
+
+    #[test]
+    fn test_requirement_ids_from_headings_criteria_and_labels() {
+        let ids = requirement_ids(REQUIREMENTS);
+        assert_eq!(
+            ids.into_iter().collect::<Vec<_>>(),
+            ["NFR1", "R1", "R1.1", "R1.2"]
+        );
+    }
+
+    #[test]
+    fn test_check_reports_dangling_requirements_links_and_anchors() {
+        let repo = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(repo.path().join("src")).unwrap();
+        std::fs::write(repo.path().join("src/main.rs"), "fn main() {}").unwrap();
+
+        let tasks = "# Implementation Plan
+
+## Sign-in flow
+
+- [ ] 1. Implement sign-in in [main](src/main.rs), see [flow](#sign-in-flow)
+  - _Requirements: 1.1, R1.3, NFR1_
+- [ ] 2. Add rate limiting per [R1](00-requirements.md#requirement-1) and R4
+  - Update [config](src/config.rs) and [missing](00-requirements.md#requirement-9)
+  - Follow [the guide](https://example.com/guide) and [design](#architecture)
+
+```text
+R99 is inside a code block and ignored
+```
+";
+        let checker = ReferenceChecker::new()
+            .with_requirements(REQUIREMENTS)
+            .with_document("00-requirements.md", REQUIREMENTS)
+            .with_repo_root(repo.path());
+        let dangling = checker.check(tasks);
+
+        let found: Vec<(usize, ReferenceKind, &str)> = dangling
+            .iter()
+            .map(|d| (d.line, d.kind, d.reference.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (6, ReferenceKind::Requirement, "R1.3"),
+                (7, ReferenceKind::Requirement, "R4"),
+                (8, ReferenceKind::File, "src/config.rs"),
+                (8, ReferenceKind::Anchor, "00-requirements.md#requirement-9"),
+                (9, ReferenceKind::Anchor, "#architecture"),
+            ]
+        );
+        assert_eq!(
+            dangling[0].warning("20-tasks.md"),
+            "dangling_reference:requirement:R1.3 (20-tasks.md:6)"
+        );
+    }
+
+    #[test]
+    fn test_check_without_sources_skips_requirements_and_files() {
+        let dangling = ReferenceChecker::new().check("See R7 and [plan](docs/plan.md)\n");
+        assert!(dangling.is_empty());
+    }
+}
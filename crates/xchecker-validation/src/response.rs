@@ -0,0 +1,327 @@
+//! Post-stream validation for raw provider responses
+//!
+//! This module runs before phase postprocessing and rejects responses that are
+//! structurally unusable regardless of phase: lossy UTF-8 decodes, byte order
+//! marks, accidental base64 dumps, truncated code fences, and responses that are
+//! too small to be a real document. When an output language is configured,
+//! responses detected as another language are rejected too. Rejected responses
+//! are retried or turned into structured failures instead of being written as
+//! artifacts.
+
+use std::fmt;
+
+use crate::language::{OutputLanguage, detect_language};
+
+/// Default minimum response size in bytes (after trimming whitespace)
+pub const DEFAULT_MIN_RESPONSE_BYTES: usize = 64;
+
+/// Default number of re-invocations when a response fails validation
+pub const DEFAULT_RESPONSE_RETRIES: u32 = 1;
+
+/// Length of an unbroken base64-alphabet run that counts as a dump
+const BASE64_RUN_THRESHOLD: usize = 1024;
+
+/// A structural problem found in a provider response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResponseIssue {
+    /// Response contains U+FFFD replacement characters from a lossy decode
+    InvalidUtf8 { replacement_chars: usize },
+    /// Response contains NUL or other non-text control characters
+    ControlCharacters { count: usize },
+    /// Response starts with (or contains) a byte order mark
+    ByteOrderMark,
+    /// Response contains a long unbroken run of base64 characters
+    Base64Dump { run_length: usize },
+    /// Response is smaller than the configured minimum
+    TooSmall { actual: usize, minimum: usize },
+    /// Response has an odd number of code fences (likely truncated)
+    UnterminatedCodeFence,
+    /// Response prose is in a different language than configured
+    LanguageMismatch {
+        expected: OutputLanguage,
+        detected: OutputLanguage,
+    },
+}
+
+impl ResponseIssue {
+    /// Stable identifier used in receipt warnings and logs
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidUtf8 { .. } => "invalid_utf8",
+            Self::ControlCharacters { .. } => "control_characters",
+            Self::ByteOrderMark => "byte_order_mark",
+            Self::Base64Dump { .. } => "base64_dump",
+            Self::TooSmall { .. } => "too_small",
+            Self::UnterminatedCodeFence => "unterminated_code_fence",
+            Self::LanguageMismatch { .. } => "language_mismatch",
+        }
+    }
+}
+
+impl fmt::Display for ResponseIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidUtf8 { replacement_chars } => write!(
+                f,
+                "response contains {replacement_chars} invalid UTF-8 sequence(s)"
+            ),
+            Self::ControlCharacters { count } => {
+                write!(f, "response contains {count} control character(s)")
+            }
+            Self::ByteOrderMark => write!(f, "response contains a byte order mark"),
+            Self::Base64Dump { run_length } => {
+                write!(f, "response contains a {run_length}-character base64 run")
+            }
+            Self::TooSmall { actual, minimum } => write!(
+                f,
+                "response too small: {actual} bytes (minimum: {minimum} bytes)"
+            ),
+            Self::UnterminatedCodeFence => write!(f, "response has an unterminated code fence"),
+            Self::LanguageMismatch { expected, detected } => {
+                write!(f, "response language is {detected} (expected: {expected})")
+            }
+        }
+    }
+}
+
+/// Validator for raw provider responses
+#[derive(Debug, Clone)]
+pub struct ResponseValidator {
+    min_bytes: usize,
+    language: Option<OutputLanguage>,
+}
+
+impl Default for ResponseValidator {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_RESPONSE_BYTES)
+    }
+}
+
+impl ResponseValidator {
+    /// Create a validator with the given minimum response size in bytes
+    #[must_use]
+    pub fn new(min_bytes: usize) -> Self {
+        Self {
+            min_bytes,
+            language: None,
+        }
+    }
+
+    /// Require responses to be written in the given language
+    #[must_use]
+    pub fn with_language(mut self, language: Option<OutputLanguage>) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// Minimum response size in bytes
+    #[must_use]
+    pub fn min_bytes(&self) -> usize {
+        self.min_bytes
+    }
+
+    /// Validate a raw provider response
+    ///
+    /// Returns `Ok(())` if the response is usable, or every issue found.
+    pub fn validate(&self, content: &str) -> Result<(), Vec<ResponseIssue>> {
+        let mut issues = Vec::new();
+
+        let replacement_chars = content.chars().filter(|c| *c == '\u{FFFD}').count();
+        if replacement_chars > 0 {
+            issues.push(ResponseIssue::InvalidUtf8 { replacement_chars });
+        }
+
+        let control_chars = content
+            .chars()
+            .filter(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+            .count();
+        if control_chars > 0 {
+            issues.push(ResponseIssue::ControlCharacters {
+                count: control_chars,
+            });
+        }
+
+        if content.contains('\u{FEFF}') {
+            issues.push(ResponseIssue::ByteOrderMark);
+        }
+
+        let run_length = longest_base64_run(content);
+        if run_length >= BASE64_RUN_THRESHOLD {
+            issues.push(ResponseIssue::Base64Dump { run_length });
+        }
+
+        let actual = content.trim().len();
+        if actual < self.min_bytes {
+            issues.push(ResponseIssue::TooSmall {
+                actual,
+                minimum: self.min_bytes,
+            });
+        }
+
+        let fences = content
+            .lines()
+            .filter(|line| line.trim_start().starts_with("```"))
+            .count();
+        if fences % 2 != 0 {
+            issues.push(ResponseIssue::UnterminatedCodeFence);
+        }
+
+        if let Some(expected) = self.language
+            && let Some(detected) = detect_language(content)
+            && detected != expected
+        {
+            issues.push(ResponseIssue::LanguageMismatch { expected, detected });
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+}
+
+/// Length of the longest run of base64 characters, allowing line wraps inside the run
+fn longest_base64_run(content: &str) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        let is_base64_line = !trimmed.is_empty()
+            && trimmed
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'='));
+
+        if is_base64_line {
+            current += trimmed.len();
+        } else {
+            // Long runs can also appear inline without line wrapping
+            let inline = trimmed
+                .split(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '=')))
+                .map(str::len)
+                .max()
+                .unwrap_or(0);
+            longest = longest.max(current).max(inline);
+            current = 0;
+        }
+    }
+
+    longest.max(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_response() -> String {
+        "# Requirements Document\n\n## Introduction\n\nThis document describes the system.\n"
+            .to_string()
+    }
+
+    #[test]
+    fn test_valid_response_passes() {
+        assert!(
+            ResponseValidator::default()
+                .validate(&valid_response())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_replacement_chars_detected() {
+        let content = format!("{}\u{FFFD}\u{FFFD}", valid_response());
+        let issues = ResponseValidator::default().validate(&content).unwrap_err();
+        assert_eq!(
+            issues,
+            vec![ResponseIssue::InvalidUtf8 {
+                replacement_chars: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn test_bom_detected() {
+        let content = format!("\u{FEFF}{}", valid_response());
+        let issues = ResponseValidator::default().validate(&content).unwrap_err();
+        assert_eq!(issues, vec![ResponseIssue::ByteOrderMark]);
+    }
+
+    #[test]
+    fn test_nul_detected() {
+        let content = format!("{}\0", valid_response());
+        let issues = ResponseValidator::default().validate(&content).unwrap_err();
+        assert_eq!(issues, vec![ResponseIssue::ControlCharacters { count: 1 }]);
+    }
+
+    #[test]
+    fn test_wrapped_base64_dump_detected() {
+        let line = "QUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVo0NTY3ODkwYWJjZGVmZ2hpamtsbW5v";
+        let dump = std::iter::repeat_n(line, 40).collect::<Vec<_>>().join("\n");
+        let content = format!("{}\n{}\n", valid_response(), dump);
+        let issues = ResponseValidator::default().validate(&content).unwrap_err();
+        assert!(matches!(issues[0], ResponseIssue::Base64Dump { .. }));
+    }
+
+    #[test]
+    fn test_short_words_not_base64() {
+        let content = format!("{}\nSee src/lib.rs and docs/INDEX.md\n", valid_response());
+        assert!(ResponseValidator::default().validate(&content).is_ok());
+    }
+
+    #[test]
+    fn test_too_small_detected() {
+        let issues = ResponseValidator::new(100)
+            .validate("# Tiny\n")
+            .unwrap_err();
+        assert_eq!(
+            issues,
+            vec![ResponseIssue::TooSmall {
+                actual: 6,
+                minimum: 100
+            }]
+        );
+    }
+
+    #[test]
+    fn test_language_mismatch_detected() {
+        let english = "The service must reject requests that are not signed, and it should \
+            log the reason for the rejection. When the signature is valid, the request is \
+            forwarded to the handler that is registered for the route. This is checked for \
+            every request and the result is stored with the audit record of the call.";
+        let validator = ResponseValidator::default().with_language(Some(OutputLanguage::German));
+        let issues = validator.validate(english).unwrap_err();
+        assert_eq!(
+            issues,
+            vec![ResponseIssue::LanguageMismatch {
+                expected: OutputLanguage::German,
+                detected: OutputLanguage::English,
+            }]
+        );
+        assert_eq!(issues[0].code(), "language_mismatch");
+
+        // Too little prose to judge is never a mismatch
+        assert!(validator.validate(&valid_response()).is_ok());
+    }
+
+    #[test]
+    fn test_unterminated_fence_detected() {
+        let content = format!("{}\n```rust\nfn main() {{}}\n", valid_response());
+        let issues = ResponseValidator::default().validate(&content).unwrap_err();
+        assert_eq!(issues, vec![ResponseIssue::UnterminatedCodeFence]);
+    }
+
+    #[test]
+    fn test_issue_codes_are_stable() {
+        assert_eq!(ResponseIssue::ByteOrderMark.code(), "byte_order_mark");
+        assert_eq!(
+            ResponseIssue::TooSmall {
+                actual: 0,
+                minimum: 1
+            }
+            .code(),
+            "too_small"
+        );
+    }
+}
@@ -0,0 +1,127 @@
+//! Sanitation of invisible characters in provider responses
+//!
+//! Some providers emit zero-width spaces, byte order marks, or CRLF line
+//! endings in otherwise identical responses. Those characters survive
+//! canonicalization and change artifact hashes without any visible
+//! difference. [`sanitize_response`] runs before validation and
+//! postprocessing and removes them:
+//!
+//! - U+200B (zero-width space) and U+2060 (word joiner) are removed.
+//! - U+FEFF (byte order mark / zero-width no-break space) is removed wherever
+//!   it appears.
+//! - `\r\n` and lone `\r` line endings become `\n`.
+//!
+//! U+200C and U+200D (zero-width non-joiner and joiner) are kept: they change
+//! the rendering of scripts such as Persian and of emoji sequences.
+
+use std::fmt;
+
+/// Characters removed from responses besides the byte order mark
+const ZERO_WIDTH_CHARS: [char; 2] = ['\u{200B}', '\u{2060}'];
+
+/// Byte order mark, also used as a zero-width no-break space
+const BOM: char = '\u{FEFF}';
+
+/// A response after sanitation, with counts of what was changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SanitizedResponse {
+    /// The sanitized text
+    pub text: String,
+    /// Zero-width spaces and word joiners removed
+    pub zero_width_removed: usize,
+    /// Byte order marks removed
+    pub boms_removed: usize,
+    /// `\r\n` and lone `\r` line endings converted to `\n`
+    pub line_endings_normalized: usize,
+}
+
+impl SanitizedResponse {
+    /// Whether sanitation changed the response
+    #[must_use]
+    pub fn changed(&self) -> bool {
+        self.zero_width_removed + self.boms_removed + self.line_endings_normalized > 0
+    }
+}
+
+impl fmt::Display for SanitizedResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if self.zero_width_removed > 0 {
+            parts.push(format!(
+                "removed {} zero-width character(s)",
+                self.zero_width_removed
+            ));
+        }
+        if self.boms_removed > 0 {
+            parts.push(format!("removed {} byte order mark(s)", self.boms_removed));
+        }
+        if self.line_endings_normalized > 0 {
+            parts.push(format!(
+                "normalized {} CRLF/CR line ending(s)",
+                self.line_endings_normalized
+            ));
+        }
+        f.write_str(&parts.join(", "))
+    }
+}
+
+/// Remove invisible characters and normalize line endings in `raw`
+#[must_use]
+pub fn sanitize_response(raw: &str) -> SanitizedResponse {
+    let mut sanitized = SanitizedResponse {
+        text: String::with_capacity(raw.len()),
+        zero_width_removed: 0,
+        boms_removed: 0,
+        line_endings_normalized: 0,
+    };
+
+    let mut chars = raw.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            BOM => sanitized.boms_removed += 1,
+            c if ZERO_WIDTH_CHARS.contains(&c) => sanitized.zero_width_removed += 1,
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                sanitized.line_endings_normalized += 1;
+                sanitized.text.push('\n');
+            }
+            c => sanitized.text.push(c),
+        }
+    }
+    sanitized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_response_is_unchanged() {
+        let raw = "# Design\n\nPlain text with emoji 👩\u{200D}💻 and نیم\u{200C}فاصله.\n";
+        let sanitized = sanitize_response(raw);
+        assert_eq!(sanitized.text, raw);
+        assert!(!sanitized.changed());
+        assert_eq!(sanitized.to_string(), "");
+    }
+
+    #[test]
+    fn test_invisible_characters_and_crlf_are_removed() {
+        let raw = "\u{FEFF}# Design\r\n\r\nZero\u{200B}width\u{2060} text\rlast\u{FEFF}";
+        let sanitized = sanitize_response(raw);
+        assert_eq!(sanitized.text, "# Design\n\nZerowidth text\nlast");
+        assert_eq!(sanitized.zero_width_removed, 2);
+        assert_eq!(sanitized.boms_removed, 2);
+        assert_eq!(sanitized.line_endings_normalized, 3);
+        assert_eq!(
+            sanitized.to_string(),
+            "removed 2 zero-width character(s), removed 2 byte order mark(s), \
+             normalized 3 CRLF/CR line ending(s)"
+        );
+
+        // Responses that differ only in invisible characters sanitize identically
+        assert_eq!(
+            sanitize_response("# Design\n\nZerowidth text\nlast").text,
+            sanitized.text
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! Stable error type for library consumers
+//!
+//! [`OrchestratorHandle`](crate::OrchestratorHandle) reports construction
+//! failures as [`XCheckerError`] and phase execution failures as `anyhow`
+//! chains. Both convert into [`Error`] at the facade boundary. Its variants
+//! correspond one-to-one with the `error_kind` values recorded in receipts, so
+//! embedding applications can match on failures without depending on anyhow
+//! or on internal error types:
+//!
+//! ```rust,no_run
+//! use xchecker::{Error, OrchestratorHandle, PhaseId};
+//!
+//! # async fn run() -> xchecker::Result<()> {
+//! let mut handle = OrchestratorHandle::new("my-feature")?;
+//! match handle.run_phase(PhaseId::Requirements).await.map_err(Error::from) {
+//!     Ok(result) => println!("exit code {}", result.exit_code),
+//!     Err(Error::LockHeld(_)) => println!("another run owns the spec"),
+//!     Err(e) => return Err(e),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use crate::exit_codes::{ExitCode, error_to_exit_code_and_kind};
+use crate::types::ErrorKind;
+use xchecker_utils::error::XCheckerError;
+
+/// Result alias using [`Error`]
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Error returned to library consumers, one variant per receipt `error_kind`
+///
+/// The `Display` output is the original message; the alternate form (`{:#}`)
+/// includes every cause, and [`source()`](std::error::Error::source) walks the
+/// same chain. New variants may be added in minor releases.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Invalid arguments, configuration, or phase transition (`cli_args`)
+    InvalidInput(Details),
+    /// The packet exceeded its size or memory budget (`packet_overflow`)
+    PacketOverflow(Details),
+    /// A secret was found in content bound for the provider (`secret_detected`)
+    SecretDetected(Details),
+    /// Another process holds the spec's lock (`lock_held`)
+    LockHeld(Details),
+    /// A phase or provider call ran past its timeout (`phase_timeout`)
+    PhaseTimeout(Details),
+    /// The kill switch file stopped the phase (`operator_halt`)
+    OperatorHalt(Details),
+    /// The compliance scan blocked the packet (`compliance_blocked`)
+    ComplianceBlocked(Details),
+    /// The LLM provider or its CLI failed (`claude_failure`)
+    ProviderFailure(Details),
+    /// Any other failure (`unknown`)
+    Other(Details),
+}
+
+/// Underlying cause of an [`Error`]; inspect it through the error's methods
+pub struct Details(anyhow::Error);
+
+impl fmt::Debug for Details {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl Error {
+    /// The receipt `error_kind` this error is recorded under
+    #[must_use]
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidInput(_) => ErrorKind::CliArgs,
+            Self::PacketOverflow(_) => ErrorKind::PacketOverflow,
+            Self::SecretDetected(_) => ErrorKind::SecretDetected,
+            Self::LockHeld(_) => ErrorKind::LockHeld,
+            Self::PhaseTimeout(_) => ErrorKind::PhaseTimeout,
+            Self::OperatorHalt(_) => ErrorKind::OperatorHalt,
+            Self::ComplianceBlocked(_) => ErrorKind::ComplianceBlocked,
+            Self::ProviderFailure(_) => ErrorKind::ClaudeFailure,
+            Self::Other(_) => ErrorKind::Unknown,
+        }
+    }
+
+    /// Exit code the CLI uses for this error
+    #[must_use]
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            Self::InvalidInput(_) => ExitCode::CLI_ARGS,
+            Self::PacketOverflow(_) => ExitCode::PACKET_OVERFLOW,
+            Self::SecretDetected(_) => ExitCode::SECRET_DETECTED,
+            Self::LockHeld(_) => ExitCode::LOCK_HELD,
+            Self::PhaseTimeout(_) => ExitCode::PHASE_TIMEOUT,
+            Self::OperatorHalt(_) => ExitCode::OPERATOR_HALT,
+            Self::ComplianceBlocked(_) => ExitCode::COMPLIANCE_BLOCKED,
+            Self::ProviderFailure(_) => ExitCode::CLAUDE_FAILURE,
+            Self::Other(_) => ExitCode::INTERNAL,
+        }
+    }
+
+    /// The structured [`XCheckerError`] in the cause chain, if there is one
+    #[must_use]
+    pub fn xchecker_error(&self) -> Option<&XCheckerError> {
+        self.details()
+            .0
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<XCheckerError>())
+    }
+
+    fn details(&self) -> &Details {
+        match self {
+            Self::InvalidInput(details)
+            | Self::PacketOverflow(details)
+            | Self::SecretDetected(details)
+            | Self::LockHeld(details)
+            | Self::PhaseTimeout(details)
+            | Self::OperatorHalt(details)
+            | Self::ComplianceBlocked(details)
+            | Self::ProviderFailure(details)
+            | Self::Other(details) => details,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.details().0, f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.details().0.source()
+    }
+}
+
+impl From<anyhow::Error> for Error {
+    /// Classify by the first [`XCheckerError`] in the chain, as receipts do
+    fn from(error: anyhow::Error) -> Self {
+        let kind = error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<XCheckerError>())
+            .map_or(ErrorKind::Unknown, |e| error_to_exit_code_and_kind(e).1);
+        let details = Details(error);
+        match kind {
+            ErrorKind::CliArgs => Self::InvalidInput(details),
+            ErrorKind::PacketOverflow => Self::PacketOverflow(details),
+            ErrorKind::SecretDetected => Self::SecretDetected(details),
+            ErrorKind::LockHeld => Self::LockHeld(details),
+            ErrorKind::PhaseTimeout => Self::PhaseTimeout(details),
+            ErrorKind::OperatorHalt => Self::OperatorHalt(details),
+            ErrorKind::ComplianceBlocked => Self::ComplianceBlocked(details),
+            ErrorKind::ClaudeFailure => Self::ProviderFailure(details),
+            ErrorKind::Unknown => Self::Other(details),
+        }
+    }
+}
+
+impl From<XCheckerError> for Error {
+    fn from(error: XCheckerError) -> Self {
+        anyhow::Error::new(error).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{ConfigError, PhaseError};
+    use anyhow::Context;
+
+    #[test]
+    fn test_kind_and_exit_code_follow_receipt_mapping() {
+        let err = Error::from(XCheckerError::Phase(PhaseError::Timeout {
+            phase: "design".to_string(),
+            timeout_seconds: 600,
+        }));
+        assert!(matches!(err, Error::PhaseTimeout(_)));
+        assert_eq!(err.kind(), ErrorKind::PhaseTimeout);
+        assert_eq!(err.exit_code(), ExitCode::PHASE_TIMEOUT);
+    }
+
+    #[test]
+    fn test_anyhow_context_keeps_kind_and_chain() {
+        let result: anyhow::Result<()> = Err(XCheckerError::Config(ConfigError::InvalidFile(
+            "config.toml".to_string(),
+        )))
+        .context("Failed to create orchestrator");
+        let err = Error::from(result.unwrap_err());
+
+        assert_eq!(err.kind(), ErrorKind::CliArgs);
+        assert_eq!(err.to_string(), "Failed to create orchestrator");
+        assert!(format!("{err:#}").contains("config.toml"));
+        assert!(std::error::Error::source(&err).is_some());
+        assert!(matches!(
+            err.xchecker_error(),
+            Some(XCheckerError::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_unclassified_errors_are_other() {
+        let err = Error::from(anyhow::anyhow!("disk on fire"));
+        assert!(matches!(err, Error::Other(_)));
+        assert_eq!(err.kind(), ErrorKind::Unknown);
+        assert_eq!(err.exit_code(), ExitCode::INTERNAL);
+        assert!(err.xchecker_error().is_none());
+    }
+}
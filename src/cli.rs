@@ -5,13 +5,10 @@
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
-use crossterm::style::{Color, Stylize};
 use std::collections::HashMap;
-use std::io::{IsTerminal, Write};
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::thread;
 use std::time::Duration;
 
 // Stable public API imports from crate root
@@ -22,59 +19,21 @@ use crate::{
 };
 
 // Internal module imports (not part of stable public API)
-use crate::atomic_write::write_file_atomic;
+use crate::atomic_write::{self, write_file_atomic};
+use crate::compression;
+use crate::console::{self, Stream};
 use crate::error::{ConfigError, PhaseError};
 use crate::error_reporter::{ErrorReport, utils as error_utils};
-use crate::logging::Logger;
+use crate::logging::{Logger, Verbosity, init_tracing};
+use crate::orchestrator::{
+    CHARS_PER_TOKEN, ExecutionResult, FailurePoint, OutputEstimateSource, RunEstimate,
+    optional_phase_names,
+};
 use crate::redaction::SecretRedactor;
+use crate::remediation;
 use crate::source::SourceResolver;
 use crate::spec_id::sanitize_spec_id;
 
-/// Check if colored output should be used.
-///
-/// Returns true only if:
-/// - stdout is a terminal (TTY)
-/// - NO_COLOR environment variable is not set
-fn use_color() -> bool {
-    std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
-}
-
-/// Return a styled check mark (✓) if colors are enabled, otherwise plain.
-fn styled_check() -> String {
-    if use_color() {
-        format!("{}", "✓".with(Color::Green).bold())
-    } else {
-        "✓".to_string()
-    }
-}
-
-/// Return a styled warning mark (⚠) if colors are enabled, otherwise plain.
-fn styled_warning() -> String {
-    if use_color() {
-        format!("{}", "⚠".with(Color::Yellow).bold())
-    } else {
-        "⚠".to_string()
-    }
-}
-
-/// Return styled success text if colors are enabled, otherwise plain.
-fn styled_success(text: &str) -> String {
-    if use_color() {
-        format!("{}", text.with(Color::Green).bold())
-    } else {
-        text.to_string()
-    }
-}
-
-/// Return styled info text (cyan) if colors are enabled, otherwise plain.
-fn styled_info(text: &str) -> String {
-    if use_color() {
-        format!("{}", text.with(Color::Cyan).bold())
-    } else {
-        text.to_string()
-    }
-}
-
 /// xchecker - Claude orchestration tool for spec generation
 #[derive(Parser)]
 #[command(name = "xchecker")]
@@ -129,6 +88,14 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub config: Option<PathBuf>,
 
+    /// Directory to use as the xchecker home instead of `XCHECKER_HOME`/`.xchecker`
+    ///
+    /// For read-only home directories (e.g. locked-down CI images). Given before
+    /// the subcommand (`xchecker --workspace /tmp/xc spec my-spec`), since
+    /// `project` subcommands use `--workspace` for the workspace file.
+    #[arg(long, value_name = "DIR")]
+    pub workspace: Option<PathBuf>,
+
     /// Model to use for LLM provider calls
     #[arg(long, global = true)]
     pub model: Option<String>,
@@ -161,9 +128,13 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub claude_path: Option<String>,
 
-    /// Enable verbose output
-    #[arg(short, long, global = true)]
-    pub verbose: bool,
+    /// Report progress and timings on stderr (-v); repeat for debug events (-vv)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Report only errors on stderr and draw no progress; stdout keeps command output
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
 
     /// Tool patterns to allow (passed to Claude as allowedTools)
     #[arg(long, global = true)]
@@ -185,6 +156,10 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub extra_secret_pattern: Vec<String>,
 
+    /// Also allow filesystem sources from this directory (repeatable)
+    #[arg(long, global = true)]
+    pub source_root: Vec<String>,
+
     /// Phase timeout in seconds (default: 600, min: 5)
     #[arg(long, global = true)]
     pub phase_timeout: Option<u64>,
@@ -205,6 +180,18 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub debug_packet: bool,
 
+    /// Write the exact rendered prompt to `context/<phase>-prompt.txt` before sending
+    #[arg(long, global = true)]
+    pub dump_prompt: bool,
+
+    /// Write the rendered prompt (as --dump-prompt) and stop without calling the provider
+    #[arg(long, global = true)]
+    pub prompt_only: bool,
+
+    /// Write context files without secret redaction (requires `allow_raw_context` in config)
+    #[arg(long, global = true)]
+    pub raw_context: bool,
+
     /// Allow symlinks and hardlinks in fixup targets
     #[arg(long, global = true)]
     pub allow_links: bool,
@@ -247,6 +234,24 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub execution_strategy: Option<String>,
 
+    /// Switch on an experimental feature (repeatable; see `[features]` in config)
+    #[arg(long = "enable-feature", global = true, value_name = "NAME")]
+    pub enable_feature: Vec<String>,
+
+    /// Fail deliberately at a point in phase execution (resilience tests only)
+    #[arg(
+        long,
+        global = true,
+        hide = true,
+        value_name = "POINT",
+        value_parser = clap::builder::PossibleValuesParser::new(FailurePoint::ALL.map(FailurePoint::as_str))
+    )]
+    pub inject_failure: Option<String>,
+
+    /// Reuse the result of a completed run with the same key instead of re-executing (safe retries)
+    #[arg(long, global = true, value_name = "KEY")]
+    pub idempotency_key: Option<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -263,12 +268,14 @@ pub enum Commands {
     ///   echo "Build a calculator app" | xchecker spec calc-app
     ///   xchecker spec issue-42 --source gh --gh myorg/myrepo
     ///   xchecker spec new-feature --source fs --repo ./project --dry-run
+    ///   xchecker spec billing-api --source openapi --openapi ./openapi.yaml
     Spec {
         /// Unique identifier for the spec
         id: String,
 
-        /// Source type: 'gh' (GitHub issue), 'fs' (filesystem), 'stdin' (standard input)
-        #[arg(long, default_value = "stdin", value_parser = ["gh", "fs", "stdin"])]
+        /// Source type: 'gh' (GitHub issue), 'fs' (filesystem), 'stdin' (standard input),
+        /// 'openapi' (OpenAPI / JSON Schema document)
+        #[arg(long, default_value = "stdin", value_parser = ["gh", "fs", "stdin", "openapi"])]
         source: String,
 
         /// GitHub repository in format 'owner/repo' (required when --source gh)
@@ -279,6 +286,13 @@ pub enum Commands {
         #[arg(long, help = "Path to local repository directory")]
         repo: Option<String>,
 
+        /// OpenAPI / JSON Schema document path (required when --source openapi)
+        #[arg(
+            long,
+            help = "Path to OpenAPI, Swagger, or JSON Schema document (YAML or JSON)"
+        )]
+        openapi: Option<String>,
+
         /// Run in dry-run mode (show what would be executed without making LLM calls)
         #[arg(long)]
         dry_run: bool,
@@ -295,26 +309,161 @@ pub enum Commands {
         #[arg(long)]
         strict_lock: bool,
 
+        /// Skip the ownership confirmation for --force/--apply-fixups on specs you don't own
+        #[arg(long, short = 'y')]
+        yes: bool,
+
         /// Output spec information as JSON (for Claude Code integration)
         #[arg(long)]
         json: bool,
     },
 
+    /// Run a spec pipeline with strict, non-interactive CI defaults
+    ///
+    /// Resolves the source like `spec`, then runs phases from requirements
+    /// through `--through`, stopping at the first failure. CI mode always
+    /// uses strict validation and strict lockfile checks, never prompts or
+    /// colors output, validates every receipt it emits against the receipt
+    /// schema, and fails on receipt warnings unless `--allow-warnings`.
+    ///
+    /// A `ci-summary.v1` JSON summary is written to `--summary-fd` or
+    /// `--summary-file`, or printed to stdout after the run.
+    ///
+    /// EXIT CODES:
+    ///   0   all phases passed with no warnings
+    ///   1   phases passed but warnings or schema violations failed the run
+    ///   *   otherwise the failing phase's exit code (see `xchecker --help`)
+    ///
+    /// EXAMPLES:
+    ///   xchecker ci my-spec --source fs --repo . --through tasks
+    ///   xchecker ci my-spec --source fs --repo . --summary-fd 3 3>ci.json
+    Ci {
+        /// Unique identifier for the spec
+        id: String,
+
+        /// Source type: 'gh' (GitHub issue), 'fs' (filesystem), 'stdin' (standard input),
+        /// 'openapi' (OpenAPI / JSON Schema document)
+        #[arg(long, default_value = "stdin", value_parser = ["gh", "fs", "stdin", "openapi"])]
+        source: String,
+
+        /// GitHub repository in format 'owner/repo' (required when --source gh)
+        #[arg(long)]
+        gh: Option<String>,
+
+        /// Local repository path (required when --source fs)
+        #[arg(long)]
+        repo: Option<String>,
+
+        /// OpenAPI / JSON Schema document path (required when --source openapi)
+        #[arg(long)]
+        openapi: Option<String>,
+
+        /// Last phase to run: requirements, design, tasks, review, fixup, final
+        #[arg(long, default_value = "requirements", value_parser = ["requirements", "design", "tasks", "review", "fixup", "final"])]
+        through: String,
+
+        /// Run in dry-run mode (no LLM calls)
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Report receipt warnings without failing the run
+        #[arg(long)]
+        allow_warnings: bool,
+
+        /// Fail unless the spec meets the `[done]` criteria of config.toml
+        #[arg(long)]
+        require_done: bool,
+
+        /// Print the estimated tokens and cost of the run and exit without running it
+        #[arg(long)]
+        estimate: bool,
+
+        /// Abort before running when the estimated cost exceeds this amount
+        /// (in the units of `[pricing]`)
+        #[arg(long, value_name = "COST")]
+        max_estimated_cost: Option<f64>,
+
+        /// Write the JSON summary to this already-open file descriptor (Unix)
+        #[arg(long, value_name = "FD", conflicts_with = "summary_file")]
+        summary_fd: Option<u32>,
+
+        /// Write the JSON summary to this file
+        #[arg(long, value_name = "PATH")]
+        summary_file: Option<PathBuf>,
+    },
+
     /// Show status of a spec
     ///
     /// Displays the current state of a spec including completed phases,
     /// artifacts with BLAKE3 hashes, receipts, and configuration.
     ///
+    /// With `--all`, lists every spec with its latest phase and `spec.toml`
+    /// metadata (title, owners, tags), optionally narrowed by `--filter`.
+    ///
     /// EXAMPLES:
     ///   xchecker status my-spec
     ///   xchecker status my-spec --json
+    ///   xchecker status --all --filter tag=payments
     Status {
         /// Spec ID to check status for
-        id: String,
+        #[arg(required_unless_present = "all")]
+        id: Option<String>,
+
+        /// List all specs instead of a single one
+        #[arg(long, conflicts_with = "id")]
+        all: bool,
+
+        /// Only list specs whose metadata matches (tag=, owner=, or title=; repeatable)
+        #[arg(
+            long = "filter",
+            value_name = "KEY=VALUE",
+            requires = "all",
+            conflicts_with = "id"
+        )]
+        filters: Vec<crate::status::metadata::SpecFilter>,
 
         /// Output status as JSON
         #[arg(long)]
         json: bool,
+
+        /// Stream one JSON object per spec as it is indexed (with --all)
+        #[arg(long, requires = "all", conflicts_with_all = ["id", "json"])]
+        ndjson: bool,
+
+        /// Remove partial files left by failed or interrupted runs before reporting
+        #[arg(long, conflicts_with = "all")]
+        clean_partials: bool,
+
+        /// Skip the ownership confirmation for specs you don't own (with --clean-partials)
+        #[arg(long, short = 'y', requires = "clean_partials")]
+        yes: bool,
+    },
+
+    /// List specs registered under XCHECKER_HOME or checked into this repository
+    ///
+    /// Without flags, lists the specs under `<XCHECKER_HOME>/specs`. With
+    /// `--discover`, walks the current repository for `.xchecker/specs/<id>`
+    /// trees and directories containing `spec.toml`, shows each spec's latest
+    /// completed phase and whether it is registered, and offers to copy
+    /// unregistered specs into the home.
+    ///
+    /// EXAMPLES:
+    ///   xchecker list
+    ///   xchecker list --discover
+    ///   xchecker list --discover --register
+    ///   xchecker list --discover --json
+    List {
+        /// Walk the current repository for spec directories
+        #[arg(long)]
+        discover: bool,
+
+        /// Register every unregistered discovered spec without asking
+        #[arg(long, requires = "discover")]
+        register: bool,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
 
     /// Resume execution from a specific phase
@@ -326,13 +475,22 @@ pub enum Commands {
     ///   xchecker resume my-spec --phase design
     ///   xchecker resume my-spec --phase requirements --dry-run
     ///   xchecker resume my-spec --phase design --json
+    ///   xchecker resume my-spec --force-phase tasks --skip-phase review
     Resume {
         /// Spec ID to resume
         id: String,
 
-        /// Phase to resume from: requirements, design, tasks, review, fixup, final
-        #[arg(long, value_parser = ["requirements", "design", "tasks", "review", "fixup", "final"])]
-        phase: String,
+        /// Phase to resume from: requirements, design, tasks, review, fixup, final, localize
+        #[arg(long, value_parser = ["requirements", "design", "tasks", "review", "fixup", "final", "localize"], required_unless_present = "force_phase")]
+        phase: Option<String>,
+
+        /// Re-run a phase even if it or later phases already completed (replaces its artifacts)
+        #[arg(long, value_parser = ["requirements", "design", "tasks", "review", "fixup", "final", "localize"], conflicts_with = "phase")]
+        force_phase: Option<String>,
+
+        /// Record an optional phase (review, fixup) as explicitly skipped (repeatable)
+        #[arg(long = "skip-phase", value_parser = clap::builder::PossibleValuesParser::new(optional_phase_names()))]
+        skip_phase: Vec<String>,
 
         /// Run in dry-run mode (show what would be executed without making LLM calls)
         #[arg(long)]
@@ -346,10 +504,36 @@ pub enum Commands {
         #[arg(long)]
         apply_fixups: bool,
 
+        /// Diff algorithm for fixup previews: myers, patience, or histogram
+        #[arg(long, value_parser = ["myers", "patience", "histogram"], default_value = "myers")]
+        diff_algorithm: String,
+
+        /// Highlight changed words within modified lines in fixup previews
+        #[arg(long)]
+        word_diff: bool,
+
+        /// Review only what changed since the last successful review (review phase only)
+        #[arg(long)]
+        differential: bool,
+
+        /// Print the estimated tokens and cost of the run and exit without running it
+        #[arg(long)]
+        estimate: bool,
+
+        /// Abort before running when the estimated cost exceeds this amount
+        /// (in the units of `[pricing]`)
+        #[arg(long, value_name = "COST")]
+        max_estimated_cost: Option<f64>,
+
         /// Hard fail on lockfile drift (exit with error if model/CLI version differs)
         #[arg(long)]
         strict_lock: bool,
 
+        /// Skip the ownership confirmation for --force, --force-phase, or
+        /// --apply-fixups on specs you don't own
+        #[arg(long, short = 'y')]
+        yes: bool,
+
         /// Output resume information as JSON (for Claude Code integration)
         #[arg(long)]
         json: bool,
@@ -358,11 +542,14 @@ pub enum Commands {
     /// Clean up spec artifacts and receipts
     ///
     /// Removes all artifacts, receipts, and context files for a spec.
-    /// Use --hard to skip confirmation prompts.
+    /// Use --hard to skip confirmation prompts. Specs whose `spec.toml` lists
+    /// `owners` that don't include $USER also need --yes (or an interactive
+    /// confirmation).
     ///
     /// EXAMPLES:
     ///   xchecker clean my-spec
     ///   xchecker clean my-spec --hard --force
+    ///   xchecker clean shared-spec --hard --yes
     Clean {
         /// Spec ID to clean
         id: String,
@@ -374,6 +561,102 @@ pub enum Commands {
         /// Force removal even if lock is present
         #[arg(long)]
         force: bool,
+
+        /// Skip the ownership confirmation for specs you don't own
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+
+    /// Apply an operation to every spec matching metadata filters
+    ///
+    /// Selects specs by their `spec.toml` metadata, prints a confirmation
+    /// summary, then applies the operation given after `--` to each spec in
+    /// turn. A failure on one spec does not stop the others; results are
+    /// consolidated at the end and the command exits with the first failing
+    /// spec's exit code.
+    ///
+    /// OPERATIONS:
+    ///   run <phase>   Run a phase (requirements, design, tasks, review, fixup, final)
+    ///   clean         Remove artifacts, receipts, and context (keeps spec.toml)
+    ///   archive       Move the spec directory to <XCHECKER_HOME>/archive/
+    ///
+    /// EXAMPLES:
+    ///   xchecker bulk --filter tag=payments -- run requirements
+    ///   xchecker bulk --filter owner=platform-team --yes -- clean
+    ///   xchecker bulk --filter tag=legacy --yes --json -- archive
+    Bulk {
+        /// Select specs whose metadata matches (tag=, owner=, or title=; repeatable)
+        #[arg(long = "filter", value_name = "KEY=VALUE", required = true)]
+        filters: Vec<crate::status::metadata::SpecFilter>,
+
+        /// Skip the confirmation prompt (required with --json)
+        #[arg(long, short = 'y')]
+        yes: bool,
+
+        /// Run phases in dry-run mode (no LLM calls)
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Force override of stale locks
+        #[arg(long)]
+        force: bool,
+
+        /// Output consolidated results as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Print the estimated tokens and cost of the phase on every selected spec and exit
+        #[arg(long)]
+        estimate: bool,
+
+        /// Abort before running when the estimated cost exceeds this amount
+        /// (in the units of `[pricing]`)
+        #[arg(long, value_name = "COST")]
+        max_estimated_cost: Option<f64>,
+
+        /// Operation to apply: `run <phase>`, `clean`, or `archive`
+        #[arg(last = true, required = true, value_name = "OPERATION")]
+        operation: Vec<String>,
+    },
+
+    /// Run one phase against several models and compare the results
+    ///
+    /// Copies the spec into one experiment branch per model
+    /// (`<spec-id>.exp-<model>`, tagged `experiment`), re-runs the phase in each
+    /// branch with that model, then prints a side-by-side report of duration,
+    /// tokens, cost, and a diff of every output against the first model's. The
+    /// source spec is left untouched; remove the branches with `xchecker clean`.
+    ///
+    /// EXAMPLES:
+    ///   xchecker experiment my-spec --models haiku,sonnet --phase design
+    ///   xchecker experiment my-spec --models haiku,sonnet,opus --phase tasks --json
+    Experiment {
+        /// Spec ID to experiment on
+        id: String,
+
+        /// Models to compare, comma-separated; the first is the diff baseline
+        #[arg(long, value_delimiter = ',', required = true)]
+        models: Vec<String>,
+
+        /// Phase to run with each model
+        #[arg(long, value_parser = ["requirements", "design", "tasks", "review", "fixup", "final", "localize"])]
+        phase: String,
+
+        /// Run in dry-run mode (no LLM calls)
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Force override of stale locks on the experiment branches
+        #[arg(long)]
+        force: bool,
+
+        /// Skip the ownership confirmation for specs you don't own
+        #[arg(long, short = 'y')]
+        yes: bool,
+
+        /// Output the comparison as JSON
+        #[arg(long)]
+        json: bool,
     },
 
     /// Run performance benchmarks (NFR1 validation)
@@ -420,6 +703,28 @@ pub enum Commands {
         max_commit_mb: Option<f64>,
     },
 
+    /// Measure packet build, redaction, canonicalization, and hashing throughput
+    ///
+    /// Runs each stage over the files in DIR and prints per-stage timings as JSON.
+    /// Intended for comparing builds on a real checkout before upgrading.
+    ///
+    /// EXAMPLES:
+    ///   xchecker bench .
+    ///   xchecker bench ~/src/monorepo --samples 20 --warmup 2
+    #[command(hide = true)]
+    Bench {
+        /// Directory whose files form the benchmark workload
+        dir: PathBuf,
+
+        /// Number of measured samples per stage
+        #[arg(long, default_value = "10")]
+        samples: usize,
+
+        /// Number of unmeasured warm-up passes per stage
+        #[arg(long, default_value = "1")]
+        warmup: usize,
+    },
+
     /// Run integration smoke tests to validate all components
     ///
     /// Validates that all systems are properly integrated and working.
@@ -443,10 +748,16 @@ pub enum Commands {
     /// Validates that Claude CLI is installed, runner configuration is correct,
     /// write permissions are available, and configuration is valid.
     ///
+    /// With `--measure`, also times a few small round-trips to each configured
+    /// provider (primary and fallback) and reports latency, output-token
+    /// throughput, and token-accounting sanity. This spends real tokens.
+    /// Add `--verbose` for a per-sample latency histogram.
+    ///
     /// EXAMPLES:
     ///   xchecker doctor
     ///   xchecker doctor --json
     ///   xchecker doctor --strict-exit  # Treat warnings as failures
+    ///   xchecker doctor --measure --samples 5 --verbose
     Doctor {
         /// Output doctor results as JSON
         #[arg(long)]
@@ -455,6 +766,43 @@ pub enum Commands {
         /// Treat warnings as failures (exit non-zero on any warn or fail)
         #[arg(long)]
         strict_exit: bool,
+
+        /// Time round-trips to each configured provider (spends tokens)
+        #[arg(long)]
+        measure: bool,
+
+        /// Round-trips per provider with --measure
+        #[arg(long, default_value = "3", requires = "measure", value_parser = clap::value_parser!(u16).range(1..=20))]
+        samples: u16,
+
+        /// Fail unless this binary was built with the `no-telemetry` feature
+        #[arg(long)]
+        require_no_telemetry: bool,
+    },
+
+    /// Update xchecker to the newest signed release
+    ///
+    /// Downloads the release binary for this platform, verifies the release's
+    /// signed checksum manifest, and atomically replaces the running binary.
+    /// Unavailable in builds made without the `self-update` feature (e.g.
+    /// distribution packages); update those through the package manager.
+    ///
+    /// EXAMPLES:
+    ///   xchecker self-update
+    ///   xchecker self-update --check
+    ///   xchecker self-update --channel prerelease
+    SelfUpdate {
+        /// Release channel: 'stable' or 'prerelease' (includes -rc/-beta/-alpha)
+        #[arg(long, default_value = "stable", value_parser = ["stable", "prerelease"])]
+        channel: String,
+
+        /// Only report whether a newer release is available
+        #[arg(long)]
+        check: bool,
+
+        /// Install the newest release even if it is not newer than this one
+        #[arg(long, conflicts_with = "check")]
+        force: bool,
     },
 
     /// Initialize a new spec with optional lockfile creation
@@ -531,119 +879,392 @@ pub enum Commands {
         json: bool,
     },
 
-    /// Manage spec templates
+    /// Generate a Keep-a-Changelog entry for a spec
     ///
-    /// Templates provide predefined configurations and problem statements
-    /// for common use cases, allowing you to bootstrap specs quickly.
+    /// Summarizes the spec's final outcome from its receipts and latest
+    /// artifact, producing a Markdown fragment suitable for dropping into the
+    /// consuming repository's CHANGELOG workflow.
     ///
     /// EXAMPLES:
-    ///   xchecker template list
-    ///   xchecker template init fullstack-nextjs my-app
-    ///   xchecker template init rust-microservice my-service
-    ///
-    /// Per FR-TEMPLATES (Requirements 4.7.1, 4.7.2, 4.7.3)
-    #[command(subcommand)]
-    Template(TemplateCommands),
-}
+    ///   xchecker changelog-fragment my-spec
+    ///   xchecker changelog-fragment my-spec >> changelog.d/my-spec.md
+    ChangelogFragment {
+        /// Spec ID to summarize
+        id: String,
+    },
 
-/// Project/workspace management subcommands
-#[derive(Subcommand)]
-pub enum ProjectCommands {
-    /// Initialize a new workspace in the current directory
+    /// Render a status badge for a spec's README
     ///
-    /// Creates a `workspace.yaml` file that marks this directory as a project root.
-    /// The workspace can then be used to manage multiple specs.
+    /// The badge shows the latest phase and whether it passed. Without
+    /// `--out` the SVG is printed to stdout. Set `[outputs] badge` in the
+    /// config to regenerate the badge automatically after every run.
     ///
     /// EXAMPLES:
-    ///   xchecker project init my-project
-    Init {
-        /// Name for the workspace
-        name: String,
+    ///   xchecker badge my-spec --out docs/badge.svg
+    ///   xchecker badge my-spec --markdown
+    ///   xchecker badge my-spec --out docs/badge.svg --markdown
+    Badge {
+        /// Spec ID to render
+        id: String,
+
+        /// Write the SVG badge to this path
+        #[arg(long)]
+        out: Option<PathBuf>,
+
+        /// Print a Markdown image snippet instead of the SVG
+        /// (points at `--out` when given, otherwise at a shields.io URL)
+        #[arg(long)]
+        markdown: bool,
     },
 
-    /// Add a spec to the workspace
+    /// Export a spec as a static HTML site
     ///
-    /// Registers a spec in the workspace registry with optional tags.
+    /// Writes an index with the spec's status, provider usage, trend charts,
+    /// and receipt log, plus a page per artifact. Only artifacts and receipts
+    /// are exported; the output can be published as-is (e.g. GitHub Pages).
     ///
     /// EXAMPLES:
-    ///   xchecker project add-spec feature-auth
-    ///   xchecker project add-spec feature-auth --tag backend --tag security
-    AddSpec {
-        /// Spec ID to add
-        spec_id: String,
-
-        /// Tags for categorization (can be specified multiple times)
-        #[arg(long, short)]
-        tag: Vec<String>,
+    ///   xchecker export-site my-spec --out ./site
+    ExportSite {
+        /// Spec ID to export
+        id: String,
 
-        /// Force override if spec already exists
+        /// Output directory
         #[arg(long)]
-        force: bool,
+        out: PathBuf,
     },
 
-    /// List all specs in the workspace
+    /// Export review findings as GitHub issues or a tasks checklist
     ///
-    /// Shows all registered specs with their tags and status.
+    /// Findings are the list items of the review artifact (`30-review.md`).
+    /// `github-issues` files one issue per finding in `--repo` using
+    /// `GITHUB_TOKEN` (or `GH_TOKEN`); with `--dry-run` the issue payloads are
+    /// printed as JSON instead. `markdown-checklist` writes a "Review
+    /// Findings" checklist to `20-tasks.findings.md` next to the tasks
+    /// artifact, replacing any earlier export; with `--dry-run` it is printed
+    /// instead.
     ///
     /// EXAMPLES:
-    ///   xchecker project list
-    List {
-        /// Path to workspace file (overrides discovery)
+    ///   xchecker export-findings my-spec --to github-issues --dry-run
+    ///   xchecker export-findings my-spec --to github-issues --repo owner/name --label review
+    ///   xchecker export-findings my-spec --to markdown-checklist
+    ExportFindings {
+        /// Spec ID whose review to export
+        id: String,
+
+        /// Export target: 'github-issues' or 'markdown-checklist'
+        #[arg(long, value_parser = ["github-issues", "markdown-checklist"])]
+        to: String,
+
+        /// Repository to file issues in (owner/name); required for github-issues without --dry-run
         #[arg(long)]
-        workspace: Option<PathBuf>,
+        repo: Option<String>,
+
+        /// Extra label for every issue (repeatable)
+        #[arg(long = "label")]
+        labels: Vec<String>,
+
+        /// Print the issue payloads or checklist without filing or writing anything
+        #[arg(long)]
+        dry_run: bool,
     },
 
-    /// Show aggregated status for all specs in the workspace
+    /// Sync the tasks artifact with GitHub or Jira issues
     ///
-    /// Displays status summary for all registered specs including phase summaries,
-    /// counts of failed/pending/stale specs, and overall workspace health.
+    /// `push` creates one tracker item per numbered task in `20-tasks.md` that
+    /// is not linked yet and records the item keys in `20-tasks.sync.yaml`,
+    /// which receipts do not cover.
+    /// `pull` fetches the state of every linked item so `xchecker status` can
+    /// report how many tasks are done. GitHub needs `GITHUB_TOKEN` (or
+    /// `GH_TOKEN`); Jira needs `JIRA_URL`, `JIRA_EMAIL`, and `JIRA_API_TOKEN`.
     ///
     /// EXAMPLES:
-    ///   xchecker project status
-    ///   xchecker project status --json
-    Status {
-        /// Path to workspace file (overrides discovery)
+    ///   xchecker sync-tasks my-spec push --tracker github --repo owner/name --dry-run
+    ///   xchecker sync-tasks my-spec push --tracker jira --project PAY
+    ///   xchecker sync-tasks my-spec pull
+    SyncTasks {
+        /// Spec ID whose tasks to sync
+        id: String,
+
+        /// 'push' to create tracker items, 'pull' to fetch their completion status
+        #[arg(value_parser = ["push", "pull"])]
+        action: String,
+
+        /// Tracker for the first push: 'github' or 'jira' (later runs use the linked tracker)
+        #[arg(long, value_parser = ["github", "jira"])]
+        tracker: Option<String>,
+
+        /// GitHub repository (owner/name) for the first push
         #[arg(long)]
-        workspace: Option<PathBuf>,
+        repo: Option<String>,
 
-        /// Output status as JSON (workspace-status-json.v1 schema)
+        /// Jira project key for the first push
         #[arg(long)]
-        json: bool,
+        project: Option<String>,
+
+        /// List the items push would create without contacting the tracker
+        #[arg(long)]
+        dry_run: bool,
     },
 
-    /// Show history timeline for a spec
+    /// Upgrade receipts written by older builds to the current schema
     ///
-    /// Displays timeline of phase progression, timestamps, and selected metrics
-    /// including LLM token usage and fixup counts.
+    /// Rewrites legacy receipts (missing fields, `timestamp` instead of
+    /// `emitted_at`) in place. The originals are copied to
+    /// `receipts-backup-<timestamp>/` in the spec directory first.
     ///
     /// EXAMPLES:
-    ///   xchecker project history feature-auth
-    ///   xchecker project history feature-auth --json
-    ///
-    /// Per FR-WORKSPACE (Requirements 4.3.5): Emits timeline of phase progression
-    History {
-        /// Spec ID to show history for
-        spec_id: String,
+    ///   xchecker migrate-receipts my-spec --to v1 --dry-run
+    ///   xchecker migrate-receipts my-spec --to v1
+    MigrateReceipts {
+        /// Spec ID whose receipts should be migrated
+        id: String,
 
-        /// Output history as JSON (workspace-history-json.v1 schema)
+        /// Target receipt schema version
+        #[arg(long, default_value = "v1", value_parser = ["v1"])]
+        to: String,
+
+        /// Report which receipts would change without writing anything
         #[arg(long)]
-        json: bool,
+        dry_run: bool,
     },
 
-    /// Launch interactive terminal UI for workspace overview
-    ///
-    /// Displays an interactive TUI showing specs list with tags and status,
-    /// latest receipt summary per selected spec, pending fixups, error counts,
-    /// and stale specs. The TUI is read-only (no destructive operations).
+    /// Rename artifacts to match the current phase order
     ///
-    /// Navigation:
-    ///   - Arrow keys or j/k: Move selection up/down
-    ///   - Enter: View details for selected spec
-    ///   - Esc: Go back / close details
-    ///   - q: Quit
+    /// Artifact numbers (`00-`, `10-`, ...) derive from the phase order.
+    /// When that order changes, existing files keep their old numbers and
+    /// phases refuse to run until they are renamed. Nothing is renamed if
+    /// any target name is already taken.
     ///
     /// EXAMPLES:
-    ///   xchecker project tui
+    ///   xchecker migrate-artifacts my-spec --dry-run
+    ///   xchecker migrate-artifacts my-spec
+    MigrateArtifacts {
+        /// Spec ID whose artifacts should be renumbered
+        id: String,
+
+        /// List the renames without touching any files
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Compare two receipts field by field
+    ///
+    /// Ignores fields expected to vary between runs (emission time, stderr)
+    /// and groups the rest by kind: model, flags, output hashes, outcome,
+    /// warnings, and packet evidence. Useful for "same inputs, different
+    /// outputs" reports.
+    ///
+    /// EXAMPLES:
+    ///   xchecker diff-receipts old.json new.json
+    ///   xchecker diff-receipts a.json b.json --json
+    DiffReceipts {
+        /// First receipt file
+        a: PathBuf,
+
+        /// Second receipt file
+        b: PathBuf,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Explain the most recent failure of a spec and how to fix it
+    ///
+    /// Reads the latest failed receipt and prints its error together with the
+    /// suggested actions for that error kind and LLM provider.
+    ///
+    /// EXAMPLES:
+    ///   xchecker explain my-spec
+    ///   xchecker explain my-spec --phase design --json
+    Explain {
+        /// Spec ID to explain
+        id: String,
+
+        /// Only consider receipts for this phase
+        #[arg(long)]
+        phase: Option<String>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Manage spec templates
+    ///
+    /// Templates provide predefined configurations and problem statements
+    /// for common use cases, allowing you to bootstrap specs quickly.
+    ///
+    /// EXAMPLES:
+    ///   xchecker template list
+    ///   xchecker template init fullstack-nextjs my-app
+    ///   xchecker template init rust-microservice my-service
+    ///
+    /// Per FR-TEMPLATES (Requirements 4.7.1, 4.7.2, 4.7.3)
+    #[command(subcommand)]
+    Template(TemplateCommands),
+
+    /// Generate the published JSON schema examples
+    ///
+    /// Writes the same minimal and full examples that ship in docs/schemas,
+    /// so packaging pipelines can regenerate them without running tests.
+    ///
+    /// EXAMPLES:
+    ///   xchecker examples emit
+    ///   xchecker examples emit --out dist/schemas
+    #[command(subcommand)]
+    Examples(ExamplesCommands),
+
+    /// Generate documentation from the published JSON schemas
+    ///
+    /// EXAMPLES:
+    ///   xchecker schema docs
+    ///   xchecker schema docs --out dist/schemas
+    #[command(subcommand)]
+    Schema(SchemaCommands),
+
+    /// Inspect the effective configuration
+    ///
+    /// EXAMPLES:
+    ///   xchecker config diff
+    ///   xchecker config diff --json
+    #[command(subcommand)]
+    Config(ConfigCommands),
+
+    /// Install and run git hooks that check committed spec state
+    ///
+    /// Installed hooks scan changed files for secrets and verify that the
+    /// artifacts of touched specs still match their latest receipts. Checks
+    /// are configured under `[git_hooks]` in `.xchecker/config.toml`.
+    ///
+    /// EXAMPLES:
+    ///   xchecker hooks install
+    ///   xchecker hooks install --hook pre-commit --hook pre-push
+    ///   xchecker hooks run pre-commit
+    #[command(subcommand)]
+    Hooks(HooksCommands),
+
+    /// Inspect the model alias registry
+    ///
+    /// EXAMPLES:
+    ///   xchecker models list
+    ///   xchecker models list --provider gemini-cli --json
+    #[command(subcommand)]
+    Models(ModelsCommands),
+
+    /// Queue prioritized phase runs for daemon mode
+    ///
+    /// Requires the experimental `daemon` feature. `queue run` executes
+    /// queued jobs one phase at a time, highest priority first; between
+    /// phases a higher-priority job pauses the running one.
+    ///
+    /// EXAMPLES:
+    ///   xchecker queue add my-spec --priority high
+    ///   xchecker queue run --watch
+    ///   xchecker queue status --json
+    #[command(subcommand)]
+    Queue(QueueCommands),
+}
+
+/// Project/workspace management subcommands
+#[derive(Subcommand)]
+pub enum ProjectCommands {
+    /// Initialize a new workspace in the current directory
+    ///
+    /// Creates a `workspace.yaml` file that marks this directory as a project root.
+    /// The workspace can then be used to manage multiple specs.
+    ///
+    /// EXAMPLES:
+    ///   xchecker project init my-project
+    Init {
+        /// Name for the workspace
+        name: String,
+    },
+
+    /// Add a spec to the workspace
+    ///
+    /// Registers a spec in the workspace registry with optional tags.
+    ///
+    /// EXAMPLES:
+    ///   xchecker project add-spec feature-auth
+    ///   xchecker project add-spec feature-auth --tag backend --tag security
+    AddSpec {
+        /// Spec ID to add
+        spec_id: String,
+
+        /// Tags for categorization (can be specified multiple times)
+        #[arg(long, short)]
+        tag: Vec<String>,
+
+        /// Force override if spec already exists
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// List all specs in the workspace
+    ///
+    /// Shows all registered specs with their tags and status.
+    ///
+    /// EXAMPLES:
+    ///   xchecker project list
+    List {
+        /// Path to workspace file (overrides discovery)
+        #[arg(long)]
+        workspace: Option<PathBuf>,
+    },
+
+    /// Show aggregated status for all specs in the workspace
+    ///
+    /// Displays status summary for all registered specs including phase summaries,
+    /// counts of failed/pending/stale specs, and overall workspace health.
+    ///
+    /// EXAMPLES:
+    ///   xchecker project status
+    ///   xchecker project status --json
+    Status {
+        /// Path to workspace file (overrides discovery)
+        #[arg(long)]
+        workspace: Option<PathBuf>,
+
+        /// Output status as JSON (workspace-status-json.v1 schema)
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Show history timeline for a spec
+    ///
+    /// Displays timeline of phase progression, timestamps, and selected metrics
+    /// including LLM token usage and fixup counts.
+    ///
+    /// EXAMPLES:
+    ///   xchecker project history feature-auth
+    ///   xchecker project history feature-auth --json
+    ///
+    /// Per FR-WORKSPACE (Requirements 4.3.5): Emits timeline of phase progression
+    History {
+        /// Spec ID to show history for
+        spec_id: String,
+
+        /// Output history as JSON (workspace-history-json.v1 schema)
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Launch interactive terminal UI for workspace overview
+    ///
+    /// Displays an interactive TUI showing specs list with tags and status,
+    /// latest receipt summary per selected spec, pending fixups, error counts,
+    /// and stale specs. The TUI is read-only (no destructive operations).
+    ///
+    /// Navigation:
+    ///   - Arrow keys or j/k: Move selection up/down
+    ///   - Enter: View details for selected spec
+    ///   - Esc: Go back / close details
+    ///   - q: Quit
+    ///
+    /// EXAMPLES:
+    ///   xchecker project tui
     ///
     /// Per FR-WORKSPACE-TUI (Requirements 4.4.1, 4.4.2, 4.4.3)
     Tui {
@@ -693,6 +1314,172 @@ pub enum TemplateCommands {
     },
 }
 
+/// Schema example subcommands
+#[derive(Subcommand)]
+pub enum ExamplesCommands {
+    /// Write minimal and full examples for every schema
+    ///
+    /// Files are named `<schema>.v1.<minimal|full>.json`, are JCS canonical,
+    /// and overwrite any existing file of the same name.
+    ///
+    /// EXAMPLES:
+    ///   xchecker examples emit
+    ///   xchecker examples emit --out dist/schemas
+    Emit {
+        /// Directory to write into (created if missing)
+        #[arg(long, default_value = "docs/schemas")]
+        out: PathBuf,
+    },
+}
+
+/// Schema documentation subcommands
+#[derive(Subcommand)]
+pub enum SchemaCommands {
+    /// Write a markdown field table for every schema
+    ///
+    /// Pages are named `<schema>.md` and list each field's type, whether it
+    /// is required, its description, and the release it appeared in. They
+    /// overwrite any existing page of the same name.
+    ///
+    /// EXAMPLES:
+    ///   xchecker schema docs
+    ///   xchecker schema docs --out dist/schemas
+    Docs {
+        /// Directory to write into (created if missing)
+        #[arg(long, default_value = "docs/schemas")]
+        out: PathBuf,
+    },
+}
+
+/// Configuration inspection subcommands
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+    /// Show only keys whose effective values differ from built-in defaults
+    ///
+    /// Each row lists the default, the effective value, and where the
+    /// override came from (cli, env, config, or programmatic). Useful for
+    /// auditing configuration drift across repositories.
+    ///
+    /// EXAMPLES:
+    ///   xchecker config diff
+    ///   xchecker --config ci/xchecker.toml config diff --json
+    Diff {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Model registry subcommands
+#[derive(Subcommand)]
+pub enum ModelsCommands {
+    /// List the models and aliases known for a provider
+    ///
+    /// Defaults to the configured provider. Models the provider has
+    /// scheduled for retirement are shown with their sunset date and
+    /// replacement; the configured default model is marked with `*`.
+    ///
+    /// EXAMPLES:
+    ///   xchecker models list
+    ///   xchecker models list --provider openrouter
+    List {
+        /// Provider to list (claude-cli, anthropic, gemini-cli, openrouter)
+        #[arg(long, value_parser = crate::llm::models::PROVIDERS)]
+        provider: Option<String>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Job queue subcommands (daemon mode)
+#[derive(Subcommand)]
+pub enum QueueCommands {
+    /// Queue phases to run for a spec
+    ///
+    /// EXAMPLES:
+    ///   xchecker queue add my-spec
+    ///   xchecker queue add my-spec --phase review --phase final --priority low
+    Add {
+        /// Spec ID
+        id: String,
+
+        /// Phase to run (repeatable; default: requirements, design, tasks)
+        #[arg(long, value_parser = ["requirements", "design", "tasks", "review", "fixup", "final", "localize"])]
+        phase: Vec<String>,
+
+        /// Scheduling priority
+        #[arg(long, default_value = "normal", value_parser = ["low", "normal", "high"])]
+        priority: String,
+    },
+
+    /// Show queued, running, paused, and finished jobs
+    ///
+    /// EXAMPLES:
+    ///   xchecker queue status
+    ///   xchecker queue status --json
+    Status {
+        /// Output as JSON (queue-json.v1)
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Run queued jobs until the queue is empty
+    ///
+    /// EXAMPLES:
+    ///   xchecker queue run
+    ///   xchecker queue run --watch --poll-interval 10
+    Run {
+        /// Preview phases without calling the LLM
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Keep running and wait for new jobs when the queue is empty
+        #[arg(long)]
+        watch: bool,
+
+        /// Seconds between checks for new jobs with --watch
+        #[arg(long, default_value = "5", requires = "watch")]
+        poll_interval: u64,
+    },
+}
+
+/// Git hook subcommands
+#[derive(Subcommand)]
+pub enum HooksCommands {
+    /// Write hooks into the current repository's hooks directory
+    ///
+    /// Honours `core.hooksPath`. Without `--hook`, installs the hooks listed
+    /// in `[git_hooks] install` (default: pre-commit).
+    ///
+    /// EXAMPLES:
+    ///   xchecker hooks install
+    ///   xchecker hooks install --hook pre-push --force
+    Install {
+        /// Hook to install (repeatable)
+        #[arg(long, value_parser = ["pre-commit", "pre-push"])]
+        hook: Vec<String>,
+
+        /// Replace existing hooks not written by xchecker
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Run the checks for a hook (invoked by installed hooks)
+    ///
+    /// Exits 8 when a secret is found and 1 when an artifact no longer
+    /// matches its receipt.
+    ///
+    /// EXAMPLES:
+    ///   xchecker hooks run pre-commit
+    Run {
+        /// Hook being run
+        #[arg(value_parser = ["pre-commit", "pre-push"])]
+        hook: String,
+    },
+}
+
 /// Build the CLI command structure without parsing arguments
 /// This is used for introspection in tests and documentation validation
 #[must_use]
@@ -712,6 +1499,18 @@ pub fn build_cli() -> clap::Command {
 pub fn run() -> Result<(), ExitCode> {
     let cli = Cli::parse();
 
+    // One verbosity for every subsystem: diagnostics go through tracing on stderr
+    let verbosity = Verbosity::from_flags(cli.quiet, cli.verbose);
+    let verbose = verbosity.is_verbose();
+    // Fails only if a subscriber is already installed (e.g. by an embedding test)
+    let _ = init_tracing(verbosity);
+
+    // Point every path helper (spec roots, locks, cache) at the override home
+    if let Some(workspace) = &cli.workspace {
+        // SAFETY: set once before the async runtime or any other thread starts
+        unsafe { std::env::set_var("XCHECKER_HOME", workspace) };
+    }
+
     // Build CLI args for configuration system (wired through build_cli)
     let cli_args = CliArgs {
         config_path: cli.config.clone(),
@@ -720,7 +1519,7 @@ pub fn run() -> Result<(), ExitCode> {
         packet_max_bytes: cli.packet_max_bytes,
         packet_max_lines: cli.packet_max_lines,
         output_format: cli.output_format.clone(),
-        verbose: Some(cli.verbose),
+        verbose: Some(verbose),
         runner_mode: cli.runner_mode.clone(),
         runner_distro: cli.runner_distro.clone(),
         claude_path: cli.claude_path.clone(),
@@ -729,11 +1528,13 @@ pub fn run() -> Result<(), ExitCode> {
         dangerously_skip_permissions: cli.dangerously_skip_permissions,
         ignore_secret_pattern: cli.ignore_secret_pattern.clone(),
         extra_secret_pattern: cli.extra_secret_pattern.clone(),
+        source_root: cli.source_root.clone(),
         phase_timeout: cli.phase_timeout,
         stdout_cap_bytes: cli.stdout_cap_bytes,
         stderr_cap_bytes: cli.stderr_cap_bytes,
         lock_ttl_seconds: cli.lock_ttl_seconds,
         debug_packet: cli.debug_packet,
+        raw_context: cli.raw_context,
         allow_links: cli.allow_links,
         strict_validation: if cli.strict_validation {
             Some(true)
@@ -749,6 +1550,11 @@ pub fn run() -> Result<(), ExitCode> {
         llm_gemini_binary: cli.llm_gemini_binary.clone(),
         llm_gemini_default_model: cli.llm_gemini_default_model.clone(),
         execution_strategy: cli.execution_strategy.clone(),
+        inject_failure: cli.inject_failure.clone(),
+        idempotency_key: cli.idempotency_key.clone(),
+        dump_prompt: cli.dump_prompt,
+        prompt_only: cli.prompt_only,
+        enable_features: cli.enable_feature.clone(),
     };
 
     // Discover and load configuration
@@ -776,11 +1582,36 @@ pub fn run() -> Result<(), ExitCode> {
         }
     };
 
+    // Spinners and progress bars draw on stderr when it is an interactive terminal
+    // (never under --quiet)
+    console::set_progress_enabled(!verbosity.is_quiet());
+
+    // Every command's atomic writes follow `[defaults] fsync` (validated above)
+    atomic_write::set_fsync_policy(
+        config
+            .defaults
+            .fsync
+            .as_deref()
+            .and_then(|policy| policy.parse().ok())
+            .unwrap_or_default(),
+    );
+    // Artifacts and receipts over `[defaults] compress_threshold_bytes` are stored as `.zst`
+    compression::set_compression_policy(compression::CompressionPolicy {
+        threshold_bytes: config.defaults.compress_threshold_bytes,
+        level: config
+            .defaults
+            .compress_level
+            .unwrap_or(compression::DEFAULT_LEVEL),
+    });
+
     // Create tokio runtime for async operations
     let rt = match tokio::runtime::Runtime::new() {
         Ok(rt) => rt,
         Err(e) => {
-            eprintln!("✗ Failed to create async runtime: {e}");
+            eprintln!(
+                "{} Failed to create async runtime: {e}",
+                console::cross_mark(Stream::Stderr)
+            );
             return Err(ExitCode::INTERNAL);
         }
     };
@@ -788,16 +1619,37 @@ pub fn run() -> Result<(), ExitCode> {
     // Determine operation context for better error reporting before moving cli.command
     let operation = match &cli.command {
         Commands::Spec { .. } => "spec",
+        Commands::Ci { .. } => "ci",
         Commands::Status { .. } => "status",
+        Commands::List { .. } => "list",
         Commands::Resume { .. } => "resume",
         Commands::Clean { .. } => "clean",
+        Commands::Bulk { .. } => "bulk",
+        Commands::Experiment { .. } => "experiment",
         Commands::Benchmark { .. } => "benchmark",
+        Commands::Bench { .. } => "bench",
         Commands::Test { .. } => "test",
         Commands::Doctor { .. } => "doctor",
+        Commands::SelfUpdate { .. } => "self-update",
         Commands::Init { .. } => "init",
         Commands::Project(_) => "project",
         Commands::Gate { .. } => "gate",
         Commands::Template(_) => "template",
+        Commands::Examples(_) => "examples",
+        Commands::Schema(_) => "schema",
+        Commands::ChangelogFragment { .. } => "changelog-fragment",
+        Commands::Badge { .. } => "badge",
+        Commands::ExportSite { .. } => "export-site",
+        Commands::ExportFindings { .. } => "export-findings",
+        Commands::SyncTasks { .. } => "sync-tasks",
+        Commands::Explain { .. } => "explain",
+        Commands::MigrateReceipts { .. } => "migrate-receipts",
+        Commands::MigrateArtifacts { .. } => "migrate-artifacts",
+        Commands::DiffReceipts { .. } => "diff-receipts",
+        Commands::Config(_) => "config",
+        Commands::Hooks(_) => "hooks",
+        Commands::Models(_) => "models",
+        Commands::Queue(_) => "queue",
     };
 
     let result = rt.block_on(async {
@@ -807,10 +1659,12 @@ pub fn run() -> Result<(), ExitCode> {
                 source,
                 gh,
                 repo,
+                openapi,
                 dry_run,
                 force,
                 apply_fixups,
                 strict_lock,
+                yes,
                 json,
             } => {
                 // Sanitize spec ID (R5.7)
@@ -826,13 +1680,21 @@ pub fn run() -> Result<(), ExitCode> {
                     return execute_spec_json_command(&sanitized_id, &config);
                 }
 
+                let destructive =
+                    destructive_flags(&[("--force", force), ("--apply-fixups", apply_fixups)]);
+                if !confirm_spec_ownership(&sanitized_id, &destructive, yes)? {
+                    println!("Spec run cancelled.");
+                    return Ok(());
+                }
+
                 execute_spec_command(
                     &sanitized_id,
                     &source,
                     gh.as_deref(),
                     repo.as_deref(),
+                    openapi.as_deref(),
                     dry_run,
-                    cli.verbose,
+                    verbose,
                     force,
                     apply_fixups,
                     strict_lock,
@@ -842,7 +1704,21 @@ pub fn run() -> Result<(), ExitCode> {
                 )
                 .await
             }
-            Commands::Status { id, json } => {
+            Commands::Ci {
+                id,
+                source,
+                gh,
+                repo,
+                openapi,
+                through,
+                dry_run,
+                allow_warnings,
+                require_done,
+                estimate,
+                max_estimated_cost,
+                summary_fd,
+                summary_file,
+            } => {
                 // Sanitize spec ID (R5.7)
                 let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
                     XCheckerError::Config(ConfigError::InvalidValue {
@@ -850,15 +1726,93 @@ pub fn run() -> Result<(), ExitCode> {
                         value: format!("{e}"),
                     })
                 })?;
-                execute_status_command(&sanitized_id, json, &config)
+                let through = parse_phase_name(&through)?;
+                let summary_target = match (summary_fd, summary_file) {
+                    (Some(fd), _) => Some(ci_summary_fd_path(fd)?),
+                    (None, path) => path,
+                };
+
+                execute_ci_command(
+                    &sanitized_id,
+                    &source,
+                    gh.as_deref(),
+                    repo.as_deref(),
+                    openapi.as_deref(),
+                    through,
+                    dry_run,
+                    allow_warnings,
+                    require_done,
+                    CostPreflight {
+                        estimate_only: estimate,
+                        max_cost: max_estimated_cost,
+                    },
+                    summary_target.as_deref(),
+                    verbose,
+                    &config,
+                    &cli_args,
+                    &redactor,
+                )
+                .await
+            }
+            Commands::Status {
+                id,
+                filters,
+                json,
+                ndjson,
+                clean_partials,
+                yes,
+                ..
+            } => {
+                // clap guarantees a spec ID unless --all is given
+                let Some(id) = id else {
+                    if ndjson {
+                        return execute_status_all_ndjson_command(&filters, &config);
+                    }
+                    return execute_status_all_command(&filters, json, &config);
+                };
+                // Sanitize spec ID (R5.7)
+                let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
+                    XCheckerError::Config(ConfigError::InvalidValue {
+                        key: "spec_id".to_string(),
+                        value: format!("{e}"),
+                    })
+                })?;
+                if clean_partials {
+                    if confirm_spec_ownership(&sanitized_id, &["clean-partials".to_string()], yes)?
+                    {
+                        execute_clean_partials_command(&sanitized_id, json)?;
+                    } else {
+                        eprintln!("Clean-partials cancelled.");
+                    }
+                }
+                execute_status_command(&sanitized_id, json, &config)
+            }
+            Commands::List {
+                discover,
+                register,
+                json,
+            } => {
+                if discover {
+                    execute_list_discover_command(register, json)
+                } else {
+                    execute_status_all_command(&[], json, &config)
+                }
             }
             Commands::Resume {
                 id,
                 phase,
+                force_phase,
+                skip_phase,
                 dry_run,
                 force,
                 apply_fixups,
+                diff_algorithm,
+                word_diff,
+                differential,
+                estimate,
+                max_estimated_cost,
                 strict_lock,
+                yes,
                 json,
             } => {
                 // Sanitize spec ID (R5.7)
@@ -869,18 +1823,57 @@ pub fn run() -> Result<(), ExitCode> {
                     })
                 })?;
 
+                // Exactly one of --phase / --force-phase is present (enforced by clap)
+                let (phase, forced) = match (phase, force_phase) {
+                    (Some(phase), _) => (phase, false),
+                    (None, Some(phase)) => (phase, true),
+                    (None, None) => unreachable!("clap requires --phase or --force-phase"),
+                };
+
                 // If --json flag is set, output resume info as JSON and return
                 if json {
-                    return execute_resume_json_command(&sanitized_id, &phase, &config);
+                    return execute_resume_json_command(
+                        &sanitized_id,
+                        &phase,
+                        forced,
+                        &skip_phase,
+                        &config,
+                    );
+                }
+
+                let destructive = destructive_flags(&[
+                    ("--force", force),
+                    ("--force-phase", forced),
+                    ("--apply-fixups", apply_fixups),
+                ]);
+                if !confirm_spec_ownership(&sanitized_id, &destructive, yes)? {
+                    println!("Resume cancelled.");
+                    return Ok(());
                 }
 
                 execute_resume_command(
                     &sanitized_id,
                     &phase,
+                    forced,
+                    &skip_phase,
                     dry_run,
-                    cli.verbose,
+                    verbose,
                     force,
                     apply_fixups,
+                    FixupPreviewStyle {
+                        algorithm: diff_algorithm.parse().map_err(|e: String| {
+                            XCheckerError::Config(ConfigError::InvalidValue {
+                                key: "diff_algorithm".to_string(),
+                                value: e,
+                            })
+                        })?,
+                        word_level: word_diff,
+                    },
+                    differential,
+                    CostPreflight {
+                        estimate_only: estimate,
+                        max_cost: max_estimated_cost,
+                    },
                     strict_lock,
                     &config,
                     &cli_args,
@@ -888,7 +1881,12 @@ pub fn run() -> Result<(), ExitCode> {
                 )
                 .await
             }
-            Commands::Clean { id, hard, force } => {
+            Commands::Clean {
+                id,
+                hard,
+                force,
+                yes,
+            } => {
                 // Sanitize spec ID (R5.7)
                 let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
                     XCheckerError::Config(ConfigError::InvalidValue {
@@ -896,8 +1894,72 @@ pub fn run() -> Result<(), ExitCode> {
                         value: format!("{e}"),
                     })
                 })?;
+                if !confirm_spec_ownership(&sanitized_id, &["clean".to_string()], yes)? {
+                    println!("Clean cancelled.");
+                    return Ok(());
+                }
                 execute_clean_command(&sanitized_id, hard, force, &config)
             }
+            Commands::Bulk {
+                filters,
+                yes,
+                dry_run,
+                force,
+                json,
+                estimate,
+                max_estimated_cost,
+                operation,
+            } => {
+                let operation = BulkOperation::parse(&operation)?;
+                execute_bulk_command(
+                    &filters,
+                    &operation,
+                    yes,
+                    dry_run,
+                    force,
+                    json,
+                    CostPreflight {
+                        estimate_only: estimate,
+                        max_cost: max_estimated_cost,
+                    },
+                    verbose,
+                    &config,
+                    &cli_args,
+                    &redactor,
+                )
+                .await
+            }
+            Commands::Experiment {
+                id,
+                models,
+                phase,
+                dry_run,
+                force,
+                yes,
+                json,
+            } => {
+                // Sanitize spec ID (R5.7)
+                let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
+                    XCheckerError::Config(ConfigError::InvalidValue {
+                        key: "spec_id".to_string(),
+                        value: format!("{e}"),
+                    })
+                })?;
+                execute_experiment_command(
+                    &sanitized_id,
+                    &models,
+                    parse_phase_name(&phase)?,
+                    dry_run,
+                    force,
+                    yes,
+                    json,
+                    verbose,
+                    &config,
+                    &cli_args,
+                    &redactor,
+                )
+                .await
+            }
             Commands::Benchmark {
                 file_count,
                 file_size,
@@ -916,14 +1978,39 @@ pub fn run() -> Result<(), ExitCode> {
                 max_packetization_ms,
                 max_rss_mb,
                 max_commit_mb,
-                cli.verbose,
+                verbose,
             ),
+            Commands::Bench {
+                dir,
+                samples,
+                warmup,
+            } => execute_bench_command(&dir, samples, warmup),
             Commands::Test { components, smoke } => {
-                execute_test_command(components, smoke, cli.verbose)
+                execute_test_command(components, smoke, verbose)
             }
-            Commands::Doctor { json, strict_exit } => {
-                execute_doctor_command(json, strict_exit, &config)
+            Commands::Doctor {
+                json,
+                strict_exit,
+                measure,
+                samples,
+                require_no_telemetry,
+            } => {
+                let samples = measure.then_some(usize::from(samples));
+                execute_doctor_command(
+                    json,
+                    strict_exit,
+                    samples,
+                    require_no_telemetry,
+                    verbose,
+                    &config,
+                )
+                .await
             }
+            Commands::SelfUpdate {
+                channel,
+                check,
+                force,
+            } => execute_self_update_command(&channel, check, force).await,
             Commands::Init { id, create_lock } => {
                 // Sanitize spec ID (R5.7)
                 let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
@@ -960,9 +2047,168 @@ pub fn run() -> Result<(), ExitCode> {
                 )
             }
             Commands::Template(template_cmd) => execute_template_command(template_cmd),
+            Commands::Examples(examples_cmd) => execute_examples_command(examples_cmd),
+            Commands::Schema(schema_cmd) => execute_schema_command(schema_cmd),
+            Commands::Config(ConfigCommands::Diff { json }) => {
+                execute_config_diff_command(&config, json)
+            }
+            Commands::Models(ModelsCommands::List { provider, json }) => {
+                execute_models_list_command(&config, provider.as_deref(), json)
+            }
+            Commands::Hooks(HooksCommands::Install { hook, force }) => {
+                execute_hooks_install_command(&config, &hook, force)
+            }
+            Commands::Hooks(HooksCommands::Run { hook }) => {
+                execute_hooks_run_command(&config, &hook, redactor.as_ref())
+            }
+            Commands::Queue(QueueCommands::Add {
+                id,
+                phase,
+                priority,
+            }) => {
+                // Sanitize spec ID (R5.7)
+                let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
+                    XCheckerError::Config(ConfigError::InvalidValue {
+                        key: "spec_id".to_string(),
+                        value: format!("{e}"),
+                    })
+                })?;
+                execute_queue_add_command(&config, &sanitized_id, &phase, &priority)
+            }
+            Commands::Queue(QueueCommands::Status { json }) => {
+                execute_queue_status_command(&config, json)
+            }
+            Commands::Queue(QueueCommands::Run {
+                dry_run,
+                watch,
+                poll_interval,
+            }) => {
+                execute_queue_run_command(
+                    dry_run,
+                    watch.then(|| Duration::from_secs(poll_interval.max(1))),
+                    verbose,
+                    &config,
+                    &cli_args,
+                    &redactor,
+                )
+                .await
+            }
+            Commands::ChangelogFragment { id } => {
+                // Sanitize spec ID (R5.7)
+                let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
+                    XCheckerError::Config(ConfigError::InvalidValue {
+                        key: "spec_id".to_string(),
+                        value: format!("{e}"),
+                    })
+                })?;
+                execute_changelog_fragment_command(&sanitized_id)
+            }
+            Commands::ExportFindings {
+                id,
+                to,
+                repo,
+                labels,
+                dry_run,
+            } => {
+                // Sanitize spec ID (R5.7)
+                let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
+                    XCheckerError::Config(ConfigError::InvalidValue {
+                        key: "spec_id".to_string(),
+                        value: format!("{e}"),
+                    })
+                })?;
+                execute_export_findings_command(
+                    &sanitized_id,
+                    &to,
+                    repo.as_deref(),
+                    &labels,
+                    dry_run,
+                )
+                .await
+            }
+            Commands::SyncTasks {
+                id,
+                action,
+                tracker,
+                repo,
+                project,
+                dry_run,
+            } => {
+                // Sanitize spec ID (R5.7)
+                let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
+                    XCheckerError::Config(ConfigError::InvalidValue {
+                        key: "spec_id".to_string(),
+                        value: format!("{e}"),
+                    })
+                })?;
+                execute_sync_tasks_command(
+                    &sanitized_id,
+                    &action,
+                    tracker.as_deref(),
+                    repo.as_deref(),
+                    project.as_deref(),
+                    dry_run,
+                )
+                .await
+            }
+            Commands::Badge { id, out, markdown } => {
+                // Sanitize spec ID (R5.7)
+                let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
+                    XCheckerError::Config(ConfigError::InvalidValue {
+                        key: "spec_id".to_string(),
+                        value: format!("{e}"),
+                    })
+                })?;
+                execute_badge_command(&sanitized_id, out.as_deref(), markdown)
+            }
+            Commands::ExportSite { id, out } => {
+                // Sanitize spec ID (R5.7)
+                let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
+                    XCheckerError::Config(ConfigError::InvalidValue {
+                        key: "spec_id".to_string(),
+                        value: format!("{e}"),
+                    })
+                })?;
+                execute_export_site_command(&sanitized_id, &out, &config)
+            }
+            Commands::DiffReceipts { a, b, json } => execute_diff_receipts_command(&a, &b, json),
+            Commands::MigrateReceipts { id, to: _, dry_run } => {
+                // Sanitize spec ID (R5.7)
+                let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
+                    XCheckerError::Config(ConfigError::InvalidValue {
+                        key: "spec_id".to_string(),
+                        value: format!("{e}"),
+                    })
+                })?;
+                execute_migrate_receipts_command(&sanitized_id, dry_run)
+            }
+            Commands::MigrateArtifacts { id, dry_run } => {
+                // Sanitize spec ID (R5.7)
+                let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
+                    XCheckerError::Config(ConfigError::InvalidValue {
+                        key: "spec_id".to_string(),
+                        value: format!("{e}"),
+                    })
+                })?;
+                execute_migrate_artifacts_command(&sanitized_id, dry_run)
+            }
+            Commands::Explain { id, phase, json } => {
+                // Sanitize spec ID (R5.7)
+                let sanitized_id = sanitize_spec_id(&id).map_err(|e| {
+                    XCheckerError::Config(ConfigError::InvalidValue {
+                        key: "spec_id".to_string(),
+                        value: format!("{e}"),
+                    })
+                })?;
+                let phase = phase.as_deref().map(parse_phase_name).transpose()?;
+                execute_explain_command(&sanitized_id, phase, json)
+            }
         }
     });
 
+    // Writes deferred by `fsync = "batch"` reach stable storage before exit
+    let result = result.and(atomic_write::flush_pending_writes().map(drop));
+
     // Handle errors with structured reporting (R1.3, R6.4, R6.8, R6.9)
     // cli::run() handles ALL output including errors (FR-CLI-3, FR-CLI-6)
     // Error messages are displayed via contextual reporting which extends display_for_user()
@@ -979,12 +2225,25 @@ pub fn run() -> Result<(), ExitCode> {
             );
             eprintln!("{contextual_report}");
 
+            // Same remediation table that failed receipts record in suggested_actions
+            let provider = config.llm.provider.as_deref().unwrap_or("claude-cli");
+            let actions = remediation::suggested_actions_for_error(xchecker_error, Some(provider));
+            if !actions.is_empty() {
+                eprintln!("  Suggested actions:");
+                for (i, action) in actions.iter().enumerate() {
+                    eprintln!("    {}. {}", i + 1, redactor.redact_string(action));
+                }
+            }
+
             // Return the appropriate exit code - main.rs will call std::process::exit()
             return Err(xchecker_error.to_exit_code());
         } else {
             // Fallback for other error types with enhanced context
             let redacted_error = redactor.redact_string(&error.to_string());
-            eprintln!("✗ Unexpected error: {redacted_error}");
+            eprintln!(
+                "{} Unexpected error: {redacted_error}",
+                console::cross_mark(Stream::Stderr)
+            );
 
             // Provide enhanced context and suggestions for common anyhow errors
             if let Some(suggestions) = enhance_error_context(&error) {
@@ -1007,6 +2266,21 @@ pub fn run() -> Result<(), ExitCode> {
     Ok(())
 }
 
+/// Report where `--prompt-only` wrote the prompt; returns true if the run stopped there.
+fn report_prompt_only(cli_args: &CliArgs, result: &ExecutionResult) -> bool {
+    if !cli_args.prompt_only || !result.success || result.receipt_path.is_some() {
+        return false;
+    }
+    for path in &result.artifact_paths {
+        println!(
+            "Prompt for {} phase written to {} (provider not called)",
+            result.phase.as_str(),
+            path.display()
+        );
+    }
+    true
+}
+
 /// Execute the spec generation command
 #[allow(clippy::too_many_arguments)]
 async fn execute_spec_command(
@@ -1014,6 +2288,7 @@ async fn execute_spec_command(
     source_type: &str,
     gh_repo: Option<&str>,
     fs_repo: Option<&str>,
+    openapi_path: Option<&str>,
     dry_run: bool,
     verbose: bool,
     force: bool,
@@ -1032,41 +2307,189 @@ async fn execute_spec_command(
         logger.verbose("Running in dry-run mode (no Claude calls will be made)");
     }
 
-    // Resolve source input (R6.4)
-    logger.start_timing("source_resolution");
-    let source_content = match source_type {
-        "gh" => {
-            let gh_repo = gh_repo.ok_or_else(|| {
-                XCheckerError::Config(ConfigError::MissingRequired("--gh owner/repo".to_string()))
-            })?;
+    let problem_statement = resolve_problem_statement(
+        spec_id,
+        source_type,
+        gh_repo,
+        fs_repo,
+        openapi_path,
+        &config.security.source_allowlist(),
+        &mut logger,
+    )?;
 
-            let parts: Vec<&str> = gh_repo.split('/').collect();
-            if parts.len() != 2 {
-                return Err(XCheckerError::Config(ConfigError::InvalidValue {
-                    key: "gh".to_string(),
-                    value: gh_repo.to_string(),
-                })
-                .into());
-            }
+    // Check for lockfile drift (R10.2, R10.4)
+    let model_full_name = config.defaults.model.as_deref().unwrap_or("haiku");
+    let claude_cli_version = detect_claude_cli_version().unwrap_or_else(|_| "unknown".to_string());
+    let _lock_drift = check_lockfile_drift(
+        spec_id,
+        strict_lock,
+        model_full_name,
+        &claude_cli_version,
+        config,
+    )?;
 
-            SourceResolver::resolve_github(parts[0], parts[1], spec_id).map_err(|_e| {
-                // Convert SourceError to XCheckerError for consistent reporting (R6.4)
-                XCheckerError::Source(crate::error::SourceError::GitHubRepoNotFound {
-                    owner: parts[0].to_string(),
-                    repo: parts[1].to_string(),
-                })
-            })?
-        }
-        "fs" => {
-            let fs_repo = fs_repo.ok_or_else(|| {
-                XCheckerError::Config(ConfigError::MissingRequired("--repo <path>".to_string()))
-            })?;
+    // Configure execution using shared helper, passing problem statement for prompt construction
+    let orchestrator_config = build_orchestrator_config(
+        dry_run,
+        verbose,
+        apply_fixups,
+        config,
+        cli_args,
+        Some(&problem_statement),
+        redactor.clone(),
+    );
 
-            let path = PathBuf::from(fs_repo);
-            SourceResolver::resolve_filesystem(&path).map_err(|e| {
-                // Enhanced error reporting for filesystem source resolution (R6.4)
-                if path.exists() {
-                    if path.is_dir() {
+    // Create orchestrator handle (this will acquire the file lock)
+    logger.start_timing("orchestrator_setup");
+    let mut handle = OrchestratorHandle::with_config_and_force(spec_id, orchestrator_config, force)
+        .with_context(|| format!("Failed to create orchestrator for spec: {spec_id}"))?;
+    logger.end_timing("orchestrator_setup");
+
+    logger.verbose("Executing Requirements phase...");
+
+    // Execute Requirements phase
+    logger.start_timing("requirements_phase");
+    let result = handle
+        .run_phase(PhaseId::Requirements)
+        .await
+        .with_context(|| "Failed to execute Requirements phase")?;
+    logger.end_timing("requirements_phase");
+
+    if report_prompt_only(cli_args, &result) {
+        return Ok(());
+    }
+
+    // Report results
+    logger.end_timing("total_execution");
+
+    if result.success {
+        println!(
+            "{} Requirements phase completed successfully",
+            console::check_mark(Stream::Stdout)
+        );
+
+        logger.verbose(&format!("Phase: {}", result.phase.as_str()));
+        logger.verbose(&format!("Exit code: {}", result.exit_code));
+        logger.verbose(&format!(
+            "Artifacts created: {}",
+            result.artifact_paths.len()
+        ));
+
+        for (i, path) in result.artifact_paths.iter().enumerate() {
+            logger.verbose(&format!("  {}: {}", i + 1, path.display()));
+        }
+
+        if let Some(receipt_path) = &result.receipt_path {
+            logger.verbose(&format!("Receipt: {}", receipt_path.display()));
+        }
+
+        // Print performance summary if verbose (R7.5, NFR5)
+        logger.print_performance_summary();
+
+        println!("\nReview the generated requirements in .xchecker/specs/{spec_id}/artifacts/");
+        print!("{}", next_steps_block(spec_id, &handle));
+    } else {
+        // Create structured error for phase failure (R1.3, R4.3)
+        let phase_error = PhaseError::ExecutionFailed {
+            phase: result.phase.as_str().to_string(),
+            code: result.exit_code,
+        };
+        let xchecker_error = XCheckerError::Phase(phase_error);
+
+        // Report with full context and suggestions
+        let report = ErrorReport::new(&xchecker_error);
+        eprintln!("{}", report.format_with_redactor(redactor.as_ref()));
+
+        // Enhanced error reporting for phase failures (R1.3, R4.3)
+        if let Some(error_msg) = &result.error {
+            let redacted_error_msg = redactor.redact_string(error_msg);
+            eprintln!("\n  Phase failure details: {redacted_error_msg}");
+
+            // Try to parse the error for enhanced reporting
+            if error_msg.contains("ExecutionFailedWithStderr") {
+                eprintln!("  ↳ Claude CLI produced error output (see receipt for full stderr)");
+            } else if error_msg.contains("PartialOutputSaved") {
+                eprintln!("  ↳ Partial output was saved for debugging");
+            }
+        }
+
+        // Show partial artifacts location (R4.3)
+        eprintln!("\n  Debugging information:");
+        if !result.artifact_paths.is_empty() {
+            eprintln!("    Partial artifacts:");
+            for path in &result.artifact_paths {
+                eprintln!("      - {}", path.display());
+            }
+        }
+        eprintln!("    Spec directory: .xchecker/specs/{spec_id}/");
+
+        if let Some(receipt_path) = &result.receipt_path {
+            eprintln!("    Execution receipt: {}", receipt_path.display());
+            eprintln!("      ↳ Contains stderr output, warnings, and execution metadata");
+        }
+
+        // Provide recovery suggestions
+        eprintln!("\n  Recovery options:");
+        eprintln!("    - Review partial outputs and receipt for error details");
+        eprintln!("    - Fix any configuration or connectivity issues");
+        eprintln!("    - Test configuration with: xchecker spec {spec_id} --dry-run");
+        eprint!("{}", next_steps_block(spec_id, &handle));
+
+        std::process::exit(result.exit_code);
+    }
+
+    Ok(())
+}
+
+/// Resolve the spec source and persist it as the spec's problem statement
+///
+/// Returns the problem statement text used for prompt construction.
+fn resolve_problem_statement(
+    spec_id: &str,
+    source_type: &str,
+    gh_repo: Option<&str>,
+    fs_repo: Option<&str>,
+    openapi_path: Option<&str>,
+    allowlist: &crate::source::SourceAllowlist,
+    logger: &mut Logger,
+) -> Result<String> {
+    // Resolve source input (R6.4)
+    logger.start_timing("source_resolution");
+    let source_content = match source_type {
+        "gh" => {
+            let gh_repo = gh_repo.ok_or_else(|| {
+                XCheckerError::Config(ConfigError::MissingRequired("--gh owner/repo".to_string()))
+            })?;
+
+            let parts: Vec<&str> = gh_repo.split('/').collect();
+            if parts.len() != 2 {
+                return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                    key: "gh".to_string(),
+                    value: gh_repo.to_string(),
+                })
+                .into());
+            }
+
+            SourceResolver::resolve_github(parts[0], parts[1], spec_id).map_err(|_e| {
+                // Convert SourceError to XCheckerError for consistent reporting (R6.4)
+                XCheckerError::Source(crate::error::SourceError::GitHubRepoNotFound {
+                    owner: parts[0].to_string(),
+                    repo: parts[1].to_string(),
+                })
+            })?
+        }
+        "fs" => {
+            let fs_repo = fs_repo.ok_or_else(|| {
+                XCheckerError::Config(ConfigError::MissingRequired("--repo <path>".to_string()))
+            })?;
+
+            let path = PathBuf::from(fs_repo);
+            SourceResolver::resolve_filesystem(&path, allowlist).map_err(|e| {
+                // Enhanced error reporting for filesystem source resolution (R6.4)
+                if matches!(e, crate::error::SourceError::FileSystemNotTrusted { .. }) {
+                    XCheckerError::Source(e)
+                } else if path.exists() {
+                    if path.is_dir() {
                         // Check if it's a permission issue or other access problem
                         match std::fs::read_dir(&path) {
                             Err(io_err)
@@ -1102,6 +2525,29 @@ async fn execute_spec_command(
                 }
             })?
         }
+        "openapi" => {
+            let openapi_path = openapi_path.ok_or_else(|| {
+                XCheckerError::Config(ConfigError::MissingRequired("--openapi <path>".to_string()))
+            })?;
+
+            let path = PathBuf::from(openapi_path);
+            let resolved =
+                SourceResolver::resolve_openapi(&path, allowlist).map_err(XCheckerError::Source)?;
+            logger.verbose(&format!(
+                "Condensed {} document ({} bytes) to {} operations and {} schemas",
+                resolved.metadata.get("type").map_or("API", String::as_str),
+                resolved
+                    .metadata
+                    .get("original_bytes")
+                    .map_or("?", String::as_str),
+                resolved
+                    .metadata
+                    .get("operations")
+                    .map_or("0", String::as_str),
+                resolved.metadata.get("schemas").map_or("0", String::as_str),
+            ));
+            resolved
+        }
         "stdin" => {
             SourceResolver::resolve_stdin().map_err(|e| {
                 // Enhanced error reporting for stdin source resolution (R6.4)
@@ -1122,7 +2568,7 @@ async fn execute_spec_command(
         _ => {
             return Err(XCheckerError::Config(ConfigError::InvalidValue {
                 key: "source".to_string(),
-                value: format!("Unknown source type '{source_type}'. Valid options: 'gh' (GitHub), 'fs' (filesystem), 'stdin' (standard input)"),
+                value: format!("Unknown source type '{source_type}'. Valid options: 'gh' (GitHub), 'fs' (filesystem), 'stdin' (standard input), 'openapi' (OpenAPI / JSON Schema)"),
             }).into());
         }
     };
@@ -1148,131 +2594,270 @@ async fn execute_spec_command(
 
     logger.verbose(&format!("Problem statement written to: {}", problem_path));
 
-    // Check for lockfile drift (R10.2, R10.4)
-    let model_full_name = config.defaults.model.as_deref().unwrap_or("haiku");
-    let claude_cli_version = detect_claude_cli_version().unwrap_or_else(|_| "unknown".to_string());
-    let _lock_drift = check_lockfile_drift(
+    Ok(problem_statement)
+}
+
+/// Bundled receipt schema that `xchecker ci` validates emitted receipts against
+const RECEIPT_SCHEMA_V1: &str = include_str!("../schemas/receipt.v1.json");
+
+/// Phases in pipeline order
+const PIPELINE_PHASES: [PhaseId; 6] = [
+    PhaseId::Requirements,
+    PhaseId::Design,
+    PhaseId::Tasks,
+    PhaseId::Review,
+    PhaseId::Fixup,
+    PhaseId::Final,
+];
+
+/// Per-phase entry in the `ci-summary.v1` output
+#[derive(Debug, serde::Serialize)]
+struct CiPhaseSummary {
+    phase: String,
+    success: bool,
+    exit_code: i32,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
+}
+
+/// Path that writes to an already-open file descriptor
+fn ci_summary_fd_path(fd: u32) -> Result<PathBuf> {
+    if cfg!(unix) {
+        Ok(PathBuf::from(format!("/dev/fd/{fd}")))
+    } else {
+        Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "summary-fd".to_string(),
+            value: "--summary-fd is only supported on Unix; use --summary-file".to_string(),
+        })
+        .into())
+    }
+}
+
+/// Execute the ci command
+#[allow(clippy::too_many_arguments)]
+async fn execute_ci_command(
+    spec_id: &str,
+    source_type: &str,
+    gh_repo: Option<&str>,
+    fs_repo: Option<&str>,
+    openapi_path: Option<&str>,
+    through: PhaseId,
+    dry_run: bool,
+    allow_warnings: bool,
+    require_done: bool,
+    preflight: CostPreflight,
+    summary_target: Option<&std::path::Path>,
+    verbose: bool,
+    config: &Config,
+    cli_args: &CliArgs,
+    redactor: &Arc<SecretRedactor>,
+) -> Result<()> {
+    console::disable_color();
+    console::set_progress_enabled(false);
+    let mut logger = Logger::new(verbose);
+
+    let problem_statement = resolve_problem_statement(
         spec_id,
-        strict_lock,
-        model_full_name,
-        &claude_cli_version,
-        config,
+        source_type,
+        gh_repo,
+        fs_repo,
+        openapi_path,
+        &config.security.source_allowlist(),
+        &mut logger,
     )?;
 
-    // Configure execution using shared helper, passing problem statement for prompt construction
-    let orchestrator_config = build_orchestrator_config(
+    // CI always hard-fails on lockfile drift
+    let model_full_name = config.defaults.model.as_deref().unwrap_or("haiku");
+    let claude_cli_version = detect_claude_cli_version().unwrap_or_else(|_| "unknown".to_string());
+    check_lockfile_drift(spec_id, true, model_full_name, &claude_cli_version, config)?;
+
+    let mut orchestrator_config = build_orchestrator_config(
         dry_run,
         verbose,
-        apply_fixups,
+        false,
         config,
         cli_args,
         Some(&problem_statement),
         redactor.clone(),
     );
+    orchestrator_config.strict_validation = true;
 
-    // Create orchestrator handle (this will acquire the file lock)
-    logger.start_timing("orchestrator_setup");
-    let mut handle = OrchestratorHandle::with_config_and_force(spec_id, orchestrator_config, force)
+    let mut handle = OrchestratorHandle::with_config_and_force(spec_id, orchestrator_config, false)
         .with_context(|| format!("Failed to create orchestrator for spec: {spec_id}"))?;
-    logger.end_timing("orchestrator_setup");
 
-    logger.verbose("Executing Requirements phase...");
+    let planned: Vec<PhaseId> = PIPELINE_PHASES
+        .into_iter()
+        .take_while(|phase| phase_order(*phase) <= phase_order(through))
+        .collect();
+    if preflight.is_requested() {
+        let estimate = handle
+            .estimate(&planned)
+            .with_context(|| "Failed to estimate the CI run")?;
+        // CI output is machine-read: the estimate is always JSON
+        preflight.check(&[estimate], true)?;
+        if preflight.estimate_only {
+            return Ok(());
+        }
+    }
 
-    // Execute Requirements phase
-    logger.start_timing("requirements_phase");
-    let result = handle
-        .run_phase(PhaseId::Requirements)
-        .await
-        .with_context(|| "Failed to execute Requirements phase")?;
-    logger.end_timing("requirements_phase");
+    let started_at = chrono::Utc::now();
+    let mut phases = Vec::new();
+    let mut failed_exit_code = None;
+    for phase in planned {
+        let result = handle
+            .run_phase(phase)
+            .await
+            .with_context(|| format!("Failed to execute {} phase", phase.as_str()))?;
+        if result.success {
+            println!("✓ {} phase completed", phase.as_str());
+        } else {
+            let detail = result
+                .error
+                .as_deref()
+                .map(|error| format!(": {}", redactor.redact_string(error)))
+                .unwrap_or_default();
+            eprintln!(
+                "✗ {} phase failed (exit {}){detail}",
+                phase.as_str(),
+                result.exit_code
+            );
+        }
+        phases.push(CiPhaseSummary {
+            phase: phase.as_str().to_string(),
+            success: result.success,
+            exit_code: result.exit_code,
+            warnings: Vec::new(),
+        });
+        if !result.success {
+            failed_exit_code = Some(result.exit_code);
+            break;
+        }
+    }
 
-    // Report results
-    logger.end_timing("total_execution");
+    // Validate and collect warnings from every receipt emitted by this run
+    let receipts: Vec<crate::types::Receipt> = handle
+        .receipt_manager()
+        .list_receipts()
+        .with_context(|| "Failed to list receipts")?
+        .into_iter()
+        .filter(|receipt| receipt.emitted_at >= started_at)
+        .collect();
+    let schema_errors = receipt_schema_errors(&receipts)?;
+    for receipt in &receipts {
+        if let Some(entry) = phases.iter_mut().find(|entry| entry.phase == receipt.phase) {
+            entry.warnings.extend(receipt.warnings.iter().cloned());
+        }
+    }
+    let warning_count: usize = phases.iter().map(|entry| entry.warnings.len()).sum();
 
-    if result.success {
-        println!("✓ Requirements phase completed successfully");
+    // Evaluated over every receipt of the spec, not just this run's
+    let done = if require_done {
+        let all_receipts = handle
+            .receipt_manager()
+            .list_receipts()
+            .with_context(|| "Failed to list receipts")?;
+        Some(crate::done::evaluate(
+            &config.done,
+            handle.artifact_manager().base_path(),
+            &all_receipts,
+        )?)
+    } else {
+        None
+    };
+    let not_done = done.as_ref().is_some_and(|done| !done.done);
+
+    let (exit_code, failure) = match failed_exit_code {
+        Some(code) => (code, Some("phase_failed")),
+        None if !schema_errors.is_empty() => (
+            crate::gate::exit_codes::POLICY_VIOLATION,
+            Some("schema_violation"),
+        ),
+        None if warning_count > 0 && !allow_warnings => {
+            (crate::gate::exit_codes::POLICY_VIOLATION, Some("warnings"))
+        }
+        None if not_done => (crate::gate::exit_codes::POLICY_VIOLATION, Some("not_done")),
+        None => (0, None),
+    };
 
-        logger.verbose(&format!("Phase: {}", result.phase.as_str()));
-        logger.verbose(&format!("Exit code: {}", result.exit_code));
-        logger.verbose(&format!(
-            "Artifacts created: {}",
-            result.artifact_paths.len()
-        ));
+    if let Some(done) = &done {
+        for criterion in &done.unmet {
+            eprintln!("✗ not done: {criterion}");
+        }
+    }
 
-        for (i, path) in result.artifact_paths.iter().enumerate() {
-            logger.verbose(&format!("  {}: {}", i + 1, path.display()));
+    for error in &schema_errors {
+        eprintln!("✗ receipt schema violation: {error}");
+    }
+    if warning_count > 0 {
+        let label = if allow_warnings { "⚠" } else { "✗" };
+        for entry in &phases {
+            for warning in &entry.warnings {
+                eprintln!(
+                    "{label} {} warning: {}",
+                    entry.phase,
+                    redactor.redact_string(warning)
+                );
+            }
         }
+    }
 
-        if let Some(receipt_path) = &result.receipt_path {
-            logger.verbose(&format!("Receipt: {}", receipt_path.display()));
+    let summary = emit_jcs(&serde_json::json!({
+        "schema_version": "ci-summary.v1",
+        "spec_id": spec_id,
+        "ok": exit_code == 0,
+        "exit_code": exit_code,
+        "failure": failure,
+        "through": through.as_str(),
+        "phases": phases,
+        "schema_errors": schema_errors,
+        "done": done,
+    }))?;
+    match summary_target {
+        Some(path) => {
+            let mut file = std::fs::OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(!path.starts_with("/dev/fd"))
+                .open(path)
+                .with_context(|| format!("Failed to open CI summary target: {}", path.display()))?;
+            writeln!(file, "{summary}")
+                .with_context(|| format!("Failed to write CI summary: {}", path.display()))?;
         }
+        None => println!("{summary}"),
+    }
 
-        // Print performance summary if verbose (R7.5, NFR5)
-        logger.print_performance_summary();
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+    Ok(())
+}
 
-        // Show next steps
-        println!("\nNext steps:");
-        println!("  - Review the generated requirements in .xchecker/specs/{spec_id}/artifacts/");
-        println!("  - Check status with: xchecker status {spec_id}");
-        println!("  - Continue to Design phase: xchecker resume {spec_id} --phase design");
-    } else {
-        // Create structured error for phase failure (R1.3, R4.3)
-        let phase_error = PhaseError::ExecutionFailed {
-            phase: result.phase.as_str().to_string(),
-            code: result.exit_code,
-        };
-        let xchecker_error = XCheckerError::Phase(phase_error);
+/// Validate receipts against the bundled receipt schema
+///
+/// Returns one message per violation, prefixed with the receipt's phase.
+fn receipt_schema_errors(receipts: &[crate::types::Receipt]) -> Result<Vec<String>> {
+    let schema: serde_json::Value = serde_json::from_str(RECEIPT_SCHEMA_V1)
+        .context("Bundled receipt schema is invalid JSON")?;
+    let validator = jsonschema::validator_for(&schema)
+        .map_err(|e| anyhow::anyhow!("Bundled receipt schema failed to compile: {e}"))?;
+
+    let mut errors = Vec::new();
+    for receipt in receipts {
+        let value = serde_json::to_value(receipt).context("Failed to serialize receipt")?;
+        errors.extend(
+            validator
+                .iter_errors(&value)
+                .map(|error| format!("{} receipt: {error}", receipt.phase)),
+        );
+    }
+    Ok(errors)
+}
 
-        // Report with full context and suggestions
-        let report = ErrorReport::new(&xchecker_error);
-        eprintln!("{}", report.format_with_redactor(redactor.as_ref()));
-
-        // Enhanced error reporting for phase failures (R1.3, R4.3)
-        if let Some(error_msg) = &result.error {
-            let redacted_error_msg = redactor.redact_string(error_msg);
-            eprintln!("\n  Phase failure details: {redacted_error_msg}");
-
-            // Try to parse the error for enhanced reporting
-            if error_msg.contains("ExecutionFailedWithStderr") {
-                eprintln!("  ↳ Claude CLI produced error output (see receipt for full stderr)");
-            } else if error_msg.contains("PartialOutputSaved") {
-                eprintln!("  ↳ Partial output was saved for debugging");
-            }
-        }
-
-        // Show partial artifacts location (R4.3)
-        eprintln!("\n  Debugging information:");
-        if !result.artifact_paths.is_empty() {
-            eprintln!("    Partial artifacts:");
-            for path in &result.artifact_paths {
-                eprintln!("      - {}", path.display());
-            }
-        }
-        eprintln!("    Spec directory: .xchecker/specs/{spec_id}/");
-
-        if let Some(receipt_path) = &result.receipt_path {
-            eprintln!("    Execution receipt: {}", receipt_path.display());
-            eprintln!("      ↳ Contains stderr output, warnings, and execution metadata");
-        }
-
-        // Provide recovery suggestions
-        eprintln!("\n  Recovery options:");
-        eprintln!("    - Review partial outputs and receipt for error details");
-        eprintln!("    - Fix any configuration or connectivity issues");
-        eprintln!("    - Retry with: xchecker spec {spec_id}");
-        eprintln!("    - Test configuration with: xchecker spec {spec_id} --dry-run");
-
-        std::process::exit(result.exit_code);
-    }
-
-    Ok(())
-}
-
-/// Execute the spec --json command (FR-Claude Code-CLI: Claude Code CLI Surfaces)
-/// Returns JSON with schema_version, spec_id, phases, config_summary
-/// Excludes full artifacts and packet contents per Requirements 4.1.1, 4.1.4
-fn execute_spec_json_command(spec_id: &str, config: &Config) -> Result<()> {
-    use crate::types::{PhaseId, PhaseInfo, SpecConfigSummary, SpecOutput};
+/// Execute the spec --json command (FR-Claude Code-CLI: Claude Code CLI Surfaces)
+/// Returns JSON with schema_version, spec_id, phases, config_summary
+/// Excludes full artifacts and packet contents per Requirements 4.1.1, 4.1.4
+fn execute_spec_json_command(spec_id: &str, config: &Config) -> Result<()> {
+    use crate::types::{PhaseId, PhaseInfo, SpecConfigSummary, SpecOutput};
 
     // Create read-only handle to access managers (no lock needed for JSON output)
     let handle = OrchestratorHandle::readonly(spec_id)
@@ -1380,27 +2965,65 @@ fn emit_status_json(output: &crate::types::StatusJsonOutput) -> Result<String> {
     emit_jcs(output).context("Failed to emit status JSON")
 }
 
+/// Parse a phase name from the command line
+fn parse_phase_name(phase_name: &str) -> Result<PhaseId> {
+    match phase_name.to_lowercase().as_str() {
+        "requirements" => Ok(PhaseId::Requirements),
+        "design" => Ok(PhaseId::Design),
+        "tasks" => Ok(PhaseId::Tasks),
+        "review" => Ok(PhaseId::Review),
+        "fixup" => Ok(PhaseId::Fixup),
+        "final" => Ok(PhaseId::Final),
+        "localize" => Ok(PhaseId::Localize),
+        _ => Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "phase".to_string(),
+            value: format!("Unknown phase '{phase_name}'. Valid phases: requirements, design, tasks, review, fixup, final, localize"),
+        })
+        .into()),
+    }
+}
+
+/// Position of a phase in the standard workflow
+const fn phase_order(phase: PhaseId) -> usize {
+    match phase {
+        PhaseId::Requirements => 0,
+        PhaseId::Design => 1,
+        PhaseId::Tasks => 2,
+        PhaseId::Review => 3,
+        PhaseId::Fixup => 4,
+        PhaseId::Final => 5,
+        PhaseId::Localize => 6,
+    }
+}
+
 /// Execute the resume --json command (FR-Claude Code-CLI: Claude Code CLI Surfaces)
 /// Returns JSON with schema_version, spec_id, phase, current_inputs, next_steps
 /// Excludes full packet and raw artifacts per Requirements 4.1.3, 4.1.4
-fn execute_resume_json_command(spec_id: &str, phase_name: &str, config: &Config) -> Result<()> {
-    use crate::types::{CurrentInputs, PhaseId, ResumeJsonOutput};
+fn execute_resume_json_command(
+    spec_id: &str,
+    phase_name: &str,
+    force_phase: bool,
+    skip_phases: &[String],
+    config: &Config,
+) -> Result<()> {
+    use crate::types::{CurrentInputs, ResumeJsonOutput};
 
     // Parse phase name
-    let phase_id = match phase_name.to_lowercase().as_str() {
-        "requirements" => PhaseId::Requirements,
-        "design" => PhaseId::Design,
-        "tasks" => PhaseId::Tasks,
-        "review" => PhaseId::Review,
-        "fixup" => PhaseId::Fixup,
-        "final" => PhaseId::Final,
-        _ => {
+    let phase_id = parse_phase_name(phase_name)?;
+    let mut skip_ids = Vec::new();
+    for name in skip_phases {
+        let skip_id = parse_phase_name(name)?;
+        if skip_id == phase_id {
             return Err(XCheckerError::Config(ConfigError::InvalidValue {
-                key: "phase".to_string(),
-                value: format!("Unknown phase '{phase_name}'. Valid phases: requirements, design, tasks, review, fixup, final"),
-            }).into());
+                key: "skip_phase".to_string(),
+                value: format!("'{name}' cannot be both skipped and run"),
+            })
+            .into());
         }
-    };
+        skip_ids.push(skip_id);
+    }
+    skip_ids.sort_by_key(|p| phase_order(*p));
+    skip_ids.dedup();
 
     // Create read-only handle to access managers (no lock needed for JSON output)
     let handle = OrchestratorHandle::readonly(spec_id)
@@ -1438,7 +3061,20 @@ fn execute_resume_json_command(spec_id: &str, phase_name: &str, config: &Config)
     };
 
     // Generate next steps hint based on phase and current state
-    let next_steps = generate_next_steps_hint(spec_id, phase_id, &current_inputs, config);
+    let mut next_steps = generate_next_steps_hint(spec_id, phase_id, &current_inputs, config);
+    if force_phase {
+        next_steps.push_str(&format!(
+            " --force-phase re-runs {} even if it or later phases already completed, replacing its artifacts.",
+            phase_id.as_str()
+        ));
+    }
+    let skip_phases: Vec<String> = skip_ids.iter().map(|p| p.as_str().to_string()).collect();
+    if !skip_phases.is_empty() {
+        next_steps.push_str(&format!(
+            " {} will be recorded as skipped.",
+            skip_phases.join(", ")
+        ));
+    }
 
     let output = ResumeJsonOutput {
         schema_version: "resume-json.v1".to_string(),
@@ -1446,6 +3082,8 @@ fn execute_resume_json_command(spec_id: &str, phase_name: &str, config: &Config)
         phase: phase_id.as_str().to_string(),
         current_inputs,
         next_steps,
+        force_phase,
+        skip_phases,
     };
 
     let json_output = emit_resume_json(&output)?;
@@ -1533,6 +3171,9 @@ fn generate_next_steps_hint(
             }
         }
         PhaseId::Final => "Run final phase to complete the spec generation workflow.".to_string(),
+        PhaseId::Localize => {
+            "Run localize phase to translate the final document into [final] languages.".to_string()
+        }
     }
 }
 
@@ -1543,263 +3184,735 @@ fn emit_resume_json(output: &crate::types::ResumeJsonOutput) -> Result<String> {
     emit_jcs(output).context("Failed to emit resume JSON")
 }
 
-/// Execute the status command
-fn execute_status_command(spec_id: &str, json: bool, config: &Config) -> Result<()> {
-    // Create read-only handle to access managers (no lock needed for status)
-    let handle = OrchestratorHandle::readonly(spec_id)
-        .with_context(|| format!("Failed to create orchestrator for spec: {spec_id}"))?;
+/// Build `status --json` output from a single receipt generation
+/// Execute `status --all`: list every spec, optionally filtered by metadata
+fn execute_status_all_command(
+    filters: &[crate::status::metadata::SpecFilter],
+    json: bool,
+    config: &Config,
+) -> Result<()> {
+    use crate::status::index::{CancelToken, default_workers, index_specs};
+    use crate::status::metadata::{SpecFilter, specs_root};
+
+    let mut specs = Vec::new();
+    index_specs(
+        &specs_root(),
+        default_workers(),
+        &CancelToken::new(),
+        |result| {
+            let spec = result.map_err(|failure| failure.error)?;
+            if SpecFilter::matches_all(filters, spec.metadata.as_ref()) {
+                let health = spec_health(&spec.spec_id, config);
+                specs.push((
+                    spec.spec_id,
+                    spec.latest_completed_phase
+                        .map(|phase| phase.as_str().to_string()),
+                    spec.metadata.unwrap_or_default(),
+                    health,
+                ));
+            }
+            Ok(())
+        },
+    )?;
+    specs.sort_by(|a, b| a.0.cmp(&b.0));
 
-    // Check if spec directory exists
-    let base_path = handle.artifact_manager().base_path();
-    if !base_path.exists() {
-        if json {
-            // Return empty JSON for non-existent spec
-            println!("{{}}");
-        } else {
-            println!("Status for spec: {spec_id}");
-            println!("  Status: No spec found");
-            println!("  Directory: {base_path} (does not exist)");
-        }
+    if json {
+        let specs: Vec<serde_json::Value> = specs
+            .iter()
+            .map(|(spec_id, latest_phase, metadata, health)| {
+                serde_json::json!({
+                    "spec_id": spec_id,
+                    "latest_completed_phase": latest_phase,
+                    "metadata": metadata,
+                    "health": health,
+                })
+            })
+            .collect();
+        let filters: Vec<String> = filters.iter().map(ToString::to_string).collect();
+        println!(
+            "{}",
+            emit_jcs(&serde_json::json!({
+                "schema_version": "status-all-json.v1",
+                "filters": filters,
+                "specs": specs,
+            }))?
+        );
         return Ok(());
     }
 
-    // If JSON output is requested, use status-json.v2 format with full details
-    // Includes artifacts with blake3_first8, effective_config, and lock_drift
-    if json {
-        use crate::lock::{RunContext, XCheckerLock};
-        use crate::types::{
-            ArtifactInfo, ConfigSource, ConfigValue, PhaseStatusInfo, StatusJsonOutput,
-        };
-        use std::collections::BTreeMap;
+    if specs.is_empty() {
+        println!("No specs found.");
+        return Ok(());
+    }
 
-        // Get all phases
-        let all_phases = [
-            PhaseId::Requirements,
-            PhaseId::Design,
-            PhaseId::Tasks,
-            PhaseId::Review,
-            PhaseId::Fixup,
-            PhaseId::Final,
-        ];
+    for (spec_id, latest_phase, metadata, health) in &specs {
+        let title = metadata
+            .title
+            .as_deref()
+            .map(|title| format!(" - {title}"))
+            .unwrap_or_default();
+        println!("{spec_id}{title}");
+        println!(
+            "  Latest completed phase: {}",
+            latest_phase.as_deref().unwrap_or("None")
+        );
+        if let Some(health) = health {
+            println!(
+                "  Health: {}/100 (recency {}/20, reliability {}/30, findings {}/30, doneness {}/20)",
+                health.score, health.recency, health.reliability, health.findings, health.doneness
+            );
+        }
+        if !metadata.owners.is_empty() {
+            println!("  Owners: {}", metadata.owners.join(", "));
+        }
+        if !metadata.tags.is_empty() {
+            println!("  Tags: {}", metadata.tags.join(", "));
+        }
+    }
 
-        // Get receipts to determine phase status and receipt IDs
-        let receipts = handle.receipt_manager().list_receipts().unwrap_or_default();
+    Ok(())
+}
 
-        // Build phase status list
-        let mut phase_statuses = Vec::new();
-        let mut has_errors = false;
+/// Health score of a spec for `status --all`, or `None` if it cannot be computed
+fn spec_health(spec_id: &str, config: &Config) -> Option<crate::types::SpecHealth> {
+    use crate::receipt::ReceiptManager;
 
-        for phase_id in &all_phases {
-            // Find the latest receipt for this phase
-            let latest_receipt = receipts
-                .iter()
-                .filter(|r| r.phase == phase_id.as_str())
-                .max_by_key(|r| r.emitted_at);
+    let spec_dir = crate::paths::spec_root(spec_id);
+    let receipts = ReceiptManager::new(&spec_dir)
+        .list_receipts()
+        .unwrap_or_default();
+    crate::health::score(&config.done, &spec_dir, &receipts, chrono::Utc::now())
+        .inspect_err(|e| tracing::warn!("Failed to score health of spec '{spec_id}': {e:#}"))
+        .ok()
+}
 
-            let (status, receipt_id) = if let Some(receipt) = latest_receipt {
-                // Check if the phase succeeded or failed
-                if receipt.exit_code == 0 {
-                    (
-                        "success".to_string(),
-                        Some(format!(
-                            "{}-{}",
-                            receipt.phase,
-                            receipt.emitted_at.format("%Y%m%d_%H%M%S")
-                        )),
-                    )
-                } else {
-                    has_errors = true;
-                    (
-                        "failed".to_string(),
-                        Some(format!(
-                            "{}-{}",
-                            receipt.phase,
-                            receipt.emitted_at.format("%Y%m%d_%H%M%S")
-                        )),
-                    )
+/// Execute `status --all --ndjson`: stream one JSON line per spec
+///
+/// Lines are written as specs are indexed, in completion order, so memory use
+/// does not grow with the number of specs. A spec that cannot be read becomes
+/// an `{"spec_id", "error"}` line instead of stopping the stream. Indexing
+/// stops quietly when stdout is closed (e.g. piped into `head`).
+fn execute_status_all_ndjson_command(
+    filters: &[crate::status::metadata::SpecFilter],
+    config: &Config,
+) -> Result<()> {
+    use crate::status::index::{CancelToken, default_workers, index_specs};
+    use crate::status::metadata::{SpecFilter, specs_root};
+    use std::io::Write;
+
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+    let result = index_specs(
+        &specs_root(),
+        default_workers(),
+        &CancelToken::new(),
+        |result| {
+            let line = match result {
+                Ok(spec) => {
+                    if !SpecFilter::matches_all(filters, spec.metadata.as_ref()) {
+                        return Ok(());
+                    }
+                    serde_json::json!({
+                        "health": spec_health(&spec.spec_id, config),
+                        "spec_id": spec.spec_id,
+                        "latest_completed_phase": spec.latest_completed_phase.map(|phase| phase.as_str()),
+                        "metadata": spec.metadata.unwrap_or_default(),
+                    })
                 }
-            } else {
-                ("not_started".to_string(), None)
+                Err(failure) => serde_json::json!({
+                    "spec_id": failure.path.file_name(),
+                    "error": format!("{:#}", failure.error),
+                }),
             };
+            writeln!(out, "{}", emit_jcs(&line)?)?;
+            Ok(())
+        },
+    );
 
-            phase_statuses.push(PhaseStatusInfo {
-                phase_id: phase_id.as_str().to_string(),
-                status,
-                receipt_id,
-            });
+    match result {
+        Err(e)
+            if e.downcast_ref::<std::io::Error>()
+                .is_some_and(|e| e.kind() == std::io::ErrorKind::BrokenPipe) =>
+        {
+            Ok(())
         }
+        other => other.map(drop),
+    }
+}
 
-        // Count pending fixups
-        let pending_fixups = count_pending_fixups_for_spec(spec_id);
-
-        // Collect artifacts with blake3_first8 from receipts
-        let mut artifact_hashes: BTreeMap<String, String> = BTreeMap::new();
-        for receipt in &receipts {
-            for output in &receipt.outputs {
-                // Extract just the filename from the path for matching
-                if let Some(filename) = output.path.split('/').next_back() {
-                    let short_hash = if output.blake3_canonicalized.len() >= 8 {
-                        &output.blake3_canonicalized[..8]
-                    } else {
-                        &output.blake3_canonicalized
-                    };
-                    artifact_hashes.insert(filename.to_string(), short_hash.to_string());
-                }
-            }
-        }
+/// Execute `list --discover`: find repository specs and offer to register them
+fn execute_list_discover_command(register: bool, json: bool) -> Result<()> {
+    use crate::status::discover::{Registration, discover_specs, register_spec, repo_root};
+    use crate::status::metadata::specs_root;
+    use std::io::IsTerminal;
 
-        // Build artifact info list
-        let artifact_files = handle
-            .artifact_manager()
-            .list_artifacts()
-            .unwrap_or_default();
+    let cwd = std::env::current_dir().context("Failed to read current directory")?;
+    let cwd = camino::Utf8PathBuf::try_from(cwd)
+        .map_err(|e| anyhow::anyhow!("Current directory is not valid UTF-8: {e}"))?;
+    let root = repo_root(&cwd);
+    let specs_root = specs_root();
+    let specs = discover_specs(&root, &specs_root)?;
 
-        let mut artifacts: Vec<ArtifactInfo> = artifact_files
+    if json {
+        let specs: Vec<serde_json::Value> = specs
             .iter()
-            .filter_map(|filename| {
-                artifact_hashes.get(filename).map(|hash| ArtifactInfo {
-                    path: format!("artifacts/{filename}"),
-                    blake3_first8: hash.clone(),
+            .map(|spec| {
+                let existing = match &spec.registration {
+                    Registration::Conflict { existing } => Some(existing.as_str()),
+                    _ => None,
+                };
+                serde_json::json!({
+                    "spec_id": spec.spec_id,
+                    "path": spec.path.as_str(),
+                    "latest_completed_phase": spec.latest_completed_phase.map(|p| p.as_str()),
+                    "registration": spec.registration.as_str(),
+                    "existing": existing,
                 })
             })
             .collect();
-        artifacts.sort_by(|a, b| a.path.cmp(&b.path));
-
-        // Build effective_config from config with source attribution
-        let mut effective_config: BTreeMap<String, ConfigValue> = BTreeMap::new();
-
-        // Add key configuration values with their sources
-        // Provider
-        if let Some(ref provider) = config.llm.provider {
-            let source = config
-                .source_attribution
-                .get("provider")
-                .cloned()
-                .unwrap_or(ConfigSource::Config);
-            effective_config.insert(
-                "provider".to_string(),
-                ConfigValue {
-                    value: serde_json::Value::String(provider.clone()),
-                    source,
-                },
+        println!(
+            "{}",
+            emit_jcs(&serde_json::json!({
+                "schema_version": "list-discover-json.v1",
+                "repo_root": root.as_str(),
+                "specs_root": specs_root.as_str(),
+                "specs": specs,
+            }))?
+        );
+    } else if specs.is_empty() {
+        println!("No specs found under {root}");
+    } else {
+        for spec in &specs {
+            let state = match &spec.registration {
+                Registration::Registered => "registered".to_string(),
+                Registration::Unregistered => "not registered".to_string(),
+                Registration::Conflict { existing } => {
+                    format!("conflicts with {existing}")
+                }
+            };
+            println!("{} ({state})", spec.spec_id);
+            println!("  Path: {}", spec.path);
+            println!(
+                "  Latest completed phase: {}",
+                spec.latest_completed_phase
+                    .map_or("None", |phase| phase.as_str())
             );
         }
+    }
 
-        // Model
-        if let Some(ref model) = config.defaults.model {
-            let source = config
-                .source_attribution
-                .get("model")
-                .cloned()
-                .unwrap_or(ConfigSource::Config);
-            effective_config.insert(
-                "model".to_string(),
-                ConfigValue {
-                    value: serde_json::Value::String(model.clone()),
-                    source,
-                },
-            );
-        }
+    let unregistered: Vec<_> = specs
+        .iter()
+        .filter(|spec| spec.registration == Registration::Unregistered)
+        // Spec IDs come from directory names; only register valid ones
+        .filter(|spec| sanitize_spec_id(&spec.spec_id).is_ok_and(|id| id == spec.spec_id))
+        .collect();
+    if unregistered.is_empty() {
+        return Ok(());
+    }
 
-        // Max turns
-        if let Some(max_turns) = config.defaults.max_turns {
-            let source = config
-                .source_attribution
-                .get("max_turns")
-                .cloned()
-                .unwrap_or(ConfigSource::Config);
-            effective_config.insert(
-                "max_turns".to_string(),
-                ConfigValue {
-                    value: serde_json::Value::Number(max_turns.into()),
-                    source,
-                },
-            );
+    if !register {
+        // Offer only when someone can answer; JSON output stays machine-readable
+        if json || !std::io::stdin().is_terminal() {
+            return Ok(());
         }
-
-        // Phase timeout
-        if let Some(timeout) = config.defaults.phase_timeout {
-            let source = config
-                .source_attribution
-                .get("phase_timeout")
-                .cloned()
-                .unwrap_or(ConfigSource::Config);
-            effective_config.insert(
-                "phase_timeout".to_string(),
-                ConfigValue {
-                    value: serde_json::Value::Number(timeout.into()),
-                    source,
-                },
-            );
+        print!(
+            "\nRegister {} spec(s) under {specs_root}? (y/N): ",
+            unregistered.len()
+        );
+        if let Err(e) = std::io::stdout().flush() {
+            tracing::warn!("Failed to flush stdout: {}", e);
+        }
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        let input = input.trim().to_lowercase();
+        if input != "y" && input != "yes" {
+            return Ok(());
         }
+    }
 
-        // Execution strategy
-        if let Some(ref strategy) = config.llm.execution_strategy {
-            let source = config
-                .source_attribution
-                .get("execution_strategy")
-                .cloned()
-                .unwrap_or(ConfigSource::Config);
-            effective_config.insert(
-                "execution_strategy".to_string(),
-                ConfigValue {
-                    value: serde_json::Value::String(strategy.clone()),
-                    source,
-                },
-            );
+    for spec in unregistered {
+        let target = register_spec(spec, &specs_root)?;
+        if !json {
+            println!("Registered '{}' at {target}", spec.spec_id);
         }
+    }
 
-        // Load lockfile and detect drift
-        let lock_drift = if let Ok(Some(lock)) = XCheckerLock::load(spec_id) {
-            // Get current run context from latest receipt or config
-            let model_full_name = receipts
-                .last()
-                .map(|r| r.model_full_name.clone())
-                .unwrap_or_else(|| config.defaults.model.clone().unwrap_or_default());
+    Ok(())
+}
 
-            let claude_cli_version = receipts
-                .last()
-                .map(|r| r.claude_cli_version.clone())
-                .unwrap_or_else(|| env!("CARGO_PKG_VERSION").to_string());
+fn build_status_json_output(
+    spec_id: &str,
+    handle: &OrchestratorHandle,
+    config: &Config,
+) -> Result<crate::types::StatusJsonOutput> {
+    use crate::lock::{RunContext, XCheckerLock};
+    use crate::types::{
+        ArtifactInfo, ConfigSource, ConfigValue, PhaseStatusInfo, StatusJsonOutput,
+    };
+    use std::collections::BTreeMap;
 
-            let context = RunContext {
-                model_full_name,
-                claude_cli_version,
-                schema_version: "1".to_string(),
-            };
+    // Get all phases
+    let all_phases = [
+        PhaseId::Requirements,
+        PhaseId::Design,
+        PhaseId::Tasks,
+        PhaseId::Review,
+        PhaseId::Fixup,
+        PhaseId::Final,
+    ];
+
+    // Get receipts to determine phase status and receipt IDs
+    let snapshot = handle.receipt_manager().snapshot()?;
+    let receipts = snapshot.receipts;
+
+    // Build phase status list
+    let mut phase_statuses = Vec::new();
+    let mut has_errors = false;
 
-            lock.detect_drift(&context)
+    for phase_id in &all_phases {
+        // Find the latest receipt for this phase
+        let latest_receipt = receipts
+            .iter()
+            .filter(|r| r.phase == phase_id.as_str())
+            .max_by_key(|r| r.emitted_at);
+
+        let (status, receipt_id) = if let Some(receipt) = latest_receipt {
+            // Check if the phase was skipped, succeeded, or failed
+            if receipt.is_skipped() {
+                (
+                    "skipped".to_string(),
+                    Some(format!(
+                        "{}-{}",
+                        receipt.phase,
+                        receipt.emitted_at.format("%Y%m%d_%H%M%S")
+                    )),
+                )
+            } else if receipt.exit_code == 0 {
+                (
+                    "success".to_string(),
+                    Some(format!(
+                        "{}-{}",
+                        receipt.phase,
+                        receipt.emitted_at.format("%Y%m%d_%H%M%S")
+                    )),
+                )
+            } else {
+                has_errors = true;
+                (
+                    "failed".to_string(),
+                    Some(format!(
+                        "{}-{}",
+                        receipt.phase,
+                        receipt.emitted_at.format("%Y%m%d_%H%M%S")
+                    )),
+                )
+            }
         } else {
-            None
+            ("not_started".to_string(), None)
         };
 
-        let output = StatusJsonOutput {
-            schema_version: "status-json.v2".to_string(),
-            spec_id: spec_id.to_string(),
-            phase_statuses,
-            pending_fixups,
-            has_errors,
-            strict_validation: config.strict_validation(),
-            artifacts,
-            effective_config,
-            lock_drift,
-        };
+        phase_statuses.push(PhaseStatusInfo {
+            phase_id: phase_id.as_str().to_string(),
+            status,
+            receipt_id,
+        });
+    }
 
-        // Emit as canonical JSON using JCS (RFC 8785)
-        let json_output =
-            emit_status_json(&output).with_context(|| "Failed to emit status JSON")?;
+    // Count pending fixups
+    let pending_fixups = count_pending_fixups_for_spec(spec_id);
 
-        println!("{json_output}");
-        return Ok(());
+    // Collect artifacts with blake3_first8 from receipts
+    let mut artifact_hashes: BTreeMap<String, String> = BTreeMap::new();
+    for receipt in &receipts {
+        for output in &receipt.outputs {
+            // Extract just the filename from the path for matching
+            if let Some(filename) = output.path.split('/').next_back() {
+                let short_hash = if output.blake3_canonicalized.len() >= 8 {
+                    &output.blake3_canonicalized[..8]
+                } else {
+                    &output.blake3_canonicalized
+                };
+                artifact_hashes.insert(filename.to_string(), short_hash.to_string());
+            }
+        }
     }
 
-    // Human-readable output
-    println!("Status for spec: {spec_id}");
-    println!("  Directory: {base_path}");
+    // Build artifact info list
+    let artifact_files = handle
+        .artifact_manager()
+        .list_artifacts()
+        .unwrap_or_default();
 
-    // Get latest completed phase and show phase progression (R2.6)
-    let latest_completed = handle.artifact_manager().get_latest_completed_phase();
-    match latest_completed {
+    let mut artifacts: Vec<ArtifactInfo> = artifact_files
+        .iter()
+        .filter_map(|filename| {
+            artifact_hashes.get(filename).map(|hash| ArtifactInfo {
+                path: format!("artifacts/{filename}"),
+                blake3_first8: hash.clone(),
+            })
+        })
+        .collect();
+    artifacts.sort_by(|a, b| a.path.cmp(&b.path));
+
+    // Build effective_config from config with source attribution
+    let mut effective_config: BTreeMap<String, ConfigValue> = BTreeMap::new();
+
+    // Add key configuration values with their sources
+    // Provider
+    if let Some(ref provider) = config.llm.provider {
+        let source = config
+            .source_attribution
+            .get("provider")
+            .cloned()
+            .unwrap_or(ConfigSource::Config);
+        effective_config.insert(
+            "provider".to_string(),
+            ConfigValue {
+                value: serde_json::Value::String(provider.clone()),
+                source,
+            },
+        );
+    }
+
+    // Model
+    if let Some(ref model) = config.defaults.model {
+        let source = config
+            .source_attribution
+            .get("model")
+            .cloned()
+            .unwrap_or(ConfigSource::Config);
+        effective_config.insert(
+            "model".to_string(),
+            ConfigValue {
+                value: serde_json::Value::String(model.clone()),
+                source,
+            },
+        );
+    }
+
+    // Max turns
+    if let Some(max_turns) = config.defaults.max_turns {
+        let source = config
+            .source_attribution
+            .get("max_turns")
+            .cloned()
+            .unwrap_or(ConfigSource::Config);
+        effective_config.insert(
+            "max_turns".to_string(),
+            ConfigValue {
+                value: serde_json::Value::Number(max_turns.into()),
+                source,
+            },
+        );
+    }
+
+    // Phase timeout
+    if let Some(timeout) = config.defaults.phase_timeout {
+        let source = config
+            .source_attribution
+            .get("phase_timeout")
+            .cloned()
+            .unwrap_or(ConfigSource::Config);
+        effective_config.insert(
+            "phase_timeout".to_string(),
+            ConfigValue {
+                value: serde_json::Value::Number(timeout.into()),
+                source,
+            },
+        );
+    }
+
+    // Execution strategy
+    if let Some(ref strategy) = config.llm.execution_strategy {
+        let source = config
+            .source_attribution
+            .get("execution_strategy")
+            .cloned()
+            .unwrap_or(ConfigSource::Config);
+        effective_config.insert(
+            "execution_strategy".to_string(),
+            ConfigValue {
+                value: serde_json::Value::String(strategy.clone()),
+                source,
+            },
+        );
+    }
+
+    // Load lockfile and detect drift
+    let lock_drift = if let Ok(Some(lock)) = XCheckerLock::load(spec_id) {
+        // Get current run context from latest receipt or config
+        let model_full_name = receipts
+            .last()
+            .map(|r| r.model_full_name.clone())
+            .unwrap_or_else(|| config.defaults.model.clone().unwrap_or_default());
+
+        let claude_cli_version = receipts
+            .last()
+            .map(|r| r.claude_cli_version.clone())
+            .unwrap_or_else(|| env!("CARGO_PKG_VERSION").to_string());
+
+        let context = RunContext {
+            model_full_name,
+            claude_cli_version,
+            schema_version: "1".to_string(),
+        };
+
+        lock.detect_drift(&context)
+    } else {
+        None
+    };
+
+    let output = StatusJsonOutput {
+        schema_version: "status-json.v2".to_string(),
+        spec_id: spec_id.to_string(),
+        phase_statuses,
+        pending_fixups,
+        has_errors,
+        strict_validation: config.strict_validation(),
+        artifacts,
+        effective_config,
+        lock_drift,
+        generation: snapshot.generation,
+        metadata: handle.artifact_manager().spec_metadata()?,
+        active_run: active_run_info(spec_id),
+        task_sync: task_sync_progress(spec_id),
+        token_usage: crate::receipt::usage::token_usage(&receipts),
+        deprecations: status_deprecations(config, handle),
+        done: done_status(config, handle, &receipts)?,
+        next_steps: crate::status::next_steps::recommend(spec_id, &receipts, pending_fixups),
+        partials: stale_partials(spec_id, &receipts),
+    };
+
+    Ok(output)
+}
+
+/// Definition-of-done evaluation, or `None` without a `[done]` config section
+fn done_status(
+    config: &Config,
+    handle: &OrchestratorHandle,
+    receipts: &[crate::types::Receipt],
+) -> Result<Option<crate::types::DoneStatus>> {
+    if !config.done.is_configured() {
+        return Ok(None);
+    }
+    crate::done::evaluate(
+        &config.done,
+        handle.artifact_manager().base_path(),
+        receipts,
+    )
+    .map(Some)
+}
+
+/// Deprecated config keys and flags in use, plus legacy fields in the spec's receipts
+fn status_deprecations(
+    config: &Config,
+    handle: &OrchestratorHandle,
+) -> Vec<crate::deprecation::DeprecationNotice> {
+    use crate::deprecation::{DeprecationKind, normalize, notice};
+
+    let mut notices = config.deprecations();
+    notices.extend(
+        handle
+            .receipt_manager()
+            .legacy_fields_in_use()
+            .into_iter()
+            .filter_map(|field| notice(DeprecationKind::SchemaField, &format!("receipt.{field}"))),
+    );
+    normalize(&mut notices);
+    notices
+}
+
+/// Phase currently executing for a spec, read from its heartbeat sidecar
+fn active_run_info(spec_id: &str) -> Option<crate::types::ActiveRunInfo> {
+    let heartbeat = crate::lock::Heartbeat::load(spec_id)?;
+    Some(crate::types::ActiveRunInfo {
+        state: heartbeat.state().as_str().to_string(),
+        heartbeat_age_secs: heartbeat.age_secs(),
+        phase: heartbeat.phase,
+        pid: heartbeat.pid,
+        started_at: heartbeat.started_at,
+        bytes_streamed: heartbeat.bytes_streamed,
+    })
+}
+
+/// Partial files left by failed or interrupted runs, or none while a run is active
+fn stale_partials(
+    spec_id: &str,
+    receipts: &[crate::types::Receipt],
+) -> Vec<crate::types::StalePartial> {
+    if active_run_info(spec_id).is_some_and(|run| run.state == "active") {
+        return Vec::new();
+    }
+    crate::status::partials::stale_partials(
+        &crate::paths::spec_root(spec_id),
+        receipts,
+        chrono::Utc::now(),
+    )
+    .unwrap_or_else(|e| {
+        tracing::warn!("Failed to list partial files of spec '{spec_id}': {e:#}");
+        Vec::new()
+    })
+}
+
+/// Age in the largest whole unit (`45s`, `12m`, `3h`, `2d`)
+fn format_age(secs: u64) -> String {
+    if secs < 60 {
+        format!("{secs}s")
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else if secs < 86400 {
+        format!("{}h", secs / 3600)
+    } else {
+        format!("{}d", secs / 86400)
+    }
+}
+
+/// Execute `status --clean-partials`: remove partial files before reporting status
+///
+/// Refuses while a run of the spec is active, since its staged outputs are
+/// not stale yet.
+fn execute_clean_partials_command(spec_id: &str, json: bool) -> Result<()> {
+    if let Some(run) = active_run_info(spec_id).filter(|run| run.state == "active") {
+        return Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "clean-partials".to_string(),
+            value: format!(
+                "a {} run of spec '{spec_id}' is active (PID {}); its partial files are still in use",
+                run.phase, run.pid
+            ),
+        })
+        .into());
+    }
+
+    let spec_root = crate::paths::spec_root(spec_id);
+    if !spec_root.exists() {
+        return Ok(());
+    }
+    // Hold the spec lock so a run cannot start staging files while they are removed
+    let _lock = crate::lock::FileLock::acquire(spec_id, false, None)
+        .map_err(|e| anyhow::anyhow!("Cannot clean partials of spec '{spec_id}': {e}"))?;
+    let removed = crate::status::partials::clean_partials(&spec_root)?;
+    // JSON output stays a single status document; the removals are reported on stderr
+    if json {
+        if !removed.is_empty() {
+            eprintln!("Removed {} partial file(s)", removed.len());
+        }
+    } else {
+        for path in &removed {
+            println!("Removed partial file: {path}");
+        }
+    }
+    Ok(())
+}
+
+/// Tracker progress of a spec's tasks, read from its `sync-tasks` links
+fn task_sync_progress(spec_id: &str) -> Option<crate::types::TaskSyncProgress> {
+    let artifacts = crate::paths::spec_root(spec_id).join("artifacts");
+    let sync = crate::task_sync::load_sync(&artifacts).ok()??;
+    Some(crate::types::TaskSyncProgress {
+        tracker: sync.tracker.as_str().to_string(),
+        linked: sync.items.len(),
+        done: sync.done_count(),
+        target: sync.target,
+        pulled_at: sync.pulled_at,
+    })
+}
+
+/// Execute the status command
+fn execute_status_command(spec_id: &str, json: bool, config: &Config) -> Result<()> {
+    // Create read-only handle to access managers (no lock needed for status)
+    let handle = OrchestratorHandle::readonly(spec_id)
+        .with_context(|| format!("Failed to create orchestrator for spec: {spec_id}"))?;
+
+    // Check if spec directory exists
+    let base_path = handle.artifact_manager().base_path();
+    if !base_path.exists() {
+        if json {
+            // Return empty JSON for non-existent spec
+            println!("{{}}");
+        } else {
+            println!("Status for spec: {spec_id}");
+            println!("  Status: No spec found");
+            println!("  Directory: {base_path} (does not exist)");
+        }
+        return Ok(());
+    }
+
+    // If JSON output is requested, use status-json.v2 format with full details
+    // Includes artifacts with blake3_first8, effective_config, and lock_drift
+    if json {
+        // Rebuild if a run wrote a receipt while artifacts were being read, so the
+        // output never mixes receipts and artifacts from different generations
+        const STATUS_SNAPSHOT_ATTEMPTS: u32 = 3;
+        let mut attempt = 0;
+        let output = loop {
+            attempt += 1;
+            let output = build_status_json_output(spec_id, &handle, config)?;
+            if attempt >= STATUS_SNAPSHOT_ATTEMPTS
+                || handle.receipt_manager().generation() == output.generation
+            {
+                break output;
+            }
+        };
+
+        // Emit as canonical JSON using JCS (RFC 8785)
+        let json_output =
+            emit_status_json(&output).with_context(|| "Failed to emit status JSON")?;
+
+        println!("{json_output}");
+        return Ok(());
+    }
+
+    // Human-readable output
+    println!("Status for spec: {spec_id}");
+    println!("  Directory: {base_path}");
+
+    if let Some(metadata) = handle.artifact_manager().spec_metadata()? {
+        if let Some(title) = &metadata.title {
+            println!("  Title: {title}");
+        }
+        if !metadata.owners.is_empty() {
+            println!("  Owners: {}", metadata.owners.join(", "));
+        }
+        if !metadata.tags.is_empty() {
+            println!("  Tags: {}", metadata.tags.join(", "));
+        }
+        for link in &metadata.links {
+            println!("  Link: {link}");
+        }
+    }
+
+    if let Some(run) = active_run_info(spec_id) {
+        println!(
+            "  Running: {} phase (PID {}, {} bytes received, last heartbeat {}s ago)",
+            run.phase, run.pid, run.bytes_streamed, run.heartbeat_age_secs
+        );
+        if run.state == "silent" {
+            println!(
+                "  {} Heartbeat stopped: the run may be hung; stop it or rerun with --force",
+                console::warning_mark(Stream::Stdout)
+            );
+        } else if run.state == "stalled" {
+            println!(
+                "  {} No provider output recently: the run may be stalled",
+                console::warning_mark(Stream::Stdout)
+            );
+        }
+    }
+
+    if let Some(progress) = task_sync_progress(spec_id) {
+        let pulled = progress.pulled_at.map_or_else(
+            || "never pulled".to_string(),
+            |at| format!("pulled {}", at.format("%Y-%m-%d %H:%M UTC")),
+        );
+        println!(
+            "  Tracker: {}/{} tasks done in {} {} ({pulled})",
+            progress.done, progress.linked, progress.tracker, progress.target
+        );
+    }
+
+    // Get latest completed phase and show phase progression (R2.6)
+    let latest_completed = handle.artifact_manager().get_latest_completed_phase();
+    match latest_completed {
         Some(phase) => {
             println!("  Latest completed phase: {}", phase.as_str());
         }
@@ -1902,6 +4015,69 @@ fn execute_status_command(spec_id: &str, json: bool, config: &Config) -> Result<
         }
     }
 
+    // Cumulative provider consumption over all runs
+    if let Some(usage) = crate::receipt::usage::token_usage(&receipts) {
+        println!(
+            "  Token usage: {} request(s), {} input / {} output tokens",
+            usage.total.requests, usage.total.tokens_input, usage.total.tokens_output
+        );
+        for phase in &usage.phases {
+            println!(
+                "    {}: {} request(s), {} input / {} output tokens",
+                phase.phase,
+                phase.counts.requests,
+                phase.counts.tokens_input,
+                phase.counts.tokens_output
+            );
+        }
+        if usage.total.requests_without_usage > 0 {
+            println!(
+                "    ({} request(s) did not report token counts)",
+                usage.total.requests_without_usage
+            );
+        }
+    }
+
+    let partials = stale_partials(spec_id, &receipts);
+    if !partials.is_empty() {
+        println!(
+            "  {} Stale partials: {} (remove with `xchecker status {spec_id} --clean-partials`)",
+            console::warning_mark(Stream::Stdout),
+            partials.len()
+        );
+        for partial in &partials {
+            let run = partial
+                .run_id
+                .as_deref()
+                .map(|run_id| format!(", run {run_id}"))
+                .unwrap_or_default();
+            println!(
+                "    - {} ({} old{run})",
+                partial.path,
+                format_age(partial.age_secs)
+            );
+        }
+    }
+
+    if let Some(done) = done_status(config, &handle, &receipts)? {
+        if done.done {
+            println!("  Done: yes");
+        } else {
+            println!("  Done: no");
+            for criterion in &done.unmet {
+                println!("    ✗ {criterion}");
+            }
+        }
+    }
+
+    let deprecations = status_deprecations(config, &handle);
+    if !deprecations.is_empty() {
+        println!("\n  Deprecations:");
+        for notice in &deprecations {
+            println!("    ⚠ {notice}");
+        }
+    }
+
     // Show effective configuration with source attribution (R11.3)
     println!("\n  Effective configuration:");
     let effective_config = config.effective_config();
@@ -2011,6 +4187,8 @@ fn execute_status_command(spec_id: &str, json: bool, config: &Config) -> Result<
     // Check for pending fixups and show intended targets (R5.6)
     check_and_display_fixup_targets(spec_id)?;
 
+    print!("{}", next_steps_block(spec_id, &handle));
+
     // Show resume suggestions
     match latest_completed {
         Some(PhaseId::Requirements) => {
@@ -2054,14 +4232,16 @@ fn check_and_display_fixup_targets(spec_id: &str) -> Result<()> {
 
     // Check if Review phase is completed and has fixup markers
     let base_path = crate::paths::spec_root(spec_id);
-    let review_md_path = base_path.join("artifacts").join("30-review.md");
+    let review_md_path = base_path
+        .join("artifacts")
+        .join(PhaseId::Review.artifact_name("md"));
 
-    if !review_md_path.exists() {
+    if !compression::exists(review_md_path.as_std_path()) {
         return Ok(()); // No review phase completed yet
     }
 
     // Read the review content
-    let review_content = match std::fs::read_to_string(&review_md_path) {
+    let review_content = match compression::read_to_string(review_md_path.as_std_path()) {
         Ok(content) => content,
         Err(_) => return Ok(()), // Can't read review file, skip fixup check
     };
@@ -2137,15 +4317,189 @@ fn check_and_display_fixup_targets(spec_id: &str) -> Result<()> {
     Ok(())
 }
 
+/// How fixup previews are rendered (`--diff-algorithm`, `--word-diff`)
+#[derive(Debug, Clone, Copy, Default)]
+struct FixupPreviewStyle {
+    algorithm: crate::fixup::DiffAlgorithm,
+    word_level: bool,
+}
+
+/// Show the change each pending fixup would make, re-diffed for review
+fn display_fixup_previews(spec_id: &str, style: FixupPreviewStyle) -> Result<()> {
+    use crate::fixup::{FixupMode, FixupParser};
+
+    let base_path = crate::paths::spec_root(spec_id);
+    let Ok(review_content) = compression::read_to_string(
+        base_path
+            .join("artifacts")
+            .join(PhaseId::Review.artifact_name("md"))
+            .as_std_path(),
+    ) else {
+        return Ok(());
+    };
+
+    let fixup_parser = FixupParser::new(FixupMode::Preview, base_path.into())?;
+    let Ok(diffs) = fixup_parser.parse_diffs(&review_content) else {
+        return Ok(());
+    };
+
+    let engine = style.algorithm.engine();
+    println!(
+        "
+Fixup preview ({} diff):",
+        engine.name()
+    );
+    for diff in &diffs {
+        match fixup_parser.render_preview(diff, engine.as_ref(), style.word_level, Stream::Stdout) {
+            Ok(rendered) if rendered.is_empty() => {
+                println!("  {}: no changes", diff.target_file);
+            }
+            Ok(rendered) => print!("{rendered}"),
+            Err(e) => println!(
+                "  {} {}: cannot preview: {e}",
+                console::warning_mark(Stream::Stdout),
+                diff.target_file
+            ),
+        }
+    }
+    Ok(())
+}
+
+/// Cost check before a run (`--estimate`, `--max-estimated-cost`)
+#[derive(Debug, Clone, Copy, Default)]
+struct CostPreflight {
+    /// Print the estimate and stop without running
+    estimate_only: bool,
+    /// Abort when the estimated cost exceeds this amount
+    max_cost: Option<f64>,
+}
+
+impl CostPreflight {
+    /// Whether the run should be estimated before it starts
+    fn is_requested(self) -> bool {
+        self.estimate_only || self.max_cost.is_some()
+    }
+
+    /// Report `estimates` and enforce `max_cost`
+    ///
+    /// The full report is printed only with `estimate_only`; otherwise a
+    /// one-line total is printed unless `json` output is reserved for results.
+    fn check(self, estimates: &[RunEstimate], json: bool) -> Result<()> {
+        let input_tokens: u64 = estimates.iter().map(|e| e.input_tokens).sum();
+        let output_tokens: u64 = estimates.iter().map(|e| e.output_tokens).sum();
+        let cost: f64 = estimates.iter().map(|e| e.cost).sum();
+        let mut unpriced: Vec<&str> = estimates
+            .iter()
+            .flat_map(|e| e.unpriced_models.iter().map(String::as_str))
+            .map(|model| {
+                if model.is_empty() {
+                    "the provider's default model"
+                } else {
+                    model
+                }
+            })
+            .collect();
+        unpriced.sort_unstable();
+        unpriced.dedup();
+
+        if self.estimate_only && json {
+            println!(
+                "{}",
+                emit_jcs(&serde_json::json!({
+                    "schema_version": "estimate-json.v1",
+                    "chars_per_token": CHARS_PER_TOKEN,
+                    "specs": estimates,
+                    "input_tokens": input_tokens,
+                    "output_tokens": output_tokens,
+                    "cost": cost,
+                    "unpriced_models": unpriced,
+                    "max_estimated_cost": self.max_cost,
+                }))?
+            );
+        } else if self.estimate_only {
+            for estimate in estimates {
+                print_run_estimate(estimate);
+            }
+            println!(
+                "Total: {input_tokens} input + {output_tokens} output tokens, estimated cost {cost:.4}"
+            );
+            if !unpriced.is_empty() {
+                println!("  Not priced (no [pricing] entry): {}", unpriced.join(", "));
+            }
+        } else if !json {
+            println!(
+                "Estimated cost: {cost:.4} ({input_tokens} input + {output_tokens} output tokens)"
+            );
+        }
+
+        let Some(max_cost) = self.max_cost else {
+            return Ok(());
+        };
+        if !unpriced.is_empty() {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "max_estimated_cost".to_string(),
+                value: format!(
+                    "cannot be enforced: no [pricing] entry for {}",
+                    unpriced.join(", ")
+                ),
+            })
+            .into());
+        }
+        if cost > max_cost {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "max_estimated_cost".to_string(),
+                value: format!("estimated cost {cost:.4} exceeds the limit of {max_cost}"),
+            })
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// Print one spec's estimate as a table of phases
+fn print_run_estimate(estimate: &RunEstimate) {
+    println!(
+        "Estimate for {} (about {CHARS_PER_TOKEN} characters per token):",
+        estimate.spec_id
+    );
+    for phase in &estimate.phases {
+        let cost = phase
+            .cost
+            .map_or_else(|| "unpriced".to_string(), |cost| format!("{cost:.4}"));
+        let model = if phase.model.is_empty() {
+            "provider default"
+        } else {
+            &phase.model
+        };
+        let source = match phase.output_source {
+            OutputEstimateSource::LastRun => "last run",
+            OutputEstimateSource::MaxOutputTokens => "max_output_tokens",
+            OutputEstimateSource::Default => "default",
+            OutputEstimateSource::Local => "local",
+        };
+        println!(
+            "  {:<12} {:>9} in {:>9} out ({source})  {cost:>10}  {model}",
+            phase.phase.as_str(),
+            phase.input_tokens,
+            phase.output_tokens,
+        );
+    }
+}
+
 /// Execute the resume command
 #[allow(clippy::too_many_arguments)]
 async fn execute_resume_command(
     spec_id: &str,
     phase_name: &str,
+    force_phase: bool,
+    skip_phases: &[String],
     dry_run: bool,
     verbose: bool,
     force: bool,
     apply_fixups: bool,
+    preview_style: FixupPreviewStyle,
+    differential: bool,
+    preflight: CostPreflight,
     strict_lock: bool,
     config: &Config,
     cli_args: &CliArgs,
@@ -2156,21 +4510,39 @@ async fn execute_resume_command(
     logger.start_timing("total_execution");
 
     // Parse phase name
-    let phase_id = match phase_name.to_lowercase().as_str() {
-        "requirements" => PhaseId::Requirements,
-        "design" => PhaseId::Design,
-        "tasks" => PhaseId::Tasks,
-        "review" => PhaseId::Review,
-        "fixup" => PhaseId::Fixup,
-        "final" => PhaseId::Final,
-        _ => {
+    let phase_id = parse_phase_name(phase_name)?;
+    if differential && phase_id != PhaseId::Review {
+        return Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "differential".to_string(),
+            value: format!("--differential only applies to the review phase, not {phase_name}"),
+        })
+        .into());
+    }
+
+    // Skips before the target phase are recorded first; skips after it are
+    // recorded once the target phase succeeds
+    let mut skips_before = Vec::new();
+    let mut skips_after = Vec::new();
+    for name in skip_phases {
+        let skip_id = parse_phase_name(name)?;
+        if skip_id == phase_id {
             return Err(XCheckerError::Config(ConfigError::InvalidValue {
-                key: "phase".to_string(),
-                value: format!("Unknown phase '{phase_name}'. Valid phases: requirements, design, tasks, review, fixup, final"),
-            }).into());
+                key: "skip_phase".to_string(),
+                value: format!("'{name}' cannot be both skipped and run"),
+            })
+            .into());
         }
-    };
-
+        if phase_order(skip_id) < phase_order(phase_id) {
+            skips_before.push(skip_id);
+        } else {
+            skips_after.push(skip_id);
+        }
+    }
+    skips_before.sort_by_key(|p| phase_order(*p));
+    skips_before.dedup();
+    skips_after.sort_by_key(|p| phase_order(*p));
+    skips_after.dedup();
+
     logger.verbose(&format!(
         "Resuming spec {} from {} phase",
         spec_id,
@@ -2193,7 +4565,7 @@ async fn execute_resume_command(
 
     // Configure execution using shared helper
     // Note: Problem statement is not passed for resume - it's already persisted in spec dir
-    let orchestrator_config = build_orchestrator_config(
+    let mut orchestrator_config = build_orchestrator_config(
         dry_run,
         verbose,
         apply_fixups,
@@ -2202,6 +4574,12 @@ async fn execute_resume_command(
         None,
         redactor.clone(),
     );
+    if differential {
+        orchestrator_config.config.insert(
+            crate::phases::review_diff::CONFIG_KEY.to_string(),
+            "true".to_string(),
+        );
+    }
 
     // Create orchestrator handle (this will acquire the file lock)
     logger.start_timing("orchestrator_setup");
@@ -2218,24 +4596,65 @@ async fn execute_resume_command(
         .into());
     }
 
+    if preflight.is_requested() {
+        let estimate = handle
+            .estimate(&[phase_id])
+            .with_context(|| format!("Failed to estimate {} phase", phase_id.as_str()))?;
+        preflight.check(&[estimate], false)?;
+        if preflight.estimate_only {
+            return Ok(());
+        }
+    }
+
     logger.verbose(&format!(
         "Checking dependencies for {} phase...",
         phase_id.as_str()
     ));
 
-    // Execute resume
+    for skip_id in skips_before {
+        handle
+            .skip_phase(skip_id)
+            .with_context(|| format!("Failed to skip {} phase", skip_id.as_str()))?;
+        println!("- {} phase skipped", skip_id.as_str());
+    }
+
+    // Execute resume (or forced re-run)
     logger.start_timing(&format!("{}_phase", phase_id.as_str()));
-    let result = handle
-        .run_phase(phase_id)
-        .await
-        .with_context(|| format!("Failed to resume {} phase", phase_id.as_str()))?;
+    let result = if force_phase {
+        handle
+            .force_phase(phase_id)
+            .await
+            .with_context(|| format!("Failed to force {} phase", phase_id.as_str()))?
+    } else {
+        handle
+            .run_phase(phase_id)
+            .await
+            .with_context(|| format!("Failed to resume {} phase", phase_id.as_str()))?
+    };
     logger.end_timing(&format!("{}_phase", phase_id.as_str()));
 
+    if report_prompt_only(cli_args, &result) {
+        return Ok(());
+    }
+
+    if result.success {
+        for skip_id in skips_after {
+            handle
+                .skip_phase(skip_id)
+                .with_context(|| format!("Failed to skip {} phase", skip_id.as_str()))?;
+            println!("- {} phase skipped", skip_id.as_str());
+        }
+    }
+
     // Report results
     logger.end_timing("total_execution");
 
     if result.success {
-        println!("✓ {} phase completed successfully", phase_id.as_str());
+        println!(
+            "{} {} phase completed successfully",
+            console::check_mark(Stream::Stdout),
+            phase_id.as_str()
+        );
 
         logger.verbose(&format!("Phase: {}", result.phase.as_str()));
         logger.verbose(&format!("Exit code: {}", result.exit_code));
@@ -2255,27 +4674,21 @@ async fn execute_resume_command(
         // Print performance summary if verbose (R7.5, NFR5)
         logger.print_performance_summary();
 
-        // Show next steps based on completed phase
-        println!("\nNext steps:");
-        match phase_id {
-            PhaseId::Requirements => {
-                println!(
-                    "  - Review the generated requirements in .xchecker/specs/{spec_id}/artifacts/"
-                );
-                println!("  - Continue to Design phase: xchecker resume {spec_id} --phase design");
-            }
-            PhaseId::Design => {
-                println!("  - Review the generated design in .xchecker/specs/{spec_id}/artifacts/");
-                println!("  - Continue to Tasks phase: xchecker resume {spec_id} --phase tasks");
-            }
-            PhaseId::Tasks => {
-                println!("  - Review the generated tasks in .xchecker/specs/{spec_id}/artifacts/");
-                println!("  - Continue to Review phase: xchecker resume {spec_id} --phase review");
-            }
-            _ => {
-                println!("  - Check status with: xchecker status {spec_id}");
-            }
+        if phase_id == PhaseId::Fixup && !apply_fixups && !dry_run {
+            display_fixup_previews(spec_id, preview_style)?;
+        }
+
+        if matches!(
+            phase_id,
+            PhaseId::Requirements | PhaseId::Design | PhaseId::Tasks
+        ) {
+            println!(
+                "\nReview the generated {} in .xchecker/specs/{spec_id}/artifacts/",
+                phase_id.as_str()
+            );
         }
+        // Computed from the phase graph and receipts, so skips and stale phases are accounted for
+        print!("{}", next_steps_block(spec_id, &handle));
     } else {
         // Create structured error for phase failure (R1.3, R4.3)
         let phase_error = PhaseError::ExecutionFailed {
@@ -2312,10 +4725,10 @@ async fn execute_resume_command(
         eprintln!("\n  Recovery options:");
         eprintln!("    - Review partial outputs and receipt for error details");
         eprintln!("    - Fix any configuration or connectivity issues");
-        eprintln!("    - Retry with: xchecker resume {spec_id} --phase {phase_name}");
         eprintln!(
             "    - Test configuration with: xchecker resume {spec_id} --phase {phase_name} --dry-run"
         );
+        eprint!("{}", next_steps_block(spec_id, &handle));
 
         std::process::exit(result.exit_code);
     }
@@ -2324,6 +4737,59 @@ async fn execute_resume_command(
 }
 
 /// Execute the clean command
+/// Names of the destructive flags that are set
+fn destructive_flags(flags: &[(&str, bool)]) -> Vec<String> {
+    flags
+        .iter()
+        .filter(|(_, set)| *set)
+        .map(|(name, _)| (*name).to_string())
+        .collect()
+}
+
+/// Confirm destructive `operations` on a spec the invoking user doesn't own
+///
+/// Specs without `owners` in `spec.toml`, or owned by $USER, pass without a
+/// prompt, as does `--yes`. Otherwise the user is asked interactively;
+/// without a terminal the command fails asking for `--yes`. Returns
+/// `Ok(false)` when the user declines.
+fn confirm_spec_ownership(spec_id: &str, operations: &[String], yes: bool) -> Result<bool> {
+    use crate::status::metadata::{foreign_owners, invoking_user, load_spec_metadata};
+    use std::io::IsTerminal;
+
+    if yes || operations.is_empty() {
+        return Ok(true);
+    }
+    let metadata = load_spec_metadata(&crate::paths::spec_root(spec_id))?;
+    let user = invoking_user();
+    let Some(owners) = foreign_owners(metadata.as_ref(), user.as_deref()) else {
+        return Ok(true);
+    };
+
+    let notice = format!(
+        "Spec '{spec_id}' is owned by {} (you are {})",
+        owners.join(", "),
+        user.as_deref().unwrap_or("an unknown user")
+    );
+    let operations = operations.join(", ");
+    if !std::io::stdin().is_terminal() {
+        return Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "owners".to_string(),
+            value: format!("{notice}; pass --yes to run {operations} anyway"),
+        })
+        .into());
+    }
+
+    print!("{notice}.\nContinue with {operations}? (y/N): ");
+    // Flush stdout, logging a warning if it fails (non-fatal)
+    if let Err(e) = std::io::stdout().flush() {
+        tracing::warn!("Failed to flush stdout: {}", e);
+    }
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+    let input = input.trim().to_lowercase();
+    Ok(input == "y" || input == "yes")
+}
+
 fn execute_clean_command(spec_id: &str, hard: bool, force: bool, _config: &Config) -> Result<()> {
     use crate::lock::utils;
 
@@ -2435,7 +4901,10 @@ fn execute_clean_command(spec_id: &str, hard: bool, force: bool, _config: &Confi
         std::fs::remove_dir_all(&artifacts_path)
             .with_context(|| format!("Failed to remove artifacts directory: {artifacts_path}"))?;
         removed_count += artifacts.len();
-        println!("{} Removed artifacts directory", styled_check());
+        println!(
+            "{} Removed artifacts directory",
+            console::check_mark(Stream::Stdout)
+        );
     }
 
     // Remove receipts directory
@@ -2443,14 +4912,20 @@ fn execute_clean_command(spec_id: &str, hard: bool, force: bool, _config: &Confi
         std::fs::remove_dir_all(&receipts_path)
             .with_context(|| format!("Failed to remove receipts directory: {receipts_path}"))?;
         removed_count += receipts.len();
-        println!("{} Removed receipts directory", styled_check());
+        println!(
+            "{} Removed receipts directory",
+            console::check_mark(Stream::Stdout)
+        );
     }
 
     // Remove context directory
     if context_path.exists() {
         std::fs::remove_dir_all(&context_path)
             .with_context(|| format!("Failed to remove context directory: {context_path}"))?;
-        println!("{} Removed context directory", styled_check());
+        println!(
+            "{} Removed context directory",
+            console::check_mark(Stream::Stdout)
+        );
     }
 
     // Remove the spec directory
@@ -2459,1443 +4934,4171 @@ fn execute_clean_command(spec_id: &str, hard: bool, force: bool, _config: &Confi
             // With --hard, remove the entire spec directory including any remaining files
             std::fs::remove_dir_all(&base_path)
                 .with_context(|| format!("Failed to remove spec directory: {base_path}"))?;
-            println!("{} Removed spec directory completely", styled_check());
+            println!(
+                "{} Removed spec directory completely",
+                console::check_mark(Stream::Stdout)
+            );
         } else {
             // Without --hard, only remove if empty
             match std::fs::remove_dir(&base_path) {
                 Ok(()) => {
-                    println!("{} Removed empty spec directory", styled_check());
+                    println!(
+                        "{} Removed empty spec directory",
+                        console::check_mark(Stream::Stdout)
+                    );
                 }
                 Err(_) => {
                     // Directory not empty, that's fine
                     println!(
                         "{} Spec directory retained (contains other files)",
-                        styled_check()
+                        console::check_mark(Stream::Stdout)
                     );
                 }
             }
         }
     }
 
-    println!("\n{}", styled_success("Clean completed successfully."));
+    println!("\n{}", console::success("Clean completed successfully."));
     println!("  Removed {removed_count} files total");
 
     Ok(())
 }
 
-/// Create default configuration from Config struct and CLI args
-fn create_default_config(
-    verbose: bool,
-    config: &Config,
-    cli_args: &CliArgs,
-) -> HashMap<String, String> {
-    let mut config_map = HashMap::new();
+/// Operation applied by `xchecker bulk` to each selected spec
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BulkOperation {
+    /// Run a single phase
+    Run(PhaseId),
+    /// Remove artifacts, receipts, and context, keeping `spec.toml`
+    Clean,
+    /// Move the spec directory under `<XCHECKER_HOME>/archive/`
+    Archive,
+}
 
-    if verbose {
-        config_map.insert("verbose".to_string(), "true".to_string());
+impl BulkOperation {
+    /// Parse the words given after `--`
+    fn parse(words: &[String]) -> Result<Self> {
+        let words: Vec<&str> = words.iter().map(String::as_str).collect();
+        match words.as_slice() {
+            ["run", phase] => Ok(Self::Run(parse_phase_name(phase)?)),
+            ["clean"] => Ok(Self::Clean),
+            ["archive"] => Ok(Self::Archive),
+            _ => Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "bulk operation".to_string(),
+                value: format!(
+                    "'{}' (expected 'run <phase>', 'clean', or 'archive')",
+                    words.join(" ")
+                ),
+            })
+            .into()),
+        }
     }
 
-    // Use values from the configuration system
-    if let Some(packet_max_bytes) = config.defaults.packet_max_bytes {
-        config_map.insert("packet_max_bytes".to_string(), packet_max_bytes.to_string());
+    fn label(self) -> String {
+        match self {
+            Self::Run(phase) => format!("run {}", phase.as_str()),
+            Self::Clean => "clean".to_string(),
+            Self::Archive => "archive".to_string(),
+        }
     }
 
-    if let Some(packet_max_lines) = config.defaults.packet_max_lines {
-        config_map.insert("packet_max_lines".to_string(), packet_max_lines.to_string());
+    /// Whether the operation removes or moves spec files
+    const fn is_destructive(self) -> bool {
+        matches!(self, Self::Clean | Self::Archive)
     }
+}
 
-    if let Some(max_turns) = config.defaults.max_turns {
-        config_map.insert("max_turns".to_string(), max_turns.to_string());
-    }
+/// Outcome of a bulk operation on one spec
+#[derive(Debug, Clone, serde::Serialize)]
+struct BulkSpecResult {
+    spec_id: String,
+    success: bool,
+    exit_code: i32,
+    message: String,
+}
 
-    if let Some(model) = &config.defaults.model {
-        config_map.insert("model".to_string(), model.clone());
-    }
+/// Execute the bulk command
+#[allow(clippy::too_many_arguments)]
+async fn execute_bulk_command(
+    filters: &[crate::status::metadata::SpecFilter],
+    operation: &BulkOperation,
+    yes: bool,
+    dry_run: bool,
+    force: bool,
+    json: bool,
+    preflight: CostPreflight,
+    verbose: bool,
+    config: &Config,
+    cli_args: &CliArgs,
+    redactor: &Arc<SecretRedactor>,
+) -> Result<()> {
+    use crate::status::metadata::{invoking_user, specs_root};
 
-    if let Some(output_format) = &config.defaults.output_format {
-        config_map.insert("output_format".to_string(), output_format.clone());
+    if json && !yes && !preflight.estimate_only {
+        return Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "bulk".to_string(),
+            value: "--json requires --yes (the confirmation prompt is interactive)".to_string(),
+        })
+        .into());
     }
 
-    if let Some(phase_timeout) = config.defaults.phase_timeout {
-        config_map.insert("phase_timeout".to_string(), phase_timeout.to_string());
-    }
+    let BulkSelection {
+        spec_ids,
+        not_owned,
+        unreadable,
+    } = select_bulk_specs(&specs_root(), filters, invoking_user().as_deref(), redactor)?;
+    let filter_labels: Vec<String> = filters.iter().map(ToString::to_string).collect();
 
-    if let Some(stdout_cap_bytes) = config.defaults.stdout_cap_bytes {
-        config_map.insert("stdout_cap_bytes".to_string(), stdout_cap_bytes.to_string());
+    if !json {
+        println!(
+            "Bulk {}: {} spec(s) match {}",
+            operation.label(),
+            spec_ids.len(),
+            filter_labels.join(", ")
+        );
+        for spec_id in &spec_ids {
+            match not_owned.get(spec_id) {
+                Some(owners) if operation.is_destructive() => {
+                    println!("  - {spec_id} (owned by {owners})");
+                }
+                _ => println!("  - {spec_id}"),
+            }
+        }
+        for result in &unreadable {
+            println!("  - {} (skipped: {})", result.spec_id, result.message);
+        }
     }
-
-    if let Some(stderr_cap_bytes) = config.defaults.stderr_cap_bytes {
-        config_map.insert("stderr_cap_bytes".to_string(), stderr_cap_bytes.to_string());
+    if spec_ids.is_empty() {
+        if json {
+            println!(
+                "{}",
+                bulk_results_json(operation, &filter_labels, &unreadable)?
+            );
+        }
+        if let Some(first) = unreadable.first() {
+            std::process::exit(first.exit_code);
+        }
+        return Ok(());
     }
 
-    if let Some(lock_ttl_seconds) = config.defaults.lock_ttl_seconds {
-        config_map.insert("lock_ttl_seconds".to_string(), lock_ttl_seconds.to_string());
+    // Estimate before asking, so the confirmation is made knowing the cost
+    if preflight.is_requested() {
+        let BulkOperation::Run(phase) = operation else {
+            return Err(XCheckerError::Config(ConfigError::InvalidValue {
+                key: "bulk".to_string(),
+                value: format!(
+                    "--estimate and --max-estimated-cost only apply to 'run', not '{}'",
+                    operation.label()
+                ),
+            })
+            .into());
+        };
+        let orchestrator_config = build_orchestrator_config(
+            dry_run,
+            verbose,
+            false,
+            config,
+            cli_args,
+            None,
+            redactor.clone(),
+        );
+        let mut estimates = Vec::with_capacity(spec_ids.len());
+        for spec_id in &spec_ids {
+            let handle = OrchestratorHandle::with_config_and_force(
+                spec_id,
+                orchestrator_config.clone(),
+                force,
+            )
+            .with_context(|| format!("Failed to create orchestrator for spec: {spec_id}"))?;
+            estimates.push(
+                handle
+                    .estimate(&[*phase])
+                    .with_context(|| format!("Failed to estimate spec: {spec_id}"))?,
+            );
+        }
+        preflight.check(&estimates, json)?;
+        if preflight.estimate_only {
+            return Ok(());
+        }
     }
 
-    if let Some(debug_packet) = config.defaults.debug_packet
-        && debug_packet
-    {
-        config_map.insert("debug_packet".to_string(), "true".to_string());
-    }
+    if !yes {
+        print!("\nApply '{}' to these specs? (y/N): ", operation.label());
+        // Flush stdout, logging a warning if it fails (non-fatal)
+        if let Err(e) = std::io::stdout().flush() {
+            tracing::warn!("Failed to flush stdout: {}", e);
+        }
 
-    if let Some(allow_links) = config.defaults.allow_links
-        && allow_links
-    {
-        config_map.insert("allow_links".to_string(), "true".to_string());
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        let input = input.trim().to_lowercase();
+        if input != "y" && input != "yes" {
+            println!("Bulk operation cancelled.");
+            return Ok(());
+        }
     }
 
-    if let Some(runner_mode) = &config.runner.mode {
-        config_map.insert("runner_mode".to_string(), runner_mode.clone());
+    // Specs whose metadata could not be read are reported, not operated on
+    let mut results = unreadable;
+    for spec_id in &spec_ids {
+        let outcome = match operation {
+            BulkOperation::Run(phase) => {
+                bulk_run_phase(
+                    spec_id, *phase, dry_run, force, verbose, config, cli_args, redactor,
+                )
+                .await
+            }
+            BulkOperation::Clean => bulk_clean_spec(spec_id, force),
+            BulkOperation::Archive => bulk_archive_spec(spec_id, force),
+        };
+        let result = match outcome {
+            Ok(result) => result,
+            Err(e) => {
+                let exit_code = e
+                    .downcast_ref::<XCheckerError>()
+                    .map_or(ExitCode::INTERNAL, XCheckerError::to_exit_code)
+                    .as_i32();
+                BulkSpecResult {
+                    spec_id: spec_id.clone(),
+                    success: false,
+                    exit_code,
+                    message: redactor.redact_string(&format!("{e:#}")),
+                }
+            }
+        };
+        if !json {
+            let marker = if result.success {
+                console::check_mark(Stream::Stdout)
+            } else {
+                console::warning_mark(Stream::Stdout)
+            };
+            println!("{marker} {}: {}", result.spec_id, result.message);
+        }
+        results.push(result);
     }
 
-    if let Some(runner_distro) = &config.runner.distro {
-        config_map.insert("runner_distro".to_string(), runner_distro.clone());
+    let failed: Vec<&BulkSpecResult> = results.iter().filter(|r| !r.success).collect();
+    if json {
+        println!(
+            "{}",
+            bulk_results_json(operation, &filter_labels, &results)?
+        );
+    } else {
+        println!(
+            "\nBulk {}: {} succeeded, {} failed",
+            operation.label(),
+            results.len() - failed.len(),
+            failed.len()
+        );
     }
 
-    if let Some(claude_path) = &config.runner.claude_path {
-        config_map.insert("claude_path".to_string(), claude_path.clone());
+    if let Some(first) = failed.first() {
+        std::process::exit(if first.exit_code == 0 {
+            ExitCode::INTERNAL.as_i32()
+        } else {
+            first.exit_code
+        });
     }
 
-    if let Some(provider) = &config.llm.provider {
-        config_map.insert("llm_provider".to_string(), provider.clone());
-    }
+    Ok(())
+}
 
-    if let Some(fallback_provider) = &config.llm.fallback_provider {
-        config_map.insert(
-            "llm_fallback_provider".to_string(),
-            fallback_provider.clone(),
-        );
-    }
+/// Specs selected by `xchecker bulk` filters
+struct BulkSelection {
+    spec_ids: Vec<String>,
+    /// Specs the invoking user doesn't own, flagged in the confirmation summary
+    not_owned: HashMap<String, String>,
+    /// Failed results for specs whose `spec.toml` could not be read
+    unreadable: Vec<BulkSpecResult>,
+}
 
-    if let Some(execution_strategy) = &config.llm.execution_strategy {
-        config_map.insert("execution_strategy".to_string(), execution_strategy.clone());
+/// Match every spec under `root` against `filters`
+///
+/// A spec with an unreadable or invalid `spec.toml` is recorded in
+/// `unreadable` instead of aborting the whole selection.
+fn select_bulk_specs(
+    root: &camino::Utf8Path,
+    filters: &[crate::status::metadata::SpecFilter],
+    user: Option<&str>,
+    redactor: &SecretRedactor,
+) -> Result<BulkSelection> {
+    use crate::status::metadata::{SpecFilter, foreign_owners, list_spec_ids, load_spec_metadata};
+
+    let mut selection = BulkSelection {
+        spec_ids: Vec::new(),
+        not_owned: HashMap::new(),
+        unreadable: Vec::new(),
+    };
+    for spec_id in list_spec_ids(root)? {
+        let metadata = match load_spec_metadata(&root.join(&spec_id)) {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                selection.unreadable.push(BulkSpecResult {
+                    spec_id,
+                    success: false,
+                    exit_code: ExitCode::CLI_ARGS.as_i32(),
+                    message: redactor.redact_string(&format!("{e:#}")),
+                });
+                continue;
+            }
+        };
+        if SpecFilter::matches_all(filters, metadata.as_ref()) {
+            if let Some(owners) = foreign_owners(metadata.as_ref(), user) {
+                selection
+                    .not_owned
+                    .insert(spec_id.clone(), owners.join(", "));
+            }
+            selection.spec_ids.push(spec_id);
+        }
     }
+    Ok(selection)
+}
 
-    if let Some(prompt_template) = &config.llm.prompt_template {
-        config_map.insert("prompt_template".to_string(), prompt_template.clone());
-    }
+fn bulk_results_json(
+    operation: &BulkOperation,
+    filters: &[String],
+    results: &[BulkSpecResult],
+) -> Result<String> {
+    let succeeded = results.iter().filter(|r| r.success).count();
+    emit_jcs(&serde_json::json!({
+        "schema_version": "bulk-json.v1",
+        "operation": operation.label(),
+        "filters": filters,
+        "results": results,
+        "summary": {
+            "total": results.len(),
+            "succeeded": succeeded,
+            "failed": results.len() - succeeded,
+        },
+    }))
+}
 
-    if let Some(claude_config) = &config.llm.claude
-        && let Some(binary) = &claude_config.binary
-    {
-        config_map.insert("llm_claude_binary".to_string(), binary.clone());
-    }
+#[allow(clippy::too_many_arguments)]
+async fn bulk_run_phase(
+    spec_id: &str,
+    phase: PhaseId,
+    dry_run: bool,
+    force: bool,
+    verbose: bool,
+    config: &Config,
+    cli_args: &CliArgs,
+    redactor: &Arc<SecretRedactor>,
+) -> Result<BulkSpecResult> {
+    let orchestrator_config = build_orchestrator_config(
+        dry_run,
+        verbose,
+        false,
+        config,
+        cli_args,
+        None,
+        redactor.clone(),
+    );
+    let mut handle = OrchestratorHandle::with_config_and_force(spec_id, orchestrator_config, force)
+        .with_context(|| format!("Failed to create orchestrator for spec: {spec_id}"))?;
+    let result = handle
+        .run_phase(phase)
+        .await
+        .with_context(|| format!("Failed to run {} phase", phase.as_str()))?;
 
-    if let Some(gemini_config) = &config.llm.gemini {
-        if let Some(binary) = &gemini_config.binary {
-            config_map.insert("llm_gemini_binary".to_string(), binary.clone());
-        }
-        if let Some(default_model) = &gemini_config.default_model {
-            config_map.insert(
-                "llm_gemini_default_model".to_string(),
-                default_model.clone(),
-            );
+    let message = if result.success {
+        format!("{} phase completed", phase.as_str())
+    } else {
+        let detail = result
+            .error
+            .as_deref()
+            .map(|error| format!(": {}", redactor.redact_string(error)))
+            .unwrap_or_default();
+        format!("{} phase failed{detail}", phase.as_str())
+    };
+    Ok(BulkSpecResult {
+        spec_id: spec_id.to_string(),
+        success: result.success,
+        exit_code: result.exit_code,
+        message,
+    })
+}
+
+fn bulk_clean_spec(spec_id: &str, force: bool) -> Result<BulkSpecResult> {
+    crate::lock::utils::can_clean(spec_id, force, None)
+        .map_err(|e| anyhow::anyhow!("Cannot clean spec '{spec_id}': {e}"))?;
+
+    let base_path = crate::paths::spec_root(spec_id);
+    let mut removed = Vec::new();
+    for dir in ["artifacts", "receipts", "context"] {
+        let path = base_path.join(dir);
+        if path.exists() {
+            std::fs::remove_dir_all(&path)
+                .with_context(|| format!("Failed to remove {dir} directory: {path}"))?;
+            removed.push(dir);
         }
     }
 
-    // Add new CLI arguments (R7.2, R7.4, R9.2)
-    if !cli_args.allow.is_empty() {
-        config_map.insert("allowed_tools".to_string(), cli_args.allow.join(","));
+    let message = if removed.is_empty() {
+        "nothing to clean".to_string()
+    } else {
+        format!("removed {}", removed.join(", "))
+    };
+    Ok(BulkSpecResult {
+        spec_id: spec_id.to_string(),
+        success: true,
+        exit_code: 0,
+        message,
+    })
+}
+
+fn bulk_archive_spec(spec_id: &str, force: bool) -> Result<BulkSpecResult> {
+    crate::lock::utils::can_clean(spec_id, force, None)
+        .map_err(|e| anyhow::anyhow!("Cannot archive spec '{spec_id}': {e}"))?;
+
+    let archive_root = crate::paths::xchecker_home().join("archive");
+    crate::paths::ensure_dir_all(&archive_root)
+        .with_context(|| format!("Failed to create archive directory: {archive_root}"))?;
+
+    // Never overwrite an earlier archive of the same spec
+    let mut destination = archive_root.join(spec_id);
+    if destination.exists() {
+        destination = archive_root.join(format!(
+            "{spec_id}-{}",
+            chrono::Utc::now().format("%Y%m%d_%H%M%S")
+        ));
     }
 
-    if !cli_args.deny.is_empty() {
-        config_map.insert("disallowed_tools".to_string(), cli_args.deny.join(","));
+    let source = crate::paths::spec_root(spec_id);
+    std::fs::rename(&source, &destination)
+        .with_context(|| format!("Failed to move {source} to {destination}"))?;
+
+    Ok(BulkSpecResult {
+        spec_id: spec_id.to_string(),
+        success: true,
+        exit_code: 0,
+        message: format!("archived to {destination}"),
+    })
+}
+
+/// Execute the experiment command
+#[allow(clippy::too_many_arguments)]
+async fn execute_experiment_command(
+    spec_id: &str,
+    models: &[String],
+    phase: PhaseId,
+    dry_run: bool,
+    force: bool,
+    yes: bool,
+    json: bool,
+    verbose: bool,
+    config: &Config,
+    cli_args: &CliArgs,
+    redactor: &Arc<SecretRedactor>,
+) -> Result<()> {
+    use crate::artifact::ArtifactType;
+    use crate::experiment::{
+        ExperimentReport, ModelRun, branch_spec_id, config_for_model, fork_spec,
+    };
+
+    let mut unique: Vec<&str> = Vec::with_capacity(models.len());
+    for model in models.iter().map(|m| m.trim()).filter(|m| !m.is_empty()) {
+        if !unique.contains(&model) {
+            unique.push(model);
+        }
+    }
+    if unique.len() < 2 {
+        return Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "models".to_string(),
+            value: format!(
+                "'{}' (an experiment compares at least two distinct models)",
+                models.join(",")
+            ),
+        })
+        .into());
     }
 
-    if cli_args.dangerously_skip_permissions {
-        config_map.insert(
-            "dangerously_skip_permissions".to_string(),
-            "true".to_string(),
-        );
+    let source = crate::paths::spec_root(spec_id);
+    if !source.exists() {
+        return Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "spec_id".to_string(),
+            value: format!("'{spec_id}' (no spec directory at {source})"),
+        })
+        .into());
     }
 
-    if !cli_args.ignore_secret_pattern.is_empty() {
-        config_map.insert(
-            "ignore_secret_patterns".to_string(),
-            cli_args.ignore_secret_pattern.join("|"),
+    let mut runs = Vec::with_capacity(unique.len());
+    for model in unique {
+        let branch_id = branch_spec_id(spec_id, model)?;
+        if !confirm_spec_ownership(&branch_id, &["experiment".to_string()], yes)? {
+            println!("Experiment cancelled.");
+            return Ok(());
+        }
+        fork_spec(&source, &branch_id, force)?;
+        if !json {
+            println!(
+                "Running {} phase with {model} in {branch_id}...",
+                phase.as_str()
+            );
+        }
+
+        let orchestrator_config = build_orchestrator_config(
+            dry_run,
+            verbose,
+            false,
+            &config_for_model(config, phase, model),
+            cli_args,
+            None,
+            redactor.clone(),
         );
+        let mut handle =
+            OrchestratorHandle::with_config_and_force(&branch_id, orchestrator_config, force)
+                .with_context(|| format!("Failed to create orchestrator for spec: {branch_id}"))?;
+        let started = std::time::Instant::now();
+        let outcome = handle.force_phase(phase).await;
+        let duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        let (success, exit_code, error) = match outcome {
+            Ok(result) => (
+                result.success,
+                result.exit_code,
+                result.error.map(|e| redactor.redact_string(&e)),
+            ),
+            Err(e) => {
+                let exit_code = e
+                    .downcast_ref::<XCheckerError>()
+                    .map_or(ExitCode::INTERNAL, XCheckerError::to_exit_code)
+                    .as_i32();
+                (
+                    false,
+                    exit_code,
+                    Some(redactor.redact_string(&format!("{e:#}"))),
+                )
+            }
+        };
+
+        // A failed run leaves the forked copy of the previous output in place
+        let output = if success {
+            Some(
+                handle
+                    .artifact_manager()
+                    .read_artifact(&phase.artifact_name("md"), ArtifactType::Markdown)?,
+            )
+        } else {
+            None
+        };
+        let llm = handle
+            .receipt_manager()
+            .read_latest_receipt(phase)?
+            .and_then(|receipt| receipt.llm);
+        let tokens_input = llm.as_ref().and_then(|llm| llm.tokens_input);
+        let tokens_output = llm.as_ref().and_then(|llm| llm.tokens_output);
+        let model_used = llm.as_ref().and_then(|llm| llm.model_used.as_deref());
+        let cost = config
+            .pricing
+            .price_for(model_used.into_iter().chain([model]))
+            .zip(tokens_input.zip(tokens_output))
+            .map(|(price, (input, output))| price.cost(input, output));
+
+        runs.push(ModelRun {
+            model: model.to_string(),
+            spec_id: branch_id,
+            success,
+            exit_code,
+            duration_ms,
+            tokens_input,
+            tokens_output,
+            cost,
+            error,
+            output,
+        });
     }
 
-    if !cli_args.extra_secret_pattern.is_empty() {
-        config_map.insert(
-            "extra_secret_patterns".to_string(),
-            cli_args.extra_secret_pattern.join("|"),
+    let report = ExperimentReport::new(spec_id, phase, runs);
+    if json {
+        println!(
+            "{}",
+            emit_jcs(&serde_json::json!({
+                "schema_version": "experiment-json.v1",
+                "spec_id": report.spec_id,
+                "phase": report.phase,
+                "runs": report.runs,
+                "diffs": report.diffs,
+            }))?
         );
+    } else {
+        println!();
+        print!("{}", report.to_markdown());
     }
 
-    // Add debug_packet flag (FR-PKT-006, FR-PKT-007) for CLI-only overrides
-    if cli_args.debug_packet {
-        config_map.insert("debug_packet".to_string(), "true".to_string());
+    if let Some(first) = report.failed().next() {
+        std::process::exit(if first.exit_code == 0 {
+            ExitCode::INTERNAL.as_i32()
+        } else {
+            first.exit_code
+        });
     }
 
-    config_map
+    Ok(())
 }
 
-/// Build an OrchestratorConfig from CLI parameters.
-///
-/// This helper reduces duplication between execute_spec_command and execute_resume_command
-/// by combining create_default_config with the common additional parameters.
-///
-/// # Arguments
-/// * `dry_run` - Whether to run in simulation mode
-/// * `verbose` - Enable verbose logging
-/// * `apply_fixups` - Whether to apply fixups (true) or preview (false)
-/// * `config` - The loaded xchecker configuration
-/// * `cli_args` - CLI arguments passed by the user
-/// * `problem_statement` - Optional problem statement to include in phase prompts
-fn build_orchestrator_config(
-    dry_run: bool,
+/// Create default configuration from Config struct and CLI args
+fn create_default_config(
     verbose: bool,
-    apply_fixups: bool,
     config: &Config,
     cli_args: &CliArgs,
-    problem_statement: Option<&str>,
-    redactor: Arc<SecretRedactor>,
-) -> OrchestratorConfig {
-    let mut config_map = create_default_config(verbose, config, cli_args);
-    config_map.insert("logger_enabled".to_string(), verbose.to_string());
-    config_map.insert("apply_fixups".to_string(), apply_fixups.to_string());
+) -> HashMap<String, String> {
+    let mut config_map = HashMap::new();
 
-    // Include problem statement in config for prompt construction (FR-PKT)
-    if let Some(ps) = problem_statement {
-        config_map.insert("problem_statement".to_string(), ps.to_string());
+    if verbose {
+        config_map.insert("verbose".to_string(), "true".to_string());
     }
 
-    OrchestratorConfig {
-        dry_run,
-        config: config_map,
-        full_config: Some(config.clone()),
-        selectors: Some(config.selectors.clone()),
-        strict_validation: config.strict_validation(),
-        redactor,
-        hooks: Some(config.hooks.clone()),
+    // Use values from the configuration system
+    if let Some(packet_max_bytes) = config.defaults.packet_max_bytes {
+        config_map.insert("packet_max_bytes".to_string(), packet_max_bytes.to_string());
     }
-}
 
-/// Enhance error reporting for common failure scenarios
-fn enhance_error_context(error: &anyhow::Error) -> Option<Vec<String>> {
-    let error_str = error.to_string();
+    if let Some(packet_max_lines) = config.defaults.packet_max_lines {
+        config_map.insert("packet_max_lines".to_string(), packet_max_lines.to_string());
+    }
 
-    if error_str.contains("Failed to create orchestrator") {
-        Some(vec![
-            "Check that the current directory is writable".to_string(),
-            "Ensure sufficient disk space is available".to_string(),
-            "Verify directory permissions".to_string(),
-            "Try running from a different directory".to_string(),
-        ])
-    } else if error_str.contains("Failed to execute") {
-        Some(vec![
-            "Check the spec ID is valid and doesn't contain special characters".to_string(),
-            "Verify Claude CLI is installed and accessible".to_string(),
-            "Try running with --dry-run to test configuration".to_string(),
-            "Check your internet connection if using Claude API".to_string(),
-        ])
-    } else if error_str.contains("Permission denied") {
-        Some(vec![
-            "Check file and directory permissions".to_string(),
-            "Ensure you have write access to the current directory".to_string(),
-            "Try running from your home directory or a writable location".to_string(),
-        ])
-    } else if error_str.contains("No such file or directory") {
-        Some(vec![
-            "Verify the specified paths exist".to_string(),
-            "Check that you're running from the correct directory".to_string(),
-            "Ensure all required files are present".to_string(),
-        ])
-    } else {
-        None
+    if let Some(max_turns) = config.defaults.max_turns {
+        config_map.insert("max_turns".to_string(), max_turns.to_string());
     }
-}
 
-/// Execute the test command for integration validation
-fn execute_test_command(components: bool, smoke: bool, verbose: bool) -> Result<()> {
-    use crate::integration_tests;
+    if let Some(model) = &config.defaults.model {
+        config_map.insert("model".to_string(), model.clone());
+    }
 
-    if verbose {
-        println!("Running integration tests...");
+    if let Some(output_format) = &config.defaults.output_format {
+        config_map.insert("output_format".to_string(), output_format.clone());
     }
 
-    // If no specific test type is specified, run both
-    let run_components = components || !smoke;
-    let run_smoke = smoke || !components;
+    if let Some(phase_timeout) = config.defaults.phase_timeout {
+        config_map.insert("phase_timeout".to_string(), phase_timeout.to_string());
+    }
 
-    if run_components {
-        integration_tests::validate_component_integration()
-            .with_context(|| "Component integration validation failed")?;
+    if let Some(stdout_cap_bytes) = config.defaults.stdout_cap_bytes {
+        config_map.insert("stdout_cap_bytes".to_string(), stdout_cap_bytes.to_string());
     }
 
-    if run_smoke {
-        integration_tests::run_smoke_tests().with_context(|| "Smoke tests failed")?;
+    if let Some(stderr_cap_bytes) = config.defaults.stderr_cap_bytes {
+        config_map.insert("stderr_cap_bytes".to_string(), stderr_cap_bytes.to_string());
     }
 
-    println!("✓ All integration tests passed successfully");
-    Ok(())
-}
+    if let Some(lock_ttl_seconds) = config.defaults.lock_ttl_seconds {
+        config_map.insert("lock_ttl_seconds".to_string(), lock_ttl_seconds.to_string());
+    }
 
-/// Execute the benchmark command (NFR1 validation)
-#[allow(clippy::too_many_arguments)]
-fn execute_benchmark_command(
-    file_count: usize,
-    file_size: usize,
-    iterations: usize,
-    json: bool,
-    max_empty_run_secs: Option<f64>,
-    max_packetization_ms: Option<f64>,
-    max_rss_mb: Option<f64>,
-    max_commit_mb: Option<f64>,
-    verbose: bool,
-) -> Result<()> {
-    use crate::benchmark::{BenchmarkConfig, BenchmarkRunner, BenchmarkThresholds};
+    if let Some(debug_packet) = config.defaults.debug_packet
+        && debug_packet
+    {
+        config_map.insert("debug_packet".to_string(), "true".to_string());
+    }
 
-    // Build custom thresholds if any overrides provided
-    let mut thresholds = BenchmarkThresholds::default();
-    if let Some(max_secs) = max_empty_run_secs {
-        thresholds.empty_run_max_secs = max_secs;
+    if let Some(allow_links) = config.defaults.allow_links
+        && allow_links
+    {
+        config_map.insert("allow_links".to_string(), "true".to_string());
     }
-    if let Some(max_ms) = max_packetization_ms {
-        thresholds.packetization_max_ms_per_100_files = max_ms;
+
+    if let Some(response_min_bytes) = config.defaults.response_min_bytes {
+        config_map.insert(
+            "response_min_bytes".to_string(),
+            response_min_bytes.to_string(),
+        );
     }
-    if let Some(max_rss) = max_rss_mb {
-        thresholds.max_rss_mb = Some(max_rss);
+
+    if let Some(response_retries) = config.defaults.response_retries {
+        config_map.insert("response_retries".to_string(), response_retries.to_string());
     }
-    if let Some(max_commit) = max_commit_mb {
-        thresholds.max_commit_mb = Some(max_commit);
+
+    if let Some(output_language) = &config.defaults.output_language {
+        config_map.insert("output_language".to_string(), output_language.clone());
+    }
+    if let Some(fsync) = &config.defaults.fsync {
+        config_map.insert("fsync".to_string(), fsync.clone());
     }
 
-    // Only print header if not in JSON mode
-    if !json {
-        println!("=== xchecker Performance Benchmark ===");
-        println!("Validating NFR1 performance targets:");
-        println!("  - Empty run: ≤ {:.3}s", thresholds.empty_run_max_secs);
-        println!(
-            "  - Packetization: ≤ {:.1}ms per 100 files",
-            thresholds.packetization_max_ms_per_100_files
+    // Packet transformers ([packet.transforms])
+    if config.packet.transforms.manifests {
+        config_map.insert("packet_transform_manifests".to_string(), "true".to_string());
+    }
+    if config.packet.transforms.lockfiles {
+        config_map.insert("packet_transform_lockfiles".to_string(), "true".to_string());
+    }
+    if config.packet.transforms.openapi {
+        config_map.insert("packet_transform_openapi".to_string(), "true".to_string());
+    }
+
+    // Packet memory budget ([packet])
+    if let Some(max_memory_bytes) = config.packet.max_memory_bytes {
+        config_map.insert(
+            "packet_max_memory_bytes".to_string(),
+            max_memory_bytes.to_string(),
+        );
+    }
+    if let Some(spill_threshold_bytes) = config.packet.spill_threshold_bytes {
+        config_map.insert(
+            "packet_spill_threshold_bytes".to_string(),
+            spill_threshold_bytes.to_string(),
         );
-        if let Some(max_rss) = thresholds.max_rss_mb {
-            println!("  - RSS memory: ≤ {max_rss:.1}MB");
-        }
-        if let Some(max_commit) = thresholds.max_commit_mb {
-            println!("  - Commit memory: ≤ {max_commit:.1}MB");
-        }
-        println!();
+    }
+    if let Some(non_utf8) = &config.packet.non_utf8 {
+        config_map.insert("packet_non_utf8".to_string(), non_utf8.clone());
     }
 
-    // Create benchmark configuration
-    let config = BenchmarkConfig {
-        file_count,
-        file_size_bytes: file_size,
-        iterations,
-        verbose: verbose && !json, // Suppress verbose output in JSON mode
-        thresholds,
-    };
+    // Context-overflow packet shrinking ([packet])
+    if let Some(factor) = config.packet.overflow_shrink_factor {
+        config_map.insert(
+            "packet_overflow_shrink_factor".to_string(),
+            factor.to_string(),
+        );
+    }
+    if let Some(retries) = config.packet.overflow_retries {
+        config_map.insert("packet_overflow_retries".to_string(), retries.to_string());
+    }
 
-    if verbose && !json {
-        println!("Benchmark configuration:");
-        println!("  File count: {}", config.file_count);
-        println!("  File size: {} bytes", config.file_size_bytes);
-        println!("  Iterations: {}", config.iterations);
-        println!();
+    // Final phase composition ([final])
+    if let Some(final_mode) = &config.final_.mode {
+        config_map.insert("final_mode".to_string(), final_mode.clone());
+    }
+    if let Some(final_template) = &config.final_.template {
+        config_map.insert("final_template".to_string(), final_template.clone());
+    }
+    if !config.final_.languages.is_empty() {
+        config_map.insert(
+            "final_languages".to_string(),
+            config.final_.languages.join(","),
+        );
     }
 
-    // Create and run benchmark
-    let runner = BenchmarkRunner::new(config);
-    let results = runner
-        .run_all_benchmarks()
-        .context("Failed to run benchmarks")?;
+    // Output sinks regenerated on run completion ([outputs])
+    if let Some(badge) = &config.outputs.badge {
+        config_map.insert("output_badge".to_string(), badge.clone());
+    }
+    if config.outputs.embed_config_snapshot {
+        config_map.insert(
+            "output_embed_config_snapshot".to_string(),
+            "true".to_string(),
+        );
+    }
+    if config.outputs.front_matter {
+        config_map.insert("output_front_matter".to_string(), "true".to_string());
+    }
 
-    // Output results
-    if json {
-        // Emit structured JSON output (FR-BENCH-004)
-        // Use JCS canonicalization for consistent JSON output (FR-CLI-6)
-        use serde_json::json;
+    // Experimental features, recorded in receipt flags ([features])
+    let features = config.features.enabled();
+    if !features.is_empty() {
+        config_map.insert("features".to_string(), features.join(","));
+    }
 
-        let json_output = json!({
-            "ok": results.ok,
-            "timings_ms": results.timings_ms,
-            "rss_mb": results.rss_mb,
-            "commit_mb": results.commit_mb,
-            "violations": results.violations,
-            "config": {
-                "file_count": file_count,
-                "file_size_bytes": file_size,
-                "iterations": iterations,
-            },
-            "thresholds": {
-                "empty_run_max_secs": runner.config.thresholds.empty_run_max_secs,
-                "packetization_max_ms_per_100_files": runner.config.thresholds.packetization_max_ms_per_100_files,
-                "max_rss_mb": runner.config.thresholds.max_rss_mb,
-                "max_commit_mb": runner.config.thresholds.max_commit_mb,
-            }
-        });
+    // License/compliance scanner for packet contents ([compliance])
+    if let Some(mode) = &config.compliance.mode {
+        config_map.insert("compliance_mode".to_string(), mode.clone());
+    }
+    if !config.compliance.patterns.is_empty() {
+        config_map.insert(
+            "compliance_patterns".to_string(),
+            config.compliance.patterns.join("\n"),
+        );
+    }
+    if !config.compliance.ignore_patterns.is_empty() {
+        config_map.insert(
+            "compliance_ignore_patterns".to_string(),
+            config.compliance.ignore_patterns.join("\n"),
+        );
+    }
 
-        let canonical_json = emit_jcs(&json_output).context("Failed to emit benchmark JSON")?;
-        println!("{canonical_json}");
-    } else {
-        // Print human-readable results
-        runner.print_summary(&results);
+    // Secret-bearing files recognized by path ([redaction.paths])
+    if !config.redaction.paths.exclude.is_empty() {
+        config_map.insert(
+            "redaction_paths_exclude".to_string(),
+            config.redaction.paths.exclude.join("\n"),
+        );
+    }
+    if !config.redaction.paths.mask.is_empty() {
+        config_map.insert(
+            "redaction_paths_mask".to_string(),
+            config.redaction.paths.mask.join("\n"),
+        );
     }
 
-    // Exit with appropriate code based on results
-    if results.ok {
-        if !json {
-            println!("\n✓ All performance targets met!");
-        }
-        Ok(())
-    } else {
-        if !json {
-            println!("\n✗ Some performance targets not met.");
-        }
-        std::process::exit(1);
+    // Quota for per-spec context/ files ([context_retention])
+    if let Some(max_bytes) = config.context_retention.max_bytes {
+        config_map.insert(
+            "context_retention_max_bytes".to_string(),
+            max_bytes.to_string(),
+        );
+    }
+    if let Some(max_files) = config.context_retention.max_files {
+        config_map.insert(
+            "context_retention_max_files".to_string(),
+            max_files.to_string(),
+        );
     }
-}
 
-/// Execute the doctor command for environment health checks
-fn execute_doctor_command(json: bool, strict_exit: bool, config: &Config) -> Result<()> {
-    use crate::doctor::DoctorCommand;
+    if let Some(runner_mode) = &config.runner.mode {
+        config_map.insert("runner_mode".to_string(), runner_mode.clone());
+    }
 
-    // Create and run doctor command (wired through Doctor::run)
-    let mut doctor = DoctorCommand::new(config.clone());
+    if let Some(runner_distro) = &config.runner.distro {
+        config_map.insert("runner_distro".to_string(), runner_distro.clone());
+    }
 
-    // Show spinner if interactive TTY and not JSON mode (RAII ensures cleanup on panic)
-    let spinner_guard = if !json && std::io::stdout().is_terminal() {
-        Some(SpinnerGuard::new())
-    } else {
-        None
-    };
+    if let Some(claude_path) = &config.runner.claude_path {
+        config_map.insert("claude_path".to_string(), claude_path.clone());
+    }
 
-    let result = doctor.run_with_options_strict(strict_exit);
+    if let Some(provider) = &config.llm.provider {
+        config_map.insert("llm_provider".to_string(), provider.clone());
+    }
 
-    // Explicitly drop spinner to clear the line before printing results
-    drop(spinner_guard);
+    if let Some(fallback_provider) = &config.llm.fallback_provider {
+        config_map.insert(
+            "llm_fallback_provider".to_string(),
+            fallback_provider.clone(),
+        );
+    }
 
-    let output = result.context("Failed to run doctor checks")?;
+    if let Some(execution_strategy) = &config.llm.execution_strategy {
+        config_map.insert("execution_strategy".to_string(), execution_strategy.clone());
+    }
 
-    if json {
-        // Emit as canonical JSON (JCS) for stable diffs (FR-CLI-6)
-        // Use emit_jcs for consistent canonicalization with receipts/status
-        let json_output = emit_jcs(&output).context("Failed to emit doctor JSON")?;
-        println!("{json_output}");
-    } else {
-        // Use log_doctor_report for human-readable output (wired into logging)
-        crate::logging::log_doctor_report(&output);
+    if let Some(prompt_template) = &config.llm.prompt_template {
+        config_map.insert("prompt_template".to_string(), prompt_template.clone());
+    }
 
-        if !output.ok {
-            println!();
-            if strict_exit {
-                println!(
-                    "Some checks failed or warned (strict mode). Please address the issues above."
-                );
-            } else {
-                println!(
-                    "Some checks failed. Please address the issues above before using xchecker."
-                );
-            }
+    if let Some(claude_config) = &config.llm.claude
+        && let Some(binary) = &claude_config.binary
+    {
+        config_map.insert("llm_claude_binary".to_string(), binary.clone());
+    }
+
+    if let Some(gemini_config) = &config.llm.gemini {
+        if let Some(binary) = &gemini_config.binary {
+            config_map.insert("llm_gemini_binary".to_string(), binary.clone());
+        }
+        if let Some(default_model) = &gemini_config.default_model {
+            config_map.insert(
+                "llm_gemini_default_model".to_string(),
+                default_model.clone(),
+            );
         }
     }
 
-    // Exit with non-zero code if any check failed (R5.6)
-    // In strict mode, warnings also cause non-zero exit
-    if !output.ok {
-        std::process::exit(1);
+    // Add new CLI arguments (R7.2, R7.4, R9.2)
+    if !cli_args.allow.is_empty() {
+        config_map.insert("allowed_tools".to_string(), cli_args.allow.join(","));
     }
 
-    Ok(())
-}
+    if !cli_args.deny.is_empty() {
+        config_map.insert("disallowed_tools".to_string(), cli_args.deny.join(","));
+    }
 
-/// Execute the gate command for policy-based spec validation
-/// Per FR-GATE (Requirements 4.5.1, 4.5.2, 4.5.3, 4.5.4)
-fn execute_gate_command(
-    spec_id: &str,
-    policy_path: Option<&std::path::Path>,
-    min_phase: Option<&str>,
-    fail_on_pending_fixups: bool,
-    max_phase_age: Option<&str>,
-    json: bool,
-) -> Result<()> {
-    use xchecker_gate::{
-        GateCommand, GatePolicy, emit_gate_json, load_policy_from_path, parse_duration,
-        parse_phase, resolve_policy_path,
-    };
+    if cli_args.dangerously_skip_permissions {
+        config_map.insert(
+            "dangerously_skip_permissions".to_string(),
+            "true".to_string(),
+        );
+    }
 
-    let policy_path = resolve_policy_path(policy_path).map_err(|e| {
-        XCheckerError::Config(ConfigError::InvalidValue {
-            key: "policy".to_string(),
-            value: e.to_string(),
-        })
-    })?;
+    if !cli_args.ignore_secret_pattern.is_empty() {
+        config_map.insert(
+            "ignore_secret_patterns".to_string(),
+            cli_args.ignore_secret_pattern.join("|"),
+        );
+    }
 
-    let mut policy = if let Some(path) = policy_path {
-        load_policy_from_path(&path).map_err(|e| {
-            XCheckerError::Config(ConfigError::InvalidValue {
-                key: "policy".to_string(),
-                value: e.to_string(),
-            })
-        })?
-    } else {
-        GatePolicy::default()
-    };
+    if !cli_args.extra_secret_pattern.is_empty() {
+        config_map.insert(
+            "extra_secret_patterns".to_string(),
+            cli_args.extra_secret_pattern.join("|"),
+        );
+    }
 
-    if let Some(min_phase) = min_phase {
-        policy.min_phase = Some(parse_phase(min_phase).map_err(|e| {
-            XCheckerError::Config(ConfigError::InvalidValue {
-                key: "min_phase".to_string(),
-                value: e.to_string(),
-            })
-        })?);
+    // Add debug_packet flag (FR-PKT-006, FR-PKT-007) for CLI-only overrides
+    if cli_args.debug_packet {
+        config_map.insert("debug_packet".to_string(), "true".to_string());
     }
 
-    if fail_on_pending_fixups {
-        policy.fail_on_pending_fixups = true;
+    // Prompt debugging: --prompt-only implies --dump-prompt
+    if cli_args.dump_prompt || cli_args.prompt_only {
+        config_map.insert("dump_prompt".to_string(), "true".to_string());
+    }
+    if cli_args.prompt_only {
+        config_map.insert("prompt_only".to_string(), "true".to_string());
     }
 
-    if let Some(age_str) = max_phase_age {
-        policy.max_phase_age = Some(parse_duration(age_str).map_err(|e| {
-            XCheckerError::Config(ConfigError::InvalidValue {
-                key: "max_phase_age".to_string(),
-                value: e.to_string(),
-            })
-        })?);
+    // Opt out of context redaction (permission already checked during config discovery)
+    if cli_args.raw_context {
+        config_map.insert("raw_context".to_string(), "true".to_string());
     }
 
-    // Execute gate evaluation
-    let gate = GateCommand::new(spec_id.to_string(), policy);
-    let result = gate
-        .execute()
-        .with_context(|| format!("Failed to evaluate gate for spec: {spec_id}"))?;
+    // Deliberate failure for resilience tests and chaos drills (hidden flag)
+    if let Some(point) = &cli_args.inject_failure {
+        config_map.insert("inject_failure".to_string(), point.clone());
+    }
 
-    // Output results
-    if json {
-        let json_output =
-            emit_gate_json(&result, spec_id).with_context(|| "Failed to emit gate JSON")?;
-        println!("{json_output}");
-    } else {
-        // Human-friendly output
-        if result.passed {
-            println!("✓ {}", result.summary);
-        } else {
-            println!("✗ {}", result.summary);
-        }
+    // Retries with the key of a completed run reuse its receipt
+    if let Some(key) = &cli_args.idempotency_key {
+        config_map.insert("idempotency_key".to_string(), key.clone());
+    }
 
-        println!();
-        println!("Conditions evaluated:");
-        for condition in &result.conditions {
-            let status = if condition.passed { "✓" } else { "✗" };
-            println!("  {} {}: {}", status, condition.name, condition.description);
-            if let Some(actual) = &condition.actual {
-                println!("      Actual: {}", actual);
-            }
-            if let Some(expected) = &condition.expected {
-                println!("      Expected: {}", expected);
-            }
-        }
+    config_map
+}
 
-        if !result.failure_reasons.is_empty() {
-            println!();
-            println!("Failure reasons:");
-            for reason in &result.failure_reasons {
-                println!("  - {}", reason);
-            }
-        }
+/// Build an OrchestratorConfig from CLI parameters.
+///
+/// This helper reduces duplication between execute_spec_command and execute_resume_command
+/// by combining create_default_config with the common additional parameters.
+///
+/// # Arguments
+/// * `dry_run` - Whether to run in simulation mode
+/// * `verbose` - Enable verbose logging
+/// * `apply_fixups` - Whether to apply fixups (true) or preview (false)
+/// * `config` - The loaded xchecker configuration
+/// * `cli_args` - CLI arguments passed by the user
+/// * `problem_statement` - Optional problem statement to include in phase prompts
+fn build_orchestrator_config(
+    dry_run: bool,
+    verbose: bool,
+    apply_fixups: bool,
+    config: &Config,
+    cli_args: &CliArgs,
+    problem_statement: Option<&str>,
+    redactor: Arc<SecretRedactor>,
+) -> OrchestratorConfig {
+    let mut config_map = create_default_config(verbose, config, cli_args);
+    config_map.insert("logger_enabled".to_string(), verbose.to_string());
+    config_map.insert("apply_fixups".to_string(), apply_fixups.to_string());
+
+    // Include problem statement in config for prompt construction (FR-PKT)
+    if let Some(ps) = problem_statement {
+        config_map.insert("problem_statement".to_string(), ps.to_string());
     }
 
-    // Exit with appropriate code
-    if result.passed {
-        Ok(())
-    } else {
-        std::process::exit(crate::gate::exit_codes::POLICY_VIOLATION);
+    OrchestratorConfig {
+        dry_run,
+        config: config_map,
+        full_config: Some(config.clone()),
+        selectors: Some(config.selectors.clone()),
+        strict_validation: config.strict_validation(),
+        redactor,
+        hooks: Some(config.hooks.clone()),
+        llm_backend: None,
     }
 }
 
-/// Execute the init command to initialize a spec with optional lockfile
-fn execute_init_command(spec_id: &str, create_lock: bool, config: &Config) -> Result<()> {
-    use crate::lock::{FlowLock, XCheckerLock};
+/// Enhance error reporting for common failure scenarios
+fn enhance_error_context(error: &anyhow::Error) -> Option<Vec<String>> {
+    let error_str = error.to_string();
 
-    println!("{}", styled_info(&format!("Initializing spec: {spec_id}")));
+    if error_str.contains("Failed to create orchestrator") {
+        Some(vec![
+            "Check that the current directory is writable".to_string(),
+            "Ensure sufficient disk space is available".to_string(),
+            "Verify directory permissions".to_string(),
+            "Try running from a different directory".to_string(),
+        ])
+    } else if error_str.contains("Failed to execute") {
+        Some(vec![
+            "Check the spec ID is valid and doesn't contain special characters".to_string(),
+            "Verify Claude CLI is installed and accessible".to_string(),
+            "Try running with --dry-run to test configuration".to_string(),
+            "Check your internet connection if using Claude API".to_string(),
+        ])
+    } else if error_str.contains("Permission denied") {
+        Some(vec![
+            "Check file and directory permissions".to_string(),
+            "Ensure you have write access to the current directory".to_string(),
+            "Try running from your home directory or a writable location".to_string(),
+        ])
+    } else if error_str.contains("No such file or directory") {
+        Some(vec![
+            "Verify the specified paths exist".to_string(),
+            "Check that you're running from the correct directory".to_string(),
+            "Ensure all required files are present".to_string(),
+        ])
+    } else {
+        None
+    }
+}
 
-    // Create spec directory structure
-    let spec_dir = PathBuf::from(".xchecker").join("specs").join(spec_id);
-    let artifacts_dir = spec_dir.join("artifacts");
-    let receipts_dir = spec_dir.join("receipts");
-    let context_dir = spec_dir.join("context");
+/// Execute the test command for integration validation
+fn execute_test_command(components: bool, smoke: bool, verbose: bool) -> Result<()> {
+    use crate::integration_tests;
 
-    // Check if spec already exists
-    if spec_dir.exists() {
-        println!("  Spec directory already exists: {}", spec_dir.display());
+    if verbose {
+        println!("Running integration tests...");
+    }
 
-    } else {
-        // Create directory structure (ignore benign races)
-        crate::paths::ensure_dir_all(&artifacts_dir).with_context(|| {
-            format!(
-                "Failed to create artifacts directory: {}",
-                artifacts_dir.display()
-            )
-        })?;
-        crate::paths::ensure_dir_all(&receipts_dir).with_context(|| {
-            format!(
-                "Failed to create receipts directory: {}",
-                receipts_dir.display()
-            )
-        })?;
-        crate::paths::ensure_dir_all(&context_dir).with_context(|| {
-            format!(
-                "Failed to create context directory: {}",
-                context_dir.display()
-            )
-        })?;
+    // If no specific test type is specified, run both
+    let run_components = components || !smoke;
+    let run_smoke = smoke || !components;
 
-        println!(
-            "  {} Created spec directory: {}",
-            styled_check(),
-            spec_dir.display()
-        );
-        println!("  {} Created artifacts directory", styled_check());
-        println!("  {} Created receipts directory", styled_check());
-        println!("  {} Created context directory", styled_check());
+    if run_components {
+        integration_tests::validate_component_integration()
+            .with_context(|| "Component integration validation failed")?;
     }
 
-    // Create lockfile if requested
-    if create_lock {
-        let lock_path = spec_dir.join("lock.json");
-        let flow_lock_path = spec_dir.join("flow.lock");
+    if run_smoke {
+        integration_tests::run_smoke_tests().with_context(|| "Smoke tests failed")?;
+    }
 
-        // Get model from config or use default
-        let model = config.defaults.model.as_deref().unwrap_or("haiku");
+    println!("✓ All integration tests passed successfully");
+    Ok(())
+}
 
-        // Get Claude CLI version (we'll need to detect this - for now use a placeholder)
-        // In a real implementation, this would call `claude --version` and parse the output
-        let claude_cli_version =
-            detect_claude_cli_version().unwrap_or_else(|_| "unknown".to_string());
+/// Execute the hidden bench command (per-stage throughput on a user directory)
+fn execute_bench_command(dir: &std::path::Path, samples: usize, warmup: usize) -> Result<()> {
+    use crate::benchmark::throughput::{ThroughputConfig, run_throughput};
 
-        let mut created_any_lock = false;
+    let root = camino::Utf8PathBuf::try_from(dir.to_path_buf()).map_err(|_| {
+        anyhow::anyhow!("Benchmark directory is not valid UTF-8: {}", dir.display())
+    })?;
+    let config = ThroughputConfig {
+        samples,
+        warmup,
+        ..ThroughputConfig::new(root)
+    };
 
-        if lock_path.exists() {
-            println!("  Lockfile already exists: {}", lock_path.display());
-        } else {
-            let lock = XCheckerLock::new(model.to_string(), claude_cli_version.clone());
-            lock.save(spec_id)
-                .with_context(|| "Failed to save lockfile")?;
+    let report = run_throughput(&config).context("Failed to run throughput benchmarks")?;
+    let canonical_json = emit_jcs(&report).context("Failed to emit bench JSON")?;
+    println!("{canonical_json}");
+    Ok(())
+}
 
-            println!("  {} Created lockfile: lock.json", styled_check());
-            println!("    Model: {model}");
-            println!("    Claude CLI version: {claude_cli_version}");
-            println!("    Schema version: 1");
-            created_any_lock = true;
-        }
+/// Execute the benchmark command (NFR1 validation)
+#[allow(clippy::too_many_arguments)]
+fn execute_benchmark_command(
+    file_count: usize,
+    file_size: usize,
+    iterations: usize,
+    json: bool,
+    max_empty_run_secs: Option<f64>,
+    max_packetization_ms: Option<f64>,
+    max_rss_mb: Option<f64>,
+    max_commit_mb: Option<f64>,
+    verbose: bool,
+) -> Result<()> {
+    use crate::benchmark::{BenchmarkConfig, BenchmarkRunner, BenchmarkThresholds};
 
-        if flow_lock_path.exists() {
-            println!("  Flow lock already exists: {}", flow_lock_path.display());
-        } else {
-            let flow_lock = FlowLock::new(build_flow_context(config));
-            flow_lock
-                .save(spec_id)
-                .with_context(|| "Failed to save flow lock")?;
+    // Build custom thresholds if any overrides provided
+    let mut thresholds = BenchmarkThresholds::default();
+    if let Some(max_secs) = max_empty_run_secs {
+        thresholds.empty_run_max_secs = max_secs;
+    }
+    if let Some(max_ms) = max_packetization_ms {
+        thresholds.packetization_max_ms_per_100_files = max_ms;
+    }
+    if let Some(max_rss) = max_rss_mb {
+        thresholds.max_rss_mb = Some(max_rss);
+    }
+    if let Some(max_commit) = max_commit_mb {
+        thresholds.max_commit_mb = Some(max_commit);
+    }
 
-            println!("  {} Created flow lock: flow.lock", styled_check());
-            println!("    Flow version: {}", flow_lock.flow_version);
-            println!("    Stage graph: {}", flow_lock.stage_graph_version);
-            println!("    Gate set: {}", flow_lock.gate_set_version);
-            println!("    Prompt pack: {}", flow_lock.prompt_pack_version);
-            println!("    Adapter: {}", flow_lock.adapter_version);
-            println!("    Provider policy: {}", flow_lock.provider_policy);
-            created_any_lock = true;
+    // Only print header if not in JSON mode
+    if !json {
+        println!("=== xchecker Performance Benchmark ===");
+        println!("Validating NFR1 performance targets:");
+        println!("  - Empty run: ≤ {:.3}s", thresholds.empty_run_max_secs);
+        println!(
+            "  - Packetization: ≤ {:.1}ms per 100 files",
+            thresholds.packetization_max_ms_per_100_files
+        );
+        if let Some(max_rss) = thresholds.max_rss_mb {
+            println!("  - RSS memory: ≤ {max_rss:.1}MB");
         }
-
-        println!("\n  Lockfiles will track drift for:");
-        println!("    - Legacy runtime drift: model, Claude CLI version, schema version");
-        println!("    - Governed flow drift: stage graph, gates, prompt pack, adapter, policy");
-        if !created_any_lock {
-            println!("  {} No new lockfiles created", styled_warning());
-            println!("    Delete the existing lock files first if you need to re-pin them");
+        if let Some(max_commit) = thresholds.max_commit_mb {
+            println!("  - Commit memory: ≤ {max_commit:.1}MB");
         }
-        println!("\n  Use --strict-lock flag to hard fail on drift detection");
-    } else {
-        println!("\n  No lockfiles created (use --create-lock to pin runtime and flow contracts)");
+        println!();
     }
 
-    println!(
-        "\n{}",
-        styled_success(&format!("Spec '{spec_id}' initialized successfully"))
-    );
-    println!("  Directory: {}", spec_dir.display());
-
-    println!("\nNext steps:");
-    println!("  1. Create your problem statement:");
-    println!("     echo \"Your problem description\" | xchecker spec {spec_id}");
-    println!("  2. Or resume from existing source:");
-    println!("     xchecker resume {spec_id} --phase requirements");
-
-    Ok(())
-}
+    // Create benchmark configuration
+    let config = BenchmarkConfig {
+        file_count,
+        file_size_bytes: file_size,
+        iterations,
+        verbose: verbose && !json, // Suppress verbose output in JSON mode
+        thresholds,
+    };
 
-/// Detect Claude CLI version by running `claude --version`
-fn detect_claude_cli_version() -> Result<String> {
-    use crate::runner::CommandSpec;
+    if verbose && !json {
+        println!("Benchmark configuration:");
+        println!("  File count: {}", config.file_count);
+        println!("  File size: {} bytes", config.file_size_bytes);
+        println!("  Iterations: {}", config.iterations);
+        println!();
+    }
 
-    let output = CommandSpec::new("claude")
-        .arg("--version")
-        .to_command()
-        .output()
-        .context("Failed to execute 'claude --version'")?;
+    // Create and run benchmark
+    let runner = BenchmarkRunner::new(config);
+    let results = runner
+        .run_all_benchmarks()
+        .context("Failed to run benchmarks")?;
 
-    if !output.status.success() {
-        return Err(anyhow::anyhow!(
-            "claude --version exited with non-zero status"
-        ));
-    }
+    // Output results
+    if json {
+        // Emit structured JSON output (FR-BENCH-004)
+        // Use JCS canonicalization for consistent JSON output (FR-CLI-6)
+        use serde_json::json;
 
-    let version_str = String::from_utf8(output.stdout)
-        .context("Failed to parse claude --version output as UTF-8")?;
+        let json_output = json!({
+            "ok": results.ok,
+            "timings_ms": results.timings_ms,
+            "rss_mb": results.rss_mb,
+            "commit_mb": results.commit_mb,
+            "violations": results.violations,
+            "config": {
+                "file_count": file_count,
+                "file_size_bytes": file_size,
+                "iterations": iterations,
+            },
+            "thresholds": {
+                "empty_run_max_secs": runner.config.thresholds.empty_run_max_secs,
+                "packetization_max_ms_per_100_files": runner.config.thresholds.packetization_max_ms_per_100_files,
+                "max_rss_mb": runner.config.thresholds.max_rss_mb,
+                "max_commit_mb": runner.config.thresholds.max_commit_mb,
+            }
+        });
 
-    // Parse version from output (format: "claude 0.8.1" or similar)
-    let version = version_str
-        .split_whitespace()
-        .last()
-        .ok_or_else(|| anyhow::anyhow!("Failed to parse version from output"))?
-        .to_string();
+        let canonical_json = emit_jcs(&json_output).context("Failed to emit benchmark JSON")?;
+        println!("{canonical_json}");
+    } else {
+        // Print human-readable results
+        runner.print_summary(&results);
+    }
 
-    Ok(version)
+    // Exit with appropriate code based on results
+    if results.ok {
+        if !json {
+            println!("\n✓ All performance targets met!");
+        }
+        Ok(())
+    } else {
+        if !json {
+            println!("\n✗ Some performance targets not met.");
+        }
+        std::process::exit(1);
+    }
 }
 
-fn build_flow_context(config: &Config) -> crate::lock::FlowContext {
-    let execution_strategy = config
-        .llm
-        .execution_strategy
-        .as_deref()
-        .unwrap_or("controlled");
-    let provider = config.llm.provider.as_deref().unwrap_or("unspecified");
-    let prompt_template = config.llm.prompt_template.as_deref().unwrap_or("default");
-    let model = config.defaults.model.as_deref().unwrap_or("haiku");
+/// Execute the self-update command
+#[cfg(all(feature = "self-update", not(feature = "no-telemetry")))]
+async fn execute_self_update_command(channel: &str, check: bool, force: bool) -> Result<()> {
+    use crate::self_update::{self, Channel, UpdateOutcome};
 
-    crate::lock::FlowContext {
-        flow_version: format!("xchecker-kernel@{}", env!("CARGO_PKG_VERSION")),
-        stage_graph_version: "requirements-design-tasks-review-fixup-final.v1".to_string(),
-        gate_set_version: "xchecker-default-gates.v1".to_string(),
-        prompt_pack_version: format!("builtin:{prompt_template}"),
-        adapter_version: format!("strategy={execution_strategy};provider={provider}"),
-        provider_policy: format!("provider={provider};model={model}"),
-        tool_context_version: "builtin-tool-context.v1".to_string(),
+    let channel = Channel::parse(channel)?;
+    let spinner = console::Spinner::start("Checking for updates...");
+    let outcome = self_update::run(channel, check, force).await;
+    drop(spinner);
+
+    match outcome.context("Self-update failed")? {
+        UpdateOutcome::UpToDate { current } => {
+            println!("xchecker {current} is up to date");
+        }
+        UpdateOutcome::Available { current, latest } => {
+            println!("xchecker {latest} is available (installed: {current})");
+            println!("Run 'xchecker self-update' to install it.");
+        }
+        UpdateOutcome::Updated { from, to } => {
+            println!(
+                "{} Updated xchecker {from} → {to} (signature and checksum verified)",
+                console::check_mark(Stream::Stdout)
+            );
+        }
     }
+    Ok(())
 }
 
-/// Check for lockfile drift and warn or fail based on `strict_lock` flag
-fn check_lockfile_drift(
+/// Execute the self-update command in builds without the `self-update` feature
+/// or with the `no-telemetry` feature
+#[cfg(not(all(feature = "self-update", not(feature = "no-telemetry"))))]
+async fn execute_self_update_command(_channel: &str, _check: bool, _force: bool) -> Result<()> {
+    Err(XCheckerError::Config(ConfigError::InvalidValue {
+        key: "self-update".to_string(),
+        value: "disabled in this build; update xchecker through your package manager".to_string(),
+    })
+    .into())
+}
+
+/// Execute the export-findings command
+async fn execute_export_findings_command(
     spec_id: &str,
-    strict_lock: bool,
-    model_full_name: &str,
-    claude_cli_version: &str,
-    config: &Config,
-) -> Result<Option<crate::types::LockDrift>> {
-    use crate::lock::{FlowLock, RunContext, XCheckerLock};
+    to: &str,
+    repo: Option<&str>,
+    labels: &[String],
+    dry_run: bool,
+) -> Result<()> {
+    use crate::export_findings::{self, ExportTarget};
 
-    // Try to load lockfile
-    let lock = match XCheckerLock::load(spec_id) {
-        Ok(Some(lock)) => Some(lock),
-        Ok(None) => None,
-        Err(e) => {
-            eprintln!("⚠ Warning: Failed to load lockfile: {e}");
-            None
+    let target = ExportTarget::parse(to)?;
+    let artifacts = crate::paths::spec_root(spec_id).join("artifacts");
+    let review_path = artifacts.join(PhaseId::Review.artifact_name("md"));
+    if !compression::exists(review_path.as_std_path()) {
+        return Err(XCheckerError::Config(ConfigError::NotFound {
+            path: format!("Review artifact: {review_path} (run the review phase first)"),
+        })
+        .into());
+    }
+    let review = compression::read_to_string(review_path.as_std_path())
+        .with_context(|| format!("Failed to read {review_path}"))?;
+
+    let findings = crate::extraction::extract_review_findings(&review);
+    if findings.is_empty() {
+        println!("No findings in the review for spec '{spec_id}'; nothing to export");
+        return Ok(());
+    }
+
+    match target {
+        ExportTarget::GithubIssues => {
+            let payloads = export_findings::issue_payloads(spec_id, &findings, labels);
+            if dry_run {
+                println!("{}", serde_json::to_string_pretty(&payloads)?);
+                return Ok(());
+            }
+
+            file_finding_issues(repo, &payloads).await?;
         }
-    };
+        ExportTarget::MarkdownChecklist => {
+            let checklist = export_findings::render_checklist(&findings);
+            if dry_run {
+                print!("{checklist}");
+                return Ok(());
+            }
 
-    let legacy_drift = if let Some(lock) = lock {
-        let context = RunContext {
-            model_full_name: model_full_name.to_string(),
-            claude_cli_version: claude_cli_version.to_string(),
-            schema_version: "1".to_string(),
-        };
-        lock.detect_drift(&context)
-    } else {
-        None
+            let checklist_path = export_findings::checklist_path(&artifacts);
+            write_file_atomic(&checklist_path, &checklist)
+                .with_context(|| format!("Failed to write {checklist_path}"))?;
+            println!(
+                "{} Wrote {} finding(s) to {checklist_path}",
+                console::check_mark(Stream::Stdout),
+                findings.len()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// File the exported issues in `repo` with the GitHub token from the environment
+#[cfg(not(feature = "no-telemetry"))]
+async fn file_finding_issues(
+    repo: Option<&str>,
+    payloads: &[crate::export_findings::IssuePayload],
+) -> Result<()> {
+    let repo = repo.ok_or_else(|| {
+        XCheckerError::Config(ConfigError::InvalidValue {
+            key: "repo".to_string(),
+            value: "--repo owner/name is required to file issues (or use --dry-run)".to_string(),
+        })
+    })?;
+    let token = std::env::var("GITHUB_TOKEN")
+        .or_else(|_| std::env::var("GH_TOKEN"))
+        .map_err(|_| {
+            XCheckerError::Config(ConfigError::InvalidValue {
+                key: "GITHUB_TOKEN".to_string(),
+                value: "set GITHUB_TOKEN or GH_TOKEN to file issues".to_string(),
+            })
+        })?;
+
+    let spinner =
+        console::Spinner::start(format!("Filing {} issue(s) in {repo}...", payloads.len()));
+    let created = crate::export_findings::file_issues(repo, &token, payloads).await;
+    drop(spinner);
+    for url in created? {
+        println!("{} {url}", console::check_mark(Stream::Stdout));
+    }
+    Ok(())
+}
+
+/// Filing issues is compiled out of `no-telemetry` builds
+#[cfg(feature = "no-telemetry")]
+async fn file_finding_issues(
+    _repo: Option<&str>,
+    _payloads: &[crate::export_findings::IssuePayload],
+) -> Result<()> {
+    Err(XCheckerError::Config(ConfigError::InvalidValue {
+        key: "export-findings".to_string(),
+        value:
+            "filing issues is disabled in this build (no-telemetry); use --dry-run to print them"
+                .to_string(),
+    })
+    .into())
+}
+
+/// Execute the sync-tasks command
+#[cfg(not(feature = "no-telemetry"))]
+async fn execute_sync_tasks_command(
+    spec_id: &str,
+    action: &str,
+    tracker: Option<&str>,
+    repo: Option<&str>,
+    project: Option<&str>,
+    dry_run: bool,
+) -> Result<()> {
+    use crate::task_sync::{self, TrackerClient, TrackerKind, TrackerSync};
+
+    let artifacts = crate::paths::spec_root(spec_id).join("artifacts");
+    let tasks_path = artifacts.join(PhaseId::Tasks.artifact_name("md"));
+    let core_path = artifacts.join(PhaseId::Tasks.artifact_name("core.yaml"));
+    if !compression::exists(tasks_path.as_std_path())
+        || !compression::exists(core_path.as_std_path())
+    {
+        return Err(XCheckerError::Config(ConfigError::NotFound {
+            path: format!("Tasks artifacts in {artifacts} (run the tasks phase first)"),
+        })
+        .into());
+    }
+    let existing = task_sync::load_sync(&artifacts)?;
+    let invalid = |key: &str, value: String| {
+        XCheckerError::Config(ConfigError::InvalidValue {
+            key: key.to_string(),
+            value,
+        })
     };
 
-    let flow_drift = match FlowLock::load(spec_id) {
-        Ok(Some(lock)) => lock.detect_drift(&build_flow_context(config)),
-        Ok(None) => None,
-        Err(e) => {
-            eprintln!("⚠ Warning: Failed to load flow lock: {e}");
-            None
+    if action == "pull" {
+        let mut sync = existing.ok_or_else(|| {
+            invalid(
+                "sync-tasks",
+                format!("no tasks of spec '{spec_id}' are linked yet; run 'sync-tasks {spec_id} push' first"),
+            )
+        })?;
+        let client = TrackerClient::from_env(sync.tracker, &sync.target)?;
+        let spinner = console::Spinner::start(format!(
+            "Pulling {} item(s) from {} {}...",
+            sync.items.len(),
+            sync.tracker.as_str(),
+            sync.target
+        ));
+        let mut result = Ok(());
+        for item in &mut sync.items {
+            match client.is_done(&item.key).await {
+                Ok(done) => item.done = done,
+                Err(e) => {
+                    result = Err(e);
+                    break;
+                }
+            }
+        }
+        drop(spinner);
+        result?;
+
+        sync.pulled_at = Some(chrono::Utc::now());
+        task_sync::save_sync(&artifacts, &sync)?;
+        println!(
+            "{} {}/{} linked tasks done",
+            console::check_mark(Stream::Stdout),
+            sync.done_count(),
+            sync.items.len()
+        );
+        return Ok(());
+    }
+
+    // push: the tracker and target are fixed by the first push
+    let kind = match (tracker.map(TrackerKind::parse).transpose()?, &existing) {
+        (Some(kind), Some(sync)) if kind != sync.tracker => {
+            return Err(invalid(
+                "tracker",
+                format!(
+                    "tasks are already linked to {}; remove {} to switch",
+                    sync.tracker.as_str(),
+                    task_sync::sync_path(&artifacts)
+                ),
+            )
+            .into());
         }
+        (_, Some(sync)) => sync.tracker,
+        (Some(kind), None) => kind,
+        (None, None) => TrackerKind::Github,
+    };
+    let target = match (&existing, kind) {
+        (Some(sync), _) => sync.target.clone(),
+        (None, TrackerKind::Github) => repo
+            .ok_or_else(|| {
+                invalid(
+                    "repo",
+                    "--repo owner/name is required for the first GitHub push".to_string(),
+                )
+            })?
+            .to_string(),
+        (None, TrackerKind::Jira) => project
+            .ok_or_else(|| {
+                invalid(
+                    "project",
+                    "--project KEY is required for the first Jira push".to_string(),
+                )
+            })?
+            .to_string(),
     };
 
-    if let Some(drift) = &legacy_drift {
-        eprintln!("\n⚠ Lockfile drift detected for spec '{spec_id}':");
+    let tasks_md = compression::read_to_string(tasks_path.as_std_path())
+        .with_context(|| format!("Failed to read {tasks_path}"))?;
+    let tasks = task_sync::parse_tasks(&tasks_md);
+    let unlinked = task_sync::unlinked_tasks(&tasks, existing.as_ref());
+    if unlinked.is_empty() {
+        println!(
+            "All {} task(s) of spec '{spec_id}' are already linked to {} {target}",
+            tasks.len(),
+            kind.as_str()
+        );
+        return Ok(());
+    }
+    if dry_run {
+        println!(
+            "Would create {} item(s) in {} {target}:",
+            unlinked.len(),
+            kind.as_str()
+        );
+        for task in &unlinked {
+            println!("  {} {}", task.number, task.title);
+        }
+        return Ok(());
+    }
 
-        if let Some(model_drift) = &drift.model_full_name {
-            eprintln!("  Model: {} → {}", model_drift.locked, model_drift.current);
+    let client = TrackerClient::from_env(kind, &target)?;
+    let mut sync = existing.unwrap_or_else(|| TrackerSync {
+        tracker: kind,
+        target: target.clone(),
+        pushed_at: chrono::Utc::now(),
+        pulled_at: None,
+        items: Vec::new(),
+    });
+    let spinner = console::Spinner::start(format!(
+        "Creating {} item(s) in {} {target}...",
+        unlinked.len(),
+        kind.as_str()
+    ));
+    let mut result = Ok(());
+    let mut created = Vec::new();
+    for task in unlinked {
+        match client.create(spec_id, task).await {
+            Ok(item) => created.push(item),
+            Err(e) => {
+                result =
+                    Err(e.context(format!("Failed to create an item for task {}", task.number)));
+                break;
+            }
         }
+    }
+    drop(spinner);
 
-        if let Some(cli_drift) = &drift.claude_cli_version {
-            eprintln!("  Claude CLI: {} → {}", cli_drift.locked, cli_drift.current);
+    // Record what was created even on failure so a retry never duplicates items
+    if !created.is_empty() {
+        sync.pushed_at = chrono::Utc::now();
+        sync.items.extend(created.iter().cloned());
+        task_sync::save_sync(&artifacts, &sync)?;
+    }
+    for item in &created {
+        println!(
+            "{} {} {}",
+            console::check_mark(Stream::Stdout),
+            item.task,
+            item.url
+        );
+    }
+    result
+}
+
+/// Execute the sync-tasks command in `no-telemetry` builds, which have no tracker clients
+#[cfg(feature = "no-telemetry")]
+async fn execute_sync_tasks_command(
+    _spec_id: &str,
+    _action: &str,
+    _tracker: Option<&str>,
+    _repo: Option<&str>,
+    _project: Option<&str>,
+    _dry_run: bool,
+) -> Result<()> {
+    Err(XCheckerError::Config(ConfigError::InvalidValue {
+        key: "sync-tasks".to_string(),
+        value: "disabled in this build (no-telemetry), which has no issue-tracker clients"
+            .to_string(),
+    })
+    .into())
+}
+
+/// Execute the doctor command for environment health checks
+async fn execute_doctor_command(
+    json: bool,
+    strict_exit: bool,
+    measure_samples: Option<usize>,
+    require_no_telemetry: bool,
+    verbose: bool,
+    config: &Config,
+) -> Result<()> {
+    use crate::doctor::{BuildFlavor, DoctorCommand};
+
+    // Create and run doctor command (wired through Doctor::run)
+    let mut doctor = DoctorCommand::new(config.clone()).with_build_flavor(BuildFlavor {
+        network_subsystems: crate::NETWORK_SUBSYSTEMS.to_vec(),
+        require_telemetry_free: require_no_telemetry,
+    });
+
+    // Show spinner unless JSON mode (RAII ensures cleanup on panic)
+    let spinner_guard = (!json).then(|| console::Spinner::start("Running health checks..."));
+
+    let result = match measure_samples {
+        Some(samples) => doctor.run_with_measurement(strict_exit, samples).await,
+        None => doctor.run_with_options_strict(strict_exit),
+    };
+
+    // Explicitly drop spinner to clear the line before printing results
+    drop(spinner_guard);
+
+    let output = result.context("Failed to run doctor checks")?;
+
+    if json {
+        // Emit as canonical JSON (JCS) for stable diffs (FR-CLI-6)
+        // Use emit_jcs for consistent canonicalization with receipts/status
+        let json_output = emit_jcs(&output).context("Failed to emit doctor JSON")?;
+        println!("{json_output}");
+    } else {
+        // Use log_doctor_report for human-readable output (wired into logging)
+        crate::logging::log_doctor_report(&output);
+
+        if verbose && !output.measurements.is_empty() {
+            println!("\nProvider round-trip latency:");
+            for measurement in &output.measurements {
+                print!(
+                    "{}",
+                    crate::doctor::measure::render_latency_histogram(measurement)
+                );
+            }
         }
 
-        if let Some(schema_drift) = &drift.schema_version {
-            eprintln!(
-                "  Schema: {} → {}",
-                schema_drift.locked, schema_drift.current
-            );
-        }
+        if !output.ok {
+            println!();
+            if strict_exit {
+                println!(
+                    "Some checks failed or warned (strict mode). Please address the issues above."
+                );
+            } else {
+                println!(
+                    "Some checks failed. Please address the issues above before using xchecker."
+                );
+            }
+        }
+    }
+
+    // Exit with non-zero code if any check failed (R5.6)
+    // In strict mode, warnings also cause non-zero exit
+    if !output.ok {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// Execute the gate command for policy-based spec validation
+/// Per FR-GATE (Requirements 4.5.1, 4.5.2, 4.5.3, 4.5.4)
+fn execute_gate_command(
+    spec_id: &str,
+    policy_path: Option<&std::path::Path>,
+    min_phase: Option<&str>,
+    fail_on_pending_fixups: bool,
+    max_phase_age: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    use xchecker_gate::{
+        GateCommand, GatePolicy, emit_gate_json, load_policy_from_path, parse_duration,
+        parse_phase, resolve_policy_path,
+    };
+
+    let policy_path = resolve_policy_path(policy_path).map_err(|e| {
+        XCheckerError::Config(ConfigError::InvalidValue {
+            key: "policy".to_string(),
+            value: e.to_string(),
+        })
+    })?;
+
+    let mut policy = if let Some(path) = policy_path {
+        load_policy_from_path(&path).map_err(|e| {
+            XCheckerError::Config(ConfigError::InvalidValue {
+                key: "policy".to_string(),
+                value: e.to_string(),
+            })
+        })?
+    } else {
+        GatePolicy::default()
+    };
+
+    if let Some(min_phase) = min_phase {
+        policy.min_phase = Some(parse_phase(min_phase).map_err(|e| {
+            XCheckerError::Config(ConfigError::InvalidValue {
+                key: "min_phase".to_string(),
+                value: e.to_string(),
+            })
+        })?);
+    }
+
+    if fail_on_pending_fixups {
+        policy.fail_on_pending_fixups = true;
+    }
+
+    if let Some(age_str) = max_phase_age {
+        policy.max_phase_age = Some(parse_duration(age_str).map_err(|e| {
+            XCheckerError::Config(ConfigError::InvalidValue {
+                key: "max_phase_age".to_string(),
+                value: e.to_string(),
+            })
+        })?);
+    }
+
+    // Execute gate evaluation
+    let gate = GateCommand::new(spec_id.to_string(), policy);
+    let result = gate
+        .execute()
+        .with_context(|| format!("Failed to evaluate gate for spec: {spec_id}"))?;
+
+    // Output results
+    if json {
+        let json_output =
+            emit_gate_json(&result, spec_id).with_context(|| "Failed to emit gate JSON")?;
+        println!("{json_output}");
+    } else {
+        // Human-friendly output
+        if result.passed {
+            println!("{} {}", console::check_mark(Stream::Stdout), result.summary);
+        } else {
+            println!("{} {}", console::cross_mark(Stream::Stdout), result.summary);
+        }
+
+        println!();
+        println!("Conditions evaluated:");
+        for condition in &result.conditions {
+            let status = if condition.passed {
+                console::check_mark(Stream::Stdout)
+            } else {
+                console::cross_mark(Stream::Stdout)
+            };
+            println!("  {} {}: {}", status, condition.name, condition.description);
+            if let Some(actual) = &condition.actual {
+                println!("      Actual: {}", actual);
+            }
+            if let Some(expected) = &condition.expected {
+                println!("      Expected: {}", expected);
+            }
+        }
+
+        if !result.failure_reasons.is_empty() {
+            println!();
+            println!("Failure reasons:");
+            for reason in &result.failure_reasons {
+                println!("  - {}", reason);
+            }
+        }
+    }
+
+    // Exit with appropriate code
+    if result.passed {
+        Ok(())
+    } else {
+        std::process::exit(crate::gate::exit_codes::POLICY_VIOLATION);
+    }
+}
+
+/// Execute the changelog-fragment command
+///
+/// Prints a Keep-a-Changelog style entry describing the spec's outcome.
+fn execute_changelog_fragment_command(spec_id: &str) -> Result<()> {
+    use crate::receipt::ReceiptManager;
+
+    let base_path = crate::paths::spec_root(spec_id);
+    let receipts = ReceiptManager::new(&base_path)
+        .list_receipts()
+        .unwrap_or_default();
+
+    if !receipts.iter().any(|r| r.exit_code == 0) {
+        return Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "spec_id".to_string(),
+            value: format!("no successful phase receipts found for spec '{spec_id}'"),
+        })
+        .into());
+    }
+
+    let summary = latest_artifact_summary(base_path.join("artifacts").as_std_path());
+    print!(
+        "{}",
+        render_changelog_fragment(spec_id, &receipts, summary.as_deref())
+    );
+
+    Ok(())
+}
+
+/// Execute the badge command
+///
+/// Writes the SVG to `out` when given, then prints either the Markdown
+/// snippet (`--markdown`) or, with no `out`, the SVG itself.
+/// Execute the hooks install command
+fn execute_hooks_install_command(config: &Config, hooks: &[String], force: bool) -> Result<()> {
+    use crate::git_hooks::{self, GitHook};
+
+    let requested: Vec<&str> = if !hooks.is_empty() {
+        hooks.iter().map(String::as_str).collect()
+    } else if !config.git_hooks.install.is_empty() {
+        config
+            .git_hooks
+            .install
+            .iter()
+            .map(String::as_str)
+            .collect()
+    } else {
+        vec![GitHook::PreCommit.as_str()]
+    };
+
+    let hooks_dir = git_hooks::hooks_dir(Path::new("."))?;
+    for name in requested {
+        let hook: GitHook = name.parse()?;
+        let (path, replaced) = git_hooks::install(&hooks_dir, hook, force)?;
+        println!(
+            "{} {} {}",
+            console::check_mark(Stream::Stdout),
+            if replaced { "Updated" } else { "Installed" },
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Execute the hooks run command
+///
+/// Prints every finding, then exits with `SECRET_DETECTED` if any secret was
+/// found or with the gate's policy-violation code for receipt mismatches.
+fn execute_hooks_run_command(config: &Config, hook: &str, redactor: &SecretRedactor) -> Result<()> {
+    use crate::git_hooks::{self, GitHook, HookFinding};
+    use std::io::Read;
+
+    let hook: GitHook = hook.parse()?;
+    let mut push_input = String::new();
+    if hook == GitHook::PrePush {
+        std::io::stdin()
+            .read_to_string(&mut push_input)
+            .context("Failed to read pre-push input")?;
+    }
+
+    let repo = Path::new(".");
+    let changed = git_hooks::changed_files(repo, hook, &push_input)?;
+    let specs_dir = crate::paths::xchecker_home().join("specs");
+    let spec_ids = git_hooks::touched_specs(&changed, &specs_dir);
+
+    let mut findings = Vec::new();
+    if config.git_hooks.secret_scan.unwrap_or(true) {
+        let to_scan = if config.git_hooks.scan_all_changed {
+            changed
+        } else {
+            git_hooks::spec_files(changed, &specs_dir)
+        };
+        findings.extend(git_hooks::scan_secrets(repo, redactor, &to_scan)?);
+    }
+    if config.git_hooks.verify_receipts.unwrap_or(true) {
+        for spec_id in &spec_ids {
+            findings.extend(git_hooks::verify_receipts(
+                spec_id,
+                &crate::paths::spec_root(spec_id),
+            )?);
+        }
+    }
+
+    if findings.is_empty() {
+        println!(
+            "{} xchecker {hook}: no secrets; {} spec(s) consistent with their receipts",
+            console::check_mark(Stream::Stdout),
+            spec_ids.len()
+        );
+        return Ok(());
+    }
+
+    eprintln!("xchecker {hook} blocked:");
+    for finding in &findings {
+        eprintln!("  {} {finding}", console::cross_mark(Stream::Stderr));
+    }
+    if findings
+        .iter()
+        .any(|f| matches!(f, HookFinding::Secret { .. }))
+    {
+        std::process::exit(crate::exit_codes::codes::SECRET_DETECTED);
+    }
+    std::process::exit(crate::gate::exit_codes::POLICY_VIOLATION);
+}
+
+/// Execute the config diff command
+fn execute_config_diff_command(config: &Config, json: bool) -> Result<()> {
+    let diff = config.diff_from_defaults()?;
+
+    if json {
+        println!(
+            "{}",
+            emit_jcs(&serde_json::json!({ "schema_version": "1", "overrides": diff }))?
+        );
+        return Ok(());
+    }
+
+    if diff.is_empty() {
+        println!("Effective configuration matches built-in defaults.");
+        return Ok(());
+    }
+
+    let unset = "(unset)".to_string();
+    let rows: Vec<[&str; 4]> = diff
+        .iter()
+        .map(|entry| {
+            [
+                entry.key.as_str(),
+                entry.default.as_ref().unwrap_or(&unset).as_str(),
+                entry.value.as_ref().unwrap_or(&unset).as_str(),
+                entry.source.as_str(),
+            ]
+        })
+        .collect();
+    let header = ["KEY", "DEFAULT", "VALUE", "SOURCE"];
+    let widths: Vec<usize> = (0..3)
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .chain([header[col].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for row in std::iter::once(header).chain(rows) {
+        println!(
+            "{:<w0$}  {:<w1$}  {:<w2$}  {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+        );
+    }
+
+    Ok(())
+}
+
+fn execute_models_list_command(config: &Config, provider: Option<&str>, json: bool) -> Result<()> {
+    use crate::llm::models::{provider_models, resolve_model};
+
+    let provider =
+        provider.unwrap_or_else(|| config.llm.provider.as_deref().unwrap_or("claude-cli"));
+    let models = provider_models(provider);
+    let default_model = config
+        .defaults
+        .model
+        .as_deref()
+        .filter(|model| !model.is_empty())
+        .map(|model| resolve_model(provider, model).name);
+
+    if json {
+        println!(
+            "{}",
+            emit_jcs(&serde_json::json!({
+                "schema_version": "1",
+                "provider": provider,
+                "default_model": default_model,
+                "models": models,
+            }))?
+        );
+        return Ok(());
+    }
+
+    if models.is_empty() {
+        println!(
+            "No models registered for provider '{provider}'; model names are passed through unchanged."
+        );
+        return Ok(());
+    }
+
+    println!("Models for provider '{provider}':");
+    for model in &models {
+        let marker = if default_model.as_deref() == Some(model.name) {
+            "*"
+        } else {
+            " "
+        };
+        let aliases = if model.aliases.is_empty() {
+            String::new()
+        } else {
+            format!(" (aliases: {})", model.aliases.join(", "))
+        };
+        let status = match (model.sunset, model.replacement) {
+            (Some(sunset), Some(replacement)) => {
+                format!(" [deprecated: retired {sunset}, use {replacement}]")
+            }
+            (Some(sunset), None) => format!(" [deprecated: retired {sunset}]"),
+            _ => String::new(),
+        };
+        println!("{marker} {}{aliases}{status}", model.name);
+    }
+
+    Ok(())
+}
+
+/// Reject `xchecker queue` unless the experimental `daemon` feature is on
+fn require_daemon_feature(config: &Config) -> Result<()> {
+    if config.features.is_enabled("daemon") {
+        return Ok(());
+    }
+    Err(XCheckerError::Config(ConfigError::InvalidValue {
+        key: "features.daemon".to_string(),
+        value: "`xchecker queue` requires the experimental daemon feature; pass --enable-feature daemon or set `daemon = true` under [features]".to_string(),
+    })
+    .into())
+}
+
+/// Execute the queue add command
+fn execute_queue_add_command(
+    config: &Config,
+    spec_id: &str,
+    phases: &[String],
+    priority: &str,
+) -> Result<()> {
+    use crate::queue::{JobPriority, JobQueue};
+
+    require_daemon_feature(config)?;
+    let priority = JobPriority::parse(priority)?;
+    let phases = if phases.is_empty() {
+        vec![PhaseId::Requirements, PhaseId::Design, PhaseId::Tasks]
+    } else {
+        phases
+            .iter()
+            .map(|phase| parse_phase_name(phase))
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    let id = JobQueue::update(|queue| queue.enqueue(spec_id, &phases, priority))?;
+    println!(
+        "{} Queued job {id}: {spec_id} ({}) at {} priority",
+        console::check_mark(Stream::Stdout),
+        phases
+            .iter()
+            .map(|phase| phase.as_str())
+            .collect::<Vec<_>>()
+            .join(", "),
+        priority.as_str()
+    );
+    Ok(())
+}
+
+/// Execute the queue status command
+fn execute_queue_status_command(config: &Config, json: bool) -> Result<()> {
+    use crate::queue::JobQueue;
+
+    require_daemon_feature(config)?;
+    let queue = JobQueue::load()?;
+    if json {
+        println!("{}", emit_jcs(&queue)?);
+        return Ok(());
+    }
+
+    if queue.jobs.is_empty() {
+        println!("Queue is empty");
+        return Ok(());
+    }
+    for job in &queue.jobs {
+        let remaining = if job.pending.is_empty() {
+            String::new()
+        } else {
+            format!(" next: {}", job.pending.join(", "))
+        };
+        let preempted = match job.preemptions {
+            0 => String::new(),
+            n => format!(" (preempted {n}x)"),
+        };
+        println!(
+            "{:>4}  {:<9} {:<6} {}{remaining}{preempted}",
+            job.id,
+            job.state.as_str(),
+            job.priority.as_str(),
+            job.spec_id
+        );
+        if let Some(error) = &job.error {
+            println!("      error: {error}");
+        }
+    }
+    Ok(())
+}
+
+/// Execute the queue run command
+///
+/// Claims one phase at a time so a higher-priority job queued meanwhile
+/// preempts the running one at the next phase boundary. With `watch`, waits
+/// for new jobs instead of returning when the queue is empty.
+async fn execute_queue_run_command(
+    dry_run: bool,
+    watch: Option<std::time::Duration>,
+    verbose: bool,
+    config: &Config,
+    cli_args: &CliArgs,
+    redactor: &Arc<SecretRedactor>,
+) -> Result<()> {
+    use crate::queue::JobQueue;
+
+    require_daemon_feature(config)?;
+    // Held until the worker returns; a second worker would re-claim running jobs
+    let _worker = JobQueue::acquire_worker()?;
+    let orchestrator_config = build_orchestrator_config(
+        dry_run,
+        verbose,
+        false,
+        config,
+        cli_args,
+        None,
+        redactor.clone(),
+    );
+
+    loop {
+        let Some(claimed) = JobQueue::update(JobQueue::claim_next)? else {
+            match watch {
+                Some(interval) => {
+                    tokio::time::sleep(interval).await;
+                    continue;
+                }
+                None => break,
+            }
+        };
+        if let Some(paused) = claimed.preempted {
+            println!(
+                "Paused job {paused} for higher-priority job {}",
+                claimed.job_id
+            );
+        }
+        println!(
+            "Job {} ({}): running {} phase",
+            claimed.job_id,
+            claimed.spec_id,
+            claimed.phase.as_str()
+        );
+
+        let outcome = match OrchestratorHandle::with_config_and_force(
+            &claimed.spec_id,
+            orchestrator_config.clone(),
+            false,
+        ) {
+            Ok(mut handle) => match handle.run_phase(claimed.phase).await {
+                Ok(result) if result.success => Ok(()),
+                Ok(result) => Err(result.error.map_or_else(
+                    || format!("exit code {}", result.exit_code),
+                    |error| redactor.redact_string(&error),
+                )),
+                Err(e) => Err(redactor.redact_string(&format!("{e:#}"))),
+            },
+            Err(e) => Err(redactor.redact_string(&format!("{e:#}"))),
+        };
+        match &outcome {
+            Ok(()) => println!(
+                "{} Job {}: {} phase completed",
+                console::check_mark(Stream::Stdout),
+                claimed.job_id,
+                claimed.phase.as_str()
+            ),
+            Err(error) => println!(
+                "Job {}: {} phase failed: {error}",
+                claimed.job_id,
+                claimed.phase.as_str()
+            ),
+        }
+        JobQueue::update(|queue| queue.finish_phase(claimed.job_id, outcome))?;
+    }
+
+    println!("Queue is empty");
+    Ok(())
+}
+
+fn execute_badge_command(
+    spec_id: &str,
+    out: Option<&std::path::Path>,
+    markdown: bool,
+) -> Result<()> {
+    use crate::receipt::ReceiptManager;
+    use crate::status::badge::{Badge, write_badge};
+
+    let base_path = crate::paths::spec_root(spec_id);
+    if !base_path.exists() {
+        return Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "spec_id".to_string(),
+            value: format!("spec '{spec_id}' does not exist"),
+        })
+        .into());
+    }
+
+    let receipts = ReceiptManager::new(&base_path)
+        .list_receipts()
+        .unwrap_or_default();
+
+    if let Some(out) = out {
+        let out_path = camino::Utf8PathBuf::try_from(out.to_path_buf())
+            .map_err(|_| anyhow::anyhow!("Badge path is not valid UTF-8: {}", out.display()))?;
+        write_badge(&out_path, &receipts)?;
+        if !markdown {
+            println!("Wrote badge for spec '{spec_id}' to {out_path}");
+        }
+    }
+
+    let badge = Badge::from_receipts(&receipts);
+    if markdown {
+        let image = out.map(|path| path.display().to_string());
+        println!("{}", badge.to_markdown(spec_id, image.as_deref()));
+    } else if out.is_none() {
+        print!("{}", badge.to_svg());
+    }
+
+    Ok(())
+}
+
+/// Execute the migrate-receipts command
+fn execute_migrate_receipts_command(spec_id: &str, dry_run: bool) -> Result<()> {
+    use crate::receipt::ReceiptManager;
+
+    let base_path = crate::paths::spec_root(spec_id);
+    if !base_path.exists() {
+        return Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "spec_id".to_string(),
+            value: format!("spec '{spec_id}' does not exist"),
+        })
+        .into());
+    }
+
+    let report = ReceiptManager::new(&base_path).migrate_receipts(spec_id, dry_run)?;
+
+    let verb = if dry_run { "Would migrate" } else { "Migrated" };
+    println!(
+        "{verb} {} receipt(s) for spec '{spec_id}' ({} already current)",
+        report.migrated.len(),
+        report.current
+    );
+    for name in &report.migrated {
+        println!("  ~ {name}");
+    }
+    if let Some(backup) = &report.backup_path {
+        println!("Originals backed up to {backup}");
+    }
+    for (name, reason) in &report.failed {
+        eprintln!("  ✗ {name}: {reason}");
+    }
+
+    if !report.failed.is_empty() {
+        return Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "receipts".to_string(),
+            value: format!("{} receipt(s) could not be migrated", report.failed.len()),
+        })
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Execute the migrate-artifacts command
+fn execute_migrate_artifacts_command(spec_id: &str, dry_run: bool) -> Result<()> {
+    use crate::status::artifact::ArtifactManager;
+
+    if !crate::paths::spec_root(spec_id).exists() {
+        return Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "spec_id".to_string(),
+            value: format!("spec '{spec_id}' does not exist"),
+        })
+        .into());
+    }
+
+    let renames = ArtifactManager::new(spec_id)?.migrate_artifact_numbering(dry_run)?;
+    if renames.is_empty() {
+        println!("Artifacts for spec '{spec_id}' already match the phase order");
+        return Ok(());
+    }
+
+    let verb = if dry_run { "Would rename" } else { "Renamed" };
+    println!("{verb} {} artifact(s) for spec '{spec_id}'", renames.len());
+    for rename in &renames {
+        println!("  {} -> {}", rename.from, rename.to);
+    }
+
+    Ok(())
+}
+
+/// Execute the export-site command
+fn execute_export_site_command(spec_id: &str, out: &Path, config: &Config) -> Result<()> {
+    use crate::status::site::export_site;
+
+    let base_path = crate::paths::spec_root(spec_id);
+    if !base_path.exists() {
+        return Err(XCheckerError::Config(ConfigError::InvalidValue {
+            key: "spec_id".to_string(),
+            value: format!("spec '{spec_id}' does not exist"),
+        })
+        .into());
+    }
+
+    let out_path = camino::Utf8PathBuf::try_from(out.to_path_buf())
+        .map_err(|_| anyhow::anyhow!("Output path is not valid UTF-8: {}", out.display()))?;
+    let health = spec_health(spec_id, config);
+    let written = export_site(spec_id, &base_path, &out_path, health.as_ref())?;
+    println!(
+        "Exported spec '{spec_id}' to {out_path} ({} files)",
+        written.len()
+    );
+
+    Ok(())
+}
+
+/// Execute the diff-receipts command
+fn execute_diff_receipts_command(a: &Path, b: &Path, json: bool) -> Result<()> {
+    use crate::receipt::{VOLATILE_FIELDS, diff_receipts};
+
+    let read = |path: &Path| -> Result<serde_json::Value> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read receipt {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse receipt {}", path.display()))
+    };
+    let diffs = diff_receipts(&read(a)?, &read(b)?);
+
+    if json {
+        println!(
+            "{}",
+            emit_jcs(&serde_json::json!({
+                "schema_version": "1",
+                "ignored": VOLATILE_FIELDS,
+                "differences": diffs,
+            }))?
+        );
+        return Ok(());
+    }
+
+    if diffs.is_empty() {
+        println!("Receipts match (ignoring {}).", VOLATILE_FIELDS.join(", "));
+        return Ok(());
+    }
+
+    let show = |value: &Option<serde_json::Value>| match value {
+        None => "(absent)".to_string(),
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    };
+    let mut category = None;
+    for diff in &diffs {
+        if category != Some(diff.category) {
+            category = Some(diff.category);
+            println!("[{}]", diff.category.as_str());
+        }
+        println!("  {}", diff.path);
+        println!("    - {}", show(&diff.left));
+        println!("    + {}", show(&diff.right));
+    }
+    println!("{} field(s) differ", diffs.len());
+
+    Ok(())
+}
+
+/// Execute the explain command
+///
+/// Shows the latest failed receipt's error and its suggested actions. Receipts
+/// written before `suggested_actions` existed are explained from the same
+/// remediation table.
+fn execute_explain_command(spec_id: &str, phase: Option<PhaseId>, json: bool) -> Result<()> {
+    use crate::receipt::ReceiptManager;
+
+    let base_path = crate::paths::spec_root(spec_id);
+    let receipts = ReceiptManager::new(&base_path)
+        .list_receipts()
+        .unwrap_or_default();
+
+    let failed = receipts
+        .iter()
+        .rev()
+        .find(|r| r.exit_code != 0 && phase.is_none_or(|phase| r.phase == phase.as_str()));
+    let Some(receipt) = failed else {
+        let scope = phase.map_or_else(String::new, |p| format!(" in the {} phase", p.as_str()));
+        if json {
+            println!(
+                "{}",
+                emit_jcs(&serde_json::json!({ "spec_id": spec_id, "failure": null }))?
+            );
+        } else {
+            println!("No failed runs recorded for spec '{spec_id}'{scope}.");
+        }
+        return Ok(());
+    };
+
+    let actions = if receipt.suggested_actions.is_empty() {
+        receipt.remediation()
+    } else {
+        receipt.suggested_actions.clone()
+    };
+    let provider = receipt.llm.as_ref().and_then(|llm| llm.provider.clone());
+
+    if json {
+        let output = serde_json::json!({
+            "spec_id": spec_id,
+            "failure": {
+                "phase": receipt.phase,
+                "emitted_at": receipt.emitted_at,
+                "exit_code": receipt.exit_code,
+                "error_kind": receipt.error_kind,
+                "error_reason": receipt.error_reason,
+                "provider": provider,
+                "suggested_actions": actions,
+            },
+        });
+        println!("{}", emit_jcs(&output)?);
+        return Ok(());
+    }
+
+    println!(
+        "Latest failure for spec '{spec_id}': {} phase at {}",
+        receipt.phase,
+        receipt.emitted_at.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    let kind = receipt.error_kind.as_ref().map_or_else(
+        || "unknown".to_string(),
+        |kind| {
+            serde_json::to_value(kind)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_string))
+                .unwrap_or_else(|| format!("{kind:?}"))
+        },
+    );
+    println!("  Exit code:  {} ({kind})", receipt.exit_code);
+    if let Some(provider) = &provider {
+        println!("  Provider:   {provider}");
+    }
+    if let Some(reason) = &receipt.error_reason {
+        println!("  Reason:     {reason}");
+    }
+    if !actions.is_empty() {
+        println!("\nSuggested actions:");
+        for (i, action) in actions.iter().enumerate() {
+            println!("  {}. {action}", i + 1);
+        }
+    }
+
+    Ok(())
+}
+
+/// Extract the first prose paragraph from the most advanced Markdown artifact
+///
+/// Artifacts are numbered by phase (`00-requirements.md`, `10-design.md`, ...),
+/// so the highest-numbered one reflects the spec's final state.
+fn latest_artifact_summary(artifacts_dir: &std::path::Path) -> Option<String> {
+    let mut markdown: Vec<(String, PathBuf)> = std::fs::read_dir(artifacts_dir)
+        .ok()?
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let file_name = entry.file_name();
+            let name = compression::logical_name(file_name.to_str()?);
+            (name.ends_with(".md") && !name.ends_with(".partial.md"))
+                .then(|| (name.to_string(), entry.path()))
+        })
+        .collect();
+    markdown.sort();
+
+    let content = compression::read_to_string(&markdown.last()?.1).ok()?;
+    let paragraph = content
+        .split("\n\n")
+        .map(str::trim)
+        .find(|block| {
+            !block.is_empty()
+                && !block.starts_with('#')
+                && !block.starts_with("```")
+                && !block.starts_with('-')
+                && !block.starts_with('|')
+        })?
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    const MAX_SUMMARY_CHARS: usize = 240;
+    if paragraph.chars().count() > MAX_SUMMARY_CHARS {
+        let truncated: String = paragraph.chars().take(MAX_SUMMARY_CHARS).collect();
+        Some(format!("{}…", truncated.trim_end()))
+    } else {
+        Some(paragraph)
+    }
+}
+
+/// Render a Keep-a-Changelog entry from a spec's receipts
+///
+/// The entry goes under "Added" on the first successful generation and under
+/// "Changed" when any phase was regenerated. Phases whose latest run failed
+/// are listed so an incomplete spec is not mistaken for a finished one.
+fn render_changelog_fragment(
+    spec_id: &str,
+    receipts: &[crate::types::Receipt],
+    summary: Option<&str>,
+) -> String {
+    use std::collections::BTreeMap;
+    use std::fmt::Write as _;
+
+    let phase_order = |phase: &str| {
+        [
+            "requirements",
+            "design",
+            "tasks",
+            "review",
+            "fixup",
+            "final",
+        ]
+        .iter()
+        .position(|p| *p == phase)
+        .unwrap_or(usize::MAX)
+    };
+
+    // Latest receipt and successful run count per phase
+    let mut latest: BTreeMap<(usize, &str), &crate::types::Receipt> = BTreeMap::new();
+    let mut successes: HashMap<&str, usize> = HashMap::new();
+    for receipt in receipts {
+        let key = (phase_order(&receipt.phase), receipt.phase.as_str());
+        if latest
+            .get(&key)
+            .is_none_or(|existing| receipt.emitted_at >= existing.emitted_at)
+        {
+            latest.insert(key, receipt);
+        }
+        if receipt.exit_code == 0 {
+            *successes.entry(receipt.phase.as_str()).or_default() += 1;
+        }
+    }
+
+    let regenerated = successes.values().any(|count| *count > 1);
+    let section = if regenerated { "Changed" } else { "Added" };
+
+    let completed: Vec<&str> = latest
+        .iter()
+        .filter(|(_, r)| r.exit_code == 0 && !r.is_skipped())
+        .map(|((_, phase), _)| *phase)
+        .collect();
+    let failed: Vec<String> = latest
+        .iter()
+        .filter(|(_, r)| r.exit_code != 0)
+        .map(|((_, phase), r)| format!("{phase} (exit {})", r.exit_code))
+        .collect();
+
+    let mut outputs: Vec<&crate::types::FileHash> = latest
+        .values()
+        .filter(|r| r.exit_code == 0)
+        .flat_map(|r| r.outputs.iter())
+        .filter(|o| o.path.ends_with(".md"))
+        .collect();
+    outputs.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let last_run = latest
+        .values()
+        .map(|r| r.emitted_at)
+        .max()
+        .map(|t| t.format("%Y-%m-%d").to_string());
+
+    let mut out = String::new();
+    let _ = writeln!(out, "### {section}");
+    let _ = writeln!(out);
+    match summary {
+        Some(summary) => {
+            let _ = writeln!(out, "- Spec `{spec_id}`: {summary}");
+        }
+        None => {
+            let _ = writeln!(out, "- Spec `{spec_id}`");
+        }
+    }
+    let _ = writeln!(out, "  - Phases completed: {}", completed.join(", "));
+    if !failed.is_empty() {
+        let _ = writeln!(out, "  - Incomplete: {}", failed.join(", "));
+    }
+    if !outputs.is_empty() {
+        let artifacts: Vec<String> = outputs
+            .iter()
+            .map(|o| {
+                let short = o
+                    .blake3_canonicalized
+                    .get(..8)
+                    .unwrap_or(&o.blake3_canonicalized);
+                format!("`{}` ({short})", o.path)
+            })
+            .collect();
+        let _ = writeln!(out, "  - Artifacts: {}", artifacts.join(", "));
+    }
+    if let Some(date) = last_run {
+        let _ = writeln!(out, "  - Last generated: {date}");
+    }
+
+    out
+}
+
+/// Execute the init command to initialize a spec with optional lockfile
+fn execute_init_command(spec_id: &str, create_lock: bool, config: &Config) -> Result<()> {
+    use crate::lock::{FlowLock, XCheckerLock};
+
+    println!(
+        "{}",
+        console::info(&format!("Initializing spec: {spec_id}"))
+    );
+
+    // Create spec directory structure
+    let spec_dir = PathBuf::from(".xchecker").join("specs").join(spec_id);
+    let artifacts_dir = spec_dir.join("artifacts");
+    let receipts_dir = spec_dir.join("receipts");
+    let context_dir = spec_dir.join("context");
+
+    // Check if spec already exists
+    if spec_dir.exists() {
+        println!("  Spec directory already exists: {}", spec_dir.display());
+    } else {
+        // Create directory structure (ignore benign races)
+        crate::paths::ensure_dir_all(&artifacts_dir).with_context(|| {
+            format!(
+                "Failed to create artifacts directory: {}",
+                artifacts_dir.display()
+            )
+        })?;
+        crate::paths::ensure_dir_all(&receipts_dir).with_context(|| {
+            format!(
+                "Failed to create receipts directory: {}",
+                receipts_dir.display()
+            )
+        })?;
+        crate::paths::ensure_dir_all(&context_dir).with_context(|| {
+            format!(
+                "Failed to create context directory: {}",
+                context_dir.display()
+            )
+        })?;
+
+        println!(
+            "  {} Created spec directory: {}",
+            console::check_mark(Stream::Stdout),
+            spec_dir.display()
+        );
+        println!(
+            "  {} Created artifacts directory",
+            console::check_mark(Stream::Stdout)
+        );
+        println!(
+            "  {} Created receipts directory",
+            console::check_mark(Stream::Stdout)
+        );
+        println!(
+            "  {} Created context directory",
+            console::check_mark(Stream::Stdout)
+        );
+    }
+
+    // Create lockfile if requested
+    if create_lock {
+        let lock_path = spec_dir.join("lock.json");
+        let flow_lock_path = spec_dir.join("flow.lock");
+
+        // Get model from config or use default
+        let model = config.defaults.model.as_deref().unwrap_or("haiku");
+
+        // Get Claude CLI version (we'll need to detect this - for now use a placeholder)
+        // In a real implementation, this would call `claude --version` and parse the output
+        let claude_cli_version =
+            detect_claude_cli_version().unwrap_or_else(|_| "unknown".to_string());
+
+        let mut created_any_lock = false;
+
+        if lock_path.exists() {
+            println!("  Lockfile already exists: {}", lock_path.display());
+        } else {
+            let lock = XCheckerLock::new(model.to_string(), claude_cli_version.clone());
+            lock.save(spec_id)
+                .with_context(|| "Failed to save lockfile")?;
+
+            println!(
+                "  {} Created lockfile: lock.json",
+                console::check_mark(Stream::Stdout)
+            );
+            println!("    Model: {model}");
+            println!("    Claude CLI version: {claude_cli_version}");
+            println!("    Schema version: 1");
+            created_any_lock = true;
+        }
+
+        if flow_lock_path.exists() {
+            println!("  Flow lock already exists: {}", flow_lock_path.display());
+        } else {
+            let flow_lock = FlowLock::new(build_flow_context(config));
+            flow_lock
+                .save(spec_id)
+                .with_context(|| "Failed to save flow lock")?;
+
+            println!(
+                "  {} Created flow lock: flow.lock",
+                console::check_mark(Stream::Stdout)
+            );
+            println!("    Flow version: {}", flow_lock.flow_version);
+            println!("    Stage graph: {}", flow_lock.stage_graph_version);
+            println!("    Gate set: {}", flow_lock.gate_set_version);
+            println!("    Prompt pack: {}", flow_lock.prompt_pack_version);
+            println!("    Adapter: {}", flow_lock.adapter_version);
+            println!("    Provider policy: {}", flow_lock.provider_policy);
+            created_any_lock = true;
+        }
+
+        println!("\n  Lockfiles will track drift for:");
+        println!("    - Legacy runtime drift: model, Claude CLI version, schema version");
+        println!("    - Governed flow drift: stage graph, gates, prompt pack, adapter, policy");
+        if !created_any_lock {
+            println!(
+                "  {} No new lockfiles created",
+                console::warning_mark(Stream::Stdout)
+            );
+            println!("    Delete the existing lock files first if you need to re-pin them");
+        }
+        println!("\n  Use --strict-lock flag to hard fail on drift detection");
+    } else {
+        println!("\n  No lockfiles created (use --create-lock to pin runtime and flow contracts)");
+    }
+
+    println!(
+        "\n{}",
+        console::success(&format!("Spec '{spec_id}' initialized successfully"))
+    );
+    println!("  Directory: {}", spec_dir.display());
+
+    println!("\nNext steps:");
+    println!("  1. Create your problem statement:");
+    println!("     echo \"Your problem description\" | xchecker spec {spec_id}");
+    println!("  2. Or resume from existing source:");
+    println!("     xchecker resume {spec_id} --phase requirements");
+
+    Ok(())
+}
+
+/// Detect Claude CLI version by running `claude --version`
+fn detect_claude_cli_version() -> Result<String> {
+    use crate::runner::CommandSpec;
+
+    let output = CommandSpec::new("claude")
+        .arg("--version")
+        .to_command()
+        .output()
+        .context("Failed to execute 'claude --version'")?;
+
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "claude --version exited with non-zero status"
+        ));
+    }
+
+    let version_str = String::from_utf8(output.stdout)
+        .context("Failed to parse claude --version output as UTF-8")?;
+
+    // Parse version from output (format: "claude 0.8.1" or similar)
+    let version = version_str
+        .split_whitespace()
+        .last()
+        .ok_or_else(|| anyhow::anyhow!("Failed to parse version from output"))?
+        .to_string();
+
+    Ok(version)
+}
+
+fn build_flow_context(config: &Config) -> crate::lock::FlowContext {
+    let execution_strategy = config
+        .llm
+        .execution_strategy
+        .as_deref()
+        .unwrap_or("controlled");
+    let provider = config.llm.provider.as_deref().unwrap_or("unspecified");
+    let prompt_template = config.llm.prompt_template.as_deref().unwrap_or("default");
+    let model = config.defaults.model.as_deref().unwrap_or("haiku");
+
+    crate::lock::FlowContext {
+        flow_version: format!("xchecker-kernel@{}", env!("CARGO_PKG_VERSION")),
+        stage_graph_version: "requirements-design-tasks-review-fixup-final.v1".to_string(),
+        gate_set_version: "xchecker-default-gates.v1".to_string(),
+        prompt_pack_version: format!("builtin:{prompt_template}"),
+        adapter_version: format!("strategy={execution_strategy};provider={provider}"),
+        provider_policy: format!("provider={provider};model={model}"),
+        tool_context_version: "builtin-tool-context.v1".to_string(),
+    }
+}
+
+/// Check for lockfile drift and warn or fail based on `strict_lock` flag
+fn check_lockfile_drift(
+    spec_id: &str,
+    strict_lock: bool,
+    model_full_name: &str,
+    claude_cli_version: &str,
+    config: &Config,
+) -> Result<Option<crate::types::LockDrift>> {
+    use crate::lock::{FlowLock, RunContext, XCheckerLock};
+
+    // Try to load lockfile
+    let lock = match XCheckerLock::load(spec_id) {
+        Ok(Some(lock)) => Some(lock),
+        Ok(None) => None,
+        Err(e) => {
+            eprintln!(
+                "{} Warning: Failed to load lockfile: {e}",
+                console::warning_mark(Stream::Stderr)
+            );
+            None
+        }
+    };
+
+    let legacy_drift = if let Some(lock) = lock {
+        let context = RunContext {
+            model_full_name: model_full_name.to_string(),
+            claude_cli_version: claude_cli_version.to_string(),
+            schema_version: "1".to_string(),
+        };
+        lock.detect_drift(&context)
+    } else {
+        None
+    };
+
+    let flow_drift = match FlowLock::load(spec_id) {
+        Ok(Some(lock)) => lock.detect_drift(&build_flow_context(config)),
+        Ok(None) => None,
+        Err(e) => {
+            eprintln!(
+                "{} Warning: Failed to load flow lock: {e}",
+                console::warning_mark(Stream::Stderr)
+            );
+            None
+        }
+    };
+
+    if let Some(drift) = &legacy_drift {
+        eprintln!(
+            "\n{} Lockfile drift detected for spec '{spec_id}':",
+            console::warning_mark(Stream::Stderr)
+        );
+
+        if let Some(model_drift) = &drift.model_full_name {
+            eprintln!("  Model: {} → {}", model_drift.locked, model_drift.current);
+        }
+
+        if let Some(cli_drift) = &drift.claude_cli_version {
+            eprintln!("  Claude CLI: {} → {}", cli_drift.locked, cli_drift.current);
+        }
+
+        if let Some(schema_drift) = &drift.schema_version {
+            eprintln!(
+                "  Schema: {} → {}",
+                schema_drift.locked, schema_drift.current
+            );
+        }
+    }
+
+    if let Some(drift) = &flow_drift {
+        eprintln!(
+            "\n{} Flow lock drift detected for spec '{spec_id}':",
+            console::warning_mark(Stream::Stderr)
+        );
+
+        if let Some(flow_version) = &drift.flow_version {
+            eprintln!(
+                "  Flow version: {} → {}",
+                flow_version.locked, flow_version.current
+            );
+        }
+        if let Some(stage_graph) = &drift.stage_graph_version {
+            eprintln!(
+                "  Stage graph: {} → {}",
+                stage_graph.locked, stage_graph.current
+            );
+        }
+        if let Some(gate_set) = &drift.gate_set_version {
+            eprintln!("  Gate set: {} → {}", gate_set.locked, gate_set.current);
+        }
+        if let Some(prompt_pack) = &drift.prompt_pack_version {
+            eprintln!(
+                "  Prompt pack: {} → {}",
+                prompt_pack.locked, prompt_pack.current
+            );
+        }
+        if let Some(adapter) = &drift.adapter_version {
+            eprintln!("  Adapter: {} → {}", adapter.locked, adapter.current);
+        }
+        if let Some(provider_policy) = &drift.provider_policy {
+            eprintln!(
+                "  Provider policy: {} → {}",
+                provider_policy.locked, provider_policy.current
+            );
+        }
+        if let Some(tool_context) = &drift.tool_context_version {
+            eprintln!(
+                "  Tool context: {} → {}",
+                tool_context.locked, tool_context.current
+            );
+        }
+    }
+
+    if strict_lock && (legacy_drift.is_some() || flow_drift.is_some()) {
+        eprintln!("\n✗ Strict lock mode enabled: failing due to drift");
+        eprintln!("  To proceed, either:");
+        eprintln!(
+            "    - Update the lockfiles: rm .xchecker/specs/{spec_id}/lock.json .xchecker/specs/{spec_id}/flow.lock && xchecker init {spec_id} --create-lock"
+        );
+        eprintln!("    - Remove --strict-lock flag to allow drift with warning");
+
+        return Err(anyhow::anyhow!("Lock drift detected in strict mode"));
+    }
+
+    if legacy_drift.is_some() || flow_drift.is_some() {
+        eprintln!("\n  Continuing with drift (use --strict-lock to fail on drift)");
+    }
+
+    Ok(legacy_drift)
+}
+
+#[cfg(test)]
+#[allow(clippy::items_after_test_module)] // Test helper functions defined after tests is intentional
+#[allow(clippy::await_holding_lock)] // Test synchronization using mutex guards across awaits is intentional
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::env;
+    use std::sync::{Mutex, MutexGuard, OnceLock};
+    use tempfile::TempDir;
+    use xchecker_utils::test_support::EnvVarGuard;
+
+    // Global lock for tests that mutate process-global CLI state (env vars, cwd).
+    // Any test that uses `TestEnvGuard` or `cli_env_guard()` will be serialized.
+    static CLI_ENV_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+
+    fn cli_env_guard() -> MutexGuard<'static, ()> {
+        CLI_ENV_LOCK.get_or_init(|| Mutex::new(())).lock().unwrap()
+    }
+
+    struct TestEnvGuard {
+        // Hold the lock for the entire lifetime of the guard
+        _lock: MutexGuard<'static, ()>,
+        _temp_dir: TempDir,
+        original_dir: PathBuf,
+        // EnvVarGuard handles save/restore of XCHECKER_HOME automatically on drop
+        _env_guard: EnvVarGuard,
+    }
+
+    impl Drop for TestEnvGuard {
+        fn drop(&mut self) {
+            // Restore cwd while still holding the lock
+            // _env_guard drops after this, restoring XCHECKER_HOME
+            let _ = env::set_current_dir(&self.original_dir);
+            // _lock field drops last, releasing the mutex
+        }
+    }
+
+    fn setup_test_environment() -> TestEnvGuard {
+        // Take the global CLI lock first
+        let lock = cli_env_guard();
+
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+
+        // EnvVarGuard captures original value and will restore on drop
+        let env_guard = EnvVarGuard::cleared("XCHECKER_HOME");
+
+        // From here onwards we're serialized against other CLI tests
+        env::set_current_dir(temp_dir.path()).unwrap();
+
+        TestEnvGuard {
+            _lock: lock,
+            _temp_dir: temp_dir,
+            original_dir,
+            _env_guard: env_guard,
+        }
+    }
+
+    #[test]
+    fn test_create_default_config() {
+        let cli_args = CliArgs::default();
+        let config = Config::discover(&cli_args).unwrap();
+        let config_map = create_default_config(true, &config, &cli_args);
+
+        assert_eq!(config_map.get("verbose"), Some(&"true".to_string()));
+        assert_eq!(
+            config_map.get("packet_max_bytes"),
+            Some(&"65536".to_string())
+        );
+        assert_eq!(
+            config_map.get("packet_max_lines"),
+            Some(&"1200".to_string())
+        );
+    }
+
+    #[test]
+    fn test_create_default_config_no_verbose() {
+        let cli_args = CliArgs::default();
+        let config = Config::discover(&cli_args).unwrap();
+        let config_map = create_default_config(false, &config, &cli_args);
+
+        assert!(!config_map.contains_key("verbose"));
+        assert_eq!(
+            config_map.get("packet_max_bytes"),
+            Some(&"65536".to_string())
+        );
+        assert_eq!(
+            config_map.get("packet_max_lines"),
+            Some(&"1200".to_string())
+        );
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_spec_command_execution() -> anyhow::Result<()> {
+        use tempfile::TempDir;
+
+        // Take the global CLI lock for env/cwd mutations
+        let _lock = cli_env_guard();
+
+        // Setup isolated test root
+        let temp = TempDir::new()?;
+        let root = temp.path();
+
+        // Make it look like a repo root
+        std::fs::create_dir_all(root.join(".git"))?;
+
+        // Use guards that restore env vars on drop (including on panic)
+        let _home_guard = EnvVarGuard::set("XCHECKER_HOME", root.to_str().unwrap());
+        let _skip_guard = EnvVarGuard::set("XCHECKER_SKIP_LLM_TESTS", "1");
+
+        // Save and change CWD
+        let original_dir = std::env::current_dir()?;
+        std::env::set_current_dir(root)?;
+
+        // Create minimal config
+        std::fs::write(
+            root.join("xchecker.toml"),
+            r#"
+[runner]
+runner_mode = "native"
+
+[packet]
+packet_max_bytes = 1048576
+packet_max_lines = 5000
+"#,
+        )?;
+
+        // Create minimal CLI args for dry-run
+        let cli_args = CliArgs::default();
+
+        let config = Config::discover(&cli_args)?;
+        let redactor = Arc::new(SecretRedactor::from_config(&config)?);
+
+        // Create a minimal input file for the spec
+        std::fs::write(root.join("input.txt"), "Test requirement")?;
+
+        // Test dry-run execution (fast, no real LLMs)
+        // This should complete quickly and not hang
+        let result = execute_spec_command(
+            "test-spec",
+            "fs",
+            Some("input.txt"),
+            Some(root.to_str().unwrap()), // repo path
+            None,                         // openapi path
+            true,                         // dry_run = true
+            false,
+            false,
+            false,
+            false,
+            &config,
+            &cli_args,
+            &redactor,
+        )
+        .await;
+
+        // Restore CWD before asserting (env vars restored by guards on drop)
+        let _ = std::env::set_current_dir(&original_dir);
+
+        // In dry-run mode with a valid source, this should succeed
+        // The important thing is it doesn't hang and completes quickly
+        assert!(
+            result.is_ok(),
+            "Dry-run spec execution should succeed: {:?}",
+            result.err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_command_no_spec() {
+        let _temp_dir = setup_test_environment();
+
+        let cli_args = CliArgs::default();
+        let config = Config::discover(&cli_args).unwrap();
+
+        // Test status for non-existent spec
+        let result = execute_status_command("nonexistent-spec", false, &config);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_status_command_with_spec() {
+        let _temp_dir = setup_test_environment();
+
+        let cli_args = CliArgs::default();
+        let config = Config::discover(&cli_args).unwrap();
+
+        // Note: We can't easily test spec creation with stdin in unit tests
+        // This test just verifies status command works with non-existent spec
+        let result = execute_status_command("test-status-spec", false, &config);
+        assert!(result.is_ok());
+    }
+
+    // ===== Spec JSON Output Tests (Task 21.1) =====
+    // **Property: JSON output includes schema version**
+    // **Validates: Requirements 4.1.1**
+
+    #[test]
+    fn test_spec_json_output_schema_version() {
+        // Test that spec JSON output includes schema_version field
+        use crate::types::{PhaseInfo, SpecConfigSummary, SpecOutput};
+
+        let output = SpecOutput {
+            schema_version: "spec-json.v1".to_string(),
+            spec_id: "test-spec".to_string(),
+            phases: vec![PhaseInfo {
+                phase_id: "requirements".to_string(),
+                status: "completed".to_string(),
+                last_run: Some(chrono::Utc::now()),
+            }],
+            config_summary: SpecConfigSummary {
+                execution_strategy: "controlled".to_string(),
+                provider: Some("claude-cli".to_string()),
+                spec_path: ".xchecker/specs/test-spec".to_string(),
+            },
+        };
+
+        // Emit as JSON
+        let json_result = emit_spec_json(&output);
+        assert!(json_result.is_ok(), "Failed to emit spec JSON");
+
+        let json_str = json_result.unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+
+        // Verify schema_version is present and correct
+        assert_eq!(parsed["schema_version"], "spec-json.v1");
+        assert_eq!(parsed["spec_id"], "test-spec");
+    }
+
+    #[test]
+    fn test_spec_json_output_excludes_packet_contents() {
+        // Test that spec JSON output excludes full packet contents
+        // per Requirements 4.1.4
+        use crate::types::{PhaseInfo, SpecConfigSummary, SpecOutput};
+
+        let output = SpecOutput {
+            schema_version: "spec-json.v1".to_string(),
+            spec_id: "test-spec".to_string(),
+            phases: vec![
+                PhaseInfo {
+                    phase_id: "requirements".to_string(),
+                    status: "completed".to_string(),
+                    last_run: None,
+                },
+                PhaseInfo {
+                    phase_id: "design".to_string(),
+                    status: "not_started".to_string(),
+                    last_run: None,
+                },
+            ],
+            config_summary: SpecConfigSummary {
+                execution_strategy: "controlled".to_string(),
+                provider: None,
+                spec_path: ".xchecker/specs/test-spec".to_string(),
+            },
+        };
+
+        let json_result = emit_spec_json(&output);
+        assert!(json_result.is_ok());
+
+        let json_str = json_result.unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+
+        // Verify no packet contents are present
+        assert!(
+            parsed.get("packet").is_none(),
+            "JSON should not contain packet field"
+        );
+        assert!(
+            parsed.get("artifacts").is_none(),
+            "JSON should not contain artifacts field"
+        );
+        assert!(
+            parsed.get("raw_response").is_none(),
+            "JSON should not contain raw_response field"
+        );
+
+        // Verify only expected fields are present
+        assert!(parsed.get("schema_version").is_some());
+        assert!(parsed.get("spec_id").is_some());
+        assert!(parsed.get("phases").is_some());
+        assert!(parsed.get("config_summary").is_some());
+    }
+
+    #[test]
+    fn test_spec_json_command_no_spec() {
+        let _temp_dir = setup_test_environment();
+
+        let cli_args = CliArgs::default();
+        let config = Config::discover(&cli_args).unwrap();
+
+        // Test spec --json for non-existent spec
+        let result = execute_spec_json_command("nonexistent-spec-json", &config);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_spec_json_canonical_format() {
+        // Test that spec JSON output is in canonical JCS format (no extra whitespace)
+        use crate::types::{PhaseInfo, SpecConfigSummary, SpecOutput};
+
+        let output = SpecOutput {
+            schema_version: "spec-json.v1".to_string(),
+            spec_id: "test-spec".to_string(),
+            phases: vec![PhaseInfo {
+                phase_id: "requirements".to_string(),
+                status: "completed".to_string(),
+                last_run: None,
+            }],
+            config_summary: SpecConfigSummary {
+                execution_strategy: "controlled".to_string(),
+                provider: None,
+                spec_path: ".xchecker/specs/test-spec".to_string(),
+            },
+        };
+
+        let json_result = emit_spec_json(&output);
+        assert!(json_result.is_ok());
+
+        let json_str = json_result.unwrap();
+
+        // Verify canonical JSON properties (no extra whitespace, no newlines)
+        assert!(
+            !json_str.contains("  "),
+            "Canonical JSON should not have indentation"
+        );
+        assert!(
+            !json_str.contains('\n'),
+            "Canonical JSON should not have newlines"
+        );
+    }
+
+    #[test]
+    fn test_spec_json_all_phases_present() {
+        // Test that all phases are represented in the output
+        use crate::types::{PhaseInfo, SpecConfigSummary, SpecOutput};
+
+        let phases = vec![
+            PhaseInfo {
+                phase_id: "requirements".to_string(),
+                status: "completed".to_string(),
+                last_run: None,
+            },
+            PhaseInfo {
+                phase_id: "design".to_string(),
+                status: "pending".to_string(),
+                last_run: None,
+            },
+            PhaseInfo {
+                phase_id: "tasks".to_string(),
+                status: "not_started".to_string(),
+                last_run: None,
+            },
+            PhaseInfo {
+                phase_id: "review".to_string(),
+                status: "not_started".to_string(),
+                last_run: None,
+            },
+            PhaseInfo {
+                phase_id: "fixup".to_string(),
+                status: "not_started".to_string(),
+                last_run: None,
+            },
+            PhaseInfo {
+                phase_id: "final".to_string(),
+                status: "not_started".to_string(),
+                last_run: None,
+            },
+        ];
+
+        let output = SpecOutput {
+            schema_version: "spec-json.v1".to_string(),
+            spec_id: "test-spec".to_string(),
+            phases,
+            config_summary: SpecConfigSummary {
+                execution_strategy: "controlled".to_string(),
+                provider: Some("openrouter".to_string()),
+                spec_path: ".xchecker/specs/test-spec".to_string(),
+            },
+        };
+
+        let json_result = emit_spec_json(&output);
+        assert!(json_result.is_ok());
+
+        let json_str = json_result.unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+
+        // Verify all 6 phases are present
+        let phases_array = parsed["phases"].as_array().unwrap();
+        assert_eq!(phases_array.len(), 6);
+
+        // Verify phase IDs
+        let phase_ids: Vec<&str> = phases_array
+            .iter()
+            .map(|p| p["phase_id"].as_str().unwrap())
+            .collect();
+        assert!(phase_ids.contains(&"requirements"));
+        assert!(phase_ids.contains(&"design"));
+        assert!(phase_ids.contains(&"tasks"));
+        assert!(phase_ids.contains(&"review"));
+        assert!(phase_ids.contains(&"fixup"));
+        assert!(phase_ids.contains(&"final"));
     }
 
-    if let Some(drift) = &flow_drift {
-        eprintln!("\n⚠ Flow lock drift detected for spec '{spec_id}':");
+    #[test]
+    fn test_benchmark_command_basic() {
+        // Test basic benchmark execution with realistic thresholds for test environments
+        // Use more generous thresholds since test environments can be slower
+        let result = execute_benchmark_command(
+            5,            // file_count
+            100,          // file_size
+            2,            // iterations
+            false,        // json
+            Some(10.0),   // max_empty_run_secs - generous for test env
+            Some(2000.0), // max_packetization_ms - generous for test env (100ms for 5 files)
+            None,         // max_rss_mb
+            None,         // max_commit_mb
+            false,        // verbose
+        );
 
-        if let Some(flow_version) = &drift.flow_version {
-            eprintln!(
-                "  Flow version: {} → {}",
-                flow_version.locked, flow_version.current
-            );
-        }
-        if let Some(stage_graph) = &drift.stage_graph_version {
-            eprintln!(
-                "  Stage graph: {} → {}",
-                stage_graph.locked, stage_graph.current
-            );
-        }
-        if let Some(gate_set) = &drift.gate_set_version {
-            eprintln!(
-                "  Gate set: {} → {}",
-                gate_set.locked, gate_set.current
-            );
-        }
-        if let Some(prompt_pack) = &drift.prompt_pack_version {
-            eprintln!(
-                "  Prompt pack: {} → {}",
-                prompt_pack.locked, prompt_pack.current
-            );
-        }
-        if let Some(adapter) = &drift.adapter_version {
-            eprintln!("  Adapter: {} → {}", adapter.locked, adapter.current);
-        }
-        if let Some(provider_policy) = &drift.provider_policy {
-            eprintln!(
-                "  Provider policy: {} → {}",
-                provider_policy.locked, provider_policy.current
-            );
-        }
-        if let Some(tool_context) = &drift.tool_context_version {
-            eprintln!(
-                "  Tool context: {} → {}",
-                tool_context.locked, tool_context.current
-            );
-        }
+        // Should succeed with realistic test environment thresholds
+        assert!(result.is_ok());
     }
 
-    if strict_lock && (legacy_drift.is_some() || flow_drift.is_some()) {
-        eprintln!("\n✗ Strict lock mode enabled: failing due to drift");
-        eprintln!("  To proceed, either:");
-        eprintln!(
-            "    - Update the lockfiles: rm .xchecker/specs/{spec_id}/lock.json .xchecker/specs/{spec_id}/flow.lock && xchecker init {spec_id} --create-lock"
+    #[test]
+    fn test_benchmark_command_with_threshold_overrides() {
+        // Test benchmark with custom thresholds (very generous to ensure pass)
+        let result = execute_benchmark_command(
+            5,             // file_count
+            100,           // file_size
+            2,             // iterations
+            false,         // json
+            Some(100.0),   // max_empty_run_secs - very generous
+            Some(10000.0), // max_packetization_ms - very generous
+            Some(1000.0),  // max_rss_mb - very generous
+            Some(2000.0),  // max_commit_mb - very generous
+            false,         // verbose
         );
-        eprintln!("    - Remove --strict-lock flag to allow drift with warning");
 
-        return Err(anyhow::anyhow!("Lock drift detected in strict mode"));
+        // Should succeed with generous thresholds
+        assert!(result.is_ok());
     }
 
-    if legacy_drift.is_some() || flow_drift.is_some() {
-        eprintln!("\n  Continuing with drift (use --strict-lock to fail on drift)");
-    }
+    #[test]
+    fn test_benchmark_command_json_output() {
+        // Test that JSON mode runs successfully
+        // (We can't easily capture stdout in unit tests, but integration tests verify JSON structure)
+        let result = execute_benchmark_command(
+            5,             // file_count
+            100,           // file_size
+            2,             // iterations
+            true,          // json - this is what we're testing
+            Some(100.0),   // max_empty_run_secs
+            Some(10000.0), // max_packetization_ms
+            None,          // max_rss_mb
+            None,          // max_commit_mb
+            false,         // verbose (should be suppressed in JSON mode)
+        );
 
-    Ok(legacy_drift)
-}
+        // Should succeed
+        assert!(result.is_ok());
+    }
 
-struct SpinnerGuard {
-    running: Arc<AtomicBool>,
-    handle: Option<thread::JoinHandle<()>>,
-}
+    #[test]
+    fn test_benchmark_thresholds_applied() {
+        // Test that custom thresholds are properly applied
+        use crate::benchmark::{BenchmarkConfig, BenchmarkThresholds};
 
-impl SpinnerGuard {
-    fn new() -> Self {
-        let running = Arc::new(AtomicBool::new(true));
-        let running_clone = running.clone();
-
-        // Hide cursor to prevent flickering
-        let _ = crossterm::execute!(std::io::stdout(), crossterm::cursor::Hide);
-
-        let handle = thread::spawn(move || {
-            let frames = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
-            let mut i = 0;
-            while running_clone.load(Ordering::Relaxed) {
-                print!("\r{} Running health checks...", frames[i]);
-                let _ = std::io::stdout().flush();
-                i = (i + 1) % frames.len();
-                thread::sleep(Duration::from_millis(80));
-            }
-            // Clear the line when done (use crossterm for portability)
-            let _ = crossterm::execute!(
-                std::io::stdout(),
-                crossterm::terminal::Clear(crossterm::terminal::ClearType::CurrentLine)
-            );
-            print!("\r");
-            let _ = std::io::stdout().flush();
-        });
+        let thresholds = BenchmarkThresholds {
+            empty_run_max_secs: 3.0,
+            packetization_max_ms_per_100_files: 150.0,
+            max_rss_mb: Some(500.0),
+            max_commit_mb: Some(1000.0),
+        };
 
-        Self {
-            running,
-            handle: Some(handle),
-        }
-    }
-}
+        let config = BenchmarkConfig {
+            file_count: 10,
+            file_size_bytes: 100,
+            iterations: 2,
+            verbose: false,
+            thresholds,
+        };
 
-impl Drop for SpinnerGuard {
-    fn drop(&mut self) {
-        self.running.store(false, Ordering::Relaxed);
-        if let Some(handle) = self.handle.take() {
-            let _ = handle.join();
-        }
-        // Restore cursor
-        let _ = crossterm::execute!(std::io::stdout(), crossterm::cursor::Show);
+        // Verify thresholds are set correctly
+        assert_eq!(config.thresholds.empty_run_max_secs, 3.0);
+        assert_eq!(config.thresholds.packetization_max_ms_per_100_files, 150.0);
+        assert_eq!(config.thresholds.max_rss_mb, Some(500.0));
+        assert_eq!(config.thresholds.max_commit_mb, Some(1000.0));
     }
-}
 
-#[cfg(test)]
-#[allow(clippy::items_after_test_module)] // Test helper functions defined after tests is intentional
-#[allow(clippy::await_holding_lock)] // Test synchronization using mutex guards across awaits is intentional
-mod tests {
-    use super::*;
-    use serial_test::serial;
-    use std::env;
-    use std::sync::{Mutex, MutexGuard, OnceLock};
-    use tempfile::TempDir;
-    use xchecker_utils::test_support::EnvVarGuard;
+    #[test]
+    fn test_benchmark_cli_parsing() {
+        // Test that CLI arguments are properly parsed
+        use clap::Parser;
 
-    // Global lock for tests that mutate process-global CLI state (env vars, cwd).
-    // Any test that uses `TestEnvGuard` or `cli_env_guard()` will be serialized.
-    static CLI_ENV_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+        // Test basic benchmark command
+        let args = vec![
+            "xchecker",
+            "benchmark",
+            "--file-count",
+            "50",
+            "--iterations",
+            "3",
+        ];
+        let cli = Cli::try_parse_from(args);
+        assert!(cli.is_ok());
 
-    fn cli_env_guard() -> MutexGuard<'static, ()> {
-        CLI_ENV_LOCK.get_or_init(|| Mutex::new(())).lock().unwrap()
-    }
+        if let Ok(cli) = cli {
+            match cli.command {
+                Commands::Benchmark {
+                    file_count,
+                    iterations,
+                    ..
+                } => {
+                    assert_eq!(file_count, 50);
+                    assert_eq!(iterations, 3);
+                }
+                _ => panic!("Expected Benchmark command"),
+            }
+        }
 
-    struct TestEnvGuard {
-        // Hold the lock for the entire lifetime of the guard
-        _lock: MutexGuard<'static, ()>,
-        _temp_dir: TempDir,
-        original_dir: PathBuf,
-        // EnvVarGuard handles save/restore of XCHECKER_HOME automatically on drop
-        _env_guard: EnvVarGuard,
-    }
+        // Test benchmark with threshold overrides
+        let args_with_thresholds = vec![
+            "xchecker",
+            "benchmark",
+            "--max-empty-run-secs",
+            "3.5",
+            "--max-packetization-ms",
+            "180.0",
+            "--json",
+        ];
+        let cli_thresholds = Cli::try_parse_from(args_with_thresholds);
+        assert!(cli_thresholds.is_ok());
 
-    impl Drop for TestEnvGuard {
-        fn drop(&mut self) {
-            // Restore cwd while still holding the lock
-            // _env_guard drops after this, restoring XCHECKER_HOME
-            let _ = env::set_current_dir(&self.original_dir);
-            // _lock field drops last, releasing the mutex
+        if let Ok(cli) = cli_thresholds {
+            match cli.command {
+                Commands::Benchmark {
+                    max_empty_run_secs,
+                    max_packetization_ms,
+                    json,
+                    ..
+                } => {
+                    assert_eq!(max_empty_run_secs, Some(3.5));
+                    assert_eq!(max_packetization_ms, Some(180.0));
+                    assert!(json);
+                }
+                _ => panic!("Expected Benchmark command"),
+            }
         }
     }
 
-    fn setup_test_environment() -> TestEnvGuard {
-        // Take the global CLI lock first
-        let lock = cli_env_guard();
-
-        let temp_dir = TempDir::new().unwrap();
-        let original_dir = env::current_dir().unwrap();
-
-        // EnvVarGuard captures original value and will restore on drop
-        let env_guard = EnvVarGuard::cleared("XCHECKER_HOME");
-
-        // From here onwards we're serialized against other CLI tests
-        env::set_current_dir(temp_dir.path()).unwrap();
+    #[test]
+    fn test_bench_cli_parsing() {
+        use clap::Parser;
 
-        TestEnvGuard {
-            _lock: lock,
-            _temp_dir: temp_dir,
-            original_dir,
-            _env_guard: env_guard,
+        let cli = Cli::try_parse_from(["xchecker", "bench", "some/dir", "--samples", "3"])
+            .expect("bench should parse");
+        match cli.command {
+            Commands::Bench {
+                dir,
+                samples,
+                warmup,
+            } => {
+                assert_eq!(dir, PathBuf::from("some/dir"));
+                assert_eq!(samples, 3);
+                assert_eq!(warmup, 1); // default
+            }
+            _ => panic!("Expected Bench command"),
         }
     }
 
     #[test]
-    fn test_create_default_config() {
-        let cli_args = CliArgs::default();
-        let config = Config::discover(&cli_args).unwrap();
-        let config_map = create_default_config(true, &config, &cli_args);
+    fn test_list_cli_parsing() {
+        use clap::Parser;
 
-        assert_eq!(config_map.get("verbose"), Some(&"true".to_string()));
-        assert_eq!(
-            config_map.get("packet_max_bytes"),
-            Some(&"65536".to_string())
-        );
-        assert_eq!(
-            config_map.get("packet_max_lines"),
-            Some(&"1200".to_string())
-        );
+        let cli = Cli::try_parse_from(["xchecker", "list", "--discover", "--register"])
+            .expect("list --discover should parse");
+        match cli.command {
+            Commands::List {
+                discover,
+                register,
+                json,
+            } => {
+                assert!(discover);
+                assert!(register);
+                assert!(!json);
+            }
+            _ => panic!("Expected List command"),
+        }
+
+        assert!(Cli::try_parse_from(["xchecker", "list", "--register"]).is_err());
     }
 
     #[test]
-    fn test_create_default_config_no_verbose() {
-        let cli_args = CliArgs::default();
-        let config = Config::discover(&cli_args).unwrap();
-        let config_map = create_default_config(false, &config, &cli_args);
+    fn test_workspace_override_cli_parsing() {
+        use clap::Parser;
 
-        assert!(!config_map.contains_key("verbose"));
-        assert_eq!(
-            config_map.get("packet_max_bytes"),
-            Some(&"65536".to_string())
-        );
-        assert_eq!(
-            config_map.get("packet_max_lines"),
-            Some(&"1200".to_string())
-        );
-    }
+        let cli = Cli::try_parse_from(["xchecker", "--workspace", "/tmp/xc", "status", "my-spec"])
+            .expect("--workspace before the subcommand should parse");
+        assert_eq!(cli.workspace, Some(PathBuf::from("/tmp/xc")));
 
-    #[tokio::test]
-    #[serial]
-    async fn test_spec_command_execution() -> anyhow::Result<()> {
-        use tempfile::TempDir;
+        // `project list --workspace` still names the workspace file, not the home
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "project",
+            "list",
+            "--workspace",
+            "workspace.yaml",
+        ])
+        .expect("project list --workspace should parse");
+        assert!(cli.workspace.is_none());
+    }
 
-        // Take the global CLI lock for env/cwd mutations
-        let _lock = cli_env_guard();
+    #[test]
+    fn test_migrate_artifacts_cli_parsing() {
+        use clap::Parser;
 
-        // Setup isolated test root
-        let temp = TempDir::new()?;
-        let root = temp.path();
+        let cli = Cli::try_parse_from(["xchecker", "migrate-artifacts", "my-spec", "--dry-run"])
+            .expect("migrate-artifacts should parse");
+        match cli.command {
+            Commands::MigrateArtifacts { id, dry_run } => {
+                assert_eq!(id, "my-spec");
+                assert!(dry_run);
+            }
+            _ => panic!("Expected MigrateArtifacts command"),
+        }
+    }
 
-        // Make it look like a repo root
-        std::fs::create_dir_all(root.join(".git"))?;
+    #[test]
+    fn test_badge_cli_parsing() {
+        use clap::Parser;
 
-        // Use guards that restore env vars on drop (including on panic)
-        let _home_guard = EnvVarGuard::set("XCHECKER_HOME", root.to_str().unwrap());
-        let _skip_guard = EnvVarGuard::set("XCHECKER_SKIP_LLM_TESTS", "1");
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "badge",
+            "my-spec",
+            "--out",
+            "badge.svg",
+            "--markdown",
+        ])
+        .expect("badge should parse");
+        match cli.command {
+            Commands::Badge { id, out, markdown } => {
+                assert_eq!(id, "my-spec");
+                assert_eq!(out, Some(PathBuf::from("badge.svg")));
+                assert!(markdown);
+            }
+            _ => panic!("Expected Badge command"),
+        }
+    }
 
-        // Save and change CWD
-        let original_dir = std::env::current_dir()?;
-        std::env::set_current_dir(root)?;
+    #[test]
+    fn test_export_findings_cli_parsing() {
+        use clap::Parser;
 
-        // Create minimal config
-        std::fs::write(
-            root.join("xchecker.toml"),
-            r#"
-[runner]
-runner_mode = "native"
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "export-findings",
+            "my-spec",
+            "--to",
+            "github-issues",
+            "--repo",
+            "owner/name",
+            "--label",
+            "review",
+            "--label",
+            "spec",
+        ])
+        .expect("export-findings should parse");
+        match cli.command {
+            Commands::ExportFindings {
+                id,
+                to,
+                repo,
+                labels,
+                dry_run,
+            } => {
+                assert_eq!(id, "my-spec");
+                assert_eq!(to, "github-issues");
+                assert_eq!(repo.as_deref(), Some("owner/name"));
+                assert_eq!(labels, vec!["review", "spec"]);
+                assert!(!dry_run);
+            }
+            _ => panic!("Expected ExportFindings command"),
+        }
 
-[packet]
-packet_max_bytes = 1048576
-packet_max_lines = 5000
-"#,
-        )?;
+        assert!(
+            Cli::try_parse_from(["xchecker", "export-findings", "my-spec", "--to", "jira"])
+                .is_err()
+        );
+        assert!(Cli::try_parse_from(["xchecker", "export-findings", "my-spec"]).is_err());
+    }
 
-        // Create minimal CLI args for dry-run
-        let cli_args = CliArgs::default();
+    #[test]
+    fn test_sync_tasks_cli_parsing() {
+        use clap::Parser;
 
-        let config = Config::discover(&cli_args)?;
-        let redactor = Arc::new(SecretRedactor::from_config(&config)?);
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "sync-tasks",
+            "my-spec",
+            "push",
+            "--tracker",
+            "jira",
+            "--project",
+            "PAY",
+            "--dry-run",
+        ])
+        .expect("sync-tasks push should parse");
+        match cli.command {
+            Commands::SyncTasks {
+                id,
+                action,
+                tracker,
+                repo,
+                project,
+                dry_run,
+            } => {
+                assert_eq!(id, "my-spec");
+                assert_eq!(action, "push");
+                assert_eq!(tracker.as_deref(), Some("jira"));
+                assert_eq!(repo, None);
+                assert_eq!(project.as_deref(), Some("PAY"));
+                assert!(dry_run);
+            }
+            _ => panic!("Expected SyncTasks command"),
+        }
 
-        // Create a minimal input file for the spec
-        std::fs::write(root.join("input.txt"), "Test requirement")?;
+        assert!(Cli::try_parse_from(["xchecker", "sync-tasks", "my-spec", "pull"]).is_ok());
+        assert!(Cli::try_parse_from(["xchecker", "sync-tasks", "my-spec", "merge"]).is_err());
+        assert!(
+            Cli::try_parse_from([
+                "xchecker",
+                "sync-tasks",
+                "my-spec",
+                "push",
+                "--tracker",
+                "linear"
+            ])
+            .is_err()
+        );
+    }
 
-        // Test dry-run execution (fast, no real LLMs)
-        // This should complete quickly and not hang
-        let result = execute_spec_command(
-            "test-spec",
-            "fs",
-            Some("input.txt"),
-            Some(root.to_str().unwrap()), // repo path
-            true,                         // dry_run = true
-            false,
-            false,
-            false,
-            false,
-            &config,
-            &cli_args,
-            &redactor,
-        )
-        .await;
+    #[test]
+    fn test_inject_failure_flag_is_hidden_and_validated() {
+        use clap::{CommandFactory, Parser};
 
-        // Restore CWD before asserting (env vars restored by guards on drop)
-        let _ = std::env::set_current_dir(&original_dir);
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "spec",
+            "my-spec",
+            "--dry-run",
+            "--inject-failure",
+            "mid-stream",
+        ])
+        .expect("--inject-failure should parse");
+        assert_eq!(cli.inject_failure.as_deref(), Some("mid-stream"));
 
-        // In dry-run mode with a valid source, this should succeed
-        // The important thing is it doesn't hang and completes quickly
         assert!(
-            result.is_ok(),
-            "Dry-run spec execution should succeed: {:?}",
-            result.err()
+            Cli::try_parse_from(["xchecker", "spec", "my-spec", "--inject-failure", "later"])
+                .is_err()
         );
 
-        Ok(())
+        let help = Cli::command().render_long_help().to_string();
+        assert!(!help.contains("inject-failure"));
+
+        let cli_args = CliArgs {
+            inject_failure: Some("before-receipt-write".to_string()),
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+        let config_map = create_default_config(false, &config, &cli_args);
+        assert_eq!(
+            config_map.get("inject_failure").map(String::as_str),
+            Some("before-receipt-write")
+        );
     }
 
     #[test]
-    fn test_status_command_no_spec() {
-        let _temp_dir = setup_test_environment();
+    fn test_idempotency_key_flag_reaches_config_map() {
+        use clap::Parser;
 
-        let cli_args = CliArgs::default();
-        let config = Config::discover(&cli_args).unwrap();
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "resume",
+            "my-spec",
+            "--phase",
+            "design",
+            "--idempotency-key",
+            "ci-run-42",
+        ])
+        .expect("--idempotency-key should parse");
+        assert_eq!(cli.idempotency_key.as_deref(), Some("ci-run-42"));
 
-        // Test status for non-existent spec
-        let result = execute_status_command("nonexistent-spec", false, &config);
-        assert!(result.is_ok());
+        let cli_args = CliArgs {
+            idempotency_key: cli.idempotency_key,
+            ..Default::default()
+        };
+        let config = Config::discover(&cli_args).unwrap();
+        let config_map = create_default_config(false, &config, &cli_args);
+        assert_eq!(
+            config_map.get("idempotency_key").map(String::as_str),
+            Some("ci-run-42")
+        );
     }
 
     #[test]
-    fn test_status_command_with_spec() {
-        let _temp_dir = setup_test_environment();
+    fn test_models_list_command_parsing() {
+        use clap::Parser;
 
-        let cli_args = CliArgs::default();
-        let config = Config::discover(&cli_args).unwrap();
+        let cli = Cli::try_parse_from(["xchecker", "models", "list", "--provider", "gemini-cli"])
+            .expect("models list should parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Models(ModelsCommands::List { provider: Some(ref p), json: false }) if p == "gemini-cli"
+        ));
 
-        // Note: We can't easily test spec creation with stdin in unit tests
-        // This test just verifies status command works with non-existent spec
-        let result = execute_status_command("test-status-spec", false, &config);
-        assert!(result.is_ok());
+        assert!(
+            Cli::try_parse_from(["xchecker", "models", "list", "--provider", "bedrock"]).is_err()
+        );
     }
 
-    // ===== Spec JSON Output Tests (Task 21.1) =====
-    // **Property: JSON output includes schema version**
-    // **Validates: Requirements 4.1.1**
-
     #[test]
-    fn test_spec_json_output_schema_version() {
-        // Test that spec JSON output includes schema_version field
-        use crate::types::{PhaseInfo, SpecConfigSummary, SpecOutput};
+    fn test_export_site_command_parsing() {
+        use clap::Parser;
 
-        let output = SpecOutput {
-            schema_version: "spec-json.v1".to_string(),
-            spec_id: "test-spec".to_string(),
-            phases: vec![PhaseInfo {
-                phase_id: "requirements".to_string(),
-                status: "completed".to_string(),
-                last_run: Some(chrono::Utc::now()),
-            }],
-            config_summary: SpecConfigSummary {
-                execution_strategy: "controlled".to_string(),
-                provider: Some("claude-cli".to_string()),
-                spec_path: ".xchecker/specs/test-spec".to_string(),
-            },
-        };
+        let cli = Cli::try_parse_from(["xchecker", "export-site", "my-spec", "--out", "./site"])
+            .expect("export-site should parse");
+        match cli.command {
+            Commands::ExportSite { id, out } => {
+                assert_eq!(id, "my-spec");
+                assert_eq!(out, PathBuf::from("./site"));
+            }
+            _ => panic!("expected export-site"),
+        }
 
-        // Emit as JSON
-        let json_result = emit_spec_json(&output);
-        assert!(json_result.is_ok(), "Failed to emit spec JSON");
+        assert!(Cli::try_parse_from(["xchecker", "export-site", "my-spec"]).is_err());
+    }
 
-        let json_str = json_result.unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+    #[test]
+    fn test_diff_receipts_command_parsing() {
+        use clap::Parser;
 
-        // Verify schema_version is present and correct
-        assert_eq!(parsed["schema_version"], "spec-json.v1");
-        assert_eq!(parsed["spec_id"], "test-spec");
+        let cli = Cli::try_parse_from(["xchecker", "diff-receipts", "a.json", "b.json", "--json"])
+            .expect("diff-receipts should parse");
+        match cli.command {
+            Commands::DiffReceipts { a, b, json } => {
+                assert_eq!(a, PathBuf::from("a.json"));
+                assert_eq!(b, PathBuf::from("b.json"));
+                assert!(json);
+            }
+            _ => panic!("expected diff-receipts"),
+        }
+
+        assert!(Cli::try_parse_from(["xchecker", "diff-receipts", "a.json"]).is_err());
     }
 
     #[test]
-    fn test_spec_json_output_excludes_packet_contents() {
-        // Test that spec JSON output excludes full packet contents
-        // per Requirements 4.1.4
-        use crate::types::{PhaseInfo, SpecConfigSummary, SpecOutput};
-
-        let output = SpecOutput {
-            schema_version: "spec-json.v1".to_string(),
-            spec_id: "test-spec".to_string(),
-            phases: vec![
-                PhaseInfo {
-                    phase_id: "requirements".to_string(),
-                    status: "completed".to_string(),
-                    last_run: None,
-                },
-                PhaseInfo {
-                    phase_id: "design".to_string(),
-                    status: "not_started".to_string(),
-                    last_run: None,
-                },
-            ],
-            config_summary: SpecConfigSummary {
-                execution_strategy: "controlled".to_string(),
-                provider: None,
-                spec_path: ".xchecker/specs/test-spec".to_string(),
-            },
-        };
+    fn test_hooks_command_parsing() {
+        use clap::Parser;
 
-        let json_result = emit_spec_json(&output);
-        assert!(json_result.is_ok());
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "hooks",
+            "install",
+            "--hook",
+            "pre-commit",
+            "--hook",
+            "pre-push",
+            "--force",
+        ])
+        .expect("hooks install should parse");
+        match cli.command {
+            Commands::Hooks(HooksCommands::Install { hook, force }) => {
+                assert_eq!(hook, vec!["pre-commit", "pre-push"]);
+                assert!(force);
+            }
+            _ => panic!("expected hooks install"),
+        }
 
-        let json_str = json_result.unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+        let cli = Cli::try_parse_from(["xchecker", "hooks", "run", "pre-push"])
+            .expect("hooks run should parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Hooks(HooksCommands::Run { ref hook }) if hook == "pre-push"
+        ));
 
-        // Verify no packet contents are present
-        assert!(
-            parsed.get("packet").is_none(),
-            "JSON should not contain packet field"
-        );
         assert!(
-            parsed.get("artifacts").is_none(),
-            "JSON should not contain artifacts field"
+            Cli::try_parse_from(["xchecker", "hooks", "install", "--hook", "post-merge"]).is_err()
         );
+        assert!(Cli::try_parse_from(["xchecker", "hooks", "run"]).is_err());
+    }
+
+    #[test]
+    fn test_status_all_filter_cli_parsing() {
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "status",
+            "--all",
+            "--filter",
+            "tag=payments",
+            "--filter",
+            "owner=team-a",
+        ])
+        .expect("status --all should parse");
+        match cli.command {
+            Commands::Status {
+                id, all, filters, ..
+            } => {
+                assert!(id.is_none());
+                assert!(all);
+                assert_eq!(
+                    filters.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                    vec!["tag=payments", "owner=team-a"]
+                );
+            }
+            _ => panic!("Expected Status command"),
+        }
+
+        // --ndjson streams --all output and cannot be combined with --json
+        let cli = Cli::try_parse_from(["xchecker", "status", "--all", "--ndjson"])
+            .expect("status --all --ndjson should parse");
+        assert!(matches!(cli.command, Commands::Status { ndjson: true, .. }));
+        assert!(Cli::try_parse_from(["xchecker", "status", "s", "--ndjson"]).is_err());
         assert!(
-            parsed.get("raw_response").is_none(),
-            "JSON should not contain raw_response field"
+            Cli::try_parse_from(["xchecker", "status", "--all", "--ndjson", "--json"]).is_err()
         );
 
-        // Verify only expected fields are present
-        assert!(parsed.get("schema_version").is_some());
-        assert!(parsed.get("spec_id").is_some());
-        assert!(parsed.get("phases").is_some());
-        assert!(parsed.get("config_summary").is_some());
+        // A spec ID is required without --all; filters require --all
+        assert!(Cli::try_parse_from(["xchecker", "status"]).is_err());
+        assert!(Cli::try_parse_from(["xchecker", "status", "s", "--filter", "tag=x"]).is_err());
+        assert!(Cli::try_parse_from(["xchecker", "status", "--all", "--filter", "x"]).is_err());
     }
 
     #[test]
-    fn test_spec_json_command_no_spec() {
-        let _temp_dir = setup_test_environment();
+    fn test_examples_emit_cli_parsing() {
+        use clap::Parser;
 
-        let cli_args = CliArgs::default();
-        let config = Config::discover(&cli_args).unwrap();
+        let cli = Cli::try_parse_from(["xchecker", "examples", "emit"])
+            .expect("examples emit should parse");
+        match cli.command {
+            Commands::Examples(ExamplesCommands::Emit { out }) => {
+                assert_eq!(out, PathBuf::from("docs/schemas"));
+            }
+            _ => panic!("Expected Examples command"),
+        }
 
-        // Test spec --json for non-existent spec
-        let result = execute_spec_json_command("nonexistent-spec-json", &config);
-        assert!(result.is_ok());
+        let cli = Cli::try_parse_from(["xchecker", "examples", "emit", "--out", "dist"])
+            .expect("examples emit --out should parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Examples(ExamplesCommands::Emit { out }) if out == Path::new("dist")
+        ));
     }
 
     #[test]
-    fn test_spec_json_canonical_format() {
-        // Test that spec JSON output is in canonical JCS format (no extra whitespace)
-        use crate::types::{PhaseInfo, SpecConfigSummary, SpecOutput};
+    fn test_schema_docs_cli_parsing() {
+        use clap::Parser;
 
-        let output = SpecOutput {
-            schema_version: "spec-json.v1".to_string(),
-            spec_id: "test-spec".to_string(),
-            phases: vec![PhaseInfo {
-                phase_id: "requirements".to_string(),
-                status: "completed".to_string(),
-                last_run: None,
-            }],
-            config_summary: SpecConfigSummary {
-                execution_strategy: "controlled".to_string(),
-                provider: None,
-                spec_path: ".xchecker/specs/test-spec".to_string(),
-            },
-        };
+        let cli =
+            Cli::try_parse_from(["xchecker", "schema", "docs"]).expect("schema docs should parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Schema(SchemaCommands::Docs { out }) if out == Path::new("docs/schemas")
+        ));
+    }
 
-        let json_result = emit_spec_json(&output);
-        assert!(json_result.is_ok());
+    #[test]
+    fn test_bulk_cli_parsing() {
+        use clap::Parser;
 
-        let json_str = json_result.unwrap();
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "bulk",
+            "--filter",
+            "tag=payments",
+            "--yes",
+            "--",
+            "run",
+            "requirements",
+        ])
+        .expect("bulk should parse");
+        match cli.command {
+            Commands::Bulk {
+                filters,
+                yes,
+                operation,
+                ..
+            } => {
+                assert_eq!(filters[0].to_string(), "tag=payments");
+                assert!(yes);
+                assert_eq!(
+                    BulkOperation::parse(&operation).unwrap(),
+                    BulkOperation::Run(PhaseId::Requirements)
+                );
+            }
+            _ => panic!("Expected Bulk command"),
+        }
+
+        let words = |w: &[&str]| w.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            BulkOperation::parse(&words(&["archive"])).unwrap(),
+            BulkOperation::Archive
+        );
+        assert!(BulkOperation::parse(&words(&["run"])).is_err());
+        assert!(BulkOperation::parse(&words(&["delete"])).is_err());
+
+        // A filter and an operation are both required
+        assert!(Cli::try_parse_from(["xchecker", "bulk", "--", "clean"]).is_err());
+        assert!(Cli::try_parse_from(["xchecker", "bulk", "--filter", "tag=x"]).is_err());
+    }
+
+    #[test]
+    fn test_bulk_selection_skips_specs_with_invalid_metadata() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = camino::Utf8Path::from_path(temp.path()).unwrap();
+        for (spec_id, metadata) in [
+            ("alpha", "tags = [\"payments\"]\n"),
+            ("broken", "tags = [\"payments\"\n"),
+            ("gamma", "tags = [\"search\"]\n"),
+        ] {
+            std::fs::create_dir_all(root.join(spec_id)).unwrap();
+            std::fs::write(root.join(spec_id).join("spec.toml"), metadata).unwrap();
+        }
+        let filters = vec!["tag=payments".parse().unwrap()];
+        let redactor = SecretRedactor::new().unwrap();
+
+        let selection = select_bulk_specs(root, &filters, None, &redactor).unwrap();
+
+        assert_eq!(selection.spec_ids, vec!["alpha".to_string()]);
+        assert_eq!(selection.unreadable.len(), 1);
+        let skipped = &selection.unreadable[0];
+        assert_eq!(skipped.spec_id, "broken");
+        assert!(!skipped.success);
+        assert_eq!(skipped.exit_code, ExitCode::CLI_ARGS.as_i32());
+        assert!(skipped.message.contains("spec.toml"));
+    }
+
+    #[test]
+    fn test_experiment_cli_parsing() {
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "experiment",
+            "payments",
+            "--models",
+            "haiku,sonnet",
+            "--phase",
+            "design",
+        ])
+        .expect("experiment should parse");
+        match cli.command {
+            Commands::Experiment {
+                id, models, phase, ..
+            } => {
+                assert_eq!(id, "payments");
+                assert_eq!(models, ["haiku", "sonnet"]);
+                assert_eq!(phase, "design");
+            }
+            _ => panic!("Expected Experiment command"),
+        }
 
-        // Verify canonical JSON properties (no extra whitespace, no newlines)
         assert!(
-            !json_str.contains("  "),
-            "Canonical JSON should not have indentation"
+            Cli::try_parse_from(["xchecker", "experiment", "payments", "--phase", "design"])
+                .is_err()
         );
         assert!(
-            !json_str.contains('\n'),
-            "Canonical JSON should not have newlines"
+            Cli::try_parse_from([
+                "xchecker",
+                "experiment",
+                "payments",
+                "--models",
+                "haiku",
+                "--phase",
+                "deploy"
+            ])
+            .is_err()
         );
     }
 
     #[test]
-    fn test_spec_json_all_phases_present() {
-        // Test that all phases are represented in the output
-        use crate::types::{PhaseInfo, SpecConfigSummary, SpecOutput};
+    fn test_spec_ownership_guard() {
+        use clap::Parser;
+        use std::io::IsTerminal;
 
-        let phases = vec![
-            PhaseInfo {
-                phase_id: "requirements".to_string(),
-                status: "completed".to_string(),
-                last_run: None,
-            },
-            PhaseInfo {
-                phase_id: "design".to_string(),
-                status: "pending".to_string(),
-                last_run: None,
-            },
-            PhaseInfo {
-                phase_id: "tasks".to_string(),
-                status: "not_started".to_string(),
-                last_run: None,
-            },
-            PhaseInfo {
-                phase_id: "review".to_string(),
-                status: "not_started".to_string(),
-                last_run: None,
-            },
-            PhaseInfo {
-                phase_id: "fixup".to_string(),
-                status: "not_started".to_string(),
-                last_run: None,
-            },
-            PhaseInfo {
-                phase_id: "final".to_string(),
-                status: "not_started".to_string(),
-                last_run: None,
-            },
-        ];
+        let cli = Cli::try_parse_from(["xchecker", "clean", "shared", "--hard", "-y"]).unwrap();
+        assert!(matches!(cli.command, Commands::Clean { yes: true, .. }));
 
-        let output = SpecOutput {
-            schema_version: "spec-json.v1".to_string(),
-            spec_id: "test-spec".to_string(),
-            phases,
-            config_summary: SpecConfigSummary {
-                execution_strategy: "controlled".to_string(),
-                provider: Some("openrouter".to_string()),
-                spec_path: ".xchecker/specs/test-spec".to_string(),
-            },
-        };
+        let _temp_dir = crate::paths::with_isolated_home();
+        let spec_dir = crate::paths::spec_root("shared");
+        crate::paths::ensure_dir_all(&spec_dir).unwrap();
+        std::fs::write(
+            spec_dir.join(crate::status::metadata::SPEC_METADATA_FILE),
+            "owners = [\"nobody-owns-this-spec\"]\n",
+        )
+        .unwrap();
+        let clean = ["clean".to_string()];
 
-        let json_result = emit_spec_json(&output);
-        assert!(json_result.is_ok());
+        // Unowned specs, non-destructive runs, and --yes never prompt
+        assert!(confirm_spec_ownership("unowned", &clean, false).unwrap());
+        assert!(confirm_spec_ownership("shared", &[], false).unwrap());
+        assert!(confirm_spec_ownership("shared", &clean, true).unwrap());
 
-        let json_str = json_result.unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+        // Without a terminal to prompt on, --yes is required
+        if !std::io::stdin().is_terminal() {
+            let err = confirm_spec_ownership("shared", &clean, false)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("nobody-owns-this-spec"), "{err}");
+            assert!(err.contains("--yes"), "{err}");
+        }
+    }
 
-        // Verify all 6 phases are present
-        let phases_array = parsed["phases"].as_array().unwrap();
-        assert_eq!(phases_array.len(), 6);
+    #[test]
+    fn test_clean_partials_holds_spec_lock() {
+        use clap::Parser;
 
-        // Verify phase IDs
-        let phase_ids: Vec<&str> = phases_array
-            .iter()
-            .map(|p| p["phase_id"].as_str().unwrap())
-            .collect();
-        assert!(phase_ids.contains(&"requirements"));
-        assert!(phase_ids.contains(&"design"));
-        assert!(phase_ids.contains(&"tasks"));
-        assert!(phase_ids.contains(&"review"));
-        assert!(phase_ids.contains(&"fixup"));
-        assert!(phase_ids.contains(&"final"));
+        let cli =
+            Cli::try_parse_from(["xchecker", "status", "spec", "--clean-partials", "-y"]).unwrap();
+        assert!(matches!(cli.command, Commands::Status { yes: true, .. }));
+        assert!(Cli::try_parse_from(["xchecker", "status", "spec", "-y"]).is_err());
+
+        let _temp_dir = crate::paths::with_isolated_home();
+        let spec_dir = crate::paths::spec_root("partials");
+        let partial = spec_dir
+            .join("artifacts")
+            .join("10-requirements.partial.md");
+        crate::paths::ensure_dir_all(partial.parent().unwrap()).unwrap();
+        std::fs::write(&partial, "partial").unwrap();
+
+        // A holder of the spec lock keeps its partial files
+        let lock = crate::lock::FileLock::acquire("partials", false, None).unwrap();
+        let err = execute_clean_partials_command("partials", true)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Cannot clean partials"), "{err}");
+        assert!(partial.exists());
+
+        drop(lock);
+        execute_clean_partials_command("partials", true).unwrap();
+        assert!(!partial.exists());
     }
 
     #[test]
-    fn test_benchmark_command_basic() {
-        // Test basic benchmark execution with realistic thresholds for test environments
-        // Use more generous thresholds since test environments can be slower
-        let result = execute_benchmark_command(
-            5,            // file_count
-            100,          // file_size
-            2,            // iterations
-            false,        // json
-            Some(10.0),   // max_empty_run_secs - generous for test env
-            Some(2000.0), // max_packetization_ms - generous for test env (100ms for 5 files)
-            None,         // max_rss_mb
-            None,         // max_commit_mb
-            false,        // verbose
-        );
+    fn test_ci_cli_parsing() {
+        use clap::Parser;
 
-        // Should succeed with realistic test environment thresholds
-        assert!(result.is_ok());
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "ci",
+            "my-spec",
+            "--source",
+            "fs",
+            "--repo",
+            ".",
+            "--through",
+            "tasks",
+            "--summary-fd",
+            "3",
+            "--require-done",
+        ])
+        .expect("ci should parse");
+        match cli.command {
+            Commands::Ci {
+                id,
+                through,
+                allow_warnings,
+                require_done,
+                summary_fd,
+                summary_file,
+                ..
+            } => {
+                assert_eq!(id, "my-spec");
+                assert_eq!(through, "tasks");
+                assert!(!allow_warnings);
+                assert!(require_done);
+                assert_eq!(summary_fd, Some(3));
+                assert_eq!(summary_file, None);
+            }
+            _ => panic!("Expected Ci command"),
+        }
+
+        // Only one summary target may be given
+        assert!(
+            Cli::try_parse_from([
+                "xchecker",
+                "ci",
+                "my-spec",
+                "--summary-fd",
+                "3",
+                "--summary-file",
+                "ci.json",
+            ])
+            .is_err()
+        );
+        assert!(Cli::try_parse_from(["xchecker", "ci", "my-spec", "--through", "deploy"]).is_err());
     }
 
     #[test]
-    fn test_benchmark_command_with_threshold_overrides() {
-        // Test benchmark with custom thresholds (very generous to ensure pass)
-        let result = execute_benchmark_command(
-            5,             // file_count
-            100,           // file_size
-            2,             // iterations
-            false,         // json
-            Some(100.0),   // max_empty_run_secs - very generous
-            Some(10000.0), // max_packetization_ms - very generous
-            Some(1000.0),  // max_rss_mb - very generous
-            Some(2000.0),  // max_commit_mb - very generous
-            false,         // verbose
+    fn test_receipt_schema_errors_flags_invalid_receipts() {
+        let receipt: crate::types::Receipt = serde_json::from_value(serde_json::json!({
+            "schema_version": "1",
+            "emitted_at": "2026-01-01T00:00:00Z",
+            "spec_id": "ci-spec",
+            "phase": "requirements",
+            "xchecker_version": "0.0.0",
+            "claude_cli_version": "unknown",
+            "model_full_name": "haiku",
+            "canonicalization_version": "yaml-v1,md-v1",
+            "canonicalization_backend": "jcs-rfc8785",
+            "flags": {},
+            "runner": "native",
+            "packet": {"files": [], "max_bytes": 1, "max_lines": 1},
+            "outputs": [],
+            "exit_code": 0,
+            "warnings": []
+        }))
+        .expect("minimal receipt should deserialize");
+        assert!(
+            receipt_schema_errors(std::slice::from_ref(&receipt))
+                .unwrap()
+                .is_empty()
         );
 
-        // Should succeed with generous thresholds
-        assert!(result.is_ok());
+        let mut bad = receipt;
+        bad.schema_version = "2".to_string();
+        let errors = receipt_schema_errors(&[bad]).unwrap();
+        assert!(!errors.is_empty());
+        assert!(errors[0].starts_with("requirements receipt: "));
     }
 
     #[test]
-    fn test_benchmark_command_json_output() {
-        // Test that JSON mode runs successfully
-        // (We can't easily capture stdout in unit tests, but integration tests verify JSON structure)
-        let result = execute_benchmark_command(
-            5,             // file_count
-            100,           // file_size
-            2,             // iterations
-            true,          // json - this is what we're testing
-            Some(100.0),   // max_empty_run_secs
-            Some(10000.0), // max_packetization_ms
-            None,          // max_rss_mb
-            None,          // max_commit_mb
-            false,         // verbose (should be suppressed in JSON mode)
-        );
+    fn test_self_update_cli_parsing() {
+        use clap::Parser;
 
-        // Should succeed
-        assert!(result.is_ok());
+        let cli = Cli::try_parse_from(["xchecker", "self-update", "--channel", "prerelease"])
+            .expect("self-update should parse");
+        match cli.command {
+            Commands::SelfUpdate {
+                channel,
+                check,
+                force,
+            } => {
+                assert_eq!(channel, "prerelease");
+                assert!(!check);
+                assert!(!force);
+            }
+            _ => panic!("Expected SelfUpdate command"),
+        }
+
+        assert!(Cli::try_parse_from(["xchecker", "self-update", "--channel", "nightly"]).is_err());
+        assert!(Cli::try_parse_from(["xchecker", "self-update", "--check", "--force"]).is_err());
     }
 
     #[test]
-    fn test_benchmark_thresholds_applied() {
-        // Test that custom thresholds are properly applied
-        use crate::benchmark::{BenchmarkConfig, BenchmarkThresholds};
+    fn test_doctor_measure_cli_parsing() {
+        use clap::Parser;
 
-        let thresholds = BenchmarkThresholds {
-            empty_run_max_secs: 3.0,
-            packetization_max_ms_per_100_files: 150.0,
-            max_rss_mb: Some(500.0),
-            max_commit_mb: Some(1000.0),
-        };
+        let cli = Cli::try_parse_from(["xchecker", "doctor", "--measure", "--samples", "5"])
+            .expect("doctor --measure should parse");
+        match cli.command {
+            Commands::Doctor {
+                measure, samples, ..
+            } => {
+                assert!(measure);
+                assert_eq!(samples, 5);
+            }
+            _ => panic!("Expected Doctor command"),
+        }
+
+        let cli = Cli::try_parse_from(["xchecker", "doctor", "--require-no-telemetry"])
+            .expect("doctor --require-no-telemetry should parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Doctor {
+                require_no_telemetry: true,
+                ..
+            }
+        ));
 
-        let config = BenchmarkConfig {
-            file_count: 10,
-            file_size_bytes: 100,
-            iterations: 2,
-            verbose: false,
-            thresholds,
-        };
+        // --samples only applies to --measure, and is bounded
+        assert!(Cli::try_parse_from(["xchecker", "doctor", "--samples", "5"]).is_err());
+        assert!(
+            Cli::try_parse_from(["xchecker", "doctor", "--measure", "--samples", "0"]).is_err()
+        );
+    }
 
-        // Verify thresholds are set correctly
-        assert_eq!(config.thresholds.empty_run_max_secs, 3.0);
-        assert_eq!(config.thresholds.packetization_max_ms_per_100_files, 150.0);
-        assert_eq!(config.thresholds.max_rss_mb, Some(500.0));
-        assert_eq!(config.thresholds.max_commit_mb, Some(1000.0));
+    #[test]
+    fn test_network_subsystems_follow_no_telemetry() {
+        if cfg!(feature = "no-telemetry") {
+            assert!(crate::NETWORK_SUBSYSTEMS.is_empty());
+        } else {
+            assert!(crate::NETWORK_SUBSYSTEMS.contains(&"issue-trackers"));
+        }
     }
 
     #[test]
-    fn test_benchmark_cli_parsing() {
-        // Test that CLI arguments are properly parsed
+    fn test_queue_cli_parsing_and_daemon_gate() {
         use clap::Parser;
 
-        // Test basic benchmark command
-        let args = vec![
+        let cli = Cli::try_parse_from([
             "xchecker",
-            "benchmark",
-            "--file-count",
-            "50",
-            "--iterations",
-            "3",
-        ];
-        let cli = Cli::try_parse_from(args);
-        assert!(cli.is_ok());
-
-        if let Ok(cli) = cli {
-            match cli.command {
-                Commands::Benchmark {
-                    file_count,
-                    iterations,
-                    ..
-                } => {
-                    assert_eq!(file_count, 50);
-                    assert_eq!(iterations, 3);
-                }
-                _ => panic!("Expected Benchmark command"),
+            "queue",
+            "add",
+            "my-spec",
+            "--phase",
+            "review",
+            "--priority",
+            "high",
+        ])
+        .expect("queue add should parse");
+        match cli.command {
+            Commands::Queue(QueueCommands::Add {
+                id,
+                phase,
+                priority,
+            }) => {
+                assert_eq!(id, "my-spec");
+                assert_eq!(phase, ["review"]);
+                assert_eq!(priority, "high");
             }
+            _ => panic!("Expected queue add command"),
         }
 
-        // Test benchmark with threshold overrides
-        let args_with_thresholds = vec![
-            "xchecker",
-            "benchmark",
-            "--max-empty-run-secs",
-            "3.5",
-            "--max-packetization-ms",
-            "180.0",
-            "--json",
-        ];
-        let cli_thresholds = Cli::try_parse_from(args_with_thresholds);
-        assert!(cli_thresholds.is_ok());
+        assert!(
+            Cli::try_parse_from([
+                "xchecker",
+                "queue",
+                "add",
+                "my-spec",
+                "--priority",
+                "urgent"
+            ])
+            .is_err()
+        );
+        // --poll-interval only applies to --watch
+        assert!(Cli::try_parse_from(["xchecker", "queue", "run", "--poll-interval", "2"]).is_err());
 
-        if let Ok(cli) = cli_thresholds {
-            match cli.command {
-                Commands::Benchmark {
-                    max_empty_run_secs,
-                    max_packetization_ms,
-                    json,
-                    ..
-                } => {
-                    assert_eq!(max_empty_run_secs, Some(3.5));
-                    assert_eq!(max_packetization_ms, Some(180.0));
-                    assert!(json);
-                }
-                _ => panic!("Expected Benchmark command"),
-            }
+        let config = Config::discover(&CliArgs::default()).unwrap();
+        let err = require_daemon_feature(&config).unwrap_err();
+        assert!(err.to_string().contains("daemon"));
+    }
+
+    #[test]
+    fn test_config_diff_cli_parsing() {
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["xchecker", "config", "diff", "--json"])
+            .expect("config diff should parse");
+        match cli.command {
+            Commands::Config(ConfigCommands::Diff { json }) => assert!(json),
+            _ => panic!("Expected Config Diff command"),
         }
     }
 
@@ -3954,6 +9157,15 @@ packet_max_lines = 5000
             artifacts: Vec::new(),
             effective_config: std::collections::BTreeMap::new(),
             lock_drift: None,
+            generation: 0,
+            metadata: None,
+            active_run: None,
+            task_sync: None,
+            token_usage: None,
+            deprecations: Vec::new(),
+            done: None,
+            next_steps: Vec::new(),
+            partials: Vec::new(),
         };
 
         // Emit as JSON
@@ -3999,6 +9211,15 @@ packet_max_lines = 5000
             artifacts: Vec::new(),
             effective_config: std::collections::BTreeMap::new(),
             lock_drift: None,
+            generation: 0,
+            metadata: None,
+            active_run: None,
+            task_sync: None,
+            token_usage: None,
+            deprecations: Vec::new(),
+            done: None,
+            next_steps: Vec::new(),
+            partials: Vec::new(),
         };
 
         let json_result = emit_status_json(&output);
@@ -4043,6 +9264,15 @@ packet_max_lines = 5000
             artifacts: Vec::new(),
             effective_config: std::collections::BTreeMap::new(),
             lock_drift: None,
+            generation: 0,
+            metadata: None,
+            active_run: None,
+            task_sync: None,
+            token_usage: None,
+            deprecations: Vec::new(),
+            done: None,
+            next_steps: Vec::new(),
+            partials: Vec::new(),
         };
 
         let json_result = emit_status_json(&output);
@@ -4095,6 +9325,15 @@ packet_max_lines = 5000
             }],
             effective_config,
             lock_drift: None,
+            generation: 0,
+            metadata: None,
+            active_run: None,
+            task_sync: None,
+            token_usage: None,
+            deprecations: Vec::new(),
+            done: None,
+            next_steps: Vec::new(),
+            partials: Vec::new(),
         };
 
         let json_result = emit_status_json(&output);
@@ -4145,6 +9384,26 @@ packet_max_lines = 5000
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_format_next_steps_numbers_steps_with_commands() {
+        use crate::types::NextStep;
+
+        let steps = [
+            NextStep {
+                summary: "Resolve the review findings".to_string(),
+                command: None,
+            },
+            NextStep {
+                summary: "Apply them".to_string(),
+                command: Some("xchecker resume demo --phase fixup --apply-fixups".to_string()),
+            },
+        ];
+        assert_eq!(
+            format_next_steps(&steps),
+            "\nNext steps:\n  1. Resolve the review findings\n  2. Apply them\n     xchecker resume demo --phase fixup --apply-fixups\n"
+        );
+    }
+
     #[test]
     fn test_status_json_all_phases_present() {
         // Test that all phases can be represented in the output
@@ -4193,6 +9452,15 @@ packet_max_lines = 5000
             artifacts: Vec::new(),
             effective_config: std::collections::BTreeMap::new(),
             lock_drift: None,
+            generation: 0,
+            metadata: None,
+            active_run: None,
+            task_sync: None,
+            token_usage: None,
+            deprecations: Vec::new(),
+            done: None,
+            next_steps: Vec::new(),
+            partials: Vec::new(),
         };
 
         let json_result = emit_status_json(&output);
@@ -4238,6 +9506,8 @@ packet_max_lines = 5000
             },
             next_steps: "Run design phase to generate architecture and design from requirements."
                 .to_string(),
+            force_phase: false,
+            skip_phases: vec![],
         };
 
         // Emit as JSON
@@ -4271,6 +9541,8 @@ packet_max_lines = 5000
                 latest_completed_phase: Some("design".to_string()),
             },
             next_steps: "Run tasks phase to generate implementation tasks from design.".to_string(),
+            force_phase: false,
+            skip_phases: vec![],
         };
 
         let json_result = emit_resume_json(&output);
@@ -4308,6 +9580,8 @@ packet_max_lines = 5000
                 latest_completed_phase: None,
             },
             next_steps: "Run requirements phase.".to_string(),
+            force_phase: false,
+            skip_phases: vec![],
         };
 
         let json_result = emit_resume_json(&output);
@@ -4342,6 +9616,8 @@ packet_max_lines = 5000
                 latest_completed_phase: Some("requirements".to_string()),
             },
             next_steps: "Run design phase.".to_string(),
+            force_phase: false,
+            skip_phases: vec![],
         };
 
         let json_result = emit_resume_json(&output);
@@ -4389,8 +9665,32 @@ packet_max_lines = 5000
         let config = Config::discover(&cli_args).unwrap();
 
         // Test resume --json for non-existent spec
-        let result = execute_resume_json_command("nonexistent-spec-json", "design", &config);
+        let result =
+            execute_resume_json_command("nonexistent-spec-json", "design", false, &[], &config);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_resume_json_command_honors_force_and_skip() {
+        let _temp_dir = setup_test_environment();
+
+        let cli_args = CliArgs::default();
+        let config = Config::discover(&cli_args).unwrap();
+
+        let skips = ["fixup".to_string(), "review".to_string()];
+        let result =
+            execute_resume_json_command("resume-json-skip", "final", true, &skips, &config);
         assert!(result.is_ok());
+
+        // A phase cannot be both run and skipped
+        let result = execute_resume_json_command(
+            "resume-json-skip",
+            "review",
+            false,
+            &["review".to_string()],
+            &config,
+        );
+        assert!(result.is_err());
     }
 
     #[test]
@@ -4418,6 +9718,8 @@ packet_max_lines = 5000
                     latest_completed_phase: None,
                 },
                 next_steps: format!("Run {} phase.", phase),
+                force_phase: false,
+                skip_phases: vec![],
             };
 
             let json_result = emit_resume_json(&output);
@@ -4449,6 +9751,8 @@ packet_max_lines = 5000
                 latest_completed_phase: None,
             },
             next_steps: "Spec 'nonexistent-spec' does not exist. Run 'xchecker spec nonexistent-spec' to create it first.".to_string(),
+            force_phase: false,
+            skip_phases: vec![],
         };
 
         let json_result = emit_resume_json(&output);
@@ -4506,6 +9810,7 @@ packet_max_lines = 5000
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         };
 
         // Create a successful receipt
@@ -4548,6 +9853,120 @@ packet_max_lines = 5000
         );
     }
 
+    fn changelog_receipt(
+        phase: PhaseId,
+        exit_code: i32,
+        outputs: Vec<crate::types::FileHash>,
+    ) -> crate::types::Receipt {
+        use crate::receipt::ReceiptManager;
+        use crate::types::PacketEvidence;
+
+        let manager = ReceiptManager::new(&crate::paths::spec_root("changelog-spec"));
+        manager.create_receipt(
+            "changelog-spec",
+            phase,
+            exit_code,
+            outputs,
+            "0.1.0",
+            "0.8.1",
+            "haiku",
+            None,
+            HashMap::new(),
+            PacketEvidence {
+                files: vec![],
+                max_bytes: 65536,
+                max_lines: 1200,
+                ..Default::default()
+            },
+            None,
+            None,
+            vec![],
+            None,
+            "native",
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_render_changelog_fragment_added() {
+        let receipts = vec![
+            changelog_receipt(
+                PhaseId::Requirements,
+                0,
+                vec![crate::types::FileHash {
+                    path: "artifacts/00-requirements.md".to_string(),
+                    blake3_canonicalized: "abcdef0123456789".to_string(),
+                    blake3_front_matter: None,
+                }],
+            ),
+            changelog_receipt(PhaseId::Design, 0, vec![]),
+        ];
+
+        let fragment =
+            render_changelog_fragment("changelog-spec", &receipts, Some("Adds a calculator."));
+
+        assert!(fragment.starts_with("### Added\n\n"));
+        assert!(fragment.contains("- Spec `changelog-spec`: Adds a calculator."));
+        assert!(fragment.contains("Phases completed: requirements, design"));
+        assert!(fragment.contains("`artifacts/00-requirements.md` (abcdef01)"));
+        assert!(!fragment.contains("Incomplete"));
+    }
+
+    #[test]
+    fn test_render_changelog_fragment_changed_and_incomplete() {
+        let mut rerun = changelog_receipt(PhaseId::Requirements, 0, vec![]);
+        rerun.emitted_at += chrono::Duration::seconds(1);
+        let receipts = vec![
+            changelog_receipt(PhaseId::Requirements, 0, vec![]),
+            rerun,
+            changelog_receipt(PhaseId::Design, 70, vec![]),
+        ];
+
+        let fragment = render_changelog_fragment("changelog-spec", &receipts, None);
+
+        assert!(fragment.starts_with("### Changed\n\n"));
+        assert!(fragment.contains("Phases completed: requirements\n"));
+        assert!(fragment.contains("Incomplete: design (exit 70)"));
+    }
+
+    #[test]
+    fn test_latest_artifact_summary_uses_highest_numbered_artifact() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("00-requirements.md"),
+            "# Requirements\n\nOld summary.\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("10-design.md"),
+            "# Design\n\n## Overview\n\nThe design\nwraps lines.\n",
+        )
+        .unwrap();
+        std::fs::write(temp.path().join("20-tasks.partial.md"), "Partial.\n").unwrap();
+
+        assert_eq!(
+            latest_artifact_summary(temp.path()).as_deref(),
+            Some("The design wraps lines.")
+        );
+    }
+
+    #[test]
+    fn test_latest_artifact_summary_reads_compressed_artifact() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("10-design.md"), "# Design\n\nPlain.\n").unwrap();
+        let tasks = compression::compress(b"# Tasks\n\nCompressed tasks.\n", 3).unwrap();
+        std::fs::write(temp.path().join("20-tasks.md.zst"), tasks).unwrap();
+
+        assert_eq!(
+            latest_artifact_summary(temp.path()).as_deref(),
+            Some("Compressed tasks.")
+        );
+    }
+
     #[test]
     fn test_derive_spec_status_with_failed_receipt() {
         // Use isolated home to avoid conflicts with other tests
@@ -4568,6 +9987,7 @@ packet_max_lines = 5000
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         };
 
         // Create a failed receipt
@@ -4630,6 +10050,7 @@ packet_max_lines = 5000
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         };
 
         // Create first receipt (requirements - success)
@@ -4928,6 +10349,234 @@ packet_max_lines = 5000
         assert!(statuses.contains(&"not_started"));
     }
 
+    #[test]
+    fn test_resume_skip_and_force_phase_parsing() {
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "resume",
+            "my-spec",
+            "--force-phase",
+            "tasks",
+            "--skip-phase",
+            "review",
+            "--skip-phase",
+            "fixup",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Resume {
+                phase,
+                force_phase,
+                skip_phase,
+                ..
+            } => {
+                assert!(phase.is_none());
+                assert_eq!(force_phase.as_deref(), Some("tasks"));
+                assert_eq!(skip_phase, vec!["review", "fixup"]);
+            }
+            _ => panic!("Expected Resume command"),
+        }
+
+        // --phase and --force-phase are mutually exclusive, and one is required
+        assert!(
+            Cli::try_parse_from([
+                "xchecker",
+                "resume",
+                "my-spec",
+                "--phase",
+                "design",
+                "--force-phase",
+                "tasks",
+            ])
+            .is_err()
+        );
+        assert!(Cli::try_parse_from(["xchecker", "resume", "my-spec"]).is_err());
+
+        // Only optional phases can be skipped
+        assert!(
+            Cli::try_parse_from([
+                "xchecker",
+                "resume",
+                "my-spec",
+                "--phase",
+                "final",
+                "--skip-phase",
+                "design",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_resume_diff_preview_flag_parsing() {
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "resume",
+            "my-spec",
+            "--phase",
+            "fixup",
+            "--diff-algorithm",
+            "histogram",
+            "--word-diff",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Resume {
+                diff_algorithm,
+                word_diff,
+                ..
+            } => {
+                assert_eq!(diff_algorithm, "histogram");
+                assert!(word_diff);
+            }
+            _ => panic!("Expected Resume command"),
+        }
+
+        let cli =
+            Cli::try_parse_from(["xchecker", "resume", "my-spec", "--phase", "fixup"]).unwrap();
+        match cli.command {
+            Commands::Resume {
+                diff_algorithm,
+                word_diff,
+                differential,
+                ..
+            } => {
+                assert_eq!(diff_algorithm, "myers");
+                assert!(!word_diff);
+                assert!(!differential);
+            }
+            _ => panic!("Expected Resume command"),
+        }
+
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "resume",
+            "my-spec",
+            "--phase",
+            "review",
+            "--differential",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Resume {
+                differential: true,
+                ..
+            }
+        ));
+
+        assert!(
+            Cli::try_parse_from([
+                "xchecker",
+                "resume",
+                "my-spec",
+                "--phase",
+                "fixup",
+                "--diff-algorithm",
+                "minimal",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_estimate_flags_parse_on_resume_ci_and_bulk() {
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "ci",
+            "my-spec",
+            "--through",
+            "tasks",
+            "--estimate",
+            "--max-estimated-cost",
+            "2.5",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Ci {
+                estimate: true,
+                max_estimated_cost: Some(cost),
+                ..
+            } if cost == 2.5
+        ));
+
+        let cli = Cli::try_parse_from([
+            "xchecker",
+            "bulk",
+            "--filter",
+            "tag=payments",
+            "--estimate",
+            "--",
+            "run",
+            "design",
+        ])
+        .unwrap();
+        assert!(matches!(cli.command, Commands::Bulk { estimate: true, .. }));
+
+        assert!(
+            Cli::try_parse_from([
+                "xchecker",
+                "resume",
+                "my-spec",
+                "--phase",
+                "design",
+                "--max-estimated-cost",
+                "cheap",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cost_preflight_enforces_limit_and_pricing() {
+        use crate::orchestrator::PhaseEstimate;
+
+        let estimate = |cost: Option<f64>| RunEstimate {
+            spec_id: "demo".to_string(),
+            phases: vec![PhaseEstimate {
+                phase: PhaseId::Design,
+                model: "haiku".to_string(),
+                input_tokens: 10_000,
+                output_tokens: 2_000,
+                output_source: OutputEstimateSource::Default,
+                cost,
+            }],
+            input_tokens: 10_000,
+            output_tokens: 2_000,
+            cost: cost.unwrap_or_default(),
+            unpriced_models: if cost.is_some() {
+                vec![]
+            } else {
+                vec!["haiku".to_string()]
+            },
+        };
+        let limit = |max_cost| CostPreflight {
+            estimate_only: false,
+            max_cost: Some(max_cost),
+        };
+
+        let priced = [estimate(Some(0.02)), estimate(Some(0.02))];
+        assert!(limit(0.05).check(&priced, true).is_ok());
+        let err = limit(0.03).check(&priced, true).unwrap_err();
+        assert!(err.to_string().contains("exceeds the limit"), "{err:#}");
+
+        let err = limit(100.0).check(&[estimate(None)], true).unwrap_err();
+        assert!(
+            err.to_string().contains("no [pricing] entry for haiku"),
+            "{err:#}"
+        );
+        assert!(
+            CostPreflight::default()
+                .check(&[estimate(None)], true)
+                .is_ok()
+        );
+    }
+
     #[test]
     fn test_workspace_status_cli_parsing() {
         // Test that CLI arguments are properly parsed for project status command
@@ -5568,6 +11217,28 @@ fn count_pending_fixups_for_spec(spec_id: &str) -> u32 {
     crate::fixup::pending_fixups_for_spec(spec_id).targets
 }
 
+/// "Next steps" block recommended from the spec's receipts and pending fixups
+fn next_steps_block(spec_id: &str, handle: &OrchestratorHandle) -> String {
+    let receipts = handle.receipt_manager().list_receipts().unwrap_or_default();
+    let steps = crate::status::next_steps::recommend(
+        spec_id,
+        &receipts,
+        count_pending_fixups_for_spec(spec_id),
+    );
+    format_next_steps(&steps)
+}
+
+fn format_next_steps(steps: &[crate::types::NextStep]) -> String {
+    let mut block = String::from("\nNext steps:\n");
+    for (i, step) in steps.iter().enumerate() {
+        block.push_str(&format!("  {}. {}\n", i + 1, step.summary));
+        if let Some(command) = &step.command {
+            block.push_str(&format!("     {command}\n"));
+        }
+    }
+    block
+}
+
 /// Emit workspace status output as canonical JSON using JCS (RFC 8785)
 fn emit_workspace_status_json(output: &crate::types::WorkspaceStatusJsonOutput) -> Result<String> {
     // Use emit_jcs from crate root for JCS canonicalization
@@ -5794,6 +11465,28 @@ fn execute_project_tui_command(workspace_override: Option<&std::path::Path>) ->
 
 /// Execute template management commands
 /// Per FR-TEMPLATES (Requirements 4.7.1, 4.7.2, 4.7.3)
+fn execute_examples_command(cmd: ExamplesCommands) -> Result<()> {
+    match cmd {
+        ExamplesCommands::Emit { out } => {
+            for path in crate::example_generators::emit_examples(&out)? {
+                println!("Wrote {}", path.display());
+            }
+            Ok(())
+        }
+    }
+}
+
+fn execute_schema_command(cmd: SchemaCommands) -> Result<()> {
+    match cmd {
+        SchemaCommands::Docs { out } => {
+            for path in crate::schema_docs::emit_schema_docs(&out)? {
+                println!("Wrote {}", path.display());
+            }
+            Ok(())
+        }
+    }
+}
+
 fn execute_template_command(cmd: TemplateCommands) -> Result<()> {
     match cmd {
         TemplateCommands::List => {
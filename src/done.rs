@@ -0,0 +1,280 @@
+//! Definition-of-done evaluation for a spec
+//!
+//! The `[done]` section of config.toml lists what a spec needs before it counts
+//! as finished: completed phases, no open review findings above a severity,
+//! and hooks that passed. [`evaluate`] checks each configured criterion against
+//! the spec's artifacts and receipts and reports the unmet ones, for
+//! `xchecker status` and `xchecker ci --require-done`.
+//!
+//! A review finding is open until it is checked off (`- [x]`) in the checklist
+//! that `xchecker export-findings --to markdown-checklist` writes to
+//! `20-tasks.findings.md` (or in the checklist section older versions appended
+//! to the tasks artifact). Hook results come from receipts, which record pre-phase hook
+//! failures; post-phase hooks run after the receipt is written and are not
+//! covered.
+
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use std::collections::BTreeMap;
+
+use crate::compression;
+use crate::config::{DoneConfig, FINDING_SEVERITIES};
+use crate::export_findings::{CHECKLIST_HEADING, checklist_path};
+use crate::extraction::{ReviewFinding, extract_review_findings};
+use crate::types::{DoneStatus, PhaseId, Receipt};
+
+/// Warning prefixes that hook failures leave in receipts
+const HOOK_FAILURE_WARNINGS: [&str; 2] = ["hook_failed:", "hook_timeout:"];
+
+/// Evaluate `criteria` for the spec stored at `spec_dir`
+///
+/// `receipts` are the spec's receipts in any order; only the latest per phase
+/// is considered for hook results.
+pub fn evaluate(
+    criteria: &DoneConfig,
+    spec_dir: &Utf8Path,
+    receipts: &[Receipt],
+) -> Result<DoneStatus> {
+    let artifacts = spec_dir.join("artifacts");
+    let mut unmet = Vec::new();
+
+    for phase in &criteria.required_phases {
+        let completed = PhaseId::ORDER
+            .into_iter()
+            .find(|known| known.as_str() == phase)
+            .is_some_and(|phase| {
+                artifacts.join(phase.artifact_name("md")).is_file()
+                    && artifacts.join(phase.artifact_name("core.yaml")).is_file()
+            });
+        if !completed {
+            unmet.push(format!("phase '{phase}' has not completed"));
+        }
+    }
+
+    if let Some(max) = criteria.max_finding_severity.as_deref() {
+        let blocking = open_findings_above(&artifacts, max)?;
+        if !blocking.is_empty() {
+            let titles: Vec<String> = blocking
+                .iter()
+                .map(|finding| {
+                    format!(
+                        "{} ({})",
+                        finding.title,
+                        finding.severity.as_deref().unwrap_or_default()
+                    )
+                })
+                .collect();
+            unmet.push(format!(
+                "{} open review finding(s) above {max}: {}",
+                blocking.len(),
+                titles.join("; ")
+            ));
+        }
+    }
+
+    if criteria.hooks_green {
+        let mut latest: BTreeMap<&str, &Receipt> = BTreeMap::new();
+        for receipt in receipts {
+            let entry = latest.entry(receipt.phase.as_str()).or_insert(receipt);
+            if receipt.emitted_at > entry.emitted_at {
+                *entry = receipt;
+            }
+        }
+        for (phase, receipt) in latest {
+            if let Some(failure) = hook_failure(receipt) {
+                unmet.push(format!("{phase} hook failed: {failure}"));
+            }
+        }
+    }
+
+    Ok(DoneStatus {
+        done: unmet.is_empty(),
+        unmet,
+    })
+}
+
+/// Review findings more severe than `max` and not checked off in the findings checklist
+fn open_findings_above(artifacts: &Utf8Path, max: &str) -> Result<Vec<ReviewFinding>> {
+    let max_rank = severity_rank(max);
+    Ok(open_findings(artifacts)?
+        .into_iter()
+        .filter(|finding| {
+            finding
+                .severity
+                .as_deref()
+                .and_then(severity_rank)
+                .zip(max_rank)
+                .is_some_and(|(rank, max_rank)| rank < max_rank)
+        })
+        .collect())
+}
+
+/// Review findings not checked off in the findings checklist, of any severity
+pub(crate) fn open_findings(artifacts: &Utf8Path) -> Result<Vec<ReviewFinding>> {
+    let review_path = artifacts.join(PhaseId::Review.artifact_name("md"));
+    if !compression::exists(review_path.as_std_path()) {
+        return Ok(Vec::new());
+    }
+    let review = compression::read_to_string(review_path.as_std_path())
+        .with_context(|| format!("Failed to read {review_path}"))?;
+    // Older exports appended the checklist to the tasks artifact itself
+    let checklist = read_optional(&checklist_path(artifacts))?;
+    let tasks = read_optional(&artifacts.join(PhaseId::Tasks.artifact_name("md")))?;
+    let resolved: Vec<&str> = checked_items(&checklist)
+        .into_iter()
+        .chain(checked_items(&tasks))
+        .collect();
+
+    Ok(extract_review_findings(&review)
+        .into_iter()
+        .filter(|finding| !resolved.iter().any(|item| item.starts_with(&finding.title)))
+        .collect())
+}
+
+/// Contents of an optional artifact, empty when it does not exist
+fn read_optional(path: &Utf8Path) -> Result<String> {
+    match compression::read_to_string(path.as_std_path()) {
+        Ok(content) => Ok(content),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+        Err(e) => Err(e).with_context(|| format!("Failed to read {path}")),
+    }
+}
+
+/// Position in [`FINDING_SEVERITIES`]; lower is more severe
+pub(crate) fn severity_rank(severity: &str) -> Option<usize> {
+    FINDING_SEVERITIES
+        .iter()
+        .position(|known| known.eq_ignore_ascii_case(severity))
+}
+
+/// Text of the checked items under the review findings heading of a document
+fn checked_items(tasks: &str) -> Vec<&str> {
+    tasks
+        .lines()
+        .skip_while(|line| line.trim() != CHECKLIST_HEADING)
+        .skip(1)
+        .take_while(|line| !line.starts_with("## "))
+        .filter_map(|line| {
+            line.trim()
+                .strip_prefix("- [x] ")
+                .or_else(|| line.trim().strip_prefix("- [X] "))
+        })
+        .collect()
+}
+
+/// The hook failure a receipt records, if any
+fn hook_failure(receipt: &Receipt) -> Option<String> {
+    receipt
+        .warnings
+        .iter()
+        .find(|warning| {
+            HOOK_FAILURE_WARNINGS
+                .iter()
+                .any(|prefix| warning.starts_with(prefix))
+        })
+        .cloned()
+        .or_else(|| {
+            receipt
+                .flags
+                .get("hook_failure")
+                .map(|hook| format!("{hook} hook failed the phase"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example_generators::make_example_receipt_minimal;
+    use tempfile::TempDir;
+
+    const REVIEW: &str = "# Review\n\n## Issues\n\n- **[High]** Retry policy is unspecified.\n- Medium: Cache has no eviction policy\n- [Critical] Secrets are logged.\n";
+
+    fn spec_dir(temp: &TempDir) -> &Utf8Path {
+        let dir = Utf8Path::from_path(temp.path()).unwrap();
+        std::fs::create_dir_all(dir.join("artifacts")).unwrap();
+        dir
+    }
+
+    fn write_artifact(dir: &Utf8Path, phase: PhaseId, suffix: &str, content: &str) {
+        std::fs::write(
+            dir.join("artifacts").join(phase.artifact_name(suffix)),
+            content,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_unconfigured_criteria_are_done() {
+        let temp = TempDir::new().unwrap();
+        let status = evaluate(&DoneConfig::default(), spec_dir(&temp), &[]).unwrap();
+        assert_eq!(
+            status,
+            DoneStatus {
+                done: true,
+                unmet: vec![]
+            }
+        );
+    }
+
+    #[test]
+    fn test_required_phases_and_open_findings() {
+        let temp = TempDir::new().unwrap();
+        let dir = spec_dir(&temp);
+        write_artifact(dir, PhaseId::Requirements, "md", "# Req");
+        write_artifact(dir, PhaseId::Requirements, "core.yaml", "{}");
+        write_artifact(dir, PhaseId::Design, "md", "# Design");
+        write_artifact(dir, PhaseId::Review, "md", REVIEW);
+        write_artifact(
+            dir,
+            PhaseId::Tasks,
+            "findings.md",
+            "## Review Findings\n\n- [x] Secrets are logged (critical)\n- [ ] Retry policy is unspecified (high)\n",
+        );
+
+        let criteria = DoneConfig {
+            required_phases: vec!["requirements".to_string(), "design".to_string()],
+            max_finding_severity: Some("medium".to_string()),
+            hooks_green: false,
+        };
+        let status = evaluate(&criteria, dir, &[]).unwrap();
+        assert!(!status.done);
+        assert_eq!(
+            status.unmet,
+            [
+                "phase 'design' has not completed",
+                "1 open review finding(s) above medium: Retry policy is unspecified (high)",
+            ]
+        );
+
+        // Allowing high findings leaves only the incomplete phase
+        let criteria = DoneConfig {
+            max_finding_severity: Some("high".to_string()),
+            ..criteria
+        };
+        assert_eq!(evaluate(&criteria, dir, &[]).unwrap().unmet.len(), 1);
+    }
+
+    #[test]
+    fn test_hooks_green_uses_latest_receipt_per_phase() {
+        let temp = TempDir::new().unwrap();
+        let dir = spec_dir(&temp);
+
+        let mut failed = make_example_receipt_minimal();
+        failed
+            .warnings
+            .push("hook_failed:pre_phase:requirements:./lint.sh:exit_code=1".to_string());
+        let mut fixed = make_example_receipt_minimal();
+        fixed.emitted_at = failed.emitted_at + chrono::Duration::minutes(5);
+
+        let criteria = DoneConfig {
+            hooks_green: true,
+            ..DoneConfig::default()
+        };
+        let status = evaluate(&criteria, dir, &[failed.clone()]).unwrap();
+        assert!(!status.done);
+        assert!(status.unmet[0].contains("hook_failed:pre_phase"));
+
+        let status = evaluate(&criteria, dir, &[fixed, failed]).unwrap();
+        assert!(status.done);
+    }
+}
@@ -0,0 +1,397 @@
+//! `xchecker experiment`: run one phase against several models side by side
+//!
+//! Each model runs the phase in its own experiment branch: a copy of the spec
+//! under `<XCHECKER_HOME>/specs/<spec-id>.exp-<model>`, tagged `experiment` in
+//! its `spec.toml`; only a spec carrying that tag is ever replaced by a new
+//! branch. The source spec is never written, and a branch is a normal
+//! spec, so its artifacts and receipts can be inspected with `xchecker status`
+//! and removed with `xchecker clean` once the comparison is done.
+//!
+//! [`ExperimentReport`] compares the runs: duration, tokens, and cost from the
+//! branch receipts, and a unified diff of every model's output against the
+//! first model's, so a cheaper model can be checked for adequacy at a glance.
+
+use anyhow::{Context, Result, anyhow};
+use camino::Utf8Path;
+use serde::Serialize;
+use std::fmt::Write as _;
+use xchecker_utils::mddiff::{DEFAULT_CONTEXT_LINES, MarkdownDiff, unified_diff};
+
+use crate::config::Config;
+use crate::lock::FileLock;
+use crate::spec_id::sanitize_spec_id;
+use crate::status::metadata::SPEC_METADATA_FILE;
+use crate::types::PhaseId;
+
+/// Tag added to the `spec.toml` of every experiment branch
+pub const EXPERIMENT_TAG: &str = "experiment";
+
+/// Spec lock file, kept while a branch is replaced
+const LOCK_FILE: &str = ".lock";
+
+/// Spec directory entries that belong to a running process, not the spec
+const SKIPPED_ENTRIES: &[&str] = &[LOCK_FILE, "flow.lock", ".lock.heartbeat", ".partial"];
+
+/// Spec ID of the experiment branch of `spec_id` for `model`
+pub fn branch_spec_id(spec_id: &str, model: &str) -> Result<String> {
+    sanitize_spec_id(&format!("{spec_id}.exp-{model}"))
+        .map_err(|e| anyhow!("Invalid experiment branch for model '{model}': {e}"))
+}
+
+/// Copy the spec at `source` into a fresh experiment branch `branch_id`
+///
+/// An existing branch is replaced, so each experiment starts from the current
+/// state of the source spec, but only if it is tagged `experiment`: any other
+/// spec under that ID is left alone. The branch lock is held while it is
+/// replaced (`force` overrides a stale one). Locks and staged partial output
+/// are not copied.
+pub fn fork_spec(source: &Utf8Path, branch_id: &str, force: bool) -> Result<()> {
+    let branch = crate::paths::spec_root(branch_id);
+    let existed = branch.exists();
+    let _lock = FileLock::acquire(branch_id, force, None)
+        .map_err(|e| anyhow!("Cannot replace experiment branch '{branch_id}': {e}"))?;
+
+    if existed {
+        let is_branch = crate::status::metadata::load_spec_metadata(&branch)?
+            .is_some_and(|metadata| metadata.tags.iter().any(|tag| tag == EXPERIMENT_TAG));
+        if !is_branch {
+            return Err(anyhow!(
+                "Refusing to replace spec '{branch_id}': it is not tagged '{EXPERIMENT_TAG}', so it is not an experiment branch"
+            ));
+        }
+        clear_branch(&branch)
+            .with_context(|| format!("Failed to remove previous experiment branch: {branch}"))?;
+    }
+    copy_dir(source, &branch)
+        .with_context(|| format!("Failed to copy spec {source} to {branch}"))?;
+
+    let mut metadata = crate::status::metadata::load_spec_metadata(&branch)?.unwrap_or_default();
+    if !metadata.tags.iter().any(|tag| tag == EXPERIMENT_TAG) {
+        metadata.tags.push(EXPERIMENT_TAG.to_string());
+    }
+    let path = branch.join(SPEC_METADATA_FILE);
+    std::fs::write(&path, toml::to_string(&metadata)?)
+        .with_context(|| format!("Failed to write {path}"))
+}
+
+/// Remove everything in `branch` but the lock held while it is replaced
+fn clear_branch(branch: &Utf8Path) -> Result<()> {
+    for entry in branch.read_dir_utf8()? {
+        let entry = entry?;
+        if entry.file_name() == LOCK_FILE {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            std::fs::remove_dir_all(entry.path())?;
+        } else {
+            std::fs::remove_file(entry.path())?;
+        }
+    }
+    Ok(())
+}
+
+fn copy_dir(from: &Utf8Path, to: &Utf8Path) -> Result<()> {
+    crate::paths::ensure_dir_all(to)?;
+    for entry in from.read_dir_utf8()? {
+        let entry = entry?;
+        if SKIPPED_ENTRIES.contains(&entry.file_name()) {
+            continue;
+        }
+        let file_type = entry.file_type()?;
+        let target = to.join(entry.file_name());
+        if file_type.is_dir() {
+            copy_dir(entry.path(), &target)?;
+        } else if file_type.is_file() {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// `config` with `model` used for `phase`
+///
+/// The model replaces `defaults.model` and any `[phases.<phase>].model`
+/// override, which would otherwise take precedence.
+#[must_use]
+pub fn config_for_model(config: &Config, phase: PhaseId, model: &str) -> Config {
+    let mut config = config.clone();
+    config.defaults.model = Some(model.to_string());
+    if let Some(overrides) = config.phases.get_mut(phase) {
+        overrides.model = None;
+    }
+    config
+}
+
+/// Outcome of the phase run for one model
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelRun {
+    /// Model as given on the command line
+    pub model: String,
+    /// Spec ID of the experiment branch the phase ran in
+    pub spec_id: String,
+    pub success: bool,
+    pub exit_code: i32,
+    pub duration_ms: u64,
+    pub tokens_input: Option<u64>,
+    pub tokens_output: Option<u64>,
+    /// Cost in the units of `[pricing]`; absent for unpriced models
+    pub cost: Option<f64>,
+    pub error: Option<String>,
+    /// Markdown artifact of the phase; absent when the run failed
+    #[serde(skip)]
+    pub output: Option<String>,
+}
+
+/// Difference between one model's output and the baseline model's
+#[derive(Debug, Clone, Serialize)]
+pub struct OutputDiff {
+    pub model: String,
+    pub baseline: String,
+    pub lines_added: usize,
+    pub lines_removed: usize,
+    /// Unified diff from the baseline output; empty when identical
+    pub diff: String,
+}
+
+/// Side-by-side comparison of an experiment's runs
+#[derive(Debug, Clone, Serialize)]
+pub struct ExperimentReport {
+    pub spec_id: String,
+    pub phase: PhaseId,
+    pub runs: Vec<ModelRun>,
+    /// Diffs against the first model that produced output
+    pub diffs: Vec<OutputDiff>,
+}
+
+impl ExperimentReport {
+    /// Compare `runs`, diffing each output against the first successful run
+    #[must_use]
+    pub fn new(spec_id: &str, phase: PhaseId, runs: Vec<ModelRun>) -> Self {
+        let artifact = phase.artifact_name("md");
+        let baseline = runs
+            .iter()
+            .find_map(|run| run.output.as_deref().map(|output| (run, output)));
+        let diffs: Vec<OutputDiff> = baseline
+            .map(|(base, base_output)| {
+                runs.iter()
+                    .filter(|run| !std::ptr::eq(*run, base))
+                    .filter_map(|run| {
+                        let output = run.output.as_deref()?;
+                        let diff = unified_diff(
+                            &MarkdownDiff::default(),
+                            base_output,
+                            output,
+                            &artifact,
+                            DEFAULT_CONTEXT_LINES,
+                        );
+                        let (lines_added, lines_removed) = count_changes(&diff);
+                        Some(OutputDiff {
+                            model: run.model.clone(),
+                            baseline: base.model.clone(),
+                            lines_added,
+                            lines_removed,
+                            diff,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            spec_id: spec_id.to_string(),
+            phase,
+            runs,
+            diffs,
+        }
+    }
+
+    /// Runs that did not complete the phase
+    pub fn failed(&self) -> impl Iterator<Item = &ModelRun> {
+        self.runs.iter().filter(|run| !run.success)
+    }
+
+    /// Comparison table followed by the output diffs
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "# Experiment: {} / {} phase\n\n",
+            self.spec_id,
+            self.phase.as_str()
+        );
+        out.push_str(
+            "| Model | Branch | Result | Duration | Input tokens | Output tokens | Cost | Changes |\n",
+        );
+        out.push_str(
+            "|-------|--------|--------|----------|--------------|---------------|------|---------|\n",
+        );
+        for run in &self.runs {
+            let changes = match self.diffs.iter().find(|diff| diff.model == run.model) {
+                Some(diff) => format!("+{} -{}", diff.lines_added, diff.lines_removed),
+                None if run.output.is_some() => "baseline".to_string(),
+                None => "-".to_string(),
+            };
+            // Writing to a String cannot fail
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} | {:.1}s | {} | {} | {} | {} |",
+                run.model,
+                run.spec_id,
+                if run.success { "ok" } else { "failed" },
+                run.duration_ms as f64 / 1000.0,
+                run.tokens_input
+                    .map_or_else(|| "-".to_string(), |t| t.to_string()),
+                run.tokens_output
+                    .map_or_else(|| "-".to_string(), |t| t.to_string()),
+                run.cost
+                    .map_or_else(|| "-".to_string(), |c| format!("{c:.4}")),
+                changes,
+            );
+        }
+
+        for run in self.failed() {
+            let _ = write!(out, "\n{} failed (exit {})", run.model, run.exit_code);
+            if let Some(error) = &run.error {
+                let _ = write!(out, ": {error}");
+            }
+            out.push('\n');
+        }
+
+        for diff in &self.diffs {
+            let _ = write!(out, "\n## {} vs {}\n\n", diff.model, diff.baseline);
+            if diff.diff.is_empty() {
+                out.push_str("Output is identical.\n");
+            } else {
+                let _ = write!(out, "```diff\n{}```\n", diff.diff);
+            }
+        }
+        out
+    }
+}
+
+/// Added and removed lines of a unified diff, excluding the file headers
+fn count_changes(diff: &str) -> (usize, usize) {
+    diff.lines()
+        .filter(|line| !line.starts_with("+++ ") && !line.starts_with("--- "))
+        .fold((0, 0), |(added, removed), line| {
+            match line.as_bytes().first() {
+                Some(b'+') => (added + 1, removed),
+                Some(b'-') => (added, removed + 1),
+                _ => (added, removed),
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(model: &str, output: Option<&str>) -> ModelRun {
+        ModelRun {
+            model: model.to_string(),
+            spec_id: format!("payments.exp-{model}"),
+            success: output.is_some(),
+            exit_code: if output.is_some() { 0 } else { 70 },
+            duration_ms: 1500,
+            tokens_input: Some(1000),
+            tokens_output: Some(200),
+            cost: None,
+            error: output.is_none().then(|| "provider crashed".to_string()),
+            output: output.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_fork_spec_skips_locks_and_tags_branch() {
+        let _home = crate::paths::with_isolated_home();
+        let source = crate::paths::spec_root("payments");
+        std::fs::create_dir_all(source.join("artifacts")).unwrap();
+        std::fs::create_dir_all(source.join(".partial")).unwrap();
+        std::fs::write(source.join("artifacts/00-requirements.md"), "# Reqs\n").unwrap();
+        std::fs::write(source.join(".lock"), "{}").unwrap();
+        std::fs::write(source.join(".partial/10-design.md"), "half").unwrap();
+        std::fs::write(source.join(SPEC_METADATA_FILE), "tags = [\"billing\"]\n").unwrap();
+
+        let branch_id = branch_spec_id("payments", "haiku").unwrap();
+        let branch = crate::paths::spec_root(&branch_id);
+        std::fs::create_dir_all(&branch).unwrap();
+        std::fs::write(branch.join("stale.txt"), "old run").unwrap();
+        std::fs::write(branch.join(SPEC_METADATA_FILE), "tags = [\"experiment\"]\n").unwrap();
+        fork_spec(&source, &branch_id, false).unwrap();
+
+        assert_eq!(branch.file_name(), Some("payments.exp-haiku"));
+        assert!(branch.join("artifacts/00-requirements.md").is_file());
+        assert!(!branch.join(".lock").exists());
+        assert!(!branch.join(".partial").exists());
+        assert!(!branch.join("stale.txt").exists());
+        let metadata = crate::status::metadata::load_spec_metadata(&branch)
+            .unwrap()
+            .unwrap();
+        assert_eq!(metadata.tags, ["billing", EXPERIMENT_TAG]);
+    }
+
+    #[test]
+    fn test_fork_spec_only_replaces_experiment_branches() {
+        let _home = crate::paths::with_isolated_home();
+        let source = crate::paths::spec_root("payments");
+        std::fs::create_dir_all(&source).unwrap();
+        std::fs::write(source.join(SPEC_METADATA_FILE), "tags = [\"billing\"]\n").unwrap();
+
+        // A spec that merely shares the branch ID is not an experiment branch
+        let branch_id = branch_spec_id("payments", "haiku").unwrap();
+        let branch = crate::paths::spec_root(&branch_id);
+        std::fs::create_dir_all(branch.join("artifacts")).unwrap();
+        std::fs::write(branch.join("artifacts/00-requirements.md"), "mine").unwrap();
+        let err = fork_spec(&source, &branch_id, false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("not tagged 'experiment'"), "{err}");
+        assert!(branch.join("artifacts/00-requirements.md").is_file());
+
+        // A branch in use by another run is not replaced either
+        std::fs::write(branch.join(SPEC_METADATA_FILE), "tags = [\"experiment\"]\n").unwrap();
+        let lock = FileLock::acquire(&branch_id, false, None).unwrap();
+        let err = fork_spec(&source, &branch_id, false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Cannot replace experiment branch"), "{err}");
+        assert!(branch.join("artifacts/00-requirements.md").is_file());
+
+        drop(lock);
+        fork_spec(&source, &branch_id, false).unwrap();
+        assert!(!branch.join("artifacts").exists());
+    }
+
+    #[test]
+    fn test_report_diffs_against_first_successful_run() {
+        let report = ExperimentReport::new(
+            "payments",
+            PhaseId::Design,
+            vec![
+                run("opus", None),
+                run("haiku", Some("# Design\n\nUse a queue.\n")),
+                run("sonnet", Some("# Design\n\nUse a queue with retries.\n")),
+            ],
+        );
+
+        assert_eq!(report.failed().count(), 1);
+        assert_eq!(report.diffs.len(), 1);
+        let diff = &report.diffs[0];
+        assert_eq!(
+            (diff.model.as_str(), diff.baseline.as_str()),
+            ("sonnet", "haiku")
+        );
+        assert_eq!((diff.lines_added, diff.lines_removed), (1, 1));
+
+        let markdown = report.to_markdown();
+        assert!(
+            markdown
+                .contains("| haiku | payments.exp-haiku | ok | 1.5s | 1000 | 200 | - | baseline |")
+        );
+        assert!(
+            markdown
+                .contains("| sonnet | payments.exp-sonnet | ok | 1.5s | 1000 | 200 | - | +1 -1 |")
+        );
+        assert!(markdown.contains("opus failed (exit 70): provider crashed"));
+        assert!(markdown.contains("+Use a queue with retries."));
+    }
+}
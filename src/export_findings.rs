@@ -0,0 +1,248 @@
+//! `xchecker export-findings`: turn review findings into trackable work
+//!
+//! Findings are extracted from the review artifact (`30-review.md`) and
+//! exported either as a batch of GitHub issues or as a Markdown checklist in
+//! `20-tasks.findings.md`, next to the tasks artifact. The checklist file is
+//! not a phase output, so receipts never cover it and checking items off does
+//! not invalidate the tasks receipt. Re-exporting replaces the file.
+
+#[cfg(not(feature = "no-telemetry"))]
+use anyhow::Context;
+use anyhow::{Result, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Serialize;
+#[cfg(not(feature = "no-telemetry"))]
+use std::time::Duration;
+
+use crate::extraction::ReviewFinding;
+use crate::types::PhaseId;
+
+/// Heading of the exported checklist
+pub const CHECKLIST_HEADING: &str = "## Review Findings";
+
+/// Label added to every exported issue
+const DEFAULT_LABEL: &str = "xchecker";
+
+/// Timeout for each GitHub API request
+#[cfg(not(feature = "no-telemetry"))]
+const HTTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Where findings are exported to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportTarget {
+    /// One GitHub issue per finding
+    GithubIssues,
+    /// A checklist section in the tasks artifact
+    MarkdownChecklist,
+}
+
+impl ExportTarget {
+    /// Parse a `--to` value
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "github-issues" => Ok(Self::GithubIssues),
+            "markdown-checklist" => Ok(Self::MarkdownChecklist),
+            other => {
+                bail!(
+                    "unknown export target '{other}' (expected github-issues or markdown-checklist)"
+                )
+            }
+        }
+    }
+}
+
+/// Body of a `POST /repos/{owner}/{repo}/issues` request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IssuePayload {
+    pub title: String,
+    pub body: String,
+    pub labels: Vec<String>,
+}
+
+/// One issue payload per finding
+///
+/// Every issue is labelled `xchecker` and `spec:<id>`, plus
+/// `severity:<level>` when the finding has one and any `extra_labels`.
+#[must_use]
+pub fn issue_payloads(
+    spec_id: &str,
+    findings: &[ReviewFinding],
+    extra_labels: &[String],
+) -> Vec<IssuePayload> {
+    findings
+        .iter()
+        .map(|finding| {
+            let mut labels = vec![DEFAULT_LABEL.to_string(), format!("spec:{spec_id}")];
+            if let Some(severity) = &finding.severity {
+                labels.push(format!("severity:{severity}"));
+            }
+            labels.extend(extra_labels.iter().cloned());
+            labels.dedup();
+
+            let mut body = finding.body.clone();
+            body.push_str("\n\n---\n");
+            match &finding.section {
+                Some(section) => body.push_str(&format!(
+                    "Raised in the **{section}** section of the xchecker review for spec `{spec_id}` (`artifacts/30-review.md`)."
+                )),
+                None => body.push_str(&format!(
+                    "Raised in the xchecker review for spec `{spec_id}` (`artifacts/30-review.md`)."
+                )),
+            }
+
+            IssuePayload {
+                title: format!("[{spec_id}] {}", finding.title),
+                body,
+                labels,
+            }
+        })
+        .collect()
+}
+
+/// Checklist section listing every finding as an unchecked item
+#[must_use]
+pub fn render_checklist(findings: &[ReviewFinding]) -> String {
+    let mut out = format!("{CHECKLIST_HEADING}\n\n");
+    for finding in findings {
+        let severity = finding
+            .severity
+            .as_ref()
+            .map(|severity| format!(" ({severity})"))
+            .unwrap_or_default();
+        let section = finding
+            .section
+            .as_ref()
+            .map(|section| format!(" — _{section}_"))
+            .unwrap_or_default();
+        out.push_str(&format!("- [ ] {}{severity}{section}\n", finding.title));
+    }
+    out
+}
+
+/// Path of the exported checklist in a spec's artifacts directory
+#[must_use]
+pub fn checklist_path(artifacts: &Utf8Path) -> Utf8PathBuf {
+    artifacts.join(PhaseId::Tasks.artifact_name("findings.md"))
+}
+
+/// Create the issues in `repo` (`owner/name`), returning their URLs
+///
+/// Stops at the first failure; the error lists the issues already created so
+/// a retry can skip them. Compiled out of `no-telemetry` builds.
+#[cfg(not(feature = "no-telemetry"))]
+pub async fn file_issues(
+    repo: &str,
+    token: &str,
+    payloads: &[IssuePayload],
+) -> Result<Vec<String>> {
+    #[derive(serde::Deserialize)]
+    struct CreatedIssue {
+        html_url: String,
+    }
+
+    let client = reqwest::Client::builder()
+        .user_agent(format!("xchecker/{}", env!("CARGO_PKG_VERSION")))
+        .timeout(HTTP_TIMEOUT)
+        .build()
+        .context("Failed to build HTTP client")?;
+
+    let mut created = Vec::with_capacity(payloads.len());
+    for payload in payloads {
+        let result = async {
+            let issue: CreatedIssue = client
+                .post(format!("https://api.github.com/repos/{repo}/issues"))
+                .header("Accept", "application/vnd.github+json")
+                .bearer_auth(token)
+                .json(payload)
+                .send()
+                .await
+                .context("Failed to reach the GitHub API")?
+                .error_for_status()
+                .context("GitHub API rejected the issue")?
+                .json()
+                .await
+                .context("Failed to parse the created issue")?;
+            Ok::<_, anyhow::Error>(issue.html_url)
+        }
+        .await;
+
+        match result {
+            Ok(url) => created.push(url),
+            Err(e) if created.is_empty() => {
+                return Err(e.context(format!("Failed to create issue '{}'", payload.title)));
+            }
+            Err(e) => {
+                return Err(e.context(format!(
+                    "Failed to create issue '{}' after creating {}: {}",
+                    payload.title,
+                    created.len(),
+                    created.join(", ")
+                )));
+            }
+        }
+    }
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn findings() -> Vec<ReviewFinding> {
+        vec![
+            ReviewFinding {
+                title: "Retry policy is unspecified".to_string(),
+                body: "Retry policy is unspecified. Say how often capture retries.".to_string(),
+                section: Some("Design Issues".to_string()),
+                severity: Some("high".to_string()),
+            },
+            ReviewFinding {
+                title: "Missing migration task".to_string(),
+                body: "Missing migration task.".to_string(),
+                section: None,
+                severity: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_issue_payloads_carry_labels_and_provenance() {
+        let payloads = issue_payloads("payments", &findings(), &["review".to_string()]);
+        assert_eq!(payloads.len(), 2);
+        assert_eq!(payloads[0].title, "[payments] Retry policy is unspecified");
+        assert_eq!(
+            payloads[0].labels,
+            vec!["xchecker", "spec:payments", "severity:high", "review"]
+        );
+        assert!(payloads[0].body.contains("**Design Issues** section"));
+        assert_eq!(
+            payloads[1].labels,
+            vec!["xchecker", "spec:payments", "review"]
+        );
+    }
+
+    #[test]
+    fn test_checklist_lists_every_finding_unchecked() {
+        assert_eq!(
+            render_checklist(&findings()),
+            "## Review Findings\n\n- [ ] Retry policy is unspecified (high) — _Design Issues_\n- [ ] Missing migration task\n"
+        );
+        assert_eq!(
+            checklist_path(Utf8Path::new("specs/payments/artifacts")),
+            "specs/payments/artifacts/20-tasks.findings.md"
+        );
+    }
+
+    #[test]
+    fn test_export_target_parse() {
+        assert_eq!(
+            ExportTarget::parse("github-issues").unwrap(),
+            ExportTarget::GithubIssues
+        );
+        assert_eq!(
+            ExportTarget::parse("markdown-checklist").unwrap(),
+            ExportTarget::MarkdownChecklist
+        );
+        assert!(ExportTarget::parse("jira").is_err());
+    }
+}
@@ -0,0 +1,470 @@
+//! `xchecker hooks`: git hooks for repositories that commit spec state
+//!
+//! `install` writes a small shell hook into the repository's hooks directory
+//! (honouring `core.hooksPath`) that runs `xchecker hooks run <hook>`. The run
+//! collects the files the commit or push changes, scans them for secrets, and
+//! checks that the artifacts of every touched spec still hash to the values in
+//! their latest receipts. What runs is configured under `[git_hooks]` in the
+//! repository's `.xchecker/config.toml`.
+//!
+//! Secrets are scanned in the content being committed (the index for
+//! pre-commit, the pushed commit for pre-push); receipt consistency is checked
+//! against the working tree.
+
+use anyhow::{Context, Result, bail};
+use camino::Utf8Path;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+use crate::compression;
+use crate::receipt::ReceiptManager;
+use crate::redaction::SecretRedactor;
+use crate::types::{FileType, PhaseId};
+
+/// Line written into every managed hook; hooks without it are never overwritten silently
+pub const HOOK_MARKER: &str = "# Managed by `xchecker hooks install`; edits are overwritten";
+
+/// Object name git uses for "no commit" in pre-push input
+const ZERO_SHA: &str = "0000000000000000000000000000000000000000";
+
+const PHASES: [PhaseId; 7] = [
+    PhaseId::Requirements,
+    PhaseId::Design,
+    PhaseId::Tasks,
+    PhaseId::Review,
+    PhaseId::Fixup,
+    PhaseId::Final,
+    PhaseId::Localize,
+];
+
+/// A git hook `xchecker hooks` can manage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitHook {
+    PreCommit,
+    PrePush,
+}
+
+impl GitHook {
+    pub const ALL: [Self; 2] = [Self::PreCommit, Self::PrePush];
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::PreCommit => "pre-commit",
+            Self::PrePush => "pre-push",
+        }
+    }
+}
+
+impl fmt::Display for GitHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for GitHook {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "pre-commit" => Ok(Self::PreCommit),
+            "pre-push" => Ok(Self::PrePush),
+            other => bail!("unknown hook '{other}' (expected pre-commit or pre-push)"),
+        }
+    }
+}
+
+/// Shell script installed for `hook`
+#[must_use]
+pub fn hook_script(hook: GitHook) -> String {
+    format!(
+        "#!/bin/sh\n{HOOK_MARKER}\n# Configure checks under [git_hooks] in .xchecker/config.toml\nexec xchecker hooks run {hook}\n"
+    )
+}
+
+/// Hooks directory of the repository containing `repo`
+pub fn hooks_dir(repo: &Path) -> Result<PathBuf> {
+    let path = git(repo, &["rev-parse", "--git-path", "hooks"])?;
+    let path = PathBuf::from(path.trim());
+    Ok(if path.is_absolute() {
+        path
+    } else {
+        repo.join(path)
+    })
+}
+
+/// Write `hook` into `hooks_dir`
+///
+/// An existing hook not written by xchecker is only replaced with `force`.
+/// Returns the hook path and whether an existing file was replaced.
+pub fn install(hooks_dir: &Path, hook: GitHook, force: bool) -> Result<(PathBuf, bool)> {
+    let path = hooks_dir.join(hook.as_str());
+    let existing = match std::fs::read_to_string(&path) {
+        Ok(content) => Some(content),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    };
+    if let Some(content) = &existing
+        && !content.contains(HOOK_MARKER)
+        && !force
+    {
+        bail!(
+            "{} already exists and was not written by xchecker; use --force to replace it",
+            path.display()
+        );
+    }
+
+    crate::paths::ensure_dir_all(hooks_dir)
+        .with_context(|| format!("Failed to create {}", hooks_dir.display()))?;
+    std::fs::write(&path, hook_script(hook))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .with_context(|| format!("Failed to make {} executable", path.display()))?;
+    }
+    Ok((path, existing.is_some()))
+}
+
+/// Files changed by the commit or push, with the revision holding their new content
+///
+/// For pre-commit the revision is the index (`""`). For pre-push, `push_input`
+/// is the `<local ref> <local sha> <remote ref> <remote sha>` lines git passes
+/// on stdin; commits not yet on any remote are inspected for new branches.
+pub fn changed_files(repo: &Path, hook: GitHook, push_input: &str) -> Result<Vec<ChangedFile>> {
+    let mut files = Vec::new();
+    match hook {
+        GitHook::PreCommit => {
+            let out = git(
+                repo,
+                &[
+                    "diff",
+                    "--cached",
+                    "--name-only",
+                    "--diff-filter=ACMR",
+                    "-z",
+                ],
+            )?;
+            files.extend(
+                out.split('\0')
+                    .filter(|p| !p.is_empty())
+                    .map(|path| ChangedFile {
+                        path: path.to_string(),
+                        rev: String::new(),
+                    }),
+            );
+        }
+        GitHook::PrePush => {
+            for line in push_input.lines() {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let [_, local_sha, _, remote_sha] = fields[..] else {
+                    continue;
+                };
+                if local_sha == ZERO_SHA {
+                    // Branch deletion pushes no content
+                    continue;
+                }
+                let range = if remote_sha == ZERO_SHA {
+                    vec![
+                        local_sha.to_string(),
+                        "--not".to_string(),
+                        "--remotes".to_string(),
+                    ]
+                } else {
+                    vec![format!("{remote_sha}..{local_sha}")]
+                };
+                let mut args = vec![
+                    "log",
+                    "--format=",
+                    "--name-only",
+                    "--diff-filter=ACMR",
+                    "-z",
+                ];
+                args.extend(range.iter().map(String::as_str));
+                let out = git(repo, &args)?;
+                let paths: BTreeSet<&str> =
+                    out.split(['\0', '\n']).filter(|p| !p.is_empty()).collect();
+                files.extend(paths.into_iter().map(|path| ChangedFile {
+                    path: path.to_string(),
+                    rev: local_sha.to_string(),
+                }));
+            }
+        }
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    files.dedup_by(|a, b| a.path == b.path);
+    Ok(files)
+}
+
+/// A file touched by the commit or push
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangedFile {
+    /// Path relative to the repository root
+    pub path: String,
+    /// Revision holding the new content; empty for the index
+    pub rev: String,
+}
+
+/// Spec IDs with files under `specs_dir` (relative to the repository root)
+#[must_use]
+pub fn touched_specs(files: &[ChangedFile], specs_dir: &Utf8Path) -> BTreeSet<String> {
+    let prefix = specs_prefix(specs_dir);
+    files
+        .iter()
+        .filter_map(|file| file.path.strip_prefix(&prefix))
+        .filter_map(|rest| rest.split_once('/'))
+        .map(|(spec_id, _)| spec_id.to_string())
+        .collect()
+}
+
+/// The subset of `files` under `specs_dir`
+#[must_use]
+pub fn spec_files(files: Vec<ChangedFile>, specs_dir: &Utf8Path) -> Vec<ChangedFile> {
+    let prefix = specs_prefix(specs_dir);
+    files
+        .into_iter()
+        .filter(|file| file.path.starts_with(&prefix))
+        .collect()
+}
+
+fn specs_prefix(specs_dir: &Utf8Path) -> String {
+    format!("{}/", specs_dir.as_str().trim_end_matches('/'))
+}
+
+/// A problem that fails the hook
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookFinding {
+    /// A secret pattern matched committed content
+    Secret {
+        path: String,
+        line: usize,
+        pattern_id: String,
+    },
+    /// A receipt output no longer matches the artifact on disk
+    ReceiptMismatch { spec_id: String, path: String },
+    /// A receipt output is missing from disk
+    MissingArtifact { spec_id: String, path: String },
+}
+
+impl fmt::Display for HookFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Secret {
+                path,
+                line,
+                pattern_id,
+            } => write!(f, "{path}:{line}: possible secret ({pattern_id})"),
+            Self::ReceiptMismatch { spec_id, path } => write!(
+                f,
+                "{spec_id}: {path} does not match the hash in its latest receipt"
+            ),
+            Self::MissingArtifact { spec_id, path } => {
+                write!(f, "{spec_id}: {path} is recorded in a receipt but missing")
+            }
+        }
+    }
+}
+
+/// Scan the committed content of `files` for secrets
+///
+/// Binary or non-UTF-8 files are skipped.
+pub fn scan_secrets(
+    repo: &Path,
+    redactor: &SecretRedactor,
+    files: &[ChangedFile],
+) -> Result<Vec<HookFinding>> {
+    let mut findings = Vec::new();
+    for file in files {
+        let object = format!("{}:{}", file.rev, file.path);
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args(["show", &object])
+            .output()
+            .context("Failed to run git")?;
+        if !output.status.success() {
+            continue;
+        }
+        let Ok(content) = String::from_utf8(output.stdout) else {
+            continue;
+        };
+        for found in redactor.scan_for_secrets(&content, &file.path)? {
+            findings.push(HookFinding::Secret {
+                path: file.path.clone(),
+                line: found.line_number,
+                pattern_id: found.pattern_id,
+            });
+        }
+    }
+    Ok(findings)
+}
+
+/// Check every output of the spec's latest receipts against the artifact on disk
+pub fn verify_receipts(spec_id: &str, spec_root: &Utf8Path) -> Result<Vec<HookFinding>> {
+    let receipts = ReceiptManager::new(&spec_root.to_path_buf());
+    let mut findings = Vec::new();
+    for phase in PHASES {
+        let Some(receipt) = receipts.read_latest_receipt(phase)? else {
+            continue;
+        };
+        for output in &receipt.outputs {
+            let path = spec_root.join(&output.path);
+            // Receipts hash the uncompressed content, so compressed artifacts verify as-is
+            let Ok(content) = compression::read_to_string(path.as_std_path()) else {
+                findings.push(HookFinding::MissingArtifact {
+                    spec_id: spec_id.to_string(),
+                    path: path.to_string(),
+                });
+                continue;
+            };
+            let file_type = FileType::from_extension(path.extension().unwrap_or(""));
+            let actual =
+                receipts.create_file_hash(&output.path, &content, file_type, phase.as_str())?;
+            if actual.blake3_canonicalized != output.blake3_canonicalized {
+                findings.push(HookFinding::ReceiptMismatch {
+                    spec_id: spec_id.to_string(),
+                    path: path.to_string(),
+                });
+            }
+        }
+    }
+    Ok(findings)
+}
+
+fn git(repo: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    String::from_utf8(output.stdout).context("git printed non-UTF-8 output")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_install_keeps_foreign_hooks_without_force() {
+        let dir = tempfile::tempdir().unwrap();
+        let (path, replaced) = install(dir.path(), GitHook::PreCommit, false).unwrap();
+        assert!(!replaced);
+        let script = std::fs::read_to_string(&path).unwrap();
+        assert!(script.starts_with("#!/bin/sh\n"));
+        assert!(script.contains("exec xchecker hooks run pre-commit"));
+
+        // Reinstalling over a managed hook is fine
+        let (_, replaced) = install(dir.path(), GitHook::PreCommit, false).unwrap();
+        assert!(replaced);
+
+        std::fs::write(dir.path().join("pre-push"), "#!/bin/sh\nmake lint\n").unwrap();
+        let err = install(dir.path(), GitHook::PrePush, false).unwrap_err();
+        assert!(err.to_string().contains("--force"));
+        install(dir.path(), GitHook::PrePush, true).unwrap();
+    }
+
+    #[test]
+    fn test_touched_specs_only_counts_files_inside_spec_dirs() {
+        let files: Vec<ChangedFile> = [
+            ".xchecker/specs/payments/artifacts/00-requirements.md",
+            ".xchecker/specs/payments/receipts/requirements-1.json",
+            ".xchecker/specs/search/spec.toml",
+            ".xchecker/specs/README.md",
+            "src/main.rs",
+        ]
+        .iter()
+        .map(|path| ChangedFile {
+            path: (*path).to_string(),
+            rev: String::new(),
+        })
+        .collect();
+
+        let specs = touched_specs(&files, Utf8Path::new(".xchecker/specs"));
+        assert_eq!(
+            specs.into_iter().collect::<Vec<_>>(),
+            vec!["payments".to_string(), "search".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_sync_and_checklist_export_keep_receipts_valid() {
+        use crate::task_sync::{SyncedTask, TrackerKind, TrackerSync};
+
+        let temp = tempfile::tempdir().unwrap();
+        let spec_root = Utf8Path::from_path(temp.path()).unwrap();
+        let artifacts = spec_root.join("artifacts");
+        std::fs::create_dir_all(&artifacts).unwrap();
+        let tasks_md = "# Implementation Plan\n\n- [ ] 1. Set up project structure\n";
+        let core_yaml = "spec_id: \"payments\"\nphase: \"tasks\"\ntasks: []\n";
+        std::fs::write(artifacts.join("20-tasks.md"), tasks_md).unwrap();
+        std::fs::write(artifacts.join("20-tasks.core.yaml"), core_yaml).unwrap();
+
+        let receipts = ReceiptManager::new(&spec_root.to_path_buf());
+        let mut receipt = crate::example_generators::make_example_receipt_minimal();
+        receipt.spec_id = "payments".to_string();
+        receipt.phase = PhaseId::Tasks.as_str().to_string();
+        receipt.outputs = vec![
+            receipts
+                .create_file_hash(
+                    "artifacts/20-tasks.md",
+                    tasks_md,
+                    FileType::Markdown,
+                    "tasks",
+                )
+                .unwrap(),
+            receipts
+                .create_file_hash(
+                    "artifacts/20-tasks.core.yaml",
+                    core_yaml,
+                    FileType::Yaml,
+                    "tasks",
+                )
+                .unwrap(),
+        ];
+        receipts.write_receipt(&receipt).unwrap();
+        assert!(verify_receipts("payments", spec_root).unwrap().is_empty());
+
+        crate::task_sync::save_sync(
+            &artifacts,
+            &TrackerSync {
+                tracker: TrackerKind::Github,
+                target: "acme/payments".to_string(),
+                pushed_at: chrono::Utc::now(),
+                pulled_at: Some(chrono::Utc::now()),
+                items: vec![SyncedTask {
+                    task: "1".to_string(),
+                    title: "Set up project structure".to_string(),
+                    key: "41".to_string(),
+                    url: "https://github.com/acme/payments/issues/41".to_string(),
+                    done: true,
+                }],
+            },
+        )
+        .unwrap();
+        std::fs::write(
+            crate::export_findings::checklist_path(&artifacts),
+            "## Review Findings\n\n- [x] Retry policy is unspecified (high)\n",
+        )
+        .unwrap();
+        assert!(verify_receipts("payments", spec_root).unwrap().is_empty());
+
+        // Editing a receipted artifact is still caught
+        std::fs::write(artifacts.join("20-tasks.md"), "# Rewritten\n").unwrap();
+        assert!(matches!(
+            verify_receipts("payments", spec_root).unwrap().as_slice(),
+            [HookFinding::ReceiptMismatch { .. }]
+        ));
+    }
+}
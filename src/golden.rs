@@ -0,0 +1,374 @@
+//! Golden-snapshot regression tests for teams extending xchecker (feature `test-support`)
+//!
+//! [`GoldenWorkflow`] runs phases against the simulated (dry-run) provider in
+//! an isolated temporary home and collects the spec's artifacts and receipts
+//! into a [`GoldenSnapshot`]. Fields that change from run to run (timestamps,
+//! versions, run IDs, process usage) are masked, so the snapshot is stable
+//! and can be compared against a file checked into the repository.
+//!
+//! ```rust,no_run
+//! use xchecker::golden::GoldenWorkflow;
+//!
+//! #[tokio::test]
+//! async fn requirements_through_tasks_are_stable() -> anyhow::Result<()> {
+//!     let snapshot = GoldenWorkflow::new("golden-demo").run().await?;
+//!     snapshot.assert_matches("tests/golden/demo.snap");
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Set `XCHECKER_UPDATE_GOLDEN=1` to write the current snapshot instead of
+//! comparing; a missing golden file is always written.
+//!
+//! The isolated home is thread-local, so run workflows on a current-thread
+//! runtime (the `#[tokio::test]` default).
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use serde_json::Value;
+
+use crate::compression;
+use crate::orchestrator::{OrchestratorConfig, OrchestratorHandle};
+use crate::paths::with_isolated_home;
+use crate::types::PhaseId;
+
+/// Environment variable that makes [`GoldenSnapshot::assert_matches`]
+/// rewrite golden files instead of comparing against them
+pub const UPDATE_GOLDEN_ENV: &str = "XCHECKER_UPDATE_GOLDEN";
+
+/// Replacement for masked values
+pub const MASKED: &str = "[masked]";
+
+/// Receipt fields masked at any depth because they differ between runs
+pub const VOLATILE_RECEIPT_FIELDS: &[&str] =
+    &["emitted_at", "xchecker_version", "run_id", "process_usage"];
+
+/// Artifact front matter keys masked because they differ between runs
+const VOLATILE_FRONT_MATTER_KEYS: &[&str] = &["run_id", "receipt"];
+
+/// YAML artifact keys masked at any depth because they differ between runs
+const VOLATILE_YAML_KEYS: &[&str] = &["generated_at"];
+
+/// A workflow to run against the simulated provider for a golden snapshot
+#[derive(Debug, Clone)]
+pub struct GoldenWorkflow {
+    spec_id: String,
+    phases: Vec<PhaseId>,
+    config: HashMap<String, String>,
+}
+
+impl GoldenWorkflow {
+    /// Workflow running Requirements, Design, and Tasks for `spec_id`
+    #[must_use]
+    pub fn new(spec_id: &str) -> Self {
+        Self {
+            spec_id: spec_id.to_string(),
+            phases: vec![PhaseId::Requirements, PhaseId::Design, PhaseId::Tasks],
+            config: HashMap::new(),
+        }
+    }
+
+    /// Run `phases` in order instead of Requirements, Design, and Tasks
+    #[must_use]
+    pub fn phases(mut self, phases: &[PhaseId]) -> Self {
+        self.phases = phases.to_vec();
+        self
+    }
+
+    /// Set an orchestrator config key for every phase
+    #[must_use]
+    pub fn config(mut self, key: &str, value: &str) -> Self {
+        self.config.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Run the phases in a fresh temporary home and snapshot the spec
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a phase fails or the spec cannot be read back.
+    pub async fn run(&self) -> Result<GoldenSnapshot> {
+        let _home = with_isolated_home();
+        let config = OrchestratorConfig {
+            dry_run: true,
+            config: self.config.clone(),
+            ..OrchestratorConfig::default()
+        };
+
+        let mut handle = OrchestratorHandle::with_config_and_force(&self.spec_id, config, false)?;
+        for &phase in &self.phases {
+            let result = handle.run_phase(phase).await?;
+            if !result.success {
+                bail!(
+                    "{} phase failed with exit code {}: {}",
+                    phase.as_str(),
+                    result.exit_code,
+                    result.error.unwrap_or_default()
+                );
+            }
+        }
+
+        GoldenSnapshot::capture(&handle)
+    }
+}
+
+/// Masked artifacts and receipts of a spec, keyed by path
+///
+/// Artifacts are keyed `artifacts/<name>`; receipts are keyed
+/// `receipts/<phase>.json` in emission order, with `.2`, `.3`, ... appended
+/// to the phase for repeated runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GoldenSnapshot {
+    /// Masked file contents by spec-relative path
+    pub files: BTreeMap<String, String>,
+}
+
+impl GoldenSnapshot {
+    fn capture(handle: &OrchestratorHandle) -> Result<Self> {
+        let mut files = BTreeMap::new();
+
+        let artifacts_dir = handle.artifact_manager().artifacts_path();
+        for name in handle.artifact_manager().list_artifacts()? {
+            let path = artifacts_dir.join(&name);
+            let content = compression::read_to_string(path.as_std_path())
+                .with_context(|| format!("Failed to read artifact {path}"))?;
+            let masked = if is_yaml(&name) {
+                mask_yaml(&content)
+            } else {
+                mask_front_matter(&content)
+            };
+            files.insert(format!("artifacts/{name}"), masked);
+        }
+
+        let mut runs: HashMap<String, usize> = HashMap::new();
+        for receipt in handle.receipt_manager().list_receipts()? {
+            let run = runs.entry(receipt.phase.clone()).or_default();
+            *run += 1;
+            let key = match *run {
+                1 => format!("receipts/{}.json", receipt.phase),
+                n => format!("receipts/{}.{n}.json", receipt.phase),
+            };
+            let mut value = serde_json::to_value(&receipt)?;
+            mask_receipt(&mut value);
+            files.insert(key, serde_json::to_string_pretty(&value)?);
+        }
+
+        Ok(Self { files })
+    }
+
+    /// Render the snapshot as one text document, one section per file
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for (path, content) in &self.files {
+            out.push_str(&format!("=== {path} ===\n"));
+            out.push_str(content);
+            if !content.ends_with('\n') {
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    /// Compare the rendered snapshot with `golden_path`
+    ///
+    /// Writes the golden file instead when it does not exist or
+    /// [`UPDATE_GOLDEN_ENV`] is set to `1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a unified diff when the snapshot differs from the golden
+    /// file, or when the golden file cannot be read or written.
+    pub fn assert_matches(&self, golden_path: impl AsRef<Path>) {
+        let golden_path = golden_path.as_ref();
+        let actual = self.render();
+        let update = std::env::var(UPDATE_GOLDEN_ENV).is_ok_and(|v| v == "1");
+
+        if update || !golden_path.exists() {
+            if let Some(parent) = golden_path.parent() {
+                std::fs::create_dir_all(parent).expect("create golden directory");
+            }
+            std::fs::write(golden_path, &actual).expect("write golden file");
+            return;
+        }
+
+        let expected = std::fs::read_to_string(golden_path).expect("read golden file");
+        if expected != actual {
+            let diff = xchecker_utils::mddiff::unified_diff(
+                &xchecker_utils::mddiff::MyersDiff,
+                &expected,
+                &actual,
+                &golden_path.display().to_string(),
+                3,
+            );
+            panic!(
+                "snapshot differs from {}; rerun with {UPDATE_GOLDEN_ENV}=1 to accept it\n{diff}",
+                golden_path.display()
+            );
+        }
+    }
+}
+
+/// Replace [`VOLATILE_RECEIPT_FIELDS`] anywhere in a receipt with [`MASKED`]
+///
+/// The output hashes of YAML artifacts are masked as well: they cover the
+/// artifact's `generated_at`, so they change on every run.
+pub fn mask_receipt(value: &mut Value) {
+    mask_volatile_fields(value);
+    if let Some(outputs) = value.get_mut("outputs").and_then(Value::as_array_mut) {
+        for output in outputs {
+            let yaml = output
+                .get("path")
+                .and_then(Value::as_str)
+                .is_some_and(is_yaml);
+            if let Some(hash) = output.get_mut("blake3_canonicalized").filter(|_| yaml) {
+                *hash = Value::String(MASKED.to_string());
+            }
+        }
+    }
+}
+
+fn mask_volatile_fields(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if VOLATILE_RECEIPT_FIELDS.contains(&key.as_str()) && !field.is_null() {
+                    *field = Value::String(MASKED.to_string());
+                } else {
+                    mask_volatile_fields(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(mask_volatile_fields),
+        _ => {}
+    }
+}
+
+/// Mask the per-run keys of a Markdown artifact's YAML front matter
+fn mask_front_matter(content: &str) -> String {
+    let Some(rest) = content.strip_prefix("---\n") else {
+        return content.to_string();
+    };
+    let Some(end) = rest.find("\n---\n") else {
+        return content.to_string();
+    };
+
+    let mut out = String::from("---\n");
+    for line in rest[..end].lines() {
+        match line.split_once(':') {
+            Some((key, _)) if VOLATILE_FRONT_MATTER_KEYS.contains(&key) => {
+                out.push_str(&format!("{key}: \"{MASKED}\"\n"));
+            }
+            _ => {
+                out.push_str(line);
+                out.push('\n');
+            }
+        }
+    }
+    out.push_str(&rest[end + 1..]);
+    out
+}
+
+/// Whether `path` names a YAML artifact, compressed or not
+fn is_yaml(path: &str) -> bool {
+    let path = path.strip_suffix(".zst").unwrap_or(path);
+    path.ends_with(".yaml") || path.ends_with(".yml")
+}
+
+/// Mask [`VOLATILE_YAML_KEYS`] in a YAML artifact, keeping indentation
+fn mask_yaml(content: &str) -> String {
+    let mut out = String::with_capacity(content.len());
+    for line in content.lines() {
+        let body = line.trim_start();
+        let indent = &line[..line.len() - body.len()];
+        match body.split_once(':') {
+            Some((key, _)) if VOLATILE_YAML_KEYS.contains(&key) => {
+                out.push_str(&format!("{indent}{key}: \"{MASKED}\"\n"));
+            }
+            _ => {
+                out.push_str(line);
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask_receipt_masks_volatile_fields_at_any_depth() {
+        let mut receipt = serde_json::json!({
+            "emitted_at": "2026-10-17T12:00:00Z",
+            "xchecker_version": "1.0.0+abc",
+            "spec_id": "demo",
+            "llm": { "process_usage": { "wall_time_ms": 12 }, "tokens_input": 1000 },
+            "runner_distro": null,
+        });
+        mask_receipt(&mut receipt);
+
+        assert_eq!(receipt["emitted_at"], MASKED);
+        assert_eq!(receipt["xchecker_version"], MASKED);
+        assert_eq!(receipt["llm"]["process_usage"], MASKED);
+        assert_eq!(receipt["spec_id"], "demo");
+        assert_eq!(receipt["llm"]["tokens_input"], 1000);
+    }
+
+    #[test]
+    fn test_mask_yaml_artifact_and_its_output_hash() {
+        let core = "spec_id: demo\ngenerated_at: 2026-10-17T12:00:00Z\nmeta:\n  generated_at: 2026-10-17T12:00:01Z\n";
+        assert_eq!(
+            mask_yaml(core),
+            "spec_id: demo\ngenerated_at: \"[masked]\"\nmeta:\n  generated_at: \"[masked]\"\n"
+        );
+
+        let mut receipt = serde_json::json!({
+            "outputs": [
+                { "path": "artifacts/00-requirements.md", "blake3_canonicalized": "aa" },
+                { "path": "artifacts/00-requirements.core.yaml", "blake3_canonicalized": "bb" },
+            ],
+        });
+        mask_receipt(&mut receipt);
+        assert_eq!(receipt["outputs"][0]["blake3_canonicalized"], "aa");
+        assert_eq!(receipt["outputs"][1]["blake3_canonicalized"], MASKED);
+    }
+
+    #[test]
+    fn test_mask_front_matter_keeps_body_and_stable_keys() {
+        let artifact = "---\nspec_id: \"demo\"\nrun_id: \"r-123\"\nreceipt: \"receipts/x.json\"\n---\n# Requirements\n";
+        assert_eq!(
+            mask_front_matter(artifact),
+            "---\nspec_id: \"demo\"\nrun_id: \"[masked]\"\nreceipt: \"[masked]\"\n---\n# Requirements\n"
+        );
+        assert_eq!(
+            mask_front_matter("# No front matter\n"),
+            "# No front matter\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_golden_workflow_snapshot_is_stable_across_runs() -> Result<()> {
+        let workflow = GoldenWorkflow::new("golden-helper").phases(&[PhaseId::Requirements]);
+        let first = workflow.run().await?;
+        let second = workflow.run().await?;
+
+        assert!(first.files.contains_key("receipts/requirements.json"));
+        assert!(
+            first
+                .files
+                .keys()
+                .any(|path| path.starts_with("artifacts/"))
+        );
+        assert_eq!(first, second);
+
+        let dir = tempfile::TempDir::new()?;
+        let golden = dir.path().join("requirements.snap");
+        first.assert_matches(&golden);
+        second.assert_matches(&golden);
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+//! Spec health score for fleet overviews
+//!
+//! [`score`] condenses a spec's receipts and artifacts into a 0–100 number
+//! shown by `xchecker status --all` and the trends section of
+//! `xchecker export-site`, so a whole fleet of specs can be triaged at a
+//! glance. Four components add up to the score:
+//!
+//! - **Recency** (20): full within a week of the last run, falling linearly to
+//!   zero at 90 days.
+//! - **Reliability** (30): share of successful runs among the last 20.
+//! - **Findings** (30): reduced for each review finding not yet checked off
+//!   in the findings checklist, more for severe ones.
+//! - **Doneness** (20): share of the `[done]` criteria met; without a `[done]`
+//!   section, share of the requirements, design, and tasks phases completed.
+//!
+//! A spec that has never run scores zero for recency and reliability.
+
+use anyhow::Result;
+use camino::Utf8Path;
+use chrono::{DateTime, Utc};
+
+use crate::config::DoneConfig;
+use crate::done::{self, open_findings, severity_rank};
+use crate::types::{PhaseId, Receipt, SpecHealth};
+
+const RECENCY_POINTS: u32 = 20;
+const RELIABILITY_POINTS: u32 = 30;
+const FINDINGS_POINTS: u32 = 30;
+const DONENESS_POINTS: u32 = 20;
+
+/// Days since the last run that still earn full recency points
+const FRESH_DAYS: i64 = 7;
+/// Days since the last run after which no recency points remain
+const STALE_DAYS: i64 = 90;
+
+/// Runs considered for reliability, most recent first
+const RECENT_RUNS: usize = 20;
+
+/// Points an open finding costs, indexed like `FINDING_SEVERITIES`
+/// (critical, high, medium, low, minor)
+const SEVERITY_PENALTIES: [u32; 5] = [15, 8, 4, 1, 1];
+/// Points an open finding without a recognized severity costs
+const UNRATED_PENALTY: u32 = 2;
+
+/// Phases whose completion stands in for doneness without a `[done]` section
+const DEFAULT_DONE_PHASES: [PhaseId; 3] = [PhaseId::Requirements, PhaseId::Design, PhaseId::Tasks];
+
+/// Health of the spec stored at `spec_dir` as of `now`
+///
+/// `receipts` are the spec's receipts in any order; `criteria` is the `[done]`
+/// section of the configuration.
+pub fn score(
+    criteria: &DoneConfig,
+    spec_dir: &Utf8Path,
+    receipts: &[Receipt],
+    now: DateTime<Utc>,
+) -> Result<SpecHealth> {
+    let recency = recency_points(receipts, now);
+    let reliability = reliability_points(receipts);
+    let findings = findings_points(spec_dir)?;
+    let doneness = doneness_points(criteria, spec_dir, receipts)?;
+
+    Ok(SpecHealth {
+        score: to_u8(recency + reliability + findings + doneness),
+        recency: to_u8(recency),
+        reliability: to_u8(reliability),
+        findings: to_u8(findings),
+        doneness: to_u8(doneness),
+    })
+}
+
+fn recency_points(receipts: &[Receipt], now: DateTime<Utc>) -> u32 {
+    let Some(latest) = receipts.iter().map(|r| r.emitted_at).max() else {
+        return 0;
+    };
+    let age_days = (now - latest).num_days().max(0);
+    if age_days <= FRESH_DAYS {
+        RECENCY_POINTS
+    } else if age_days >= STALE_DAYS {
+        0
+    } else {
+        let remaining = (STALE_DAYS - age_days) as u32;
+        ratio(RECENCY_POINTS, remaining, (STALE_DAYS - FRESH_DAYS) as u32)
+    }
+}
+
+fn reliability_points(receipts: &[Receipt]) -> u32 {
+    let mut recent: Vec<&Receipt> = receipts.iter().collect();
+    recent.sort_by_key(|r| std::cmp::Reverse(r.emitted_at));
+    recent.truncate(RECENT_RUNS);
+    if recent.is_empty() {
+        return 0;
+    }
+    let succeeded = recent.iter().filter(|r| r.exit_code == 0).count();
+    ratio(RELIABILITY_POINTS, succeeded as u32, recent.len() as u32)
+}
+
+fn findings_points(spec_dir: &Utf8Path) -> Result<u32> {
+    let penalty: u32 = open_findings(&spec_dir.join("artifacts"))?
+        .iter()
+        .map(|finding| {
+            finding
+                .severity
+                .as_deref()
+                .and_then(severity_rank)
+                .map_or(UNRATED_PENALTY, |rank| SEVERITY_PENALTIES[rank])
+        })
+        .sum();
+    Ok(FINDINGS_POINTS.saturating_sub(penalty))
+}
+
+fn doneness_points(
+    criteria: &DoneConfig,
+    spec_dir: &Utf8Path,
+    receipts: &[Receipt],
+) -> Result<u32> {
+    let criteria = if criteria.is_configured() {
+        criteria.clone()
+    } else {
+        DoneConfig {
+            required_phases: DEFAULT_DONE_PHASES
+                .iter()
+                .map(|phase| phase.as_str().to_string())
+                .collect(),
+            ..DoneConfig::default()
+        }
+    };
+    let total = criteria.required_phases.len()
+        + usize::from(criteria.max_finding_severity.is_some())
+        + usize::from(criteria.hooks_green);
+    if total == 0 {
+        return Ok(DONENESS_POINTS);
+    }
+
+    // Several hooks can fail at once; each criterion counts as unmet only once
+    let unmet = done::evaluate(&criteria, spec_dir, receipts)?
+        .unmet
+        .len()
+        .min(total);
+    Ok(ratio(DONENESS_POINTS, (total - unmet) as u32, total as u32))
+}
+
+/// `points * part / whole`, rounded to the nearest point
+fn ratio(points: u32, part: u32, whole: u32) -> u32 {
+    (points * part + whole / 2) / whole
+}
+
+fn to_u8(points: u32) -> u8 {
+    u8::try_from(points).unwrap_or(u8::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example_generators::make_example_receipt_minimal;
+    use tempfile::TempDir;
+
+    fn spec_dir(temp: &TempDir) -> &Utf8Path {
+        let dir = Utf8Path::from_path(temp.path()).unwrap();
+        std::fs::create_dir_all(dir.join("artifacts")).unwrap();
+        dir
+    }
+
+    fn write_artifact(dir: &Utf8Path, phase: PhaseId, suffix: &str, content: &str) {
+        std::fs::write(
+            dir.join("artifacts").join(phase.artifact_name(suffix)),
+            content,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_never_run_spec_scores_only_for_missing_findings() {
+        let temp = TempDir::new().unwrap();
+        let health = score(&DoneConfig::default(), spec_dir(&temp), &[], Utc::now()).unwrap();
+        assert_eq!(
+            health,
+            SpecHealth {
+                score: 30,
+                recency: 0,
+                reliability: 0,
+                findings: 30,
+                doneness: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_score_combines_recency_failures_findings_and_phases() {
+        let temp = TempDir::new().unwrap();
+        let dir = spec_dir(&temp);
+        for phase in [PhaseId::Requirements, PhaseId::Design] {
+            write_artifact(dir, phase, "md", "# Doc");
+            write_artifact(dir, phase, "core.yaml", "{}");
+        }
+        write_artifact(
+            dir,
+            PhaseId::Review,
+            "md",
+            "# Review\n\n## Issues\n\n- **[High]** Retry policy is unspecified.\n- [Critical] Secrets are logged.\n",
+        );
+        write_artifact(
+            dir,
+            PhaseId::Tasks,
+            "md",
+            "# Tasks\n\n## Review Findings\n\n- [x] Secrets are logged (critical)\n- [ ] Retry policy is unspecified (high)\n",
+        );
+
+        let succeeded = make_example_receipt_minimal();
+        let mut failed = succeeded.clone();
+        failed.exit_code = 70;
+        failed.emitted_at = succeeded.emitted_at + chrono::Duration::minutes(5);
+        let receipts = [succeeded.clone(), succeeded, failed.clone(), failed];
+
+        // 48 days after the last run: halfway through the recency decay
+        let now = receipts[3].emitted_at + chrono::Duration::days(48);
+        let health = score(&DoneConfig::default(), dir, &receipts, now).unwrap();
+        assert_eq!(
+            health,
+            SpecHealth {
+                score: 10 + 15 + 22 + 13,
+                recency: 10,
+                reliability: 15,
+                findings: 22,
+                doneness: 13,
+            }
+        );
+
+        // Configured criteria replace the default phases
+        let criteria = DoneConfig {
+            required_phases: vec!["requirements".to_string()],
+            ..DoneConfig::default()
+        };
+        assert_eq!(score(&criteria, dir, &receipts, now).unwrap().doneness, 20);
+    }
+}
@@ -61,6 +61,7 @@
 //!
 //! - [`PhaseId`] - Phase identifiers (Requirements, Design, Tasks, etc.)
 //! - [`Config`] and [`ConfigBuilder`] - Configuration management
+//! - [`Error`] and [`Result`] - Error type for embedding, one variant per receipt `error_kind`
 //! - [`XCheckerError`] - Library error type
 //! - [`ExitCode`] - CLI exit codes
 //! - [`StatusOutput`] - Spec status information
@@ -124,6 +125,18 @@ pub use xchecker_config::ConfigBuilder;
 /// Library code returns `XCheckerError` and does NOT call `std::process::exit()`.
 pub use xchecker_utils::error::XCheckerError;
 
+/// Stable error type for embedding applications.
+///
+/// Convert facade errors with `?` or [`Error::from`]: both the
+/// [`XCheckerError`] returned by [`OrchestratorHandle`] constructors and the
+/// `anyhow` chains returned by phase execution map to the variant matching the
+/// receipt `error_kind`, with [`kind()`](Error::kind) and
+/// [`exit_code()`](Error::exit_code) accessors.
+pub use api_error::{Error, Result};
+
+/// Receipt `error_kind` values, returned by [`Error::kind()`].
+pub use xchecker_utils::types::ErrorKind;
+
 /// Exit codes matching the documented exit code table.
 ///
 /// `ExitCode` provides type-safe exit code handling for xchecker operations.
@@ -179,6 +192,18 @@ pub fn xchecker_version() -> String {
     format!("{}+{}", env!("CARGO_PKG_VERSION"), env!("GIT_HASH"))
 }
 
+/// Network-optional subsystems compiled into this build
+///
+/// Empty in builds made with the `no-telemetry` feature, which `xchecker
+/// doctor` reports as its `build_flavor` check. `issue-trackers` covers the
+/// GitHub and Jira clients of `export-findings` and `sync-tasks`.
+pub const NETWORK_SUBSYSTEMS: &[&str] = &[
+    #[cfg(all(feature = "self-update", not(feature = "no-telemetry")))]
+    "self-update",
+    #[cfg(not(feature = "no-telemetry"))]
+    "issue-trackers",
+];
+
 #[cfg(any(test, feature = "test-utils"))]
 #[doc(hidden)]
 pub use xchecker_utils::test_support;
@@ -186,8 +211,8 @@ pub use xchecker_utils::test_support;
 pub use xchecker_redaction as redaction;
 #[doc(hidden)]
 pub use xchecker_utils::{
-    atomic_write, cache, canonicalization, error, exit_codes, lock, logging, paths, process_memory,
-    ring_buffer, source, spec_id, types,
+    atomic_write, cache, canonicalization, compression, console, deprecation, error, exit_codes,
+    lock, logging, paths, process_memory, remediation, ring_buffer, source, spec_id, types,
 };
 
 #[doc(hidden)]
@@ -216,14 +241,37 @@ pub use xchecker_doctor::wsl;
 #[doc(hidden)]
 pub use xchecker_engine::claude;
 
+mod api_error;
+
 // CLI module - internal implementation detail, not part of stable public API
 // Exported with #[doc(hidden)] to allow white-box testing of CLI flag parsing
 // External consumers should use OrchestratorHandle, not CLI internals
 #[doc(hidden)]
 pub mod cli;
 #[doc(hidden)]
+pub mod done;
+#[doc(hidden)]
 pub mod error_reporter;
 #[doc(hidden)]
+pub mod experiment;
+#[doc(hidden)]
+pub mod export_findings;
+#[doc(hidden)]
+pub mod git_hooks;
+#[cfg(feature = "test-support")]
+pub mod golden;
+#[doc(hidden)]
+pub mod health;
+#[doc(hidden)]
+pub mod queue;
+#[doc(hidden)]
+pub mod schema_docs;
+#[cfg(all(feature = "self-update", not(feature = "no-telemetry")))]
+#[doc(hidden)]
+pub mod self_update;
+#[doc(hidden)]
+pub mod task_sync;
+#[doc(hidden)]
 pub mod tui;
 
 // Legacy re-exports for backward compatibility (will be deprecated)
@@ -0,0 +1,394 @@
+//! `xchecker queue`: prioritized phase runs for daemon mode (`[features] daemon`)
+//!
+//! `queue add` appends a job (a spec and the phases to run for it) to
+//! `<XCHECKER_HOME>/queue.json`; `queue run` is the long-running worker that
+//! executes jobs one phase at a time, highest priority first and oldest first
+//! within a priority.
+//!
+//! Preemption is cooperative: a phase is never interrupted. Between phases
+//! the worker claims its next phase again, and if a waiting job outranks the
+//! running one, the running job is paused with its remaining phases and
+//! resumes once nothing outranks it. The queue is rewritten after every
+//! transition, under a lock, so `queue status` always shows the worker's view.
+//!
+//! Only one worker runs at a time: `queue run` holds `queue.worker.lock` for
+//! its whole lifetime, because a second worker would claim the first one's
+//! running job again.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow};
+use camino::Utf8PathBuf;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::atomic_write::write_file_atomic;
+use crate::lock::{LockError, RepoLock};
+use crate::types::PhaseId;
+
+/// `schema_version` of `queue.json` and `queue status --json`
+pub const QUEUE_SCHEMA_VERSION: &str = "queue-json.v1";
+
+/// How long a queue update waits for another process's update to finish
+const QUEUE_LOCK_WAIT: Duration = Duration::from_secs(30);
+
+/// Scheduling priority of a job
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobPriority {
+    Low,
+    Normal,
+    High,
+}
+
+impl JobPriority {
+    pub const ALL: [Self; 3] = [Self::Low, Self::Normal, Self::High];
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Normal => "normal",
+            Self::High => "high",
+        }
+    }
+
+    /// Parse a priority name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for names other than `low`, `normal`, and `high`.
+    pub fn parse(name: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|priority| priority.as_str() == name)
+            .with_context(|| format!("Unknown priority '{name}'. Valid: low, normal, high"))
+    }
+}
+
+/// Lifecycle state of a job
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobState {
+    /// Waiting for its first phase
+    Queued,
+    /// Claimed by the worker
+    Running,
+    /// Preempted between phases by a higher-priority job
+    Paused,
+    /// Every phase succeeded
+    Completed,
+    /// A phase failed; the remaining phases were not run
+    Failed,
+}
+
+impl JobState {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Running => "running",
+            Self::Paused => "paused",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+        }
+    }
+
+    /// Whether the job still has phases to run
+    #[must_use]
+    pub const fn is_active(self) -> bool {
+        matches!(self, Self::Queued | Self::Running | Self::Paused)
+    }
+}
+
+/// A spec and the phases to run for it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueueJob {
+    pub id: u64,
+    pub spec_id: String,
+    pub priority: JobPriority,
+    pub state: JobState,
+    /// Phases still to run, in order
+    pub pending: Vec<String>,
+    /// Phases that succeeded, in order
+    pub completed: Vec<String>,
+    /// Times the job was paused for a higher-priority job
+    pub preemptions: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub enqueued_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Next phase the worker should run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimedPhase {
+    pub job_id: u64,
+    pub spec_id: String,
+    pub phase: PhaseId,
+    /// Job paused to make way for this one, if any
+    pub preempted: Option<u64>,
+}
+
+/// Persisted queue state (`<XCHECKER_HOME>/queue.json`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobQueue {
+    pub schema_version: String,
+    pub next_id: u64,
+    pub jobs: Vec<QueueJob>,
+}
+
+impl Default for JobQueue {
+    fn default() -> Self {
+        Self {
+            schema_version: QUEUE_SCHEMA_VERSION.to_string(),
+            next_id: 1,
+            jobs: Vec::new(),
+        }
+    }
+}
+
+impl JobQueue {
+    /// Location of the queue in the xchecker home
+    #[must_use]
+    pub fn path() -> Utf8PathBuf {
+        crate::paths::xchecker_home().join("queue.json")
+    }
+
+    /// Load the queue, or an empty queue if none was written yet
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the queue file cannot be read or parsed.
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content =
+            std::fs::read_to_string(&path).with_context(|| format!("Failed to read {path}"))?;
+        serde_json::from_str(&content).with_context(|| format!("Failed to parse {path}"))
+    }
+
+    /// Load the queue, apply `update`, and write it back under the queue lock
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock cannot be taken or the queue cannot be
+    /// read or written.
+    pub fn update<R>(update: impl FnOnce(&mut Self) -> R) -> Result<R> {
+        let path = Self::path();
+        let _lock = RepoLock::acquire_at(
+            path.with_extension("lock").as_std_path(),
+            "queue",
+            QUEUE_LOCK_WAIT,
+        )?;
+        let mut queue = Self::load()?;
+        let result = update(&mut queue);
+        let json = serde_json::to_string_pretty(&queue)?;
+        write_file_atomic(&path, &json).with_context(|| format!("Failed to write {path}"))?;
+        Ok(result)
+    }
+
+    /// Take the worker lock, held by `queue run` for as long as it runs
+    ///
+    /// Fails at once if another worker holds it. The lock is an OS file lock,
+    /// so a crashed worker releases it and the next worker resumes the job it
+    /// left running.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if another worker is running or the lock file cannot
+    /// be opened.
+    pub fn acquire_worker() -> Result<RepoLock> {
+        let path = Self::path().with_file_name("queue.worker.lock");
+        RepoLock::acquire_at(path.as_std_path(), "queue-worker", Duration::ZERO).map_err(
+            |e| match e {
+                LockError::RepoBusy { pid, .. } => {
+                    anyhow!("another `xchecker queue run` worker is already running (pid {pid})")
+                }
+                e => e.into(),
+            },
+        )
+    }
+
+    /// Append a job and return its ID
+    pub fn enqueue(&mut self, spec_id: &str, phases: &[PhaseId], priority: JobPriority) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        let now = Utc::now();
+        self.jobs.push(QueueJob {
+            id,
+            spec_id: spec_id.to_string(),
+            priority,
+            state: JobState::Queued,
+            pending: phases.iter().map(|p| p.as_str().to_string()).collect(),
+            completed: Vec::new(),
+            preemptions: 0,
+            error: None,
+            enqueued_at: now,
+            updated_at: now,
+        });
+        id
+    }
+
+    /// Claim the next phase to run, pausing a running job that is outranked
+    ///
+    /// The highest-priority active job wins; a running job keeps its turn
+    /// against jobs of equal priority, and otherwise the oldest job wins.
+    /// Returns `None` when no active job remains.
+    pub fn claim_next(&mut self) -> Option<ClaimedPhase> {
+        let now = Utc::now();
+        let (pick, phase) = loop {
+            let job = self
+                .jobs
+                .iter_mut()
+                .filter(|job| job.state.is_active())
+                .max_by_key(|job| {
+                    (
+                        job.priority,
+                        job.state == JobState::Running,
+                        std::cmp::Reverse(job.id),
+                    )
+                })?;
+            match job.pending.first() {
+                Some(name) => match PhaseId::from_name(name) {
+                    Some(phase) => break (job.id, phase),
+                    None => {
+                        job.state = JobState::Failed;
+                        job.error = Some(format!("Unknown phase '{name}' in queued job"));
+                    }
+                },
+                None => job.state = JobState::Completed,
+            }
+            job.updated_at = now;
+        };
+
+        let mut preempted = None;
+        for job in &mut self.jobs {
+            if job.id == pick {
+                job.state = JobState::Running;
+                job.updated_at = now;
+            } else if job.state == JobState::Running {
+                job.state = JobState::Paused;
+                job.preemptions += 1;
+                job.updated_at = now;
+                preempted = Some(job.id);
+            }
+        }
+
+        let spec_id = self.jobs.iter().find(|job| job.id == pick)?.spec_id.clone();
+        Some(ClaimedPhase {
+            job_id: pick,
+            spec_id,
+            phase,
+            preempted,
+        })
+    }
+
+    /// Record the outcome of a claimed phase
+    ///
+    /// A failure fails the job; a success completes it once no phases remain.
+    /// Otherwise the job stays running until the next claim.
+    pub fn finish_phase(&mut self, job_id: u64, outcome: Result<(), String>) {
+        let Some(job) = self.jobs.iter_mut().find(|job| job.id == job_id) else {
+            return;
+        };
+        job.updated_at = Utc::now();
+        match outcome {
+            Ok(()) => {
+                if !job.pending.is_empty() {
+                    job.completed.push(job.pending.remove(0));
+                }
+                if job.pending.is_empty() {
+                    job.state = JobState::Completed;
+                }
+            }
+            Err(error) => {
+                job.state = JobState::Failed;
+                job.error = Some(error);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHASES: [PhaseId; 2] = [PhaseId::Requirements, PhaseId::Design];
+
+    fn run_claimed(queue: &mut JobQueue) -> ClaimedPhase {
+        let claimed = queue.claim_next().expect("a phase to run");
+        queue.finish_phase(claimed.job_id, Ok(()));
+        claimed
+    }
+
+    #[test]
+    fn test_higher_priority_job_preempts_between_phases() {
+        let mut queue = JobQueue::default();
+        let low = queue.enqueue("low-spec", &PHASES, JobPriority::Low);
+
+        assert_eq!(run_claimed(&mut queue).phase, PhaseId::Requirements);
+        assert_eq!(queue.jobs[0].state, JobState::Running);
+
+        let high = queue.enqueue("high-spec", &PHASES, JobPriority::High);
+        let claimed = queue.claim_next().unwrap();
+        assert_eq!(claimed.job_id, high);
+        assert_eq!(claimed.preempted, Some(low));
+        assert_eq!(queue.jobs[0].state, JobState::Paused);
+        assert_eq!(queue.jobs[0].preemptions, 1);
+        queue.finish_phase(high, Ok(()));
+        assert_eq!(run_claimed(&mut queue).job_id, high);
+        assert_eq!(queue.jobs[1].state, JobState::Completed);
+
+        // The paused job resumes where it stopped
+        let resumed = run_claimed(&mut queue);
+        assert_eq!((resumed.job_id, resumed.phase), (low, PhaseId::Design));
+        assert_eq!(queue.jobs[0].completed, ["requirements", "design"]);
+        assert!(queue.claim_next().is_none());
+    }
+
+    #[test]
+    fn test_equal_priority_does_not_preempt_and_failures_stop_the_job() {
+        let mut queue = JobQueue::default();
+        let first = queue.enqueue("first", &PHASES, JobPriority::Normal);
+        run_claimed(&mut queue);
+        let second = queue.enqueue("second", &PHASES, JobPriority::Normal);
+
+        let claimed = queue.claim_next().unwrap();
+        assert_eq!((claimed.job_id, claimed.preempted), (first, None));
+        queue.finish_phase(first, Err("design phase failed".to_string()));
+        assert_eq!(queue.jobs[0].state, JobState::Failed);
+        assert_eq!(queue.jobs[0].pending, ["design"]);
+
+        assert_eq!(queue.claim_next().unwrap().job_id, second);
+    }
+
+    #[test]
+    fn test_queue_state_is_persisted() {
+        let _home = crate::paths::with_isolated_home();
+        let id = JobQueue::update(|queue| {
+            queue.enqueue("persisted", &[PhaseId::Tasks], JobPriority::High)
+        })
+        .unwrap();
+
+        let queue = JobQueue::load().unwrap();
+        assert_eq!(queue.schema_version, QUEUE_SCHEMA_VERSION);
+        assert_eq!(queue.next_id, id + 1);
+        assert_eq!(queue.jobs[0].pending, ["tasks"]);
+        assert_eq!(JobPriority::parse("high").unwrap(), queue.jobs[0].priority);
+        assert!(JobPriority::parse("urgent").is_err());
+    }
+
+    #[test]
+    fn test_only_one_worker_holds_the_queue() {
+        let _home = crate::paths::with_isolated_home();
+        let worker = JobQueue::acquire_worker().unwrap();
+        let err = JobQueue::acquire_worker().unwrap_err();
+        assert!(err.to_string().contains("already running"), "{err}");
+
+        drop(worker);
+        JobQueue::acquire_worker().unwrap();
+    }
+}
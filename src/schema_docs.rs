@@ -0,0 +1,376 @@
+//! `xchecker schema docs`: render the JSON Schemas as markdown field tables
+//!
+//! Every published schema is bundled into the binary and rendered into one
+//! markdown page per schema, listing each field with its type, whether it is
+//! required, its description, and the release it appeared in. Nested objects
+//! and arrays of objects are flattened into dotted paths (`packet.files[].path`)
+//! so a single table covers the whole document.
+//!
+//! The pages are generated from the schema files alone, so the schema stays
+//! the single source of truth: a field added to a schema appears in its page
+//! the next time the docs are regenerated. A field records the release that
+//! introduced it with an `"x-since"` annotation; fields without one date from
+//! the first release of the schema version.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+/// A bundled JSON Schema and the repository path it was read from
+pub struct SchemaSource {
+    /// Path of the schema relative to the repository root
+    pub path: &'static str,
+    /// Schema document
+    pub json: &'static str,
+}
+
+impl SchemaSource {
+    /// Schema name without directory and `.json` extension (`receipt.v1`)
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        let file = self.path.rsplit('/').next().unwrap_or(self.path);
+        file.strip_suffix(".json").unwrap_or(file)
+    }
+
+    /// Schema version encoded in the name (`v1`), the default `since` value
+    fn version(&self) -> &'static str {
+        self.name().rsplit('.').next().unwrap_or("v1")
+    }
+}
+
+/// Every published schema, in the order of the contracts reference
+pub static SCHEMAS: &[SchemaSource] = &[
+    SchemaSource {
+        path: "schemas/receipt.v1.json",
+        json: include_str!("../schemas/receipt.v1.json"),
+    },
+    SchemaSource {
+        path: "schemas/status.v1.json",
+        json: include_str!("../schemas/status.v1.json"),
+    },
+    SchemaSource {
+        path: "schemas/doctor.v1.json",
+        json: include_str!("../schemas/doctor.v1.json"),
+    },
+    SchemaSource {
+        path: "schemas/events.v1.json",
+        json: include_str!("../schemas/events.v1.json"),
+    },
+    SchemaSource {
+        path: "schemas/requirements-core.v1.json",
+        json: include_str!("../schemas/requirements-core.v1.json"),
+    },
+    SchemaSource {
+        path: "schemas/design-core.v1.json",
+        json: include_str!("../schemas/design-core.v1.json"),
+    },
+    SchemaSource {
+        path: "schemas/tasks-core.v1.json",
+        json: include_str!("../schemas/tasks-core.v1.json"),
+    },
+    SchemaSource {
+        path: "docs/schemas/spec-json.v1.json",
+        json: include_str!("../docs/schemas/spec-json.v1.json"),
+    },
+    SchemaSource {
+        path: "docs/schemas/status-json.v1.json",
+        json: include_str!("../docs/schemas/status-json.v1.json"),
+    },
+    SchemaSource {
+        path: "docs/schemas/resume-json.v1.json",
+        json: include_str!("../docs/schemas/resume-json.v1.json"),
+    },
+    SchemaSource {
+        path: "docs/schemas/gate-json.v1.json",
+        json: include_str!("../docs/schemas/gate-json.v1.json"),
+    },
+    SchemaSource {
+        path: "docs/schemas/workspace-status-json.v1.json",
+        json: include_str!("../docs/schemas/workspace-status-json.v1.json"),
+    },
+    SchemaSource {
+        path: "docs/schemas/workspace-history-json.v1.json",
+        json: include_str!("../docs/schemas/workspace-history-json.v1.json"),
+    },
+];
+
+/// One row of a schema's field table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDoc {
+    /// Dotted path from the document root; `[]` marks array elements
+    pub path: String,
+    pub ty: String,
+    pub required: bool,
+    pub description: String,
+    pub since: String,
+}
+
+/// Write a markdown page for every entry of [`SCHEMAS`] into `dir`
+///
+/// Pages are named `<schema>.md` (`receipt.v1.md`), creating `dir` if needed
+/// and overwriting existing pages. Returns the written paths in table order.
+pub fn emit_schema_docs(dir: &Path) -> Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+    SCHEMAS
+        .iter()
+        .map(|source| {
+            let path = dir.join(format!("{}.md", source.name()));
+            let markdown = render_schema(source)
+                .with_context(|| format!("Failed to render {}", source.path))?;
+            std::fs::write(&path, markdown)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            Ok(path)
+        })
+        .collect()
+}
+
+/// Markdown page for one bundled schema
+pub fn render_schema(source: &SchemaSource) -> Result<String> {
+    let schema: Value = serde_json::from_str(source.json)
+        .with_context(|| format!("{} is not valid JSON", source.path))?;
+    let title = schema
+        .get("title")
+        .and_then(Value::as_str)
+        .unwrap_or_else(|| source.name());
+
+    let mut out = format!("# {title}\n\n");
+    if let Some(description) = schema.get("description").and_then(Value::as_str) {
+        out.push_str(description);
+        out.push_str("\n\n");
+    }
+    // Writing to a String cannot fail
+    let _ = writeln!(
+        out,
+        "<!-- Generated from {} by `xchecker schema docs`; do not edit. -->\n",
+        source.path
+    );
+
+    out.push_str("| Field | Type | Required | Description | Since |\n");
+    out.push_str("|-------|------|----------|-------------|-------|\n");
+    for field in fields(&schema, source.version()) {
+        let _ = writeln!(
+            out,
+            "| `{}` | {} | {} | {} | {} |",
+            field.path,
+            escape_cell(&field.ty),
+            if field.required { "yes" } else { "no" },
+            escape_cell(&field.description),
+            escape_cell(&field.since),
+        );
+    }
+    Ok(out)
+}
+
+/// Every documented field of `schema`, parents before their children
+///
+/// `version` is the `since` value of fields without an `"x-since"` annotation.
+#[must_use]
+pub fn fields(schema: &Value, version: &str) -> Vec<FieldDoc> {
+    let mut fields = Vec::new();
+    collect_fields(schema, "", version, &mut fields);
+    fields
+}
+
+fn collect_fields(schema: &Value, prefix: &str, since: &str, out: &mut Vec<FieldDoc>) {
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return;
+    };
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    for (name, property) in properties {
+        let path = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{prefix}.{name}")
+        };
+        let since = property
+            .get("x-since")
+            .and_then(Value::as_str)
+            .unwrap_or(since);
+        out.push(FieldDoc {
+            path: path.clone(),
+            ty: type_name(property),
+            required: required.contains(&name.as_str()),
+            description: description(property),
+            since: since.to_string(),
+        });
+
+        // Children inherit the parent's release unless they declare their own
+        collect_fields(property, &path, since, out);
+        if let Some(items) = property.get("items") {
+            collect_fields(items, &format!("{path}[]"), since, out);
+        }
+    }
+}
+
+/// Display type of a property: `string | null`, `array<string>`, `map<integer>`
+fn type_name(property: &Value) -> String {
+    let types: Vec<&str> = match property.get("type") {
+        Some(Value::String(ty)) => vec![ty.as_str()],
+        Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if types.is_empty() {
+        // `{"const": "tasks"}` constrains the type through its value
+        let value = property
+            .get("const")
+            .or_else(|| property.get("enum").and_then(|values| values.get(0)));
+        return match value {
+            Some(Value::String(_)) => "string",
+            Some(Value::Number(_)) => "number",
+            Some(Value::Bool(_)) => "boolean",
+            _ => "any",
+        }
+        .to_string();
+    }
+
+    types
+        .iter()
+        .map(|&ty| match ty {
+            "array" => match property.get("items") {
+                Some(items) => format!("array<{}>", type_name(items)),
+                None => "array".to_string(),
+            },
+            "object" => match property.get("additionalProperties") {
+                Some(values @ Value::Object(_)) if property.get("properties").is_none() => {
+                    format!("map<{}>", type_name(values))
+                }
+                _ => "object".to_string(),
+            },
+            "string" => match property.get("format").and_then(Value::as_str) {
+                Some(format) => format!("string ({format})"),
+                None => "string".to_string(),
+            },
+            other => other.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+/// Description with the allowed values spelled out for `const` and `enum`
+fn description(property: &Value) -> String {
+    let mut text = property
+        .get("description")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let constraint = if let Some(value) = property.get("const") {
+        Some(format!("Always `{}`.", literal(value)))
+    } else {
+        property
+            .get("enum")
+            .and_then(Value::as_array)
+            .map(|values| {
+                let values: Vec<String> = values
+                    .iter()
+                    .map(|value| format!("`{}`", literal(value)))
+                    .collect();
+                format!("One of {}.", values.join(", "))
+            })
+    };
+    if let Some(constraint) = constraint {
+        if !text.is_empty() {
+            if !text.ends_with('.') {
+                text.push('.');
+            }
+            text.push(' ');
+        }
+        text.push_str(&constraint);
+    }
+    text
+}
+
+/// Strings without quotes, everything else as JSON
+fn literal(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Keep a value on one table row: pipes escaped, line breaks flattened
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_fields_flatten_nested_objects_and_arrays() {
+        let schema = json!({
+            "type": "object",
+            "required": ["schema_version", "outputs"],
+            "properties": {
+                "schema_version": {"type": "string", "const": "1", "description": "Schema version"},
+                "outputs": {
+                    "type": "array",
+                    "description": "Generated files",
+                    "items": {
+                        "type": "object",
+                        "required": ["path"],
+                        "properties": {
+                            "path": {"type": "string"},
+                            "hash": {"type": ["string", "null"], "x-since": "1.3.0"}
+                        }
+                    }
+                },
+                "flags": {"type": "object", "additionalProperties": {"type": "string"}},
+                "runner": {"type": "string", "enum": ["native", "wsl"]}
+            }
+        });
+
+        let rows: Vec<_> = fields(&schema, "v1")
+            .into_iter()
+            .map(|f| (f.path, f.ty, f.required, f.description, f.since))
+            .collect();
+        let row = |path: &str, ty: &str, required: bool, description: &str, since: &str| {
+            (
+                path.to_string(),
+                ty.to_string(),
+                required,
+                description.to_string(),
+                since.to_string(),
+            )
+        };
+        assert_eq!(
+            rows,
+            [
+                row("flags", "map<string>", false, "", "v1"),
+                row("outputs", "array<object>", true, "Generated files", "v1"),
+                row("outputs[].hash", "string | null", false, "", "1.3.0"),
+                row("outputs[].path", "string", true, "", "v1"),
+                row("runner", "string", false, "One of `native`, `wsl`.", "v1"),
+                row(
+                    "schema_version",
+                    "string",
+                    true,
+                    "Schema version. Always `1`.",
+                    "v1"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_bundled_schema_renders_a_table() {
+        for source in SCHEMAS {
+            let markdown = render_schema(source).unwrap();
+            assert!(markdown.starts_with("# "), "{} has no title", source.path);
+            assert!(
+                markdown.lines().any(|line| line.starts_with("| `")),
+                "{} renders no fields",
+                source.path
+            );
+        }
+        assert_eq!(SCHEMAS[0].name(), "receipt.v1");
+    }
+}
@@ -0,0 +1,458 @@
+//! `xchecker self-update`: replace the running binary with a signed release
+//!
+//! Each GitHub release publishes, next to the platform binaries, a `SHA256SUMS`
+//! manifest and `SHA256SUMS.sig`, a raw Ed25519 signature of the manifest made
+//! with the release key. The manifest starts with a `# version <x.y.z>` line, so
+//! the signature also covers the release version. An update is installed only
+//! if the signature verifies against the public key compiled into this binary,
+//! the signed version matches the release tag and is newer than the running
+//! version (`--force` may reinstall the same version, never an older one), and
+//! the downloaded binary's SHA-256 matches its manifest entry. This keeps an
+//! older, correctly signed release from being served as an update. The new
+//! binary is written next to the
+//! current one and renamed over it, so an interrupted update never leaves a
+//! partial executable behind.
+//!
+//! The public key comes from `XCHECKER_RELEASE_PUBLIC_KEY` (hex) at build time;
+//! builds without it refuse to update. Distribution packagers build without the
+//! default `self-update` feature, which removes this module entirely.
+
+use anyhow::{Context, Result, bail};
+use ring::signature::{ED25519, UnparsedPublicKey};
+use serde::Deserialize;
+use std::path::Path;
+use std::time::Duration;
+
+/// GitHub API endpoint listing xchecker releases
+const RELEASES_API: &str = "https://api.github.com/repos/EffortlessMetrics/xchecker/releases";
+
+/// Checksum manifest published with every release
+pub const MANIFEST_ASSET: &str = "SHA256SUMS";
+
+/// Ed25519 signature of [`MANIFEST_ASSET`]
+pub const SIGNATURE_ASSET: &str = "SHA256SUMS.sig";
+
+/// Prefix of the manifest line that records the release version
+const MANIFEST_VERSION_PREFIX: &str = "# version ";
+
+/// Hex-encoded Ed25519 release public key embedded at build time
+const RELEASE_PUBLIC_KEY: Option<&str> = option_env!("XCHECKER_RELEASE_PUBLIC_KEY");
+
+/// Timeout for each API request and download
+const HTTP_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Release channel to update from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    /// Latest non-prerelease
+    Stable,
+    /// Latest release, including `-rc`/`-beta`/`-alpha` prereleases
+    Prerelease,
+}
+
+impl Channel {
+    /// Parse a `--channel` value
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "stable" => Ok(Self::Stable),
+            "prerelease" => Ok(Self::Prerelease),
+            other => bail!("unknown channel '{other}' (expected stable or prerelease)"),
+        }
+    }
+}
+
+/// A downloadable release asset
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub browser_download_url: String,
+}
+
+/// A GitHub release as returned by the releases API
+#[derive(Debug, Clone, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    #[serde(default)]
+    pub prerelease: bool,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+}
+
+impl Release {
+    /// Version parsed from the tag (`v1.3.0` → `1.3.0`)
+    pub fn version(&self) -> Result<semver::Version> {
+        let tag = self.tag_name.trim_start_matches('v');
+        semver::Version::parse(tag)
+            .with_context(|| format!("Release tag is not a version: {}", self.tag_name))
+    }
+
+    fn asset(&self, name: &str) -> Result<&ReleaseAsset> {
+        self.assets
+            .iter()
+            .find(|asset| asset.name == name)
+            .with_context(|| format!("Release {} has no asset named {name}", self.tag_name))
+    }
+}
+
+/// What `self-update` did
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdateOutcome {
+    /// The running version is already the newest on the channel
+    UpToDate { current: String },
+    /// A newer version exists (`--check` only)
+    Available { current: String, latest: String },
+    /// The binary was replaced
+    Updated { from: String, to: String },
+}
+
+/// Release asset name of the binary for this build's target
+#[must_use]
+pub fn platform_asset_name() -> String {
+    format!(
+        "xchecker-{}{}",
+        env!("XCHECKER_BUILD_TARGET"),
+        std::env::consts::EXE_SUFFIX
+    )
+}
+
+/// Check for (and unless `check_only`, install) the newest release on `channel`
+///
+/// With `force`, the newest release is installed even if it is not newer than
+/// the running version.
+pub async fn run(channel: Channel, check_only: bool, force: bool) -> Result<UpdateOutcome> {
+    let public_key = release_public_key()?;
+    let current = semver::Version::parse(env!("CARGO_PKG_VERSION"))
+        .context("Running version is not a valid semver version")?;
+
+    let client = reqwest::Client::builder()
+        .user_agent(format!("xchecker/{current}"))
+        .timeout(HTTP_TIMEOUT)
+        .build()
+        .context("Failed to build HTTP client")?;
+
+    let release = latest_release(&client, channel).await?;
+    let latest = release.version()?;
+    if latest <= current && !force {
+        return Ok(UpdateOutcome::UpToDate {
+            current: current.to_string(),
+        });
+    }
+    if check_only {
+        return Ok(UpdateOutcome::Available {
+            current: current.to_string(),
+            latest: latest.to_string(),
+        });
+    }
+
+    let manifest = download(&client, release.asset(MANIFEST_ASSET)?).await?;
+    let signature = download(&client, release.asset(SIGNATURE_ASSET)?).await?;
+    let signed = verify_manifest(&manifest, &signature, &public_key)?;
+    if signed != latest {
+        bail!(
+            "Signed manifest is for version {signed}, but the release is tagged {}",
+            release.tag_name
+        );
+    }
+    check_update_version(&signed, &current, force)?;
+
+    let asset_name = platform_asset_name();
+    let manifest = String::from_utf8(manifest).context("Checksum manifest is not UTF-8")?;
+    let expected = manifest_digest(&manifest, &asset_name)
+        .with_context(|| format!("Checksum manifest has no entry for {asset_name}"))?;
+
+    let binary = download(&client, release.asset(&asset_name)?).await?;
+    let actual = sha256_hex(&binary);
+    if actual != expected {
+        bail!("Checksum mismatch for {asset_name}: expected {expected}, got {actual}");
+    }
+
+    let exe = std::env::current_exe().context("Failed to locate the running executable")?;
+    replace_binary(&exe, &binary)?;
+
+    Ok(UpdateOutcome::Updated {
+        from: current.to_string(),
+        to: latest.to_string(),
+    })
+}
+
+fn release_public_key() -> Result<Vec<u8>> {
+    let Some(hex) = RELEASE_PUBLIC_KEY else {
+        bail!(
+            "This build has no release signing key, so updates cannot be verified; \
+             reinstall from a release or update through your package manager"
+        );
+    };
+    let key = decode_hex(hex.trim()).context("Embedded release public key is not valid hex")?;
+    if key.len() != 32 {
+        bail!("Embedded release public key must be 32 bytes");
+    }
+    Ok(key)
+}
+
+async fn latest_release(client: &reqwest::Client, channel: Channel) -> Result<Release> {
+    let releases: Vec<Release> = client
+        .get(format!("{RELEASES_API}?per_page=30"))
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .context("Failed to query releases")?
+        .error_for_status()
+        .context("Release API returned an error")?
+        .json()
+        .await
+        .context("Failed to parse release list")?;
+
+    select_release(releases, channel)
+        .with_context(|| format!("No published release on the {channel:?} channel"))
+}
+
+/// Newest non-draft release on `channel`, by version
+#[must_use]
+pub fn select_release(releases: Vec<Release>, channel: Channel) -> Option<Release> {
+    releases
+        .into_iter()
+        .filter(|release| !release.draft)
+        .filter(|release| channel == Channel::Prerelease || !release.prerelease)
+        .filter_map(|release| release.version().ok().map(|version| (version, release)))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, release)| release)
+}
+
+async fn download(client: &reqwest::Client, asset: &ReleaseAsset) -> Result<Vec<u8>> {
+    let bytes = client
+        .get(&asset.browser_download_url)
+        .send()
+        .await
+        .with_context(|| format!("Failed to download {}", asset.name))?
+        .error_for_status()
+        .with_context(|| format!("Download of {} failed", asset.name))?
+        .bytes()
+        .await
+        .with_context(|| format!("Failed to read {}", asset.name))?;
+    Ok(bytes.to_vec())
+}
+
+/// Verify the Ed25519 `signature` of `manifest` against `public_key`
+///
+/// Returns the release version recorded in the signed manifest.
+pub fn verify_manifest(
+    manifest: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> Result<semver::Version> {
+    UnparsedPublicKey::new(&ED25519, public_key)
+        .verify(manifest, signature)
+        .map_err(|_| anyhow::anyhow!("Release manifest signature verification failed"))?;
+    let manifest = std::str::from_utf8(manifest).context("Checksum manifest is not UTF-8")?;
+    manifest_version(manifest).context("Signed manifest does not record a release version")
+}
+
+/// Release version from the manifest's `# version <x.y.z>` line
+#[must_use]
+pub fn manifest_version(manifest: &str) -> Option<semver::Version> {
+    manifest.lines().find_map(|line| {
+        let version = line.strip_prefix(MANIFEST_VERSION_PREFIX)?;
+        semver::Version::parse(version.trim()).ok()
+    })
+}
+
+/// Refuse to install `signed` unless it is newer than `current`
+///
+/// With `force` the running version may be reinstalled; an older version is
+/// always rejected.
+pub fn check_update_version(
+    signed: &semver::Version,
+    current: &semver::Version,
+    force: bool,
+) -> Result<()> {
+    if signed < current || (signed == current && !force) {
+        bail!("Refusing to replace xchecker {current} with signed release {signed}");
+    }
+    Ok(())
+}
+
+/// SHA-256 (lowercase hex) listed for `asset` in a `sha256sum`-style manifest
+#[must_use]
+pub fn manifest_digest(manifest: &str, asset: &str) -> Option<String> {
+    manifest.lines().find_map(|line| {
+        let (digest, name) = line.split_once(char::is_whitespace)?;
+        // `sha256sum` marks binary-mode entries with a leading '*'
+        let name = name.trim().trim_start_matches('*');
+        (name == asset).then(|| digest.to_ascii_lowercase())
+    })
+}
+
+/// Lowercase hex SHA-256 of `bytes`
+#[must_use]
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    ring::digest::digest(&ring::digest::SHA256, bytes)
+        .as_ref()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Atomically replace the executable at `target` with `contents`
+///
+/// The new binary is written to a temp file in the same directory and renamed
+/// over `target`. On Windows the running executable cannot be overwritten, so
+/// it is first moved aside to `<name>.old`.
+pub fn replace_binary(target: &Path, contents: &[u8]) -> Result<()> {
+    use std::io::Write;
+
+    let dir = target
+        .parent()
+        .with_context(|| format!("Executable has no parent directory: {}", target.display()))?;
+    let mut staged = tempfile::NamedTempFile::new_in(dir)
+        .with_context(|| format!("Failed to stage update in {}", dir.display()))?;
+    staged
+        .write_all(contents)
+        .context("Failed to write the new binary")?;
+    staged
+        .as_file()
+        .sync_all()
+        .context("Failed to flush the new binary")?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(target)
+            .map(|meta| meta.permissions().mode())
+            .unwrap_or(0o755);
+        std::fs::set_permissions(staged.path(), std::fs::Permissions::from_mode(mode))
+            .context("Failed to mark the new binary executable")?;
+    }
+
+    #[cfg(windows)]
+    let old = {
+        let old = target.with_extension("old");
+        let _ = std::fs::remove_file(&old);
+        std::fs::rename(target, &old)
+            .with_context(|| format!("Failed to move aside {}", target.display()))?;
+        old
+    };
+
+    let persisted = staged.persist(target).map_err(|e| e.error);
+    // Put the original binary back so a failed update leaves it runnable
+    #[cfg(windows)]
+    {
+        if persisted.is_err() {
+            let _ = std::fs::rename(&old, target);
+        }
+    }
+    persisted.with_context(|| format!("Failed to replace {}", target.display()))?;
+    Ok(())
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        bail!("odd number of hex digits");
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex[i..i + 2], 16).with_context(|| format!("invalid hex: {hex}"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::rand::SystemRandom;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+    use tempfile::TempDir;
+
+    fn release(tag: &str, prerelease: bool) -> Release {
+        Release {
+            tag_name: tag.to_string(),
+            prerelease,
+            draft: false,
+            assets: vec![],
+        }
+    }
+
+    #[test]
+    fn test_verify_manifest_signature() {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let key = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        let manifest = b"# version 1.3.0\nabc123  xchecker-x86_64-unknown-linux-gnu\n";
+        let signature = key.sign(manifest);
+
+        let version =
+            verify_manifest(manifest, signature.as_ref(), key.public_key().as_ref()).unwrap();
+        assert_eq!(version, semver::Version::new(1, 3, 0));
+        assert!(
+            verify_manifest(b"tampered", signature.as_ref(), key.public_key().as_ref()).is_err()
+        );
+
+        // A signed manifest without a version line is rejected
+        let unversioned = b"abc123  xchecker-x86_64-unknown-linux-gnu\n";
+        let signature = key.sign(unversioned);
+        assert!(
+            verify_manifest(unversioned, signature.as_ref(), key.public_key().as_ref()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_check_update_version_rejects_downgrades() {
+        let current = semver::Version::new(1, 3, 0);
+        let older = semver::Version::new(1, 2, 9);
+        let newer = semver::Version::new(1, 4, 0);
+
+        check_update_version(&newer, &current, false).unwrap();
+        assert!(check_update_version(&current, &current, false).is_err());
+        check_update_version(&current, &current, true).unwrap();
+        assert!(check_update_version(&older, &current, false).is_err());
+        assert!(check_update_version(&older, &current, true).is_err());
+    }
+
+    #[test]
+    fn test_manifest_digest_and_sha256() {
+        let digest = sha256_hex(b"xchecker");
+        let manifest = format!(
+            "{}  xchecker-x86_64-apple-darwin\n{} *xchecker-x86_64-pc-windows-msvc.exe\n",
+            "0".repeat(64),
+            digest.to_uppercase()
+        );
+
+        assert_eq!(
+            manifest_digest(&manifest, "xchecker-x86_64-pc-windows-msvc.exe"),
+            Some(digest)
+        );
+        assert_eq!(manifest_digest(&manifest, "xchecker-x86_64"), None);
+        assert_eq!(
+            sha256_hex(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn test_select_release_by_channel() {
+        let releases = vec![
+            release("v1.2.0", false),
+            release("v1.4.0-rc.1", true),
+            release("v1.3.0", false),
+            release("nightly", false),
+        ];
+
+        let stable = select_release(releases.clone(), Channel::Stable).unwrap();
+        assert_eq!(stable.tag_name, "v1.3.0");
+        let pre = select_release(releases, Channel::Prerelease).unwrap();
+        assert_eq!(pre.tag_name, "v1.4.0-rc.1");
+        assert!(Channel::parse("nightly").is_err());
+    }
+
+    #[test]
+    fn test_replace_binary_swaps_contents() {
+        let temp = TempDir::new().unwrap();
+        let target = temp.path().join("xchecker");
+        std::fs::write(&target, b"old").unwrap();
+
+        replace_binary(&target, b"new").unwrap();
+
+        assert_eq!(std::fs::read(&target).unwrap(), b"new");
+    }
+}
@@ -0,0 +1,459 @@
+//! `xchecker sync-tasks`: two-way sync between the tasks artifact and an issue tracker
+//!
+//! `push` creates one tracker item (GitHub issue or Jira issue) per numbered
+//! task in `20-tasks.md` and records the item keys in `20-tasks.sync.yaml`
+//! next to the artifact. Tasks already recorded there are skipped, so pushes
+//! are idempotent. `pull` fetches the state of every recorded item and stores
+//! which are done; `xchecker status` reads the file to report tracker progress.
+//!
+//! The sync file is not a phase output: receipts never list it, so syncing
+//! leaves the receipted artifacts untouched and the links survive re-running
+//! the tasks phase. Specs synced before the file existed kept the links in a
+//! `tracker_sync` block of `20-tasks.core.yaml`; that block is still read until
+//! the next push or pull writes the sync file.
+
+use anyhow::{Context, Result, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+#[cfg(not(feature = "no-telemetry"))]
+use std::time::Duration;
+
+use crate::atomic_write::write_file_atomic;
+use crate::compression;
+use crate::types::PhaseId;
+
+/// Comment line that opens the sync file
+const SYNC_MARKER: &str = "# Tracker items created by `xchecker sync-tasks` (do not edit by hand)";
+
+/// Label added to every GitHub issue
+#[cfg(not(feature = "no-telemetry"))]
+const DEFAULT_LABEL: &str = "xchecker";
+
+/// Timeout for each tracker API request
+#[cfg(not(feature = "no-telemetry"))]
+const HTTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Issue tracker a spec's tasks are synced with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrackerKind {
+    /// GitHub issues in an `owner/name` repository
+    Github,
+    /// Jira issues in a project
+    Jira,
+}
+
+impl TrackerKind {
+    /// Parse a `--tracker` value
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "github" => Ok(Self::Github),
+            "jira" => Ok(Self::Jira),
+            other => bail!("unknown tracker '{other}' (expected github or jira)"),
+        }
+    }
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Github => "github",
+            Self::Jira => "jira",
+        }
+    }
+}
+
+/// A numbered checkbox item of the tasks artifact
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskItem {
+    /// Task number as written, e.g. `2` or `2.1`
+    pub number: String,
+    /// Task text after the number
+    pub title: String,
+    /// Whether the checkbox is ticked
+    pub checked: bool,
+}
+
+/// A task linked to a tracker item
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncedTask {
+    /// Task number in `20-tasks.md`
+    pub task: String,
+    /// Task title when the item was created
+    pub title: String,
+    /// Tracker key: the issue number on GitHub, e.g. `PAY-12` on Jira
+    pub key: String,
+    /// Web URL of the item
+    pub url: String,
+    /// Whether the item was done at the last pull
+    #[serde(default)]
+    pub done: bool,
+}
+
+/// Contents of `20-tasks.sync.yaml`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrackerSync {
+    pub tracker: TrackerKind,
+    /// `owner/name` for GitHub, the project key for Jira
+    pub target: String,
+    pub pushed_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pulled_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub items: Vec<SyncedTask>,
+}
+
+impl TrackerSync {
+    /// Number of linked items marked done at the last pull
+    #[must_use]
+    pub fn done_count(&self) -> usize {
+        self.items.iter().filter(|item| item.done).count()
+    }
+}
+
+/// Numbered checkbox items (`- [ ] 1. ...`, `- [x]* 2.1 ...`) in document order
+#[must_use]
+pub fn parse_tasks(markdown: &str) -> Vec<TaskItem> {
+    let task_re =
+        Regex::new(r"^\s*[-*]\s+\[([ xX])\]\*?\s+(\d+(?:\.\d+)*)\.?\s+(.+?)\s*$").unwrap();
+    markdown
+        .lines()
+        .filter_map(|line| {
+            let caps = task_re.captures(line)?;
+            Some(TaskItem {
+                number: caps[2].to_string(),
+                title: caps[3].to_string(),
+                checked: &caps[1] != " ",
+            })
+        })
+        .collect()
+}
+
+/// Path of the sync file in a spec's artifacts directory
+#[must_use]
+pub fn sync_path(artifacts: &Utf8Path) -> Utf8PathBuf {
+    artifacts.join(PhaseId::Tasks.artifact_name("sync.yaml"))
+}
+
+/// The spec's tracker links, or `None` before the first push
+///
+/// Falls back to the `tracker_sync` block that older versions wrote into
+/// `20-tasks.core.yaml`.
+pub fn load_sync(artifacts: &Utf8Path) -> Result<Option<TrackerSync>> {
+    let path = sync_path(artifacts);
+    match std::fs::read_to_string(&path) {
+        Ok(content) => {
+            return serde_yaml::from_str(&content)
+                .map(Some)
+                .with_context(|| format!("Failed to parse {path}"));
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {path}")),
+    }
+
+    let core_path = artifacts.join(PhaseId::Tasks.artifact_name("core.yaml"));
+    if !compression::exists(core_path.as_std_path()) {
+        return Ok(None);
+    }
+    let core_yaml = compression::read_to_string(core_path.as_std_path())
+        .with_context(|| format!("Failed to read {core_path}"))?;
+    read_legacy_sync(&core_yaml)
+}
+
+/// Write `sync` to the sync file, replacing any previous links
+pub fn save_sync(artifacts: &Utf8Path, sync: &TrackerSync) -> Result<Utf8PathBuf> {
+    let path = sync_path(artifacts);
+    let yaml = serde_yaml::to_string(sync).context("Failed to serialize tracker sync")?;
+    write_file_atomic(&path, &format!("{SYNC_MARKER}\n{yaml}"))
+        .with_context(|| format!("Failed to write {path}"))?;
+    Ok(path)
+}
+
+/// The `tracker_sync` block of a core YAML written before the sync file existed
+fn read_legacy_sync(core_yaml: &str) -> Result<Option<TrackerSync>> {
+    #[derive(Deserialize)]
+    struct CoreTasks {
+        #[serde(default)]
+        tracker_sync: Option<TrackerSync>,
+    }
+
+    let core: CoreTasks =
+        serde_yaml::from_str(core_yaml).context("Failed to parse tasks core YAML")?;
+    Ok(core.tracker_sync)
+}
+
+/// Tasks from `tasks` that have no tracker item yet
+#[must_use]
+pub fn unlinked_tasks<'a>(tasks: &'a [TaskItem], sync: Option<&TrackerSync>) -> Vec<&'a TaskItem> {
+    tasks
+        .iter()
+        .filter(|task| {
+            sync.is_none_or(|sync| !sync.items.iter().any(|item| item.task == task.number))
+        })
+        .collect()
+}
+
+/// Credentials and endpoint for one tracker
+///
+/// Compiled out of `no-telemetry` builds.
+#[cfg(not(feature = "no-telemetry"))]
+pub struct TrackerClient {
+    target: String,
+    auth: TrackerAuth,
+    http: reqwest::Client,
+}
+
+#[cfg(not(feature = "no-telemetry"))]
+enum TrackerAuth {
+    Github {
+        token: String,
+    },
+    Jira {
+        base_url: String,
+        email: String,
+        token: String,
+    },
+}
+
+#[cfg(not(feature = "no-telemetry"))]
+impl TrackerClient {
+    /// Client for `target`, with credentials from the environment
+    ///
+    /// GitHub uses `GITHUB_TOKEN` (or `GH_TOKEN`); Jira uses `JIRA_URL`,
+    /// `JIRA_EMAIL`, and `JIRA_API_TOKEN`.
+    pub fn from_env(kind: TrackerKind, target: &str) -> Result<Self> {
+        let env = |name: &str| {
+            std::env::var(name)
+                .map_err(|_| anyhow::anyhow!("set {name} to sync with {}", kind.as_str()))
+        };
+        let auth = match kind {
+            TrackerKind::Github => TrackerAuth::Github {
+                token: env("GITHUB_TOKEN").or_else(|_| env("GH_TOKEN"))?,
+            },
+            TrackerKind::Jira => TrackerAuth::Jira {
+                base_url: env("JIRA_URL")?.trim_end_matches('/').to_string(),
+                email: env("JIRA_EMAIL")?,
+                token: env("JIRA_API_TOKEN")?,
+            },
+        };
+        let http = reqwest::Client::builder()
+            .user_agent(format!("xchecker/{}", env!("CARGO_PKG_VERSION")))
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .context("Failed to build HTTP client")?;
+        Ok(Self {
+            target: target.to_string(),
+            auth,
+            http,
+        })
+    }
+
+    /// Create an item for `task` of spec `spec_id`
+    pub async fn create(&self, spec_id: &str, task: &TaskItem) -> Result<SyncedTask> {
+        let summary = format!("[{spec_id}] {} {}", task.number, task.title);
+        let description = format!(
+            "Task {} of the xchecker tasks for spec `{spec_id}` (`artifacts/20-tasks.md`).",
+            task.number
+        );
+
+        let (key, url) = match &self.auth {
+            TrackerAuth::Github { token } => {
+                #[derive(Deserialize)]
+                struct Created {
+                    number: u64,
+                    html_url: String,
+                }
+                let created: Created = self
+                    .http
+                    .post(format!(
+                        "https://api.github.com/repos/{}/issues",
+                        self.target
+                    ))
+                    .header("Accept", "application/vnd.github+json")
+                    .bearer_auth(token)
+                    .json(&serde_json::json!({
+                        "title": summary,
+                        "body": description,
+                        "labels": [DEFAULT_LABEL, format!("spec:{spec_id}")],
+                    }))
+                    .send()
+                    .await
+                    .context("Failed to reach the GitHub API")?
+                    .error_for_status()
+                    .context("GitHub API rejected the issue")?
+                    .json()
+                    .await
+                    .context("Failed to parse the created issue")?;
+                (created.number.to_string(), created.html_url)
+            }
+            TrackerAuth::Jira {
+                base_url,
+                email,
+                token,
+            } => {
+                #[derive(Deserialize)]
+                struct Created {
+                    key: String,
+                }
+                let created: Created = self
+                    .http
+                    .post(format!("{base_url}/rest/api/2/issue"))
+                    .basic_auth(email, Some(token))
+                    .json(&serde_json::json!({
+                        "fields": {
+                            "project": { "key": self.target },
+                            "summary": summary,
+                            "description": description,
+                            "issuetype": { "name": "Task" },
+                            "labels": [DEFAULT_LABEL],
+                        }
+                    }))
+                    .send()
+                    .await
+                    .context("Failed to reach the Jira API")?
+                    .error_for_status()
+                    .context("Jira API rejected the issue")?
+                    .json()
+                    .await
+                    .context("Failed to parse the created issue")?;
+                let url = format!("{base_url}/browse/{}", created.key);
+                (created.key, url)
+            }
+        };
+
+        Ok(SyncedTask {
+            task: task.number.clone(),
+            title: task.title.clone(),
+            key,
+            url,
+            done: false,
+        })
+    }
+
+    /// Whether the item with `key` is closed (GitHub) or in a done status (Jira)
+    pub async fn is_done(&self, key: &str) -> Result<bool> {
+        match &self.auth {
+            TrackerAuth::Github { token } => {
+                #[derive(Deserialize)]
+                struct Issue {
+                    state: String,
+                }
+                let issue: Issue = self
+                    .http
+                    .get(format!(
+                        "https://api.github.com/repos/{}/issues/{key}",
+                        self.target
+                    ))
+                    .header("Accept", "application/vnd.github+json")
+                    .bearer_auth(token)
+                    .send()
+                    .await
+                    .context("Failed to reach the GitHub API")?
+                    .error_for_status()
+                    .with_context(|| format!("Failed to fetch issue #{key}"))?
+                    .json()
+                    .await
+                    .context("Failed to parse the issue")?;
+                Ok(issue.state == "closed")
+            }
+            TrackerAuth::Jira {
+                base_url,
+                email,
+                token,
+            } => {
+                let issue: serde_json::Value = self
+                    .http
+                    .get(format!("{base_url}/rest/api/2/issue/{key}?fields=status"))
+                    .basic_auth(email, Some(token))
+                    .send()
+                    .await
+                    .context("Failed to reach the Jira API")?
+                    .error_for_status()
+                    .with_context(|| format!("Failed to fetch issue {key}"))?
+                    .json()
+                    .await
+                    .context("Failed to parse the issue")?;
+                Ok(issue["fields"]["status"]["statusCategory"]["key"] == "done")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TASKS: &str = "# Implementation Plan\n\n- [ ] 1. Set up project structure\n  - Create directories\n\n- [x] 2. Implement data models\n- [ ]* 2.1 Write unit tests for models\n\n## Review Findings\n\n- [ ] Retry policy is unspecified\n";
+
+    fn sync() -> TrackerSync {
+        TrackerSync {
+            tracker: TrackerKind::Github,
+            target: "acme/payments".to_string(),
+            pushed_at: "2026-10-01T12:00:00Z".parse().unwrap(),
+            pulled_at: None,
+            items: vec![SyncedTask {
+                task: "1".to_string(),
+                title: "Set up project structure".to_string(),
+                key: "41".to_string(),
+                url: "https://github.com/acme/payments/issues/41".to_string(),
+                done: true,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_parse_tasks_numbered_checkboxes_only() {
+        let tasks = parse_tasks(TASKS);
+        let numbers: Vec<_> = tasks.iter().map(|task| task.number.as_str()).collect();
+        assert_eq!(numbers, vec!["1", "2", "2.1"]);
+        assert_eq!(tasks[0].title, "Set up project structure");
+        assert!(!tasks[0].checked);
+        assert!(tasks[1].checked);
+        assert_eq!(tasks[2].title, "Write unit tests for models");
+
+        let unlinked = unlinked_tasks(&tasks, Some(&sync()));
+        assert_eq!(unlinked.len(), 2);
+        assert_eq!(unlinked[0].number, "2");
+    }
+
+    #[test]
+    fn test_sync_file_round_trips_and_replaces() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let artifacts = Utf8Path::from_path(temp.path()).unwrap();
+        assert_eq!(load_sync(artifacts).unwrap(), None);
+
+        let path = save_sync(artifacts, &sync()).unwrap();
+        assert_eq!(path, artifacts.join("20-tasks.sync.yaml"));
+        assert!(
+            std::fs::read_to_string(&path)
+                .unwrap()
+                .starts_with(SYNC_MARKER)
+        );
+        assert_eq!(load_sync(artifacts).unwrap(), Some(sync()));
+        assert_eq!(sync().done_count(), 1);
+
+        let mut pulled = sync();
+        pulled.items[0].done = false;
+        save_sync(artifacts, &pulled).unwrap();
+        assert_eq!(load_sync(artifacts).unwrap(), Some(pulled));
+    }
+
+    #[test]
+    fn test_legacy_core_yaml_block_is_read_until_the_sync_file_exists() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let artifacts = Utf8Path::from_path(temp.path()).unwrap();
+        let block = serde_yaml::to_string(&serde_json::json!({ "tracker_sync": sync() })).unwrap();
+        std::fs::write(
+            artifacts.join("20-tasks.core.yaml"),
+            format!("spec_id: \"payments\"\nphase: \"tasks\"\ntasks: []\n\n{block}"),
+        )
+        .unwrap();
+        assert_eq!(load_sync(artifacts).unwrap(), Some(sync()));
+
+        let mut pulled = sync();
+        pulled.items[0].done = false;
+        save_sync(artifacts, &pulled).unwrap();
+        assert_eq!(load_sync(artifacts).unwrap(), Some(pulled));
+    }
+}
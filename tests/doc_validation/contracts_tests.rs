@@ -5,6 +5,7 @@
 //! - Documents array sorting rules
 //! - Describes deprecation policy
 //! - Lists correct schema files
+//! - Has generated field tables in sync with the schemas
 //!
 //! Requirements: R5
 
@@ -90,10 +91,12 @@ mod tests {
             FileHash {
                 path: "artifacts/10-design.md".to_string(),
                 blake3_canonicalized: "fedcba9876543210".to_string(),
+                blake3_front_matter: None,
             },
             FileHash {
                 path: "artifacts/00-requirements.md".to_string(),
                 blake3_canonicalized: "0123456789abcdef".to_string(),
+                blake3_front_matter: None,
             },
         ];
 
@@ -101,10 +104,12 @@ mod tests {
             FileHash {
                 path: "artifacts/00-requirements.md".to_string(),
                 blake3_canonicalized: "0123456789abcdef".to_string(),
+                blake3_front_matter: None,
             },
             FileHash {
                 path: "artifacts/10-design.md".to_string(),
                 blake3_canonicalized: "fedcba9876543210".to_string(),
+                blake3_front_matter: None,
             },
         ];
 
@@ -112,6 +117,7 @@ mod tests {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         };
 
         let fixed_timestamp = chrono::DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
@@ -252,6 +258,7 @@ mod tests {
             effective_config: config1,
             lock_drift: None,
             pending_fixups: None,
+            metadata: None,
         };
 
         let status2 = StatusOutput {
@@ -267,6 +274,7 @@ mod tests {
             effective_config: config2,
             lock_drift: None,
             pending_fixups: None,
+            metadata: None,
         };
 
         // Serialize using StatusManager's emit_json (which uses JCS)
@@ -325,6 +333,8 @@ mod tests {
             ok: true,
             checks: checks1,
             cache_stats: None,
+            measurements: Vec::new(),
+            deprecations: Vec::new(),
         };
 
         let mut output2 = DoctorOutput {
@@ -333,6 +343,8 @@ mod tests {
             ok: true,
             checks: checks2,
             cache_stats: None,
+            measurements: Vec::new(),
+            deprecations: Vec::new(),
         };
 
         // Sort both (as the actual implementation does)
@@ -443,14 +455,17 @@ mod tests {
             FileHash {
                 path: "artifacts/20-tasks.md".to_string(),
                 blake3_canonicalized: "cccccccccccccccc".to_string(),
+                blake3_front_matter: None,
             },
             FileHash {
                 path: "artifacts/00-requirements.md".to_string(),
                 blake3_canonicalized: "aaaaaaaaaaaaaaaa".to_string(),
+                blake3_front_matter: None,
             },
             FileHash {
                 path: "artifacts/10-design.md".to_string(),
                 blake3_canonicalized: "bbbbbbbbbbbbbbbb".to_string(),
+                blake3_front_matter: None,
             },
         ];
 
@@ -458,6 +473,7 @@ mod tests {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         };
 
         let receipt = manager.create_receipt(
@@ -668,4 +684,24 @@ mod tests {
         );
         println!("✓ All documented schema files exist");
     }
+
+    /// Test that the generated schema field tables match the schemas
+    ///
+    /// The pages in docs/schemas/ are written by `xchecker schema docs`;
+    /// regenerate them after changing a schema.
+    #[test]
+    fn test_schema_docs_match_schemas() {
+        for source in xchecker::schema_docs::SCHEMAS {
+            let page = Path::new("docs/schemas").join(format!("{}.md", source.name()));
+            let committed = fs::read_to_string(&page)
+                .unwrap_or_else(|e| panic!("Failed to read {}: {e}", page.display()));
+            let rendered = xchecker::schema_docs::render_schema(source).unwrap();
+            assert_eq!(
+                committed,
+                rendered,
+                "{} is out of date; run `xchecker schema docs`",
+                page.display()
+            );
+        }
+    }
 }
@@ -16,9 +16,11 @@ mod tests {
         assert!(variants.contains(&"SecretDetected"));
         assert!(variants.contains(&"LockHeld"));
         assert!(variants.contains(&"PhaseTimeout"));
+        assert!(variants.contains(&"OperatorHalt"));
+        assert!(variants.contains(&"ComplianceBlocked"));
         assert!(variants.contains(&"ClaudeFailure"));
         assert!(variants.contains(&"Unknown"));
-        assert_eq!(variants.len(), 7);
+        assert_eq!(variants.len(), 9);
     }
 
     #[test]
@@ -59,6 +61,8 @@ mod tests {
         assert!(transformed.contains("secret_detected"));
         assert!(transformed.contains("lock_held"));
         assert!(transformed.contains("phase_timeout"));
+        assert!(transformed.contains("operator_halt"));
+        assert!(transformed.contains("compliance_blocked"));
         assert!(transformed.contains("claude_failure"));
         assert!(transformed.contains("unknown"));
     }
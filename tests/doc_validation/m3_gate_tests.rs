@@ -154,6 +154,8 @@ fn m3_gate_exit_code_table_matches() {
     actual_codes.insert(8, "SECRET_DETECTED");
     actual_codes.insert(9, "LOCK_HELD");
     actual_codes.insert(10, "PHASE_TIMEOUT");
+    actual_codes.insert(11, "OPERATOR_HALT");
+    actual_codes.insert(12, "COMPLIANCE_BLOCKED");
     actual_codes.insert(70, "CLAUDE_FAILURE");
 
     // Verify all documented codes match actual codes
@@ -170,6 +170,8 @@ mod tests {
         actual_codes.insert(8, "SECRET_DETECTED");
         actual_codes.insert(9, "LOCK_HELD");
         actual_codes.insert(10, "PHASE_TIMEOUT");
+        actual_codes.insert(11, "OPERATOR_HALT");
+        actual_codes.insert(12, "COMPLIANCE_BLOCKED");
         actual_codes.insert(70, "CLAUDE_FAILURE");
 
         // Compare documented codes with actual constants
@@ -82,6 +82,7 @@ impl GoldenPipelineTestEnvironment {
             strict_validation: false,
             redactor: Default::default(),
             hooks: None,
+            llm_backend: None,
         }
     }
 }
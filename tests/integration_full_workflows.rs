@@ -100,6 +100,7 @@ impl WorkflowTestEnvironment {
             strict_validation: false,
             redactor: Default::default(),
             hooks: None,
+            llm_backend: None,
         }
     }
 
@@ -120,6 +121,7 @@ impl WorkflowTestEnvironment {
             strict_validation: false,
             redactor: Default::default(),
             hooks: None,
+            llm_backend: None,
         }
     }
 }
@@ -377,6 +379,7 @@ async fn test_determinism_with_identical_inputs() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute Requirements phase in both environments
@@ -575,7 +578,7 @@ async fn test_multi_phase_workflow_with_dependencies() -> Result<()> {
     }
 
     // Sort by emitted_at timestamp
-    receipts.sort_by(|a, b| a.emitted_at.cmp(&b.emitted_at));
+    receipts.sort_by_key(|a| a.emitted_at);
 
     assert_eq!(
         receipts[0].phase, "requirements",
@@ -89,6 +89,7 @@ async fn test_complete_requirements_phase_with_claude_integration() -> Result<()
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute Requirements phase
@@ -180,6 +181,7 @@ async fn test_receipt_metadata_completeness() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute phase
@@ -356,6 +358,7 @@ async fn test_claude_wrapper_fallback_behavior() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute phase - this should trigger fallback behavior
@@ -443,6 +446,7 @@ async fn test_end_to_end_m1_gate_validation() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute complete Requirements phase
@@ -547,6 +551,7 @@ async fn test_error_handling_and_partial_outputs() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute phase - this should fail
@@ -122,15 +122,18 @@ async fn test_m1_gate_validation_summary() -> Result<()> {
             files: Vec::new(),
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         },
         outputs: vec![
             FileHash {
                 path: "artifacts/00-requirements.md".to_string(),
                 blake3_canonicalized: "a".repeat(64),
+                blake3_front_matter: None,
             },
             FileHash {
                 path: "artifacts/00-requirements.core.yaml".to_string(),
                 blake3_canonicalized: "b".repeat(64),
+                blake3_front_matter: None,
             },
         ],
         exit_code: 0,
@@ -144,6 +147,9 @@ async fn test_m1_gate_validation_summary() -> Result<()> {
 
         llm: None,
         pipeline: None,
+        suggested_actions: vec![],
+        config_hash: None,
+        config_snapshot: None,
     };
 
     // Test JSON serialization/deserialization
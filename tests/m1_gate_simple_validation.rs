@@ -51,6 +51,7 @@ async fn test_m1_gate_requirements_phase_integration() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute Requirements phase
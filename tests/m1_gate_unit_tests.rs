@@ -242,15 +242,18 @@ async fn test_m1_gate_receipt_structure() -> Result<()> {
             files: Vec::new(),
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         },
         outputs: vec![
             FileHash {
                 path: "artifacts/00-requirements.md".to_string(),
                 blake3_canonicalized: "a".repeat(64),
+                blake3_front_matter: None,
             },
             FileHash {
                 path: "artifacts/00-requirements.core.yaml".to_string(),
                 blake3_canonicalized: "b".repeat(64),
+                blake3_front_matter: None,
             },
         ],
         exit_code: 0,
@@ -263,6 +266,9 @@ async fn test_m1_gate_receipt_structure() -> Result<()> {
         diff_context: None,
         llm: None,
         pipeline: None,
+        suggested_actions: vec![],
+        config_hash: None,
+        config_snapshot: None,
     };
 
     // Validate receipt structure (R2.1)
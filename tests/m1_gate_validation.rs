@@ -93,6 +93,7 @@ async fn test_complete_requirements_phase_with_claude_integration() -> Result<()
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute Requirements phase
@@ -184,6 +185,7 @@ async fn test_receipt_metadata_completeness() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute phase
@@ -466,6 +468,7 @@ async fn test_error_handling_and_partial_outputs() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute phase - this should fail
@@ -526,6 +529,7 @@ async fn test_end_to_end_m1_gate_validation() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute complete Requirements phase
@@ -40,11 +40,13 @@ fn test_receipt_jcs_emission() {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized:
                 "def456abc789012345678901234567890123456789012345678901234567890a".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized:
                 "abc123def456789012345678901234567890123456789012345678901234abcd".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
@@ -52,6 +54,7 @@ fn test_receipt_jcs_emission() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -148,6 +151,7 @@ fn test_status_jcs_emission() {
         effective_config,
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     };
 
     // Emit as canonical JSON using JCS
@@ -185,16 +189,19 @@ fn test_arrays_sorted_before_emission() {
             path: "artifacts/20-tasks.md".to_string(),
             blake3_canonicalized:
                 "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized:
                 "abc123def456789012345678901234567890123456789012345678901234abcd".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized:
                 "def456abc789012345678901234567890123456789012345678901234567890a".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
@@ -202,6 +209,7 @@ fn test_arrays_sorted_before_emission() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -270,6 +278,7 @@ fn test_arrays_sorted_before_emission() {
         effective_config: BTreeMap::new(),
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     };
 
     // Verify artifacts are sorted
@@ -433,16 +442,19 @@ fn test_different_insertion_orders_produce_identical_json() {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized:
                 "abc123def456789012345678901234567890123456789012345678901234abcd".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized:
                 "def456abc789012345678901234567890123456789012345678901234567890a".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/20-tasks.md".to_string(),
             blake3_canonicalized:
                 "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
@@ -452,16 +464,19 @@ fn test_different_insertion_orders_produce_identical_json() {
             path: "artifacts/20-tasks.md".to_string(),
             blake3_canonicalized:
                 "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized:
                 "abc123def456789012345678901234567890123456789012345678901234abcd".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized:
                 "def456abc789012345678901234567890123456789012345678901234567890a".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
@@ -480,6 +495,7 @@ fn test_different_insertion_orders_produce_identical_json() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     // Use a fixed timestamp for both receipts
@@ -625,6 +641,7 @@ fn test_different_insertion_orders_produce_identical_json() {
         effective_config: config1,
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     };
 
     let status2 = StatusOutput {
@@ -640,6 +657,7 @@ fn test_different_insertion_orders_produce_identical_json() {
         effective_config: config2,
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     };
 
     // Serialize both status outputs using JCS
@@ -299,6 +299,7 @@ async fn test_status_command_shows_complete_phase_information() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute Requirements and Design phases to create artifacts and receipts
@@ -456,6 +457,7 @@ async fn test_verbose_logging_provides_debugging_information() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Capture logging output during phase execution
@@ -669,6 +671,7 @@ async fn test_review_phase_integration_with_fixup_detection() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute Requirements, Design, and Tasks phases first
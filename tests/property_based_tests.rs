@@ -682,7 +682,8 @@ fn prop_doctor_never_triggers_llm_completions_for_cli_providers() {
                         check.name == "write_permissions" ||
                         check.name == "atomic_rename" ||
                         check.name == "config_parse" ||
-                        check.name == "llm_provider",
+                        check.name == "llm_provider" ||
+                        check.name == "phase_heartbeats",
                         "Check name '{}' should be a standard validation check, not an LLM invocation",
                         check.name
                     );
@@ -940,7 +941,8 @@ fn prop_doctor_never_triggers_llm_completions_for_gemini_cli() {
                         check.name == "write_permissions" ||
                         check.name == "atomic_rename" ||
                         check.name == "config_parse" ||
-                        check.name == "llm_provider",
+                        check.name == "llm_provider" ||
+                        check.name == "phase_heartbeats",
                         "Check name '{}' should be a standard validation check, not an LLM invocation",
                         check.name
                     );
@@ -1611,6 +1613,15 @@ mod json_size_limits_property {
                 artifacts: Vec::new(),
                 effective_config: std::collections::BTreeMap::new(),
                 lock_drift: None,
+                generation: 0,
+                metadata: None,
+                active_run: None,
+                task_sync: None,
+                token_usage: None,
+                deprecations: Vec::new(),
+                done: None,
+                next_steps: Vec::new(),
+                partials: Vec::new(),
             };
 
             // Serialize to JSON
@@ -1636,8 +1647,8 @@ mod json_size_limits_property {
             );
 
             // Verify only expected top-level fields are present
-            // v2 adds artifacts, effective_config, lock_drift, strict_validation
-            let expected_fields = ["schema_version", "spec_id", "phase_statuses", "pending_fixups", "has_errors", "artifacts", "effective_config", "lock_drift", "strict_validation"];
+            // v2 adds artifacts, effective_config, lock_drift, strict_validation, generation
+            let expected_fields = ["schema_version", "spec_id", "phase_statuses", "pending_fixups", "has_errors", "artifacts", "effective_config", "lock_drift", "strict_validation", "generation"];
             for (key, _) in parsed.as_object().unwrap() {
                 prop_assert!(
                     expected_fields.contains(&key.as_str()),
@@ -1685,6 +1696,8 @@ mod json_size_limits_property {
                     latest_completed_phase: latest_phase,
                 },
                 next_steps: format!("Run {} phase to continue", phase),
+                force_phase: false,
+                skip_phases: vec![],
             };
 
             // Serialize to JSON
@@ -1760,6 +1773,8 @@ mod json_size_limits_property {
                     latest_completed_phase: None,
                 },
                 next_steps: format!("Run {} phase", phase),
+                force_phase: false,
+                skip_phases: vec![],
             };
 
             // Serialize to JSON
@@ -2840,6 +2855,7 @@ fn prop_receipt_creation_applies_redaction() {
                 files: vec![],
                 max_bytes: 100000,
                 max_lines: 1000,
+                ..Default::default()
             },
             Some(stderr_with_secret.clone()), // stderr_tail
             Some(stderr_with_secret.clone()), // stderr_redacted
@@ -24,6 +24,7 @@ fn test_apply_fixups_config_handling() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Simulate how orchestrator determines fixup mode
@@ -52,6 +53,7 @@ fn test_apply_fixups_config_handling() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     let apply_fixups_apply = orchestrator_config_apply
@@ -349,7 +349,31 @@ fn test_verbose_flag() {
     let args = vec!["xchecker", "--verbose", "status", "test-spec"];
     let cli = xchecker::cli::Cli::try_parse_from(args).unwrap();
 
-    assert!(cli.verbose);
+    assert_eq!(cli.verbose, 1);
+    assert!(!cli.quiet);
+}
+
+/// Test -vv counts and --quiet conflicts with -v
+#[test]
+fn test_verbosity_flags() {
+    use clap::Parser;
+    use xchecker::logging::Verbosity;
+
+    let cli =
+        xchecker::cli::Cli::try_parse_from(["xchecker", "status", "test-spec", "-vv"]).unwrap();
+    assert_eq!(
+        Verbosity::from_flags(cli.quiet, cli.verbose),
+        Verbosity::Debug
+    );
+
+    let cli =
+        xchecker::cli::Cli::try_parse_from(["xchecker", "-q", "status", "test-spec"]).unwrap();
+    assert_eq!(
+        Verbosity::from_flags(cli.quiet, cli.verbose),
+        Verbosity::Quiet
+    );
+
+    assert!(xchecker::cli::Cli::try_parse_from(["xchecker", "-q", "-v", "status", "x"]).is_err());
 }
 
 /// Test --force flag on spec command
@@ -422,11 +446,13 @@ fn test_cli_flags_override_config() -> Result<()> {
         dangerously_skip_permissions: false,
         ignore_secret_pattern: vec!["test.*".to_string()],
         extra_secret_pattern: vec!["custom.*".to_string()],
+        source_root: vec![],
         phase_timeout: Some(900),
         stdout_cap_bytes: Some(4194304),
         stderr_cap_bytes: Some(524288),
         lock_ttl_seconds: Some(1800),
         debug_packet: false,
+        raw_context: false,
         allow_links: false,
         strict_validation: None,
         llm_provider: None,
@@ -436,6 +462,11 @@ fn test_cli_flags_override_config() -> Result<()> {
         llm_fallback_provider: None,
         prompt_template: None,
         execution_strategy: None,
+        inject_failure: None,
+        idempotency_key: None,
+        dump_prompt: false,
+        prompt_only: false,
+        enable_features: vec![],
     };
 
     // Load config (will use defaults since no config file)
@@ -569,11 +600,13 @@ fn test_new_flags_in_config_system() -> Result<()> {
         dangerously_skip_permissions: false,
         ignore_secret_pattern: vec![],
         extra_secret_pattern: vec![],
+        source_root: vec![],
         phase_timeout: None,
         stdout_cap_bytes: Some(4194304),
         stderr_cap_bytes: Some(524288),
         lock_ttl_seconds: Some(1800),
         debug_packet: true,
+        raw_context: false,
         allow_links: true,
         strict_validation: Some(true),
         llm_provider: None,
@@ -583,6 +616,11 @@ fn test_new_flags_in_config_system() -> Result<()> {
         llm_fallback_provider: None,
         prompt_template: None,
         execution_strategy: None,
+        inject_failure: None,
+        idempotency_key: None,
+        dump_prompt: false,
+        prompt_only: false,
+        enable_features: vec![],
     };
 
     // Load config
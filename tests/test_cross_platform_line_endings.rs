@@ -195,6 +195,7 @@ fn test_json_receipt_written_with_lf() -> Result<()> {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         },
         outputs: vec![],
         exit_code: 0,
@@ -208,6 +209,9 @@ fn test_json_receipt_written_with_lf() -> Result<()> {
 
         llm: None,
         pipeline: None,
+        suggested_actions: vec![],
+        config_hash: None,
+        config_snapshot: None,
     };
 
     // Write receipt
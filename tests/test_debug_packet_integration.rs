@@ -49,6 +49,7 @@ async fn test_debug_packet_written_with_flag() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute requirements phase
@@ -99,6 +100,7 @@ async fn test_debug_packet_not_written_without_flag() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute requirements phase
@@ -155,6 +157,7 @@ async fn test_debug_packet_not_written_on_secret_detection() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute requirements phase (will fail due to secret detection)
@@ -212,6 +215,7 @@ async fn test_debug_packet_not_in_receipts() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute requirements phase
@@ -263,6 +267,7 @@ async fn test_packet_preview_always_written() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute requirements phase
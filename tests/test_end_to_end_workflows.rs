@@ -61,6 +61,7 @@ fn create_success_config() -> OrchestratorConfig {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     }
 }
 
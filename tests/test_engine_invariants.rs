@@ -21,6 +21,7 @@ fn dry_run_config() -> xchecker::orchestrator::OrchestratorConfig {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     }
 }
 
@@ -516,6 +517,7 @@ async fn test_packet_construction_in_execute_phase_core() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     let mut handle =
@@ -896,6 +898,7 @@ async fn test_packet_evidence_round_trip_validation() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     let mut handle =
@@ -25,6 +25,7 @@ fn dry_run_config() -> xchecker::orchestrator::OrchestratorConfig {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     }
 }
 
@@ -170,6 +171,7 @@ fn test_error_receipt_packet_overflow() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -283,6 +285,7 @@ fn test_error_receipt_secret_detected() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -380,6 +383,7 @@ fn test_error_receipt_lock_held() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -466,6 +470,7 @@ fn test_error_receipt_claude_failure() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -563,6 +568,7 @@ fn test_error_receipt_unknown_error() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -649,6 +655,7 @@ fn test_error_receipt_write_and_read() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -747,6 +754,7 @@ fn test_error_receipt_preserves_packet_evidence() -> Result<()> {
         ],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
@@ -812,6 +820,7 @@ fn test_error_receipt_json_structure() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let pipeline = Some(xchecker::types::PipelineInfo {
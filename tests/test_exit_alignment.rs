@@ -225,6 +225,8 @@ fn test_error_kind_serialization() {
         (ErrorKind::SecretDetected, "secret_detected"),
         (ErrorKind::LockHeld, "lock_held"),
         (ErrorKind::PhaseTimeout, "phase_timeout"),
+        (ErrorKind::OperatorHalt, "operator_halt"),
+        (ErrorKind::ComplianceBlocked, "compliance_blocked"),
         (ErrorKind::ClaudeFailure, "claude_failure"),
         (ErrorKind::Unknown, "unknown"),
     ];
@@ -328,6 +330,14 @@ fn test_receipt_exit_code_alignment() {
             codes::PHASE_TIMEOUT,
             ErrorKind::PhaseTimeout,
         ),
+        (
+            XCheckerError::Phase(PhaseError::ComplianceBlocked {
+                phase: "DESIGN".to_string(),
+                findings: "gpl_license in src/vendored.rs:1".to_string(),
+            }),
+            codes::COMPLIANCE_BLOCKED,
+            ErrorKind::ComplianceBlocked,
+        ),
     ];
 
     for (error, expected_code, expected_kind) in test_cases {
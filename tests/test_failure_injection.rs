@@ -0,0 +1,152 @@
+//! Integration tests for failure injection (`--inject-failure`)
+//!
+//! **WHITE-BOX TEST**: This test uses internal module APIs (`orchestrator::{OrchestratorConfig,
+//! PhaseOrchestrator}`, `paths`) and may break with internal refactors. See FR-TEST-4 for
+//! white-box test policy.
+//!
+//! Each injection point must leave the spec recoverable:
+//! - after-packet-build: context written, no artifacts or receipt, lock released
+//! - mid-stream: partial artifact salvaged and a failure receipt written
+//! - before-receipt-write: artifacts promoted without a receipt; a rerun completes
+
+use anyhow::Result;
+use std::collections::HashMap;
+use xchecker::orchestrator::{FailurePoint, OrchestratorConfig, PhaseOrchestrator};
+use xchecker::paths;
+
+fn dry_run_config(inject_failure: Option<FailurePoint>) -> OrchestratorConfig {
+    let mut config = HashMap::new();
+    if let Some(point) = inject_failure {
+        config.insert("inject_failure".to_string(), point.as_str().to_string());
+    }
+    OrchestratorConfig {
+        dry_run: true,
+        config,
+        full_config: None,
+        selectors: None,
+        strict_validation: false,
+        redactor: Default::default(),
+        hooks: None,
+        llm_backend: None,
+    }
+}
+
+fn receipt_count(spec_id: &str) -> usize {
+    receipts_dir_files(spec_id, ".json")
+}
+
+/// Files in the spec's receipts directory whose names end with `suffix`
+fn receipts_dir_files(spec_id: &str, suffix: &str) -> usize {
+    std::fs::read_dir(paths::spec_root(spec_id).join("receipts"))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.file_name().to_string_lossy().ends_with(suffix))
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+/// Rerun without injection; succeeds only if the lock was released and state is consistent
+async fn assert_recovers(spec_id: &str) -> Result<()> {
+    let orchestrator = PhaseOrchestrator::new(spec_id)?;
+    let result = orchestrator
+        .execute_requirements_phase(&dry_run_config(None))
+        .await?;
+    assert!(
+        result.success,
+        "rerun after injected failure should succeed"
+    );
+    assert!(receipt_count(spec_id) >= 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_after_packet_build_aborts_before_llm() -> Result<()> {
+    let _home = paths::with_isolated_home();
+    let spec_id = "test-inject-after-packet";
+
+    {
+        let orchestrator = PhaseOrchestrator::new(spec_id)?;
+        let err = orchestrator
+            .execute_requirements_phase(&dry_run_config(Some(FailurePoint::AfterPacketBuild)))
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("injected failure at after-packet-build"),
+            "{err}"
+        );
+    }
+
+    let spec_root = paths::spec_root(spec_id);
+    assert!(spec_root.join("context/requirements-packet.txt").exists());
+    assert!(!spec_root.join("artifacts/00-requirements.md").exists());
+    assert_eq!(receipt_count(spec_id), 0);
+    // The run's event log still records how far it got
+    assert_eq!(receipts_dir_files(spec_id, ".events.ndjson"), 1);
+
+    assert_recovers(spec_id).await
+}
+
+#[tokio::test]
+async fn test_mid_stream_salvages_partial_output() -> Result<()> {
+    let _home = paths::with_isolated_home();
+    let spec_id = "test-inject-mid-stream";
+
+    {
+        let orchestrator = PhaseOrchestrator::new(spec_id)?;
+        let result = orchestrator
+            .execute_requirements_phase(&dry_run_config(Some(FailurePoint::MidStream)))
+            .await?;
+        assert!(!result.success);
+        assert_eq!(result.exit_code, 70);
+        assert!(result.receipt_path.is_some());
+    }
+
+    let spec_root = paths::spec_root(spec_id);
+    assert!(
+        spec_root
+            .join("artifacts/00-requirements.partial.md")
+            .exists()
+    );
+    assert!(!spec_root.join("artifacts/00-requirements.md").exists());
+
+    assert_recovers(spec_id).await
+}
+
+#[tokio::test]
+async fn test_before_receipt_write_leaves_unreceipted_artifacts() -> Result<()> {
+    let _home = paths::with_isolated_home();
+    let spec_id = "test-inject-before-receipt";
+
+    {
+        let orchestrator = PhaseOrchestrator::new(spec_id)?;
+        let err = orchestrator
+            .execute_requirements_phase(&dry_run_config(Some(FailurePoint::BeforeReceiptWrite)))
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("injected failure at before-receipt-write"),
+            "{err}"
+        );
+    }
+
+    assert!(
+        paths::spec_root(spec_id)
+            .join("artifacts/00-requirements.md")
+            .exists()
+    );
+    assert_eq!(receipt_count(spec_id), 0);
+
+    assert_recovers(spec_id).await
+}
+
+#[test]
+fn test_failure_point_names_round_trip() {
+    for point in FailurePoint::ALL {
+        assert_eq!(point.as_str().parse::<FailurePoint>(), Ok(point));
+    }
+    assert!("after-llm".parse::<FailurePoint>().is_err());
+}
@@ -141,6 +141,7 @@ async fn test_fixup_phase_preview_mode() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute fixup phase
@@ -189,6 +190,7 @@ async fn test_fixup_phase_apply_mode() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute fixup phase
@@ -241,6 +243,7 @@ async fn test_fixup_phase_validates_dependencies() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Try to execute fixup without dependencies - should fail
@@ -276,6 +279,7 @@ async fn test_fixup_phase_creates_artifacts() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Execute fixup phase
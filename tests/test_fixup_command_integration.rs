@@ -56,6 +56,7 @@ async fn test_fixup_command_parsing() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Create dummy artifacts for dependencies
@@ -30,11 +30,13 @@ fn test_generated_receipt_validates_against_schema() {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized:
                 "abc123def456789012345678901234567890123456789012345678901234abcd".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized:
                 "def456abc789012345678901234567890123456789012345678901234567890a".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
@@ -42,6 +44,7 @@ fn test_generated_receipt_validates_against_schema() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -138,6 +141,7 @@ fn test_generated_status_validates_against_schema() {
         effective_config,
         lock_drift,
         pending_fixups: None,
+        metadata: None,
     };
 
     // Serialize to JSON
@@ -186,6 +190,8 @@ fn test_generated_doctor_validates_against_schema() {
         ok: true,
         checks,
         cache_stats: None,
+        measurements: Vec::new(),
+        deprecations: Vec::new(),
     };
 
     // Serialize to JSON
@@ -221,16 +227,19 @@ fn test_generated_outputs_have_sorted_arrays() {
             path: "artifacts/20-tasks.md".to_string(),
             blake3_canonicalized:
                 "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized:
                 "abc123def456789012345678901234567890123456789012345678901234abcd".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized:
                 "def456abc789012345678901234567890123456789012345678901234567890a".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
@@ -238,6 +247,7 @@ fn test_generated_outputs_have_sorted_arrays() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -315,6 +325,7 @@ fn test_generated_outputs_have_sorted_arrays() {
         effective_config: BTreeMap::new(),
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     };
 
     let json_value = serde_json::to_value(&status).unwrap();
@@ -362,6 +373,8 @@ fn test_generated_outputs_have_sorted_arrays() {
         ok: true,
         checks,
         cache_stats: None,
+        measurements: Vec::new(),
+        deprecations: Vec::new(),
     };
 
     let json_value = serde_json::to_value(&doctor).unwrap();
@@ -446,6 +459,7 @@ fn test_different_insertion_orders_produce_identical_json() {
         effective_config: config1,
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     };
 
     let status2 = StatusOutput {
@@ -461,6 +475,7 @@ fn test_different_insertion_orders_produce_identical_json() {
         effective_config: config2,
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     };
 
     // Serialize both to JSON strings
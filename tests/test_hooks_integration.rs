@@ -102,6 +102,7 @@ async fn test_pre_and_post_hooks_execute() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: Some(hooks),
+        llm_backend: None,
     };
 
     let mut handle = OrchestratorHandle::with_config_and_force(&spec_id, config, false)?;
@@ -152,6 +153,7 @@ async fn test_pre_phase_hook_failure_aborts_phase() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: Some(hooks),
+        llm_backend: None,
     };
 
     let mut handle = OrchestratorHandle::with_config_and_force(&spec_id, config, false)?;
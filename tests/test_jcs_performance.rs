@@ -107,6 +107,7 @@ fn create_typical_status() -> StatusOutput {
         effective_config,
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     }
 }
 
@@ -156,6 +157,8 @@ fn create_typical_doctor() -> DoctorOutput {
         ok: true,
         checks,
         cache_stats: None,
+        measurements: Vec::new(),
+        deprecations: Vec::new(),
     }
 }
 
@@ -363,6 +366,7 @@ fn test_large_status_jcs_performance() -> Result<()> {
         effective_config,
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     };
 
     // Warm-up run
@@ -247,3 +247,138 @@ fn test_gate_json_output_matches_schema() {
     // Verify schema_version is correct
     assert_eq!(json_value["schema_version"], "gate-json.v1");
 }
+
+#[test]
+fn test_event_log_lines_validate_against_schema() {
+    use xchecker::receipt::events::{EventLog, RunEvent};
+
+    let schema: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string("schemas/events.v1.json").expect("Failed to read events schema"),
+    )
+    .expect("Failed to parse events schema");
+    let validator = jsonschema::validator_for(&schema).expect("Failed to compile events schema");
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let receipts = camino::Utf8Path::from_path(temp.path()).unwrap();
+    let mut log = EventLog::start(receipts, "demo", "design");
+    let path = EventLog::path(receipts, log.run_id());
+    for event in [
+        RunEvent::PhaseStarted,
+        RunEvent::HookFinished {
+            hook: "pre_phase".to_string(),
+            success: true,
+            warning: Some("hook_warned:pre_phase:design:./lint.sh:exit_code=1".to_string()),
+        },
+        RunEvent::PacketBuilt {
+            packet_hash: "abc123".to_string(),
+            files: 3,
+            bytes: 2048,
+            lines: 80,
+        },
+        RunEvent::PacketScanned {
+            allowed: true,
+            compliance_findings: 0,
+        },
+        RunEvent::LlmStarted {
+            mode: "provider".to_string(),
+        },
+        RunEvent::LlmFinished {
+            exit_code: 0,
+            bytes: 4096,
+        },
+        RunEvent::ArtifactsPromoted {
+            artifacts: vec!["artifacts/10-design.md".to_string()],
+        },
+        RunEvent::HookFinished {
+            hook: "post_phase".to_string(),
+            success: false,
+            warning: None,
+        },
+    ] {
+        log.record(event);
+    }
+    // Dropping an unfinished log appends phase_aborted
+    drop(log);
+
+    let content = fs::read_to_string(&path).unwrap();
+    let mut lines = content.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 9);
+    let finished = serde_json::json!({
+        "schema_version": "1",
+        "run_id": "0123456789abcdef",
+        "seq": 9,
+        "ts": "2025-01-01T00:00:00Z",
+        "spec_id": "demo",
+        "phase": "design",
+        "event": "phase_finished",
+        "success": false,
+        "error": "Failed to postprocess response",
+    })
+    .to_string();
+    lines.push(&finished);
+
+    for line in lines {
+        let value: serde_json::Value = serde_json::from_str(line).unwrap();
+        if let Err(error) = validator.validate(&value) {
+            panic!("Event line failed validation:\n{line}\n{error}");
+        }
+    }
+}
+
+#[test]
+fn test_core_artifact_schemas_match_models() {
+    use xchecker::extraction::{
+        DesignCore, RequirementsCore, TasksCore, summarize_design, summarize_requirements,
+        summarize_tasks,
+    };
+
+    let now = chrono::Utc::now();
+    let cases = [
+        (
+            "schemas/requirements-core.v1.json",
+            RequirementsCore::json_schema(),
+            serde_json::to_value(RequirementsCore::new(
+                "demo",
+                &summarize_requirements("### Requirement 1\n**User Story:** As a user\n"),
+                now,
+            )),
+        ),
+        (
+            "schemas/design-core.v1.json",
+            DesignCore::json_schema(),
+            serde_json::to_value(DesignCore::new(
+                "demo",
+                &summarize_design("## Architecture\n"),
+                now,
+            )),
+        ),
+        (
+            "schemas/tasks-core.v1.json",
+            TasksCore::json_schema(),
+            serde_json::to_value(TasksCore::new(
+                "demo",
+                &summarize_tasks("## Task 1\n- [ ] a\n"),
+                now,
+            )),
+        ),
+    ];
+
+    for (path, emitted, instance) in cases {
+        let published: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(path).unwrap_or_else(|_| panic!("Failed to read {path}")),
+        )
+        .expect("Failed to parse schema JSON");
+        assert_eq!(
+            published,
+            emitted,
+            "{path} is out of date; replace it with:\n{}",
+            serde_json::to_string_pretty(&emitted).unwrap()
+        );
+
+        let validator = jsonschema::validator_for(&published).expect("Failed to compile schema");
+        let instance = instance.unwrap();
+        if let Err(error) = validator.validate(&instance) {
+            panic!("Core artifact failed validation against {path}:\n{error}");
+        }
+    }
+}
@@ -256,6 +256,7 @@ fn test_stderr_truncation_for_receipts() {
         stderr_truncated: true,
         stdout_total_bytes: 0,
         stderr_total_bytes: large_stderr.len(),
+        process_usage: None,
     };
 
     // Get stderr for receipt (should be truncated to 2048 bytes)
@@ -293,6 +294,7 @@ fn test_stderr_no_truncation_when_small() {
         stderr_truncated: false,
         stdout_total_bytes: 0,
         stderr_total_bytes: small_stderr.len(),
+        process_usage: None,
     };
 
     // Get stderr for receipt
@@ -323,6 +325,7 @@ fn test_stderr_exact_2048_bytes() {
         stderr_truncated: false,
         stdout_total_bytes: 0,
         stderr_total_bytes: exact_stderr.len(),
+        process_usage: None,
     };
 
     // Get stderr for receipt
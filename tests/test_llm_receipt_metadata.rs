@@ -32,6 +32,7 @@ fn test_execution_strategy_in_receipt_controlled() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let pipeline = Some(PipelineInfo {
@@ -84,6 +85,7 @@ fn test_execution_strategy_optional_for_backward_compat() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -127,6 +129,7 @@ fn test_llm_metadata_in_receipt() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let mut receipt = manager.create_receipt(
@@ -161,6 +164,9 @@ fn test_llm_metadata_in_receipt() {
         timed_out: Some(false),
         timeout_seconds: None,
         budget_exhausted: None,
+        requests: None,
+        provider_usage: None,
+        process_usage: None,
     });
 
     // Verify LLM metadata is present
@@ -192,6 +198,9 @@ fn test_llm_metadata_optional_fields() {
         timed_out: None,
         timeout_seconds: None,
         budget_exhausted: None,
+        requests: None,
+        provider_usage: None,
+        process_usage: None,
     };
 
     // Should serialize without errors
@@ -255,6 +264,7 @@ async fn test_dry_run_receipt_has_llm_metadata() {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     let spec_id = format!("test-dry-run-metadata-{}", std::process::id());
@@ -344,6 +354,7 @@ async fn test_dry_run_receipt_has_full_llm_metadata() {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     let spec_id = format!("test-dry-run-full-metadata-{}", std::process::id());
@@ -561,6 +572,7 @@ fn test_complete_provider_metadata_in_receipt() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     // Create a receipt
@@ -674,6 +686,7 @@ mod property_tests {
                 files: vec![],
                 max_bytes: 65536,
                 max_lines: 1200,
+                ..Default::default()
             };
 
             let pipeline = strategy.map(|s| PipelineInfo {
@@ -744,6 +757,7 @@ mod property_tests {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         };
 
         let pipeline = Some(PipelineInfo {
@@ -754,6 +768,7 @@ mod property_tests {
         let outputs = vec![xchecker::types::FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized: "abc123".to_string(),
+            blake3_front_matter: None,
         }];
 
         let receipt = manager.create_receipt(
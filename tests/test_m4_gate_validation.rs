@@ -175,6 +175,7 @@ fn test_error_receipt_exit_code_alignment() {
                 files: vec![],
                 max_bytes: 65536,
                 max_lines: 1200,
+                ..Default::default()
             },
             None,     // stderr_tail
             None,     // stderr_redacted
@@ -396,6 +397,7 @@ fn test_phase_timeout_configuration() {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
     let timeout = PhaseTimeout::from_config(&config);
     assert_eq!(timeout.duration.as_secs(), PhaseTimeout::DEFAULT_SECS);
@@ -411,6 +413,7 @@ fn test_phase_timeout_configuration() {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
     let timeout = PhaseTimeout::from_config(&config);
     assert_eq!(timeout.duration.as_secs(), 300);
@@ -426,6 +429,7 @@ fn test_phase_timeout_configuration() {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
     let timeout = PhaseTimeout::from_config(&config);
     assert_eq!(timeout.duration.as_secs(), PhaseTimeout::MIN_SECS);
@@ -482,6 +486,7 @@ fn test_receipt_schema_version() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         },
         None,     // stderr_redacted
         None,     // stderr_redacted
@@ -524,6 +529,7 @@ fn test_receipt_canonical_json_emission() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         },
         None,     // stderr_redacted
         None,     // stderr_redacted
@@ -630,6 +636,7 @@ mod integration_tests {
             strict_validation: false,
             redactor: Default::default(),
             hooks: None,
+            llm_backend: None,
         };
 
         let result = env.orchestrator.execute_requirements_phase(&config).await?;
@@ -40,6 +40,7 @@ fn dry_run_config() -> OrchestratorConfig {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     }
 }
 
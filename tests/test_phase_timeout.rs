@@ -84,6 +84,7 @@ fn test_phase_timeout_from_config() {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
     let timeout = PhaseTimeout::from_config(&config);
     assert_eq!(timeout.duration.as_secs(), 300);
@@ -99,6 +100,7 @@ fn test_phase_timeout_from_config() {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
     let timeout = PhaseTimeout::from_config(&config);
     assert_eq!(timeout.duration.as_secs(), PhaseTimeout::MIN_SECS);
@@ -112,6 +114,7 @@ fn test_phase_timeout_from_config() {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
     let timeout = PhaseTimeout::from_config(&config);
     assert_eq!(timeout.duration.as_secs(), PhaseTimeout::DEFAULT_SECS);
@@ -127,6 +130,7 @@ fn test_phase_timeout_from_config() {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
     let timeout = PhaseTimeout::from_config(&config);
     assert_eq!(timeout.duration.as_secs(), PhaseTimeout::DEFAULT_SECS);
@@ -152,6 +156,7 @@ async fn test_timeout_creates_partial_and_receipt() -> Result<()> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     // Note: This test would need a way to simulate a slow Claude response
@@ -267,6 +272,7 @@ mod integration_tests {
             strict_validation: false,
             redactor: Default::default(),
             hooks: None,
+            llm_backend: None,
         };
 
         let result = env.orchestrator.execute_requirements_phase(&config).await?;
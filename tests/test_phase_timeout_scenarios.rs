@@ -67,6 +67,7 @@ fn create_config_with_timeout(timeout_secs: u64, dry_run: bool) -> OrchestratorC
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     }
 }
 
@@ -88,6 +89,7 @@ fn create_stub_timeout_config(timeout_secs: u64) -> Option<OrchestratorConfig> {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     })
 }
 
@@ -429,6 +431,7 @@ fn test_timeout_invalid_config_uses_default() {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     let timeout = PhaseTimeout::from_config(&config);
@@ -453,6 +456,7 @@ fn test_timeout_negative_config_uses_default() {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     };
 
     let timeout = PhaseTimeout::from_config(&config);
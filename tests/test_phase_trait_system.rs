@@ -145,6 +145,7 @@ fn test_packet_creation() {
         }],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let budget = BudgetUsage::new(65536, 1200);
@@ -184,6 +185,7 @@ fn test_packet_with_evidence() {
         files: files.clone(),
         max_bytes: 100000,
         max_lines: 2000,
+        ..Default::default()
     };
 
     let budget = BudgetUsage::new(100000, 2000);
@@ -273,6 +275,7 @@ fn test_packet_is_within_budget() {
         files: vec![],
         max_bytes: 1000,
         max_lines: 100,
+        ..Default::default()
     };
 
     let mut budget = BudgetUsage::new(1000, 100);
@@ -289,6 +292,7 @@ fn test_packet_exceeds_budget() {
         files: vec![],
         max_bytes: 1000,
         max_lines: 100,
+        ..Default::default()
     };
 
     let mut budget = BudgetUsage::new(1000, 100);
@@ -499,7 +503,8 @@ fn test_requirements_phase_artifact_generation() -> Result<()> {
     );
     assert!(yaml_artifact.content.contains("spec_id"));
     assert!(yaml_artifact.content.contains("test-req-artifacts"));
-    assert!(yaml_artifact.content.contains("phase: \"requirements\""));
+    let core = xchecker::extraction::RequirementsCore::from_yaml(&yaml_artifact.content)?;
+    assert_eq!(core.phase, "requirements");
     assert!(!yaml_artifact.blake3_hash.is_empty());
 
     Ok(())
@@ -622,7 +627,8 @@ fn test_design_phase_artifact_generation() -> Result<()> {
     );
     assert!(yaml_artifact.content.contains("spec_id"));
     assert!(yaml_artifact.content.contains("test-design-artifacts"));
-    assert!(yaml_artifact.content.contains("phase: \"design\""));
+    let core = xchecker::extraction::DesignCore::from_yaml(&yaml_artifact.content)?;
+    assert_eq!(core.phase, "design");
     assert!(!yaml_artifact.blake3_hash.is_empty());
 
     Ok(())
@@ -745,7 +751,8 @@ fn test_tasks_phase_artifact_generation() -> Result<()> {
     );
     assert!(yaml_artifact.content.contains("spec_id"));
     assert!(yaml_artifact.content.contains("test-tasks-artifacts"));
-    assert!(yaml_artifact.content.contains("phase: \"tasks\""));
+    let core = xchecker::extraction::TasksCore::from_yaml(&yaml_artifact.content)?;
+    assert_eq!(core.phase, "tasks");
     assert!(!yaml_artifact.blake3_hash.is_empty());
 
     Ok(())
@@ -25,7 +25,9 @@ use xchecker::{
     CliArgs,
     // Configuration
     Config,
+    Error,
     ErrorCategory,
+    ErrorKind,
     // Exit codes
     ExitCode,
     // Primary facade for embedding
@@ -171,6 +173,13 @@ fn test_error_types_accessible() {
     // (verified by the fact that XCheckerError implements it)
     fn _assert_user_friendly_error<T: UserFriendlyError>() {}
     _assert_user_friendly_error::<XCheckerError>();
+
+    // Error is the anyhow-free error type for embedding applications
+    fn _assert_std_error<T: std::error::Error + Send + Sync + 'static>() {}
+    _assert_std_error::<Error>();
+    let _: fn(&Error) -> ErrorKind = Error::kind;
+    let _: fn(&Error) -> ExitCode = Error::exit_code;
+    let _: fn(XCheckerError) -> Error = Error::from;
 }
 
 // ============================================================================
@@ -29,6 +29,7 @@ fn test_receipt_schema_version_and_emitted_at() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         },
         outputs: vec![],
         exit_code: 0,
@@ -42,6 +43,9 @@ fn test_receipt_schema_version_and_emitted_at() {
 
         llm: None,
         pipeline: None,
+        suggested_actions: vec![],
+        config_hash: None,
+        config_snapshot: None,
     };
 
     // Verify schema_version is set
@@ -85,6 +89,7 @@ fn test_receipt_error_fields() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         },
         outputs: vec![],
         exit_code: 70,
@@ -98,6 +103,9 @@ fn test_receipt_error_fields() {
 
         llm: None,
         pipeline: None,
+        suggested_actions: vec![],
+        config_hash: None,
+        config_snapshot: None,
     };
 
     // Verify error fields
@@ -123,14 +131,17 @@ fn test_outputs_sorted_by_path() {
         FileHash {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized: "hash3".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized: "hash1".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/05-analysis.md".to_string(),
             blake3_canonicalized: "hash2".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
@@ -152,6 +163,7 @@ fn test_outputs_sorted_by_path() {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         },
         outputs,
         exit_code: 0,
@@ -165,6 +177,9 @@ fn test_outputs_sorted_by_path() {
 
         llm: None,
         pipeline: None,
+        suggested_actions: vec![],
+        config_hash: None,
+        config_snapshot: None,
     };
 
     // Note: The ReceiptManager.create_receipt() sorts outputs, but when creating
@@ -191,10 +206,12 @@ fn test_jcs_canonical_json_emission() {
         FileHash {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized: "hash3".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized: "hash1".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
@@ -202,6 +219,7 @@ fn test_jcs_canonical_json_emission() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -280,16 +298,19 @@ fn test_different_insertion_orders_produce_identical_json() {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized:
                 "abc123def456789012345678901234567890123456789012345678901234abcd".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized:
                 "def456abc789012345678901234567890123456789012345678901234567890a".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/20-tasks.md".to_string(),
             blake3_canonicalized:
                 "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
@@ -299,16 +320,19 @@ fn test_different_insertion_orders_produce_identical_json() {
             path: "artifacts/20-tasks.md".to_string(),
             blake3_canonicalized:
                 "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized:
                 "abc123def456789012345678901234567890123456789012345678901234abcd".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized:
                 "def456abc789012345678901234567890123456789012345678901234567890a".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
@@ -327,6 +351,7 @@ fn test_different_insertion_orders_produce_identical_json() {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     // Use a fixed timestamp for both receipts to ensure identical output
@@ -514,6 +539,7 @@ fn test_status_different_insertion_orders_produce_identical_json() {
         effective_config: config1,
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     };
 
     // Sort artifacts by path for status1
@@ -533,6 +559,7 @@ fn test_status_different_insertion_orders_produce_identical_json() {
         effective_config: config2,
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     };
 
     // Sort artifacts by path for status2
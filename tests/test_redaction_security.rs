@@ -132,6 +132,7 @@ fn test_receipts_no_raw_packet_content() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     // Create a receipt
@@ -213,6 +214,7 @@ fn test_receipts_packet_evidence_no_content_leak() -> Result<()> {
         }],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     // Create a receipt
@@ -295,6 +297,7 @@ fn test_status_no_environment_variables() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = receipt_manager.create_receipt(
@@ -490,6 +493,7 @@ fn test_receipt_stderr_tail_no_secrets() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let stderr_tail =
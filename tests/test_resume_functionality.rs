@@ -162,6 +162,7 @@ async fn test_resume_with_failed_dependency_fails() -> Result<()> {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         },
         None,                             // stderr_tail
         None,                             // stderr_redacted
@@ -371,6 +372,7 @@ async fn test_resume_after_timeout() -> Result<()> {
             files: vec![],
             max_bytes: 65536,
             max_lines: 1200,
+            ..Default::default()
         },
         None,                                                  // stderr_tail
         None,                                                  // stderr_redacted
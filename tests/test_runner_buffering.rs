@@ -123,6 +123,7 @@ fn test_stderr_receipt_truncation() {
         stderr_truncated: true,
         stdout_total_bytes: 0,
         stderr_total_bytes: large_stderr.len(),
+        process_usage: None,
     };
 
     // Get stderr for receipt (should be truncated to 2048 bytes)
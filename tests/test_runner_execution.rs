@@ -363,6 +363,7 @@ fn test_claude_response_structure() {
         stderr_truncated: false,
         stdout_total_bytes: 11,
         stderr_total_bytes: 10,
+        process_usage: None,
     };
 
     assert_eq!(response.stdout, "test output");
@@ -19,7 +19,7 @@ use std::fs;
 use std::path::PathBuf;
 use tempfile::TempDir;
 use xchecker::error::UserFriendlyError;
-use xchecker::source::{SourceError, SourceResolver, SourceType};
+use xchecker::source::{SourceAllowlist, SourceError, SourceResolver, SourceType};
 
 // ============================================================================
 // GitHub Source Resolution Tests (FR-SOURCE-001, FR-SOURCE-002)
@@ -184,7 +184,8 @@ fn test_filesystem_source_resolution_file() {
     fs::write(&file_path, test_content).unwrap();
 
     // Test file source resolution
-    let result = SourceResolver::resolve_filesystem(&file_path);
+    let result =
+        SourceResolver::resolve_filesystem(&file_path, &SourceAllowlist::new([temp_dir.path()]));
 
     assert!(
         result.is_ok(),
@@ -219,7 +220,8 @@ fn test_filesystem_source_resolution_directory() {
     let dir_path = temp_dir.path().to_path_buf();
 
     // Test directory source resolution
-    let result = SourceResolver::resolve_filesystem(&dir_path);
+    let result =
+        SourceResolver::resolve_filesystem(&dir_path, &SourceAllowlist::new([temp_dir.path()]));
 
     assert!(
         result.is_ok(),
@@ -250,7 +252,8 @@ fn test_filesystem_source_resolution_file_with_unicode() {
     fs::write(&file_path, test_content).unwrap();
 
     // Test file source resolution with unicode
-    let result = SourceResolver::resolve_filesystem(&file_path);
+    let result =
+        SourceResolver::resolve_filesystem(&file_path, &SourceAllowlist::new([temp_dir.path()]));
 
     assert!(
         result.is_ok(),
@@ -270,7 +273,8 @@ fn test_filesystem_source_resolution_empty_file() {
     fs::write(&file_path, "").unwrap();
 
     // Test empty file source resolution
-    let result = SourceResolver::resolve_filesystem(&file_path);
+    let result =
+        SourceResolver::resolve_filesystem(&file_path, &SourceAllowlist::new([temp_dir.path()]));
 
     assert!(result.is_ok(), "Empty file should resolve successfully");
 
@@ -283,7 +287,8 @@ fn test_filesystem_validation_path_not_found() {
     // Test validation: non-existent path should fail
     let nonexistent_path = PathBuf::from("/nonexistent/path/to/file.txt");
 
-    let result = SourceResolver::resolve_filesystem(&nonexistent_path);
+    let result =
+        SourceResolver::resolve_filesystem(&nonexistent_path, &SourceAllowlist::project_root());
 
     assert!(result.is_err(), "Non-existent path should fail validation");
 
@@ -300,7 +305,8 @@ fn test_filesystem_validation_relative_path_not_found() {
     // Test validation: non-existent relative path should fail
     let nonexistent_path = PathBuf::from("./this/does/not/exist.txt");
 
-    let result = SourceResolver::resolve_filesystem(&nonexistent_path);
+    let result =
+        SourceResolver::resolve_filesystem(&nonexistent_path, &SourceAllowlist::project_root());
 
     assert!(
         result.is_err(),
@@ -316,7 +322,8 @@ fn test_filesystem_source_resolution_nested_directory() {
     fs::create_dir_all(&nested_dir).unwrap();
 
     // Test nested directory source resolution
-    let result = SourceResolver::resolve_filesystem(&nested_dir);
+    let result =
+        SourceResolver::resolve_filesystem(&nested_dir, &SourceAllowlist::new([temp_dir.path()]));
 
     assert!(
         result.is_ok(),
@@ -535,7 +542,8 @@ fn test_filesystem_file_metadata_tracking() {
     let file_path = temp_dir.path().join("metadata_test.txt");
     fs::write(&file_path, "test content").unwrap();
 
-    let result = SourceResolver::resolve_filesystem(&file_path);
+    let result =
+        SourceResolver::resolve_filesystem(&file_path, &SourceAllowlist::new([temp_dir.path()]));
     assert!(result.is_ok());
 
     let content = result.unwrap();
@@ -560,7 +568,8 @@ fn test_filesystem_directory_metadata_tracking() {
     let temp_dir = TempDir::new().unwrap();
     let dir_path = temp_dir.path().to_path_buf();
 
-    let result = SourceResolver::resolve_filesystem(&dir_path);
+    let result =
+        SourceResolver::resolve_filesystem(&dir_path, &SourceAllowlist::new([temp_dir.path()]));
     assert!(result.is_ok());
 
     let content = result.unwrap();
@@ -629,7 +638,8 @@ fn test_source_resolution_workflow_filesystem() {
     let file_path = temp_dir.path().join("workflow_test.txt");
     fs::write(&file_path, "Workflow test content").unwrap();
 
-    let result = SourceResolver::resolve_filesystem(&file_path);
+    let result =
+        SourceResolver::resolve_filesystem(&file_path, &SourceAllowlist::new([temp_dir.path()]));
 
     assert!(result.is_ok());
     let content = result.unwrap();
@@ -654,8 +664,10 @@ fn test_multiple_source_resolutions() {
     fs::write(&file1, "Content 1").unwrap();
     fs::write(&file2, "Content 2").unwrap();
 
-    let result1 = SourceResolver::resolve_filesystem(&file1);
-    let result2 = SourceResolver::resolve_filesystem(&file2);
+    let result1 =
+        SourceResolver::resolve_filesystem(&file1, &SourceAllowlist::new([temp_dir.path()]));
+    let result2 =
+        SourceResolver::resolve_filesystem(&file2, &SourceAllowlist::new([temp_dir.path()]));
     let result3 = SourceResolver::resolve_github("owner1", "repo1", "1");
     let result4 = SourceResolver::resolve_github("owner2", "repo2", "2");
 
@@ -60,6 +60,7 @@ fn create_test_receipt(
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = receipt_manager.create_receipt(
@@ -211,6 +212,7 @@ fn test_artifact_enumeration_with_blake3() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let outputs = vec![
@@ -218,11 +220,13 @@ fn test_artifact_enumeration_with_blake3() -> Result<()> {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized:
                 "abcd1234567890abcdef1234567890abcdef1234567890abcdef1234567890ab".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/00-requirements.core.yaml".to_string(),
             blake3_canonicalized:
                 "1234abcd567890abcdef1234567890abcdef1234567890abcdef1234567890cd".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
@@ -561,6 +565,7 @@ fn test_status_artifacts_sorted() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let outputs = vec![
@@ -568,16 +573,19 @@ fn test_status_artifacts_sorted() -> Result<()> {
             path: "artifacts/99-final.md".to_string(),
             blake3_canonicalized:
                 "zzzz1234567890abcdef1234567890abcdef1234567890abcdef1234567890ab".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized:
                 "aaaa1234567890abcdef1234567890abcdef1234567890abcdef1234567890ab".to_string(),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/50-middle.md".to_string(),
             blake3_canonicalized:
                 "mmmm1234567890abcdef1234567890abcdef1234567890abcdef1234567890ab".to_string(),
+            blake3_front_matter: None,
         },
     ];
 
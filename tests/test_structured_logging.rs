@@ -9,7 +9,8 @@
 //! works correctly with the required fields: spec_id, phase, duration_ms, runner_mode
 
 use xchecker::logging::{
-    Logger, init_tracing, log_phase_complete, log_phase_error, log_phase_start, phase_span,
+    Logger, Verbosity, init_tracing, log_phase_complete, log_phase_error, log_phase_start,
+    phase_span,
 };
 use xchecker::test_support;
 
@@ -17,14 +18,14 @@ use xchecker::test_support;
 fn test_tracing_initialization() {
     // Test that tracing can be initialized without errors
     // Note: May fail if already initialized in another test, which is acceptable
-    let result = init_tracing(false);
+    let result = init_tracing(Verbosity::Normal);
     assert!(result.is_ok() || result.is_err()); // Either is fine in tests
 }
 
 #[test]
 fn test_verbose_tracing_initialization() {
     // Test verbose mode initialization
-    let result = init_tracing(true);
+    let result = init_tracing(Verbosity::Verbose);
     assert!(result.is_ok() || result.is_err()); // Either is fine in tests
 }
 
@@ -35,14 +35,17 @@ fn test_jcs_byte_identical_reserialization() -> Result<()> {
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized: "aaaa".repeat(16), // 64 chars
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized: "bbbb".repeat(16),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/20-tasks.md".to_string(),
             blake3_canonicalized: "cccc".repeat(16),
+            blake3_front_matter: None,
         },
     ];
 
@@ -51,14 +54,17 @@ fn test_jcs_byte_identical_reserialization() -> Result<()> {
         FileHash {
             path: "artifacts/20-tasks.md".to_string(),
             blake3_canonicalized: "cccc".repeat(16),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized: "aaaa".repeat(16),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/10-design.md".to_string(),
             blake3_canonicalized: "bbbb".repeat(16),
+            blake3_front_matter: None,
         },
     ];
 
@@ -77,6 +83,7 @@ fn test_jcs_byte_identical_reserialization() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let mut receipt1 = Receipt {
@@ -105,6 +112,9 @@ fn test_jcs_byte_identical_reserialization() -> Result<()> {
         diff_context: None,
         llm: None,
         pipeline: None,
+        suggested_actions: vec![],
+        config_hash: None,
+        config_snapshot: None,
     };
 
     let mut receipt2 = Receipt {
@@ -133,6 +143,9 @@ fn test_jcs_byte_identical_reserialization() -> Result<()> {
         diff_context: None,
         llm: None,
         pipeline: None,
+        suggested_actions: vec![],
+        config_hash: None,
+        config_snapshot: None,
     };
 
     // Sort outputs to ensure deterministic ordering
@@ -168,14 +181,17 @@ fn test_jcs_sorted_arrays_receipts() -> Result<()> {
         FileHash {
             path: "artifacts/99-final.md".to_string(),
             blake3_canonicalized: "zzzz".repeat(16),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/00-requirements.md".to_string(),
             blake3_canonicalized: "aaaa".repeat(16),
+            blake3_front_matter: None,
         },
         FileHash {
             path: "artifacts/50-middle.md".to_string(),
             blake3_canonicalized: "mmmm".repeat(16),
+            blake3_front_matter: None,
         },
     ];
 
@@ -183,6 +199,7 @@ fn test_jcs_sorted_arrays_receipts() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -262,6 +279,7 @@ fn test_jcs_sorted_arrays_status() -> Result<()> {
         effective_config: BTreeMap::new(),
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     };
 
     // Serialize using JCS
@@ -366,6 +384,7 @@ fn test_receipts_use_jcs() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -438,6 +457,7 @@ fn test_status_uses_jcs() -> Result<()> {
         effective_config: BTreeMap::new(),
         lock_drift: None,
         pending_fixups: None,
+        metadata: None,
     };
 
     // Serialize using JCS
@@ -586,6 +606,7 @@ fn test_error_receipts_use_jcs() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
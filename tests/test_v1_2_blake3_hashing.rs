@@ -204,6 +204,7 @@ fn test_blake3_receipts_match_on_disk_artifacts() -> Result<()> {
         files: vec![],
         max_bytes: 65536,
         max_lines: 1200,
+        ..Default::default()
     };
 
     let receipt = manager.create_receipt(
@@ -52,6 +52,7 @@ fn dry_run_config() -> OrchestratorConfig {
         strict_validation: false,
         redactor: Default::default(),
         hooks: None,
+        llm_backend: None,
     }
 }
 
@@ -211,6 +212,7 @@ async fn test_single_phase_vs_workflow_receipt_parity() -> Result<()> {
             strict_validation: false,
             redactor: Default::default(),
             hooks: None,
+            llm_backend: None,
         },
         false,
     )?;
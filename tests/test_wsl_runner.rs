@@ -345,6 +345,7 @@ mod wsl_runner_tests {
             stderr_truncated: false,
             stdout_total_bytes: 0,
             stderr_total_bytes: 0,
+            process_usage: None,
         };
 
         // Verify that runner_distro is captured
@@ -371,6 +372,7 @@ mod wsl_runner_tests {
             stderr_truncated: false,
             stdout_total_bytes: 0,
             stderr_total_bytes: 0,
+            process_usage: None,
         };
 
         // Verify that runner_distro is None for native execution